@@ -14,7 +14,6 @@ pub enum RendererError {
     UnsupportedTarget,
 
     /// Vulkan API error.
-    #[cfg(not(target_arch = "wasm32"))]
     #[error("Vulkan error: {0}")]
     Vulkan(#[from] ash::vk::Result),
 
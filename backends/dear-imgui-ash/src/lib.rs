@@ -9,37 +9,26 @@
 //!
 //! ## Target support
 //!
-//! Native targets only. On `wasm32`, this crate provides a stub implementation
-//! that always returns `RendererError::UnsupportedTarget`.
+//! Rendering requires a native target with Vulkan and the `vulkan` feature (enabled by default). On
+//! `wasm32`, or on any target with default features disabled, the crate is built API-only: the public types and methods are the same,
+//! but every renderer constructor returns [`RendererError::UnsupportedTarget`]. Multi-backend
+//! crates can use this to type-check and generate docs without a Vulkan setup.
 
 mod error;
 pub use error::*;
 
+mod options;
+pub use options::*;
+
 mod texture;
 pub use texture::*;
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(target_arch = "wasm32"), feature = "vulkan"))]
 mod renderer;
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(target_arch = "wasm32"), feature = "vulkan"))]
 pub use renderer::*;
 
-#[cfg(target_arch = "wasm32")]
-mod wasm_stub {
-    use super::RendererError;
-
-    /// Stub renderer for `wasm32` targets.
-    #[derive(Debug, Default)]
-    pub struct AshRenderer;
-
-    impl AshRenderer {
-        pub fn new() -> Result<Self, RendererError> {
-            Err(RendererError::UnsupportedTarget)
-        }
-    }
-
-    #[derive(Debug, Default, Clone, Copy)]
-    pub struct Options;
-}
-
-#[cfg(target_arch = "wasm32")]
-pub use wasm_stub::*;
+#[cfg(any(target_arch = "wasm32", not(feature = "vulkan")))]
+mod stub;
+#[cfg(any(target_arch = "wasm32", not(feature = "vulkan")))]
+pub use stub::*;
@@ -1,4 +1,6 @@
-use super::*;
+//! Renderer options shared by the Vulkan and API-only builds.
+
+use ash::vk;
 
 /// Optional parameters of the renderer.
 #[derive(Debug, Clone, Copy)]
@@ -30,6 +32,13 @@ pub struct Options {
     ///
     /// Recommended: keep this as `vk::Format::R8G8B8A8_UNORM` to match the shader gamma path.
     pub texture_format: vk::Format,
+    /// Pipeline cache used when creating the renderer's graphics pipelines.
+    ///
+    /// `vk::PipelineCache::null()` (the default) disables caching. Pass the application's cache
+    /// (e.g. one loaded from disk at startup) to make renderer creation fast after the first run.
+    /// The cache must outlive the renderer's pipeline creation calls; the renderer never
+    /// destroys it.
+    pub pipeline_cache: vk::PipelineCache,
 }
 
 impl Default for Options {
@@ -44,10 +53,22 @@ impl Default for Options {
             framebuffer_srgb: false,
             color_gamma_override: None,
             texture_format: vk::Format::R8G8B8A8_UNORM,
+            pipeline_cache: vk::PipelineCache::null(),
         }
     }
 }
 
+impl Options {
+    /// Sets [`in_flight_frames`](Self::in_flight_frames): the number of per-frame vertex/index
+    /// buffer sets, which should match the frames the application keeps in flight (2-3 is
+    /// typical). Use [`AshRenderer::render`](crate::AshRenderer::render) with the application's
+    /// frame index when it is greater than one.
+    pub fn in_flight_frames(mut self, frames: usize) -> Self {
+        self.in_flight_frames = frames;
+        self
+    }
+}
+
 /// `dynamic-rendering` feature related params.
 #[cfg(feature = "dynamic-rendering")]
 #[derive(Debug, Clone, Copy)]
@@ -1,4 +1,5 @@
 use super::*;
+use crate::Options;
 
 /// Vulkan renderer for Dear ImGui using `ash`.
 ///
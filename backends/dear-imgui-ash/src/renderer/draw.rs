@@ -1,10 +1,39 @@
 use super::*;
 
 impl AshRenderer {
+    /// Record draw commands for `draw_data` into `command_buffer`.
+    ///
+    /// Vertex/index buffers rotate through the `Options::in_flight_frames` sets in call order.
+    /// Skipped frames (empty draw data) don't advance the rotation, so applications with more
+    /// than one frame in flight should prefer [`render`](Self::render).
     pub fn cmd_draw(
         &mut self,
         command_buffer: vk::CommandBuffer,
         draw_data: &mut dear_imgui_rs::render::DrawData,
+    ) -> RendererResult<()> {
+        self.draw_with_frame(command_buffer, draw_data, None)
+    }
+
+    /// Record draw commands using the vertex/index buffers of frame `frame_index`.
+    ///
+    /// Pass the application's frame-in-flight index (taken modulo `Options::in_flight_frames`):
+    /// the buffers of a frame are only rewritten once that frame comes around again, i.e. after
+    /// the application waited on that frame's fence, so the CPU never overwrites buffers the GPU
+    /// is still reading.
+    pub fn render(
+        &mut self,
+        command_buffer: vk::CommandBuffer,
+        draw_data: &mut dear_imgui_rs::render::DrawData,
+        frame_index: usize,
+    ) -> RendererResult<()> {
+        self.draw_with_frame(command_buffer, draw_data, Some(frame_index))
+    }
+
+    fn draw_with_frame(
+        &mut self,
+        command_buffer: vk::CommandBuffer,
+        draw_data: &mut dear_imgui_rs::render::DrawData,
+        frame_index: Option<usize>,
     ) -> RendererResult<()> {
         let gamma = self.gamma();
         if !draw_data.valid() || draw_data.total_vtx_count() == 0 {
@@ -14,7 +43,11 @@ impl AshRenderer {
         self.reap_completed_uploads()?;
         self.process_texture_requests(draw_data)?;
 
-        let Some(mesh) = self.frames.next() else {
+        let mesh = match frame_index {
+            Some(index) => self.frames.get(index),
+            None => self.frames.next(),
+        };
+        let Some(mesh) = mesh else {
             return Err(RendererError::FrameResourcesUnavailable);
         };
         record_draw_commands(
@@ -83,6 +116,15 @@ impl Frames {
         Some(&mut self.meshes[i])
     }
 
+    /// Buffers of frame `frame_index` (modulo the frame count); does not touch the rotation.
+    pub(super) fn get(&mut self, frame_index: usize) -> Option<&mut Mesh> {
+        if self.meshes.is_empty() {
+            return None;
+        }
+        let i = frame_index % self.meshes.len();
+        Some(&mut self.meshes[i])
+    }
+
     pub(super) fn destroy(self, device: &Device, allocator: &mut Allocator) -> RendererResult<()> {
         for mesh in self.meshes {
             mesh.destroy(device, allocator)?;
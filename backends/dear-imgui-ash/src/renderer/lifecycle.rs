@@ -1,4 +1,7 @@
 use super::*;
+#[cfg(feature = "dynamic-rendering")]
+use crate::DynamicRendering;
+use crate::Options;
 
 impl AshRenderer {
     pub fn configure_imgui_context(&self, imgui_context: &mut Context) {
@@ -261,6 +264,7 @@ impl AshRenderer {
             framebuffer_srgb: false,
             color_gamma_override: self.options.color_gamma_override,
             texture_format: self.options.texture_format,
+            pipeline_cache: self.options.pipeline_cache,
         };
 
         #[cfg(not(feature = "dynamic-rendering"))]
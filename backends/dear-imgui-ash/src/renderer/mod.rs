@@ -9,10 +9,11 @@ mod lifecycle;
 pub mod multi_viewport;
 #[cfg(feature = "multi-viewport-sdl3")]
 pub mod multi_viewport_sdl3;
-mod options;
 #[cfg(any(feature = "multi-viewport-winit", feature = "multi-viewport-sdl3"))]
 mod pipeline;
 mod shaders;
+#[cfg(feature = "swapchain")]
+pub mod swapchain;
 #[cfg(test)]
 mod tests;
 mod texture;
@@ -32,9 +33,6 @@ use self::allocator::{Allocate, Allocator, Memory};
 use self::callbacks::draw_callback_reset_render_state;
 pub use self::core::AshRenderer;
 use self::draw::Frames;
-#[cfg(feature = "dynamic-rendering")]
-pub use self::options::DynamicRendering;
-pub use self::options::Options;
 #[cfg(all(
     any(feature = "multi-viewport-winit", feature = "multi-viewport-sdl3"),
     not(feature = "dynamic-rendering")
@@ -0,0 +1,831 @@
+//! Window surface and swapchain helper for simple integrations.
+//!
+//! [`WindowSwapchain`] owns everything between a Vulkan device and a window that an ImGui-only
+//! app needs: the surface, the swapchain and its image views, a clearing render pass (or the
+//! dynamic rendering setup with the `dynamic-rendering` feature), a command pool, per-frame
+//! synchronization, and resize/out-of-date handling. Instance and device creation stay with the
+//! application.
+//!
+//! ```rust,ignore
+//! let extensions = WindowSwapchain::required_instance_extensions(display_handle)?;
+//! // ... create the instance with `extensions` and a device with `VK_KHR_swapchain` ...
+//! let mut swapchain = unsafe {
+//!     WindowSwapchain::new(
+//!         &entry, &instance, physical_device, device.clone(), queue, queue_family_index,
+//!         display_handle, window_handle, [width, height], SwapchainConfig::default(),
+//!     )?
+//! };
+//! let mut renderer = AshRenderer::with_default_allocator(
+//!     &instance, physical_device, device, queue, swapchain.command_pool(),
+//!     swapchain.render_pass(), &mut imgui,
+//!     Some(Options {
+//!         in_flight_frames: swapchain.frames_in_flight(),
+//!         framebuffer_srgb: swapchain.is_srgb(),
+//!         ..Default::default()
+//!     }),
+//! )?;
+//!
+//! // Window resized:
+//! swapchain.resize([width, height]);
+//! // Each frame:
+//! let draw_data = imgui.render();
+//! swapchain.draw_frame(|cmd, frame_index| renderer.render(cmd, draw_data, frame_index))?;
+//! ```
+//!
+//! Drop the renderer before the swapchain: the swapchain owns the command pool the renderer
+//! allocates from.
+
+use std::ffi::c_char;
+
+use ash::khr::{surface as khr_surface, swapchain as khr_swapchain};
+use ash::{Device, Entry, Instance, vk};
+use raw_window_handle::{RawDisplayHandle, RawWindowHandle};
+
+use crate::{RendererError, RendererResult};
+
+#[cfg(feature = "dynamic-rendering")]
+use crate::DynamicRendering;
+
+/// Settings of a [`WindowSwapchain`].
+#[derive(Debug, Clone, Copy)]
+pub struct SwapchainConfig {
+    /// Frames recorded ahead of the GPU. Use the same value for `Options::in_flight_frames`.
+    pub frames_in_flight: usize,
+    /// Preferred present mode. `None` picks MAILBOX when available and FIFO otherwise; an
+    /// unsupported mode falls back to FIFO, which is always available.
+    pub present_mode: Option<vk::PresentModeKHR>,
+    /// Color the swapchain image is cleared to before the UI is drawn.
+    pub clear_color: [f32; 4],
+}
+
+impl Default for SwapchainConfig {
+    fn default() -> Self {
+        Self {
+            frames_in_flight: 2,
+            present_mode: None,
+            clear_color: [0.0, 0.0, 0.0, 1.0],
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct FrameSync {
+    image_available: vk::Semaphore,
+    render_finished: vk::Semaphore,
+    fence: vk::Fence,
+    command_buffer: vk::CommandBuffer,
+}
+
+/// A window surface with its swapchain, ready to draw ImGui frames into.
+pub struct WindowSwapchain {
+    device: Device,
+    physical_device: vk::PhysicalDevice,
+    queue: vk::Queue,
+    surface_loader: khr_surface::Instance,
+    surface: vk::SurfaceKHR,
+    swapchain_loader: khr_swapchain::Device,
+    swapchain: vk::SwapchainKHR,
+    surface_format: vk::SurfaceFormatKHR,
+    extent: vk::Extent2D,
+    images: Vec<vk::Image>,
+    image_views: Vec<vk::ImageView>,
+    #[cfg(not(feature = "dynamic-rendering"))]
+    render_pass: vk::RenderPass,
+    #[cfg(not(feature = "dynamic-rendering"))]
+    framebuffers: Vec<vk::Framebuffer>,
+    command_pool: vk::CommandPool,
+    frames: Vec<FrameSync>,
+    images_in_flight: Vec<vk::Fence>,
+    frame_index: usize,
+    config: SwapchainConfig,
+    window_size: [u32; 2],
+    dirty: bool,
+}
+
+impl WindowSwapchain {
+    /// Instance extensions needed to create a surface for `display_handle`.
+    pub fn required_instance_extensions(
+        display_handle: RawDisplayHandle,
+    ) -> RendererResult<&'static [*const c_char]> {
+        Ok(ash_window::enumerate_required_extensions(display_handle)?)
+    }
+
+    /// Create the surface and swapchain for a window of `size` physical pixels.
+    ///
+    /// `device` must have `VK_KHR_swapchain` enabled, and `queue` (from `queue_family_index`)
+    /// must support graphics and presenting to the surface. With the `dynamic-rendering`
+    /// feature the device also needs dynamic rendering (Vulkan 1.3 or `VK_KHR_dynamic_rendering`).
+    ///
+    /// # Safety
+    ///
+    /// The handles must be valid and outlive the returned value; `instance` must have been
+    /// created with [`required_instance_extensions`](Self::required_instance_extensions).
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn new(
+        entry: &Entry,
+        instance: &Instance,
+        physical_device: vk::PhysicalDevice,
+        device: Device,
+        queue: vk::Queue,
+        queue_family_index: u32,
+        display_handle: RawDisplayHandle,
+        window_handle: RawWindowHandle,
+        size: [u32; 2],
+        config: SwapchainConfig,
+    ) -> RendererResult<Self> {
+        if config.frames_in_flight == 0 {
+            return Err(RendererError::InvalidRenderState(
+                "SwapchainConfig::frames_in_flight must be >= 1".to_string(),
+            ));
+        }
+
+        let surface_loader = khr_surface::Instance::new(entry, instance);
+        let surface = unsafe {
+            ash_window::create_surface(entry, instance, display_handle, window_handle, None)?
+        };
+        let formats = match unsafe {
+            surface_loader.get_physical_device_surface_formats(physical_device, surface)
+        } {
+            Ok(formats) => formats,
+            Err(err) => {
+                unsafe { surface_loader.destroy_surface(surface, None) };
+                return Err(err.into());
+            }
+        };
+        let surface_format = pick_surface_format(&formats);
+
+        // Resources are created one by one below; from here on `Drop` frees whatever exists.
+        let mut this = Self {
+            swapchain_loader: khr_swapchain::Device::new(instance, &device),
+            device,
+            physical_device,
+            queue,
+            surface_loader,
+            surface,
+            swapchain: vk::SwapchainKHR::null(),
+            surface_format,
+            extent: vk::Extent2D::default(),
+            images: Vec::new(),
+            image_views: Vec::new(),
+            #[cfg(not(feature = "dynamic-rendering"))]
+            render_pass: vk::RenderPass::null(),
+            #[cfg(not(feature = "dynamic-rendering"))]
+            framebuffers: Vec::new(),
+            command_pool: vk::CommandPool::null(),
+            frames: Vec::new(),
+            images_in_flight: Vec::new(),
+            frame_index: 0,
+            config,
+            window_size: size,
+            dirty: false,
+        };
+
+        this.command_pool = unsafe {
+            this.device.create_command_pool(
+                &vk::CommandPoolCreateInfo::default()
+                    .queue_family_index(queue_family_index)
+                    .flags(vk::CommandPoolCreateFlags::RESET_COMMAND_BUFFER),
+                None,
+            )?
+        };
+        #[cfg(not(feature = "dynamic-rendering"))]
+        {
+            this.render_pass = create_render_pass(&this.device, surface_format.format)?;
+        }
+        for _ in 0..config.frames_in_flight {
+            let frame = create_frame_sync(&this.device, this.command_pool)?;
+            this.frames.push(frame);
+        }
+        this.create_swapchain()?;
+        Ok(this)
+    }
+
+    /// Render pass to create the renderer with (one color attachment, cleared, presented).
+    #[cfg(not(feature = "dynamic-rendering"))]
+    pub fn render_pass(&self) -> vk::RenderPass {
+        self.render_pass
+    }
+
+    /// Dynamic rendering setup to create the renderer with.
+    #[cfg(feature = "dynamic-rendering")]
+    pub fn dynamic_rendering(&self) -> DynamicRendering {
+        DynamicRendering {
+            color_attachment_format: self.surface_format.format,
+            depth_attachment_format: None,
+        }
+    }
+
+    /// Command pool to create the renderer with.
+    pub fn command_pool(&self) -> vk::CommandPool {
+        self.command_pool
+    }
+
+    /// Number of frames in flight (see [`SwapchainConfig::frames_in_flight`]).
+    pub fn frames_in_flight(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Color format of the swapchain images.
+    ///
+    /// It stays the same when the swapchain is recreated, so the renderer's pipeline stays valid.
+    pub fn format(&self) -> vk::Format {
+        self.surface_format.format
+    }
+
+    /// Whether the swapchain images are sRGB; pass it as `Options::framebuffer_srgb`.
+    pub fn is_srgb(&self) -> bool {
+        matches!(
+            self.surface_format.format,
+            vk::Format::B8G8R8A8_SRGB
+                | vk::Format::R8G8B8A8_SRGB
+                | vk::Format::A8B8G8R8_SRGB_PACK32
+        )
+    }
+
+    /// Current size of the swapchain images.
+    pub fn extent(&self) -> vk::Extent2D {
+        self.extent
+    }
+
+    /// Set the color images are cleared to before drawing.
+    pub fn set_clear_color(&mut self, color: [f32; 4]) {
+        self.config.clear_color = color;
+    }
+
+    /// Report a new window size (physical pixels); the swapchain is recreated on the next frame.
+    ///
+    /// A zero size (minimized window) skips frames until the window is restored.
+    pub fn resize(&mut self, size: [u32; 2]) {
+        if size != self.window_size {
+            self.window_size = size;
+            self.dirty = true;
+        }
+    }
+
+    /// Acquire an image, record a frame and present it.
+    ///
+    /// `draw` records into a command buffer inside the clearing render pass (or dynamic
+    /// rendering scope) and gets the frame-in-flight index to pass to
+    /// [`AshRenderer::render`](crate::AshRenderer::render). Returns `false` when the frame was
+    /// skipped because the window is minimized or the swapchain went out of date; it is
+    /// recreated for the next frame.
+    pub fn draw_frame(
+        &mut self,
+        draw: impl FnOnce(vk::CommandBuffer, usize) -> RendererResult<()>,
+    ) -> RendererResult<bool> {
+        if self.window_size[0] == 0 || self.window_size[1] == 0 {
+            return Ok(false);
+        }
+        if self.dirty {
+            self.recreate_swapchain()?;
+        }
+
+        let frame_index = self.frame_index;
+        let frame = self.frames[frame_index];
+        unsafe {
+            self.device
+                .wait_for_fences(&[frame.fence], true, u64::MAX)?;
+        }
+
+        let acquire = unsafe {
+            self.swapchain_loader.acquire_next_image(
+                self.swapchain,
+                u64::MAX,
+                frame.image_available,
+                vk::Fence::null(),
+            )
+        };
+        let image_index = match acquire {
+            Ok((image_index, suboptimal)) => {
+                self.dirty |= suboptimal;
+                image_index
+            }
+            Err(vk::Result::ERROR_OUT_OF_DATE_KHR) => {
+                self.dirty = true;
+                return Ok(false);
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let image = image_index as usize;
+
+        // Another frame in flight may still be rendering to this image.
+        let image_fence = self.images_in_flight[image];
+        if image_fence != vk::Fence::null() {
+            unsafe {
+                self.device
+                    .wait_for_fences(&[image_fence], true, u64::MAX)?;
+            }
+        }
+        self.images_in_flight[image] = frame.fence;
+
+        let cmd = frame.command_buffer;
+        unsafe {
+            self.device
+                .reset_command_buffer(cmd, vk::CommandBufferResetFlags::empty())?;
+            self.device.begin_command_buffer(
+                cmd,
+                &vk::CommandBufferBeginInfo::default()
+                    .flags(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT),
+            )?;
+        }
+        self.begin_rendering(cmd, image);
+        let recorded = draw(cmd, frame_index);
+        self.end_rendering(cmd, image);
+        unsafe { self.device.end_command_buffer(cmd)? };
+        if let Err(err) = recorded {
+            self.abandon_acquired_image(frame, image, image_fence)?;
+            return Err(err);
+        }
+
+        let wait_stages = [vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT];
+        let submit_info = vk::SubmitInfo::default()
+            .wait_semaphores(std::slice::from_ref(&frame.image_available))
+            .wait_dst_stage_mask(&wait_stages)
+            .command_buffers(std::slice::from_ref(&cmd))
+            .signal_semaphores(std::slice::from_ref(&frame.render_finished));
+        unsafe {
+            self.device.reset_fences(&[frame.fence])?;
+            self.device.queue_submit(
+                self.queue,
+                std::slice::from_ref(&submit_info),
+                frame.fence,
+            )?;
+        }
+
+        let present_info = vk::PresentInfoKHR::default()
+            .wait_semaphores(std::slice::from_ref(&frame.render_finished))
+            .swapchains(std::slice::from_ref(&self.swapchain))
+            .image_indices(std::slice::from_ref(&image_index));
+        match unsafe {
+            self.swapchain_loader
+                .queue_present(self.queue, &present_info)
+        } {
+            Ok(suboptimal) => self.dirty |= suboptimal,
+            Err(vk::Result::ERROR_OUT_OF_DATE_KHR) => self.dirty = true,
+            Err(err) => return Err(err.into()),
+        }
+
+        self.frame_index = (self.frame_index + 1) % self.frames.len();
+        Ok(true)
+    }
+
+    fn clear_value(&self) -> vk::ClearValue {
+        vk::ClearValue {
+            color: vk::ClearColorValue {
+                float32: self.config.clear_color,
+            },
+        }
+    }
+
+    #[cfg(not(feature = "dynamic-rendering"))]
+    fn begin_rendering(&self, cmd: vk::CommandBuffer, image: usize) {
+        let clear_values = [self.clear_value()];
+        unsafe {
+            self.device.cmd_begin_render_pass(
+                cmd,
+                &vk::RenderPassBeginInfo::default()
+                    .render_pass(self.render_pass)
+                    .framebuffer(self.framebuffers[image])
+                    .render_area(vk::Rect2D {
+                        offset: vk::Offset2D { x: 0, y: 0 },
+                        extent: self.extent,
+                    })
+                    .clear_values(&clear_values),
+                vk::SubpassContents::INLINE,
+            );
+        }
+    }
+
+    #[cfg(not(feature = "dynamic-rendering"))]
+    fn end_rendering(&self, cmd: vk::CommandBuffer, _image: usize) {
+        unsafe { self.device.cmd_end_render_pass(cmd) };
+    }
+
+    #[cfg(feature = "dynamic-rendering")]
+    fn begin_rendering(&self, cmd: vk::CommandBuffer, image: usize) {
+        // The image is cleared, so its previous contents (and layout) don't matter.
+        transition_image(
+            &self.device,
+            cmd,
+            self.images[image],
+            vk::ImageLayout::UNDEFINED,
+            vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
+        );
+        let color_attachment = vk::RenderingAttachmentInfo::default()
+            .image_view(self.image_views[image])
+            .image_layout(vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL)
+            .load_op(vk::AttachmentLoadOp::CLEAR)
+            .store_op(vk::AttachmentStoreOp::STORE)
+            .clear_value(self.clear_value());
+        unsafe {
+            self.device.cmd_begin_rendering(
+                cmd,
+                &vk::RenderingInfo::default()
+                    .render_area(vk::Rect2D {
+                        offset: vk::Offset2D { x: 0, y: 0 },
+                        extent: self.extent,
+                    })
+                    .layer_count(1)
+                    .color_attachments(std::slice::from_ref(&color_attachment)),
+            );
+        }
+    }
+
+    #[cfg(feature = "dynamic-rendering")]
+    fn end_rendering(&self, cmd: vk::CommandBuffer, image: usize) {
+        unsafe { self.device.cmd_end_rendering(cmd) };
+        transition_image(
+            &self.device,
+            cmd,
+            self.images[image],
+            vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
+            vk::ImageLayout::PRESENT_SRC_KHR,
+        );
+    }
+
+    /// Undoes an acquire whose frame will not be submitted.
+    ///
+    /// `acquire_next_image` already signaled `image_available`: an empty submit waits on it so the
+    /// next acquire gets an unsignaled semaphore, and re-signals the frame fence. The image itself
+    /// is never presented, so the swapchain is recreated on the next frame to release it.
+    fn abandon_acquired_image(
+        &mut self,
+        frame: FrameSync,
+        image: usize,
+        previous_fence: vk::Fence,
+    ) -> RendererResult<()> {
+        self.images_in_flight[image] = previous_fence;
+        self.dirty = true;
+        let wait_stages = [vk::PipelineStageFlags::ALL_COMMANDS];
+        let submit_info = vk::SubmitInfo::default()
+            .wait_semaphores(std::slice::from_ref(&frame.image_available))
+            .wait_dst_stage_mask(&wait_stages);
+        unsafe {
+            self.device.reset_fences(&[frame.fence])?;
+            self.device.queue_submit(
+                self.queue,
+                std::slice::from_ref(&submit_info),
+                frame.fence,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn recreate_swapchain(&mut self) -> RendererResult<()> {
+        unsafe { self.device.device_wait_idle()? };
+        self.destroy_swapchain_images();
+        self.create_swapchain()?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Create the swapchain (replacing the current one, if any) and its per-image resources.
+    fn create_swapchain(&mut self) -> RendererResult<()> {
+        let caps = unsafe {
+            self.surface_loader
+                .get_physical_device_surface_capabilities(self.physical_device, self.surface)?
+        };
+        let present_modes = unsafe {
+            self.surface_loader
+                .get_physical_device_surface_present_modes(self.physical_device, self.surface)?
+        };
+        let present_mode = pick_present_mode(&present_modes, self.config.present_mode);
+        let extent = pick_extent(&caps, self.window_size);
+        let min_image_count = {
+            let desired = caps.min_image_count.saturating_add(1);
+            if caps.max_image_count > 0 {
+                desired.min(caps.max_image_count)
+            } else {
+                desired
+            }
+        };
+        let composite_alpha = [
+            vk::CompositeAlphaFlagsKHR::OPAQUE,
+            vk::CompositeAlphaFlagsKHR::INHERIT,
+            vk::CompositeAlphaFlagsKHR::PRE_MULTIPLIED,
+            vk::CompositeAlphaFlagsKHR::POST_MULTIPLIED,
+        ]
+        .into_iter()
+        .find(|c| caps.supported_composite_alpha.contains(*c))
+        .unwrap_or(vk::CompositeAlphaFlagsKHR::OPAQUE);
+
+        let old_swapchain = self.swapchain;
+        let create_info = vk::SwapchainCreateInfoKHR::default()
+            .surface(self.surface)
+            .min_image_count(min_image_count)
+            .image_format(self.surface_format.format)
+            .image_color_space(self.surface_format.color_space)
+            .image_extent(extent)
+            .image_array_layers(1)
+            .image_usage(vk::ImageUsageFlags::COLOR_ATTACHMENT)
+            .image_sharing_mode(vk::SharingMode::EXCLUSIVE)
+            .pre_transform(caps.current_transform)
+            .composite_alpha(composite_alpha)
+            .present_mode(present_mode)
+            .clipped(true)
+            .old_swapchain(old_swapchain);
+        let swapchain = unsafe { self.swapchain_loader.create_swapchain(&create_info, None)? };
+        self.swapchain = swapchain;
+        if old_swapchain != vk::SwapchainKHR::null() {
+            unsafe { self.swapchain_loader.destroy_swapchain(old_swapchain, None) };
+        }
+        self.extent = extent;
+
+        self.images = unsafe { self.swapchain_loader.get_swapchain_images(swapchain)? };
+        self.images_in_flight = vec![vk::Fence::null(); self.images.len()];
+        for &image in &self.images {
+            let view = unsafe {
+                self.device.create_image_view(
+                    &vk::ImageViewCreateInfo::default()
+                        .image(image)
+                        .view_type(vk::ImageViewType::TYPE_2D)
+                        .format(self.surface_format.format)
+                        .subresource_range(vk::ImageSubresourceRange {
+                            aspect_mask: vk::ImageAspectFlags::COLOR,
+                            base_mip_level: 0,
+                            level_count: 1,
+                            base_array_layer: 0,
+                            layer_count: 1,
+                        }),
+                    None,
+                )?
+            };
+            self.image_views.push(view);
+        }
+
+        #[cfg(not(feature = "dynamic-rendering"))]
+        for &view in &self.image_views {
+            let framebuffer = unsafe {
+                self.device.create_framebuffer(
+                    &vk::FramebufferCreateInfo::default()
+                        .render_pass(self.render_pass)
+                        .attachments(std::slice::from_ref(&view))
+                        .width(extent.width)
+                        .height(extent.height)
+                        .layers(1),
+                    None,
+                )?
+            };
+            self.framebuffers.push(framebuffer);
+        }
+        Ok(())
+    }
+
+    /// Destroy the per-image resources, keeping the swapchain for `old_swapchain`.
+    fn destroy_swapchain_images(&mut self) {
+        unsafe {
+            #[cfg(not(feature = "dynamic-rendering"))]
+            for framebuffer in self.framebuffers.drain(..) {
+                self.device.destroy_framebuffer(framebuffer, None);
+            }
+            for view in self.image_views.drain(..) {
+                self.device.destroy_image_view(view, None);
+            }
+        }
+        self.images.clear();
+    }
+}
+
+impl Drop for WindowSwapchain {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = self.device.device_wait_idle();
+        }
+        self.destroy_swapchain_images();
+        unsafe {
+            for frame in self.frames.drain(..) {
+                self.device.destroy_semaphore(frame.image_available, None);
+                self.device.destroy_semaphore(frame.render_finished, None);
+                self.device.destroy_fence(frame.fence, None);
+            }
+            if self.command_pool != vk::CommandPool::null() {
+                self.device.destroy_command_pool(self.command_pool, None);
+            }
+            #[cfg(not(feature = "dynamic-rendering"))]
+            if self.render_pass != vk::RenderPass::null() {
+                self.device.destroy_render_pass(self.render_pass, None);
+            }
+            if self.swapchain != vk::SwapchainKHR::null() {
+                self.swapchain_loader
+                    .destroy_swapchain(self.swapchain, None);
+            }
+            self.surface_loader.destroy_surface(self.surface, None);
+        }
+    }
+}
+
+fn pick_surface_format(formats: &[vk::SurfaceFormatKHR]) -> vk::SurfaceFormatKHR {
+    if formats.len() == 1 && formats[0].format == vk::Format::UNDEFINED {
+        return vk::SurfaceFormatKHR {
+            format: vk::Format::B8G8R8A8_SRGB,
+            color_space: vk::ColorSpaceKHR::SRGB_NONLINEAR,
+        };
+    }
+
+    let preferred = [
+        vk::Format::B8G8R8A8_SRGB,
+        vk::Format::R8G8B8A8_SRGB,
+        vk::Format::B8G8R8A8_UNORM,
+        vk::Format::R8G8B8A8_UNORM,
+    ];
+    for p in preferred {
+        if let Some(f) = formats.iter().find(|f| f.format == p) {
+            return *f;
+        }
+    }
+    *formats.first().unwrap_or(&vk::SurfaceFormatKHR {
+        format: vk::Format::B8G8R8A8_UNORM,
+        color_space: vk::ColorSpaceKHR::SRGB_NONLINEAR,
+    })
+}
+
+fn pick_present_mode(
+    modes: &[vk::PresentModeKHR],
+    preferred: Option<vk::PresentModeKHR>,
+) -> vk::PresentModeKHR {
+    match preferred {
+        Some(mode) if modes.contains(&mode) => mode,
+        Some(_) => vk::PresentModeKHR::FIFO,
+        None if modes.contains(&vk::PresentModeKHR::MAILBOX) => vk::PresentModeKHR::MAILBOX,
+        None => vk::PresentModeKHR::FIFO,
+    }
+}
+
+fn pick_extent(caps: &vk::SurfaceCapabilitiesKHR, size: [u32; 2]) -> vk::Extent2D {
+    if caps.current_extent.width != u32::MAX && caps.current_extent.height != u32::MAX {
+        return caps.current_extent;
+    }
+    vk::Extent2D {
+        width: size[0].clamp(caps.min_image_extent.width, caps.max_image_extent.width),
+        height: size[1].clamp(caps.min_image_extent.height, caps.max_image_extent.height),
+    }
+}
+
+fn create_frame_sync(device: &Device, command_pool: vk::CommandPool) -> RendererResult<FrameSync> {
+    let semaphore_info = vk::SemaphoreCreateInfo::default();
+    let fence_info = vk::FenceCreateInfo::default().flags(vk::FenceCreateFlags::SIGNALED);
+    unsafe {
+        let command_buffer = device.allocate_command_buffers(
+            &vk::CommandBufferAllocateInfo::default()
+                .command_pool(command_pool)
+                .level(vk::CommandBufferLevel::PRIMARY)
+                .command_buffer_count(1),
+        )?[0];
+        let image_available = device.create_semaphore(&semaphore_info, None)?;
+        let render_finished = match device.create_semaphore(&semaphore_info, None) {
+            Ok(semaphore) => semaphore,
+            Err(err) => {
+                device.destroy_semaphore(image_available, None);
+                return Err(err.into());
+            }
+        };
+        let fence = match device.create_fence(&fence_info, None) {
+            Ok(fence) => fence,
+            Err(err) => {
+                device.destroy_semaphore(image_available, None);
+                device.destroy_semaphore(render_finished, None);
+                return Err(err.into());
+            }
+        };
+        Ok(FrameSync {
+            image_available,
+            render_finished,
+            fence,
+            command_buffer,
+        })
+    }
+}
+
+#[cfg(not(feature = "dynamic-rendering"))]
+fn create_render_pass(device: &Device, format: vk::Format) -> RendererResult<vk::RenderPass> {
+    let attachments = [vk::AttachmentDescription::default()
+        .format(format)
+        .samples(vk::SampleCountFlags::TYPE_1)
+        .load_op(vk::AttachmentLoadOp::CLEAR)
+        .store_op(vk::AttachmentStoreOp::STORE)
+        .stencil_load_op(vk::AttachmentLoadOp::DONT_CARE)
+        .stencil_store_op(vk::AttachmentStoreOp::DONT_CARE)
+        .initial_layout(vk::ImageLayout::UNDEFINED)
+        .final_layout(vk::ImageLayout::PRESENT_SRC_KHR)];
+    let color_attachment_refs = [vk::AttachmentReference::default()
+        .attachment(0)
+        .layout(vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL)];
+    let subpass = [vk::SubpassDescription::default()
+        .pipeline_bind_point(vk::PipelineBindPoint::GRAPHICS)
+        .color_attachments(&color_attachment_refs)];
+    let dependencies = [vk::SubpassDependency::default()
+        .src_subpass(vk::SUBPASS_EXTERNAL)
+        .dst_subpass(0)
+        .src_stage_mask(vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT)
+        .dst_stage_mask(vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT)
+        .dst_access_mask(
+            vk::AccessFlags::COLOR_ATTACHMENT_READ | vk::AccessFlags::COLOR_ATTACHMENT_WRITE,
+        )];
+    let info = vk::RenderPassCreateInfo::default()
+        .attachments(&attachments)
+        .subpasses(&subpass)
+        .dependencies(&dependencies);
+    unsafe { Ok(device.create_render_pass(&info, None)?) }
+}
+
+#[cfg(feature = "dynamic-rendering")]
+fn transition_image(
+    device: &Device,
+    cmd: vk::CommandBuffer,
+    image: vk::Image,
+    old_layout: vk::ImageLayout,
+    new_layout: vk::ImageLayout,
+) {
+    let (src_access, dst_access, src_stage, dst_stage) =
+        if new_layout == vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL {
+            (
+                vk::AccessFlags::empty(),
+                vk::AccessFlags::COLOR_ATTACHMENT_WRITE,
+                vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT,
+                vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT,
+            )
+        } else {
+            (
+                vk::AccessFlags::COLOR_ATTACHMENT_WRITE,
+                vk::AccessFlags::empty(),
+                vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT,
+                vk::PipelineStageFlags::BOTTOM_OF_PIPE,
+            )
+        };
+    let barrier = vk::ImageMemoryBarrier::default()
+        .src_access_mask(src_access)
+        .dst_access_mask(dst_access)
+        .old_layout(old_layout)
+        .new_layout(new_layout)
+        .src_queue_family_index(vk::QUEUE_FAMILY_IGNORED)
+        .dst_queue_family_index(vk::QUEUE_FAMILY_IGNORED)
+        .image(image)
+        .subresource_range(vk::ImageSubresourceRange {
+            aspect_mask: vk::ImageAspectFlags::COLOR,
+            base_mip_level: 0,
+            level_count: 1,
+            base_array_layer: 0,
+            layer_count: 1,
+        });
+    unsafe {
+        device.cmd_pipeline_barrier(
+            cmd,
+            src_stage,
+            dst_stage,
+            vk::DependencyFlags::empty(),
+            &[],
+            &[],
+            std::slice::from_ref(&barrier),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn present_mode_prefers_mailbox_and_falls_back_to_fifo() {
+        let modes = [vk::PresentModeKHR::FIFO, vk::PresentModeKHR::MAILBOX];
+        assert_eq!(pick_present_mode(&modes, None), vk::PresentModeKHR::MAILBOX);
+        assert_eq!(
+            pick_present_mode(&modes[..1], None),
+            vk::PresentModeKHR::FIFO
+        );
+        assert_eq!(
+            pick_present_mode(&modes, Some(vk::PresentModeKHR::IMMEDIATE)),
+            vk::PresentModeKHR::FIFO
+        );
+        assert_eq!(
+            pick_present_mode(&modes, Some(vk::PresentModeKHR::FIFO)),
+            vk::PresentModeKHR::FIFO
+        );
+    }
+
+    #[test]
+    fn extent_follows_the_surface_unless_the_window_decides() {
+        let mut caps = vk::SurfaceCapabilitiesKHR {
+            current_extent: vk::Extent2D {
+                width: 800,
+                height: 600,
+            },
+            min_image_extent: vk::Extent2D {
+                width: 1,
+                height: 1,
+            },
+            max_image_extent: vk::Extent2D {
+                width: 4096,
+                height: 4096,
+            },
+            ..Default::default()
+        };
+        assert_eq!(pick_extent(&caps, [1024, 768]).width, 800);
+
+        caps.current_extent = vk::Extent2D {
+            width: u32::MAX,
+            height: u32::MAX,
+        };
+        let extent = pick_extent(&caps, [8000, 768]);
+        assert_eq!((extent.width, extent.height), (4096, 768));
+    }
+}
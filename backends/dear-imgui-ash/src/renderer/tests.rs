@@ -1,3 +1,4 @@
+use super::draw::Frames;
 use super::texture::{TextureWriteback, texture_data_to_rgba_subrect};
 use super::{TextureId, TextureStatus};
 use dear_imgui_rs::texture::{TextureData, TextureFormat as ImFormat};
@@ -68,3 +69,17 @@ fn texture_writeback_destroyed_sets_destroy_next_frame_and_status() {
         assert!((*tex.as_raw()).WantDestroyNextFrame);
     }
 }
+
+#[test]
+fn frames_by_index_wrap_and_leave_rotation_alone() {
+    let mut frames = Frames::new(3);
+    let ptr = |mesh: Option<&mut super::draw::Mesh>| mesh.map(|m| m as *const _).unwrap();
+    let second = ptr(frames.get(1));
+    assert_eq!(ptr(frames.get(4)), second);
+
+    let first = ptr(frames.get(0));
+    assert_eq!(ptr(frames.next()), first);
+    assert_eq!(ptr(frames.next()), second);
+
+    assert!(Frames::new(0).get(0).is_none());
+}
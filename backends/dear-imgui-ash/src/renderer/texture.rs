@@ -350,7 +350,7 @@ impl AshRenderer {
                 };
                 let replacing_existing = self.textures.textures.contains_key(&id);
                 if replacing_existing {
-                    self.wait_for_pending_uploads()?;
+                    self.wait_for_texture_release()?;
                 }
 
                 let (w, h) = (texture_data.width(), texture_data.height());
@@ -497,7 +497,7 @@ impl AshRenderer {
             TextureStatus::WantDestroy => {
                 let id = texture_data.tex_id().id();
                 if self.textures.textures.contains_key(&id) {
-                    self.wait_for_pending_uploads()?;
+                    self.wait_for_texture_release()?;
                 }
                 if let Some(tex) = self.textures.textures.remove(&id) {
                     tex.destroy(&self.device, &mut self.allocator, self.descriptor_pool);
@@ -522,7 +522,7 @@ impl AshRenderer {
         };
         let replacing_existing = self.textures.textures.contains_key(&id);
         if replacing_existing {
-            self.wait_for_pending_uploads()?;
+            self.wait_for_texture_release()?;
         }
 
         let (w, h) = (texture_data.width(), texture_data.height());
@@ -605,6 +605,15 @@ impl AshRenderer {
     }
 }
 
+impl dear_imgui_rs::TextureUpdate for AshRenderer {
+    type Error = RendererError;
+
+    fn update_texture_data(&mut self, texture_data: &mut TextureData) -> RendererResult<()> {
+        self.update_texture(texture_data)?.apply_to(texture_data);
+        Ok(())
+    }
+}
+
 impl AshRenderer {
     pub(super) fn create_default_texture(&mut self) -> RendererResult<u64> {
         // 1x1 white RGBA.
@@ -694,7 +703,7 @@ impl AshRenderer {
                 };
                 let replacing_existing = self.textures.textures.contains_key(&id);
                 if replacing_existing {
-                    self.wait_for_pending_uploads()?;
+                    self.wait_for_texture_release()?;
                 }
 
                 let (w, h) = (td.width(), td.height());
@@ -820,7 +829,7 @@ impl AshRenderer {
                 TextureStatus::WantDestroy => {
                     let id = internal_id;
                     if self.textures.textures.contains_key(&id) {
-                        if let Err(err) = self.wait_for_pending_uploads() {
+                        if let Err(err) = self.wait_for_texture_release() {
                             self.discard_pending_texture_work(creates, updates);
                             return Err(err);
                         }
@@ -153,4 +153,16 @@ impl AshRenderer {
         }
         self.reap_all_uploads()
     }
+
+    /// Wait until an existing texture can be freed or replaced.
+    ///
+    /// Besides pending uploads, command buffers of earlier frames (recorded by the application
+    /// and possibly still in flight) may sample it, so this also waits for the queue to go idle,
+    /// like the official Vulkan backend does when destroying textures. Dear ImGui only asks for
+    /// this on atlas rebuilds/resizes and texture destruction, not every frame.
+    pub(super) fn wait_for_texture_release(&mut self) -> RendererResult<()> {
+        self.wait_for_pending_uploads()?;
+        unsafe { self.device.queue_wait_idle(self.queue)? };
+        Ok(())
+    }
 }
@@ -120,7 +120,7 @@ pub fn create_vulkan_pipeline(
     device: &Device,
     pipeline_layout: vk::PipelineLayout,
     #[cfg(not(feature = "dynamic-rendering"))] render_pass: vk::RenderPass,
-    #[cfg(feature = "dynamic-rendering")] dynamic_rendering: super::DynamicRendering,
+    #[cfg(feature = "dynamic-rendering")] dynamic_rendering: crate::DynamicRendering,
     options: Options,
 ) -> RendererResult<vk::Pipeline> {
     let entry_point_name = CString::new("main").unwrap();
@@ -253,7 +253,7 @@ pub fn create_vulkan_pipeline(
 
     let pipeline = match unsafe {
         device.create_graphics_pipelines(
-            vk::PipelineCache::null(),
+            options.pipeline_cache,
             std::slice::from_ref(&pipeline_info),
             None,
         )
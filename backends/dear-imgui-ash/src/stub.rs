@@ -0,0 +1,330 @@
+//! API-only build of the renderer (`wasm32` targets or without the `vulkan` feature).
+//!
+//! Every type and method of the Vulkan renderer is present with the same signature, so crates
+//! supporting several backends can type-check and build docs against this one anywhere. A renderer
+//! can never be created: all constructors return [`RendererError::UnsupportedTarget`].
+
+#[cfg(feature = "dynamic-rendering")]
+use crate::DynamicRendering;
+use crate::{Options, RendererError, RendererResult, TextureUpdateResult};
+#[cfg(not(any(feature = "gpu-allocator", feature = "vk-mem")))]
+use ash::Instance;
+use ash::{Device, vk};
+use dear_imgui_rs::{Context, TextureData, TextureId};
+use std::convert::Infallible;
+
+/// Vulkan renderer for Dear ImGui using `ash`.
+///
+/// This build has no Vulkan implementation (see "Target support" in the crate docs).
+pub struct AshRenderer {
+    never: Infallible,
+}
+
+#[allow(clippy::too_many_arguments, unused_variables)]
+impl AshRenderer {
+    pub fn configure_imgui_context(&self, imgui_context: &mut Context) {
+        match self.never {}
+    }
+
+    /// Create a new renderer using the internal default allocator.
+    ///
+    /// Always returns [`RendererError::UnsupportedTarget`] in this build.
+    #[cfg(not(any(feature = "gpu-allocator", feature = "vk-mem")))]
+    pub fn with_default_allocator(
+        instance: &Instance,
+        physical_device: vk::PhysicalDevice,
+        device: Device,
+        queue: vk::Queue,
+        command_pool: vk::CommandPool,
+        #[cfg(not(feature = "dynamic-rendering"))] render_pass: vk::RenderPass,
+        #[cfg(feature = "dynamic-rendering")] dynamic_rendering: DynamicRendering,
+        imgui: &mut Context,
+        options: Option<Options>,
+    ) -> RendererResult<Self> {
+        Err(RendererError::UnsupportedTarget)
+    }
+
+    /// Create a new renderer using a shared `gpu-allocator` allocator.
+    ///
+    /// Always returns [`RendererError::UnsupportedTarget`] in this build.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "gpu-allocator"))]
+    pub fn with_gpu_allocator(
+        allocator: std::sync::Arc<std::sync::Mutex<gpu_allocator::vulkan::Allocator>>,
+        device: Device,
+        queue: vk::Queue,
+        command_pool: vk::CommandPool,
+        #[cfg(not(feature = "dynamic-rendering"))] render_pass: vk::RenderPass,
+        #[cfg(feature = "dynamic-rendering")] dynamic_rendering: DynamicRendering,
+        imgui: &mut Context,
+        options: Option<Options>,
+    ) -> RendererResult<Self> {
+        Err(RendererError::UnsupportedTarget)
+    }
+
+    /// Create a new renderer using a shared `vk-mem` allocator.
+    ///
+    /// Always returns [`RendererError::UnsupportedTarget`] in this build.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "vk-mem"))]
+    pub fn with_vk_mem_allocator(
+        allocator: std::sync::Arc<std::sync::Mutex<vk_mem::Allocator>>,
+        device: Device,
+        queue: vk::Queue,
+        command_pool: vk::CommandPool,
+        #[cfg(not(feature = "dynamic-rendering"))] render_pass: vk::RenderPass,
+        #[cfg(feature = "dynamic-rendering")] dynamic_rendering: DynamicRendering,
+        imgui: &mut Context,
+        options: Option<Options>,
+    ) -> RendererResult<Self> {
+        Err(RendererError::UnsupportedTarget)
+    }
+
+    pub fn options(&self) -> Options {
+        match self.never {}
+    }
+
+    /// Set clear color for secondary viewports (multi-viewport mode).
+    #[cfg(any(feature = "multi-viewport-winit", feature = "multi-viewport-sdl3"))]
+    pub fn set_viewport_clear_color(&mut self, color: [f32; 4]) {
+        match self.never {}
+    }
+
+    /// Get clear color for secondary viewports (multi-viewport mode).
+    #[cfg(any(feature = "multi-viewport-winit", feature = "multi-viewport-sdl3"))]
+    pub fn viewport_clear_color(&self) -> [f32; 4] {
+        match self.never {}
+    }
+
+    pub fn cmd_draw(
+        &mut self,
+        command_buffer: vk::CommandBuffer,
+        draw_data: &mut dear_imgui_rs::render::DrawData,
+    ) -> RendererResult<()> {
+        match self.never {}
+    }
+
+    pub fn render(
+        &mut self,
+        command_buffer: vk::CommandBuffer,
+        draw_data: &mut dear_imgui_rs::render::DrawData,
+        frame_index: usize,
+    ) -> RendererResult<()> {
+        match self.never {}
+    }
+
+    pub fn register_texture_descriptor_set(&mut self, set: vk::DescriptorSet) -> TextureId {
+        match self.never {}
+    }
+
+    /// Remove a previously registered external texture descriptor set.
+    pub fn remove_texture_descriptor_set(&mut self, id: TextureId) {
+        match self.never {}
+    }
+
+    /// Register an external `vk::ImageView` + `vk::Sampler` as a legacy `TextureId`.
+    pub fn register_external_texture_with_sampler(
+        &mut self,
+        image_view: vk::ImageView,
+        sampler: vk::Sampler,
+    ) -> RendererResult<TextureId> {
+        match self.never {}
+    }
+
+    /// Update the view for an already-registered external texture.
+    pub fn update_external_texture_view(
+        &mut self,
+        texture_id: TextureId,
+        image_view: vk::ImageView,
+    ) -> bool {
+        match self.never {}
+    }
+
+    /// Update (or set) a custom sampler for an already-registered external texture.
+    pub fn update_external_texture_sampler(
+        &mut self,
+        texture_id: TextureId,
+        sampler: vk::Sampler,
+    ) -> bool {
+        match self.never {}
+    }
+
+    /// Unregister a texture id.
+    pub fn unregister_texture(&mut self, texture_id: TextureId) {
+        match self.never {}
+    }
+
+    /// Update a single texture manually.
+    pub fn update_texture(
+        &mut self,
+        texture_data: &TextureData,
+    ) -> RendererResult<TextureUpdateResult> {
+        match self.never {}
+    }
+}
+
+#[allow(unused_variables)]
+impl dear_imgui_rs::TextureUpdate for AshRenderer {
+    type Error = RendererError;
+
+    fn update_texture_data(&mut self, texture_data: &mut TextureData) -> RendererResult<()> {
+        match self.never {}
+    }
+}
+
+/// Window surface and swapchain helper. [`WindowSwapchain::new`](swapchain::WindowSwapchain::new)
+/// always returns [`RendererError::UnsupportedTarget`] in this build.
+#[cfg(feature = "swapchain")]
+pub mod swapchain {
+    #[cfg(feature = "dynamic-rendering")]
+    use crate::DynamicRendering;
+    use crate::{RendererError, RendererResult};
+    use ash::{Device, Entry, Instance, vk};
+    use raw_window_handle::{RawDisplayHandle, RawWindowHandle};
+    use std::convert::Infallible;
+    use std::ffi::c_char;
+
+    /// Settings of a [`WindowSwapchain`].
+    #[derive(Debug, Clone, Copy)]
+    pub struct SwapchainConfig {
+        /// Frames recorded ahead of the GPU. Use the same value for `Options::in_flight_frames`.
+        pub frames_in_flight: usize,
+        /// Preferred present mode. `None` picks MAILBOX when available and FIFO otherwise.
+        pub present_mode: Option<vk::PresentModeKHR>,
+        /// Color the swapchain image is cleared to before the UI is drawn.
+        pub clear_color: [f32; 4],
+    }
+
+    impl Default for SwapchainConfig {
+        fn default() -> Self {
+            Self {
+                frames_in_flight: 2,
+                present_mode: None,
+                clear_color: [0.0, 0.0, 0.0, 1.0],
+            }
+        }
+    }
+
+    /// A window surface with its swapchain, ready to draw ImGui frames into.
+    ///
+    /// This build has no Vulkan implementation (see "Target support" in the crate docs).
+    pub struct WindowSwapchain {
+        never: Infallible,
+    }
+
+    #[allow(clippy::too_many_arguments, unused_variables)]
+    impl WindowSwapchain {
+        /// Always returns [`RendererError::UnsupportedTarget`] in this build.
+        pub fn required_instance_extensions(
+            display_handle: RawDisplayHandle,
+        ) -> RendererResult<&'static [*const c_char]> {
+            Err(RendererError::UnsupportedTarget)
+        }
+
+        /// Always returns [`RendererError::UnsupportedTarget`] in this build.
+        ///
+        /// # Safety
+        ///
+        /// Same contract as the Vulkan build.
+        pub unsafe fn new(
+            entry: &Entry,
+            instance: &Instance,
+            physical_device: vk::PhysicalDevice,
+            device: Device,
+            queue: vk::Queue,
+            queue_family_index: u32,
+            display_handle: RawDisplayHandle,
+            window_handle: RawWindowHandle,
+            size: [u32; 2],
+            config: SwapchainConfig,
+        ) -> RendererResult<Self> {
+            Err(RendererError::UnsupportedTarget)
+        }
+
+        #[cfg(not(feature = "dynamic-rendering"))]
+        pub fn render_pass(&self) -> vk::RenderPass {
+            match self.never {}
+        }
+
+        #[cfg(feature = "dynamic-rendering")]
+        pub fn dynamic_rendering(&self) -> DynamicRendering {
+            match self.never {}
+        }
+
+        pub fn command_pool(&self) -> vk::CommandPool {
+            match self.never {}
+        }
+
+        pub fn frames_in_flight(&self) -> usize {
+            match self.never {}
+        }
+
+        pub fn format(&self) -> vk::Format {
+            match self.never {}
+        }
+
+        pub fn is_srgb(&self) -> bool {
+            match self.never {}
+        }
+
+        pub fn extent(&self) -> vk::Extent2D {
+            match self.never {}
+        }
+
+        pub fn set_clear_color(&mut self, color: [f32; 4]) {
+            match self.never {}
+        }
+
+        pub fn resize(&mut self, size: [u32; 2]) {
+            match self.never {}
+        }
+
+        pub fn draw_frame(
+            &mut self,
+            draw: impl FnOnce(vk::CommandBuffer, usize) -> RendererResult<()>,
+        ) -> RendererResult<bool> {
+            match self.never {}
+        }
+    }
+}
+
+/// Multi-viewport helpers (winit). No-ops in this build, since no renderer can exist.
+#[cfg(feature = "multi-viewport-winit")]
+pub mod multi_viewport {
+    pub use super::viewport_stub::{disable, enable, shutdown_multi_viewport_support};
+}
+
+/// Multi-viewport helpers (SDL3). No-ops in this build, since no renderer can exist.
+#[cfg(feature = "multi-viewport-sdl3")]
+pub mod multi_viewport_sdl3 {
+    pub use super::viewport_stub::{disable, enable, shutdown_multi_viewport_support};
+}
+
+#[cfg(any(feature = "multi-viewport-winit", feature = "multi-viewport-sdl3"))]
+mod viewport_stub {
+    use super::AshRenderer;
+    use ash::vk;
+    use dear_imgui_rs::Context;
+
+    /// Enable Vulkan multi-viewport: installs renderer callbacks.
+    #[allow(clippy::too_many_arguments, unused_variables)]
+    pub fn enable(
+        renderer: &mut AshRenderer,
+        imgui_context: &mut Context,
+        entry: ash::Entry,
+        instance: ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        present_queue: vk::Queue,
+        graphics_queue_family_index: u32,
+        present_queue_family_index: u32,
+    ) {
+        match renderer.never {}
+    }
+
+    /// Disable multi-viewport callbacks and clear stored globals.
+    pub fn disable(_imgui_context: &mut Context) {}
+
+    /// Convenience helper that destroys all platform windows and disables callbacks.
+    pub fn shutdown_multi_viewport_support(context: &mut Context) {
+        context.destroy_platform_windows();
+        disable(context);
+    }
+}
@@ -63,6 +63,11 @@ pub enum InitError {
     #[error("TextureId is out of range for OpenGL: {0}")]
     TextureIdOutOfRange(u64),
 
+    /// TextureId is not registered with the renderer and cannot be looked up as a GL name
+    /// (WebGL).
+    #[error("TextureId {0} is not registered with the renderer")]
+    UnregisteredTextureId(u64),
+
     /// TextureId zero/null is not valid for this operation.
     #[error("TextureId must be non-zero for OpenGL")]
     NullTextureId,
@@ -57,7 +57,9 @@ pub use renderer::multi_viewport;
 pub type GlBuffer = <Context as HasContext>::Buffer;
 pub type GlTexture = <Context as HasContext>::Texture;
 pub type GlVertexArray = <Context as HasContext>::VertexArray;
+pub type GlFramebuffer = <Context as HasContext>::Framebuffer;
 pub type GlProgram = <Context as HasContext>::Program;
+pub type GlSampler = <Context as HasContext>::Sampler;
 pub type GlUniformLocation = <Context as HasContext>::UniformLocation;
 
 /// Convert a DrawVert slice to a byte slice
@@ -5,9 +5,10 @@ mod core;
 mod device;
 mod draw;
 mod init;
+mod render_target;
 mod texture;
 
 #[cfg(feature = "multi-viewport")]
 pub mod multi_viewport;
 
-pub use core::GlowRenderer;
+pub use core::{GlowRenderer, OutputSrgb, RendererOptions, StateBackup};
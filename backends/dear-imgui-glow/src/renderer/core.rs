@@ -1,10 +1,71 @@
 use dear_imgui_rs::sys;
 
+use super::render_target::RenderTarget;
 use crate::{
     GlBuffer, GlTexture, GlVertexArray, shaders::Shaders, state::GlStateBackup,
     texture::TextureMap, versions::GlVersion,
 };
 
+/// How the renderer writes colors to the framebuffer.
+///
+/// Dear ImGui colors are sRGB-encoded. Writing them unchanged to a linear framebuffer is what
+/// the official OpenGL3 backend does; with an sRGB framebuffer the colors have to be decoded
+/// first so the hardware encoding does not brighten them, which is what the WGPU backend's
+/// `GammaMode::Auto` does for sRGB surfaces.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum OutputSrgb {
+    /// Leave `GL_FRAMEBUFFER_SRGB` untouched and write colors as-is.
+    #[default]
+    Off,
+    /// Enable `GL_FRAMEBUFFER_SRGB` while drawing and decode vertex colors (gamma 2.2).
+    Enabled,
+    /// Query the color encoding of the bound draw framebuffer each frame and behave like
+    /// `Enabled` when it is sRGB. Falls back to `Off` where the query is unavailable
+    /// (OpenGL ES 2.0 / WebGL1).
+    Auto,
+}
+
+/// What the renderer does with the host's OpenGL state around [`GlowRenderer::render`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum StateBackup {
+    /// Query the state the renderer touches before drawing and restore it afterwards, like the
+    /// official OpenGL3 backend. Safe to interleave with any host rendering.
+    #[default]
+    Full,
+    /// Skip the state queries (which can stall some drivers) and only undo what commonly breaks
+    /// host rendering: scissor testing is disabled and the renderer's program, buffers, vertex
+    /// array and texture are unbound. Blend, depth, stencil, cull face and viewport are left as
+    /// Dear ImGui rendering set them, so the host has to set those itself.
+    Minimal,
+}
+
+/// Renderer configuration (see [`GlowRenderer::set_options`]).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RendererOptions {
+    /// GL state save/restore policy around rendering.
+    pub state_backup: StateBackup,
+}
+
+/// How the renderer reaches its OpenGL context.
+///
+/// The two modes never mix: an owned renderer uses its own context everywhere, while an
+/// external one only touches GL through the context passed to its `*_with_context` methods.
+pub(super) enum GlContextMode {
+    /// Created by `new`/`with_texture_map`; device objects are freed on drop.
+    Owned(std::rc::Rc<glow::Context>),
+    /// Created by `with_external_context`; the caller frees device objects with `destroy`.
+    External,
+}
+
+impl GlContextMode {
+    pub(super) fn owned(&self) -> Option<&std::rc::Rc<glow::Context>> {
+        match self {
+            Self::Owned(gl) => Some(gl),
+            Self::External => None,
+        }
+    }
+}
+
 /// Main renderer for Dear ImGui using Glow (OpenGL)
 ///
 /// This renderer provides a unified API similar to the WGPU backend while maintaining
@@ -25,10 +86,16 @@ pub struct GlowRenderer {
     pub is_destroyed: bool,
 
     // Resource management
-    pub(super) gl_context: Option<std::rc::Rc<glow::Context>>, // None = externally managed
+    pub(super) gl_context: GlContextMode,
     pub(super) texture_map: Option<Box<dyn TextureMap>>,
-    // Optional: enable GL_FRAMEBUFFER_SRGB during ImGui rendering
-    pub(super) framebuffer_srgb: bool,
+    // Offscreen target of `render_to_texture`, created on first use
+    pub(super) render_target: Option<RenderTarget>,
+    pub(super) options: RendererOptions,
+    pub(super) output_srgb: OutputSrgb,
+    // Whether the current frame writes through GL_FRAMEBUFFER_SRGB (resolved from `output_srgb`)
+    pub(super) frame_srgb: bool,
+    // Upload ImGui-managed textures as SRGB8_ALPHA8 so sampling decodes them
+    pub(super) srgb_textures: bool,
     // Optional: override color gamma applied to vertex colors (None = auto)
     pub(super) color_gamma_override: Option<f32>,
     // Clear color used for secondary viewports (multi-viewport). Main framebuffer
@@ -1,6 +1,6 @@
 use glow::{Context, HasContext};
 
-use super::GlowRenderer;
+use super::{GlowRenderer, OutputSrgb, RendererOptions, core::GlContextMode};
 use crate::{
     error::{RenderError, RenderResult},
     shaders::Shaders,
@@ -37,6 +37,7 @@ impl GlowRenderer {
             unsafe { gl.delete_texture(h) };
             self.font_atlas_texture = None;
         }
+        self.delete_render_target(gl);
 
         #[cfg(feature = "bind_vertex_array_support")]
         if let Some(vao) = self.vertex_array_object {
@@ -55,8 +56,10 @@ impl GlowRenderer {
     }
 
     /// Get a reference to the OpenGL context (if owned by the renderer)
+    ///
+    /// Returns `None` for renderers created with `with_external_context`.
     pub fn gl_context(&self) -> Option<&std::rc::Rc<glow::Context>> {
-        self.gl_context.as_ref()
+        self.gl_context.owned()
     }
 
     /// Get a reference to the texture map
@@ -74,29 +77,76 @@ impl GlowRenderer {
     }
 
     /// Called every frame to prepare for rendering
+    ///
+    /// Requires an owned context; renderers created with `with_external_context` use
+    /// [`new_frame_with_context`](Self::new_frame_with_context).
     pub fn new_frame(&mut self) -> RenderResult<()> {
-        // Check if we need to recreate device objects
-        let needs_recreation = self.is_destroyed || self.shaders.program.is_none();
+        let gl = self
+            .gl_context
+            .owned()
+            .cloned()
+            .ok_or(RenderError::MissingGlContext)?;
+        self.new_frame_with_context(&gl)
+    }
 
-        if needs_recreation {
-            if let Some(gl) = self.gl_context.clone() {
-                self.create_device_objects(&gl)?;
-            } else {
-                return Err(RenderError::MissingGlContext);
-            }
+    /// Called every frame to prepare for rendering, using an externally managed OpenGL context.
+    pub fn new_frame_with_context(&mut self, gl: &Context) -> RenderResult<()> {
+        // Check if we need to recreate device objects
+        if self.is_destroyed || self.shaders.program.is_none() {
+            self.create_device_objects(gl)?;
         }
         Ok(())
     }
 
+    /// Replace the renderer configuration (see [`RendererOptions`]).
+    ///
+    /// Takes effect with the next rendered frame.
+    pub fn set_options(&mut self, options: RendererOptions) {
+        self.options = options;
+    }
+
+    /// The current renderer configuration.
+    pub fn options(&self) -> RendererOptions {
+        self.options
+    }
+
     /// Enable/disable GL_FRAMEBUFFER_SRGB around ImGui rendering
     /// Default is disabled; prefer application-level control of sRGB.
+    ///
+    /// Shorthand for `set_output_srgb(OutputSrgb::Enabled)` / `set_output_srgb(OutputSrgb::Off)`.
     pub fn set_framebuffer_srgb_enabled(&mut self, enabled: bool) {
-        self.framebuffer_srgb = enabled;
+        self.set_output_srgb(if enabled {
+            OutputSrgb::Enabled
+        } else {
+            OutputSrgb::Off
+        });
+    }
+
+    /// Choose how colors are written to the framebuffer (see [`OutputSrgb`]).
+    ///
+    /// Use `OutputSrgb::Auto` to match the WGPU backend on sRGB default framebuffers.
+    pub fn set_output_srgb(&mut self, mode: OutputSrgb) {
+        self.output_srgb = mode;
+    }
+
+    /// The configured output mode.
+    pub fn output_srgb(&self) -> OutputSrgb {
+        self.output_srgb
+    }
+
+    /// Upload textures created by Dear ImGui (font atlas, `ImTextureData` requests) as
+    /// `SRGB8_ALPHA8`, so sampling decodes them to linear before blending into an sRGB
+    /// framebuffer.
+    ///
+    /// Only affects textures created afterwards; set it before the first frame. Ignored on
+    /// OpenGL ES 2.0 / WebGL1, which have no sRGB texture format. Default is disabled.
+    pub fn set_srgb_textures(&mut self, enabled: bool) {
+        self.srgb_textures = enabled;
     }
 
     /// Override the color gamma applied to ImGui vertex colors.
     /// Pass `Some(gamma)` to force a value (e.g., 2.2 or 1.0), or `None` to use auto:
-    /// auto = 2.2 when sRGB is enabled, otherwise 1.0.
+    /// auto = 2.2 when sRGB output is active for the frame, otherwise 1.0.
     pub fn set_color_gamma_override(&mut self, gamma: Option<f32>) {
         self.color_gamma_override = gamma;
     }
@@ -155,6 +205,7 @@ impl GlowRenderer {
         if let Some(texture) = self.font_atlas_texture.take() {
             unsafe { gl.delete_texture(texture) };
         }
+        self.delete_render_target(gl);
         self.is_destroyed = true;
     }
 }
@@ -165,7 +216,10 @@ impl Drop for GlowRenderer {
         {
             self.clear_multi_viewport_renderer_state();
         }
-        if let Some(gl) = self.gl_context.take() {
+        // External contexts are not ours to use here; their owner calls `destroy(gl)`.
+        if let GlContextMode::Owned(gl) =
+            std::mem::replace(&mut self.gl_context, GlContextMode::External)
+        {
             self.destroy_device_objects(&gl);
         }
     }
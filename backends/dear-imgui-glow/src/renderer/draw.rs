@@ -5,23 +5,30 @@ use dear_imgui_rs::{
 use glow::{Context, HasContext};
 use std::mem::size_of;
 
-use super::GlowRenderer;
+use super::{GlowRenderer, OutputSrgb, StateBackup};
 use crate::{
     draw_indices_as_bytes, draw_verts_as_bytes,
     error::{RenderError, RenderResult},
     gl_debug_message,
+    state::reset_minimal_state,
     texture::TextureMap,
+    versions::GlVersion,
 };
 
 impl GlowRenderer {
     /// Render Dear ImGui draw data
+    ///
+    /// Requires an owned context; renderers created with `with_external_context` use
+    /// [`render_with_context`](Self::render_with_context).
     pub fn render(&mut self, draw_data: &mut DrawData) -> RenderResult<()> {
         let gl = self
             .gl_context
-            .clone()
+            .owned()
+            .cloned()
             .ok_or(RenderError::MissingGlContext)?;
 
         // Handle texture updates first, following the original Dear ImGui OpenGL3 implementation
+        self.sync_font_atlas_texture_data();
         let mut textures = draw_data.textures_mut();
         while let Some(mut texture_data) = textures.next() {
             if texture_data.status() != dear_imgui_rs::TextureStatus::OK {
@@ -40,6 +47,7 @@ impl GlowRenderer {
         draw_data: &mut DrawData,
     ) -> RenderResult<()> {
         // Handle texture updates first
+        self.sync_font_atlas_texture_data();
         let mut textures = draw_data.textures_mut();
         while let Some(mut texture_data) = textures.next() {
             if texture_data.status() != dear_imgui_rs::TextureStatus::OK {
@@ -65,7 +73,14 @@ impl GlowRenderer {
 
         gl_debug_message(gl, "dear-imgui-glow: start render");
 
-        self.state_backup.backup(gl, self.gl_version);
+        let full_backup = self.options.state_backup == StateBackup::Full;
+        if full_backup {
+            self.state_backup.backup(gl, self.gl_version);
+            if !self.uses_vertex_array_object() {
+                self.state_backup
+                    .backup_vertex_attribs(gl, self.vertex_attrib_locations());
+            }
+        }
 
         #[cfg(feature = "bind_vertex_array_support")]
         if self.gl_version.bind_vertex_array_support() {
@@ -82,6 +97,7 @@ impl GlowRenderer {
             }
         }
 
+        self.frame_srgb = resolve_output_srgb(gl, self.output_srgb, self.gl_version);
         self.set_up_render_state(gl, draw_data, fb_width, fb_height)?;
 
         // Render draw lists. We temporarily move `texture_map` out to avoid creating
@@ -104,15 +120,41 @@ impl GlowRenderer {
         }
 
         // Optionally disable FRAMEBUFFER_SRGB before restoring state (we didn't back it up)
-        if self.framebuffer_srgb {
+        if self.frame_srgb && !self.gl_version.is_es {
             unsafe { gl.disable(glow::FRAMEBUFFER_SRGB) };
         }
-        self.state_backup.restore(gl, self.gl_version);
+        if full_backup {
+            if !self.uses_vertex_array_object() {
+                self.state_backup
+                    .restore_vertex_attribs(gl, self.vertex_attrib_locations());
+            }
+            self.state_backup.restore(gl, self.gl_version);
+        } else {
+            let vertex_attribs =
+                (!self.uses_vertex_array_object()).then(|| self.vertex_attrib_locations());
+            reset_minimal_state(gl, self.gl_version, vertex_attribs);
+        }
         gl_debug_message(gl, "dear-imgui-glow: end render");
 
         Ok(())
     }
 
+    /// Whether draws go through a per-frame vertex array object
+    ///
+    /// OpenGL ES 2.0 / WebGL1 and GL < 3.0 (or builds without `bind_vertex_array_support`)
+    /// set the vertex attributes on the global vertex state instead.
+    fn uses_vertex_array_object(&self) -> bool {
+        cfg!(feature = "bind_vertex_array_support") && self.gl_version.bind_vertex_array_support()
+    }
+
+    fn vertex_attrib_locations(&self) -> [u32; 3] {
+        [
+            self.shaders.attrib_location_vtx_pos,
+            self.shaders.attrib_location_vtx_uv,
+            self.shaders.attrib_location_vtx_color,
+        ]
+    }
+
     /// Set up OpenGL render state for ImGui rendering
     fn set_up_render_state(
         &mut self,
@@ -139,18 +181,14 @@ impl GlowRenderer {
             gl.enable(glow::SCISSOR_TEST);
 
             // Optionally enable sRGB frame-buffer writes for sRGB-capable surfaces.
-            // Note: This is typically controlled by the application. We expose a toggle
-            // for convenience; it will be disabled after rendering to avoid leaking state.
-            if self.framebuffer_srgb {
+            // Note: This is typically controlled by the application (see `OutputSrgb`); by
+            // default we leave it alone like the official OpenGL3 backend. When enabled it is
+            // disabled again after rendering to avoid leaking state. OpenGL ES has no toggle:
+            // writes to sRGB attachments are always encoded there.
+            if self.frame_srgb && !self.gl_version.is_es {
                 gl.enable(glow::FRAMEBUFFER_SRGB);
             }
 
-            // Note: We don't enable GL_FRAMEBUFFER_SRGB here because:
-            // 1. Modern applications typically create sRGB surfaces directly (e.g., glutin's .with_srgb(true))
-            // 2. The official OpenGL3 backend also doesn't explicitly enable GL_FRAMEBUFFER_SRGB
-            // 3. Enabling it when the surface is already sRGB would cause incorrect double conversion
-            // The sRGB conversion is handled by the surface/framebuffer configuration
-
             #[cfg(feature = "polygon_mode_support")]
             if self.gl_version.polygon_mode_support() {
                 gl.polygon_mode(glow::FRONT_AND_BACK, glow::FILL);
@@ -201,13 +239,11 @@ impl GlowRenderer {
             if let Some(location) = self.shaders.attrib_location_color_gamma {
                 // Decode vertex color from sRGB when writing to sRGB framebuffer,
                 // otherwise pass-through (1.0). Allow override if set.
-                let gamma = self
-                    .color_gamma_override
-                    .unwrap_or(if self.framebuffer_srgb {
-                        2.2_f32
-                    } else {
-                        1.0_f32
-                    });
+                let gamma = self.color_gamma_override.unwrap_or(if self.frame_srgb {
+                    2.2_f32
+                } else {
+                    1.0_f32
+                });
                 gl.uniform_1_f32(Some(&location), gamma);
             }
 
@@ -463,3 +499,29 @@ impl GlowRenderer {
         Ok(())
     }
 }
+
+/// Decide whether this frame should write sRGB-encoded output.
+fn resolve_output_srgb(gl: &Context, mode: OutputSrgb, gl_version: GlVersion) -> bool {
+    match mode {
+        OutputSrgb::Off => false,
+        OutputSrgb::Enabled => true,
+        OutputSrgb::Auto => {
+            gl_version.srgb_framebuffer_query_support()
+                && unsafe {
+                    let default_fbo = gl
+                        .get_parameter_framebuffer(glow::DRAW_FRAMEBUFFER_BINDING)
+                        .is_none();
+                    let attachment = match (default_fbo, gl_version.is_es) {
+                        (true, true) => glow::BACK,
+                        (true, false) => glow::BACK_LEFT,
+                        (false, _) => glow::COLOR_ATTACHMENT0,
+                    };
+                    gl.get_framebuffer_attachment_parameter_i32(
+                        glow::DRAW_FRAMEBUFFER,
+                        attachment,
+                        glow::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+                    ) == glow::SRGB as i32
+                }
+        }
+    }
+}
@@ -7,6 +7,7 @@ use super::{
         draw_callback_reset_render_state, draw_callback_set_sampler_linear,
         draw_callback_set_sampler_nearest,
     },
+    core::GlContextMode,
 };
 use crate::{
     GlTexture,
@@ -149,7 +150,7 @@ impl GlowRenderer {
 
         // Configure ImGui context BEFORE building font atlas
         // This sets RENDERER_HAS_TEXTURES flag which is required for ImGui 1.92+
-        Self::configure_imgui_context_static(imgui_context);
+        Self::configure_imgui_context_static(imgui_context, gl_version);
 
         let font_atlas_texture = Self::prepare_font_atlas(gl, imgui_context, &mut *texture_map)?;
         let font_atlas_texture_data = imgui_context.fonts().get_tex_data();
@@ -172,9 +173,16 @@ impl GlowRenderer {
             gl_version,
             has_clip_origin_support,
             is_destroyed: false,
-            gl_context: owned_gl,
+            gl_context: match owned_gl {
+                Some(gl) => GlContextMode::Owned(gl),
+                None => GlContextMode::External,
+            },
             texture_map: Some(texture_map),
-            framebuffer_srgb: false,
+            render_target: None,
+            options: crate::RendererOptions::default(),
+            output_srgb: crate::OutputSrgb::Off,
+            frame_srgb: false,
+            srgb_textures: false,
             color_gamma_override: None,
             viewport_clear_color: [0.0, 0.0, 0.0, 1.0],
         };
@@ -352,7 +360,7 @@ impl GlowRenderer {
     }
 
     /// Configure the ImGui context for this renderer (static version)
-    fn configure_imgui_context_static(imgui_context: &mut ImGuiContext) {
+    fn configure_imgui_context_static(imgui_context: &mut ImGuiContext, gl_version: GlVersion) {
         let should_set_name = imgui_context.io().backend_renderer_name().is_none();
         if should_set_name {
             let _ = imgui_context.set_renderer_name(Some(format!(
@@ -365,7 +373,12 @@ impl GlowRenderer {
 
         // Set backend capabilities
         let mut flags = io.backend_flags();
-        flags.insert(dear_imgui_rs::BackendFlags::RENDERER_HAS_VTX_OFFSET);
+        // Without glDrawElementsBaseVertex (OpenGL ES, WebGL, GL < 3.2) `vtx_offset` can't be
+        // honored, so let Dear ImGui split large meshes into 16-bit indexable chunks instead.
+        flags.set(
+            dear_imgui_rs::BackendFlags::RENDERER_HAS_VTX_OFFSET,
+            cfg!(feature = "vertex_offset_support") && gl_version.vertex_offset_support(),
+        );
         flags.insert(dear_imgui_rs::BackendFlags::RENDERER_HAS_TEXTURES);
 
         #[cfg(feature = "multi-viewport")]
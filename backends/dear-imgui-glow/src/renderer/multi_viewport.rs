@@ -241,9 +241,13 @@ mod tests {
             },
             has_clip_origin_support: false,
             is_destroyed: false,
-            gl_context: None,
+            gl_context: crate::renderer::core::GlContextMode::External,
             texture_map: Some(Box::new(SimpleTextureMap::default())),
-            framebuffer_srgb: false,
+            render_target: None,
+            options: crate::RendererOptions::default(),
+            output_srgb: crate::OutputSrgb::Off,
+            frame_srgb: false,
+            srgb_textures: false,
             color_gamma_override: None,
             viewport_clear_color: [0.0, 0.0, 0.0, 1.0],
         }
@@ -0,0 +1,211 @@
+//! Rendering Dear ImGui into a renderer-owned texture instead of the bound framebuffer
+
+use dear_imgui_rs::render::DrawData;
+use glow::{Context, HasContext};
+
+use super::GlowRenderer;
+use crate::{
+    GlFramebuffer, GlTexture,
+    error::{RenderError, RenderResult},
+};
+
+/// Offscreen color target used by [`GlowRenderer::render_to_texture`]
+pub(super) struct RenderTarget {
+    framebuffer: GlFramebuffer,
+    texture: GlTexture,
+    size: (u32, u32),
+}
+
+impl GlowRenderer {
+    /// Render Dear ImGui draw data into a texture of `width` x `height` pixels and return it
+    ///
+    /// The texture and its framebuffer are owned by the renderer: they are reused while the size
+    /// stays the same, recreated when it changes and freed with the renderer, so don't delete the
+    /// returned texture. It is cleared to transparent black before drawing. `width` and `height`
+    /// should match the draw data's framebuffer size (`display_size * framebuffer_scale`).
+    ///
+    /// The image is stored bottom-up like any OpenGL render target: sample it with flipped V
+    /// coordinates (`v = 1.0` at the top) to show it upright, e.g. on an in-world screen.
+    ///
+    /// Requires an owned context; renderers created with `with_external_context` use
+    /// [`render_to_texture_with_context`](Self::render_to_texture_with_context).
+    pub fn render_to_texture(
+        &mut self,
+        draw_data: &mut DrawData,
+        width: u32,
+        height: u32,
+    ) -> RenderResult<GlTexture> {
+        let gl = self
+            .gl_context
+            .owned()
+            .cloned()
+            .ok_or(RenderError::MissingGlContext)?;
+        self.render_to_texture_with_context(&gl, draw_data, width, height)
+    }
+
+    /// Render into a renderer-owned texture with an external OpenGL context
+    ///
+    /// See [`render_to_texture`](Self::render_to_texture). The previously bound framebuffer,
+    /// clear color and scissor test are restored afterwards.
+    pub fn render_to_texture_with_context(
+        &mut self,
+        gl: &Context,
+        draw_data: &mut DrawData,
+        width: u32,
+        height: u32,
+    ) -> RenderResult<GlTexture> {
+        if self.is_destroyed {
+            return Err(RenderError::RendererDestroyed);
+        }
+
+        let (framebuffer, texture) = self.ensure_render_target(gl, width, height)?;
+        unsafe {
+            let previous_framebuffer = gl.get_parameter_framebuffer(glow::FRAMEBUFFER_BINDING);
+            let mut clear_color = [0.0f32; 4];
+            gl.get_parameter_f32_slice(glow::COLOR_CLEAR_VALUE, &mut clear_color);
+            let scissor_test_enabled = gl.is_enabled(glow::SCISSOR_TEST);
+
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            gl.disable(glow::SCISSOR_TEST);
+            gl.clear_color(0.0, 0.0, 0.0, 0.0);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+            gl.clear_color(
+                clear_color[0],
+                clear_color[1],
+                clear_color[2],
+                clear_color[3],
+            );
+            if scissor_test_enabled {
+                gl.enable(glow::SCISSOR_TEST);
+            }
+
+            let result = self.render_with_context(gl, draw_data);
+            gl.bind_framebuffer(glow::FRAMEBUFFER, previous_framebuffer);
+            result?;
+        }
+
+        Ok(texture)
+    }
+
+    /// Create the render target, or recreate it if the requested size changed
+    fn ensure_render_target(
+        &mut self,
+        gl: &Context,
+        width: u32,
+        height: u32,
+    ) -> RenderResult<(GlFramebuffer, GlTexture)> {
+        if let Some(target) = &self.render_target
+            && target.size == (width, height)
+        {
+            return Ok((target.framebuffer, target.texture));
+        }
+        self.delete_render_target(gl);
+
+        let invalid_size = || RenderError::CreateResource {
+            resource: "render target",
+            error: format!("invalid size {width}x{height}"),
+        };
+        if width == 0 || height == 0 {
+            return Err(invalid_size());
+        }
+        let gl_width = i32::try_from(width).map_err(|_| invalid_size())?;
+        let gl_height = i32::try_from(height).map_err(|_| invalid_size())?;
+        // OpenGL ES 2.0 / WebGL1 only accept unsized internal formats.
+        let internal_format = if self.gl_version.is_es && self.gl_version.major < 3 {
+            glow::RGBA
+        } else {
+            glow::RGBA8
+        };
+
+        unsafe {
+            let previous_texture = gl.get_parameter_texture(glow::TEXTURE_BINDING_2D);
+            let previous_framebuffer = gl.get_parameter_framebuffer(glow::FRAMEBUFFER_BINDING);
+
+            let texture = gl
+                .create_texture()
+                .map_err(|error| RenderError::CreateResource {
+                    resource: "render target texture",
+                    error,
+                })?;
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::LINEAR as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::LINEAR as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_S,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_T,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                internal_format as i32,
+                gl_width,
+                gl_height,
+                0,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelUnpackData::Slice(None),
+            );
+            gl.bind_texture(glow::TEXTURE_2D, previous_texture);
+
+            let framebuffer = match gl.create_framebuffer() {
+                Ok(framebuffer) => framebuffer,
+                Err(error) => {
+                    gl.delete_texture(texture);
+                    return Err(RenderError::CreateResource {
+                        resource: "render target framebuffer",
+                        error,
+                    });
+                }
+            };
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            gl.framebuffer_texture_2d(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::TEXTURE_2D,
+                Some(texture),
+                0,
+            );
+            let status = gl.check_framebuffer_status(glow::FRAMEBUFFER);
+            gl.bind_framebuffer(glow::FRAMEBUFFER, previous_framebuffer);
+            if status != glow::FRAMEBUFFER_COMPLETE {
+                gl.delete_framebuffer(framebuffer);
+                gl.delete_texture(texture);
+                return Err(RenderError::CreateResource {
+                    resource: "render target framebuffer",
+                    error: format!("framebuffer incomplete (status 0x{status:04X})"),
+                });
+            }
+
+            self.render_target = Some(RenderTarget {
+                framebuffer,
+                texture,
+                size: (width, height),
+            });
+            Ok((framebuffer, texture))
+        }
+    }
+
+    /// Free the render target created by `render_to_texture`, if any
+    pub(super) fn delete_render_target(&mut self, gl: &Context) {
+        if let Some(target) = self.render_target.take() {
+            unsafe {
+                gl.delete_framebuffer(target.framebuffer);
+                gl.delete_texture(target.texture);
+            }
+        }
+    }
+}
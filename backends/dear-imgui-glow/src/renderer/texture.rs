@@ -1,4 +1,4 @@
-use dear_imgui_rs::{TextureData, TextureFormat, TextureId};
+use dear_imgui_rs::{TextureData, TextureFormat, TextureId, sys};
 use glow::{Context, HasContext};
 
 use super::GlowRenderer;
@@ -17,18 +17,45 @@ impl GlowRenderer {
             )
     }
 
-    fn convert_subrect_to_rgba(
-        texture_data: &dear_imgui_rs::TextureData,
-        rect: dear_imgui_rs::texture::TextureRect,
-    ) -> Option<Vec<u8>> {
-        let pixels = texture_data.pixels()?;
-        let tex_w = usize::try_from(texture_data.width()).ok()?;
-        let tex_h = usize::try_from(texture_data.height()).ok()?;
-        if tex_w == 0 || tex_h == 0 {
-            return None;
+    /// Follows the font atlas to its current `ImTextureData`.
+    ///
+    /// Dear ImGui replaces the atlas texture when the atlas grows or is rebuilt (dynamic glyph
+    /// loading, added fonts): the old texture is queued for destruction and a new one is created.
+    /// Tracking the replacement keeps `font_atlas_texture` pointing at a live GL texture.
+    pub(super) fn sync_font_atlas_texture_data(&mut self) {
+        let tex_data = unsafe {
+            let ctx = sys::igGetCurrentContext();
+            if ctx.is_null() {
+                return;
+            }
+            let io = sys::igGetIO_ContextPtr(ctx);
+            if io.is_null() || (*io).Fonts.is_null() {
+                return;
+            }
+            (*(*io).Fonts).TexData
+        };
+        self.track_font_atlas_texture_data(tex_data);
+    }
+
+    fn track_font_atlas_texture_data(&mut self, tex_data: *mut sys::ImTextureData) {
+        if tex_data.is_null() || tex_data == self.font_atlas_texture_data {
+            return;
+        }
+        self.font_atlas_texture_data = tex_data;
+        // A replacement that is already uploaded becomes the fallback right away; otherwise it is
+        // picked up when its WantCreate request is processed.
+        let tex_id = TextureId::from(unsafe { (*tex_data).TexID });
+        if let Some(gl_texture) = self.texture_map().get(tex_id) {
+            self.font_atlas_texture = Some(gl_texture);
         }
+    }
 
-        let bpp = texture_data.bytes_per_pixel();
+    /// Clips `rect` to a `tex_w` x `tex_h` texture, returning `(x, y, w, h)` if anything is left.
+    fn clamp_texture_rect(
+        tex_w: usize,
+        tex_h: usize,
+        rect: dear_imgui_rs::texture::TextureRect,
+    ) -> Option<(usize, usize, usize, usize)> {
         let (rx, ry, rw, rh) = (
             rect.x as usize,
             rect.y as usize,
@@ -38,9 +65,18 @@ impl GlowRenderer {
         if rw == 0 || rh == 0 || rx >= tex_w || ry >= tex_h {
             return None;
         }
+        Some((rx, ry, rw.min(tex_w - rx), rh.min(tex_h - ry)))
+    }
 
-        let rw = rw.min(tex_w.saturating_sub(rx));
-        let rh = rh.min(tex_h.saturating_sub(ry));
+    fn convert_subrect_to_rgba(
+        texture_data: &dear_imgui_rs::TextureData,
+        rect: dear_imgui_rs::texture::TextureRect,
+    ) -> Option<Vec<u8>> {
+        let pixels = texture_data.pixels()?;
+        let tex_w = usize::try_from(texture_data.width()).ok()?;
+        let tex_h = usize::try_from(texture_data.height()).ok()?;
+        let bpp = texture_data.bytes_per_pixel();
+        let (rx, ry, rw, rh) = Self::clamp_texture_rect(tex_w, tex_h, rect)?;
 
         let mut out = vec![0u8; rw.checked_mul(rh)?.checked_mul(4)?];
         match texture_data.format() {
@@ -100,6 +136,15 @@ impl GlowRenderer {
         Ok(())
     }
 
+    /// Internal format for textures created from `ImTextureData` (see `set_srgb_textures`).
+    fn texture_internal_format(&self) -> i32 {
+        if self.srgb_textures && self.gl_version.srgb_texture_support() {
+            glow::SRGB8_ALPHA8 as i32
+        } else {
+            glow::RGBA as i32
+        }
+    }
+
     fn required_gl_context(gl: Option<&Context>) -> RenderResult<&Context> {
         gl.ok_or(RenderError::MissingGlContext)
     }
@@ -116,6 +161,7 @@ impl GlowRenderer {
         let (width_i32, height_i32) =
             checked_gl_texture_size(width, height).map_err(RenderError::DeviceObjectInit)?;
         let format = texture_data.format();
+        let internal_format = self.texture_internal_format();
 
         if let Some(pixels) = texture_data.pixels() {
             let gl_texture = unsafe {
@@ -124,10 +170,7 @@ impl GlowRenderer {
                     .ok()
                     .unwrap_or(glow::TEXTURE0);
                 gl.active_texture(glow::TEXTURE0);
-                let last_texture = u32::try_from(gl.get_parameter_i32(glow::TEXTURE_BINDING_2D))
-                    .ok()
-                    .and_then(std::num::NonZeroU32::new)
-                    .map(glow::NativeTexture);
+                let last_texture = gl.get_parameter_texture(glow::TEXTURE_BINDING_2D);
                 let last_unpack = gl.get_parameter_i32(glow::UNPACK_ALIGNMENT);
 
                 let gl_texture = gl
@@ -168,7 +211,7 @@ impl GlowRenderer {
                         gl.tex_image_2d(
                             glow::TEXTURE_2D,
                             0,
-                            glow::RGBA as i32,
+                            internal_format,
                             width_i32,
                             height_i32,
                             0,
@@ -204,7 +247,7 @@ impl GlowRenderer {
                         gl.tex_image_2d(
                             glow::TEXTURE_2D,
                             0,
-                            glow::RGBA as i32,
+                            internal_format,
                             width_i32,
                             height_i32,
                             0,
@@ -265,15 +308,26 @@ impl GlowRenderer {
         let last_active = u32::try_from(unsafe { gl.get_parameter_i32(glow::ACTIVE_TEXTURE) })
             .ok()
             .unwrap_or(glow::TEXTURE0);
-        let last_texture = u32::try_from(unsafe { gl.get_parameter_i32(glow::TEXTURE_BINDING_2D) })
-            .ok()
-            .and_then(std::num::NonZeroU32::new)
-            .map(glow::NativeTexture);
+        let last_texture = unsafe { gl.get_parameter_texture(glow::TEXTURE_BINDING_2D) };
         let last_unpack = unsafe { gl.get_parameter_i32(glow::UNPACK_ALIGNMENT) };
+        // RGBA32 rects are uploaded straight from the CPU pixels with GL_UNPACK_ROW_LENGTH; Alpha8
+        // rects still have to be expanded to RGBA first.
+        let direct_upload = texture_data.format() == TextureFormat::RGBA32
+            && self.gl_version.unpack_row_length_support();
+        let last_row_length = if direct_upload {
+            unsafe { gl.get_parameter_i32(glow::UNPACK_ROW_LENGTH) }
+        } else {
+            0
+        };
+        let tex_w = usize::try_from(texture_data.width()).unwrap_or(0);
+        let tex_h = usize::try_from(texture_data.height()).unwrap_or(0);
         unsafe {
             gl.active_texture(glow::TEXTURE0);
             gl.bind_texture(glow::TEXTURE_2D, Some(gl_texture));
             gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, 1);
+            if direct_upload {
+                gl.pixel_store_i32(glow::UNPACK_ROW_LENGTH, texture_data.width() as i32);
+            }
         }
 
         // Collect update rects; prefer explicit Updates[] then fallback to single UpdateRect
@@ -290,6 +344,9 @@ impl GlowRenderer {
             texture_data.set_status(dear_imgui_rs::TextureStatus::OK);
             // Restore previous binding and pixel store
             unsafe {
+                if direct_upload {
+                    gl.pixel_store_i32(glow::UNPACK_ROW_LENGTH, last_row_length);
+                }
                 gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, last_unpack);
                 gl.bind_texture(glow::TEXTURE_2D, last_texture);
                 gl.active_texture(last_active);
@@ -298,19 +355,37 @@ impl GlowRenderer {
         }
 
         // Iterate update rects and upload each sub-region
+        let mut result = Ok(());
         for rect in rects.into_iter() {
-            let rx = rect.x as u32;
-            let ry = rect.y as u32;
-            let rw = rect.w as u32;
-            let rh = rect.h as u32;
-            let rx_i32 = gl_texture_size_i32("x", rx).map_err(RenderError::DeviceObjectInit)?;
-            let ry_i32 = gl_texture_size_i32("y", ry).map_err(RenderError::DeviceObjectInit)?;
-            let (rw_i32, rh_i32) =
-                checked_gl_texture_size(rw, rh).map_err(RenderError::DeviceObjectInit)?;
-
-            let Some(sub_rgba) = Self::convert_subrect_to_rgba(texture_data, rect) else {
+            let Some((rx, ry, rw, rh)) = Self::clamp_texture_rect(tex_w, tex_h, rect) else {
                 continue;
             };
+            let rect_i32 = gl_texture_size_i32("x", rx as u32).and_then(|x| {
+                let y = gl_texture_size_i32("y", ry as u32)?;
+                let (w, h) = checked_gl_texture_size(rw as u32, rh as u32)?;
+                Ok((x, y, w, h))
+            });
+            let (rx_i32, ry_i32, rw_i32, rh_i32) = match rect_i32 {
+                Ok(rect) => rect,
+                Err(e) => {
+                    result = Err(RenderError::DeviceObjectInit(e));
+                    break;
+                }
+            };
+
+            let converted;
+            let data = if direct_upload {
+                let Some(pixels) = texture_data.pixels() else {
+                    continue;
+                };
+                &pixels[(ry * tex_w + rx) * 4..]
+            } else {
+                let Some(sub_rgba) = Self::convert_subrect_to_rgba(texture_data, rect) else {
+                    continue;
+                };
+                converted = sub_rgba;
+                &converted[..]
+            };
 
             unsafe {
                 gl.tex_sub_image_2d(
@@ -322,17 +397,21 @@ impl GlowRenderer {
                     rh_i32,
                     glow::RGBA,
                     glow::UNSIGNED_BYTE,
-                    glow::PixelUnpackData::Slice(Some(&sub_rgba)),
+                    glow::PixelUnpackData::Slice(Some(data)),
                 );
             }
         }
 
         // Restore previous binding and pixel store
         unsafe {
+            if direct_upload {
+                gl.pixel_store_i32(glow::UNPACK_ROW_LENGTH, last_row_length);
+            }
             gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, last_unpack);
             gl.bind_texture(glow::TEXTURE_2D, last_texture);
             gl.active_texture(last_active);
         }
+        result?;
 
         // Mark status OK after updates
         texture_data.set_status(dear_imgui_rs::TextureStatus::OK);
@@ -378,7 +457,11 @@ impl GlowRenderer {
         height: u32,
         data: &[u8],
     ) -> InitResult<()> {
-        let gl = self.gl_context.clone().ok_or(InitError::MissingGlContext)?;
+        let gl = self
+            .gl_context
+            .owned()
+            .cloned()
+            .ok_or(InitError::MissingGlContext)?;
         self.update_texture_with_context(&gl, texture_id, width, height, data)
     }
 
@@ -424,7 +507,11 @@ impl GlowRenderer {
         format: TextureFormat,
         data: &[u8],
     ) -> InitResult<TextureId> {
-        let gl = self.gl_context.clone().ok_or(InitError::MissingGlContext)?;
+        let gl = self
+            .gl_context
+            .owned()
+            .cloned()
+            .ok_or(InitError::MissingGlContext)?;
         self.register_texture_with_context(&gl, width, height, format, data)
     }
 
@@ -462,6 +549,19 @@ impl GlowRenderer {
     }
 }
 
+/// Handles `TextureData` requests like [`GlowRenderer::render`] does for the draw data's textures.
+///
+/// Creating and updating textures needs the owned OpenGL context; renderers created with an
+/// external context return [`RenderError::MissingGlContext`] for those requests.
+impl dear_imgui_rs::TextureUpdate for GlowRenderer {
+    type Error = RenderError;
+
+    fn update_texture_data(&mut self, texture_data: &mut TextureData) -> RenderResult<()> {
+        let gl = self.gl_context.owned().cloned();
+        self.update_texture_from_data(gl.as_deref(), texture_data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::GlowRenderer;
@@ -471,9 +571,11 @@ mod tests {
     use dear_imgui_rs::{
         TextureData, TextureFormat, TextureId, TextureStatus, texture::TextureRect,
     };
+    use std::sync::Mutex;
     use std::sync::atomic::{AtomicU32, Ordering};
 
     static LAST_BOUND_TEXTURE: AtomicU32 = AtomicU32::new(0);
+    static LAST_SUB_IMAGE: Mutex<Option<(i32, i32, i32, i32, [u8; 4])>> = Mutex::new(None);
 
     fn make_test_renderer() -> GlowRenderer {
         GlowRenderer {
@@ -500,9 +602,13 @@ mod tests {
             },
             has_clip_origin_support: false,
             is_destroyed: false,
-            gl_context: None,
+            gl_context: crate::renderer::core::GlContextMode::External,
             texture_map: Some(Box::new(SimpleTextureMap::default())),
-            framebuffer_srgb: false,
+            render_target: None,
+            options: crate::RendererOptions::default(),
+            output_srgb: crate::OutputSrgb::Off,
+            frame_srgb: false,
+            srgb_textures: false,
             color_gamma_override: None,
             viewport_clear_color: [0.0, 0.0, 0.0, 1.0],
         }
@@ -549,6 +655,23 @@ mod tests {
             _pixels: *const std::ffi::c_void,
         ) {
         }
+        unsafe extern "system" fn fake_gl_tex_sub_image_2d(
+            _target: u32,
+            _level: i32,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+            _format: u32,
+            _type_: u32,
+            pixels: *const std::ffi::c_void,
+        ) {
+            let mut first = [0u8; 4];
+            unsafe {
+                std::ptr::copy_nonoverlapping(pixels.cast::<u8>(), first.as_mut_ptr(), 4);
+            }
+            *LAST_SUB_IMAGE.lock().unwrap() = Some((x, y, width, height, first));
+        }
 
         unsafe {
             glow::Context::from_loader_function(|name| {
@@ -566,6 +689,9 @@ mod tests {
                         fake_gl_pixel_store_i as *const () as *const std::ffi::c_void
                     }
                     "glTexImage2D" => fake_gl_tex_image_2d as *const () as *const std::ffi::c_void,
+                    "glTexSubImage2D" => {
+                        fake_gl_tex_sub_image_2d as *const () as *const std::ffi::c_void
+                    }
                     _ => std::ptr::null(),
                 };
                 ptr
@@ -573,6 +699,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn srgb_textures_use_srgb_internal_format_where_supported() {
+        let mut renderer = make_test_renderer();
+        assert_eq!(renderer.texture_internal_format(), glow::RGBA as i32);
+
+        renderer.set_srgb_textures(true);
+        assert_eq!(
+            renderer.texture_internal_format(),
+            glow::SRGB8_ALPHA8 as i32
+        );
+
+        renderer.gl_version = GlVersion {
+            major: 2,
+            minor: 0,
+            is_es: true,
+        };
+        assert_eq!(renderer.texture_internal_format(), glow::RGBA as i32);
+    }
+
     #[test]
     fn convert_subrect_to_rgba_rgba32_full_rect() {
         let mut tex = TextureData::new();
@@ -648,6 +793,20 @@ mod tests {
         assert_eq!(tex.status(), TextureStatus::Destroyed);
     }
 
+    #[test]
+    fn external_context_renderer_never_falls_back_to_an_owned_context() {
+        let mut renderer = make_test_renderer();
+        assert!(renderer.gl_context().is_none());
+        assert!(matches!(
+            renderer.new_frame(),
+            Err(crate::RenderError::MissingGlContext)
+        ));
+        assert!(matches!(
+            renderer.register_texture(1, 1, TextureFormat::RGBA32, &[0; 4]),
+            Err(crate::InitError::MissingGlContext)
+        ));
+    }
+
     #[test]
     fn update_texture_with_context_uses_registered_gl_texture() {
         let mut renderer = make_test_renderer();
@@ -665,4 +824,84 @@ mod tests {
 
         assert_eq!(LAST_BOUND_TEXTURE.load(Ordering::SeqCst), 99);
     }
+
+    #[test]
+    fn clamp_texture_rect_clips_to_texture_bounds() {
+        let rect = |x, y, w, h| TextureRect { x, y, w, h };
+        assert_eq!(
+            GlowRenderer::clamp_texture_rect(4, 4, rect(2, 3, 8, 8)),
+            Some((2, 3, 2, 1))
+        );
+        assert_eq!(
+            GlowRenderer::clamp_texture_rect(4, 4, rect(0, 0, 0, 2)),
+            None
+        );
+        assert_eq!(
+            GlowRenderer::clamp_texture_rect(4, 4, rect(4, 0, 1, 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn rgba32_update_rect_uploads_from_pixels_in_place() {
+        let mut renderer = make_test_renderer();
+        let texture_id = TextureId::from(7u64);
+        let gl_texture = glow::NativeTexture(std::num::NonZeroU32::new(77).unwrap());
+        renderer.texture_map_mut().set(texture_id, gl_texture);
+
+        let mut tex = TextureData::new();
+        tex.create(TextureFormat::RGBA32, 2, 2);
+        let pixels: Vec<u8> = (0..16).collect();
+        tex.set_data(&pixels);
+        tex.set_tex_id(texture_id);
+        unsafe {
+            (*tex.as_raw_mut()).UpdateRect = dear_imgui_rs::sys::ImTextureRect {
+                x: 1,
+                y: 1,
+                w: 4,
+                h: 4,
+            };
+        }
+
+        let gl = make_fake_gl();
+        renderer
+            .update_texture_from_data(Some(&gl), &mut tex)
+            .expect("partial update should succeed");
+
+        assert_eq!(tex.status(), TextureStatus::OK);
+        // Clipped to the single bottom-right texel, read directly from the pixel buffer.
+        assert_eq!(
+            *LAST_SUB_IMAGE.lock().unwrap(),
+            Some((1, 1, 1, 1, [12, 13, 14, 15]))
+        );
+    }
+
+    #[test]
+    fn font_atlas_tracking_follows_replaced_texture_data() {
+        let mut renderer = make_test_renderer();
+        let old_texture = glow::NativeTexture(std::num::NonZeroU32::new(10).unwrap());
+        let new_texture = glow::NativeTexture(std::num::NonZeroU32::new(11).unwrap());
+        renderer.font_atlas_texture = Some(old_texture);
+        renderer
+            .texture_map_mut()
+            .set(TextureId::from(11u64), new_texture);
+
+        let mut old_atlas = TextureData::new();
+        renderer.track_font_atlas_texture_data(old_atlas.as_raw_mut());
+        assert!(renderer.is_font_atlas_texture(&old_atlas));
+
+        let mut new_atlas = TextureData::new();
+        new_atlas.set_tex_id(TextureId::from(11u64));
+        renderer.track_font_atlas_texture_data(new_atlas.as_raw_mut());
+        assert!(renderer.is_font_atlas_texture(&new_atlas));
+        assert!(!renderer.is_font_atlas_texture(&old_atlas));
+        assert_eq!(renderer.font_atlas_texture, Some(new_texture));
+
+        // Destroying the replaced atlas texture keeps the live fallback.
+        old_atlas.set_status(TextureStatus::WantDestroy);
+        renderer
+            .update_texture_from_data(None, &mut old_atlas)
+            .expect("destroying an unknown texture should not require a GL context");
+        assert_eq!(renderer.font_atlas_texture, Some(new_texture));
+    }
 }
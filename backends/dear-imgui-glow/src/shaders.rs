@@ -110,11 +110,9 @@ impl Shaders {
     /// Generate vertex shader source
     fn vertex_shader_source(glsl_version: &GlslVersion) -> String {
         let version_str = glsl_version.as_str();
-        let is_legacy =
-            version_str.contains("#version 120") || version_str.contains("#version 100");
 
-        if is_legacy {
-            // GLSL 120 and ES 100 use attribute/varying
+        if glsl_version.is_legacy() {
+            // GLSL 110/120 and ES 100 use attribute/varying
             format!(
                 r#"{version}
 {precision}
@@ -133,11 +131,7 @@ void main()
 }}
 "#,
                 version = version_str,
-                precision = if version_str.contains("es") {
-                    "precision mediump float;"
-                } else {
-                    ""
-                }
+                precision = precision_statement(glsl_version)
             )
         } else {
             // GLSL 130+ use in/out
@@ -159,11 +153,7 @@ void main()
 }}
 "#,
                 version = version_str,
-                precision = if version_str.contains("es") {
-                    "precision mediump float;"
-                } else {
-                    ""
-                }
+                precision = precision_statement(glsl_version)
             )
         }
     }
@@ -175,11 +165,9 @@ void main()
     /// manual shader-based conversion like the WGPU backend.
     fn fragment_shader_source(glsl_version: &GlslVersion) -> String {
         let version_str = glsl_version.as_str();
-        let is_legacy =
-            version_str.contains("#version 120") || version_str.contains("#version 100");
 
-        if is_legacy {
-            // GLSL 120 and ES 100 use gl_FragColor and texture2D
+        if glsl_version.is_legacy() {
+            // GLSL 110/120 and ES 100 use gl_FragColor and texture2D
             format!(
                 r#"{version}
 {precision}
@@ -196,11 +184,7 @@ void main()
 }}
 "#,
                 version = version_str,
-                precision = if version_str.contains("es") || version_str.contains("#version 120") {
-                    "#ifdef GL_ES\n    precision mediump float;\n#endif"
-                } else {
-                    ""
-                }
+                precision = precision_statement(glsl_version)
             )
         } else {
             // GLSL 130+ use out variables and texture()
@@ -221,12 +205,55 @@ void main()
 }}
 "#,
                 version = version_str,
-                precision = if version_str.contains("es") {
-                    "precision mediump float;"
-                } else {
-                    ""
-                }
+                precision = precision_statement(glsl_version)
             )
         }
     }
 }
+
+/// Default float precision; GLSL ES fragment shaders have none and fail to compile without it.
+fn precision_statement(glsl_version: &GlslVersion) -> &'static str {
+    if glsl_version.is_es() {
+        "precision mediump float;"
+    } else {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glsl(major: u32, minor: u32, is_es: bool) -> GlslVersion {
+        GlslVersion::for_gl_version(GlVersion {
+            major,
+            minor,
+            is_es,
+        })
+    }
+
+    #[test]
+    fn glsl_es_100_shaders_use_legacy_syntax_and_declare_precision() {
+        let glsl = glsl(2, 0, true);
+        let vertex = Shaders::vertex_shader_source(&glsl);
+        let fragment = Shaders::fragment_shader_source(&glsl);
+
+        assert!(vertex.starts_with("#version 100\n"));
+        assert!(vertex.contains("attribute vec2 Position;"));
+        assert!(fragment.contains("precision mediump float;"));
+        assert!(fragment.contains("gl_FragColor"));
+        assert!(fragment.contains("texture2D("));
+    }
+
+    #[test]
+    fn desktop_shaders_pick_syntax_by_glsl_version() {
+        let legacy = Shaders::fragment_shader_source(&glsl(2, 0, false));
+        assert!(legacy.starts_with("#version 110\n"));
+        assert!(legacy.contains("gl_FragColor"));
+        assert!(!legacy.contains("precision"));
+
+        let modern = Shaders::fragment_shader_source(&glsl(3, 3, false));
+        assert!(modern.contains("out vec4 Out_Color;"));
+        assert!(!modern.contains("precision"));
+    }
+}
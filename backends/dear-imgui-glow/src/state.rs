@@ -1,5 +1,7 @@
 //! OpenGL state backup and restoration
 
+#[cfg(feature = "bind_sampler_support")]
+use crate::GlSampler;
 use crate::{GlBuffer, GlProgram, GlTexture, GlVersion, GlVertexArray};
 use glow::{Context, HasContext};
 
@@ -27,6 +29,8 @@ pub struct GlStateBackup {
     // Vertex array
     #[cfg(feature = "bind_vertex_array_support")]
     vertex_array_binding: Option<GlVertexArray>,
+    // Enabled state of the renderer's vertex attributes (only backed up without a VAO)
+    vertex_attrib_enabled: [bool; 3],
 
     // Textures
     active_texture: u32,
@@ -50,7 +54,7 @@ pub struct GlStateBackup {
 
     // Sampler binding (OpenGL 3.3+/ES 3.0+)
     #[cfg(feature = "bind_sampler_support")]
-    sampler_binding: u32,
+    sampler_binding: Option<GlSampler>,
 }
 
 impl GlStateBackup {
@@ -76,43 +80,28 @@ impl GlStateBackup {
             self.scissor_box.copy_from_slice(&scissor);
 
             // Buffers
-            let buffer_binding = gl.get_parameter_i32(glow::ARRAY_BUFFER_BINDING);
-            self.array_buffer_binding = u32::try_from(buffer_binding)
-                .ok()
-                .and_then(std::num::NonZeroU32::new)
-                .map(glow::NativeBuffer);
-            let element_buffer_binding = gl.get_parameter_i32(glow::ELEMENT_ARRAY_BUFFER_BINDING);
-            self.element_array_buffer_binding = u32::try_from(element_buffer_binding)
-                .ok()
-                .and_then(std::num::NonZeroU32::new)
-                .map(glow::NativeBuffer);
+            self.array_buffer_binding = gl.get_parameter_buffer(glow::ARRAY_BUFFER_BINDING);
+            self.element_array_buffer_binding =
+                gl.get_parameter_buffer(glow::ELEMENT_ARRAY_BUFFER_BINDING);
 
             // Vertex array
             #[cfg(feature = "bind_vertex_array_support")]
             if gl_version.bind_vertex_array_support() {
-                let vao_binding = gl.get_parameter_i32(glow::VERTEX_ARRAY_BINDING);
-                self.vertex_array_binding = u32::try_from(vao_binding)
-                    .ok()
-                    .and_then(std::num::NonZeroU32::new)
-                    .map(glow::NativeVertexArray);
+                self.vertex_array_binding =
+                    gl.get_parameter_vertex_array(glow::VERTEX_ARRAY_BINDING);
             }
 
             // Textures
             self.active_texture = u32::try_from(gl.get_parameter_i32(glow::ACTIVE_TEXTURE))
                 .ok()
                 .unwrap_or(glow::TEXTURE0);
-            let texture_binding = gl.get_parameter_i32(glow::TEXTURE_BINDING_2D);
-            self.texture_2d_binding = u32::try_from(texture_binding)
-                .ok()
-                .and_then(std::num::NonZeroU32::new)
-                .map(glow::NativeTexture);
+            // The renderer draws with texture unit 0, so that is the binding to save (like the
+            // official OpenGL3 backend); the sampler binding below is read for it as well.
+            gl.active_texture(glow::TEXTURE0);
+            self.texture_2d_binding = gl.get_parameter_texture(glow::TEXTURE_BINDING_2D);
 
             // Shader program
-            let program_binding = gl.get_parameter_i32(glow::CURRENT_PROGRAM);
-            self.current_program = u32::try_from(program_binding)
-                .ok()
-                .and_then(std::num::NonZeroU32::new)
-                .map(glow::NativeProgram);
+            self.current_program = gl.get_parameter_program(glow::CURRENT_PROGRAM);
 
             // Other state
             self.cull_face_enabled = gl.is_enabled(glow::CULL_FACE);
@@ -136,9 +125,38 @@ impl GlStateBackup {
             // Sampler binding
             #[cfg(feature = "bind_sampler_support")]
             if gl_version.bind_sampler_support() {
-                self.sampler_binding = u32::try_from(gl.get_parameter_i32(glow::SAMPLER_BINDING))
-                    .ok()
-                    .unwrap_or(0);
+                self.sampler_binding = gl.get_parameter_sampler(glow::SAMPLER_BINDING);
+            }
+        }
+    }
+
+    /// Backup the enabled state of the given vertex attribute arrays
+    ///
+    /// Without a vertex array object (OpenGL ES 2.0 / WebGL1, GL < 3.0) attribute arrays are
+    /// global state, so enabling ours would otherwise leak into the host's draws.
+    pub fn backup_vertex_attribs(&mut self, gl: &Context, locations: [u32; 3]) {
+        for (enabled, location) in self.vertex_attrib_enabled.iter_mut().zip(locations) {
+            let mut value = [0.0f32; 4];
+            unsafe {
+                gl.get_vertex_attrib_parameter_f32_slice(
+                    location,
+                    glow::VERTEX_ATTRIB_ARRAY_ENABLED,
+                    &mut value,
+                );
+            }
+            *enabled = value[0] != 0.0;
+        }
+    }
+
+    /// Restore the vertex attribute arrays saved by [`backup_vertex_attribs`](Self::backup_vertex_attribs)
+    pub fn restore_vertex_attribs(&self, gl: &Context, locations: [u32; 3]) {
+        for (&enabled, location) in self.vertex_attrib_enabled.iter().zip(locations) {
+            unsafe {
+                if enabled {
+                    gl.enable_vertex_attrib_array(location);
+                } else {
+                    gl.disable_vertex_attrib_array(location);
+                }
             }
         }
     }
@@ -193,8 +211,13 @@ impl GlStateBackup {
             }
 
             // Restore textures
-            gl.active_texture(self.active_texture);
+            gl.active_texture(glow::TEXTURE0);
             gl.bind_texture(glow::TEXTURE_2D, self.texture_2d_binding);
+            #[cfg(feature = "bind_sampler_support")]
+            if gl_version.bind_sampler_support() {
+                gl.bind_sampler(0, self.sampler_binding);
+            }
+            gl.active_texture(self.active_texture);
 
             // Restore shader program
             gl.use_program(self.current_program);
@@ -231,12 +254,33 @@ impl GlStateBackup {
                     gl.disable(glow::PRIMITIVE_RESTART);
                 }
             }
+        }
+    }
+}
 
-            // Restore sampler binding
-            #[cfg(feature = "bind_sampler_support")]
-            if gl_version.bind_sampler_support() {
-                gl.bind_sampler(0, None); // Reset to default
+/// Reset what rendering leaves behind under [`StateBackup::Minimal`](crate::StateBackup::Minimal)
+///
+/// No state is queried: scissor testing is disabled and the renderer's program, buffers,
+/// vertex array and texture are unbound. `vertex_attribs` are disabled when rendering set them on
+/// the global vertex state (no VAO). Blend, depth, stencil, cull face and viewport keep the
+/// values Dear ImGui rendering needed.
+pub fn reset_minimal_state(gl: &Context, gl_version: GlVersion, vertex_attribs: Option<[u32; 3]>) {
+    unsafe {
+        gl.disable(glow::SCISSOR_TEST);
+        if let Some(locations) = vertex_attribs {
+            for location in locations {
+                gl.disable_vertex_attrib_array(location);
             }
         }
+        #[cfg(feature = "bind_vertex_array_support")]
+        if gl_version.bind_vertex_array_support() {
+            gl.bind_vertex_array(None);
+        }
+        #[cfg(not(feature = "bind_vertex_array_support"))]
+        let _ = gl_version;
+        gl.bind_buffer(glow::ARRAY_BUFFER, None);
+        gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, None);
+        gl.bind_texture(glow::TEXTURE_2D, None);
+        gl.use_program(None);
     }
 }
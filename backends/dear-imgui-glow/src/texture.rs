@@ -366,10 +366,7 @@ pub(crate) fn upload_texture_data(
         let last_active = u32::try_from(gl.get_parameter_i32(glow::ACTIVE_TEXTURE))
             .ok()
             .unwrap_or(glow::TEXTURE0);
-        let last_texture = u32::try_from(gl.get_parameter_i32(glow::TEXTURE_BINDING_2D))
-            .ok()
-            .and_then(std::num::NonZeroU32::new)
-            .map(glow::NativeTexture);
+        let last_texture = gl.get_parameter_texture(glow::TEXTURE_BINDING_2D);
         let last_unpack = gl.get_parameter_i32(glow::UNPACK_ALIGNMENT);
 
         gl.active_texture(glow::TEXTURE0);
@@ -395,6 +392,23 @@ pub(crate) fn upload_texture_data(
     Ok(())
 }
 
+/// Interpret a `TextureId` that is not in the texture map as an OpenGL texture name
+#[cfg(not(target_arch = "wasm32"))]
+fn gl_texture_from_id(texture_id: TextureId) -> InitResult<GlTexture> {
+    let name = u32::try_from(texture_id.id())
+        .map_err(|_| InitError::TextureIdOutOfRange(texture_id.id()))?;
+    std::num::NonZeroU32::new(name)
+        .map(glow::NativeTexture)
+        .ok_or(InitError::NullTextureId)
+}
+
+/// WebGL textures are JavaScript objects without a numeric name, so only textures known to the
+/// texture map can be updated.
+#[cfg(target_arch = "wasm32")]
+fn gl_texture_from_id(texture_id: TextureId) -> InitResult<GlTexture> {
+    Err(InitError::UnregisteredTextureId(texture_id.id()))
+}
+
 /// Update texture from ImGui texture data (similar to ImGui_ImplOpenGL3_UpdateTexture)
 pub fn update_imgui_texture(
     gl: &Context,
@@ -406,10 +420,7 @@ pub fn update_imgui_texture(
     let (width_i32, height_i32) = checked_gl_texture_size(width, height)?;
     unsafe {
         // Backup current texture binding
-        let last_texture = u32::try_from(gl.get_parameter_i32(glow::TEXTURE_BINDING_2D))
-            .ok()
-            .and_then(std::num::NonZeroU32::new)
-            .map(glow::NativeTexture);
+        let last_texture = gl.get_parameter_texture(glow::TEXTURE_BINDING_2D);
 
         // Set pixel store parameters
         gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, 1);
@@ -454,11 +465,7 @@ pub fn update_imgui_texture(
             texture
         } else {
             // Update existing texture
-            let texture_u32 = u32::try_from(texture_id.id())
-                .map_err(|_| InitError::TextureIdOutOfRange(texture_id.id()))?;
-            let texture_nz =
-                std::num::NonZeroU32::new(texture_u32).ok_or(InitError::NullTextureId)?;
-            let texture = glow::NativeTexture(texture_nz);
+            let texture = gl_texture_from_id(texture_id)?;
             gl.bind_texture(glow::TEXTURE_2D, Some(texture));
             gl.tex_image_2d(
                 glow::TEXTURE_2D,
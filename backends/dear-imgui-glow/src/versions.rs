@@ -110,6 +110,30 @@ impl GlVersion {
         !self.is_es // Not supported in OpenGL ES
     }
 
+    /// Check if this version supports GL_UNPACK_ROW_LENGTH (sub-rect uploads without a copy)
+    pub fn unpack_row_length_support(self) -> bool {
+        if self.is_es {
+            self.major >= 3 // OpenGL ES 3.0+ / WebGL 2
+        } else {
+            true // Core since OpenGL 1.1
+        }
+    }
+
+    /// Check if this version can query an attachment's color encoding
+    /// (GL_FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING)
+    pub fn srgb_framebuffer_query_support(self) -> bool {
+        self.major >= 3 // OpenGL 3.0+ or OpenGL ES 3.0+ / WebGL 2
+    }
+
+    /// Check if this version supports the SRGB8_ALPHA8 texture format
+    pub fn srgb_texture_support(self) -> bool {
+        if self.is_es {
+            self.major >= 3 // OpenGL ES 3.0+ / WebGL 2
+        } else {
+            self.major > 2 || (self.major == 2 && self.minor >= 1) // OpenGL 2.1+
+        }
+    }
+
     /// Check if this version supports GL_PRIMITIVE_RESTART
     pub fn primitive_restart_support(self) -> bool {
         if self.is_es {
@@ -161,4 +185,60 @@ impl GlslVersion {
     pub fn as_str(&self) -> &str {
         &self.version_string
     }
+
+    /// Check if this is a GLSL ES version (`#version 100` or `#version 300 es`)
+    pub fn is_es(&self) -> bool {
+        self.version_string == "#version 100" || self.version_string.ends_with(" es")
+    }
+
+    /// Check if this version predates `in`/`out` and needs `attribute`/`varying`,
+    /// `texture2D()` and `gl_FragColor` (GLSL 1.10, 1.20 and GLSL ES 1.00)
+    pub fn is_legacy(&self) -> bool {
+        matches!(
+            self.version_string.as_str(),
+            "#version 100" | "#version 110" | "#version 120"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gles2_and_webgl1_select_glsl_es_100() {
+        for version_string in [
+            "OpenGL ES 2.0 Mesa 23.2.1",
+            "WebGL 1.0 (OpenGL ES 2.0 Chromium)",
+        ] {
+            let gl_version = GlVersion::parse(version_string);
+            assert!(gl_version.is_es);
+            assert_eq!((gl_version.major, gl_version.minor), (2, 0));
+            assert!(!gl_version.bind_vertex_array_support());
+            assert!(!gl_version.vertex_offset_support());
+
+            let glsl = GlslVersion::for_gl_version(gl_version);
+            assert_eq!(glsl.as_str(), "#version 100");
+            assert!(glsl.is_es());
+            assert!(glsl.is_legacy());
+        }
+    }
+
+    #[test]
+    fn glsl_dialect_classification() {
+        let glsl = |major, minor, is_es| {
+            GlslVersion::for_gl_version(GlVersion {
+                major,
+                minor,
+                is_es,
+            })
+        };
+        assert!(glsl(2, 0, false).is_legacy());
+        assert!(!glsl(2, 0, false).is_es());
+        assert!(glsl(2, 1, false).is_legacy());
+        assert!(!glsl(3, 0, false).is_legacy());
+        assert!(!glsl(3, 0, true).is_legacy());
+        assert!(glsl(3, 0, true).is_es());
+        assert!(!glsl(4, 6, false).is_es());
+    }
 }
@@ -0,0 +1,35 @@
+//! Error types for the Metal renderer.
+
+use thiserror::Error;
+
+/// Result type for renderer operations.
+pub type RendererResult<T> = Result<T, RendererError>;
+
+/// Errors that can occur during Metal renderer initialization or rendering.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum RendererError {
+    /// The embedded Metal shader library failed to compile.
+    #[error("Shader compilation error: {0}")]
+    Shader(String),
+
+    /// The render pipeline state could not be created.
+    #[error("Pipeline creation error: {0}")]
+    Pipeline(String),
+
+    /// Renderer options are invalid.
+    #[error("Invalid options: {0}")]
+    InvalidOptions(String),
+
+    /// A draw command references a texture id the renderer doesn't know.
+    #[error("Bad texture id: {0}")]
+    BadTextureId(u64),
+
+    /// A texture has a size Metal can't create.
+    #[error("Invalid texture size {width}x{height}")]
+    InvalidTextureSize { width: u32, height: u32 },
+
+    /// The draw data is larger than Metal buffers can address.
+    #[error("{buffer} buffer too large")]
+    DrawBufferTooLarge { buffer: &'static str },
+}
@@ -0,0 +1,41 @@
+//! Metal renderer backend for Dear ImGui.
+//!
+//! This crate renders Dear ImGui draw data with Metal through the `metal` bindings, for macOS and
+//! iOS apps and engines that already own a Metal device and render loop. It follows the same
+//! contract as the other renderer backends in this repository: Dear ImGui 1.92 managed textures
+//! (`DrawData::textures()`) are created, updated and destroyed by the renderer, and user textures
+//! are registered to get a [`dear_imgui_rs::TextureId`].
+//!
+//! ## Reference
+//!
+//! This backend is a port of the official `imgui_impl_metal.mm` backend.
+//!
+//! ## Target support
+//!
+//! Metal is only available on Apple targets. On other targets the crate builds but is empty, so
+//! it can sit behind a `cfg(target_vendor = "apple")` in multi-backend workspaces without extra
+//! features.
+
+#[cfg(target_vendor = "apple")]
+mod error;
+#[cfg(target_vendor = "apple")]
+pub use error::*;
+
+#[cfg(target_vendor = "apple")]
+mod options;
+#[cfg(target_vendor = "apple")]
+pub use options::*;
+
+#[cfg(target_vendor = "apple")]
+mod texture;
+#[cfg(target_vendor = "apple")]
+pub use dear_imgui_rs::TextureUpdateResult;
+
+#[cfg(target_vendor = "apple")]
+mod renderer;
+#[cfg(target_vendor = "apple")]
+pub use renderer::MetalRenderer;
+
+/// Re-export of the `metal` crate version this backend is built against.
+#[cfg(target_vendor = "apple")]
+pub use metal;
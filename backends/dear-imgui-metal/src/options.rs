@@ -0,0 +1,58 @@
+//! Renderer options.
+
+use metal::MTLPixelFormat;
+
+/// Optional parameters of the renderer.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// The number of in-flight frames of the application.
+    ///
+    /// The renderer keeps one vertex/index buffer pair per frame; pass the application's frame
+    /// index to [`MetalRenderer::render`](crate::MetalRenderer::render).
+    pub in_flight_frames: usize,
+    /// Pixel format of the color attachment the renderer draws into (e.g. the `CAMetalLayer`'s).
+    pub color_format: MTLPixelFormat,
+    /// Pixel format of the depth attachment, or `MTLPixelFormat::Invalid` for none.
+    pub depth_format: MTLPixelFormat,
+    /// Pixel format of the stencil attachment, or `MTLPixelFormat::Invalid` for none.
+    pub stencil_format: MTLPixelFormat,
+    /// Sample count of the render pass attachments.
+    pub sample_count: u64,
+    /// Override the gamma used for sRGB->linear conversion in the shader.
+    ///
+    /// - `None`: auto (2.2 when `color_format` is an sRGB format, else 1.0)
+    /// - `Some(gamma)`: force a value (e.g. 2.2 or 1.0)
+    pub color_gamma_override: Option<f32>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            in_flight_frames: 3,
+            color_format: MTLPixelFormat::BGRA8Unorm,
+            depth_format: MTLPixelFormat::Invalid,
+            stencil_format: MTLPixelFormat::Invalid,
+            sample_count: 1,
+            color_gamma_override: None,
+        }
+    }
+}
+
+impl Options {
+    /// Gamma applied by the fragment shader.
+    pub(crate) fn gamma(&self) -> f32 {
+        self.color_gamma_override
+            .unwrap_or(if is_srgb_format(self.color_format) {
+                2.2
+            } else {
+                1.0
+            })
+    }
+}
+
+pub(crate) fn is_srgb_format(format: MTLPixelFormat) -> bool {
+    matches!(
+        format,
+        MTLPixelFormat::BGRA8Unorm_sRGB | MTLPixelFormat::RGBA8Unorm_sRGB
+    )
+}
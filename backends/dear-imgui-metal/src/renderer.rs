@@ -0,0 +1,496 @@
+//! Metal renderer implementation.
+
+use std::ffi::c_void;
+
+use dear_imgui_rs::render::{DrawCmd, DrawData, DrawIdx, DrawVert};
+use dear_imgui_rs::{BackendFlags, Context, TextureId};
+use metal::{
+    Buffer, CompileOptions, DepthStencilDescriptor, DepthStencilState, Device, MTLBlendFactor,
+    MTLBlendOperation, MTLCompareFunction, MTLCullMode, MTLIndexType, MTLPrimitiveType,
+    MTLResourceOptions, MTLSamplerAddressMode, MTLSamplerMinMagFilter, MTLScissorRect,
+    MTLVertexFormat, MTLVertexStepFunction, MTLViewport, RenderCommandEncoderRef,
+    RenderPipelineDescriptor, RenderPipelineState, SamplerDescriptor, SamplerState,
+    VertexDescriptor,
+};
+
+use crate::texture::TextureRegistry;
+use crate::{Options, RendererError, RendererResult};
+
+const SHADER_SOURCE: &str = include_str!("shader.metal");
+
+/// Vertex buffer index of the vertex descriptor; uniforms use index 1.
+const VERTEX_BUFFER_INDEX: u64 = 0;
+const UNIFORMS_BUFFER_INDEX: u64 = 1;
+
+/// Vertex and fragment shader uniforms (`Uniforms` in `shader.metal`).
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Uniforms {
+    projection: [[f32; 4]; 4],
+    gamma: f32,
+    _pad: [f32; 3],
+}
+
+/// Metal renderer for Dear ImGui.
+///
+/// It encodes draw commands into a render command encoder provided by the application, which
+/// owns the command buffer, the render pass and presentation.
+pub struct MetalRenderer {
+    pub(crate) device: Device,
+    pipeline: RenderPipelineState,
+    depth_stencil: DepthStencilState,
+    linear_sampler: SamplerState,
+    nearest_sampler: SamplerState,
+    pub(crate) textures: TextureRegistry,
+    frames: Vec<FrameBuffers>,
+    options: Options,
+}
+
+/// Vertex and index buffers of one frame in flight.
+#[derive(Default)]
+struct FrameBuffers {
+    vertices: Option<Buffer>,
+    indices: Option<Buffer>,
+}
+
+impl MetalRenderer {
+    /// Create a renderer drawing into attachments described by `options`.
+    ///
+    /// Also calls [`configure_imgui_context`](Self::configure_imgui_context).
+    pub fn new(
+        device: Device,
+        imgui: &mut Context,
+        options: Option<Options>,
+    ) -> RendererResult<Self> {
+        let options = options.unwrap_or_default();
+        if options.in_flight_frames == 0 {
+            return Err(RendererError::InvalidOptions(
+                "in_flight_frames must be >= 1".to_string(),
+            ));
+        }
+
+        let pipeline = create_pipeline(&device, &options)?;
+        let depth_stencil = {
+            let desc = DepthStencilDescriptor::new();
+            desc.set_depth_compare_function(MTLCompareFunction::Always);
+            desc.set_depth_write_enabled(false);
+            device.new_depth_stencil_state(&desc)
+        };
+        let linear_sampler = create_sampler(&device, MTLSamplerMinMagFilter::Linear);
+        let nearest_sampler = create_sampler(&device, MTLSamplerMinMagFilter::Nearest);
+
+        let renderer = Self {
+            device,
+            pipeline,
+            depth_stencil,
+            linear_sampler,
+            nearest_sampler,
+            textures: TextureRegistry::default(),
+            frames: (0..options.in_flight_frames)
+                .map(|_| FrameBuffers::default())
+                .collect(),
+            options,
+        };
+        renderer.configure_imgui_context(imgui);
+        Ok(renderer)
+    }
+
+    /// Set the renderer name and backend flags on the Dear ImGui context.
+    pub fn configure_imgui_context(&self, imgui_context: &mut Context) {
+        let should_set_name = imgui_context.io().backend_renderer_name().is_none();
+        if should_set_name {
+            let _ = imgui_context.set_renderer_name(Some(format!(
+                "dear-imgui-metal {}",
+                env!("CARGO_PKG_VERSION")
+            )));
+        }
+
+        let io = imgui_context.io_mut();
+        let mut flags = io.backend_flags();
+        flags.insert(BackendFlags::RENDERER_HAS_VTX_OFFSET);
+        flags.insert(BackendFlags::RENDERER_HAS_TEXTURES);
+        io.set_backend_flags(flags);
+
+        let platform_io = imgui_context.platform_io_mut();
+        platform_io
+            .set_draw_callback_reset_render_state_raw(Some(draw_callback_reset_render_state));
+        platform_io
+            .set_draw_callback_set_sampler_linear_raw(Some(draw_callback_set_sampler_linear));
+        platform_io
+            .set_draw_callback_set_sampler_nearest_raw(Some(draw_callback_set_sampler_nearest));
+    }
+
+    /// The Metal device the renderer was created with.
+    pub fn device(&self) -> &metal::DeviceRef {
+        &self.device
+    }
+
+    /// The renderer options.
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Encode draw commands for `draw_data` into `encoder`.
+    ///
+    /// `encoder` must belong to a render pass whose attachments match the formats and sample
+    /// count of [`Options`]. Managed texture requests of the frame are handled first.
+    ///
+    /// Pass the application's frame-in-flight index (taken modulo `Options::in_flight_frames`):
+    /// the vertex/index buffers of a frame are only rewritten once that frame comes around again,
+    /// i.e. after the application waited for its command buffer (usually a semaphore signalled in
+    /// the completion handler), so the CPU never overwrites buffers the GPU is still reading.
+    pub fn render(
+        &mut self,
+        draw_data: &mut DrawData,
+        encoder: &RenderCommandEncoderRef,
+        frame_index: usize,
+    ) -> RendererResult<()> {
+        self.process_texture_requests(draw_data)?;
+
+        let fb_width = draw_data.display_size[0] * draw_data.framebuffer_scale[0];
+        let fb_height = draw_data.display_size[1] * draw_data.framebuffer_scale[1];
+        if !draw_data.valid()
+            || draw_data.total_vtx_count() == 0
+            || fb_width <= 0.0
+            || fb_height <= 0.0
+        {
+            return Ok(());
+        }
+
+        let frame_count = self.frames.len();
+        let frame = &mut self.frames[frame_index % frame_count];
+        let vertices = upload(
+            &self.device,
+            &mut frame.vertices,
+            draw_data.draw_lists().map(|list| list.vtx_buffer()),
+            draw_data.total_vtx_count(),
+            "vertex",
+        )?;
+        let indices = upload(
+            &self.device,
+            &mut frame.indices,
+            draw_data.draw_lists().map(|list| list.idx_buffer()),
+            draw_data.total_idx_count(),
+            "index",
+        )?;
+
+        let uniforms = Uniforms {
+            projection: ortho_projection(draw_data.display_pos, draw_data.display_size),
+            gamma: self.options.gamma(),
+            _pad: [0.0; 3],
+        };
+        let viewport = MTLViewport {
+            originX: 0.0,
+            originY: 0.0,
+            width: f64::from(fb_width),
+            height: f64::from(fb_height),
+            znear: 0.0,
+            zfar: 1.0,
+        };
+        let setup_render_state = || {
+            encoder.set_render_pipeline_state(&self.pipeline);
+            encoder.set_depth_stencil_state(&self.depth_stencil);
+            encoder.set_cull_mode(MTLCullMode::None);
+            encoder.set_viewport(viewport);
+            encoder.set_vertex_buffer(VERTEX_BUFFER_INDEX, Some(vertices), 0);
+            let uniforms_ptr = (&uniforms as *const Uniforms).cast::<c_void>();
+            let uniforms_len = std::mem::size_of::<Uniforms>() as u64;
+            encoder.set_vertex_bytes(UNIFORMS_BUFFER_INDEX, uniforms_len, uniforms_ptr);
+            encoder.set_fragment_bytes(UNIFORMS_BUFFER_INDEX, uniforms_len, uniforms_ptr);
+        };
+        setup_render_state();
+
+        let clip_off = draw_data.display_pos;
+        let clip_scale = draw_data.framebuffer_scale;
+        let mut standard_sampler = &self.linear_sampler;
+        let mut global_vtx_offset = 0usize;
+        let mut global_idx_offset = 0usize;
+
+        for draw_list in draw_data.draw_lists() {
+            for cmd in draw_list.commands() {
+                match cmd {
+                    DrawCmd::Elements {
+                        count,
+                        cmd_params,
+                        raw_cmd,
+                    } => {
+                        let Some(scissor) = clip_rect_to_scissor(
+                            cmd_params.clip_rect,
+                            clip_off,
+                            clip_scale,
+                            [fb_width, fb_height],
+                        ) else {
+                            continue;
+                        };
+
+                        let tex_id = resolve_effective_texture_id(cmd_params.texture_id, raw_cmd);
+                        let texture = self
+                            .textures
+                            .get(tex_id.id())
+                            .ok_or(RendererError::BadTextureId(tex_id.id()))?;
+                        encoder.set_fragment_texture(0, Some(&texture.texture));
+                        encoder.set_fragment_sampler_state(
+                            0,
+                            Some(texture.sampler.as_ref().unwrap_or(standard_sampler)),
+                        );
+                        encoder.set_scissor_rect(scissor);
+
+                        let vtx_offset = global_vtx_offset + cmd_params.vtx_offset;
+                        let idx_offset = global_idx_offset + cmd_params.idx_offset;
+                        encoder.set_vertex_buffer_offset(
+                            VERTEX_BUFFER_INDEX,
+                            (vtx_offset * std::mem::size_of::<DrawVert>()) as u64,
+                        );
+                        encoder.draw_indexed_primitives(
+                            MTLPrimitiveType::Triangle,
+                            count as u64,
+                            MTLIndexType::UInt16,
+                            indices,
+                            (idx_offset * std::mem::size_of::<DrawIdx>()) as u64,
+                        );
+                    }
+                    DrawCmd::ResetRenderState => {
+                        setup_render_state();
+                        standard_sampler = &self.linear_sampler;
+                    }
+                    DrawCmd::SetSamplerLinear => standard_sampler = &self.linear_sampler,
+                    DrawCmd::SetSamplerNearest => standard_sampler = &self.nearest_sampler,
+                    DrawCmd::RawCallback { .. } => {
+                        log::warn!(
+                            target: "dear-imgui-metal",
+                            "Raw callbacks are not supported by the Metal renderer"
+                        );
+                    }
+                }
+            }
+            global_vtx_offset += draw_list.vtx_buffer().len();
+            global_idx_offset += draw_list.idx_buffer().len();
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for MetalRenderer {
+    fn drop(&mut self) {
+        // Metal objects are reference counted; command buffers still in flight keep what they
+        // use alive.
+        self.textures.clear();
+    }
+}
+
+fn create_pipeline(device: &Device, options: &Options) -> RendererResult<RenderPipelineState> {
+    let library = device
+        .new_library_with_source(SHADER_SOURCE, &CompileOptions::new())
+        .map_err(RendererError::Shader)?;
+    let vertex_fn = library
+        .get_function("vertex_main", None)
+        .map_err(RendererError::Shader)?;
+    let fragment_fn = library
+        .get_function("fragment_main", None)
+        .map_err(RendererError::Shader)?;
+
+    let vertex_desc = VertexDescriptor::new();
+    let attributes = [
+        (
+            0,
+            MTLVertexFormat::Float2,
+            std::mem::offset_of!(DrawVert, pos),
+        ),
+        (
+            1,
+            MTLVertexFormat::Float2,
+            std::mem::offset_of!(DrawVert, uv),
+        ),
+        (
+            2,
+            MTLVertexFormat::UChar4Normalized,
+            std::mem::offset_of!(DrawVert, col),
+        ),
+    ];
+    for (index, format, offset) in attributes {
+        let attribute = vertex_desc
+            .attributes()
+            .object_at(index)
+            .expect("vertex attribute descriptor");
+        attribute.set_format(format);
+        attribute.set_offset(offset as u64);
+        attribute.set_buffer_index(VERTEX_BUFFER_INDEX);
+    }
+    let layout = vertex_desc
+        .layouts()
+        .object_at(VERTEX_BUFFER_INDEX)
+        .expect("vertex buffer layout descriptor");
+    layout.set_stride(std::mem::size_of::<DrawVert>() as u64);
+    layout.set_step_function(MTLVertexStepFunction::PerVertex);
+    layout.set_step_rate(1);
+
+    let desc = RenderPipelineDescriptor::new();
+    desc.set_label("dear-imgui-metal pipeline");
+    desc.set_vertex_function(Some(&vertex_fn));
+    desc.set_fragment_function(Some(&fragment_fn));
+    desc.set_vertex_descriptor(Some(vertex_desc));
+    desc.set_raster_sample_count(options.sample_count);
+    desc.set_depth_attachment_pixel_format(options.depth_format);
+    desc.set_stencil_attachment_pixel_format(options.stencil_format);
+
+    let color = desc
+        .color_attachments()
+        .object_at(0)
+        .expect("color attachment descriptor");
+    color.set_pixel_format(options.color_format);
+    color.set_blending_enabled(true);
+    color.set_rgb_blend_operation(MTLBlendOperation::Add);
+    color.set_alpha_blend_operation(MTLBlendOperation::Add);
+    color.set_source_rgb_blend_factor(MTLBlendFactor::SourceAlpha);
+    color.set_destination_rgb_blend_factor(MTLBlendFactor::OneMinusSourceAlpha);
+    color.set_source_alpha_blend_factor(MTLBlendFactor::One);
+    color.set_destination_alpha_blend_factor(MTLBlendFactor::OneMinusSourceAlpha);
+
+    device
+        .new_render_pipeline_state(&desc)
+        .map_err(RendererError::Pipeline)
+}
+
+fn create_sampler(device: &Device, filter: MTLSamplerMinMagFilter) -> SamplerState {
+    let desc = SamplerDescriptor::new();
+    desc.set_min_filter(filter);
+    desc.set_mag_filter(filter);
+    desc.set_address_mode_s(MTLSamplerAddressMode::ClampToEdge);
+    desc.set_address_mode_t(MTLSamplerAddressMode::ClampToEdge);
+    device.new_sampler(&desc)
+}
+
+/// Copy the concatenated `slices` into `buffer`, growing it when needed, and return it.
+fn upload<'a, 'b, T: Copy + 'b>(
+    device: &Device,
+    buffer: &'a mut Option<Buffer>,
+    slices: impl Iterator<Item = &'b [T]>,
+    total_len: usize,
+    name: &'static str,
+) -> RendererResult<&'a Buffer> {
+    let size = total_len
+        .checked_mul(std::mem::size_of::<T>())
+        .ok_or(RendererError::DrawBufferTooLarge { buffer: name })?;
+    if buffer
+        .as_ref()
+        .is_none_or(|buffer| (buffer.length() as usize) < size)
+    {
+        // Grow with headroom so small UI changes don't reallocate every frame.
+        let capacity = size.checked_next_power_of_two().unwrap_or(size).max(1024);
+        *buffer = Some(device.new_buffer(capacity as u64, MTLResourceOptions::StorageModeShared));
+    }
+    let buffer = buffer.as_ref().expect("buffer allocated above");
+
+    let dst = buffer.contents().cast::<T>();
+    let mut written = 0usize;
+    for slice in slices {
+        let len = slice.len().min(total_len - written);
+        // SAFETY: the buffer holds at least `total_len` elements and the slices are copied back
+        // to back; it is shared CPU memory not in use by the GPU for this frame index.
+        unsafe { std::ptr::copy_nonoverlapping(slice.as_ptr(), dst.add(written), len) };
+        written += len;
+    }
+    Ok(buffer)
+}
+
+/// Orthographic projection mapping the display rectangle to Metal clip space (column-major).
+fn ortho_projection(display_pos: [f32; 2], display_size: [f32; 2]) -> [[f32; 4]; 4] {
+    let l = display_pos[0];
+    let r = display_pos[0] + display_size[0];
+    let t = display_pos[1];
+    let b = display_pos[1] + display_size[1];
+    [
+        [2.0 / (r - l), 0.0, 0.0, 0.0],
+        [0.0, 2.0 / (t - b), 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [(r + l) / (l - r), (t + b) / (b - t), 0.0, 1.0],
+    ]
+}
+
+/// Project a clip rectangle into framebuffer space; `None` when nothing is left to draw.
+fn clip_rect_to_scissor(
+    clip_rect: [f32; 4],
+    clip_off: [f32; 2],
+    clip_scale: [f32; 2],
+    fb_size: [f32; 2],
+) -> Option<MTLScissorRect> {
+    let min_x = ((clip_rect[0] - clip_off[0]) * clip_scale[0]).max(0.0);
+    let min_y = ((clip_rect[1] - clip_off[1]) * clip_scale[1]).max(0.0);
+    let max_x = ((clip_rect[2] - clip_off[0]) * clip_scale[0]).min(fb_size[0]);
+    let max_y = ((clip_rect[3] - clip_off[1]) * clip_scale[1]).min(fb_size[1]);
+    if max_x <= min_x || max_y <= min_y {
+        return None;
+    }
+    Some(MTLScissorRect {
+        x: min_x as u64,
+        y: min_y as u64,
+        width: (max_x - min_x) as u64,
+        height: (max_y - min_y) as u64,
+    })
+}
+
+fn resolve_effective_texture_id(
+    legacy: TextureId,
+    raw_cmd: *const dear_imgui_rs::sys::ImDrawCmd,
+) -> TextureId {
+    if raw_cmd.is_null() {
+        return legacy;
+    }
+    unsafe {
+        let mut copy = *raw_cmd;
+        TextureId::from(dear_imgui_rs::sys::ImDrawCmd_GetTexID(&mut copy))
+    }
+}
+
+// Marker callbacks: Dear ImGui compares draw command callbacks against these pointers and the
+// renderer turns them into `DrawCmd::ResetRenderState` / `SetSampler*`.
+unsafe extern "C" fn draw_callback_reset_render_state(
+    _parent_list: *const dear_imgui_rs::sys::ImDrawList,
+    _cmd: *const dear_imgui_rs::sys::ImDrawCmd,
+) {
+}
+
+unsafe extern "C" fn draw_callback_set_sampler_linear(
+    _parent_list: *const dear_imgui_rs::sys::ImDrawList,
+    _cmd: *const dear_imgui_rs::sys::ImDrawCmd,
+) {
+}
+
+unsafe extern "C" fn draw_callback_set_sampler_nearest(
+    _parent_list: *const dear_imgui_rs::sys::ImDrawList,
+    _cmd: *const dear_imgui_rs::sys::ImDrawCmd,
+) {
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scissor_is_scaled_offset_and_clamped() {
+        let scissor = clip_rect_to_scissor(
+            [10.0, 20.0, 500.0, 60.0],
+            [5.0, 10.0],
+            [2.0, 2.0],
+            [800.0, 600.0],
+        )
+        .unwrap();
+        assert_eq!(
+            (scissor.x, scissor.y, scissor.width, scissor.height),
+            (10, 20, 790, 80)
+        );
+        assert!(
+            clip_rect_to_scissor([10.0, 10.0, 10.0, 50.0], [0.0; 2], [1.0; 2], [800.0, 600.0])
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn projection_maps_display_corners_to_clip_space() {
+        let m = ortho_projection([100.0, 50.0], [200.0, 100.0]);
+        let project = |x: f32, y: f32| [m[0][0] * x + m[3][0], m[1][1] * y + m[3][1]];
+        assert_eq!(project(100.0, 50.0), [-1.0, 1.0]);
+        assert_eq!(project(300.0, 150.0), [1.0, -1.0]);
+    }
+}
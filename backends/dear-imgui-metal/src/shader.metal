@@ -0,0 +1,37 @@
+#include <metal_stdlib>
+using namespace metal;
+
+struct Uniforms {
+    float4x4 projection;
+    float gamma;
+};
+
+struct VertexIn {
+    float2 position [[attribute(0)]];
+    float2 uv [[attribute(1)]];
+    float4 color [[attribute(2)]];
+};
+
+struct VertexOut {
+    float4 position [[position]];
+    float2 uv;
+    float4 color;
+};
+
+vertex VertexOut vertex_main(VertexIn in [[stage_in]],
+                             constant Uniforms &uniforms [[buffer(1)]]) {
+    VertexOut out;
+    out.position = uniforms.projection * float4(in.position, 0.0, 1.0);
+    out.uv = in.uv;
+    out.color = in.color;
+    return out;
+}
+
+fragment float4 fragment_main(VertexOut in [[stage_in]],
+                              constant Uniforms &uniforms [[buffer(1)]],
+                              texture2d<float, access::sample> texture [[texture(0)]],
+                              sampler texture_sampler [[sampler(0)]]) {
+    float4 color = in.color * texture.sample(texture_sampler, in.uv);
+    // ImGui colors are sRGB; linearize them when rendering to an sRGB target.
+    return float4(pow(color.rgb, float3(uniforms.gamma)), color.a);
+}
@@ -0,0 +1,310 @@
+//! Texture management for the Metal renderer.
+//!
+//! Dear ImGui managed textures (`ImTextureData`) and user-registered Metal textures share one id
+//! space. Metal command buffers retain the textures they reference, so a texture can be released
+//! as soon as Dear ImGui asks for it, even while a frame using it is still in flight.
+
+use std::collections::HashMap;
+
+use dear_imgui_rs::{
+    TextureData, TextureFormat, TextureId, TextureStatus, TextureUpdate, TextureUpdateResult,
+};
+use metal::{
+    DeviceRef, MTLPixelFormat, MTLRegion, MTLTextureUsage, SamplerState, Texture, TextureDescriptor,
+};
+
+use crate::renderer::MetalRenderer;
+use crate::{RendererError, RendererResult};
+
+pub(crate) struct MetalTexture {
+    pub(crate) texture: Texture,
+    /// Sampler to use instead of the renderer's standard (linear/nearest) one.
+    pub(crate) sampler: Option<SamplerState>,
+}
+
+#[derive(Default)]
+pub(crate) struct TextureRegistry {
+    textures: HashMap<u64, MetalTexture>,
+    next_id: u64,
+}
+
+impl TextureRegistry {
+    pub(crate) fn get(&self, id: u64) -> Option<&MetalTexture> {
+        self.textures.get(&id)
+    }
+
+    fn contains(&self, id: u64) -> bool {
+        self.textures.contains_key(&id)
+    }
+
+    fn allocate_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn insert(&mut self, id: u64, texture: MetalTexture) {
+        self.textures.insert(id, texture);
+    }
+
+    fn remove(&mut self, id: u64) -> Option<MetalTexture> {
+        self.textures.remove(&id)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.textures.clear();
+    }
+}
+
+impl MetalRenderer {
+    /// Register a Metal texture for use in Dear ImGui draw commands (e.g. `ui.image()`).
+    ///
+    /// The renderer keeps a reference to the texture until
+    /// [`unregister_texture`](Self::unregister_texture) is called. It is sampled with the
+    /// renderer's standard sampler (linear unless a draw command switches to nearest).
+    pub fn register_texture(&mut self, texture: &metal::TextureRef) -> TextureId {
+        self.register(texture, None)
+    }
+
+    /// Register a Metal texture that is always sampled with `sampler`.
+    pub fn register_texture_with_sampler(
+        &mut self,
+        texture: &metal::TextureRef,
+        sampler: &metal::SamplerStateRef,
+    ) -> TextureId {
+        self.register(texture, Some(sampler.to_owned()))
+    }
+
+    fn register(
+        &mut self,
+        texture: &metal::TextureRef,
+        sampler: Option<SamplerState>,
+    ) -> TextureId {
+        let id = self.textures.allocate_id();
+        self.textures.insert(
+            id,
+            MetalTexture {
+                texture: texture.to_owned(),
+                sampler,
+            },
+        );
+        TextureId::from(id)
+    }
+
+    /// Unregister a texture id.
+    pub fn unregister_texture(&mut self, texture_id: TextureId) {
+        self.textures.remove(texture_id.id());
+    }
+
+    /// Update a single texture manually.
+    ///
+    /// Use this for user-created `ImTextureData` that isn't in Dear ImGui's texture list (so
+    /// [`render`](Self::render) doesn't see it), and apply the result with
+    /// [`TextureUpdateResult::apply_to`] before the texture is drawn.
+    pub fn update_texture(
+        &mut self,
+        texture_data: &TextureData,
+    ) -> RendererResult<TextureUpdateResult> {
+        let id = texture_data.tex_id().id();
+        match texture_data.status() {
+            TextureStatus::WantCreate => self.create_texture(texture_data),
+            TextureStatus::WantUpdates if id == 0 || !self.textures.contains(id) => {
+                // Not created yet: treat updates as a full create.
+                self.create_texture(texture_data)
+            }
+            TextureStatus::WantUpdates => {
+                let Some(existing) = self.textures.get(id) else {
+                    return Ok(TextureUpdateResult::Failed);
+                };
+                let texture = &existing.texture;
+                let (tw, th) = (texture.width() as u32, texture.height() as u32);
+                let (x, y, w, h) = clamp_rect(texture_data.update_rect(), tw, th);
+                if w == 0 || h == 0 {
+                    return Ok(TextureUpdateResult::Updated);
+                }
+                let Some(pixels) = texture_data_to_rgba_subrect(texture_data, x, y, w, h) else {
+                    return Ok(TextureUpdateResult::Failed);
+                };
+                replace_region(texture, x, y, w, h, &pixels);
+                Ok(TextureUpdateResult::Updated)
+            }
+            TextureStatus::WantDestroy => {
+                self.textures.remove(id);
+                Ok(TextureUpdateResult::Destroyed)
+            }
+            TextureStatus::OK | TextureStatus::Destroyed => Ok(TextureUpdateResult::NoAction),
+        }
+    }
+
+    fn create_texture(
+        &mut self,
+        texture_data: &TextureData,
+    ) -> RendererResult<TextureUpdateResult> {
+        let (w, h) = (texture_data.width(), texture_data.height());
+        if w == 0 || h == 0 {
+            return Ok(TextureUpdateResult::Failed);
+        }
+        let Some(pixels) = texture_data_to_rgba_full(texture_data) else {
+            return Ok(TextureUpdateResult::Failed);
+        };
+        let texture = create_rgba_texture(&self.device, w, h, &pixels)?;
+
+        // Reuse the id when Dear ImGui recreates a texture it already has (e.g. an atlas resize).
+        let current = texture_data.tex_id().id();
+        let id = if current != 0 && self.textures.contains(current) {
+            current
+        } else {
+            self.textures.allocate_id()
+        };
+        self.textures.insert(
+            id,
+            MetalTexture {
+                texture,
+                sampler: None,
+            },
+        );
+        Ok(TextureUpdateResult::Created {
+            texture_id: TextureId::from(id),
+        })
+    }
+
+    /// Handle the create/update/destroy requests of Dear ImGui managed textures.
+    pub(crate) fn process_texture_requests(
+        &mut self,
+        draw_data: &mut dear_imgui_rs::render::DrawData,
+    ) -> RendererResult<()> {
+        let mut textures = draw_data.textures_mut();
+        while let Some(mut td) = textures.next() {
+            if matches!(td.status(), TextureStatus::OK | TextureStatus::Destroyed) {
+                continue;
+            }
+            let result = self.update_texture(&td)?;
+            result.apply_to(&mut td);
+        }
+        Ok(())
+    }
+}
+
+impl TextureUpdate for MetalRenderer {
+    type Error = RendererError;
+
+    fn update_texture_data(&mut self, texture_data: &mut TextureData) -> RendererResult<()> {
+        self.update_texture(texture_data)?.apply_to(texture_data);
+        Ok(())
+    }
+}
+
+/// Largest 2D texture size supported by every Metal GPU family since Apple3 / Mac2.
+const MAX_TEXTURE_SIZE: u32 = 16384;
+
+fn create_rgba_texture(
+    device: &DeviceRef,
+    width: u32,
+    height: u32,
+    pixels: &[u8],
+) -> RendererResult<Texture> {
+    if width > MAX_TEXTURE_SIZE || height > MAX_TEXTURE_SIZE {
+        return Err(RendererError::InvalidTextureSize { width, height });
+    }
+    let desc = TextureDescriptor::new();
+    desc.set_pixel_format(MTLPixelFormat::RGBA8Unorm);
+    desc.set_width(u64::from(width));
+    desc.set_height(u64::from(height));
+    desc.set_usage(MTLTextureUsage::ShaderRead);
+    let texture = device.new_texture(&desc);
+    replace_region(&texture, 0, 0, width, height, pixels);
+    Ok(texture)
+}
+
+/// Upload tightly packed RGBA8 rows into a region of `texture`.
+///
+/// `replaceRegion` copies on the CPU right away; like the official backend, updates of a texture
+/// a frame in flight is still sampling are not synchronized (font atlas updates only add glyphs
+/// in unused space).
+fn replace_region(texture: &metal::TextureRef, x: u32, y: u32, w: u32, h: u32, pixels: &[u8]) {
+    texture.replace_region(
+        MTLRegion::new_2d(u64::from(x), u64::from(y), u64::from(w), u64::from(h)),
+        0,
+        pixels.as_ptr().cast(),
+        u64::from(w) * 4,
+    );
+}
+
+fn texture_data_to_rgba_full(td: &TextureData) -> Option<Vec<u8>> {
+    texture_data_to_rgba_subrect(td, 0, 0, td.width(), td.height())
+}
+
+fn texture_data_to_rgba_subrect(
+    td: &TextureData,
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+) -> Option<Vec<u8>> {
+    let pixels = td.pixels()?;
+    let tex_w = usize::try_from(td.width()).ok()?;
+    let tex_h = usize::try_from(td.height()).ok()?;
+    let (x, y, w, h) = (x as usize, y as usize, w as usize, h as usize);
+    if w == 0 || h == 0 || x >= tex_w || y >= tex_h {
+        return None;
+    }
+    let w = w.min(tex_w - x);
+    let h = h.min(tex_h - y);
+    let bpp = td.bytes_per_pixel();
+
+    let mut out = vec![0u8; w.checked_mul(h)?.checked_mul(4)?];
+    for row in 0..h {
+        let src_off = ((y + row) * tex_w + x) * bpp;
+        let dst_row = &mut out[row * w * 4..(row + 1) * w * 4];
+        match td.format() {
+            TextureFormat::RGBA32 => {
+                dst_row.copy_from_slice(pixels.get(src_off..src_off + w * 4)?);
+            }
+            TextureFormat::Alpha8 => {
+                let src_row = pixels.get(src_off..src_off + w)?;
+                for (dst, &a) in dst_row.chunks_exact_mut(4).zip(src_row) {
+                    dst.copy_from_slice(&[255, 255, 255, a]);
+                }
+            }
+        }
+    }
+    Some(out)
+}
+
+fn clamp_rect(rect: dear_imgui_rs::texture::TextureRect, tw: u32, th: u32) -> (u32, u32, u32, u32) {
+    let x = u32::from(rect.x).min(tw);
+    let y = u32::from(rect.y).min(th);
+    let w = u32::from(rect.w);
+    let h = u32::from(rect.h);
+    if w == 0 || h == 0 || x >= tw || y >= th {
+        return (x, y, 0, 0);
+    }
+    (x, y, w.min(tw - x), h.min(th - y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_rect_is_clamped_to_the_texture() {
+        let rect = dear_imgui_rs::texture::TextureRect {
+            x: 60,
+            y: 10,
+            w: 10,
+            h: 10,
+        };
+        assert_eq!(clamp_rect(rect, 64, 64), (60, 10, 4, 10));
+        assert_eq!(clamp_rect(rect, 32, 64), (32, 10, 0, 0));
+    }
+
+    #[test]
+    fn alpha8_texels_expand_to_white_rgba() {
+        let mut tex = TextureData::new();
+        tex.create(TextureFormat::Alpha8, 2, 1);
+        tex.set_data(&[0, 128]);
+        assert_eq!(
+            texture_data_to_rgba_full(&tex),
+            Some(vec![255, 255, 255, 0, 255, 255, 255, 128])
+        );
+    }
+}
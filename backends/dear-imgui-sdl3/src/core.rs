@@ -196,6 +196,8 @@ pub enum Sdl3BackendError {
     Renderer3InitFailed,
     #[error("ImGui_ImplSDLGPU3_Init returned false")]
     Gpu3InitFailed,
+    #[error("SDL_InitSubSystem(SDL_INIT_GAMEPAD) failed: {0}")]
+    GamepadInitFailed(String),
 }
 
 #[cfg(feature = "opengl3-renderer")]
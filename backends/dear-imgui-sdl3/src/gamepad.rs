@@ -12,6 +12,24 @@ pub enum GamepadMode {
     AutoAll,
 }
 
+/// Turn on gamepad navigation: initialize SDL's gamepad subsystem and set
+/// `ConfigFlags::NAV_ENABLE_GAMEPAD`.
+///
+/// The upstream backend maps buttons and sticks (with deadzones) to ImGui's
+/// gamepad keys and sets `BackendFlags::HAS_GAMEPAD` while a gamepad is open,
+/// but it can only open gamepads once `SDL_INIT_GAMEPAD` is initialized, which
+/// `sdl3::init()?.video()` alone does not do. Call this after SDL is
+/// initialized; SDL reference-counts subsystems, so calling it more than once
+/// is harmless.
+pub fn enable_gamepad_navigation(imgui: &mut Context) -> Result<(), Sdl3BackendError> {
+    if !unsafe { sdl3_sys::init::SDL_InitSubSystem(sdl3_sys::init::SDL_INIT_GAMEPAD) } {
+        return Err(Sdl3BackendError::GamepadInitFailed(sdl3::get_error()));
+    }
+    let io = imgui.io_mut();
+    io.set_config_flags(io.config_flags() | dear_imgui_rs::ConfigFlags::NAV_ENABLE_GAMEPAD);
+    Ok(())
+}
+
 /// Configure how the SDL3 backend handles gamepads.
 ///
 /// Call this after backend initialization if you want a mode other than the
@@ -74,8 +74,8 @@ pub use self::backend::{
 pub use self::core::Sdl3BackendError;
 pub use self::events::{process_sys_event, process_sys_event_for_context, sdl3_poll_event_ll};
 pub use self::gamepad::{
-    GamepadMode, set_gamepad_mode, set_gamepad_mode_for_context, set_gamepad_mode_manual,
-    set_gamepad_mode_manual_for_context,
+    GamepadMode, enable_gamepad_navigation, set_gamepad_mode, set_gamepad_mode_for_context,
+    set_gamepad_mode_manual, set_gamepad_mode_manual_for_context,
 };
 #[cfg(feature = "sdlgpu3-renderer")]
 pub use self::viewport::{SdlGpu3InitInfo, init_for_sdlgpu3, init_for_sdlgpu3_default};
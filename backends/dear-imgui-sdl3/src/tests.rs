@@ -6,4 +6,8 @@ fn backend_error_display_is_stable() {
         Sdl3BackendError::InvalidGlslVersion.to_string(),
         "Invalid GLSL version string"
     );
+    assert_eq!(
+        Sdl3BackendError::GamepadInitFailed("no driver".into()).to_string(),
+        "SDL_InitSubSystem(SDL_INIT_GAMEPAD) failed: no driver"
+    );
 }
@@ -7,9 +7,13 @@ use sdl3::gpu::{Device, PresentMode, SampleCount, SwapchainComposition, TextureF
 ///
 /// This should be called before creating any SDL3 windows so that the
 /// underlying backend can display the OS IME UI correctly.
+///
+/// Sets `SDL_HINT_IME_IMPLEMENTED_UI` to `"none"`: Dear ImGui draws neither the
+/// composition string nor the candidate list, so the OS has to. (SDL2's
+/// `SDL_HINT_IME_SHOW_UI` no longer exists in SDL3.)
 pub fn enable_native_ime_ui() {
     // Best-effort: ignore return value; missing hints are not fatal.
-    let _ = sdl3::hint::set("SDL_HINT_IME_SHOW_UI", "1");
+    let _ = sdl3::hint::set("SDL_IME_IMPLEMENTED_UI", "none");
 }
 
 /// Initialize the Dear ImGui SDL3 + OpenGL3 backends.
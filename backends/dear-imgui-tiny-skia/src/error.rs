@@ -0,0 +1,24 @@
+//! Error types for the tiny-skia renderer.
+
+use thiserror::Error;
+
+/// Result type for renderer operations.
+pub type RendererResult<T> = Result<T, RendererError>;
+
+/// Errors that can occur while rendering.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum RendererError {
+    /// A draw command references a texture id the renderer doesn't know.
+    #[error("Bad texture id: {0}")]
+    BadTextureId(u64),
+
+    /// Pixel data doesn't match the texture size.
+    #[error("Texture data is {len} bytes, expected {expected} for {width}x{height} RGBA")]
+    InvalidTextureData {
+        width: u32,
+        height: u32,
+        len: usize,
+        expected: usize,
+    },
+}
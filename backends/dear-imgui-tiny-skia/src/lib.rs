@@ -0,0 +1,34 @@
+//! CPU renderer backend for Dear ImGui.
+//!
+//! [`TinySkiaRenderer`] rasterizes Dear ImGui draw data on the CPU into a [`tiny_skia`] pixmap:
+//! textured, vertex-colored triangles with scissor clipping and alpha blending, like the GPU
+//! renderers in this repository. No GPU, driver or window is needed, which makes it useful for
+//! tools on servers and VMs, and for golden-image tests, since the output only depends on the
+//! draw data.
+//!
+//! To show the pixmap in a window, copy it into a [`softbuffer`](https://crates.io/crates/softbuffer)
+//! buffer with [`copy_to_softbuffer`]:
+//!
+//! ```rust,ignore
+//! let mut pixmap = tiny_skia::Pixmap::new(width, height).unwrap();
+//! pixmap.fill(tiny_skia::Color::from_rgba8(30, 30, 30, 255));
+//! renderer.render(imgui.render(), &mut pixmap.as_mut())?;
+//!
+//! let mut buffer = surface.buffer_mut()?;
+//! dear_imgui_tiny_skia::copy_to_softbuffer(&pixmap.as_ref(), &mut buffer);
+//! buffer.present()?;
+//! ```
+
+mod error;
+pub use error::*;
+
+mod raster;
+
+mod renderer;
+pub use renderer::{TinySkiaRenderer, copy_to_softbuffer};
+
+mod texture;
+pub use dear_imgui_rs::TextureUpdateResult;
+
+/// Re-export of the `tiny-skia` crate version this backend is built against.
+pub use tiny_skia;
@@ -0,0 +1,299 @@
+//! Triangle rasterizer for Dear ImGui geometry.
+//!
+//! Pixels are sampled at their centers and shared edges follow a top-left fill rule, so the
+//! two triangles of a quad (or the anti-aliasing fringes around a shape) never blend a pixel
+//! twice. Coverage is binary: Dear ImGui anti-aliases with its own alpha fringes.
+
+/// Premultiplied RGBA8 pixels, `width * height * 4` bytes, rows top to bottom.
+pub(crate) struct Target<'a> {
+    pub(crate) data: &'a mut [u8],
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+/// Straight (not premultiplied) RGBA8 texels, `width * height * 4` bytes.
+#[derive(Clone, Copy)]
+pub(crate) struct TextureView<'a> {
+    pub(crate) rgba: &'a [u8],
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Filter {
+    Linear,
+    Nearest,
+}
+
+/// Pixel rectangle `[min, max)` triangles are clipped to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ClipRect {
+    pub(crate) min_x: u32,
+    pub(crate) min_y: u32,
+    pub(crate) max_x: u32,
+    pub(crate) max_y: u32,
+}
+
+/// A vertex in target pixel coordinates with its straight RGBA color in `0.0..=1.0`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Vertex {
+    pub(crate) pos: [f32; 2],
+    pub(crate) uv: [f32; 2],
+    pub(crate) color: [f32; 4],
+}
+
+/// Draw one textured, vertex-colored triangle with source-over blending.
+pub(crate) fn draw_triangle(
+    target: &mut Target<'_>,
+    clip: ClipRect,
+    mut v: [Vertex; 3],
+    texture: TextureView<'_>,
+    filter: Filter,
+) {
+    let mut area = orient(v[0].pos, v[1].pos, v[2].pos);
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+    // Dear ImGui emits both windings; normalize so the edge functions are positive inside.
+    if area < 0.0 {
+        v.swap(1, 2);
+        area = -area;
+    }
+
+    let xs = [v[0].pos[0], v[1].pos[0], v[2].pos[0]];
+    let ys = [v[0].pos[1], v[1].pos[1], v[2].pos[1]];
+    // Pixel (x, y) is covered when its center (x + 0.5, y + 0.5) is.
+    let min_x = pixel_start(xs.into_iter().fold(f32::INFINITY, f32::min), clip.min_x);
+    let min_y = pixel_start(ys.into_iter().fold(f32::INFINITY, f32::min), clip.min_y);
+    let max_x = pixel_end(
+        xs.into_iter().fold(f32::NEG_INFINITY, f32::max),
+        clip.max_x.min(target.width),
+    );
+    let max_y = pixel_end(
+        ys.into_iter().fold(f32::NEG_INFINITY, f32::max),
+        clip.max_y.min(target.height),
+    );
+    if min_x >= max_x || min_y >= max_y {
+        return;
+    }
+
+    let edges = [
+        (v[1].pos, v[2].pos),
+        (v[2].pos, v[0].pos),
+        (v[0].pos, v[1].pos),
+    ];
+    let include_zero = edges.map(|(a, b)| is_top_left(a, b));
+    let inv_area = 1.0 / area;
+
+    for y in min_y..max_y {
+        let py = y as f32 + 0.5;
+        let row = (y as usize) * (target.width as usize) * 4;
+        for x in min_x..max_x {
+            let p = [x as f32 + 0.5, py];
+            let w = edges.map(|(a, b)| orient(a, b, p));
+            if !(0..3).all(|i| w[i] > 0.0 || (w[i] == 0.0 && include_zero[i])) {
+                continue;
+            }
+            let l = w.map(|w| w * inv_area);
+            let uv = [
+                l[0] * v[0].uv[0] + l[1] * v[1].uv[0] + l[2] * v[2].uv[0],
+                l[0] * v[0].uv[1] + l[1] * v[1].uv[1] + l[2] * v[2].uv[1],
+            ];
+            let texel = sample(texture, uv, filter);
+            let src = std::array::from_fn(|c| {
+                let color = l[0] * v[0].color[c] + l[1] * v[1].color[c] + l[2] * v[2].color[c];
+                (color * texel[c]).clamp(0.0, 1.0)
+            });
+            let i = row + (x as usize) * 4;
+            blend_over(&mut target.data[i..i + 4], src);
+        }
+    }
+}
+
+/// Twice the signed area of `(a, b, p)`; positive when `p` is on the inner side of `a -> b`.
+fn orient(a: [f32; 2], b: [f32; 2], p: [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])
+}
+
+/// Whether pixel centers exactly on edge `a -> b` belong to the triangle.
+///
+/// A shared edge is walked in opposite directions by its two triangles, so exactly one of
+/// them owns it.
+fn is_top_left(a: [f32; 2], b: [f32; 2]) -> bool {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    dy > 0.0 || (dy == 0.0 && dx < 0.0)
+}
+
+/// First pixel whose center is at or after `min`, clamped to `clip_min`.
+fn pixel_start(min: f32, clip_min: u32) -> u32 {
+    let start = (min - 0.5).ceil().max(0.0);
+    (start.min(u32::MAX as f32) as u32).max(clip_min)
+}
+
+/// One past the last pixel whose center is at or before `max`, clamped to `clip_max`.
+fn pixel_end(max: f32, clip_max: u32) -> u32 {
+    let end = ((max - 0.5).floor() + 1.0).max(0.0);
+    (end.min(u32::MAX as f32) as u32).min(clip_max)
+}
+
+/// Sample straight RGBA in `0.0..=1.0` with clamp-to-edge addressing.
+fn sample(texture: TextureView<'_>, uv: [f32; 2], filter: Filter) -> [f32; 4] {
+    let (w, h) = (texture.width as i64, texture.height as i64);
+    let texel = |x: i64, y: i64| -> [f32; 4] {
+        let x = x.clamp(0, w - 1) as usize;
+        let y = y.clamp(0, h - 1) as usize;
+        let i = (y * texture.width as usize + x) * 4;
+        let t = &texture.rgba[i..i + 4];
+        [t[0], t[1], t[2], t[3]].map(|c| f32::from(c) / 255.0)
+    };
+
+    let x = uv[0] * w as f32;
+    let y = uv[1] * h as f32;
+    match filter {
+        Filter::Nearest => texel(x.floor() as i64, y.floor() as i64),
+        Filter::Linear => {
+            let (x, y) = (x - 0.5, y - 0.5);
+            let (x0, y0) = (x.floor(), y.floor());
+            let (fx, fy) = (x - x0, y - y0);
+            let (x0, y0) = (x0 as i64, y0 as i64);
+            let t00 = texel(x0, y0);
+            let t10 = texel(x0 + 1, y0);
+            let t01 = texel(x0, y0 + 1);
+            let t11 = texel(x0 + 1, y0 + 1);
+            std::array::from_fn(|c| {
+                let top = t00[c] + (t10[c] - t00[c]) * fx;
+                let bottom = t01[c] + (t11[c] - t01[c]) * fx;
+                top + (bottom - top) * fy
+            })
+        }
+    }
+}
+
+/// Blend straight `src` over a premultiplied RGBA8 pixel.
+fn blend_over(dst: &mut [u8], src: [f32; 4]) {
+    let a = src[3];
+    if a <= 0.0 {
+        return;
+    }
+    for (d, s) in dst[..3].iter_mut().zip(src) {
+        *d = to_u8(s * a + f32::from(*d) / 255.0 * (1.0 - a));
+    }
+    let d = f32::from(dst[3]) / 255.0;
+    dst[3] = to_u8(a + d * (1.0 - a));
+}
+
+fn to_u8(v: f32) -> u8 {
+    (v.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+    const FULL: ClipRect = ClipRect {
+        min_x: 0,
+        min_y: 0,
+        max_x: 4,
+        max_y: 4,
+    };
+
+    fn white() -> TextureView<'static> {
+        TextureView {
+            rgba: &WHITE,
+            width: 1,
+            height: 1,
+        }
+    }
+
+    fn vertex(x: f32, y: f32, color: [f32; 4]) -> Vertex {
+        Vertex {
+            pos: [x, y],
+            uv: [0.5, 0.5],
+            color,
+        }
+    }
+
+    /// Draw a `[x0, x1) x [y0, y1)` quad as two triangles sharing the diagonal.
+    fn quad(data: &mut [u8], clip: ClipRect, [x0, y0, x1, y1]: [f32; 4], color: [f32; 4]) {
+        let mut target = Target {
+            data,
+            width: 4,
+            height: 4,
+        };
+        let (a, b) = (vertex(x0, y0, color), vertex(x1, y0, color));
+        let (c, d) = (vertex(x1, y1, color), vertex(x0, y1, color));
+        draw_triangle(&mut target, clip, [a, b, c], white(), Filter::Linear);
+        draw_triangle(&mut target, clip, [a, c, d], white(), Filter::Linear);
+    }
+
+    fn alpha_at(data: &[u8], x: usize, y: usize) -> u8 {
+        data[(y * 4 + x) * 4 + 3]
+    }
+
+    #[test]
+    fn quad_covers_its_pixels_exactly_once() {
+        let mut data = vec![0u8; 4 * 4 * 4];
+        quad(&mut data, FULL, [1.0, 1.0, 3.0, 3.0], [1.0, 0.0, 0.0, 0.5]);
+        for y in 0..4 {
+            for x in 0..4 {
+                let inside = (1..3).contains(&x) && (1..3).contains(&y);
+                // A second blend over the diagonal would give 191 instead of 128.
+                assert_eq!(
+                    alpha_at(&data, x, y),
+                    if inside { 128 } else { 0 },
+                    "({x}, {y})"
+                );
+            }
+        }
+        assert_eq!(&data[(4 + 1) * 4..(4 + 1) * 4 + 4], &[128, 0, 0, 128]);
+    }
+
+    #[test]
+    fn adjacent_quads_do_not_overlap() {
+        let mut data = vec![0u8; 4 * 4 * 4];
+        let color = [0.0, 0.0, 1.0, 0.5];
+        quad(&mut data, FULL, [0.0, 0.0, 2.0, 4.0], color);
+        quad(&mut data, FULL, [2.0, 0.0, 4.0, 4.0], color);
+        assert!(data.chunks_exact(4).all(|px| px[3] == 128));
+    }
+
+    #[test]
+    fn clip_rect_limits_coverage() {
+        let mut data = vec![0u8; 4 * 4 * 4];
+        let clip = ClipRect {
+            min_x: 1,
+            min_y: 0,
+            max_x: 2,
+            max_y: 4,
+        };
+        quad(&mut data, clip, [0.0, 0.0, 4.0, 4.0], [1.0; 4]);
+        for y in 0..4 {
+            for x in 0..4 {
+                assert_eq!(alpha_at(&data, x, y), if x == 1 { 255 } else { 0 });
+            }
+        }
+    }
+
+    #[test]
+    fn sampling_filters_and_clamps_to_edge() {
+        let texels = [255, 0, 0, 255, 0, 255, 0, 255];
+        let texture = TextureView {
+            rgba: &texels,
+            width: 2,
+            height: 1,
+        };
+        assert_eq!(
+            sample(texture, [0.25, 0.5], Filter::Nearest),
+            [1.0, 0.0, 0.0, 1.0]
+        );
+        assert_eq!(
+            sample(texture, [0.5, 0.5], Filter::Linear),
+            [0.5, 0.5, 0.0, 1.0]
+        );
+        assert_eq!(
+            sample(texture, [-1.0, 0.5], Filter::Linear),
+            [1.0, 0.0, 0.0, 1.0]
+        );
+    }
+}
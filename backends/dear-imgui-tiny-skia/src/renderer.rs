@@ -0,0 +1,192 @@
+//! Draw data traversal for the tiny-skia renderer.
+
+use dear_imgui_rs::render::{DrawCmd, DrawData};
+use dear_imgui_rs::{BackendFlags, Context, TextureId};
+use tiny_skia::{PixmapMut, PixmapRef};
+
+use crate::raster::{self, ClipRect, Filter, Target, Vertex};
+use crate::texture::TextureRegistry;
+use crate::{RendererError, RendererResult};
+
+/// CPU renderer for Dear ImGui drawing into a [`tiny_skia`] pixmap.
+#[derive(Default)]
+pub struct TinySkiaRenderer {
+    pub(crate) textures: TextureRegistry,
+}
+
+impl TinySkiaRenderer {
+    /// Create a renderer and configure the Dear ImGui context for it.
+    pub fn new(imgui: &mut Context) -> Self {
+        let renderer = Self::default();
+        renderer.configure_imgui_context(imgui);
+        renderer
+    }
+
+    /// Set the renderer name and backend flags on the Dear ImGui context.
+    pub fn configure_imgui_context(&self, imgui_context: &mut Context) {
+        let should_set_name = imgui_context.io().backend_renderer_name().is_none();
+        if should_set_name {
+            let _ = imgui_context.set_renderer_name(Some(format!(
+                "dear-imgui-tiny-skia {}",
+                env!("CARGO_PKG_VERSION")
+            )));
+        }
+
+        let io = imgui_context.io_mut();
+        let mut flags = io.backend_flags();
+        flags.insert(BackendFlags::RENDERER_HAS_VTX_OFFSET);
+        flags.insert(BackendFlags::RENDERER_HAS_TEXTURES);
+        io.set_backend_flags(flags);
+
+        let platform_io = imgui_context.platform_io_mut();
+        platform_io
+            .set_draw_callback_reset_render_state_raw(Some(draw_callback_reset_render_state));
+        platform_io
+            .set_draw_callback_set_sampler_linear_raw(Some(draw_callback_set_sampler_linear));
+        platform_io
+            .set_draw_callback_set_sampler_nearest_raw(Some(draw_callback_set_sampler_nearest));
+    }
+
+    /// Rasterize `draw_data` over the current contents of `target`.
+    ///
+    /// The pixmap is not cleared first; fill it with a background color as needed. It should
+    /// be the draw data's framebuffer size (`display_size * framebuffer_scale`); anything
+    /// outside is clipped. Managed texture requests of the frame are handled first.
+    ///
+    /// Colors are blended in sRGB space like a UNORM render target on the GPU renderers, so
+    /// the pixmap holds sRGB values.
+    pub fn render(
+        &mut self,
+        draw_data: &mut DrawData,
+        target: &mut PixmapMut<'_>,
+    ) -> RendererResult<()> {
+        self.process_texture_requests(draw_data);
+        if !draw_data.valid() || draw_data.total_vtx_count() == 0 {
+            return Ok(());
+        }
+
+        let (width, height) = (target.width(), target.height());
+        let mut target = Target {
+            data: target.data_mut(),
+            width,
+            height,
+        };
+        let offset = draw_data.display_pos;
+        let scale = draw_data.framebuffer_scale;
+        let to_target =
+            |p: [f32; 2]| [(p[0] - offset[0]) * scale[0], (p[1] - offset[1]) * scale[1]];
+
+        let mut filter = Filter::Linear;
+        for draw_list in draw_data.draw_lists() {
+            let vtx = draw_list.vtx_buffer();
+            let idx = draw_list.idx_buffer();
+            for cmd in draw_list.commands() {
+                match cmd {
+                    DrawCmd::Elements {
+                        count,
+                        cmd_params,
+                        raw_cmd,
+                    } => {
+                        let [x0, y0] =
+                            to_target([cmd_params.clip_rect[0], cmd_params.clip_rect[1]]);
+                        let [x1, y1] =
+                            to_target([cmd_params.clip_rect[2], cmd_params.clip_rect[3]]);
+                        let clip = ClipRect {
+                            min_x: x0.max(0.0) as u32,
+                            min_y: y0.max(0.0) as u32,
+                            max_x: x1.max(0.0).ceil().min(width as f32) as u32,
+                            max_y: y1.max(0.0).ceil().min(height as f32) as u32,
+                        };
+                        if clip.min_x >= clip.max_x || clip.min_y >= clip.max_y {
+                            continue;
+                        }
+
+                        let tex_id = resolve_effective_texture_id(cmd_params.texture_id, raw_cmd);
+                        let texture = self
+                            .textures
+                            .get(tex_id.id())
+                            .ok_or(RendererError::BadTextureId(tex_id.id()))?
+                            .view();
+
+                        let start = cmd_params.idx_offset;
+                        let Some(indices) = idx.get(start..start + count) else {
+                            continue;
+                        };
+                        for tri in indices.chunks_exact(3) {
+                            let vertex = |i: u16| {
+                                let v = vtx.get(cmd_params.vtx_offset + usize::from(i))?;
+                                Some(Vertex {
+                                    pos: to_target(v.pos),
+                                    uv: v.uv,
+                                    color: v.rgba().map(|c| f32::from(c) / 255.0),
+                                })
+                            };
+                            let (Some(a), Some(b), Some(c)) =
+                                (vertex(tri[0]), vertex(tri[1]), vertex(tri[2]))
+                            else {
+                                continue;
+                            };
+                            raster::draw_triangle(&mut target, clip, [a, b, c], texture, filter);
+                        }
+                    }
+                    DrawCmd::ResetRenderState | DrawCmd::SetSamplerLinear => {
+                        filter = Filter::Linear;
+                    }
+                    DrawCmd::SetSamplerNearest => filter = Filter::Nearest,
+                    DrawCmd::RawCallback { .. } => {
+                        log::warn!(
+                            target: "dear-imgui-tiny-skia",
+                            "Raw callbacks are not supported by the tiny-skia renderer"
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Copy a pixmap into a `softbuffer` buffer (`0x00RRGGBB` per pixel), as if composited over
+/// black.
+///
+/// `dst` is usually `&mut softbuffer::Buffer`, which derefs to `[u32]`; it must have the
+/// pixmap's size. Extra pixels on either side are left alone.
+pub fn copy_to_softbuffer(pixmap: &PixmapRef<'_>, dst: &mut [u32]) {
+    for (dst, px) in dst.iter_mut().zip(pixmap.data().chunks_exact(4)) {
+        // Premultiplied color over black is the premultiplied color itself.
+        *dst = (u32::from(px[0]) << 16) | (u32::from(px[1]) << 8) | u32::from(px[2]);
+    }
+}
+
+fn resolve_effective_texture_id(
+    legacy: TextureId,
+    raw_cmd: *const dear_imgui_rs::sys::ImDrawCmd,
+) -> TextureId {
+    if raw_cmd.is_null() {
+        return legacy;
+    }
+    unsafe {
+        let mut copy = *raw_cmd;
+        TextureId::from(dear_imgui_rs::sys::ImDrawCmd_GetTexID(&mut copy))
+    }
+}
+
+// Marker callbacks: Dear ImGui compares draw command callbacks against these pointers and the
+// renderer turns them into `DrawCmd::ResetRenderState` / `SetSampler*`.
+unsafe extern "C" fn draw_callback_reset_render_state(
+    _parent_list: *const dear_imgui_rs::sys::ImDrawList,
+    _cmd: *const dear_imgui_rs::sys::ImDrawCmd,
+) {
+}
+
+unsafe extern "C" fn draw_callback_set_sampler_linear(
+    _parent_list: *const dear_imgui_rs::sys::ImDrawList,
+    _cmd: *const dear_imgui_rs::sys::ImDrawCmd,
+) {
+}
+
+unsafe extern "C" fn draw_callback_set_sampler_nearest(
+    _parent_list: *const dear_imgui_rs::sys::ImDrawList,
+    _cmd: *const dear_imgui_rs::sys::ImDrawCmd,
+) {
+}
@@ -0,0 +1,206 @@
+//! Texture management for the tiny-skia renderer.
+//!
+//! Textures live in CPU memory as straight RGBA8 pixels. Dear ImGui managed textures
+//! (`ImTextureData`) and user-registered pixel buffers share one id space.
+
+use std::collections::HashMap;
+
+use dear_imgui_rs::{
+    TextureData, TextureFormat, TextureId, TextureStatus, TextureUpdate, TextureUpdateResult,
+};
+
+use crate::raster::TextureView;
+use crate::renderer::TinySkiaRenderer;
+use crate::{RendererError, RendererResult};
+
+pub(crate) struct SoftTexture {
+    width: u32,
+    height: u32,
+    rgba: Vec<u8>,
+}
+
+impl SoftTexture {
+    pub(crate) fn view(&self) -> TextureView<'_> {
+        TextureView {
+            rgba: &self.rgba,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct TextureRegistry {
+    textures: HashMap<u64, SoftTexture>,
+    next_id: u64,
+}
+
+impl TextureRegistry {
+    pub(crate) fn get(&self, id: u64) -> Option<&SoftTexture> {
+        self.textures.get(&id)
+    }
+
+    fn allocate_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+impl TinySkiaRenderer {
+    /// Register straight (not premultiplied) RGBA8 pixels as a texture for draw commands
+    /// (e.g. `ui.image()`).
+    pub fn register_texture(
+        &mut self,
+        width: u32,
+        height: u32,
+        rgba: Vec<u8>,
+    ) -> RendererResult<TextureId> {
+        let expected = (width as usize) * (height as usize) * 4;
+        if width == 0 || height == 0 || rgba.len() != expected {
+            return Err(RendererError::InvalidTextureData {
+                width,
+                height,
+                len: rgba.len(),
+                expected,
+            });
+        }
+        let id = self.textures.allocate_id();
+        self.textures.textures.insert(
+            id,
+            SoftTexture {
+                width,
+                height,
+                rgba,
+            },
+        );
+        Ok(TextureId::from(id))
+    }
+
+    /// Unregister a texture id.
+    pub fn unregister_texture(&mut self, texture_id: TextureId) {
+        self.textures.textures.remove(&texture_id.id());
+    }
+
+    /// Update a single texture manually.
+    ///
+    /// Use this for user-created `ImTextureData` that isn't in Dear ImGui's texture list (so
+    /// [`render`](Self::render) doesn't see it), and apply the result with
+    /// [`TextureUpdateResult::apply_to`] before the texture is drawn.
+    pub fn update_texture(&mut self, texture_data: &TextureData) -> TextureUpdateResult {
+        let id = texture_data.tex_id().id();
+        match texture_data.status() {
+            TextureStatus::WantCreate => self.create_texture(texture_data),
+            TextureStatus::WantUpdates => {
+                let Some(existing) = self.textures.textures.get_mut(&id) else {
+                    // Not created yet: treat updates as a full create.
+                    return self.create_texture(texture_data);
+                };
+                if existing.width != texture_data.width()
+                    || existing.height != texture_data.height()
+                {
+                    return self.create_texture(texture_data);
+                }
+                // Copying the whole texture is cheap next to rasterizing, and keeps the update
+                // independent of which rectangles Dear ImGui reports.
+                match texture_data_to_rgba(texture_data) {
+                    Some(rgba) => {
+                        existing.rgba = rgba;
+                        TextureUpdateResult::Updated
+                    }
+                    None => TextureUpdateResult::Failed,
+                }
+            }
+            TextureStatus::WantDestroy => {
+                self.textures.textures.remove(&id);
+                TextureUpdateResult::Destroyed
+            }
+            TextureStatus::OK | TextureStatus::Destroyed => TextureUpdateResult::NoAction,
+        }
+    }
+
+    fn create_texture(&mut self, texture_data: &TextureData) -> TextureUpdateResult {
+        let Some(rgba) = texture_data_to_rgba(texture_data) else {
+            return TextureUpdateResult::Failed;
+        };
+        // Reuse the id when Dear ImGui recreates a texture it already has (e.g. an atlas resize).
+        let current = texture_data.tex_id().id();
+        let id = if current != 0 && self.textures.textures.contains_key(&current) {
+            current
+        } else {
+            self.textures.allocate_id()
+        };
+        self.textures.textures.insert(
+            id,
+            SoftTexture {
+                width: texture_data.width(),
+                height: texture_data.height(),
+                rgba,
+            },
+        );
+        TextureUpdateResult::Created {
+            texture_id: TextureId::from(id),
+        }
+    }
+
+    /// Handle the create/update/destroy requests of Dear ImGui managed textures.
+    pub(crate) fn process_texture_requests(
+        &mut self,
+        draw_data: &mut dear_imgui_rs::render::DrawData,
+    ) {
+        let mut textures = draw_data.textures_mut();
+        while let Some(mut td) = textures.next() {
+            if matches!(td.status(), TextureStatus::OK | TextureStatus::Destroyed) {
+                continue;
+            }
+            let result = self.update_texture(&td);
+            result.apply_to(&mut td);
+        }
+    }
+}
+
+impl TextureUpdate for TinySkiaRenderer {
+    type Error = std::convert::Infallible;
+
+    fn update_texture_data(
+        &mut self,
+        texture_data: &mut TextureData,
+    ) -> Result<(), std::convert::Infallible> {
+        self.update_texture(texture_data).apply_to(texture_data);
+        Ok(())
+    }
+}
+
+/// Convert texture data to straight RGBA8; `None` if there are no pixels.
+fn texture_data_to_rgba(td: &TextureData) -> Option<Vec<u8>> {
+    let pixels = td.pixels()?;
+    let texel_count = (td.width() as usize).checked_mul(td.height() as usize)?;
+    if texel_count == 0 {
+        return None;
+    }
+    match td.format() {
+        TextureFormat::RGBA32 => pixels.get(..texel_count * 4).map(<[u8]>::to_vec),
+        TextureFormat::Alpha8 => Some(
+            pixels
+                .get(..texel_count)?
+                .iter()
+                .flat_map(|&a| [255, 255, 255, a])
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha8_texels_expand_to_white_rgba() {
+        let mut tex = TextureData::new();
+        tex.create(TextureFormat::Alpha8, 2, 1);
+        tex.set_data(&[0, 128]);
+        assert_eq!(
+            texture_data_to_rgba(&tex),
+            Some(vec![255, 255, 255, 0, 255, 255, 255, 128])
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use dear_imgui_rs::{Condition, Context};
+use dear_imgui_tiny_skia::TinySkiaRenderer;
+use dear_imgui_tiny_skia::tiny_skia::{Color, Pixmap};
+
+#[test]
+fn renders_a_window_deterministically() {
+    let mut ctx = Context::create();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+    ctx.io_mut().set_display_size([160.0, 120.0]);
+    ctx.io_mut().set_delta_time(1.0 / 60.0);
+    let mut renderer = TinySkiaRenderer::new(&mut ctx);
+
+    let mut frame = |ctx: &mut Context| {
+        let ui = ctx.frame();
+        ui.window("Test")
+            .position([10.0, 10.0], Condition::Always)
+            .size([100.0, 80.0], Condition::Always)
+            .build(|| ui.text("Hello"));
+        let mut pixmap = Pixmap::new(160, 120).unwrap();
+        pixmap.fill(Color::BLACK);
+        renderer
+            .render(ctx.render(), &mut pixmap.as_mut())
+            .expect("render");
+        pixmap
+    };
+
+    // The first frame creates the font atlas texture; compare the two frames after it.
+    frame(&mut ctx);
+    let a = frame(&mut ctx);
+    let b = frame(&mut ctx);
+    assert_eq!(a.data(), b.data());
+
+    let lit = |x: u32, y: u32| a.pixel(x, y).unwrap().red() > 0;
+    assert!(lit(50, 50), "window background is drawn");
+    assert!(!lit(5, 5), "outside the window stays black");
+    assert!(!lit(150, 110));
+}
@@ -77,3 +77,15 @@ impl WgpuRenderer {
         }
     }
 }
+
+impl dear_imgui_rs::TextureUpdate for WgpuRenderer {
+    type Error = RendererError;
+
+    fn update_texture_data(
+        &mut self,
+        texture_data: &mut dear_imgui_rs::TextureData,
+    ) -> RendererResult<()> {
+        self.update_texture(texture_data)?.apply_to(texture_data);
+        Ok(())
+    }
+}
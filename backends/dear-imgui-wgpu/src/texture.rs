@@ -17,7 +17,7 @@ use dear_imgui_rs::{TextureData, TextureFormat as ImGuiTextureFormat, TextureId,
 use std::collections::HashMap;
 use wgpu::*;
 
+pub use dear_imgui_rs::TextureUpdateResult;
 pub use resource::WgpuTexture;
-pub use result::TextureUpdateResult;
 
 pub use manager::WgpuTextureManager;
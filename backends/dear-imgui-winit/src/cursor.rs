@@ -4,7 +4,7 @@
 //! unnecessary system calls when changing cursor appearance.
 
 use dear_imgui_rs::MouseCursor;
-use winit::window::{CursorIcon as WinitCursor, Window};
+use winit::window::{Cursor, CursorIcon as WinitCursor, CustomCursor, Window};
 
 /// Cursor settings cache to avoid unnecessary cursor changes
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -14,12 +14,12 @@ pub struct CursorSettings {
 }
 
 impl CursorSettings {
-    /// Apply cursor settings to the window
-    pub fn apply(&self, window: &Window) {
+    /// Apply cursor settings to the window, preferring a custom image registered for the cursor
+    pub fn apply(&self, window: &Window, custom: &[(MouseCursor, CustomCursor)]) {
         match self.cursor {
             Some(mouse_cursor) if !self.draw_cursor => {
                 window.set_cursor_visible(true);
-                window.set_cursor(to_winit_cursor(mouse_cursor));
+                window.set_cursor(resolve_cursor(mouse_cursor, custom));
             }
             _ => {
                 window.set_cursor_visible(!self.draw_cursor);
@@ -28,6 +28,13 @@ impl CursorSettings {
     }
 }
 
+fn resolve_cursor(cursor: MouseCursor, custom: &[(MouseCursor, CustomCursor)]) -> Cursor {
+    match custom.iter().find(|(c, _)| *c == cursor) {
+        Some((_, image)) => Cursor::Custom(image.clone()),
+        None => Cursor::Icon(to_winit_cursor(cursor)),
+    }
+}
+
 /// Convert Dear ImGui mouse cursor to winit cursor
 pub fn to_winit_cursor(cursor: MouseCursor) -> WinitCursor {
     match cursor {
@@ -78,6 +85,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cursor_without_custom_image_uses_icon() {
+        assert_eq!(
+            resolve_cursor(MouseCursor::ResizeAll, &[]),
+            Cursor::Icon(WinitCursor::Move)
+        );
+    }
+
     #[test]
     fn test_cursor_settings_equality() {
         let settings1 = CursorSettings {
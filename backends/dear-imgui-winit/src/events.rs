@@ -5,9 +5,8 @@
 
 use dear_imgui_rs::Context;
 use dear_imgui_rs::input::MouseSource;
-use winit::event::{DeviceEvent, ElementState, Ime, KeyEvent, MouseScrollDelta, TouchPhase};
+use winit::event::{DeviceEvent, ElementState, Force, Ime, KeyEvent, MouseScrollDelta, TouchPhase};
 
-use std::cell::RefCell;
 use winit::window::Window;
 
 use crate::input::{to_imgui_mouse_button, winit_key_to_imgui_key};
@@ -151,73 +150,128 @@ pub fn handle_ime_event(ime: &Ime, imgui_ctx: &mut Context) {
     }
 }
 
-/// Handle touch events by converting them to mouse events
-pub fn handle_touch_event(touch: &winit::event::Touch, _window: &Window, _imgui_ctx: &mut Context) {
-    thread_local! {
-        static ACTIVE_TOUCH: RefCell<Option<u64>> = const { RefCell::new(None) };
+/// Touch contacts currently down, mapped onto Dear ImGui's single pointer.
+///
+/// The first contact drives the pointer. Further contacts are remembered so that, when the
+/// primary one lifts, the pointer falls back to the oldest contact still down instead of
+/// freezing until every finger is lifted.
+#[derive(Debug, Default)]
+pub(crate) struct TouchState {
+    primary: Option<u64>,
+    /// Whether the primary contact holds the left button (not the case after a fallback).
+    pressed: bool,
+    /// Normalized force of the primary contact, when the device reports one.
+    pressure: Option<f32>,
+    /// Other contacts, oldest first, with their last logical position.
+    others: Vec<(u64, [f32; 2])>,
+}
+
+impl TouchState {
+    /// Pressure of the contact driving the pointer, in `0.0..=1.0`.
+    pub(crate) fn pressure(&self) -> Option<f32> {
+        self.primary.and(self.pressure)
     }
+}
 
-    // Convert touch events to mouse events for basic touch support
-    ACTIVE_TOUCH.with(|active| {
-        let active_id = *active.borrow();
-        let id = touch.id;
-        match touch.phase {
-            TouchPhase::Started => {
-                if active_id.is_none() {
-                    let pos = touch
-                        .location
-                        .to_logical::<f64>(sanitize::positive_finite_or(
-                            _window.scale_factor(),
-                            1.0,
-                        ));
-                    let Some(pos) = sanitize::finite_position(pos) else {
-                        return;
-                    };
-
-                    // Capture this touch as the active pointer
-                    *active.borrow_mut() = Some(id);
-                    let io = _imgui_ctx.io_mut();
-                    // Map touch to a touchscreen mouse source.
-                    io.add_mouse_source_event(MouseSource::TouchScreen);
-                    io.add_mouse_pos_event(pos);
-                    io.add_mouse_button_event(dear_imgui_rs::input::MouseButton::Left, true);
-                }
+/// Handle touch events by converting them to mouse events
+pub fn handle_touch_event(
+    state: &mut TouchState,
+    touch: &winit::event::Touch,
+    window: &Window,
+    imgui_ctx: &mut Context,
+) {
+    let pos = touch
+        .location
+        .to_logical::<f64>(sanitize::positive_finite_or(window.scale_factor(), 1.0));
+    apply_touch(
+        state,
+        touch.id,
+        touch.phase,
+        sanitize::finite_position(pos),
+        touch.force,
+        imgui_ctx,
+    );
+}
+
+fn apply_touch(
+    state: &mut TouchState,
+    id: u64,
+    phase: TouchPhase,
+    pos: Option<[f32; 2]>,
+    force: Option<Force>,
+    imgui_ctx: &mut Context,
+) {
+    let io = imgui_ctx.io_mut();
+    let is_primary = state.primary == Some(id);
+    match phase {
+        TouchPhase::Started => {
+            let Some(pos) = pos else {
+                return;
+            };
+            if state.primary.is_none() {
+                state.primary = Some(id);
+                state.pressed = true;
+                state.pressure = touch_pressure(force);
+                io.add_mouse_source_event(touch_source(force));
+                io.add_mouse_pos_event(pos);
+                io.add_mouse_button_event(dear_imgui_rs::input::MouseButton::Left, true);
+            } else {
+                state.others.push((id, pos));
             }
-            TouchPhase::Moved => {
-                if active_id == Some(id) {
-                    let pos = touch
-                        .location
-                        .to_logical::<f64>(sanitize::positive_finite_or(
-                            _window.scale_factor(),
-                            1.0,
-                        ));
-                    let Some(pos) = sanitize::finite_position(pos) else {
-                        return;
-                    };
-                    let io = _imgui_ctx.io_mut();
-                    io.add_mouse_source_event(MouseSource::TouchScreen);
-                    io.add_mouse_pos_event(pos);
-                }
+        }
+        TouchPhase::Moved if is_primary => {
+            state.pressure = touch_pressure(force).or(state.pressure);
+            if let Some(pos) = pos {
+                io.add_mouse_source_event(touch_source(force));
+                io.add_mouse_pos_event(pos);
             }
-            TouchPhase::Ended | TouchPhase::Cancelled => {
-                if active_id == Some(id) {
-                    let pos = touch
-                        .location
-                        .to_logical::<f64>(sanitize::positive_finite_or(
-                            _window.scale_factor(),
-                            1.0,
-                        ));
-                    let io = _imgui_ctx.io_mut();
-                    io.add_mouse_source_event(MouseSource::TouchScreen);
-                    if let Some(pos) = sanitize::finite_position(pos) {
-                        io.add_mouse_pos_event(pos);
-                    }
-                    io.add_mouse_button_event(dear_imgui_rs::input::MouseButton::Left, false);
-                    *active.borrow_mut() = None;
-                }
+        }
+        TouchPhase::Moved => {
+            if let (Some(pos), Some(other)) =
+                (pos, state.others.iter_mut().find(|(other, _)| *other == id))
+            {
+                other.1 = pos;
+            }
+        }
+        TouchPhase::Ended | TouchPhase::Cancelled if is_primary => {
+            io.add_mouse_source_event(touch_source(force));
+            if let Some(pos) = pos {
+                io.add_mouse_pos_event(pos);
             }
+            if state.pressed {
+                io.add_mouse_button_event(dear_imgui_rs::input::MouseButton::Left, false);
+            }
+            // Hand the pointer to the oldest remaining contact without pressing again, so a
+            // lifted first finger does not turn the next one into a click.
+            state.pressed = false;
+            state.pressure = None;
+            state.primary = None;
+            if !state.others.is_empty() {
+                let (next, next_pos) = state.others.remove(0);
+                state.primary = Some(next);
+                io.add_mouse_pos_event(next_pos);
+            }
+        }
+        TouchPhase::Ended | TouchPhase::Cancelled => {
+            state.others.retain(|(other, _)| *other != id);
         }
-    });
+    }
+}
+
+/// Pens report an altitude angle with their force; fingers do not.
+fn touch_source(force: Option<Force>) -> MouseSource {
+    match force {
+        Some(Force::Calibrated {
+            altitude_angle: Some(_),
+            ..
+        }) => MouseSource::Pen,
+        _ => MouseSource::TouchScreen,
+    }
+}
+
+fn touch_pressure(force: Option<Force>) -> Option<f32> {
+    let pressure = sanitize::finite_f64_to_f32(force?.normalized())?;
+    Some(pressure.clamp(0.0, 1.0))
 }
 
 /// Handle device events (raw input events)
@@ -329,4 +383,72 @@ mod tests {
         assert!(!ui.io().key_shift());
         assert!(!ui.io().key_alt());
     }
+
+    #[test]
+    fn test_touch_falls_back_to_remaining_contact_without_clicking() {
+        let _guard = lock_context();
+        let mut ctx = Context::create();
+        let io = ctx.io_mut();
+        io.set_display_size([100.0, 100.0]);
+        io.set_backend_flags(
+            io.backend_flags() | dear_imgui_rs::BackendFlags::RENDERER_HAS_TEXTURES,
+        );
+        ctx.fonts().build();
+
+        let mut state = TouchState::default();
+        apply_touch(
+            &mut state,
+            1,
+            TouchPhase::Started,
+            Some([10.0, 10.0]),
+            None,
+            &mut ctx,
+        );
+        apply_touch(
+            &mut state,
+            2,
+            TouchPhase::Started,
+            Some([50.0, 50.0]),
+            None,
+            &mut ctx,
+        );
+        apply_touch(
+            &mut state,
+            2,
+            TouchPhase::Moved,
+            Some([60.0, 40.0]),
+            None,
+            &mut ctx,
+        );
+        apply_touch(
+            &mut state,
+            1,
+            TouchPhase::Ended,
+            Some([10.0, 10.0]),
+            None,
+            &mut ctx,
+        );
+        assert_eq!(state.primary, Some(2));
+        assert!(!state.pressed);
+
+        let ui = ctx.frame();
+        assert_eq!(ui.mouse_pos(), [60.0, 40.0]);
+        assert!(!ui.is_mouse_down(dear_imgui_rs::input::MouseButton::Left));
+    }
+
+    #[test]
+    fn test_pen_force_reports_source_and_pressure() {
+        let pen = Force::Calibrated {
+            force: 1.5,
+            max_possible_force: 3.0,
+            altitude_angle: Some(1.0),
+        };
+        assert_eq!(touch_source(Some(pen)), MouseSource::Pen);
+        assert_eq!(touch_pressure(Some(pen)), Some(0.5));
+
+        let finger = Force::Normalized(2.0);
+        assert_eq!(touch_source(Some(finger)), MouseSource::TouchScreen);
+        assert_eq!(touch_pressure(Some(finger)), Some(1.0));
+        assert_eq!(touch_pressure(None), None);
+    }
 }
@@ -9,6 +9,7 @@
 //! - **Basic Platform Support**: Window events, input handling, cursor management
 //! - **Multi-Viewport Support**: Create and manage multiple OS windows (requires `multi-viewport` feature)
 //! - **DPI Awareness**: Proper handling of high-DPI displays
+//! - **Web**: Browser clipboard and canvas sizing helpers on `wasm32` (see [`web`])
 //!
 //! # Example - Basic Usage
 //!
@@ -54,6 +55,8 @@ mod platform;
 mod sanitize;
 #[cfg(test)]
 mod test_util;
+#[cfg(target_arch = "wasm32")]
+pub mod web;
 
 // Re-export main types
-pub use platform::{HiDpiMode, WinitPlatform};
+pub use platform::{HiDpiMode, ImePreedit, WinitPlatform};
@@ -72,6 +72,19 @@ pub fn init_multi_viewport_support(ctx: &mut Context, main_window: &Window) {
     }
 }
 
+/// Re-enumerate monitors into `PlatformIO.Monitors`.
+///
+/// winit has no monitor hot-plug notification, so the list built by
+/// [`init_multi_viewport_support`] goes stale when displays are added, removed or rescaled.
+/// [`handle_event_with_multi_viewport`] calls this on `ScaleFactorChanged`; call it yourself
+/// after other changes you detect.
+pub fn update_monitors(ctx: &mut Context, window: &Window) {
+    let _context_guard = unsafe { CurrentContextGuard::bind(ctx.as_raw()) };
+    unsafe {
+        setup_monitors_with_window(window, ctx);
+    }
+}
+
 /// Shutdown multi-viewport support for `ctx`.
 pub fn shutdown_multi_viewport_support(ctx: &mut Context) {
     // Clean up any remaining viewports
@@ -175,6 +175,15 @@ pub fn handle_event_with_multi_viewport<T>(
 ) -> bool {
     let mut consumed = false;
 
+    // A DPI change usually means a window crossed monitors or a display was reconfigured.
+    if let Event::WindowEvent {
+        event: WindowEvent::ScaleFactorChanged { .. },
+        ..
+    } = event
+    {
+        super::update_monitors(imgui_ctx, main_window);
+    }
+
     // Forward events that target the main window through the standard platform handler
     if let Event::WindowEvent { window_id, .. } = event {
         if *window_id == main_window.id() {
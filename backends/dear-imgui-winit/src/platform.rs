@@ -5,10 +5,10 @@
 
 use std::ffi::c_void;
 
-use dear_imgui_rs::{BackendFlags, ConfigFlags, Context};
+use dear_imgui_rs::{BackendFlags, ConfigFlags, Context, DpiManager, Style};
 use winit::dpi::{LogicalPosition, LogicalSize};
-use winit::event::{Event, WindowEvent};
-use winit::window::{Window, WindowAttributes};
+use winit::event::{ElementState, Event, WindowEvent};
+use winit::window::{CustomCursor, Window, WindowAttributes};
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant;
@@ -136,14 +136,28 @@ pub enum HiDpiMode {
     Rounded,
 }
 
+/// Text being composed by the IME, before it is committed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImePreedit {
+    pub text: String,
+    /// Byte range of the IME cursor or selection within `text`, if the IME reports one.
+    pub cursor: Option<(usize, usize)>,
+}
+
 /// Main platform backend for Dear ImGui with winit integration
 pub struct WinitPlatform {
     hidpi_mode: HiDpiMode,
     hidpi_factor: f64,
+    dpi_style_scaling: bool,
+    /// Unscaled base style and applied scale of DPI style scaling, created on first use.
+    dpi: Option<DpiManager>,
     cursor_cache: Option<CursorSettings>,
+    custom_cursors: Vec<(dear_imgui_rs::MouseCursor, CustomCursor)>,
     ime_enabled: bool,
     ime_auto_manage: bool,
+    ime_preedit: Option<ImePreedit>,
     last_frame: Instant,
+    touch: events::TouchState,
 }
 
 impl WinitPlatform {
@@ -193,10 +207,15 @@ impl WinitPlatform {
         Self {
             hidpi_mode: HiDpiMode::default(),
             hidpi_factor: 1.0,
+            dpi_style_scaling: false,
+            dpi: None,
             cursor_cache: None,
+            custom_cursors: Vec::new(),
             ime_enabled: false,
             ime_auto_manage: true,
+            ime_preedit: None,
             last_frame: Instant::now(),
+            touch: events::TouchState::default(),
         }
     }
 
@@ -205,6 +224,33 @@ impl WinitPlatform {
         self.hidpi_mode = hidpi_mode;
     }
 
+    /// Keep `Style` sizes and `Style::font_scale_dpi` in step with the monitor DPI.
+    ///
+    /// Dear ImGui coordinates absorb the factor chosen by [`HiDpiMode`]; this scales the style by
+    /// whatever that factor leaves out (winit's scale factor divided by it) on
+    /// [`attach_window`](Self::attach_window) and every `ScaleFactorChanged`. With
+    /// `HiDpiMode::Default` that is always 1; with `Locked(1.0)` on a 2x display, widgets and
+    /// text are doubled so they keep their physical size.
+    ///
+    /// The style found when scaling is first applied is kept as the unscaled base, and every scale
+    /// is derived from it (see [`DpiManager`]), so sizes do not drift over monitor changes. Change
+    /// the style afterwards through [`set_dpi_base_style`](Self::set_dpi_base_style), or it is
+    /// overwritten on the next scale change.
+    ///
+    /// Off by default. Disabling it stops tracking but leaves the current style as is.
+    pub fn set_dpi_style_scaling(&mut self, enabled: bool) {
+        self.dpi_style_scaling = enabled;
+    }
+
+    /// Replace the unscaled base style of DPI style scaling, e.g. after switching themes, and
+    /// apply the current scale to it.
+    pub fn set_dpi_base_style(&mut self, style: Style, imgui_ctx: &mut Context) {
+        match self.dpi.as_mut() {
+            Some(dpi) => dpi.set_base_style(imgui_ctx, style),
+            None => *imgui_ctx.style_mut() = style,
+        }
+    }
+
     /// Enable or disable IME events for the attached window.
     ///
     /// Winit does not deliver `WindowEvent::Ime` events unless IME is explicitly
@@ -225,6 +271,15 @@ impl WinitPlatform {
         self.ime_enabled
     }
 
+    /// The IME composition in progress, if any.
+    ///
+    /// Dear ImGui has no preedit display, and most IMEs draw the composition in their own
+    /// candidate window at the caret. Applications whose IME does not can draw this text
+    /// themselves. Keyboard events are not forwarded to Dear ImGui while it is `Some`.
+    pub fn ime_preedit(&self) -> Option<&ImePreedit> {
+        self.ime_preedit.as_ref()
+    }
+
     /// Enable or disable automatic IME management.
     ///
     /// When enabled (default), the backend will call `set_ime_allowed` based on
@@ -240,6 +295,14 @@ impl WinitPlatform {
         self.hidpi_factor
     }
 
+    /// Pressure of the touch or pen contact currently driving the mouse, in `0.0..=1.0`.
+    ///
+    /// Dear ImGui has no pressure input, so drawing tools can read it here. Returns `None`
+    /// when no contact is down or the device does not report force.
+    pub fn pointer_pressure(&self) -> Option<f32> {
+        self.touch.pressure()
+    }
+
     /// Attach the platform to a window
     pub fn attach_window(
         &mut self,
@@ -259,6 +322,7 @@ impl WinitPlatform {
 
         io.set_display_size(sanitize::finite_non_negative_size(logical_size));
         io.set_display_framebuffer_scale(sanitize::framebuffer_scale(self.hidpi_factor, 1.0));
+        self.update_style_scale(window.scale_factor(), imgui_ctx);
 
         // Enable IME by default so WindowEvent::Ime events and IME composition
         // are available on desktop platforms. Auto-management (when enabled)
@@ -384,8 +448,17 @@ impl WinitPlatform {
                     self.hidpi_factor,
                     1.0,
                 ));
+                self.update_style_scale(*scale_factor, imgui_ctx);
                 false
             }
+            // The IME owns keys (Enter, Backspace, arrows) while composing; forwarding them
+            // would also edit the widget's text under the composition. Releases are still
+            // forwarded so keys pressed before composition started do not stay down.
+            WindowEvent::KeyboardInput { event, .. }
+                if self.ime_preedit.is_some() && event.state == ElementState::Pressed =>
+            {
+                true
+            }
             WindowEvent::KeyboardInput { event, .. } => {
                 events::handle_keyboard_input(event, imgui_ctx)
             }
@@ -448,10 +521,19 @@ impl WinitPlatform {
                 events::handle_ime_event(ime, imgui_ctx);
                 // Track IME enabled/disabled state based on winit notifications.
                 self.ime_enabled = !matches!(ime, winit::event::Ime::Disabled);
+                self.ime_preedit = match ime {
+                    winit::event::Ime::Preedit(text, cursor) if !text.is_empty() => {
+                        Some(ImePreedit {
+                            text: text.clone(),
+                            cursor: *cursor,
+                        })
+                    }
+                    _ => None,
+                };
                 imgui_ctx.io().want_capture_keyboard()
             }
             WindowEvent::Touch(touch) => {
-                events::handle_touch_event(touch, window, imgui_ctx);
+                events::handle_touch_event(&mut self.touch, touch, window, imgui_ctx);
                 imgui_ctx.io().want_capture_mouse()
             }
             WindowEvent::Focused(focused) => events::handle_focused(*focused, imgui_ctx),
@@ -487,6 +569,7 @@ impl WinitPlatform {
                 let io = imgui_ctx.io_mut();
                 io.set_display_size(sanitize::finite_non_negative_size(logical_size));
                 io.set_display_framebuffer_scale(sanitize::framebuffer_scale(hidpi, 1.0));
+                self.update_style_scale(winit_scale, imgui_ctx);
             }
         }
 
@@ -521,6 +604,24 @@ impl WinitPlatform {
         self.cursor_cache = None;
     }
 
+    /// Use a custom image for one Dear ImGui cursor shape, or restore the system icon with `None`.
+    ///
+    /// Create the image through winit, e.g.
+    /// `event_loop.create_custom_cursor(CustomCursor::from_rgba(rgba, w, h, hot_x, hot_y)?)`.
+    /// Shapes without a custom image keep their [`CursorIcon`](winit::window::CursorIcon)
+    /// mapping. Has no effect while Dear ImGui draws the cursor itself.
+    pub fn set_custom_cursor(
+        &mut self,
+        cursor: dear_imgui_rs::MouseCursor,
+        image: Option<CustomCursor>,
+    ) {
+        self.custom_cursors.retain(|(c, _)| *c != cursor);
+        if let Some(image) = image {
+            self.custom_cursors.push((cursor, image));
+        }
+        self.cursor_cache = None;
+    }
+
     /// Update cursor given a Ui reference (preferred, matches upstream)
     pub fn prepare_render_with_ui(&mut self, ui: &dear_imgui_rs::Ui, window: &Window) {
         // Auto-manage IME allowed state based on Dear ImGui's intent. This lets
@@ -543,13 +644,12 @@ impl WinitPlatform {
             .config_flags()
             .contains(ConfigFlags::NO_MOUSE_CURSOR_CHANGE)
         {
-            // Our Io wrapper does not currently expose MouseDrawCursor, assume false (OS cursor)
             let cursor = CursorSettings {
                 cursor: ui.mouse_cursor(),
                 draw_cursor: ui.io().mouse_draw_cursor(),
             };
             if self.cursor_cache != Some(cursor) {
-                cursor.apply(window);
+                cursor.apply(window, &self.custom_cursors);
                 self.cursor_cache = Some(cursor);
             }
         }
@@ -611,6 +711,19 @@ impl WinitPlatform {
         }
     }
 
+    fn update_style_scale(&mut self, winit_scale: f64, imgui_ctx: &mut Context) {
+        if !self.dpi_style_scaling {
+            return;
+        }
+        let target = style_scale_for(winit_scale, self.hidpi_factor) as f32;
+        if !target.is_finite() || target <= 0.0 {
+            return;
+        }
+        self.dpi
+            .get_or_insert_with(|| DpiManager::new(imgui_ctx))
+            .set_scale(imgui_ctx, target);
+    }
+
     /// Create window attributes with Dear ImGui defaults
     pub fn create_window_attributes() -> WindowAttributes {
         WindowAttributes::default()
@@ -619,6 +732,13 @@ impl WinitPlatform {
     }
 }
 
+/// Part of winit's scale factor that Dear ImGui coordinates do not already absorb.
+fn style_scale_for(winit_scale: f64, hidpi_factor: f64) -> f64 {
+    let winit_scale = sanitize::positive_finite_or(winit_scale, 1.0);
+    let hidpi_factor = sanitize::positive_finite_or(hidpi_factor, 1.0);
+    sanitize::positive_finite_or(winit_scale / hidpi_factor, 1.0)
+}
+
 fn rescale_mouse_pos_for_hidpi_change(
     mouse: [f32; 2],
     old_hidpi: f64,
@@ -685,6 +805,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dpi_style_scaling_applies_only_the_unabsorbed_factor() {
+        assert_eq!(style_scale_for(2.0, 2.0), 1.0);
+        assert_eq!(style_scale_for(1.5, 2.0), 0.75);
+        assert_eq!(style_scale_for(f64::NAN, 2.0), 0.5);
+
+        let _guard = lock_context();
+        let mut ctx = Context::create();
+        let mut platform = WinitPlatform::new(&mut ctx);
+        let padding = ctx.style().window_padding();
+
+        // Disabled by default.
+        platform.update_style_scale(2.0, &mut ctx);
+        assert_eq!(ctx.style().window_padding(), padding);
+
+        platform.set_dpi_style_scaling(true);
+        platform.update_style_scale(2.0, &mut ctx);
+        assert_eq!(
+            ctx.style().window_padding(),
+            [padding[0] * 2.0, padding[1] * 2.0]
+        );
+        assert_eq!(ctx.style().font_scale_dpi(), 2.0);
+
+        // Scaling is relative to what was applied before, not cumulative per event.
+        platform.update_style_scale(2.0, &mut ctx);
+        platform.update_style_scale(1.0, &mut ctx);
+        assert_eq!(ctx.style().window_padding(), padding);
+        assert_eq!(ctx.style().font_scale_dpi(), 1.0);
+    }
+
+    #[test]
+    fn dpi_style_scaling_round_trips_non_integer_scales() {
+        let _guard = lock_context();
+        let mut ctx = Context::create();
+        let mut platform = WinitPlatform::new(&mut ctx);
+        platform.set_dpi_style_scaling(true);
+        let base = ctx.style().clone();
+
+        // 1.5x truncates odd sizes (e.g. a frame padding of 3 becomes 4), which must not leak
+        // into the sizes restored at 1x.
+        for _ in 0..3 {
+            platform.update_style_scale(1.5, &mut ctx);
+            platform.update_style_scale(1.0, &mut ctx);
+        }
+        assert_eq!(ctx.style().frame_padding(), base.frame_padding());
+        assert_eq!(ctx.style().window_padding(), base.window_padding());
+        assert_eq!(ctx.style().scrollbar_size(), base.scrollbar_size());
+        assert_eq!(ctx.style().font_scale_dpi(), base.font_scale_dpi());
+    }
+
     #[test]
     fn test_ime_callback_ownership_detection() {
         unsafe extern "C" fn other_ime_callback(
@@ -0,0 +1,114 @@
+//! Browser helpers for `wasm32` targets
+//!
+//! winit's web backend delivers input and resize events like on desktop, but the clipboard and
+//! the canvas backing store need browser APIs. See the crate README for a WebGL2 setup using
+//! `dear-imgui-glow`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dear_imgui_rs::ClipboardBackend;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+use winit::platform::web::WindowExtWebSys;
+use winit::window::Window;
+
+/// Clipboard backend using the browser's Clipboard API
+///
+/// Copied text is written with `navigator.clipboard.writeText`. Browsers only allow reading the
+/// clipboard asynchronously (and behind a permission prompt), so pasting returns the text of the
+/// document's latest `paste` event, or the last copied text before any paste happened.
+///
+/// Browsers only fire `paste` for a shortcut whose `keydown` was not cancelled: create the window
+/// with `WindowAttributesExtWebSys::with_prevent_default(false)` for Ctrl/Cmd+V to reach Dear
+/// ImGui with the pasted text.
+///
+/// ```rust,ignore
+/// imgui.set_clipboard_backend(dear_imgui_winit::web::WebClipboard::new());
+/// ```
+pub struct WebClipboard {
+    text: Rc<RefCell<Option<String>>>,
+    document: Option<web_sys::Document>,
+    on_paste: Option<Closure<dyn FnMut(web_sys::ClipboardEvent)>>,
+}
+
+impl WebClipboard {
+    /// Create the backend and start listening for `paste` events on the document
+    pub fn new() -> Self {
+        let text = Rc::new(RefCell::new(None));
+        let document = web_sys::window().and_then(|window| window.document());
+        let on_paste = document.as_ref().map(|document| {
+            let text = Rc::clone(&text);
+            let on_paste = Closure::<dyn FnMut(web_sys::ClipboardEvent)>::new(
+                move |event: web_sys::ClipboardEvent| {
+                    if let Some(pasted) = event
+                        .clipboard_data()
+                        .and_then(|data| data.get_data("text/plain").ok())
+                    {
+                        *text.borrow_mut() = Some(pasted);
+                    }
+                },
+            );
+            let _ = document
+                .add_event_listener_with_callback("paste", on_paste.as_ref().unchecked_ref());
+            on_paste
+        });
+        Self {
+            text,
+            document,
+            on_paste,
+        }
+    }
+}
+
+impl Default for WebClipboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for WebClipboard {
+    fn drop(&mut self) {
+        if let (Some(document), Some(on_paste)) = (&self.document, &self.on_paste) {
+            let _ = document
+                .remove_event_listener_with_callback("paste", on_paste.as_ref().unchecked_ref());
+        }
+    }
+}
+
+impl ClipboardBackend for WebClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.text.borrow().clone()
+    }
+
+    fn set(&mut self, value: &str) {
+        *self.text.borrow_mut() = Some(value.to_owned());
+        if let Some(window) = web_sys::window() {
+            // The promise rejects when the page lacks focus or permission; the text stays
+            // available for pasting inside the app either way.
+            let _ = window.navigator().clipboard().write_text(value);
+        }
+    }
+}
+
+/// Match the canvas backing store to the window's physical inner size
+///
+/// WebGL renders into the canvas' `width` x `height` pixels, which must follow the CSS size times
+/// the device pixel ratio for the UI to be sharp and correctly placed. Call this on
+/// `WindowEvent::Resized` and `ScaleFactorChanged` (or before each frame). Returns `true` when the
+/// canvas was resized.
+pub fn sync_canvas_size(window: &Window) -> bool {
+    let Some(canvas) = window.canvas() else {
+        return false;
+    };
+    let size = window.inner_size();
+    if size.width == 0 || size.height == 0 {
+        return false;
+    }
+    if canvas.width() == size.width && canvas.height() == size.height {
+        return false;
+    }
+    canvas.set_width(size.width);
+    canvas.set_height(size.height);
+    true
+}
@@ -0,0 +1,166 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, SendError, Sender, TryIter};
+use std::sync::{Arc, Mutex};
+
+use winit::event_loop::EventLoopProxy;
+
+/// Wakes the runner's event loop from other threads.
+///
+/// Wake-ups are coalesced: only the first request after a frame drained its events posts to the
+/// event loop.
+#[derive(Debug, Default)]
+pub(crate) struct RunnerWaker {
+    proxy: Mutex<Option<EventLoopProxy<()>>>,
+    pending: AtomicBool,
+}
+
+impl RunnerWaker {
+    /// Attaches the running event loop, flushing wake-ups requested before it started.
+    pub(crate) fn attach(&self, proxy: EventLoopProxy<()>) {
+        let mut slot = self.proxy.lock().unwrap_or_else(|e| e.into_inner());
+        if self.pending.load(Ordering::Acquire) {
+            let _ = proxy.send_event(());
+        }
+        *slot = Some(proxy);
+    }
+
+    fn wake(&self) {
+        if self.pending.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let slot = self.proxy.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(proxy) = slot.as_ref() {
+            // Fails only once the event loop has exited.
+            let _ = proxy.send_event(());
+        }
+    }
+
+    fn clear_pending(&self) {
+        self.pending.store(false, Ordering::Release);
+    }
+}
+
+/// Sending half of [`runner_channel`]: cloneable and `Send`, for worker threads.
+///
+/// Every sent event or redraw request wakes the runner, so results of background work show up
+/// even with [`RedrawMode::Wait`](crate::RedrawMode::Wait).
+#[derive(Debug)]
+pub struct RunnerHandle<E> {
+    sender: Sender<E>,
+    waker: Arc<RunnerWaker>,
+}
+
+impl<E> Clone for RunnerHandle<E> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            waker: Arc::clone(&self.waker),
+        }
+    }
+}
+
+impl<E> RunnerHandle<E> {
+    /// Queues `event` for the next frame and wakes the runner.
+    ///
+    /// Fails, returning the event, once the receiving [`RunnerEvents`] has been dropped (usually
+    /// because the app exited).
+    pub fn send(&self, event: E) -> Result<(), SendError<E>> {
+        self.sender.send(event)?;
+        self.waker.wake();
+        Ok(())
+    }
+
+    /// Requests a frame without sending an event.
+    pub fn request_redraw(&self) {
+        self.waker.wake();
+    }
+}
+
+/// Receiving half of [`runner_channel`], handed to [`AppBuilder::on_frame_with_events`].
+///
+/// [`AppBuilder::on_frame_with_events`]: crate::AppBuilder::on_frame_with_events
+#[derive(Debug)]
+pub struct RunnerEvents<E> {
+    receiver: Receiver<E>,
+    waker: Arc<RunnerWaker>,
+}
+
+impl<E> RunnerEvents<E> {
+    /// Takes all events queued so far, in send order.
+    pub fn drain(&self) -> TryIter<'_, E> {
+        // Clear first so events sent while draining wake the runner again.
+        self.waker.clear_pending();
+        self.receiver.try_iter()
+    }
+
+    pub(crate) fn waker(&self) -> Arc<RunnerWaker> {
+        Arc::clone(&self.waker)
+    }
+}
+
+/// Creates a typed channel from background threads into the UI callback.
+///
+/// Pass the [`RunnerEvents`] to [`AppBuilder::on_frame_with_events`] and move clones of the
+/// [`RunnerHandle`] into worker threads. Before each frame the runner drains the queued events
+/// and hands them to the frame callback.
+///
+/// ```no_run
+/// use dear_app::{AppBuilder, runner_channel};
+///
+/// enum Loaded {
+///     Text(String),
+/// }
+///
+/// let (handle, events) = runner_channel::<Loaded>();
+/// std::thread::spawn(move || {
+///     let _ = handle.send(Loaded::Text("done".into()));
+/// });
+///
+/// let mut last = String::new();
+/// AppBuilder::new()
+///     .on_frame_with_events(events, move |ui, _addons, events| {
+///         for Loaded::Text(text) in events {
+///             last = text;
+///         }
+///         ui.text(&last);
+///     })
+///     .run()
+///     .unwrap();
+/// ```
+///
+/// [`AppBuilder::on_frame_with_events`]: crate::AppBuilder::on_frame_with_events
+pub fn runner_channel<E>() -> (RunnerHandle<E>, RunnerEvents<E>) {
+    let (sender, receiver) = mpsc::channel();
+    let waker = Arc::new(RunnerWaker::default());
+    (
+        RunnerHandle {
+            sender,
+            waker: Arc::clone(&waker),
+        },
+        RunnerEvents { receiver, waker },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::runner_channel;
+
+    #[test]
+    fn events_are_queued_before_the_event_loop_starts() {
+        let (handle, events) = runner_channel::<u32>();
+        let worker = handle.clone();
+        std::thread::spawn(move || {
+            worker.send(1).unwrap();
+            worker.send(2).unwrap();
+        })
+        .join()
+        .unwrap();
+        handle.request_redraw();
+
+        assert_eq!(events.drain().collect::<Vec<_>>(), [1, 2]);
+        assert!(events.drain().next().is_none());
+
+        drop(events);
+        assert_eq!(handle.send(3).unwrap_err().0, 3);
+    }
+}
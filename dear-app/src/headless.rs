@@ -0,0 +1,263 @@
+//! Offscreen runner: renders frames into a WGPU texture without creating a window.
+
+use std::collections::HashMap;
+
+use crate::{
+    AddOns, AddOnsConfig, DearAppError, FrameTargets, GpuApi, RunnerCallbacks, RunnerConfig,
+    UiState, apply_config_flags, clear_color, create_context, encode_frame, imgui, imgui_wgpu,
+    supported_sample_count,
+};
+
+/// Color format of the offscreen target (and of [`HeadlessImage::rgba`]).
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+/// Configuration for [`AppBuilder::run_headless`](crate::AppBuilder::run_headless).
+#[derive(Clone, Debug)]
+pub struct HeadlessConfig {
+    /// Framebuffer size in physical pixels (DPI scale 1).
+    pub size: (u32, u32),
+    /// Number of frames to run; at least one frame always runs.
+    pub frames: u32,
+    /// Frame delta reported to Dear ImGui, in seconds.
+    pub delta_time: f32,
+    /// Read the last frame back to the CPU.
+    pub capture: bool,
+}
+
+impl Default for HeadlessConfig {
+    fn default() -> Self {
+        Self {
+            size: (1280, 720),
+            frames: 3,
+            delta_time: 1.0 / 60.0,
+            capture: false,
+        }
+    }
+}
+
+/// Pixels of the last frame of a headless run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeadlessImage {
+    pub width: u32,
+    pub height: u32,
+    /// Tightly packed sRGB RGBA8 rows, top row first.
+    pub rgba: Vec<u8>,
+}
+
+impl HeadlessImage {
+    /// RGBA value of the pixel at `(x, y)`.
+    ///
+    /// # Panics
+    /// Panics if the coordinates are outside the image.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        assert!(
+            x < self.width && y < self.height,
+            "pixel ({x}, {y}) outside {}x{} image",
+            self.width,
+            self.height
+        );
+        let i = (y as usize * self.width as usize + x as usize) * 4;
+        [
+            self.rgba[i],
+            self.rgba[i + 1],
+            self.rgba[i + 2],
+            self.rgba[i + 3],
+        ]
+    }
+}
+
+pub(crate) fn run<F>(
+    cfg: &RunnerConfig,
+    addons: &AddOnsConfig,
+    cbs: &mut RunnerCallbacks,
+    headless: &HeadlessConfig,
+    gui: &mut F,
+) -> Result<Option<HeadlessImage>, DearAppError>
+where
+    F: FnMut(&imgui::Ui, &mut AddOns),
+{
+    let width = headless.size.0.max(1);
+    let height = headless.size.1.max(1);
+
+    let instance = cfg.wgpu.create_instance();
+    let (adapter, device, queue) = cfg.wgpu.request_device(&instance, None)?;
+
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("dear-app headless target"),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let targets = FrameTargets::new(
+        &device,
+        FORMAT,
+        supported_sample_count(&adapter, FORMAT, cfg.msaa_samples),
+        cfg.depth_format,
+        width,
+        height,
+    );
+
+    let mut context = create_context(cfg, cbs);
+    {
+        // No platform backend: provide what it would set each frame.
+        let io = context.io_mut();
+        io.set_display_size([width as f32, height as f32]);
+        io.set_display_framebuffer_scale([1.0, 1.0]);
+        io.set_delta_time(headless.delta_time);
+    }
+    let init_info = imgui_wgpu::WgpuInitInfo::new(device.clone(), queue.clone(), FORMAT)
+        .with_multisample_state(targets.multisample_state());
+    let mut renderer = imgui_wgpu::WgpuRenderer::new(init_info, &mut context)
+        .map_err(DearAppError::RendererInit)?;
+    renderer.set_gamma_mode(imgui_wgpu::GammaMode::Auto);
+    apply_config_flags(&mut context, cfg);
+    let mut ui_state = UiState::new(&context, cfg, addons);
+    let mut uploaded_textures = HashMap::new();
+    if let Some(cb) = cbs.on_post_init.as_mut() {
+        cb(&mut context);
+    }
+
+    for _ in 0..headless.frames.max(1) {
+        let ui = context.frame();
+        ui_state.frame(
+            ui,
+            gui,
+            &cfg.docking,
+            GpuApi {
+                device: &device,
+                queue: &queue,
+                renderer: &mut renderer,
+                uploaded_textures: &mut uploaded_textures,
+            },
+        );
+        let draw_data = context.render();
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Headless Encoder"),
+        });
+        encode_frame(
+            targets.context(&device, &queue, &mut encoder, &view, (width, height)),
+            clear_color(cfg),
+            cbs.on_render.as_mut(),
+            &mut renderer,
+            draw_data,
+        )?;
+        queue.submit(Some(encoder.finish()));
+        ui_state.post_swap();
+    }
+
+    let image = if headless.capture {
+        Some(read_back(&device, &queue, &target, width, height)?)
+    } else {
+        None
+    };
+
+    if let Some(cb) = cbs.on_exit.as_mut() {
+        cb(&mut context);
+    }
+    ui_state.shutdown();
+    Ok(image)
+}
+
+fn read_back(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    width: u32,
+    height: u32,
+) -> Result<HeadlessImage, DearAppError> {
+    let row_bytes = width * 4;
+    let padded_row_bytes =
+        row_bytes.div_ceil(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT) * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("dear-app headless readback"),
+        size: u64::from(padded_row_bytes) * u64::from(height),
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("Headless Readback Encoder"),
+    });
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::TexelCopyBufferInfo {
+            buffer: &buffer,
+            layout: wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_row_bytes),
+                rows_per_image: Some(height),
+            },
+        },
+        wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+    );
+    queue.submit(Some(encoder.finish()));
+
+    let slice = buffer.slice(..);
+    let (tx, rx) = std::sync::mpsc::channel();
+    slice.map_async(wgpu::MapMode::Read, move |res| {
+        let _ = tx.send(res);
+    });
+    device
+        .poll(wgpu::PollType::wait_indefinitely())
+        .map_err(|e| DearAppError::Readback(e.to_string()))?;
+    rx.recv()
+        .map_err(|e| DearAppError::Readback(e.to_string()))?
+        .map_err(|e| DearAppError::Readback(e.to_string()))?;
+
+    let rgba = {
+        let mapped = slice
+            .get_mapped_range()
+            .map_err(|e| DearAppError::Readback(e.to_string()))?;
+        strip_row_padding(&mapped, row_bytes as usize, padded_row_bytes as usize)
+    };
+    buffer.unmap();
+    Ok(HeadlessImage {
+        width,
+        height,
+        rgba,
+    })
+}
+
+/// Drops the per-row padding WGPU requires for texture-to-buffer copies.
+fn strip_row_padding(data: &[u8], row_bytes: usize, padded_row_bytes: usize) -> Vec<u8> {
+    data.chunks_exact(padded_row_bytes)
+        .flat_map(|row| &row[..row_bytes])
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readback_rows_are_unpadded_and_indexable() {
+        // 2x2 image with rows padded to 12 bytes.
+        let padded = [
+            1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, //
+            9, 10, 11, 12, 13, 14, 15, 16, 0, 0, 0, 0,
+        ];
+        let image = HeadlessImage {
+            width: 2,
+            height: 2,
+            rgba: strip_row_padding(&padded, 8, 12),
+        };
+        assert_eq!(image.rgba.len(), 16);
+        assert_eq!(image.pixel(1, 0), [5, 6, 7, 8]);
+        assert_eq!(image.pixel(0, 1), [9, 10, 11, 12]);
+    }
+}
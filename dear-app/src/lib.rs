@@ -22,13 +22,16 @@ use dear_imgui_rs as imgui;
 use dear_imgui_rs::{ConfigFlags, DockFlags, Id, TextureId, WindowFlags};
 use dear_imgui_wgpu as imgui_wgpu;
 use dear_imgui_winit as imgui_winit;
+#[cfg(feature = "multi-viewport")]
+use dear_imgui_winit::multi_viewport as winit_mvp;
 use pollster::block_on;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use thiserror::Error;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use winit::application::ApplicationHandler;
 use winit::dpi::LogicalSize;
 use winit::event::WindowEvent;
@@ -38,6 +41,12 @@ use winit::window::{Window, WindowId};
 /// Re-exported for convenience when configuring `WgpuConfig`.
 pub use wgpu;
 
+mod channel;
+mod headless;
+use channel::RunnerWaker;
+pub use channel::{RunnerEvents, RunnerHandle, runner_channel};
+pub use headless::{HeadlessConfig, HeadlessImage};
+
 #[cfg(feature = "imnodes")]
 use dear_imnodes as imnodes;
 #[cfg(feature = "implot")]
@@ -45,6 +54,15 @@ use dear_implot as implot;
 #[cfg(feature = "implot3d")]
 use dear_implot3d as implot3d;
 
+#[cfg(feature = "imguizmo")]
+use dear_imguizmo as imguizmo;
+
+#[cfg(feature = "file-browser")]
+use dear_file_browser as file_browser;
+
+#[cfg(feature = "test-engine")]
+use dear_imgui_test_engine as test_engine;
+
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum DearAppError {
@@ -58,6 +76,8 @@ pub enum DearAppError {
     SurfaceCreation(#[source] wgpu::CreateSurfaceError),
     #[error("no suitable WGPU adapter found: {0}")]
     AdapterUnavailable(#[source] wgpu::RequestAdapterError),
+    #[error("the provided WGPU adapter cannot present to the window surface")]
+    IncompatibleAdapter,
     #[error("WGPU device request failed: {0}")]
     DeviceRequest(#[source] wgpu::RequestDeviceError),
     #[error("WGPU renderer initialization failed: {0}")]
@@ -74,16 +94,42 @@ pub enum DearAppError {
     SurfaceTimeout,
     #[error("WGPU surface validation failed while acquiring the next frame")]
     SurfaceValidation,
+    #[error("invalid texture upload: {0}")]
+    TextureUpload(String),
+    #[error("invalid window icon: {0}")]
+    WindowIcon(#[source] winit::window::BadIcon),
+    #[error("headless frame readback failed: {0}")]
+    Readback(String),
     #[error("Generic error: {0}")]
     Generic(String),
 }
 
 /// Add-ons to be initialized and provided to the UI callback
-#[derive(Default, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct AddOnsConfig {
     pub with_implot: bool,
     pub with_imnodes: bool,
     pub with_implot3d: bool,
+    pub with_imguizmo: bool,
+    pub with_file_browser: bool,
+    /// Create and start Dear ImGui Test Engine for the main context (feature `test-engine`).
+    pub with_test_engine: bool,
+    /// Key that shows or hides the test engine windows (default F12).
+    pub test_engine_toggle_key: Option<imgui::Key>,
+}
+
+impl Default for AddOnsConfig {
+    fn default() -> Self {
+        Self {
+            with_implot: false,
+            with_imnodes: false,
+            with_implot3d: false,
+            with_imguizmo: false,
+            with_file_browser: false,
+            with_test_engine: false,
+            test_engine_toggle_key: Some(imgui::Key::F12),
+        }
+    }
 }
 
 impl AddOnsConfig {
@@ -95,6 +141,10 @@ impl AddOnsConfig {
             with_implot: cfg!(feature = "implot"),
             with_imnodes: cfg!(feature = "imnodes"),
             with_implot3d: cfg!(feature = "implot3d"),
+            with_imguizmo: cfg!(feature = "imguizmo"),
+            with_file_browser: cfg!(feature = "file-browser"),
+            with_test_engine: cfg!(feature = "test-engine"),
+            ..Self::default()
         }
     }
 }
@@ -115,8 +165,33 @@ pub struct AddOns<'a> {
     pub implot3d: Option<&'a implot3d::Plot3DContext>,
     #[cfg(not(feature = "implot3d"))]
     pub implot3d: Option<()>,
+
+    /// ImGuizmo frame, begun with its rect covering the main viewport. Call `set_rect` /
+    /// `set_drawlist_window` to confine gizmos to a scene window instead.
+    #[cfg(feature = "imguizmo")]
+    pub imguizmo: Option<&'a imguizmo::GizmoUi<'a>>,
+    #[cfg(not(feature = "imguizmo"))]
+    pub imguizmo: Option<()>,
+
+    /// In-UI file dialogs, kept across frames: open one with `open_browser` and call
+    /// `show_browser` every frame until it returns a result.
+    #[cfg(feature = "file-browser")]
+    pub file_browser: Option<&'a mut file_browser::DialogManager>,
+    #[cfg(not(feature = "file-browser"))]
+    pub file_browser: Option<()>,
+
+    /// The started test engine, to register and queue tests. The runner shows its windows
+    /// (toggled with `AddOnsConfig::test_engine_toggle_key`) and calls `post_swap` each frame.
+    #[cfg(feature = "test-engine")]
+    pub test_engine: Option<&'a mut test_engine::TestEngine>,
+    #[cfg(not(feature = "test-engine"))]
+    pub test_engine: Option<()>,
     pub docking: DockingApi<'a>,
     pub gpu: GpuApi<'a>,
+    /// Files dropped onto the main window since the previous frame.
+    pub dropped_files: &'a [PathBuf],
+    /// Files currently dragged over the main window (not reported by every platform).
+    pub hovered_files: &'a [PathBuf],
     _marker: PhantomData<&'a ()>,
 }
 
@@ -137,6 +212,22 @@ pub struct RunnerConfig {
     pub io_config_flags: Option<ConfigFlags>,
     /// Optional built-in theme to apply at startup (before on_style callback)
     pub theme: Option<Theme>,
+    /// Enable Dear ImGui multi-viewports: windows dragged outside the main window become
+    /// separate OS windows.
+    ///
+    /// Requires the `multi-viewport` feature; without it the flag is ignored with a warning.
+    pub viewports: bool,
+    /// Main window appearance and placement (icon, decorations, size limits, ...).
+    pub window: WindowConfig,
+    /// Create a depth target of this format for the `on_render` scene callback
+    /// ([`RenderContext::depth`]). Dear ImGui itself draws without depth.
+    pub depth_format: Option<wgpu::TextureFormat>,
+    /// Color format family of the main window surface. Keeps text and colors identical on
+    /// machines whose surfaces offer different formats.
+    pub surface_format: SurfaceFormatPreference,
+    /// MSAA sample count for the main window (1 disables MSAA). Counts the adapter does not
+    /// support fall back to 1 with a warning; multi-viewport windows always render without MSAA.
+    pub msaa_samples: u32,
 }
 
 impl Default for RunnerConfig {
@@ -153,10 +244,97 @@ impl Default for RunnerConfig {
             redraw: RedrawMode::Poll,
             io_config_flags: None,
             theme: None,
+            viewports: false,
+            window: WindowConfig::default(),
+            depth_format: None,
+            surface_format: SurfaceFormatPreference::default(),
+            msaa_samples: 1,
+        }
+    }
+}
+
+/// Which color format the runner renders the main window in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum SurfaceFormatPreference {
+    /// sRGB output: blending happens in linear space and the hardware encodes on write.
+    #[default]
+    Srgb,
+    /// Linear (`*Unorm`) output; the renderer applies gamma in the shader.
+    Linear,
+    /// This exact format, or its sRGB/linear counterpart. Falls back to [`Self::Srgb`] with a
+    /// warning if the surface supports neither.
+    Exact(wgpu::TextureFormat),
+}
+
+/// Picks `(surface format, render format)` from the formats a surface supports.
+///
+/// If the surface offers no format of the preferred kind, it is configured with its first format
+/// and viewed as the sRGB/linear counterpart, so output looks the same on every machine.
+fn pick_surface_format(
+    preference: SurfaceFormatPreference,
+    formats: &[wgpu::TextureFormat],
+) -> (wgpu::TextureFormat, wgpu::TextureFormat) {
+    use wgpu::TextureFormat as F;
+    let fallback = formats.first().copied().unwrap_or(F::Bgra8UnormSrgb);
+    match preference {
+        SurfaceFormatPreference::Srgb => {
+            let preferred = [F::Bgra8UnormSrgb, F::Rgba8UnormSrgb];
+            match preferred
+                .into_iter()
+                .chain(formats.iter().copied())
+                .find(|f| f.is_srgb() && formats.contains(f))
+            {
+                Some(format) => (format, format),
+                None => (fallback, fallback.add_srgb_suffix()),
+            }
+        }
+        SurfaceFormatPreference::Linear => {
+            let preferred = [F::Bgra8Unorm, F::Rgba8Unorm];
+            match preferred
+                .into_iter()
+                .chain(formats.iter().copied())
+                .find(|f| !f.is_srgb() && formats.contains(f))
+            {
+                Some(format) => (format, format),
+                None => (fallback, fallback.remove_srgb_suffix()),
+            }
+        }
+        SurfaceFormatPreference::Exact(format) => {
+            if formats.contains(&format) {
+                return (format, format);
+            }
+            let counterpart = if format.is_srgb() {
+                format.remove_srgb_suffix()
+            } else {
+                format.add_srgb_suffix()
+            };
+            if counterpart != format && formats.contains(&counterpart) {
+                return (counterpart, format);
+            }
+            warn!("surface does not support {format:?}; using an sRGB format");
+            pick_surface_format(SurfaceFormatPreference::Srgb, formats)
         }
     }
 }
 
+/// Returns `requested` if `format` supports that many MSAA samples on `adapter`, else 1.
+fn supported_sample_count(
+    adapter: &wgpu::Adapter,
+    format: wgpu::TextureFormat,
+    requested: u32,
+) -> u32 {
+    if requested <= 1 {
+        return 1;
+    }
+    let flags = adapter.get_texture_format_features(format).flags;
+    if flags.sample_count_supported(requested) {
+        requested
+    } else {
+        warn!("{requested}x MSAA is not supported for {format:?}; rendering without MSAA");
+        1
+    }
+}
+
 /// WGPU configuration for adapter/device creation.
 ///
 /// This is intentionally a small, stable subset of WGPU knobs that tend to matter for apps
@@ -176,6 +354,23 @@ pub struct WgpuConfig {
     pub required_limits: wgpu::Limits,
     /// Memory allocation hints for the device.
     pub memory_hints: wgpu::MemoryHints,
+    /// Run on these WGPU objects instead of creating new ones, e.g. to share the device with the
+    /// application's own renderer.
+    ///
+    /// When set, all other fields are ignored.
+    pub context: Option<WgpuContext>,
+}
+
+/// WGPU objects created by the application for dear-app to share.
+///
+/// The device and queue must come from `adapter`, and the adapter must be able to present to
+/// surfaces created by `instance` for the window.
+#[derive(Clone, Debug)]
+pub struct WgpuContext {
+    pub instance: wgpu::Instance,
+    pub adapter: wgpu::Adapter,
+    pub device: wgpu::Device,
+    pub queue: wgpu::Queue,
 }
 
 /// Small set of curated WGPU presets for common application needs.
@@ -206,6 +401,7 @@ impl Default for WgpuConfig {
             required_features: wgpu::Features::empty(),
             required_limits: wgpu::Limits::default(),
             memory_hints: wgpu::MemoryHints::default(),
+            context: None,
         }
     }
 }
@@ -243,6 +439,159 @@ impl WgpuConfig {
             },
         }
     }
+
+    fn create_instance(&self) -> wgpu::Instance {
+        match &self.context {
+            Some(ctx) => ctx.instance.clone(),
+            None => wgpu::Instance::new(wgpu::InstanceDescriptor {
+                backends: self.backends,
+                ..wgpu::InstanceDescriptor::new_without_display_handle()
+            }),
+        }
+    }
+
+    /// Returns the provided [`WgpuContext`] objects, or requests an adapter (able to present to
+    /// `surface`, if any) and a device from `instance`.
+    fn request_device(
+        &self,
+        instance: &wgpu::Instance,
+        surface: Option<&wgpu::Surface<'_>>,
+    ) -> Result<(wgpu::Adapter, wgpu::Device, wgpu::Queue), DearAppError> {
+        if let Some(ctx) = &self.context {
+            if surface.is_some_and(|surface| !ctx.adapter.is_surface_supported(surface)) {
+                return Err(DearAppError::IncompatibleAdapter);
+            }
+            return Ok((ctx.adapter.clone(), ctx.device.clone(), ctx.queue.clone()));
+        }
+
+        let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: self.power_preference,
+            compatible_surface: surface,
+            apply_limit_buckets: false,
+            force_fallback_adapter: self.force_fallback_adapter,
+        }))
+        .map_err(DearAppError::AdapterUnavailable)?;
+        let device_desc = wgpu::DeviceDescriptor {
+            label: self.device_label.as_deref(),
+            required_features: self.required_features,
+            required_limits: self.required_limits.clone(),
+            memory_hints: self.memory_hints.clone(),
+            ..Default::default()
+        };
+        let (device, queue) =
+            block_on(adapter.request_device(&device_desc)).map_err(DearAppError::DeviceRequest)?;
+        Ok((adapter, device, queue))
+    }
+}
+
+/// Main window configuration, forwarded to winit when the window is created.
+///
+/// Title and initial size live in [`RunnerConfig::window_title`] and
+/// [`RunnerConfig::window_size`]; sizes here are logical pixels as well.
+#[derive(Clone, Debug)]
+pub struct WindowConfig {
+    /// Window icon. Ignored on platforms without window icons (e.g. macOS, Wayland).
+    pub icon: Option<WindowIcon>,
+    /// Request a framebuffer with alpha so a translucent
+    /// [`RunnerConfig::clear_color`] shows the desktop behind the window.
+    pub transparent: bool,
+    /// Show the title bar and borders. `false` gives a borderless window.
+    pub decorations: bool,
+    pub resizable: bool,
+    /// Keep the window above other windows.
+    pub always_on_top: bool,
+    pub min_size: Option<(f64, f64)>,
+    pub max_size: Option<(f64, f64)>,
+    pub maximized: bool,
+    /// Start in borderless fullscreen on the current monitor.
+    pub fullscreen: bool,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            icon: None,
+            transparent: false,
+            decorations: true,
+            resizable: true,
+            always_on_top: false,
+            min_size: None,
+            max_size: None,
+            maximized: false,
+            fullscreen: false,
+        }
+    }
+}
+
+impl WindowConfig {
+    fn attributes(
+        &self,
+        title: &str,
+        size: (f64, f64),
+    ) -> Result<winit::window::WindowAttributes, DearAppError> {
+        let mut attrs = Window::default_attributes()
+            .with_title(title)
+            .with_inner_size(LogicalSize::new(size.0, size.1))
+            .with_transparent(self.transparent)
+            .with_decorations(self.decorations)
+            .with_resizable(self.resizable)
+            .with_maximized(self.maximized);
+        if let Some(icon) = &self.icon {
+            let icon = winit::window::Icon::from_rgba(icon.rgba.clone(), icon.width, icon.height)
+                .map_err(DearAppError::WindowIcon)?;
+            attrs = attrs.with_window_icon(Some(icon));
+        }
+        if self.always_on_top {
+            attrs = attrs.with_window_level(winit::window::WindowLevel::AlwaysOnTop);
+        }
+        if let Some((w, h)) = self.min_size {
+            attrs = attrs.with_min_inner_size(LogicalSize::new(w, h));
+        }
+        if let Some((w, h)) = self.max_size {
+            attrs = attrs.with_max_inner_size(LogicalSize::new(w, h));
+        }
+        if self.fullscreen {
+            attrs = attrs.with_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
+        }
+        Ok(attrs)
+    }
+}
+
+/// Window icon as tightly packed RGBA8 pixels (`width * height * 4` bytes).
+#[derive(Clone, Debug)]
+pub struct WindowIcon {
+    pub rgba: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl WindowIcon {
+    pub fn from_rgba(rgba: Vec<u8>, width: u32, height: u32) -> Self {
+        Self {
+            rgba,
+            width,
+            height,
+        }
+    }
+}
+
+/// Picks a surface alpha mode that composites with the desktop for transparent windows.
+fn pick_alpha_mode(
+    transparent: bool,
+    supported: &[wgpu::CompositeAlphaMode],
+) -> wgpu::CompositeAlphaMode {
+    if transparent {
+        let preferred = [
+            wgpu::CompositeAlphaMode::PreMultiplied,
+            wgpu::CompositeAlphaMode::PostMultiplied,
+            wgpu::CompositeAlphaMode::Inherit,
+        ];
+        if let Some(mode) = preferred.into_iter().find(|m| supported.contains(m)) {
+            return mode;
+        }
+        warn!("Transparent window requested but the surface only supports opaque compositing");
+    }
+    wgpu::CompositeAlphaMode::Auto
 }
 
 /// Docking configuration
@@ -282,6 +631,9 @@ pub enum RedrawMode {
     /// Always redraw (ControlFlow::Poll)
     Poll,
     /// On-demand redraw (ControlFlow::Wait)
+    ///
+    /// Frames are still scheduled while widgets report pending animations through
+    /// [`imgui::AnimationClock`], so animations finish instead of freezing until the next event.
     Wait,
     /// Redraw at most `fps` per second using WaitUntil
     WaitUntil { fps: f32 },
@@ -317,9 +669,39 @@ pub struct RunnerCallbacks {
     >,
     pub on_event:
         Option<Box<dyn FnMut(&winit::event::Event<()>, &Arc<Window>, &mut imgui::Context)>>,
+    /// Called for main-window events (except `RedrawRequested`) before Dear ImGui and the runner
+    /// see them. Returning `true` consumes the event, e.g. to veto `CloseRequested`.
+    pub on_window_event:
+        Option<Box<dyn FnMut(&WindowEvent, &Arc<Window>, &mut imgui::Context) -> bool>>,
+    /// Called every main-window frame after the target was cleared and before Dear ImGui draws,
+    /// to record the application's own scene under the UI.
+    pub on_render: Option<Box<dyn FnMut(&mut RenderContext)>>,
     pub on_exit: Option<Box<dyn FnMut(&mut imgui::Context)>>,
 }
 
+/// GPU state handed to the `on_render` callback.
+///
+/// Passes recorded into `encoder` run after the clear and before the Dear ImGui pass, which loads
+/// the color target instead of clearing it.
+pub struct RenderContext<'a> {
+    pub device: &'a wgpu::Device,
+    pub queue: &'a wgpu::Queue,
+    pub encoder: &'a mut wgpu::CommandEncoder,
+    /// Color attachment of this frame, cleared to `RunnerConfig::clear_color`. Multisampled when
+    /// `sample_count > 1`; pass `resolve_target` along with it.
+    pub view: &'a wgpu::TextureView,
+    /// The frame's single-sampled view that `view` resolves into, when MSAA is on.
+    pub resolve_target: Option<&'a wgpu::TextureView>,
+    pub format: wgpu::TextureFormat,
+    pub sample_count: u32,
+    /// Target size in physical pixels.
+    pub size: (u32, u32),
+    /// Depth target cleared to 1.0, if `RunnerConfig::depth_format` is set. Has `sample_count`
+    /// samples.
+    pub depth: Option<&'a wgpu::TextureView>,
+    pub depth_format: Option<wgpu::TextureFormat>,
+}
+
 impl Default for RunnerCallbacks {
     fn default() -> Self {
         Self {
@@ -329,6 +711,8 @@ impl Default for RunnerCallbacks {
             on_post_init: None,
             on_gpu_init: None,
             on_event: None,
+            on_window_event: None,
+            on_render: None,
             on_exit: None,
         }
     }
@@ -340,6 +724,7 @@ pub struct AppBuilder {
     addons: AddOnsConfig,
     cbs: RunnerCallbacks,
     on_frame: Option<Box<dyn FnMut(&imgui::Ui, &mut AddOns) + 'static>>,
+    wakers: Vec<Arc<RunnerWaker>>,
 }
 
 impl AppBuilder {
@@ -349,6 +734,7 @@ impl AppBuilder {
             addons: AddOnsConfig::default(),
             cbs: RunnerCallbacks::default(),
             on_frame: None,
+            wakers: Vec::new(),
         }
     }
     pub fn with_config(mut self, cfg: RunnerConfig) -> Self {
@@ -363,6 +749,13 @@ impl AppBuilder {
         self.cfg.theme = Some(theme);
         self
     }
+    /// Share the application's WGPU instance, adapter, device and queue with the runner.
+    ///
+    /// See [`WgpuConfig::context`].
+    pub fn with_wgpu_context(mut self, context: WgpuContext) -> Self {
+        self.cfg.wgpu.context = Some(context);
+        self
+    }
     pub fn on_setup<F: FnMut(&mut imgui::Context) + 'static>(mut self, f: F) -> Self {
         self.cbs.on_setup = Some(Box::new(f));
         self
@@ -397,10 +790,44 @@ impl AppBuilder {
         self.cbs.on_event = Some(Box::new(f));
         self
     }
+    /// Hooks main-window events before Dear ImGui handles them; return `true` to consume one.
+    ///
+    /// See [`RunnerCallbacks::on_window_event`].
+    pub fn on_window_event<
+        F: FnMut(&WindowEvent, &Arc<Window>, &mut imgui::Context) -> bool + 'static,
+    >(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.cbs.on_window_event = Some(Box::new(f));
+        self
+    }
     pub fn on_frame<F: FnMut(&imgui::Ui, &mut AddOns) + 'static>(mut self, f: F) -> Self {
         self.on_frame = Some(Box::new(f));
         self
     }
+    /// Like [`Self::on_frame`], also handing over the events sent through the [`RunnerHandle`]s
+    /// of `events` since the previous frame (see [`runner_channel`]).
+    ///
+    /// Sending an event wakes the runner, so the frame that receives it is drawn even with
+    /// [`RedrawMode::Wait`].
+    pub fn on_frame_with_events<E, F>(mut self, events: RunnerEvents<E>, mut f: F) -> Self
+    where
+        E: 'static,
+        F: FnMut(&imgui::Ui, &mut AddOns, Vec<E>) + 'static,
+    {
+        self.wakers.push(events.waker());
+        self.on_frame = Some(Box::new(move |ui, addons| {
+            let batch: Vec<E> = events.drain().collect();
+            f(ui, addons, batch)
+        }));
+        self
+    }
+    /// Records the application's scene under the UI each frame; see [`RenderContext`].
+    pub fn on_render<F: FnMut(&mut RenderContext) + 'static>(mut self, f: F) -> Self {
+        self.cbs.on_render = Some(Box::new(f));
+        self
+    }
     pub fn on_exit<F: FnMut(&mut imgui::Context) + 'static>(mut self, f: F) -> Self {
         self.cbs.on_exit = Some(Box::new(f));
         self
@@ -410,7 +837,29 @@ impl AppBuilder {
             .on_frame
             .take()
             .ok_or(DearAppError::MissingFrameCallback)?;
-        run_with_callbacks(self.cfg, self.addons, self.cbs, frame_fn)
+        run_app(self.cfg, self.addons, self.cbs, self.wakers, frame_fn)
+    }
+    /// Runs `headless.frames` frames into an offscreen texture instead of a window, e.g. to
+    /// smoke-test the UI in CI. Returns the last frame's pixels if `headless.capture` is set.
+    ///
+    /// Window-related settings and callbacks (`on_gpu_init`, `on_event`, `on_window_event`) are
+    /// not used. A GPU adapter is still required; `WgpuPreset::SoftwareFallback` picks a
+    /// software one where available.
+    pub fn run_headless(
+        mut self,
+        headless: HeadlessConfig,
+    ) -> Result<Option<HeadlessImage>, DearAppError> {
+        let mut frame_fn = self
+            .on_frame
+            .take()
+            .ok_or(DearAppError::MissingFrameCallback)?;
+        headless::run(
+            &self.cfg,
+            &self.addons,
+            &mut self.cbs,
+            &headless,
+            &mut frame_fn,
+        )
     }
 }
 
@@ -430,6 +879,24 @@ where
     )
 }
 
+/// Runs `frames` frames of `gui` offscreen with the default configuration and returns the last
+/// frame's pixels.
+///
+/// See [`AppBuilder::run_headless`] for configurable runs.
+pub fn run_headless<F>(frames: u32, gui: F) -> Result<HeadlessImage, DearAppError>
+where
+    F: FnMut(&imgui::Ui, &mut AddOns) + 'static,
+{
+    let image = AppBuilder::new()
+        .on_frame(gui)
+        .run_headless(HeadlessConfig {
+            frames,
+            capture: true,
+            ..Default::default()
+        })?;
+    Ok(image.expect("capture was requested"))
+}
+
 /// Run an app with configuration and add-ons.
 ///
 /// The `gui` callback is called every frame with access to ImGui `Ui` and the initialized add-ons.
@@ -447,10 +914,26 @@ pub fn run_with_callbacks<F>(
     cbs: RunnerCallbacks,
     gui: F,
 ) -> Result<(), DearAppError>
+where
+    F: FnMut(&imgui::Ui, &mut AddOns) + 'static,
+{
+    run_app(runner, addons_cfg, cbs, Vec::new(), gui)
+}
+
+fn run_app<F>(
+    runner: RunnerConfig,
+    addons_cfg: AddOnsConfig,
+    cbs: RunnerCallbacks,
+    wakers: Vec<Arc<RunnerWaker>>,
+    gui: F,
+) -> Result<(), DearAppError>
 where
     F: FnMut(&imgui::Ui, &mut AddOns) + 'static,
 {
     let event_loop = EventLoop::new()?;
+    for waker in &wakers {
+        waker.attach(event_loop.create_proxy());
+    }
     match runner.redraw {
         RedrawMode::Poll => event_loop.set_control_flow(ControlFlow::Poll),
         RedrawMode::Wait => event_loop.set_control_flow(ControlFlow::Wait),
@@ -496,6 +979,7 @@ pub struct GpuApi<'a> {
     device: &'a wgpu::Device,
     queue: &'a wgpu::Queue,
     renderer: &'a mut imgui_wgpu::WgpuRenderer,
+    uploaded_textures: &'a mut HashMap<TextureId, wgpu::Texture>,
 }
 
 impl<'a> GpuApi<'a> {
@@ -523,6 +1007,89 @@ impl<'a> GpuApi<'a> {
     pub fn unregister_texture(&mut self, tex_id: TextureId) {
         self.renderer.unregister_texture(tex_id)
     }
+    /// Upload tightly packed RGBA8 pixels (`width * height * 4` bytes, row-major) as a new
+    /// texture and obtain an ImGui texture id for `ui.image(...)`.
+    ///
+    /// The texture is owned by dear-app until [`Self::destroy_texture`] is called. Textures do
+    /// not survive a GPU state rebuild after a fatal render error; upload them again then.
+    pub fn upload_rgba8(
+        &mut self,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+    ) -> Result<TextureId, DearAppError> {
+        let max_dim = self.device.limits().max_texture_dimension_2d;
+        if width == 0 || height == 0 || width > max_dim || height > max_dim {
+            return Err(DearAppError::TextureUpload(format!(
+                "texture size {width}x{height} must be within 1..={max_dim}"
+            )));
+        }
+        let expected_len = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|px| px.checked_mul(4));
+        if expected_len != Some(pixels.len()) {
+            return Err(DearAppError::TextureUpload(format!(
+                "RGBA8 data size mismatch: {width}x{height} needs {} bytes, got {}",
+                u64::from(width) * u64::from(height) * 4,
+                pixels.len()
+            )));
+        }
+
+        let size = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        // Same format as ImGui-managed RGBA32 textures, so colors match `TextureData` uploads.
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("dear-app uploaded texture"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        self.queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            pixels,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(width * 4),
+                rows_per_image: Some(height),
+            },
+            size,
+        );
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let tex_id = self.renderer.register_external_texture(&texture, &view);
+        self.uploaded_textures.insert(tex_id, texture);
+        Ok(tex_id)
+    }
+    /// Convert `image` to RGBA8 and upload it with [`Self::upload_rgba8`] (feature `image`).
+    #[cfg(feature = "image")]
+    pub fn upload_image(&mut self, image: &image::DynamicImage) -> Result<TextureId, DearAppError> {
+        let rgba = image.to_rgba8();
+        self.upload_rgba8(rgba.width(), rgba.height(), rgba.as_raw())
+    }
+    /// Unregister and free a texture created by [`Self::upload_rgba8`] or `upload_image`.
+    ///
+    /// Returns false if `tex_id` was not uploaded through this API (use
+    /// [`Self::unregister_texture`] for textures registered with [`Self::register_texture`]).
+    /// Do not draw `tex_id` afterwards, including later in the current frame.
+    pub fn destroy_texture(&mut self, tex_id: TextureId) -> bool {
+        let Some(texture) = self.uploaded_textures.remove(&tex_id) else {
+            return false;
+        };
+        self.renderer.unregister_texture(tex_id);
+        texture.destroy();
+        true
+    }
     /// Optional: directly drive managed TextureData create/update without waiting for draw pass
     pub fn update_texture_data(
         &mut self,
@@ -546,20 +1113,433 @@ struct AppWindow {
     queue: wgpu::Queue,
     window: Arc<Window>,
     surface_desc: wgpu::SurfaceConfiguration,
+    targets: FrameTargets,
     surface: wgpu::Surface<'static>,
     imgui: ImguiState,
+    ui_state: UiState,
+
+    // config for rendering
+    clear_color: wgpu::Color,
+    // multi-viewport support is active (requested and compiled in)
+    #[cfg_attr(not(feature = "multi-viewport"), allow(dead_code))]
+    viewports: bool,
+    // textures created through `GpuApi::upload_rgba8`
+    uploaded_textures: HashMap<TextureId, wgpu::Texture>,
+}
 
-    // add-ons
+/// Add-on contexts, docking flags and file-drop state: everything a frame's [`AddOns`] view
+/// borrows besides the GPU. Shared by the windowed and headless runners.
+struct UiState {
     #[cfg(feature = "implot")]
     implot_ctx: Option<implot::PlotContext>,
     #[cfg(feature = "imnodes")]
     imnodes_ctx: Option<imnodes::Context>,
     #[cfg(feature = "implot3d")]
     implot3d_ctx: Option<implot3d::Plot3DContext>,
+    #[cfg(feature = "imguizmo")]
+    imguizmo_ctx: Option<imguizmo::GuizmoContext>,
+    #[cfg(feature = "file-browser")]
+    file_browser: Option<file_browser::DialogManager>,
+    #[cfg(feature = "test-engine")]
+    test_engine: Option<test_engine::TestEngine>,
+    #[cfg(feature = "test-engine")]
+    test_engine_visible: bool,
+    #[cfg(feature = "test-engine")]
+    test_engine_toggle_key: Option<imgui::Key>,
+    docking_ctrl: DockingController,
+    dropped_files: Vec<PathBuf>,
+    hovered_files: Vec<PathBuf>,
+}
 
-    // config for rendering
+impl UiState {
+    fn new(context: &imgui::Context, cfg: &RunnerConfig, addons: &AddOnsConfig) -> Self {
+        let _ = (context, addons);
+        Self {
+            #[cfg(feature = "implot")]
+            implot_ctx: addons
+                .with_implot
+                .then(|| implot::PlotContext::create(context)),
+            #[cfg(feature = "imnodes")]
+            imnodes_ctx: addons
+                .with_imnodes
+                .then(|| imnodes::Context::create(context)),
+            #[cfg(feature = "implot3d")]
+            implot3d_ctx: addons
+                .with_implot3d
+                .then(|| implot3d::Plot3DContext::create(context)),
+            #[cfg(feature = "imguizmo")]
+            imguizmo_ctx: addons.with_imguizmo.then(imguizmo::GuizmoContext::new),
+            #[cfg(feature = "file-browser")]
+            file_browser: addons
+                .with_file_browser
+                .then(file_browser::DialogManager::new),
+            #[cfg(feature = "test-engine")]
+            test_engine: addons.with_test_engine.then(|| {
+                let mut engine = test_engine::TestEngine::create();
+                engine.start(context);
+                engine
+            }),
+            #[cfg(feature = "test-engine")]
+            test_engine_visible: false,
+            #[cfg(feature = "test-engine")]
+            test_engine_toggle_key: addons.test_engine_toggle_key,
+            docking_ctrl: DockingController {
+                flags: DockFlags::from_bits_retain(cfg.docking.dockspace_flags.bits()),
+            },
+            dropped_files: Vec::new(),
+            hovered_files: Vec::new(),
+        }
+    }
+
+    /// Submits the optional dockspace, then calls `gui` with the add-ons view.
+    fn frame<F>(&mut self, ui: &imgui::Ui, gui: &mut F, docking: &DockingConfig, gpu: GpuApi<'_>)
+    where
+        F: FnMut(&imgui::Ui, &mut AddOns),
+    {
+        // Optional fullscreen dockspace
+        if docking.enable && docking.auto_dockspace {
+            let viewport = ui.main_viewport();
+            // Host window always covering the main viewport
+            ui.set_next_window_viewport(viewport.id());
+            let pos = viewport.pos();
+            let size = viewport.size();
+            // NO_BACKGROUND if passthru central node
+            let current_flags = DockFlags::from_bits_retain(self.docking_ctrl.flags.bits());
+            let mut win_flags = docking.host_window_flags;
+            if current_flags.contains(DockFlags::PASSTHRU_CENTRAL_NODE) {
+                win_flags |= WindowFlags::NO_BACKGROUND;
+            }
+            ui.window(docking.host_window_name)
+                .flags(win_flags)
+                .position([pos[0], pos[1]], imgui::Condition::Always)
+                .size([size[0], size[1]], imgui::Condition::Always)
+                .build(|| {
+                    let ds_flags = DockFlags::from_bits_retain(current_flags.bits());
+                    let _ = ui.dockspace_over_main_viewport_with_flags(Id::from(0u32), ds_flags);
+                });
+        }
+
+        // ImGuizmo needs `BeginFrame` once per frame, after the dockspace host window.
+        #[cfg(feature = "imguizmo")]
+        let gizmo = self.imguizmo_ctx.as_ref().map(|ctx| {
+            let gizmo = ctx.begin_frame(ui);
+            let viewport = ui.main_viewport();
+            gizmo.set_rect_pos_size(viewport.pos(), viewport.size());
+            gizmo
+        });
+
+        // Build add-ons view
+        let dropped_files = std::mem::take(&mut self.dropped_files);
+        let mut addons = AddOns {
+            #[cfg(feature = "implot")]
+            implot: self.implot_ctx.as_ref(),
+            #[cfg(not(feature = "implot"))]
+            implot: None,
+            #[cfg(feature = "imnodes")]
+            imnodes: self.imnodes_ctx.as_ref(),
+            #[cfg(not(feature = "imnodes"))]
+            imnodes: None,
+            #[cfg(feature = "implot3d")]
+            implot3d: self.implot3d_ctx.as_ref(),
+            #[cfg(not(feature = "implot3d"))]
+            implot3d: None,
+            #[cfg(feature = "imguizmo")]
+            imguizmo: gizmo.as_ref(),
+            #[cfg(not(feature = "imguizmo"))]
+            imguizmo: None,
+            #[cfg(feature = "file-browser")]
+            file_browser: self.file_browser.as_mut(),
+            #[cfg(not(feature = "file-browser"))]
+            file_browser: None,
+            #[cfg(feature = "test-engine")]
+            test_engine: self.test_engine.as_mut(),
+            #[cfg(not(feature = "test-engine"))]
+            test_engine: None,
+            docking: DockingApi {
+                ctrl: &mut self.docking_ctrl,
+            },
+            gpu,
+            dropped_files: &dropped_files,
+            hovered_files: &self.hovered_files,
+            _marker: PhantomData,
+        };
+
+        // Call user GUI
+        gui(ui, &mut addons);
+
+        #[cfg(feature = "test-engine")]
+        if let Some(engine) = self.test_engine.as_mut() {
+            if let Some(key) = self.test_engine_toggle_key {
+                if ui.is_key_pressed(key) {
+                    self.test_engine_visible = !self.test_engine_visible;
+                }
+            }
+            if self.test_engine_visible {
+                engine.show_windows(ui, Some(&mut self.test_engine_visible));
+            }
+        }
+    }
+
+    /// Lets the test engine capture the presented frame; call after submitting it.
+    fn post_swap(&mut self) {
+        #[cfg(feature = "test-engine")]
+        if let Some(engine) = self.test_engine.as_mut() {
+            engine.post_swap();
+        }
+    }
+
+    /// True while queued tests are running and need frames to make progress.
+    fn is_running_tests(&self) -> bool {
+        #[cfg(feature = "test-engine")]
+        if let Some(engine) = self.test_engine.as_ref() {
+            return engine.is_running_tests() || !engine.is_test_queue_empty();
+        }
+        false
+    }
+
+    /// Detaches the test engine; must run before the ImGui context is destroyed.
+    fn shutdown(&mut self) {
+        #[cfg(feature = "test-engine")]
+        if let Some(engine) = self.test_engine.as_mut() {
+            engine.shutdown();
+        }
+    }
+}
+
+/// Creates the Dear ImGui context and runs the setup, theme, style and font hooks.
+fn create_context(cfg: &RunnerConfig, cbs: &mut RunnerCallbacks) -> imgui::Context {
+    let mut context = imgui::Context::create();
+    // ini setup before fonts
+    if !cfg.restore_previous_geometry {
+        let _ = context.set_ini_filename(None::<String>);
+    } else if let Some(p) = &cfg.ini_filename {
+        let _ = context.set_ini_filename(Some(p.clone()));
+    } else {
+        let _ = context.set_ini_filename(None::<String>);
+    }
+
+    // lifecycle: on_setup/style/fonts before renderer init
+    if let Some(cb) = cbs.on_setup.as_mut() {
+        cb(&mut context);
+    }
+    // Apply optional theme from config before user style tweak
+    if let Some(theme) = cfg.theme {
+        apply_theme(&mut context, theme);
+    }
+    if let Some(cb) = cbs.on_style.as_mut() {
+        cb(&mut context);
+    }
+    if let Some(cb) = cbs.on_fonts.as_mut() {
+        cb(&mut context);
+    }
+    context
+}
+
+/// Applies the docking flag and `RunnerConfig::io_config_flags`.
+fn apply_config_flags(context: &mut imgui::Context, cfg: &RunnerConfig) {
+    let io = context.io_mut();
+    let mut flags = io.config_flags();
+    if cfg.docking.enable {
+        flags.insert(ConfigFlags::DOCKING_ENABLE);
+    }
+    if let Some(extra) = &cfg.io_config_flags {
+        let merged = flags.bits() | extra.bits();
+        flags = ConfigFlags::from_bits_retain(merged);
+    }
+    io.set_config_flags(flags);
+}
+
+fn clear_color(cfg: &RunnerConfig) -> wgpu::Color {
+    wgpu::Color {
+        r: cfg.clear_color[0] as f64,
+        g: cfg.clear_color[1] as f64,
+        b: cfg.clear_color[2] as f64,
+        a: cfg.clear_color[3] as f64,
+    }
+}
+
+/// Render format and the MSAA color and depth attachments sized to a target.
+struct FrameTargets {
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    depth_format: Option<wgpu::TextureFormat>,
+    msaa_view: Option<wgpu::TextureView>,
+    depth_view: Option<wgpu::TextureView>,
+}
+
+impl FrameTargets {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+        depth_format: Option<wgpu::TextureFormat>,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        let mut targets = Self {
+            format,
+            sample_count,
+            depth_format,
+            msaa_view: None,
+            depth_view: None,
+        };
+        targets.resize(device, width, height);
+        targets
+    }
+
+    fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
+        let (width, height) = (width.max(1), height.max(1));
+        self.msaa_view = (self.sample_count > 1).then(|| {
+            create_attachment(
+                device,
+                "dear-app MSAA target",
+                self.format,
+                self.sample_count,
+                width,
+                height,
+            )
+        });
+        self.depth_view = self.depth_format.map(|format| {
+            create_attachment(
+                device,
+                "dear-app depth target",
+                format,
+                self.sample_count,
+                width,
+                height,
+            )
+        });
+    }
+
+    /// The render context for a frame presented through `view`.
+    fn context<'a>(
+        &'a self,
+        device: &'a wgpu::Device,
+        queue: &'a wgpu::Queue,
+        encoder: &'a mut wgpu::CommandEncoder,
+        view: &'a wgpu::TextureView,
+        size: (u32, u32),
+    ) -> RenderContext<'a> {
+        let (view, resolve_target) = match &self.msaa_view {
+            Some(msaa_view) => (msaa_view, Some(view)),
+            None => (view, None),
+        };
+        RenderContext {
+            device,
+            queue,
+            encoder,
+            view,
+            resolve_target,
+            format: self.format,
+            sample_count: self.sample_count,
+            size,
+            depth: self.depth_view.as_ref(),
+            depth_format: self.depth_format,
+        }
+    }
+
+    fn multisample_state(&self) -> wgpu::MultisampleState {
+        wgpu::MultisampleState {
+            count: self.sample_count,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        }
+    }
+}
+
+fn create_attachment(
+    device: &wgpu::Device,
+    label: &str,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    width: u32,
+    height: u32,
+) -> wgpu::TextureView {
+    device
+        .create_texture(&wgpu::TextureDescriptor {
+            label: Some(label),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        })
+        .create_view(&wgpu::TextureViewDescriptor::default())
+}
+
+/// Records a frame into `ctx.encoder`: clears the targets, runs `on_render`, then draws
+/// `draw_data` on top. Without `on_render` the clear is folded into the Dear ImGui pass.
+fn encode_frame(
+    mut ctx: RenderContext<'_>,
     clear_color: wgpu::Color,
-    docking_ctrl: DockingController,
+    on_render: Option<&mut Box<dyn FnMut(&mut RenderContext)>>,
+    renderer: &mut imgui_wgpu::WgpuRenderer,
+    draw_data: &mut imgui::render::DrawData,
+) -> Result<(), DearAppError> {
+    let load = match on_render {
+        Some(on_render) => {
+            let depth_stencil_attachment = ctx.depth.zip(ctx.depth_format).map(|(view, format)| {
+                wgpu::RenderPassDepthStencilAttachment {
+                    view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: format.has_stencil_aspect().then_some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                }
+            });
+            ctx.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Clear Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: ctx.view,
+                    resolve_target: ctx.resolve_target,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(clear_color),
+                        store: wgpu::StoreOp::Store,
+                    },
+                    depth_slice: None,
+                })],
+                depth_stencil_attachment,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+                multiview_mask: None,
+            });
+            on_render(&mut ctx);
+            wgpu::LoadOp::Load
+        }
+        None => wgpu::LoadOp::Clear(clear_color),
+    };
+
+    let mut rpass = ctx.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("Render Pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view: ctx.view,
+            resolve_target: ctx.resolve_target,
+            ops: wgpu::Operations {
+                load,
+                store: wgpu::StoreOp::Store,
+            },
+            depth_slice: None,
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+        multiview_mask: None,
+    });
+
+    renderer.new_frame().map_err(DearAppError::FramePrepare)?;
+    renderer
+        .render_draw_data(draw_data, &mut rpass)
+        .map_err(DearAppError::Render)
 }
 
 impl AppWindow {
@@ -569,61 +1549,45 @@ impl AppWindow {
         addons: &AddOnsConfig,
         cbs: &mut RunnerCallbacks,
     ) -> Result<Self, DearAppError> {
-        let _ = addons;
+        let viewports = cfg.viewports && cfg!(feature = "multi-viewport");
+        if cfg.viewports && !viewports {
+            warn!(
+                "RunnerConfig::viewports requires the dear-app `multi-viewport` feature; ignoring"
+            );
+        }
 
         // WGPU instance and window
-        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
-            backends: cfg.wgpu.backends,
-            ..wgpu::InstanceDescriptor::new_without_display_handle()
-        });
+        let instance = cfg.wgpu.create_instance();
 
-        let window = {
-            let size = LogicalSize::new(cfg.window_size.0, cfg.window_size.1);
-            Arc::new(
-                event_loop
-                    .create_window(
-                        Window::default_attributes()
-                            .with_title(cfg.window_title.clone())
-                            .with_inner_size(size),
-                    )
-                    .map_err(DearAppError::WindowCreation)?,
-            )
-        };
+        let window = Arc::new(
+            event_loop
+                .create_window(cfg.window.attributes(&cfg.window_title, cfg.window_size)?)
+                .map_err(DearAppError::WindowCreation)?,
+        );
 
         let surface = instance
             .create_surface(window.clone())
             .map_err(DearAppError::SurfaceCreation)?;
 
-        let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
-            power_preference: cfg.wgpu.power_preference,
-            compatible_surface: Some(&surface),
-            apply_limit_buckets: false,
-            force_fallback_adapter: cfg.wgpu.force_fallback_adapter,
-        }))
-        .map_err(DearAppError::AdapterUnavailable)?;
-
-        let device_desc = wgpu::DeviceDescriptor {
-            label: cfg.wgpu.device_label.as_deref(),
-            required_features: cfg.wgpu.required_features,
-            required_limits: cfg.wgpu.required_limits.clone(),
-            memory_hints: cfg.wgpu.memory_hints.clone(),
-            ..Default::default()
-        };
-        let (device, queue) =
-            block_on(adapter.request_device(&device_desc)).map_err(DearAppError::DeviceRequest)?;
+        let (adapter, device, queue) = cfg.wgpu.request_device(&instance, Some(&surface))?;
 
         // Surface config
         let physical_size = window.inner_size();
         let caps = surface.get_capabilities(&adapter);
-        let preferred_srgb = [
-            wgpu::TextureFormat::Bgra8UnormSrgb,
-            wgpu::TextureFormat::Rgba8UnormSrgb,
-        ];
-        let format = preferred_srgb
-            .iter()
-            .cloned()
-            .find(|f| caps.formats.contains(f))
-            .unwrap_or(caps.formats[0]);
+        let (format, render_format) = pick_surface_format(cfg.surface_format, &caps.formats);
+        let mut msaa_samples = cfg.msaa_samples;
+        if viewports && msaa_samples > 1 {
+            warn!("MSAA is not supported together with multi-viewports; rendering without MSAA");
+            msaa_samples = 1;
+        }
+        let targets = FrameTargets::new(
+            &device,
+            render_format,
+            supported_sample_count(&adapter, render_format, msaa_samples),
+            cfg.depth_format,
+            physical_size.width,
+            physical_size.height,
+        );
 
         let surface_desc = wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
@@ -632,8 +1596,12 @@ impl AppWindow {
             width: physical_size.width,
             height: physical_size.height,
             present_mode: cfg.present_mode,
-            alpha_mode: wgpu::CompositeAlphaMode::Auto,
-            view_formats: vec![],
+            alpha_mode: pick_alpha_mode(cfg.window.transparent, &caps.alpha_modes),
+            view_formats: if render_format != format {
+                vec![render_format]
+            } else {
+                vec![]
+            },
             desired_maximum_frame_latency: 2,
         };
 
@@ -644,74 +1612,38 @@ impl AppWindow {
         }
 
         // ImGui setup
-        let mut context = imgui::Context::create();
-        // ini setup before fonts
-        if !cfg.restore_previous_geometry {
-            let _ = context.set_ini_filename(None::<String>);
-        } else if let Some(p) = &cfg.ini_filename {
-            let _ = context.set_ini_filename(Some(p.clone()));
-        } else {
-            let _ = context.set_ini_filename(None::<String>);
-        }
+        let mut context = create_context(cfg, cbs);
 
-        // lifecycle: on_setup/style/fonts before renderer init
-        if let Some(cb) = cbs.on_setup.as_mut() {
-            cb(&mut context);
-        }
-        // Apply optional theme from config before user style tweak
-        if let Some(theme) = cfg.theme {
-            apply_theme(&mut context, theme);
-        }
-        if let Some(cb) = cbs.on_style.as_mut() {
-            cb(&mut context);
-        }
-        if let Some(cb) = cbs.on_fonts.as_mut() {
-            cb(&mut context);
+        #[cfg(feature = "multi-viewport")]
+        if viewports {
+            context.enable_multi_viewport();
         }
 
         let mut platform = imgui_winit::WinitPlatform::new(&mut context);
         platform.attach_window(&window, imgui_winit::HiDpiMode::Default, &mut context);
 
-        let init_info =
-            imgui_wgpu::WgpuInitInfo::new(device.clone(), queue.clone(), surface_desc.format);
+        let mut init_info =
+            imgui_wgpu::WgpuInitInfo::new(device.clone(), queue.clone(), targets.format)
+                .with_multisample_state(targets.multisample_state());
+        if viewports {
+            // Secondary viewports create their own surfaces.
+            init_info = init_info
+                .with_instance(instance.clone())
+                .with_adapter(adapter.clone());
+        }
         let mut renderer = imgui_wgpu::WgpuRenderer::new(init_info, &mut context)
             .map_err(DearAppError::RendererInit)?;
         renderer.set_gamma_mode(imgui_wgpu::GammaMode::Auto);
 
-        // Configure IO flags & docking (never enable multi-viewport here)
-        {
-            let io = context.io_mut();
-            let mut flags = io.config_flags();
-            if cfg.docking.enable {
-                flags.insert(ConfigFlags::DOCKING_ENABLE);
-            }
-            if let Some(extra) = &cfg.io_config_flags {
-                let merged = flags.bits() | extra.bits();
-                flags = ConfigFlags::from_bits_retain(merged);
-            }
-            io.set_config_flags(flags);
-        }
-
-        #[cfg(feature = "implot")]
-        let implot_ctx = if addons.with_implot {
-            Some(implot::PlotContext::create(&context))
-        } else {
-            None
-        };
+        // Configure IO flags & docking (multi-viewport flags were set above when requested)
+        apply_config_flags(&mut context, cfg);
 
-        #[cfg(feature = "imnodes")]
-        let imnodes_ctx = if addons.with_imnodes {
-            Some(imnodes::Context::create(&context))
-        } else {
-            None
-        };
+        #[cfg(feature = "multi-viewport")]
+        if viewports {
+            winit_mvp::init_multi_viewport_support(&mut context, &window);
+        }
 
-        #[cfg(feature = "implot3d")]
-        let implot3d_ctx = if addons.with_implot3d {
-            Some(implot3d::Plot3DContext::create(&context))
-        } else {
-            None
-        };
+        let ui_state = UiState::new(&context, cfg, addons);
 
         let imgui = ImguiState {
             context,
@@ -727,33 +1659,57 @@ impl AppWindow {
             surface_desc,
             surface,
             imgui,
-            #[cfg(feature = "implot")]
-            implot_ctx,
-            #[cfg(feature = "imnodes")]
-            imnodes_ctx,
-            #[cfg(feature = "implot3d")]
-            implot3d_ctx,
-            clear_color: wgpu::Color {
-                r: cfg.clear_color[0] as f64,
-                g: cfg.clear_color[1] as f64,
-                b: cfg.clear_color[2] as f64,
-                a: cfg.clear_color[3] as f64,
-            },
-            docking_ctrl: DockingController {
-                flags: DockFlags::from_bits_retain(cfg.docking.dockspace_flags.bits()),
-            },
+            ui_state,
+            clear_color: clear_color(cfg),
+            uploaded_textures: HashMap::new(),
+            targets,
+            viewports,
         })
     }
 
+    /// Installs the renderer's viewport callbacks. They capture the renderer's address, so this
+    /// runs once the window has reached its final place in `App::window`.
+    fn install_viewport_renderer(&mut self) {
+        #[cfg(feature = "multi-viewport")]
+        if self.viewports {
+            imgui_wgpu::multi_viewport::enable(&mut self.imgui.renderer, &mut self.imgui.context);
+        }
+    }
+
+    /// Forwards an event to the platform backend, routing secondary viewport events when
+    /// multi-viewports are enabled.
+    fn handle_platform_event(&mut self, event: &winit::event::Event<()>) {
+        #[cfg(feature = "multi-viewport")]
+        if self.viewports {
+            let _ = winit_mvp::handle_event_with_multi_viewport(
+                &mut self.imgui.platform,
+                &mut self.imgui.context,
+                &self.window,
+                event,
+            );
+            return;
+        }
+        self.imgui
+            .platform
+            .handle_event(&mut self.imgui.context, &self.window, event);
+    }
+
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         if new_size.width > 0 && new_size.height > 0 {
             self.surface_desc.width = new_size.width;
             self.surface_desc.height = new_size.height;
             self.surface.configure(&self.device, &self.surface_desc);
+            self.targets
+                .resize(&self.device, new_size.width, new_size.height);
         }
     }
 
-    fn render<F>(&mut self, gui: &mut F, docking: &DockingConfig) -> Result<(), DearAppError>
+    fn render<F>(
+        &mut self,
+        gui: &mut F,
+        docking: &DockingConfig,
+        on_render: Option<&mut Box<dyn FnMut(&mut RenderContext)>>,
+    ) -> Result<(), DearAppError>
     where
         F: FnMut(&imgui::Ui, &mut AddOns),
     {
@@ -761,57 +1717,17 @@ impl AppWindow {
             .platform
             .prepare_frame(&self.window, &mut self.imgui.context);
         let ui = self.imgui.context.frame();
-
-        // Optional fullscreen dockspace
-        if docking.enable && docking.auto_dockspace {
-            let viewport = ui.main_viewport();
-            // Host window always covering the main viewport
-            ui.set_next_window_viewport(viewport.id());
-            let pos = viewport.pos();
-            let size = viewport.size();
-            // NO_BACKGROUND if passthru central node
-            let current_flags = DockFlags::from_bits_retain(self.docking_ctrl.flags.bits());
-            let mut win_flags = docking.host_window_flags;
-            if current_flags.contains(DockFlags::PASSTHRU_CENTRAL_NODE) {
-                win_flags |= WindowFlags::NO_BACKGROUND;
-            }
-            ui.window(docking.host_window_name)
-                .flags(win_flags)
-                .position([pos[0], pos[1]], imgui::Condition::Always)
-                .size([size[0], size[1]], imgui::Condition::Always)
-                .build(|| {
-                    let ds_flags = DockFlags::from_bits_retain(current_flags.bits());
-                    let _ = ui.dockspace_over_main_viewport_with_flags(Id::from(0u32), ds_flags);
-                });
-        }
-
-        // Build add-ons view
-        let mut addons = AddOns {
-            #[cfg(feature = "implot")]
-            implot: self.implot_ctx.as_ref(),
-            #[cfg(not(feature = "implot"))]
-            implot: None,
-            #[cfg(feature = "imnodes")]
-            imnodes: self.imnodes_ctx.as_ref(),
-            #[cfg(not(feature = "imnodes"))]
-            imnodes: None,
-            #[cfg(feature = "implot3d")]
-            implot3d: self.implot3d_ctx.as_ref(),
-            #[cfg(not(feature = "implot3d"))]
-            implot3d: None,
-            docking: DockingApi {
-                ctrl: &mut self.docking_ctrl,
-            },
-            gpu: GpuApi {
+        self.ui_state.frame(
+            ui,
+            gui,
+            docking,
+            GpuApi {
                 device: &self.device,
                 queue: &self.queue,
                 renderer: &mut self.imgui.renderer,
+                uploaded_textures: &mut self.uploaded_textures,
             },
-            _marker: PhantomData,
-        };
-
-        // Call user GUI
-        gui(&ui, &mut addons);
+        );
 
         // Keep OS cursor/IME state in sync with Dear ImGui's per-frame intent.
         self.imgui
@@ -836,52 +1752,59 @@ impl AppWindow {
             }
         };
 
-        let view = frame
-            .texture
-            .create_view(&wgpu::TextureViewDescriptor::default());
+        let view = frame.texture.create_view(&wgpu::TextureViewDescriptor {
+            format: Some(self.targets.format),
+            ..Default::default()
+        });
         let mut encoder = self
             .device
             .create_command_encoder(&wgpu::CommandEncoderDescriptor {
                 label: Some("Render Encoder"),
             });
 
-        {
-            let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(self.clear_color),
-                        store: wgpu::StoreOp::Store,
-                    },
-                    depth_slice: None,
-                })],
-                depth_stencil_attachment: None,
-                timestamp_writes: None,
-                occlusion_query_set: None,
-                multiview_mask: None,
-            });
-
-            self.imgui
-                .renderer
-                .new_frame()
-                .map_err(DearAppError::FramePrepare)?;
-            self.imgui
-                .renderer
-                .render_draw_data(draw_data, &mut rpass)
-                .map_err(DearAppError::Render)?;
-        }
+        encode_frame(
+            self.targets.context(
+                &self.device,
+                &self.queue,
+                &mut encoder,
+                &view,
+                (self.surface_desc.width, self.surface_desc.height),
+            ),
+            self.clear_color,
+            on_render,
+            &mut self.imgui.renderer,
+            draw_data,
+        )?;
 
         self.queue.submit(Some(encoder.finish()));
         self.queue.present(frame);
+        self.ui_state.post_swap();
         if reconfigure_after_present {
             self.surface.configure(&self.device, &self.surface_desc);
         }
+
+        // Secondary viewports are rendered after the main window has been presented.
+        #[cfg(feature = "multi-viewport")]
+        if self.viewports {
+            self.imgui.context.update_platform_windows();
+            self.imgui.context.render_platform_windows_default();
+        }
         Ok(())
     }
 }
 
+impl Drop for AppWindow {
+    fn drop(&mut self) {
+        // Platform windows and the test engine must be detached before the ImGui context is
+        // destroyed.
+        #[cfg(feature = "multi-viewport")]
+        if self.viewports {
+            winit_mvp::shutdown_multi_viewport_support(&mut self.imgui.context);
+        }
+        self.ui_state.shutdown();
+    }
+}
+
 struct App<F>
 where
     F: FnMut(&imgui::Ui, &mut AddOns) + 'static,
@@ -892,6 +1815,8 @@ where
     gui: F,
     cbs: RunnerCallbacks,
     last_wake: Instant,
+    /// Redraw deadline requested through the context's `AnimationClock` by the last frame.
+    animation_deadline: Option<Instant>,
 }
 
 impl<F> App<F>
@@ -906,7 +1831,22 @@ where
             gui,
             cbs,
             last_wake: Instant::now(),
+            animation_deadline: None,
+        }
+    }
+
+    /// Requests a redraw once the pending animation deadline has passed and returns the deadline
+    /// that is still ahead, if any.
+    fn poll_animation_deadline(&mut self, now: Instant) -> Option<Instant> {
+        let deadline = self.animation_deadline?;
+        if now < deadline {
+            return Some(deadline);
+        }
+        self.animation_deadline = None;
+        if let Some(window) = &self.window {
+            window.window.request_redraw();
         }
+        None
     }
 }
 
@@ -920,6 +1860,9 @@ where
                 Ok(window) => {
                     self.window = Some(window);
                     info!("Window created successfully");
+                    if let Some(w) = self.window.as_mut() {
+                        w.install_viewport_renderer();
+                    }
                     if let Some(cb) = self.cbs.on_post_init.as_mut() {
                         if let Some(w) = self.window.as_mut() {
                             cb(&mut w.imgui.context);
@@ -957,17 +1900,35 @@ where
                     if let Some(cb) = self.cbs.on_event.as_mut() {
                         cb(&full_event, &window.window, &mut window.imgui.context);
                     }
-                    window.imgui.platform.handle_event(
-                        &mut window.imgui.context,
-                        &window.window,
-                        &full_event,
-                    );
+                    window.handle_platform_event(&full_event);
 
-                    if let Err(e) = window.render(&mut self.gui, &self.cfg.docking) {
+                    // Secondary viewports are drawn from the main window's frame.
+                    if window_id != window.window.id() {
+                        return;
+                    }
+                    // ImGui may create OS windows for new viewports while rendering.
+                    #[cfg(feature = "multi-viewport")]
+                    let _event_loop_guard = window
+                        .viewports
+                        .then(|| winit_mvp::set_event_loop_for_frame(event_loop));
+
+                    if let Err(e) = window.render(
+                        &mut self.gui,
+                        &self.cfg.docking,
+                        self.cbs.on_render.as_mut(),
+                    ) {
                         error!("Render error: {e}; attempting to recover by recreating GPU state");
                         need_recreate = true;
-                    } else if matches!(self.cfg.redraw, RedrawMode::Poll) {
-                        window.window.request_redraw();
+                    } else {
+                        self.animation_deadline = window
+                            .imgui
+                            .context
+                            .animation_clock()
+                            .next_redraw_in()
+                            .map(|delay| Instant::now() + delay);
+                        if matches!(self.cfg.redraw, RedrawMode::Poll) {
+                            window.window.request_redraw();
+                        }
                     }
                 }
 
@@ -979,6 +1940,7 @@ where
                             self.window = Some(window);
                             info!("Successfully recreated window and GPU state after error");
                             if let Some(window) = self.window.as_mut() {
+                                window.install_viewport_renderer();
                                 if let Some(cb) = self.cbs.on_post_init.as_mut() {
                                     cb(&mut window.imgui.context);
                                 }
@@ -1003,6 +1965,13 @@ where
                     None => return,
                 };
 
+                if window_id == window.window.id()
+                    && let Some(cb) = self.cbs.on_window_event.as_mut()
+                    && cb(&event, &window.window, &mut window.imgui.context)
+                {
+                    return;
+                }
+
                 let full_event: winit::event::Event<()> = winit::event::Event::WindowEvent {
                     window_id,
                     event: event.clone(),
@@ -1010,13 +1979,27 @@ where
                 if let Some(cb) = self.cbs.on_event.as_mut() {
                     cb(&full_event, &window.window, &mut window.imgui.context);
                 }
-                window.imgui.platform.handle_event(
-                    &mut window.imgui.context,
-                    &window.window,
-                    &full_event,
-                );
+                window.handle_platform_event(&full_event);
+
+                // Secondary viewport windows are resized and closed by Dear ImGui itself.
+                if window_id != window.window.id() {
+                    return;
+                }
 
                 match event {
+                    WindowEvent::DroppedFile(path) => {
+                        window.ui_state.hovered_files.retain(|p| *p != path);
+                        window.ui_state.dropped_files.push(path);
+                        window.window.request_redraw();
+                    }
+                    WindowEvent::HoveredFile(path) => {
+                        window.ui_state.hovered_files.push(path);
+                        window.window.request_redraw();
+                    }
+                    WindowEvent::HoveredFileCancelled => {
+                        window.ui_state.hovered_files.clear();
+                        window.window.request_redraw();
+                    }
                     WindowEvent::Resized(physical_size) => {
                         window.resize(physical_size);
                         window.window.request_redraw();
@@ -1040,7 +2023,22 @@ where
         }
     }
 
+    /// Wake-up posted by a [`RunnerHandle`].
+    fn user_event(&mut self, _event_loop: &ActiveEventLoop, _event: ()) {
+        if let Some(window) = &self.window {
+            window.window.request_redraw();
+        }
+    }
+
     fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        // Queued tests drive the UI themselves; keep frames coming until they finish.
+        if let Some(window) = &self.window
+            && window.ui_state.is_running_tests()
+        {
+            event_loop.set_control_flow(ControlFlow::Poll);
+            window.window.request_redraw();
+            return;
+        }
         match self.cfg.redraw {
             RedrawMode::Poll => {
                 event_loop.set_control_flow(ControlFlow::Poll);
@@ -1048,9 +2046,10 @@ where
                     window.window.request_redraw();
                 }
             }
-            RedrawMode::Wait => {
-                event_loop.set_control_flow(ControlFlow::Wait);
-            }
+            RedrawMode::Wait => match self.poll_animation_deadline(Instant::now()) {
+                Some(deadline) => event_loop.set_control_flow(ControlFlow::WaitUntil(deadline)),
+                None => event_loop.set_control_flow(ControlFlow::Wait),
+            },
             RedrawMode::WaitUntil { fps } => {
                 let frame = Duration::from_secs_f32(1.0f32 / fps.max(1.0));
                 let now = Instant::now();
@@ -1070,7 +2069,10 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{AppBuilder, DearAppError};
+    use super::{
+        AppBuilder, DearAppError, SurfaceFormatPreference, WindowConfig, WindowIcon,
+        pick_alpha_mode, pick_surface_format,
+    };
 
     #[test]
     fn app_builder_run_requires_frame_callback_without_starting_event_loop() {
@@ -1084,4 +2086,80 @@ mod tests {
             "AppBuilder::run requires an on_frame callback"
         );
     }
+
+    #[test]
+    fn window_config_forwards_attributes_and_validates_icon() {
+        let cfg = WindowConfig {
+            icon: Some(WindowIcon::from_rgba(vec![255; 16 * 16 * 4], 16, 16)),
+            transparent: true,
+            decorations: false,
+            always_on_top: true,
+            min_size: Some((320.0, 240.0)),
+            fullscreen: true,
+            ..WindowConfig::default()
+        };
+        let attrs = cfg.attributes("Tool", (800.0, 600.0)).unwrap();
+        assert_eq!(attrs.title, "Tool");
+        assert!(attrs.transparent);
+        assert!(!attrs.decorations);
+        assert!(attrs.window_icon.is_some());
+        assert_eq!(attrs.window_level, winit::window::WindowLevel::AlwaysOnTop);
+        assert!(attrs.min_inner_size.is_some());
+        assert!(attrs.max_inner_size.is_none());
+        assert!(attrs.fullscreen.is_some());
+
+        let bad = WindowConfig {
+            icon: Some(WindowIcon::from_rgba(vec![0; 10], 16, 16)),
+            ..WindowConfig::default()
+        };
+        assert!(matches!(
+            bad.attributes("Tool", (800.0, 600.0)),
+            Err(DearAppError::WindowIcon(_))
+        ));
+    }
+
+    #[test]
+    fn transparent_windows_prefer_compositing_alpha_modes() {
+        use wgpu::CompositeAlphaMode as Mode;
+        assert_eq!(
+            pick_alpha_mode(true, &[Mode::Opaque, Mode::PostMultiplied]),
+            Mode::PostMultiplied
+        );
+        assert_eq!(pick_alpha_mode(true, &[Mode::Opaque]), Mode::Auto);
+        assert_eq!(pick_alpha_mode(false, &[Mode::PreMultiplied]), Mode::Auto);
+    }
+
+    #[test]
+    fn surface_format_preference_reinterprets_when_kind_is_missing() {
+        use wgpu::TextureFormat as F;
+        let both = [F::Bgra8Unorm, F::Bgra8UnormSrgb];
+        let linear_only = [F::Bgra8Unorm];
+        let srgb = SurfaceFormatPreference::Srgb;
+        let linear = SurfaceFormatPreference::Linear;
+
+        assert_eq!(
+            pick_surface_format(srgb, &both),
+            (F::Bgra8UnormSrgb, F::Bgra8UnormSrgb)
+        );
+        assert_eq!(
+            pick_surface_format(linear, &both),
+            (F::Bgra8Unorm, F::Bgra8Unorm)
+        );
+        // No sRGB format offered: configure the linear one and view it as sRGB.
+        assert_eq!(
+            pick_surface_format(srgb, &linear_only),
+            (F::Bgra8Unorm, F::Bgra8UnormSrgb)
+        );
+        assert_eq!(
+            pick_surface_format(
+                SurfaceFormatPreference::Exact(F::Rgba8UnormSrgb),
+                &[F::Rgba8Unorm]
+            ),
+            (F::Rgba8Unorm, F::Rgba8UnormSrgb)
+        );
+        assert_eq!(
+            pick_surface_format(SurfaceFormatPreference::Exact(F::Rgba16Float), &both),
+            (F::Bgra8UnormSrgb, F::Bgra8UnormSrgb)
+        );
+    }
 }
@@ -79,6 +79,7 @@ fn main() {
     println!("cargo:rerun-if-changed=src/wasm_bindings_pregenerated.rs");
     println!("cargo:rerun-if-changed=src/imgui_test_engine_hooks.cpp");
     println!("cargo:rerun-if-changed=src/platform_io_hooks.cpp");
+    println!("cargo:rerun-if-changed=src/debug_upstream_config.h");
     println!("cargo:rerun-if-changed=src/stack_layout_shim.cpp");
     println!("cargo:rerun-if-changed=src/stack_layout_imgui_externs.cpp.inc");
     println!("cargo:rerun-if-changed=src/stack_layout_imgui_item_add.cpp.inc");
@@ -93,6 +94,7 @@ fn main() {
     println!("cargo:rerun-if-env-changed=IMGUI_SYS_FORCE_BUILD");
     println!("cargo:rerun-if-env-changed=IMGUI_SYS_PREBUILT_URL");
     println!("cargo:rerun-if-env-changed=IMGUI_SYS_USE_CMAKE");
+    println!("cargo:rerun-if-env-changed=IMGUI_SYS_ASAN");
     println!("cargo:rerun-if-env-changed=DEAR_IMGUI_RS_REGEN_BINDINGS");
     println!("cargo:rerun-if-env-changed=DOCS_RS");
     println!("cargo:rerun-if-env-changed=CARGO_NET_OFFLINE");
@@ -146,6 +148,7 @@ fn main() {
     // (we still prefer prebuilt if compatible, including freetype variants).
     let force_build = cfg!(feature = "build-from-source")
         || cfg!(feature = "test-engine")
+        || cfg!(feature = "debug-upstream")
         || env::var("IMGUI_SYS_FORCE_BUILD").is_ok();
 
     // Try prebuilt dear_imgui first (static lib) unless force_build
@@ -586,6 +589,34 @@ fn build_platform_io_hooks(cfg: &BuildConfig) {
     build.compile("dear_imgui_sys_platform_io_hooks");
 }
 
+fn debug_upstream_config_header(cfg: &BuildConfig) -> String {
+    // Absolute path so downstream C++ builds that replay DEP_DEAR_IMGUI_DEFINE_* find it too.
+    let header = cfg.manifest_dir.join("src/debug_upstream_config.h");
+    format!("\"{}\"", header.display().to_string().replace('\\', "/"))
+}
+
+fn configure_debug_upstream(build: &mut cc::Build, cfg: &BuildConfig) {
+    if !cfg!(feature = "debug-upstream") {
+        return;
+    }
+    build.define(
+        "IMGUI_USER_CONFIG",
+        debug_upstream_config_header(cfg).as_str(),
+    );
+    if build_support::parse_bool_env("IMGUI_SYS_ASAN") {
+        if cfg.is_msvc() {
+            build.flag("/fsanitize=address");
+        } else {
+            build.flag("-fsanitize=address");
+            build.flag("-fno-omit-frame-pointer");
+        }
+        println!(
+            "cargo:warning=dear-imgui-sys: IMGUI_SYS_ASAN=1 compiles Dear ImGui with AddressSanitizer; \
+             the final binary must link the ASan runtime (e.g. RUSTFLAGS=\"-Zsanitizer=address\" on nightly)."
+        );
+    }
+}
+
 fn any_backend_shim_enabled() -> bool {
     cfg!(feature = "backend-shim-android")
         || cfg!(feature = "backend-shim-dx11")
@@ -599,6 +630,7 @@ fn new_native_cpp_build(cfg: &BuildConfig) -> cc::Build {
     build_support::configure_cpp_runtime_linkage(&mut build, &cfg.target_os, &cfg.target_env);
     build.include(cfg.imgui_src());
     build.define("IMGUI_USE_WCHAR32", None);
+    configure_debug_upstream(&mut build, cfg);
     if cfg.is_msvc() && cfg.is_windows() {
         build.flag("/EHsc");
         let use_static = cfg.use_static_crt();
@@ -806,6 +838,12 @@ fn export_include_paths(cfg: &BuildConfig) {
         }
     );
     println!("cargo:DEFINE_IMGUI_USE_WCHAR32=1");
+    if cfg!(feature = "debug-upstream") {
+        println!(
+            "cargo:DEFINE_IMGUI_USER_CONFIG={}",
+            debug_upstream_config_header(cfg)
+        );
+    }
 }
 
 fn expected_lib_name(target_env: &str) -> String {
@@ -0,0 +1,36 @@
+//! IM_ASSERT handler for the `debug-upstream` feature.
+//!
+//! `src/debug_upstream_config.h` redirects every `IM_ASSERT` in the bundled C++ to
+//! [`dear_imgui_sys_assert_failed`], which prints the failing expression and C++ source location
+//! and aborts the process. It does not panic: the bindings declare the ImGui functions `extern "C"`,
+//! so unwinding back through their C++ frames would be undefined behavior.
+
+use std::ffi::{CStr, c_char, c_int};
+
+fn lossy(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        return String::from("<unknown>");
+    }
+    unsafe { CStr::from_ptr(ptr) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Called by `IM_ASSERT` when its expression is false.
+///
+/// # Safety
+/// `expr` and `file` must be null or valid NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dear_imgui_sys_assert_failed(
+    expr: *const c_char,
+    file: *const c_char,
+    line: c_int,
+) -> ! {
+    eprintln!(
+        "Dear ImGui assertion failed: {} ({}:{line})\n{}",
+        lossy(expr),
+        lossy(file),
+        std::backtrace::Backtrace::force_capture()
+    );
+    std::process::abort();
+}
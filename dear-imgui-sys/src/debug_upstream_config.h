@@ -0,0 +1,16 @@
+// IMGUI_USER_CONFIG injected by the `debug-upstream` feature of dear-imgui-sys.
+// This file is part of dear-imgui-rs and is licensed under MIT OR Apache-2.0.
+//
+// Enables Dear ImGui's paranoid self-checks and routes IM_ASSERT failures to the Rust side,
+// which prints the failing expression and source location and aborts.
+
+#pragma once
+
+#define IMGUI_DEBUG_PARANOID
+
+#ifdef __cplusplus
+extern "C"
+#endif
+void dear_imgui_sys_assert_failed(const char* expr, const char* file, int line);
+
+#define IM_ASSERT(_EXPR) ((_EXPR) ? (void)0 : dear_imgui_sys_assert_failed(#_EXPR, __FILE__, __LINE__))
@@ -8,6 +8,8 @@
 //! - **docking**: Always enabled in this crate
 //! - **freetype**: Enable FreeType font rasterizer support
 //! - **wasm**: Enable WebAssembly compatibility
+//! - **debug-upstream**: Build the bundled C++ with `IMGUI_DEBUG_PARANOID` and abort with the failing
+//!   expression on `IM_ASSERT` failures (optionally with AddressSanitizer via `IMGUI_SYS_ASAN=1`)
 //! - **backend-shim-\***: Expose selected repository-owned backend shim modules
 //!   for low-level integrations
 //!
@@ -91,6 +93,9 @@
 mod ffi;
 pub use ffi::*;
 
+#[cfg(feature = "debug-upstream")]
+mod debug_upstream;
+
 /// Optional backend shim entry points for downstream integrations.
 ///
 /// These modules expose the repository-owned C shim ABI for selected official
@@ -0,0 +1,68 @@
+//! Frame cost of list widgets over 10k entries.
+//!
+//! Run with `cargo bench -p dear-imgui-rs --bench list_widgets`.
+
+use dear_imgui_rs as imgui;
+use std::borrow::Cow;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITEMS: usize = 10_000;
+const FRAMES: u32 = 200;
+
+fn setup_context() -> imgui::Context {
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+    ctx
+}
+
+fn bench(name: &str, ctx: &mut imgui::Context, mut draw: impl FnMut(&imgui::Ui)) -> Duration {
+    // Warm up so the clipper knows the row height and allocations have settled.
+    for _ in 0..5 {
+        let ui = ctx.frame();
+        ui.window("bench")
+            .size([400.0, 400.0], imgui::Condition::Always)
+            .build(|| draw(ui));
+        let _ = ctx.render();
+    }
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        let ui = ctx.frame();
+        ui.window("bench")
+            .size([400.0, 400.0], imgui::Condition::Always)
+            .build(|| draw(ui));
+        black_box(ctx.render());
+    }
+    let per_frame = start.elapsed() / FRAMES;
+    println!("{name:<32} {per_frame:>12.2?} / frame");
+    per_frame
+}
+
+fn main() {
+    let labels: Vec<String> = (0..ITEMS).map(|i| format!("item {i}")).collect();
+    let mut ctx = setup_context();
+    let mut current = ITEMS / 2;
+
+    let all = bench("list_box build_simple_string", &mut ctx, |ui| {
+        ui.list_box_config("list")
+            .size([0.0, 300.0])
+            .build_simple_string(ui, &mut current, &labels);
+    });
+    let clipped = bench("list_box build_with_getter", &mut ctx, |ui| {
+        ui.list_box_config("list")
+            .size([0.0, 300.0])
+            .build_with_getter(ui, &mut current, labels.len(), |i| {
+                Cow::Borrowed(labels[i].as_str())
+            });
+    });
+    println!(
+        "speedup: {:.1}x",
+        all.as_secs_f64() / clipped.as_secs_f64().max(f64::EPSILON)
+    );
+}
@@ -0,0 +1,150 @@
+//! Custom memory allocator hooks
+//!
+//! Routes Dear ImGui's heap allocations (`ImGui::MemAlloc`/`MemFree`) through an
+//! [`ImGuiAllocator`], so engines with tracked or arena allocators can account for
+//! Dear ImGui's memory and share one heap across DLL boundaries.
+//!
+use std::ffi::c_void;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::ptr;
+use std::sync::OnceLock;
+
+use crate::context::binding::{CTX_MUTEX, has_live_contexts, no_current_context};
+use crate::error::{ImGuiError, ImGuiResult};
+use crate::sys;
+
+static ALLOCATOR: OnceLock<&'static dyn ImGuiAllocator> = OnceLock::new();
+
+/// A heap allocator for Dear ImGui, installed with [`set_allocator`].
+///
+/// Dear ImGui frees memory without passing the allocation size; allocators that need it must
+/// store it themselves (e.g. in a header in front of the returned block).
+///
+/// # Safety
+///
+/// `alloc` must return null or a pointer to at least `size` writable bytes, aligned like
+/// `malloc` (16 bytes on 64-bit targets), that stays valid until it is passed to `free`. The
+/// allocator is shared by all contexts on all threads.
+pub unsafe trait ImGuiAllocator: Send + Sync {
+    /// Allocates `size` bytes.
+    fn alloc(&self, size: usize) -> *mut c_void;
+
+    /// Frees a block returned by [`ImGuiAllocator::alloc`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` is a non-null pointer returned by `alloc` that was not freed yet.
+    unsafe fn free(&self, ptr: *mut c_void);
+}
+
+/// Installs `allocator` for all Dear ImGui allocations of the process.
+///
+/// The allocator can be installed once and stays installed for the rest of the process. Returns
+/// an error if an allocator was already installed or a context is alive on any thread.
+///
+/// # Safety
+///
+/// Dear ImGui must not have allocated anything yet: call this before creating any `Context`,
+/// `SharedFontAtlas`, `FontConfig` or texture data, on any thread. Memory allocated with the
+/// default allocator would otherwise be freed through `allocator`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// use std::alloc::{Layout, alloc, dealloc};
+/// use std::ffi::c_void;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// // Counts live bytes; the size is kept in a 16-byte header in front of each block.
+/// struct Counting(AtomicUsize);
+///
+/// unsafe impl ImGuiAllocator for Counting {
+///     fn alloc(&self, size: usize) -> *mut c_void {
+///         let layout = Layout::from_size_align(size + 16, 16).unwrap();
+///         unsafe {
+///             let base = alloc(layout);
+///             if base.is_null() {
+///                 return std::ptr::null_mut();
+///             }
+///             base.cast::<usize>().write(size);
+///             self.0.fetch_add(size, Ordering::Relaxed);
+///             base.add(16).cast()
+///         }
+///     }
+///
+///     unsafe fn free(&self, ptr: *mut c_void) {
+///         unsafe {
+///             let base = ptr.cast::<u8>().sub(16);
+///             let size = base.cast::<usize>().read();
+///             self.0.fetch_sub(size, Ordering::Relaxed);
+///             dealloc(base, Layout::from_size_align_unchecked(size + 16, 16));
+///         }
+///     }
+/// }
+///
+/// static COUNTING: Counting = Counting(AtomicUsize::new(0));
+///
+/// // First thing in `main`, before Dear ImGui allocated anything.
+/// unsafe { set_allocator(&COUNTING) }.expect("no context yet");
+/// let ctx = Context::create();
+/// ```
+#[doc(alias = "SetAllocatorFunctions")]
+pub unsafe fn set_allocator(allocator: &'static dyn ImGuiAllocator) -> ImGuiResult<()> {
+    let _guard = CTX_MUTEX.lock();
+    if !no_current_context() || has_live_contexts() {
+        return Err(ImGuiError::invalid_operation(
+            "set_allocator() must be called before any context is created",
+        ));
+    }
+    if ALLOCATOR.set(allocator).is_err() {
+        return Err(ImGuiError::invalid_operation(
+            "set_allocator() was already called",
+        ));
+    }
+    unsafe {
+        sys::igSetAllocatorFunctions(
+            Some(alloc_trampoline),
+            Some(free_trampoline),
+            ptr::null_mut(),
+        );
+    }
+    Ok(())
+}
+
+/// Returns true if an allocator was installed with [`set_allocator`].
+pub fn has_custom_allocator() -> bool {
+    ALLOCATOR.get().is_some()
+}
+
+fn abort_if_panicked<T>(ctx: &str, res: std::thread::Result<T>) -> T {
+    match res {
+        Ok(v) => v,
+        Err(_) => {
+            eprintln!("dear-imgui-rs: panic in ImGuiAllocator::{ctx}");
+            std::process::abort();
+        }
+    }
+}
+
+unsafe extern "C" fn alloc_trampoline(size: usize, _user_data: *mut c_void) -> *mut c_void {
+    let Some(allocator) = ALLOCATOR.get() else {
+        return ptr::null_mut();
+    };
+    abort_if_panicked(
+        "alloc",
+        catch_unwind(AssertUnwindSafe(|| allocator.alloc(size))),
+    )
+}
+
+unsafe extern "C" fn free_trampoline(ptr: *mut c_void, _user_data: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    if let Some(allocator) = ALLOCATOR.get() {
+        abort_if_panicked(
+            "free",
+            catch_unwind(AssertUnwindSafe(|| unsafe { allocator.free(ptr) })),
+        );
+    }
+}
@@ -49,6 +49,7 @@ impl Color {
     ///
     /// ImGui packs colors with IM_COL32(R,G,B,A) into `(A<<24)|(B<<16)|(G<<8)|R`.
     /// This converts that ABGR-packed u32 into an RGBA float Color.
+    #[doc(alias = "from_u32")]
     pub fn from_imgui_u32(abgr: u32) -> Self {
         unsafe {
             let v = sys::igColorConvertU32ToFloat4(abgr);
@@ -67,6 +68,9 @@ impl Color {
     }
 
     /// Pack to ImGui ImU32 ABGR order `(A<<24)|(B<<16)|(G<<8)|R`.
+    ///
+    /// This is the value `IM_COL32(r, g, b, a)` produces.
+    #[doc(alias = "IM_COL32", alias = "to_u32")]
     pub fn to_imgui_u32(self) -> u32 {
         unsafe {
             sys::igColorConvertFloat4ToU32(sys::ImVec4_c {
@@ -162,6 +166,43 @@ impl Color {
         };
         Self::new(r + m, g + m, b + m, 1.0)
     }
+
+    /// Convert from sRGB to linear RGB (alpha is unchanged).
+    ///
+    /// Dear ImGui treats style and draw-list colors as sRGB. Use this when feeding colors to a
+    /// renderer or shader that works in linear space.
+    pub fn to_linear(self) -> Self {
+        fn channel(c: f32) -> f32 {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        Self::new(channel(self.r), channel(self.g), channel(self.b), self.a)
+    }
+
+    /// Convert from linear RGB to sRGB (alpha is unchanged). Inverse of [`Color::to_linear`].
+    pub fn to_srgb(self) -> Self {
+        fn channel(c: f32) -> f32 {
+            if c <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        }
+        Self::new(channel(self.r), channel(self.g), channel(self.b), self.a)
+    }
+
+    /// Returns the `index`-th color of an endless palette of distinct, equally bright hues.
+    ///
+    /// Hues are spaced by the golden ratio, so neighbouring indices stay easy to tell apart.
+    /// Handy for plot series, graph nodes or per-item highlights.
+    pub fn palette(index: usize) -> Self {
+        const GOLDEN_RATIO_CONJUGATE: f32 = 0.618_034;
+        let hue = (index as f32 * GOLDEN_RATIO_CONJUGATE).fract();
+        Self::from_hsv01(hue, 0.6, 0.85)
+    }
 }
 
 impl Default for Color {
@@ -5,6 +5,7 @@
 //! your chosen backend. See struct-level docs for details and caveats about one
 //! active context at a time.
 
+mod animation;
 pub(crate) mod binding;
 mod clipboard;
 mod core;
@@ -12,15 +13,17 @@ mod fonts;
 mod frame;
 mod platform;
 mod settings;
+mod shell;
 mod suspended;
 #[cfg(test)]
 mod tests;
 mod texture_registry;
 
+pub use self::animation::AnimationClock;
 pub use self::core::{Context, ContextAliveToken};
 pub use self::fonts::ContextFontStackToken;
 pub use self::frame::{FrameLifecycleState, FramePrepareOptions, FrameResult, FrameToken};
-pub use self::suspended::SuspendedContext;
+pub use self::suspended::{ScopedContext, SuspendedContext};
 pub use self::texture_registry::RegisteredUserTexture;
 
 pub(crate) use self::texture_registry::unregister_user_texture_from_all_contexts;
@@ -0,0 +1,149 @@
+use std::cell::Cell;
+use std::time::Duration;
+
+use crate::sys;
+
+use super::Context;
+
+// Dear ImGui shows the InputText cursor while `fmod(CursorAnim, 1.20) <= 0.80`.
+const INPUT_TEXT_BLINK_PERIOD: f32 = 1.20;
+const INPUT_TEXT_BLINK_VISIBLE: f32 = 0.80;
+
+/// Animation time and redraw requests for one context.
+///
+/// Event loops that only redraw on input (power-saving modes) would otherwise freeze animated
+/// widgets between two events. Widgets consult the clock through [`Ui::animation_clock`] to read
+/// a frame-rate independent time and to tell the host loop that another frame is needed; the host
+/// reads [`AnimationClock::next_redraw_in`] from [`Context::animation_clock`] after rendering and
+/// schedules a redraw accordingly.
+///
+/// Time is Dear ImGui's own clock (`ImGui::GetTime()`, the sum of `io.DeltaTime`), so animations
+/// progress at the same speed regardless of how often frames are produced.
+///
+/// [`Ui::animation_clock`]: crate::Ui::animation_clock
+/// [`Context::animation_clock`]: crate::Context::animation_clock
+#[derive(Debug, Default)]
+pub struct AnimationClock {
+    now: Cell<f64>,
+    frame_requested: Cell<bool>,
+    animate_until: Cell<f64>,
+    wake_at: Cell<Option<f64>>,
+}
+
+impl AnimationClock {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Called right after `NewFrame()` with the new `ImGui::GetTime()`.
+    pub(crate) fn begin_frame(&self, now: f64) {
+        self.now.set(now);
+        self.frame_requested.set(false);
+        if self.wake_at.get().is_some_and(|at| at <= now) {
+            self.wake_at.set(None);
+        }
+    }
+
+    /// Schedules a wake-up for the next blink of an active InputText cursor.
+    ///
+    /// # Safety
+    /// `ctx` must be the live context this clock belongs to, inside its frame.
+    pub(crate) unsafe fn track_input_text_cursor(&self, ctx: *mut sys::ImGuiContext) {
+        let g = unsafe { &*ctx };
+        let state = &g.InputTextState;
+        if g.ActiveId == 0 || state.ID != g.ActiveId || !g.IO.ConfigInputTextCursorBlink {
+            return;
+        }
+        let t = state.CursorAnim.max(0.0) % INPUT_TEXT_BLINK_PERIOD;
+        let next_edge = if t <= INPUT_TEXT_BLINK_VISIBLE {
+            INPUT_TEXT_BLINK_VISIBLE - t
+        } else {
+            INPUT_TEXT_BLINK_PERIOD - t
+        };
+        self.request_wake_after(Duration::from_secs_f32(next_edge));
+    }
+
+    /// Seconds elapsed on the context clock at the start of the current frame.
+    pub fn time(&self) -> f64 {
+        self.now.get()
+    }
+
+    /// Returns the position within a repeating cycle of `period` seconds, in `0.0..1.0`.
+    ///
+    /// Only reading the phase does not keep frames coming; pair it with
+    /// [`Self::request_frame`] (or [`Self::animate_for`]) while the animation is visible.
+    pub fn phase(&self, period: f32) -> f32 {
+        if !(period.is_finite() && period > 0.0) {
+            return 0.0;
+        }
+        (self.now.get() % f64::from(period) / f64::from(period)) as f32
+    }
+
+    /// Returns true during the first `duty` fraction of each `period`, e.g. for blinking cursors.
+    ///
+    /// Also schedules a wake-up for the next on/off transition so the blink keeps going while the
+    /// host loop is otherwise idle.
+    pub fn blink(&self, period: f32, duty: f32) -> bool {
+        if !(period.is_finite() && period > 0.0) {
+            return true;
+        }
+        let duty = duty.clamp(0.0, 1.0);
+        let phase = self.phase(period);
+        let on = phase < duty;
+        let next_edge = if on { duty } else { 1.0 };
+        let remaining = f64::from((next_edge - phase).max(0.0) * period);
+        self.request_wake_after(Duration::from_secs_f64(remaining));
+        on
+    }
+
+    /// Requests another frame as soon as possible. Continuous animations call this every frame.
+    pub fn request_frame(&self) {
+        self.frame_requested.set(true);
+    }
+
+    /// Keeps frames coming for `duration` from now, e.g. while a toggle knob slides into place.
+    pub fn animate_for(&self, duration: Duration) {
+        let until = self.now.get() + duration.as_secs_f64();
+        if until > self.animate_until.get() {
+            self.animate_until.set(until);
+        }
+    }
+
+    /// Requests a single frame once `delay` has elapsed, without rendering in between.
+    pub fn request_wake_after(&self, delay: Duration) {
+        let at = self.now.get() + delay.as_secs_f64();
+        match self.wake_at.get() {
+            Some(existing) if existing <= at => {}
+            _ => self.wake_at.set(Some(at)),
+        }
+    }
+
+    /// Returns true if any animation wants another frame right away.
+    pub fn is_animating(&self) -> bool {
+        self.frame_requested.get() || self.animate_until.get() > self.now.get()
+    }
+
+    /// How long the host may wait before rendering the next frame.
+    ///
+    /// `Some(Duration::ZERO)` means an animation is running and the next frame should be rendered
+    /// immediately, `Some(delay)` that a wake-up was requested, and `None` that nothing is
+    /// pending. The value refers to the last frame started on this context.
+    pub fn next_redraw_in(&self) -> Option<Duration> {
+        if self.is_animating() {
+            return Some(Duration::ZERO);
+        }
+        self.wake_at
+            .get()
+            .map(|at| Duration::from_secs_f64((at - self.now.get()).max(0.0)))
+    }
+}
+
+impl Context {
+    /// Returns the animation clock shared with this context's [`Ui`](crate::Ui).
+    ///
+    /// On-demand event loops call [`AnimationClock::next_redraw_in`] after rendering a frame to
+    /// decide when the next one is due.
+    pub fn animation_clock(&self) -> &AnimationClock {
+        &self.ui.animation
+    }
+}
@@ -1,5 +1,7 @@
 use parking_lot::ReentrantMutex;
+use std::cell::RefCell;
 use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::sys;
 
@@ -7,13 +9,47 @@ use crate::sys;
 // Dear ImGui active context
 pub(crate) static CTX_MUTEX: ReentrantMutex<()> = parking_lot::const_reentrant_mutex(());
 
+thread_local! {
+    // Contexts created through `Context`/`SuspendedContext` and not yet destroyed. `Context` is
+    // `!Send`, so a per-thread list sees all contexts a scoped activation can restore.
+    static LIVE_CONTEXTS: RefCell<Vec<*mut sys::ImGuiContext>> = const { RefCell::new(Vec::new()) };
+}
+
+// Number of live contexts across all threads, for checks that must see every context (e.g.
+// installing an allocator), not just the ones owned by the calling thread.
+static LIVE_CONTEXT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+pub(super) fn register_live_context(ctx: *mut sys::ImGuiContext) {
+    LIVE_CONTEXTS.with(|live| live.borrow_mut().push(ctx));
+    LIVE_CONTEXT_COUNT.fetch_add(1, Ordering::SeqCst);
+}
+
+pub(super) fn unregister_live_context(ctx: *mut sys::ImGuiContext) {
+    let removed = LIVE_CONTEXTS.with(|live| {
+        let mut live = live.borrow_mut();
+        let before = live.len();
+        live.retain(|&live_ctx| live_ctx != ctx);
+        before - live.len()
+    });
+    LIVE_CONTEXT_COUNT.fetch_sub(removed, Ordering::SeqCst);
+}
+
+pub(super) fn is_live_context(ctx: *mut sys::ImGuiContext) -> bool {
+    LIVE_CONTEXTS.with(|live| live.borrow().contains(&ctx))
+}
+
+/// Returns true if a `Context` is alive on any thread.
+pub(crate) fn has_live_contexts() -> bool {
+    LIVE_CONTEXT_COUNT.load(Ordering::SeqCst) > 0
+}
+
 pub(super) fn clear_current_context() {
     unsafe {
         sys::igSetCurrentContext(ptr::null_mut());
     }
 }
 
-pub(super) fn no_current_context() -> bool {
+pub(crate) fn no_current_context() -> bool {
     let ctx = unsafe { sys::igGetCurrentContext() };
     ctx.is_null()
 }
@@ -7,7 +7,10 @@ use crate::fonts::SharedFontAtlas;
 use crate::io::Io;
 use crate::sys;
 
-use super::binding::{CTX_MUTEX, clear_current_context, no_current_context, with_bound_context};
+use super::binding::{
+    CTX_MUTEX, clear_current_context, no_current_context, register_live_context,
+    unregister_live_context, with_bound_context,
+};
 use super::texture_registry::unregister_user_textures_for_context;
 
 /// An imgui context.
@@ -18,6 +21,8 @@ use super::texture_registry::unregister_user_textures_for_context;
 ///
 /// If you need more than one context, you can use suspended contexts. As long as only one context
 /// is active at a time, it's possible to have multiple independent contexts.
+/// [`SuspendedContext::activate_scoped`](crate::SuspendedContext::activate_scoped) switches to a suspended context for a scope and back to
+/// the active one afterwards.
 ///
 /// # Examples
 ///
@@ -47,21 +52,34 @@ pub struct Context {
     // Boxed so the raw PlatformIO user-data pointer remains stable.
     // Interior mutability and reentrancy guarding live inside ClipboardContext.
     pub(in crate::context) clipboard_ctx: Box<ClipboardContext>,
+    // Boxed for the same reason; `None` keeps Dear ImGui's built-in shell function.
+    pub(in crate::context) open_in_shell: Option<Box<super::shell::OpenInShellHandler>>,
     pub(in crate::context) ui: crate::ui::Ui,
 }
 
 /// A weak token that indicates whether a `Context` is still alive.
 #[derive(Clone, Debug)]
-pub struct ContextAliveToken(Weak<()>);
+pub struct ContextAliveToken {
+    alive: Weak<()>,
+    animation: Weak<super::AnimationClock>,
+}
 
 impl ContextAliveToken {
-    pub(in crate::context) fn new(alive: &Rc<()>) -> Self {
-        Self(Rc::downgrade(alive))
+    pub(in crate::context) fn new(alive: &Rc<()>, animation: &Rc<super::AnimationClock>) -> Self {
+        Self {
+            alive: Rc::downgrade(alive),
+            animation: Rc::downgrade(animation),
+        }
+    }
+
+    /// Returns the animation clock of the originating `Context`, if it is still alive.
+    pub(crate) fn animation_clock(&self) -> Option<Rc<super::AnimationClock>> {
+        self.animation.upgrade()
     }
 
     /// Returns true if the originating `Context` has not been dropped.
     pub fn is_alive(&self) -> bool {
-        self.0.upgrade().is_some()
+        self.alive.upgrade().is_some()
     }
 }
 
@@ -103,7 +121,7 @@ impl Context {
     /// Useful for extension crates that store raw pointers and need to avoid calling into FFI
     /// after the owning `Context` has been dropped.
     pub fn alive_token(&self) -> ContextAliveToken {
-        ContextAliveToken::new(&self.alive)
+        ContextAliveToken::new(&self.alive, &self.ui.animation)
     }
 
     // removed legacy create_or_panic variants (use create()/try_create())
@@ -157,9 +175,11 @@ impl Context {
         unsafe {
             sys::igSetCurrentContext(raw);
         }
+        register_live_context(raw);
 
         let alive = Rc::new(());
-        let ui = crate::ui::Ui::new(raw, ContextAliveToken::new(&alive));
+        let animation = Rc::new(super::AnimationClock::new());
+        let ui = crate::ui::Ui::new(raw, ContextAliveToken::new(&alive, &animation));
 
         Ok(Context {
             raw,
@@ -170,6 +190,7 @@ impl Context {
             platform_name: None,
             renderer_name: None,
             clipboard_ctx: Box::new(ClipboardContext::dummy()),
+            open_in_shell: None,
             ui,
         })
     }
@@ -246,6 +267,7 @@ impl Drop for Context {
                     clear_current_context();
                 }
                 sys::igDestroyContext(self.raw);
+                unregister_live_context(self.raw);
             }
         }
     }
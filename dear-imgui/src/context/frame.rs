@@ -143,6 +143,7 @@ dear-imgui-winit::WinitPlatform::prepare_frame().",
                 );
             }
             sys::igNewFrame();
+            self.ui.animation.begin_frame(sys::igGetTime());
         }
         &mut self.ui
     }
@@ -184,6 +185,7 @@ dear-imgui-winit::WinitPlatform::prepare_frame().",
         self.assert_can_render_unlocked("Context::render()");
 
         unsafe {
+            self.ui.animation.track_input_text_cursor(self.raw);
             sys::igRender();
             let dd = sys::igGetDrawData();
             if dd.is_null() {
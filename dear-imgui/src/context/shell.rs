@@ -0,0 +1,128 @@
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::c_char;
+
+use crate::sys;
+
+use super::Context;
+use super::binding::CTX_MUTEX;
+
+type OpenInShellFn = unsafe extern "C" fn(*mut sys::ImGuiContext, *const c_char) -> bool;
+
+/// Rust handler installed as `PlatformIO.Platform_OpenInShellFn`.
+pub(in crate::context) struct OpenInShellHandler {
+    handler: RefCell<Box<dyn FnMut(&str) -> bool>>,
+    // Dear ImGui's built-in shell function, restored when the handler is cleared.
+    previous: Option<OpenInShellFn>,
+}
+
+impl fmt::Debug for OpenInShellHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OpenInShellHandler")
+            .field("handler", &(&**self.handler.borrow() as *const _))
+            .field("previous", &self.previous)
+            .finish()
+    }
+}
+
+unsafe extern "C" fn open_in_shell(ctx: *mut sys::ImGuiContext, path: *const c_char) -> bool {
+    let result = std::panic::catch_unwind(|| {
+        let platform_io = unsafe {
+            if ctx.is_null() {
+                sys::igGetPlatformIO_Nil()
+            } else {
+                sys::igGetPlatformIO_ContextPtr(ctx)
+            }
+        };
+        if platform_io.is_null() || path.is_null() {
+            return false;
+        }
+        let user_data = unsafe { (*platform_io).Platform_OpenInShellUserData };
+        if user_data.is_null() {
+            return false;
+        }
+        let handler = unsafe { &*(user_data as *const OpenInShellHandler) };
+        let Ok(mut handler) = handler.handler.try_borrow_mut() else {
+            return false;
+        };
+        let path = unsafe { CStr::from_ptr(path) }.to_string_lossy();
+        handler(path.as_ref())
+    });
+    result.unwrap_or_else(|_| {
+        eprintln!("Open-in-shell handler panicked");
+        std::process::abort();
+    })
+}
+
+impl Context {
+    /// Sets the handler Dear ImGui calls to open a URL or path, e.g. from
+    /// [`Ui::text_link_open_url`](crate::Ui::text_link_open_url).
+    ///
+    /// The handler receives the URL and returns true if it was opened. Use it to route links
+    /// through your own launcher, confirm before leaving the app, or log clicks in tests. Without a
+    /// handler, Dear ImGui uses its built-in shell function (`ShellExecute`, `open` or `xdg-open`)
+    /// where available.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # let mut ctx = dear_imgui_rs::Context::create();
+    /// ctx.set_open_in_shell_handler(|url| {
+    ///     println!("open {url}");
+    ///     true
+    /// });
+    /// ```
+    #[doc(alias = "Platform_OpenInShellFn")]
+    pub fn set_open_in_shell_handler<F>(&mut self, handler: F)
+    where
+        F: FnMut(&str) -> bool + 'static,
+    {
+        let _guard = CTX_MUTEX.lock();
+        let previous = self
+            .open_in_shell
+            .take()
+            .map(|h| h.previous)
+            .unwrap_or_else(|| unsafe { (*self.shell_platform_io()).Platform_OpenInShellFn });
+        let handler = Box::new(OpenInShellHandler {
+            handler: RefCell::new(Box::new(handler)),
+            previous,
+        });
+
+        // Function pointers cannot cross the wasm import boundary, see
+        // `Context::set_clipboard_backend`.
+        #[cfg(not(target_arch = "wasm32"))]
+        unsafe {
+            let platform_io = self.shell_platform_io();
+            (*platform_io).Platform_OpenInShellFn = Some(open_in_shell);
+            (*platform_io).Platform_OpenInShellUserData =
+                handler.as_ref() as *const OpenInShellHandler as *mut _;
+        }
+
+        self.open_in_shell = Some(handler);
+    }
+
+    /// Removes the handler set with [`Context::set_open_in_shell_handler`], restoring Dear
+    /// ImGui's built-in shell function.
+    pub fn clear_open_in_shell_handler(&mut self) {
+        let _guard = CTX_MUTEX.lock();
+        let Some(handler) = self.open_in_shell.take() else {
+            return;
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        unsafe {
+            let platform_io = self.shell_platform_io();
+            (*platform_io).Platform_OpenInShellFn = handler.previous;
+            (*platform_io).Platform_OpenInShellUserData = std::ptr::null_mut();
+        }
+        drop(handler);
+    }
+
+    fn shell_platform_io(&self) -> *mut sys::ImGuiPlatformIO {
+        let platform_io = unsafe { sys::igGetPlatformIO_ContextPtr(self.raw) };
+        if platform_io.is_null() {
+            panic!("Context open-in-shell handlers require a valid ImGui context");
+        }
+        platform_io
+    }
+}
@@ -1,3 +1,4 @@
+use std::ops::{Deref, DerefMut};
 use std::ptr;
 use std::rc::Rc;
 
@@ -6,7 +7,9 @@ use crate::fonts::SharedFontAtlas;
 use crate::sys;
 
 use super::Context;
-use super::binding::{CTX_MUTEX, clear_current_context, no_current_context};
+use super::binding::{
+    CTX_MUTEX, clear_current_context, is_live_context, no_current_context, register_live_context,
+};
 
 impl Context {
     /// Suspends this context so another context can be the active context
@@ -70,8 +73,10 @@ impl SuspendedContext {
             });
         }
 
+        register_live_context(raw);
         let alive = Rc::new(());
-        let ui = crate::ui::Ui::new(raw, super::core::ContextAliveToken::new(&alive));
+        let animation = Rc::new(super::AnimationClock::new());
+        let ui = crate::ui::Ui::new(raw, super::core::ContextAliveToken::new(&alive, &animation));
 
         let ctx = Context {
             raw,
@@ -82,6 +87,7 @@ impl SuspendedContext {
             platform_name: None,
             renderer_name: None,
             clipboard_ctx: Box::new(ClipboardContext::dummy()),
+            open_in_shell: None,
             ui,
         };
 
@@ -108,4 +114,76 @@ impl SuspendedContext {
             Err(self)
         }
     }
+
+    /// Makes this context current until the returned guard is dropped
+    ///
+    /// Unlike [`activate`](Self::activate), this works while another context is active: that
+    /// context is switched out and made current again when the guard drops. Plugin hosts can keep
+    /// their own `Context` active and run each plugin's UI in its own suspended context:
+    ///
+    /// ```
+    /// let mut host = dear_imgui_rs::Context::create();
+    /// let mut plugin = dear_imgui_rs::SuspendedContext::create();
+    /// {
+    ///     let mut active = plugin.activate_scoped();
+    ///     active.io_mut().set_display_size([320.0, 240.0]);
+    ///     // `host` is not current here: calling its frame/render methods panics.
+    /// }
+    /// host.io_mut().set_display_size([640.0, 480.0]);
+    /// ```
+    ///
+    /// `Ui` handles always run on the context that created them, so a host `Ui` stays usable
+    /// while a plugin context is active.
+    pub fn activate_scoped(&mut self) -> ScopedContext<'_> {
+        let _guard = CTX_MUTEX.lock();
+        let previous = unsafe { sys::igGetCurrentContext() };
+        unsafe {
+            sys::igSetCurrentContext(self.0.raw);
+        }
+        ScopedContext {
+            ctx: &mut self.0,
+            previous,
+        }
+    }
+}
+
+/// A suspended context made current by [`SuspendedContext::activate_scoped`]
+///
+/// Dereferences to the [`Context`]. Dropping it makes the previously current context current
+/// again (or none, if that context was destroyed meanwhile), unless the current context was
+/// changed in the meantime.
+#[derive(Debug)]
+pub struct ScopedContext<'a> {
+    ctx: &'a mut Context,
+    previous: *mut sys::ImGuiContext,
+}
+
+impl Deref for ScopedContext<'_> {
+    type Target = Context;
+
+    fn deref(&self) -> &Context {
+        self.ctx
+    }
+}
+
+impl DerefMut for ScopedContext<'_> {
+    fn deref_mut(&mut self) -> &mut Context {
+        self.ctx
+    }
+}
+
+impl Drop for ScopedContext<'_> {
+    fn drop(&mut self) {
+        let _guard = CTX_MUTEX.lock();
+        if !self.ctx.is_current_context() {
+            return;
+        }
+        if self.previous.is_null() || is_live_context(self.previous) {
+            unsafe {
+                sys::igSetCurrentContext(self.previous);
+            }
+        } else {
+            clear_current_context();
+        }
+    }
 }
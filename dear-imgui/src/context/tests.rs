@@ -101,6 +101,54 @@ fn io_font_global_scale_uses_owner_context_not_current_context() {
     drop(ctx_a);
 }
 
+#[test]
+fn scoped_activation_restores_the_previous_context() {
+    let _guard = crate::test_support::imgui_context_guard();
+    let mut host = Context::create();
+    let mut plugin_a = super::SuspendedContext::create();
+    let mut plugin_b = super::SuspendedContext::create();
+
+    {
+        let mut active_a = plugin_a.activate_scoped();
+        assert_eq!(unsafe { crate::sys::igGetCurrentContext() }, active_a.raw);
+        active_a.io_mut().set_display_size([32.0, 32.0]);
+
+        {
+            let active_b = plugin_b.activate_scoped();
+            assert_eq!(unsafe { crate::sys::igGetCurrentContext() }, active_b.raw);
+        }
+        assert_eq!(unsafe { crate::sys::igGetCurrentContext() }, active_a.raw);
+
+        // The host is switched out while the plugin context is active.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = host.draw_data();
+        }));
+        assert!(result.is_err());
+    }
+
+    assert_eq!(unsafe { crate::sys::igGetCurrentContext() }, host.raw);
+    assert_eq!(plugin_a.0.io().display_size(), [32.0, 32.0]);
+    host.io_mut().set_display_size([64.0, 64.0]);
+
+    drop(plugin_b);
+    drop(plugin_a);
+    drop(host);
+}
+
+#[test]
+fn scoped_activation_does_not_restore_a_destroyed_context() {
+    let _guard = crate::test_support::imgui_context_guard();
+    let host = Context::create();
+    let mut plugin = super::SuspendedContext::create();
+
+    let active = plugin.activate_scoped();
+    drop(host);
+    drop(active);
+
+    assert!(unsafe { crate::sys::igGetCurrentContext() }.is_null());
+    drop(plugin);
+}
+
 #[test]
 fn frame_lifecycle_requires_receiver_to_be_current_context() {
     let _guard = crate::test_support::imgui_context_guard();
@@ -385,3 +433,73 @@ fn user_texture_registration_is_idempotent_and_unregister_is_noop_when_missing()
     ctx.unregister_user_texture(&mut texture);
     ctx.unregister_user_texture(&mut texture);
 }
+
+#[test]
+fn animation_clock_tracks_requests_across_frames() {
+    use std::time::Duration;
+
+    let _guard = crate::test_support::imgui_context_guard();
+    let mut ctx = Context::create();
+    let _ = ctx.font_atlas_mut().build();
+    ctx.prepare_frame(super::FramePrepareOptions::new([640.0, 480.0], 0.25));
+
+    let ui = ctx.frame();
+    assert_eq!(ui.animation_clock().next_redraw_in(), None);
+    ui.animation_clock().animate_for(Duration::from_millis(400));
+    ui.animation_clock()
+        .request_wake_after(Duration::from_secs(2));
+    let _ = ctx.render();
+    assert_eq!(ctx.animation_clock().next_redraw_in(), Some(Duration::ZERO));
+
+    // 0.25s later the 400ms animation is still running.
+    let _ = ctx.frame();
+    let _ = ctx.render();
+    assert!(ctx.animation_clock().is_animating());
+
+    // Once it settles only the pending wake-up remains.
+    let _ = ctx.frame();
+    let _ = ctx.render();
+    let wait = ctx.animation_clock().next_redraw_in().unwrap();
+    assert!(wait > Duration::ZERO && wait <= Duration::from_millis(1500));
+
+    let ui = ctx.frame();
+    ui.animation_clock().request_frame();
+    let _ = ctx.render();
+    assert_eq!(ctx.animation_clock().next_redraw_in(), Some(Duration::ZERO));
+}
+
+#[test]
+fn animation_clock_blink_schedules_next_edge() {
+    let clock = super::AnimationClock::new();
+    clock.begin_frame(0.1);
+    assert!(clock.blink(1.0, 0.5));
+    let wait = clock.next_redraw_in().unwrap();
+    assert!((wait.as_secs_f64() - 0.4).abs() < 1e-6);
+
+    clock.begin_frame(0.6);
+    assert!(!clock.blink(1.0, 0.5));
+    let wait = clock.next_redraw_in().unwrap();
+    assert!((wait.as_secs_f64() - 0.4).abs() < 1e-6);
+    assert_eq!(clock.phase(0.0), 0.0);
+    assert!(!clock.is_animating());
+
+    clock.begin_frame(1.5);
+    assert_eq!(clock.next_redraw_in(), None);
+}
+
+#[test]
+fn ui_from_raw_in_frame_shares_animation_clock() {
+    let _guard = crate::test_support::imgui_context_guard();
+    let mut ctx = Context::create();
+    let _ = ctx.font_atlas_mut().build();
+    ctx.prepare_frame(super::FramePrepareOptions::new([640.0, 480.0], 1.0 / 60.0));
+
+    let raw = ctx.as_raw();
+    let token = ctx.alive_token();
+    let _ = ctx.frame();
+    let ui = unsafe { crate::Ui::from_raw_in_frame(raw, token) };
+    ui.animation_clock().request_frame();
+    drop(ui);
+    let _ = ctx.render();
+    assert!(ctx.animation_clock().is_animating());
+}
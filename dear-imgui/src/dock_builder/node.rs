@@ -5,8 +5,9 @@ use crate::ui::Ui;
 /// Opaque reference to an ImGui dock node, valid for the duration of the current frame.
 ///
 /// This wraps a raw `ImGuiDockNode*` and exposes a few read-only queries.
-/// Instances are created via `DockBuilder::node()` / `DockBuilder::central_node()`
-/// with a lifetime tied to a `Ui` reference.
+/// Instances are created via `DockBuilder::node()` / `DockBuilder::central_node()` /
+/// `DockBuilder::window_node()` or `Ui::window_dock_node()`, with a lifetime tied to a `Ui`
+/// reference.
 pub struct DockNode<'ui> {
     raw: *mut sys::ImGuiDockNode,
     ui: &'ui Ui,
@@ -19,11 +20,57 @@ pub struct NodeRect {
     pub max: [f32; 2],
 }
 
-pub(super) fn new_dock_node<'ui>(ui: &'ui Ui, raw: *mut sys::ImGuiDockNode) -> DockNode<'ui> {
+pub(crate) fn new_dock_node<'ui>(ui: &'ui Ui, raw: *mut sys::ImGuiDockNode) -> DockNode<'ui> {
     DockNode { raw, ui }
 }
 
 impl<'ui> DockNode<'ui> {
+    /// Wraps `ptr`, or returns `None` if it is null.
+    pub(crate) fn wrap(ui: &'ui Ui, ptr: *mut sys::ImGuiDockNode) -> Option<DockNode<'ui>> {
+        (!ptr.is_null()).then(|| new_dock_node(ui, ptr))
+    }
+
+    /// Returns the ID of this node
+    pub fn id(&self) -> crate::Id {
+        self.ui
+            .run_with_bound_context(|| unsafe { crate::Id::from((*self.raw).ID) })
+    }
+
+    /// Returns the parent of this node, or `None` for a root node
+    pub fn parent(&self) -> Option<DockNode<'ui>> {
+        Self::wrap(self.ui, unsafe { (*self.raw).ParentNode })
+    }
+
+    /// Returns the central node of this node's dock tree, if it has one
+    ///
+    /// Only dock spaces have a central node; it is the area left to the application when
+    /// every other node of the dock space is occupied by docked windows.
+    pub fn central_node(&self) -> Option<DockNode<'ui>> {
+        let root = self.root()?;
+        Self::wrap(self.ui, unsafe { (*root.raw).CentralNode })
+    }
+
+    /// Returns the number of windows docked in this node
+    pub fn window_count(&self) -> usize {
+        usize::try_from(unsafe { (*self.raw).Windows.Size }).unwrap_or(0)
+    }
+
+    /// Returns the ID of the selected tab, or `None` if no tab is selected
+    pub fn selected_tab_id(&self) -> Option<crate::Id> {
+        let id = unsafe { (*self.raw).SelectedTabId };
+        (id != 0).then(|| crate::Id::from(id))
+    }
+
+    /// Returns true if this node currently shows a tab bar
+    ///
+    /// A split node, an empty node, or a node with a hidden or disabled tab bar shows none.
+    pub fn is_tab_bar_visible(&self) -> bool {
+        !self.is_split()
+            && self.window_count() > 0
+            && !self.is_hidden_tab_bar()
+            && !self.is_no_tab_bar()
+    }
+
     /// Returns true if this node is the central node of its hierarchy
     pub fn is_central(&self) -> bool {
         self.ui
@@ -101,11 +148,7 @@ impl<'ui> DockNode<'ui> {
         let ptr = self
             .ui
             .run_with_bound_context(|| unsafe { sys::igDockNodeGetRootNode(self.raw) });
-        if ptr.is_null() {
-            None
-        } else {
-            Some(new_dock_node(self.ui, ptr))
-        }
+        Self::wrap(self.ui, ptr)
     }
 
     /// Returns true if `self` is in the hierarchy of `parent`
@@ -37,6 +37,20 @@ impl DockBuilder {
         }
     }
 
+    /// Returns the dock node hosting the window named `window_name`, or `None` if the window
+    /// does not exist or is floating.
+    pub fn window_node<'ui>(ui: &'ui Ui, window_name: &str) -> Option<DockNode<'ui>> {
+        let ptr = ui.run_with_bound_context(|| unsafe {
+            let window = sys::igFindWindowByName(ui.scratch_txt(window_name));
+            if window.is_null() {
+                std::ptr::null_mut()
+            } else {
+                (*window).DockNode
+            }
+        });
+        DockNode::wrap(ui, ptr)
+    }
+
     /// Returns true if a dock node with the given ID exists this frame.
     pub fn node_exists(ui: &Ui, node_id: Id) -> bool {
         Self::node(ui, node_id).is_some()
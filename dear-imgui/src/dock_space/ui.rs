@@ -1,6 +1,7 @@
 use super::flags::{DockNodeFlags, validate_dock_node_flags};
 use super::validation::{assert_finite_vec2, assert_nonzero_id};
 use super::window_class::WindowClass;
+use crate::dock_builder::DockNode;
 use crate::ui::Ui;
 use crate::{Id, sys};
 use std::ptr;
@@ -288,4 +289,32 @@ impl Ui {
     pub fn is_window_docked(&self) -> bool {
         self.run_with_bound_context(|| unsafe { sys::igIsWindowDocked() })
     }
+
+    /// Returns the dock node hosting the current window, or `None` if it is floating
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// ui.window("Inspector").build(|| {
+    ///     match ui.window_dock_node() {
+    ///         Some(node) if node.is_tab_bar_visible() => ui.text("Docked with other tabs"),
+    ///         Some(_) => ui.text("Docked"),
+    ///         None => ui.text("Floating"),
+    ///     }
+    /// });
+    /// ```
+    pub fn window_dock_node(&self) -> Option<DockNode<'_>> {
+        let ptr = self.run_with_bound_context(|| unsafe {
+            let window = sys::igGetCurrentWindowRead();
+            if window.is_null() {
+                ptr::null_mut()
+            } else {
+                (*window).DockNode
+            }
+        });
+        DockNode::wrap(self, ptr)
+    }
 }
@@ -64,14 +64,40 @@ impl<'ui, T: AsRef<str>> DragDropSource<'ui, T> {
         payload: P,
     ) -> Option<DragDropSourceTooltip<'ui>> {
         unsafe {
-            let payload_size = std::mem::size_of::<TypedPayload<P>>();
-            assert!(
-                payload_size <= i32::MAX as usize,
-                "DragDropSource::begin_payload() payload size exceeds Dear ImGui's i32 payload range"
-            );
+            self.begin_payload_with(payload, |flags| {
+                sys::igBeginDragDropSource(flags.bits() as i32)
+            })
+        }
+    }
+
+    /// Begin drag source with typed payload, using a custom function to start the source
+    ///
+    /// Extensions start drag sources from their own items this way, e.g. ImPlot's
+    /// `BeginDragDropSourceItem` for plot legend entries. `begin` receives the configured flags
+    /// and returns whether the drag source began.
+    ///
+    /// # Safety
+    /// `begin` must behave like `ImGui::BeginDragDropSource` on this `Ui`'s context: when it
+    /// returns true, a drag source must have begun that `ImGui::EndDragDropSource` ends.
+    pub unsafe fn begin_payload_with<P: Copy + 'static>(
+        self,
+        payload: P,
+        begin: impl FnOnce(DragDropSourceFlags) -> bool,
+    ) -> Option<DragDropSourceTooltip<'ui>> {
+        let payload_size = std::mem::size_of::<TypedPayload<P>>();
+        assert!(
+            payload_size <= i32::MAX as usize,
+            "DragDropSource::begin_payload() payload size exceeds Dear ImGui's i32 payload range"
+        );
 
-            let payload = make_typed_payload(payload);
-            self.begin_payload_unchecked(&payload as *const _ as *const ffi::c_void, payload_size)
+        let payload = make_typed_payload(payload);
+        unsafe {
+            self.begin_with(
+                &payload as *const _ as *const ffi::c_void,
+                payload_size,
+                "DragDropSource::begin_payload()",
+                begin,
+            )
         }
     }
 
@@ -91,15 +117,27 @@ impl<'ui, T: AsRef<str>> DragDropSource<'ui, T> {
         ptr: *const ffi::c_void,
         size: usize,
     ) -> Option<DragDropSourceTooltip<'ui>> {
-        validate_payload_submission(
-            self.name.as_ref(),
-            ptr,
-            size,
-            "DragDropSource::begin_payload_unchecked()",
-        );
-        validate_drag_drop_source_flags("DragDropSource::begin_payload_unchecked()", self.flags);
+        unsafe {
+            self.begin_with(
+                ptr,
+                size,
+                "DragDropSource::begin_payload_unchecked()",
+                |flags| sys::igBeginDragDropSource(flags.bits() as i32),
+            )
+        }
+    }
+
+    unsafe fn begin_with(
+        &self,
+        ptr: *const ffi::c_void,
+        size: usize,
+        caller: &str,
+        begin: impl FnOnce(DragDropSourceFlags) -> bool,
+    ) -> Option<DragDropSourceTooltip<'ui>> {
+        validate_payload_submission(self.name.as_ref(), ptr, size, caller);
+        validate_drag_drop_source_flags(caller, self.flags);
         self.ui.run_with_bound_context(|| unsafe {
-            let should_begin = sys::igBeginDragDropSource(self.flags.bits() as i32);
+            let should_begin = begin(self.flags);
 
             if should_begin {
                 sys::igSetDragDropPayload(
@@ -14,6 +14,18 @@ use crate::{Ui, sys};
 pub struct DragDropTarget<'ui>(pub(super) &'ui Ui);
 
 impl<'ui> DragDropTarget<'ui> {
+    /// Wraps a drag drop target begun outside of this crate
+    ///
+    /// Extensions begin targets over their own widgets this way, e.g. ImPlot's
+    /// `BeginDragDropTargetPlot`. The target is ended with `ImGui::EndDragDropTarget` when the
+    /// returned value is dropped.
+    ///
+    /// # Safety
+    /// A drag drop target must have been successfully begun on `ui`'s context and not ended yet.
+    pub unsafe fn from_begun(ui: &'ui Ui) -> Self {
+        Self(ui)
+    }
+
     /// Accept an empty payload
     ///
     /// This is the safest option for drag and drop operations.
@@ -2,7 +2,7 @@ use super::flags::{DragDropPayloadCond, DragDropSourceFlags};
 use super::payload::DragDropPayload;
 use super::source::DragDropSource;
 use super::target::DragDropTarget;
-use crate::{Ui, sys};
+use crate::{Id, Ui, sys};
 
 impl Ui {
     /// Creates a new drag drop source configuration
@@ -60,6 +60,83 @@ impl Ui {
         }
     }
 
+    /// Creates a drag drop target for an arbitrary screen-space rectangle of the current window
+    ///
+    /// Unlike [`Ui::drag_drop_target`] this does not need an item: `id` identifies the target
+    /// (e.g. from [`Ui::get_id`]) and must not be zero. When several targets overlap, Dear ImGui
+    /// delivers to the smallest one, so item targets inside the rectangle keep working.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let min = ui.cursor_screen_pos();
+    /// let max = [min[0] + 200.0, min[1] + 100.0];
+    /// if let Some(target) = ui.drag_drop_target_rect(min, max, ui.get_id("drop_zone")) {
+    ///     if let Some(Ok(payload)) = target.accept_payload::<u32, _>("ASSET", DragDropTargetFlags::NONE) {
+    ///         println!("Dropped asset {}", payload.data);
+    ///     }
+    /// }
+    /// ```
+    #[doc(alias = "BeginDragDropTargetCustom")]
+    pub fn drag_drop_target_rect(
+        &self,
+        min: [f32; 2],
+        max: [f32; 2],
+        id: Id,
+    ) -> Option<DragDropTarget<'_>> {
+        assert!(
+            id.raw() != 0,
+            "Ui::drag_drop_target_rect() id must not be zero"
+        );
+        let bb = sys::ImRect_c {
+            Min: sys::ImVec2_c {
+                x: min[0],
+                y: min[1],
+            },
+            Max: sys::ImVec2_c {
+                x: max[0],
+                y: max[1],
+            },
+        };
+        let should_begin = self
+            .run_with_bound_context(|| unsafe { sys::igBeginDragDropTargetCustom(bb, id.raw()) });
+        should_begin.then(|| DragDropTarget(self))
+    }
+
+    /// Creates a drag drop target covering the whole current window
+    ///
+    /// The target spans the window's inner rectangle (without title bar, menu bar and
+    /// scrollbars) and uses the window ID. Items of the window that are drop targets themselves
+    /// still take priority, which makes this suited for "drop anywhere" areas such as a scene
+    /// view or a dockspace host window.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// ui.window("Scene").build(|| {
+    ///     ui.text("Drop assets anywhere in this window");
+    ///     if let Some(target) = ui.drag_drop_target_window() {
+    ///         if target.accept_payload_empty("ASSET", DragDropTargetFlags::NONE).is_some() {
+    ///             println!("Asset dropped into the scene");
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    pub fn drag_drop_target_window(&self) -> Option<DragDropTarget<'_>> {
+        let should_begin = self.run_with_bound_context(|| unsafe {
+            let window = sys::igGetCurrentWindowRead();
+            if window.is_null() {
+                return false;
+            }
+            sys::igBeginDragDropTargetCustom((*window).InnerRect, (*window).ID)
+        });
+        should_begin.then(|| DragDropTarget(self))
+    }
+
     /// Returns the current drag and drop payload, if any.
     ///
     /// This is a convenience wrapper over `ImGui::GetDragDropPayload`.
@@ -13,6 +13,7 @@ mod id;
 mod loader;
 mod shared;
 mod source;
+mod stack;
 mod state;
 #[cfg(test)]
 mod tests;
@@ -25,6 +26,7 @@ pub use id::FontId;
 pub use loader::{FontLoader, FontLoaderFlags};
 pub use shared::SharedFontAtlas;
 pub use source::FontSource;
+pub use stack::{FontStack, FontStackEntry, MissingGlyphReport, ScriptRange};
 pub use texture::FontAtlasTexture;
 
 pub(crate) use id::{validate_font_for_current_context, validate_font_id_for_current_context};
@@ -148,6 +148,27 @@ impl FontConfig {
         self
     }
 
+    /// Load color glyphs (COLR/CPAL layers and CBDT/sbix bitmaps, e.g. color emoji).
+    ///
+    /// Sets [`FontLoaderFlags::LOAD_COLOR`] without touching the other loader flags. Only the
+    /// FreeType loader (`freetype` feature) renders color glyphs; stb_truetype ignores the flag.
+    /// Adding a color font switches an `Alpha8` atlas to `RGBA32`, see
+    /// [`FontAtlas::set_tex_desired_format`](crate::fonts::FontAtlas::set_tex_desired_format).
+    pub fn load_color(mut self, load_color: bool) -> Self {
+        let flags = FontLoaderFlags::LOAD_COLOR.0;
+        if load_color {
+            self.raw.FontLoaderFlags |= flags;
+        } else {
+            self.raw.FontLoaderFlags &= !flags;
+        }
+        self
+    }
+
+    /// Returns true if this font loads color glyphs, see [`FontConfig::load_color`].
+    pub fn loads_color(&self) -> bool {
+        FontLoaderFlags(self.raw.FontLoaderFlags).contains(FontLoaderFlags::LOAD_COLOR)
+    }
+
     /// Set inclusive glyph ranges to exclude from this font.
     ///
     /// The input is a slice of `(start, end)` pairs. It is converted to Dear ImGui's
@@ -196,6 +217,16 @@ impl FontConfig {
         self
     }
 
+    /// Returns the configured glyph exclude ranges as inclusive `(start, end)` pairs.
+    pub(super) fn glyph_exclude_range_pairs(&self) -> Vec<(u32, u32)> {
+        self.glyph_exclude_ranges
+            .as_deref()
+            .unwrap_or_default()
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect()
+    }
+
     /// Set a custom font loader for this font.
     ///
     /// The loader must be static because Dear ImGui stores the raw `ImFontLoader*` in the
@@ -149,6 +149,7 @@ impl FontAtlas {
     #[doc(alias = "AddFont")]
     pub fn add_font_with_config(&mut self, font_cfg: &FontConfig) -> &mut Font {
         font_cfg.validate_for_add_font("FontAtlas::add_font_with_config()");
+        self.use_rgba_for_color_glyphs(Some(font_cfg));
         unsafe {
             let font_ptr = sys::ImFontAtlas_AddFont(self.raw, font_cfg.raw());
             if font_cfg.raw.MergeMode {
@@ -164,6 +165,7 @@ impl FontAtlas {
         if let Some(cfg) = font_cfg {
             cfg.validate_for_add_font_default("FontAtlas::add_font_default()");
         }
+        self.use_rgba_for_color_glyphs(font_cfg);
         unsafe {
             let cfg_ptr = font_cfg.map_or(ptr::null(), |cfg| cfg.raw());
             let font_ptr = sys::ImFontAtlas_AddFontDefault(self.raw, cfg_ptr);
@@ -193,8 +195,9 @@ impl FontAtlas {
         if let Some(cfg) = font_cfg {
             cfg.validate_for_add_font_with_size("FontAtlas::add_font_from_file_ttf()", size_pixels);
         }
+        let filename_cstr = std::ffi::CString::new(filename).ok()?;
+        self.use_rgba_for_color_glyphs(font_cfg);
         unsafe {
-            let filename_cstr = std::ffi::CString::new(filename).ok()?;
             let cfg_ptr = font_cfg.map_or(ptr::null(), |cfg| cfg.raw());
             let ranges_ptr = glyph_ranges.map_or(ptr::null(), |ranges| ranges.as_ptr());
 
@@ -245,6 +248,7 @@ impl FontAtlas {
             return None;
         }
         let font_data_len = i32::try_from(font_data.len()).ok()?;
+        self.use_rgba_for_color_glyphs(font_cfg);
         unsafe {
             // SAFETY: `AddFontFromMemoryTTF()` stores the pointer for (potential) rebuilds and may
             // free it later depending on `FontDataOwnedByAtlas`. Never pass a pointer into
@@ -315,6 +319,7 @@ impl FontAtlas {
             return None;
         }
         let compressed_len = i32::try_from(compressed_font_data.len()).ok()?;
+        self.use_rgba_for_color_glyphs(font_cfg);
 
         unsafe {
             let cfg = font_cfg.cloned().unwrap_or_default();
@@ -369,6 +374,7 @@ impl FontAtlas {
             return None;
         }
         let base85 = std::ffi::CString::new(compressed_font_data_base85).ok()?;
+        self.use_rgba_for_color_glyphs(font_cfg);
 
         unsafe {
             let cfg = font_cfg.cloned().unwrap_or_default();
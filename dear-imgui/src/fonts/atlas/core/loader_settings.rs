@@ -1,5 +1,7 @@
+use crate::fonts::atlas::config::FontConfig;
 use crate::fonts::atlas::loader::{FontLoader, FontLoaderFlags};
 use crate::sys;
+use crate::texture::TextureFormat;
 
 use super::FontAtlas;
 
@@ -29,10 +31,23 @@ impl FontAtlas {
         unsafe {
             (*self.raw).FontLoaderFlags = flags.0;
         }
+        self.use_rgba_for_color_glyphs(None);
     }
 
     /// Gets the current font loader flags
     pub fn font_loader_flags(&self) -> FontLoaderFlags {
         unsafe { FontLoaderFlags((*self.raw).FontLoaderFlags) }
     }
+
+    /// Switches an `Alpha8` atlas to `RGBA32` when `font_cfg` or the atlas flags request color
+    /// glyphs, which an alpha-only texture would flatten to their coverage.
+    pub(super) fn use_rgba_for_color_glyphs(&mut self, font_cfg: Option<&FontConfig>) {
+        let load_color = font_cfg.is_some_and(FontConfig::loads_color)
+            || self
+                .font_loader_flags()
+                .contains(FontLoaderFlags::LOAD_COLOR);
+        if load_color && self.tex_desired_format() == TextureFormat::Alpha8 {
+            self.set_tex_desired_format(TextureFormat::RGBA32);
+        }
+    }
 }
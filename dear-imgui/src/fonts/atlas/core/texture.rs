@@ -1,4 +1,5 @@
 use crate::sys;
+use crate::texture::TextureFormat;
 
 use super::FontAtlas;
 
@@ -60,6 +61,25 @@ impl FontAtlas {
         }
     }
 
+    /// Returns the format Dear ImGui creates the atlas texture with (`RGBA32` by default).
+    #[doc(alias = "TexDesiredFormat")]
+    pub fn tex_desired_format(&self) -> TextureFormat {
+        unsafe { (*self.raw).TexDesiredFormat.into() }
+    }
+
+    /// Sets the format Dear ImGui creates the atlas texture with.
+    ///
+    /// `Alpha8` uses a quarter of the memory but cannot store color glyphs; adding a font with
+    /// [`FontConfig::load_color`](crate::fonts::FontConfig::load_color) switches back to
+    /// `RGBA32`. Set this before the atlas texture is created (before building the atlas or
+    /// rendering the first frame).
+    #[doc(alias = "TexDesiredFormat")]
+    pub fn set_tex_desired_format(&mut self, format: TextureFormat) {
+        unsafe {
+            (*self.raw).TexDesiredFormat = format.into();
+        }
+    }
+
     /// Get texture reference for the font atlas
     ///
     /// Note: Our Dear ImGui version uses ImTextureRef instead of a simple texture ID
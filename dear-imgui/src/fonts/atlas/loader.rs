@@ -93,6 +93,11 @@ impl FontLoaderFlags {
 
     /// Enable FreeType bitmap glyphs
     pub const BITMAP: Self = Self(1 << 9);
+
+    /// Returns true if all bits of `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
 }
 
 impl std::ops::BitOr for FontLoaderFlags {
@@ -0,0 +1,308 @@
+//! Font fallback chains with per-script priorities.
+//!
+//! Dear ImGui resolves a glyph by walking the sources merged into a font in order and using the
+//! first one that contains it. [`FontStack`] builds such a merged font from a list of entries,
+//! ordering them by priority and adding glyph exclude ranges so that an entry with a higher
+//! priority for a given script wins that script even when it comes later in the chain.
+
+use crate::fonts::{Font, FontId};
+use crate::sys;
+
+use super::core::FontAtlas;
+use super::source::FontSource;
+
+const IMWCHAR_MAX: u32 = if std::mem::size_of::<sys::ImWchar>() == 2 {
+    0xFFFF
+} else {
+    0x10FFFF
+};
+
+/// A named, inclusive range of Unicode code points (usually a Unicode block).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScriptRange {
+    /// Human-readable name used in reports.
+    pub name: &'static str,
+    /// First code point of the range.
+    pub start: u32,
+    /// Last code point of the range (inclusive).
+    pub end: u32,
+}
+
+impl ScriptRange {
+    pub const BASIC_LATIN: Self = Self::new("Basic Latin", 0x0020, 0x007F);
+    pub const LATIN_EXTENDED: Self = Self::new("Latin-1 Supplement/Extended", 0x0080, 0x024F);
+    pub const GREEK: Self = Self::new("Greek", 0x0370, 0x03FF);
+    pub const CYRILLIC: Self = Self::new("Cyrillic", 0x0400, 0x052F);
+    pub const HEBREW: Self = Self::new("Hebrew", 0x0590, 0x05FF);
+    pub const ARABIC: Self = Self::new("Arabic", 0x0600, 0x06FF);
+    pub const DEVANAGARI: Self = Self::new("Devanagari", 0x0900, 0x097F);
+    pub const THAI: Self = Self::new("Thai", 0x0E00, 0x0E7F);
+    pub const GENERAL_PUNCTUATION: Self = Self::new("General Punctuation", 0x2000, 0x206F);
+    pub const CJK_SYMBOLS_AND_PUNCTUATION: Self =
+        Self::new("CJK Symbols and Punctuation", 0x3000, 0x303F);
+    pub const HIRAGANA: Self = Self::new("Hiragana", 0x3040, 0x309F);
+    pub const KATAKANA: Self = Self::new("Katakana", 0x30A0, 0x30FF);
+    pub const CJK_UNIFIED_IDEOGRAPHS: Self = Self::new("CJK Unified Ideographs", 0x4E00, 0x9FFF);
+    pub const HANGUL_SYLLABLES: Self = Self::new("Hangul Syllables", 0xAC00, 0xD7AF);
+    pub const HALFWIDTH_AND_FULLWIDTH_FORMS: Self =
+        Self::new("Halfwidth and Fullwidth Forms", 0xFF00, 0xFFEF);
+    /// Emoji and pictographs. Only reachable when `ImWchar` is 32-bit.
+    pub const EMOJI: Self = Self::new("Emoji", 0x1F300, 0x1FAFF);
+
+    /// Ranges used to classify missing glyphs in [`MissingGlyphReport`].
+    pub const KNOWN: &'static [Self] = &[
+        Self::BASIC_LATIN,
+        Self::LATIN_EXTENDED,
+        Self::GREEK,
+        Self::CYRILLIC,
+        Self::HEBREW,
+        Self::ARABIC,
+        Self::DEVANAGARI,
+        Self::THAI,
+        Self::GENERAL_PUNCTUATION,
+        Self::CJK_SYMBOLS_AND_PUNCTUATION,
+        Self::HIRAGANA,
+        Self::KATAKANA,
+        Self::CJK_UNIFIED_IDEOGRAPHS,
+        Self::HANGUL_SYLLABLES,
+        Self::HALFWIDTH_AND_FULLWIDTH_FORMS,
+        Self::EMOJI,
+    ];
+
+    /// Creates a custom script range.
+    ///
+    /// Panics if `start > end`.
+    pub const fn new(name: &'static str, start: u32, end: u32) -> Self {
+        assert!(start <= end, "ScriptRange::new() requires start <= end");
+        Self { name, start, end }
+    }
+
+    /// Returns true if `c` lies in this range.
+    pub fn contains(&self, c: char) -> bool {
+        (self.start..=self.end).contains(&(c as u32))
+    }
+}
+
+/// One font in a [`FontStack`].
+#[derive(Clone, Debug)]
+pub struct FontStackEntry<'a> {
+    source: FontSource<'a>,
+    priority: i32,
+    script_priorities: Vec<(ScriptRange, i32)>,
+}
+
+impl<'a> FontStackEntry<'a> {
+    /// Creates an entry with priority `0`.
+    pub fn new(source: FontSource<'a>) -> Self {
+        Self {
+            source,
+            priority: 0,
+            script_priorities: Vec::new(),
+        }
+    }
+
+    /// Sets the base priority. Higher priorities come earlier in the fallback chain.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Overrides the priority of this entry for one script range.
+    pub fn script_priority(mut self, script: ScriptRange, priority: i32) -> Self {
+        self.script_priorities.retain(|(s, _)| *s != script);
+        self.script_priorities.push((script, priority));
+        self
+    }
+
+    fn priority_for(&self, script: &ScriptRange) -> i32 {
+        self.script_priorities
+            .iter()
+            .find(|(s, _)| s == script)
+            .map_or(self.priority, |&(_, p)| p)
+    }
+}
+
+/// A merged font built from several sources with per-script priorities.
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let (ui_ttf, jp_ttf, sc_ttf): (&[u8], &[u8], &[u8]) = (&[], &[], &[]);
+/// let font = FontStack::new()
+///     .size_pixels(16.0)
+///     .push(FontStackEntry::new(FontSource::ttf_data(ui_ttf)).priority(10))
+///     .push(FontStackEntry::new(FontSource::ttf_data(sc_ttf)))
+///     .push(
+///         FontStackEntry::new(FontSource::ttf_data(jp_ttf))
+///             .script_priority(ScriptRange::CJK_UNIFIED_IDEOGRAPHS, 20),
+///     )
+///     .build(&mut ctx.font_atlas_mut());
+/// ```
+///
+/// Entries are ordered by base priority (ties keep insertion order) and merged into the first one.
+/// For every script range named by [`FontStackEntry::script_priority`], entries that rank below the
+/// best entry for that range get the range excluded, so the preferred font wins it. Excluded
+/// entries no longer act as fallback for that range.
+#[derive(Clone, Debug, Default)]
+pub struct FontStack<'a> {
+    size_pixels: Option<f32>,
+    entries: Vec<FontStackEntry<'a>>,
+}
+
+impl<'a> FontStack<'a> {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the size applied to entries that do not specify their own.
+    pub fn size_pixels(mut self, size: f32) -> Self {
+        self.size_pixels = Some(size);
+        self
+    }
+
+    /// Appends an entry.
+    pub fn push(mut self, entry: FontStackEntry<'a>) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Returns the entries in fallback order.
+    pub fn entries(&self) -> impl Iterator<Item = &FontStackEntry<'a>> {
+        self.ordered().into_iter()
+    }
+
+    fn ordered(&self) -> Vec<&FontStackEntry<'a>> {
+        let mut ordered: Vec<_> = self.entries.iter().collect();
+        ordered.sort_by_key(|entry| std::cmp::Reverse(entry.priority));
+        ordered
+    }
+
+    /// Resolves the stack into font sources in fallback order, ready for [`FontAtlas::add_font`].
+    pub fn resolve(&self) -> Vec<FontSource<'a>> {
+        let ordered = self.ordered();
+        let mut scripts: Vec<ScriptRange> = Vec::new();
+        for entry in &ordered {
+            for (script, _) in &entry.script_priorities {
+                if !scripts.contains(script) {
+                    scripts.push(*script);
+                }
+            }
+        }
+
+        ordered
+            .iter()
+            .map(|entry| {
+                let excluded: Vec<(u32, u32)> = scripts
+                    .iter()
+                    .filter(|script| {
+                        let best = ordered
+                            .iter()
+                            .map(|other| other.priority_for(script))
+                            .max()
+                            .unwrap_or(i32::MIN);
+                        entry.priority_for(script) < best
+                    })
+                    .filter(|script| script.start <= IMWCHAR_MAX)
+                    .map(|script| (script.start, script.end.min(IMWCHAR_MAX)))
+                    .collect();
+                self.apply(entry.source.clone(), &excluded)
+            })
+            .collect()
+    }
+
+    fn apply(&self, mut source: FontSource<'a>, excluded: &[(u32, u32)]) -> FontSource<'a> {
+        let (size_pixels, config) = match &mut source {
+            FontSource::DefaultFontData {
+                size_pixels,
+                config,
+            }
+            | FontSource::TtfData {
+                size_pixels,
+                config,
+                ..
+            }
+            | FontSource::CompressedTtfData {
+                size_pixels,
+                config,
+                ..
+            }
+            | FontSource::CompressedTtfBase85 {
+                size_pixels,
+                config,
+                ..
+            }
+            | FontSource::TtfFile {
+                size_pixels,
+                config,
+                ..
+            } => (size_pixels, config),
+        };
+        if size_pixels.is_none() {
+            *size_pixels = self.size_pixels;
+        }
+        if !excluded.is_empty() {
+            let cfg = config.take().unwrap_or_default();
+            let mut ranges = cfg.glyph_exclude_range_pairs();
+            ranges.extend_from_slice(excluded);
+            *config = Some(cfg.glyph_exclude_ranges(&ranges));
+        }
+        source
+    }
+
+    /// Adds the merged font to `atlas` and returns its id.
+    ///
+    /// Panics if the stack is empty.
+    pub fn build(&self, atlas: &mut FontAtlas) -> FontId {
+        assert!(
+            !self.entries.is_empty(),
+            "FontStack::build() requires at least one entry"
+        );
+        atlas.add_font(&self.resolve())
+    }
+}
+
+/// Characters a font could not display, grouped by script.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MissingGlyphReport {
+    /// Missing characters, deduplicated, in order of first appearance.
+    pub missing: Vec<char>,
+    /// Known scripts (see [`ScriptRange::KNOWN`]) that contain at least one missing character.
+    pub scripts: Vec<ScriptRange>,
+}
+
+impl MissingGlyphReport {
+    /// Returns true when every character was available.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    pub(crate) fn from_missing(missing: Vec<char>) -> Self {
+        let mut scripts = Vec::new();
+        for script in ScriptRange::KNOWN {
+            if missing.iter().any(|&c| script.contains(c)) {
+                scripts.push(*script);
+            }
+        }
+        Self { missing, scripts }
+    }
+}
+
+impl Font {
+    /// Returns the characters of `text` that no source of this font can display.
+    ///
+    /// Control characters are ignored. Use this after building a [`FontStack`] to detect
+    /// scripts that still lack coverage.
+    pub fn missing_glyphs(&self, text: &str) -> MissingGlyphReport {
+        let mut missing = Vec::new();
+        for c in text.chars() {
+            if c.is_control() || missing.contains(&c) {
+                continue;
+            }
+            if !self.is_glyph_in_font(c) {
+                missing.push(c);
+            }
+        }
+        MissingGlyphReport::from_missing(missing)
+    }
+}
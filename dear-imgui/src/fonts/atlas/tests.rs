@@ -284,3 +284,129 @@ fn set_texture_id_preserves_managed_tex_data_reference() {
     let resolved = unsafe { sys::ImTextureRef_GetTexID(&mut tex_ref) };
     assert_eq!(resolved, texture_id.id() as sys::ImTextureID);
 }
+
+fn source_exclude_ranges(source: &FontSource<'_>) -> Vec<(u32, u32)> {
+    match source {
+        FontSource::DefaultFontData { config, .. }
+        | FontSource::TtfData { config, .. }
+        | FontSource::CompressedTtfData { config, .. }
+        | FontSource::CompressedTtfBase85 { config, .. }
+        | FontSource::TtfFile { config, .. } => config
+            .as_ref()
+            .map(|cfg| cfg.glyph_exclude_range_pairs())
+            .unwrap_or_default(),
+    }
+}
+
+#[test]
+fn font_stack_orders_by_priority_and_excludes_lower_ranked_scripts() {
+    let cjk = ScriptRange::CJK_UNIFIED_IDEOGRAPHS;
+    let stack = FontStack::new()
+        .size_pixels(18.0)
+        .push(FontStackEntry::new(FontSource::default_font()))
+        .push(FontStackEntry::new(FontSource::default_font_with_size(13.0)).priority(5))
+        .push(
+            FontStackEntry::new(
+                FontSource::default_font()
+                    .with_config(FontConfig::new().glyph_exclude_ranges(&[(0x41, 0x5a)])),
+            )
+            .script_priority(cjk, 10),
+        );
+
+    let resolved = stack.resolve();
+    assert_eq!(resolved.len(), 3);
+    assert!(matches!(
+        resolved[0],
+        FontSource::DefaultFontData {
+            size_pixels: Some(13.0),
+            ..
+        }
+    ));
+    assert!(matches!(
+        resolved[1],
+        FontSource::DefaultFontData {
+            size_pixels: Some(18.0),
+            ..
+        }
+    ));
+    assert_eq!(
+        source_exclude_ranges(&resolved[0]),
+        vec![(cjk.start, cjk.end)]
+    );
+    assert_eq!(
+        source_exclude_ranges(&resolved[1]),
+        vec![(cjk.start, cjk.end)]
+    );
+    assert_eq!(source_exclude_ranges(&resolved[2]), vec![(0x41, 0x5a)]);
+}
+
+#[test]
+fn missing_glyph_report_groups_known_scripts() {
+    let report = MissingGlyphReport::from_missing(vec!['中', 'あ', '文']);
+    assert!(!report.is_empty());
+    assert_eq!(
+        report.scripts,
+        vec![ScriptRange::HIRAGANA, ScriptRange::CJK_UNIFIED_IDEOGRAPHS]
+    );
+    assert!(MissingGlyphReport::from_missing(Vec::new()).is_empty());
+}
+
+#[test]
+fn font_config_load_color_toggles_only_the_color_flag() {
+    let cfg = FontConfig::new()
+        .font_loader_flags(FontLoaderFlags::BOLD)
+        .load_color(true);
+    assert!(cfg.loads_color());
+    assert_eq!(
+        FontLoaderFlags(cfg.raw.FontLoaderFlags),
+        FontLoaderFlags::BOLD | FontLoaderFlags::LOAD_COLOR
+    );
+
+    let cfg = cfg.load_color(false);
+    assert!(!cfg.loads_color());
+    assert_eq!(
+        FontLoaderFlags(cfg.raw.FontLoaderFlags),
+        FontLoaderFlags::BOLD
+    );
+}
+
+#[test]
+fn color_fonts_switch_alpha8_atlases_to_rgba() {
+    let mut atlas = FontAtlas::new();
+    atlas.set_tex_desired_format(crate::texture::TextureFormat::Alpha8);
+
+    atlas.add_font_default(None);
+    assert_eq!(
+        atlas.tex_desired_format(),
+        crate::texture::TextureFormat::Alpha8
+    );
+
+    let cfg = FontConfig::new().load_color(true);
+    atlas.add_font_default(Some(&cfg));
+    assert_eq!(
+        atlas.tex_desired_format(),
+        crate::texture::TextureFormat::RGBA32
+    );
+}
+
+#[test]
+fn rejected_color_fonts_keep_the_atlas_format() {
+    let mut atlas = FontAtlas::new();
+    atlas.set_tex_desired_format(crate::texture::TextureFormat::Alpha8);
+
+    let cfg = FontConfig::new().load_color(true);
+    assert!(
+        atlas
+            .add_font_from_memory_ttf(&[0; 16], 13.0, Some(&cfg), None)
+            .is_none()
+    );
+    assert!(
+        atlas
+            .add_font_from_file_ttf("bad\0name.ttf", 13.0, Some(&cfg), None)
+            .is_none()
+    );
+    assert_eq!(
+        atlas.tex_desired_format(),
+        crate::texture::TextureFormat::Alpha8
+    );
+}
@@ -15,6 +15,7 @@
 mod keyboard;
 mod mouse;
 mod shortcut;
+mod shortcut_registry;
 mod text_flags;
 mod ui;
 
@@ -24,4 +25,5 @@ pub use shortcut::{
     InputFlags, ItemKeyOwnerFlags, NextItemShortcutFlags, NextItemShortcutOptions, ShortcutFlags,
     ShortcutGlobalRouteFlags, ShortcutOptions, ShortcutRoute,
 };
+pub use shortcut_registry::{ShortcutBinding, Shortcuts};
 pub use text_flags::{InputScalarFlags, InputTextFlags, InputTextMultilineFlags};
@@ -294,6 +294,11 @@ impl KeyChord {
         Self(self.0 | mods.raw())
     }
 
+    /// Returns the modifier flags of the chord.
+    pub fn mods(self) -> KeyMods {
+        KeyMods::from_bits_truncate(self.0)
+    }
+
     /// Returns the raw `ImGuiKeyChord` value.
     pub fn raw(self) -> sys::ImGuiKeyChord {
         self.0
@@ -0,0 +1,252 @@
+use super::keyboard::{KeyChord, KeyMods};
+use super::shortcut::ShortcutOptions;
+use crate::sys;
+use crate::{Id, Ui};
+
+/// One registered shortcut of a [`Shortcuts`] registry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortcutBinding<A> {
+    /// Action reported when the chord triggers.
+    pub action: A,
+    /// Key chord, e.g. `Ctrl+S`.
+    pub chord: KeyChord,
+    /// Routing policy and flags passed to `Shortcut()`.
+    pub options: ShortcutOptions,
+    /// Owner ID used for routing. `None` uses the current focus scope, like [`Ui::shortcut`].
+    pub owner: Option<Id>,
+}
+
+/// A registry of keyboard shortcuts mapped to application actions.
+///
+/// Register chords once, call [`Shortcuts::poll`] every frame (inside the window that should
+/// own the shortcuts for focused routes) and handle the actions it returns. The same registry
+/// renders the chords in menus, so menu labels never drift from the actual bindings.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// #[derive(Clone, Copy, PartialEq, Debug)]
+/// enum Action {
+///     Save,
+///     Quit,
+/// }
+///
+/// let mut shortcuts = Shortcuts::new();
+/// shortcuts
+///     .register(Action::Save, KeyChord::new(Key::S).with_mods(KeyMods::CTRL))
+///     .register_with(
+///         Action::Quit,
+///         KeyChord::new(Key::Q).with_mods(KeyMods::CTRL),
+///         ShortcutRoute::Global(ShortcutGlobalRouteFlags::NONE),
+///         None,
+///     );
+///
+/// for action in shortcuts.poll(&ui) {
+///     println!("{action:?}");
+/// }
+/// if let Some(_menu) = ui.begin_menu("File") {
+///     shortcuts.menu_item(&ui, "Save", &Action::Save);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Shortcuts<A> {
+    bindings: Vec<ShortcutBinding<A>>,
+    triggered: Vec<A>,
+}
+
+impl<A> Default for Shortcuts<A> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            triggered: Vec::new(),
+        }
+    }
+}
+
+impl<A: Clone + PartialEq> Shortcuts<A> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds `chord` to `action` with Dear ImGui's default (focused) routing.
+    pub fn register(&mut self, action: A, chord: impl Into<KeyChord>) -> &mut Self {
+        self.register_with(action, chord, ShortcutOptions::new(), None)
+    }
+
+    /// Binds `chord` to `action` with explicit routing options and an optional owner ID.
+    pub fn register_with(
+        &mut self,
+        action: A,
+        chord: impl Into<KeyChord>,
+        options: impl Into<ShortcutOptions>,
+        owner: Option<Id>,
+    ) -> &mut Self {
+        self.bindings.push(ShortcutBinding {
+            action,
+            chord: chord.into(),
+            options: options.into(),
+            owner,
+        });
+        self
+    }
+
+    /// Removes every binding of `action`.
+    pub fn unregister(&mut self, action: &A) {
+        self.bindings.retain(|binding| binding.action != *action);
+    }
+
+    /// Returns the registered bindings in registration order.
+    pub fn bindings(&self) -> &[ShortcutBinding<A>] {
+        &self.bindings
+    }
+
+    /// Returns the first chord bound to `action`.
+    pub fn chord(&self, action: &A) -> Option<KeyChord> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.action == *action)
+            .map(|binding| binding.chord)
+    }
+
+    /// Submits every binding to Dear ImGui's shortcut routing and returns the actions triggered
+    /// this frame, in registration order.
+    ///
+    /// Call it once per frame. Focused routes only trigger while the window `poll` is called in
+    /// (or one of its children) is focused.
+    pub fn poll(&mut self, ui: &Ui) -> &[A] {
+        self.triggered.clear();
+        for binding in &self.bindings {
+            let triggered = match binding.owner {
+                Some(owner) => ui.shortcut_with_owner(binding.chord, binding.options, owner),
+                None => ui.shortcut_with_flags(binding.chord, binding.options),
+            };
+            if triggered && !self.triggered.contains(&binding.action) {
+                self.triggered.push(binding.action.clone());
+            }
+        }
+        &self.triggered
+    }
+
+    /// Returns true if `action` was triggered by the last [`Shortcuts::poll`].
+    pub fn triggered(&self, action: &A) -> bool {
+        self.triggered.contains(action)
+    }
+
+    /// Returns the display string of the chord bound to `action`, see [`Ui::key_chord_name`].
+    pub fn display(&self, ui: &Ui, action: &A) -> Option<String> {
+        self.chord(action).map(|chord| ui.key_chord_name(chord))
+    }
+
+    /// Draws a menu item for `action` showing its chord. Returns true when the item is clicked.
+    ///
+    /// Triggering the chord itself is reported by [`Shortcuts::poll`], so handle both:
+    /// `if shortcuts.menu_item(ui, "Save", &Save) || shortcuts.triggered(&Save) { ... }`.
+    pub fn menu_item(&self, ui: &Ui, label: impl AsRef<str>, action: &A) -> bool {
+        match self.display(ui, action) {
+            Some(shortcut) => ui.menu_item_with_shortcut(label, shortcut),
+            None => ui.menu_item(label),
+        }
+    }
+}
+
+impl Ui {
+    /// Call ImGui shortcut routing on behalf of `owner` (e.g. a widget or window ID).
+    #[doc(alias = "Shortcut")]
+    pub fn shortcut_with_owner(
+        &self,
+        key_chord: KeyChord,
+        flags: impl Into<ShortcutOptions>,
+        owner: Id,
+    ) -> bool {
+        let flags = flags.into();
+        self.run_with_bound_context(|| unsafe {
+            sys::igShortcut_ID(key_chord.raw(), flags.raw(), owner.raw())
+        })
+    }
+
+    /// Returns a display string for `key_chord`, e.g. `Ctrl+Shift+S`.
+    ///
+    /// When [`Io::config_macosx_behaviors`](crate::Io::config_macosx_behaviors) is set (the
+    /// default on macOS), modifiers use the macOS names instead, e.g. `Cmd+Shift+S`. Dear ImGui
+    /// maps Cmd to `Ctrl` in that mode, so `KeyMods::CTRL` is shown as `Cmd` and `KeyMods::SUPER`
+    /// as `Ctrl`. See [`Ui::key_chord_symbols`] for the `⇧⌘S` form.
+    #[doc(alias = "GetKeyChordName")]
+    pub fn key_chord_name(&self, key_chord: KeyChord) -> String {
+        self.format_key_chord(key_chord, false)
+    }
+
+    /// Like [`Ui::key_chord_name`], but uses the macOS modifier symbols when
+    /// [`Io::config_macosx_behaviors`](crate::Io::config_macosx_behaviors) is set, e.g. `⇧⌘S`.
+    ///
+    /// The default font (ProggyClean) has no glyphs for ⌘ (U+2318), ⇧ (U+21E7), ⌥ (U+2325) and
+    /// ⌃ (U+2303); merge a font covering them into the atlas, or they render as missing glyphs.
+    pub fn key_chord_symbols(&self, key_chord: KeyChord) -> String {
+        self.format_key_chord(key_chord, true)
+    }
+
+    fn format_key_chord(&self, key_chord: KeyChord, symbols: bool) -> String {
+        let mods = key_chord.mods();
+        let key = key_chord.raw() & !(sys::ImGuiMod_Mask_ as sys::ImGuiKeyChord);
+        let key_name = if key == sys::ImGuiKey_None as sys::ImGuiKeyChord {
+            ""
+        } else {
+            self.run_with_bound_context(|| unsafe {
+                let name_ptr = sys::igGetKeyName(key as sys::ImGuiKey);
+                if name_ptr.is_null() {
+                    return "Unknown";
+                }
+                std::ffi::CStr::from_ptr(name_ptr)
+                    .to_str()
+                    .unwrap_or("Unknown")
+            })
+        };
+
+        let macos = self.io().config_macosx_behaviors();
+        let mut name = String::new();
+        if macos && symbols {
+            for (flag, symbol) in [
+                (KeyMods::SUPER, '⌃'),
+                (KeyMods::ALT, '⌥'),
+                (KeyMods::SHIFT, '⇧'),
+                (KeyMods::CTRL, '⌘'),
+            ] {
+                if mods.contains(flag) {
+                    name.push(symbol);
+                }
+            }
+            name.push_str(key_name);
+            return name;
+        }
+
+        let prefixes = if macos {
+            [
+                (KeyMods::CTRL, "Cmd"),
+                (KeyMods::SHIFT, "Shift"),
+                (KeyMods::ALT, "Option"),
+                (KeyMods::SUPER, "Ctrl"),
+            ]
+        } else {
+            [
+                (KeyMods::CTRL, "Ctrl"),
+                (KeyMods::SHIFT, "Shift"),
+                (KeyMods::ALT, "Alt"),
+                (KeyMods::SUPER, "Super"),
+            ]
+        };
+        for (flag, prefix) in prefixes {
+            if mods.contains(flag) {
+                name.push_str(prefix);
+                name.push('+');
+            }
+        }
+        if key_name.is_empty() {
+            name.pop();
+        }
+        name.push_str(key_name);
+        name
+    }
+}
@@ -32,12 +32,14 @@ mod input_state;
 mod metrics;
 mod mouse;
 mod settings;
+mod synthetic;
 #[cfg(test)]
 mod tests;
 mod validation;
 
 pub use core::Io;
 pub use flags::{BackendFlags, ConfigFlags, ViewportFlags};
+pub use synthetic::InputEvent;
 
 pub(crate) use core::BoundContextGuard;
 pub(crate) use flags::{validate_backend_flags, validate_config_flags, validate_viewport_flags};
@@ -1,4 +1,4 @@
-use crate::io::{Io, assert_finite_vec2};
+use crate::io::{Io, assert_finite_f32, assert_finite_vec2};
 use crate::sys;
 
 impl Io {
@@ -9,6 +9,25 @@ impl Io {
         }
     }
 
+    /// Add an analog key event (e.g. a gamepad trigger) to the input queue.
+    ///
+    /// `value` is the analog position in `0.0..=1.0`.
+    #[doc(alias = "AddKeyAnalogEvent")]
+    pub fn add_key_analog_event(&mut self, key: crate::Key, down: bool, value: f32) {
+        assert_finite_f32("Io::add_key_analog_event()", "value", value);
+        unsafe {
+            sys::ImGuiIO_AddKeyAnalogEvent(self.inner_mut() as *mut _, key.into(), down, value);
+        }
+    }
+
+    /// Add a character input event for every character of `text` to the input queue
+    #[doc(alias = "AddInputCharactersUTF8")]
+    pub fn add_input_characters(&mut self, text: &str) {
+        for character in text.chars() {
+            self.add_input_character(character);
+        }
+    }
+
     /// Add a character input event to the input queue
     pub fn add_input_character(&mut self, character: char) {
         unsafe {
@@ -62,6 +81,33 @@ impl Io {
         }
     }
 
+    /// Set whether input events are queued.
+    ///
+    /// While disabled, the `add_*_event` functions drop their events (Dear ImGui keeps the
+    /// current input state).
+    #[doc(alias = "SetAppAcceptingEvents")]
+    pub fn set_app_accepting_events(&mut self, accepting_events: bool) {
+        unsafe {
+            sys::ImGuiIO_SetAppAcceptingEvents(self.inner_mut() as *mut _, accepting_events);
+        }
+    }
+
+    /// Drop all queued input events that were not processed yet.
+    #[doc(alias = "ClearEventsQueue")]
+    pub fn clear_events_queue(&mut self) {
+        unsafe {
+            sys::ImGuiIO_ClearEventsQueue(self.inner_mut() as *mut _);
+        }
+    }
+
+    /// Release all keys and modifiers, e.g. when input moves to another handler.
+    #[doc(alias = "ClearInputKeys")]
+    pub fn clear_input_keys(&mut self) {
+        unsafe {
+            sys::ImGuiIO_ClearInputKeys(self.inner_mut() as *mut _);
+        }
+    }
+
     /// Notify Dear ImGui that the application window gained or lost focus
     /// This mirrors `io.AddFocusEvent()` in Dear ImGui and is used by platform backends.
     pub fn add_focus_event(&mut self, focused: bool) {
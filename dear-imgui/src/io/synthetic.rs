@@ -0,0 +1,97 @@
+use crate::Key;
+use crate::input::{MouseButton, MouseSource};
+use crate::io::Io;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// One input event for Dear ImGui's input queue.
+///
+/// Platform backends feed the queue through the `Io::add_*_event` functions. `InputEvent`
+/// describes the same events as data, so remote control, input replay, and accessibility
+/// layers can record, send, or generate input and drive the UI without a platform backend.
+/// With the `serde` feature events are serializable.
+///
+/// Mouse events carry the [`MouseSource`] they come from. [`Io::push_input_event`] queues a
+/// source event before each of them, so touch or pen input keeps Dear ImGui's touch behaviors
+/// (e.g. no hover without contact).
+///
+/// Events are processed by the next `Context::frame`; Dear ImGui spreads conflicting events
+/// (like a press and release of the same button) over several frames.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// let click = [
+///     InputEvent::MousePos { pos: [40.0, 20.0], source: MouseSource::TouchScreen },
+///     InputEvent::MouseButton { button: MouseButton::Left, down: true, source: MouseSource::TouchScreen },
+///     InputEvent::MouseButton { button: MouseButton::Left, down: false, source: MouseSource::TouchScreen },
+/// ];
+/// ctx.io_mut().push_input_events(&click);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InputEvent {
+    /// The mouse moved to `pos`, in Dear ImGui coordinates.
+    MousePos { pos: [f32; 2], source: MouseSource },
+    /// A mouse button was pressed or released.
+    MouseButton {
+        button: MouseButton,
+        down: bool,
+        source: MouseSource,
+    },
+    /// The mouse wheel scrolled; see [`Io::add_mouse_wheel_event`].
+    MouseWheel {
+        wheel: [f32; 2],
+        source: MouseSource,
+    },
+    /// A key was pressed or released.
+    ///
+    /// Shortcuts read the modifier state, so send [`Key::ModCtrl`], [`Key::ModShift`], etc. along
+    /// with the left/right modifier keys.
+    Key { key: Key, down: bool },
+    /// An analog key moved to `value` in `0.0..=1.0`, e.g. a gamepad trigger.
+    KeyAnalog { key: Key, down: bool, value: f32 },
+    /// Text was typed.
+    Text(String),
+    /// The application window gained or lost focus.
+    Focus(bool),
+}
+
+impl Io {
+    /// Queue one [`InputEvent`].
+    pub fn push_input_event(&mut self, event: &InputEvent) {
+        match *event {
+            InputEvent::MousePos { pos, source } => {
+                self.add_mouse_source_event(source);
+                self.add_mouse_pos_event(pos);
+            }
+            InputEvent::MouseButton {
+                button,
+                down,
+                source,
+            } => {
+                self.add_mouse_source_event(source);
+                self.add_mouse_button_event(button, down);
+            }
+            InputEvent::MouseWheel { wheel, source } => {
+                self.add_mouse_source_event(source);
+                self.add_mouse_wheel_event(wheel);
+            }
+            InputEvent::Key { key, down } => self.add_key_event(key, down),
+            InputEvent::KeyAnalog { key, down, value } => {
+                self.add_key_analog_event(key, down, value)
+            }
+            InputEvent::Text(ref text) => self.add_input_characters(text),
+            InputEvent::Focus(focused) => self.add_focus_event(focused),
+        }
+    }
+
+    /// Queue several [`InputEvent`]s in order.
+    pub fn push_input_events<'a>(&mut self, events: impl IntoIterator<Item = &'a InputEvent>) {
+        for event in events {
+            self.push_input_event(event);
+        }
+    }
+}
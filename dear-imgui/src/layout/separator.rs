@@ -17,6 +17,13 @@ impl Ui {
         self.run_with_bound_context(|| unsafe { sys::igSeparatorText(text) });
     }
 
+    /// Renders a separator with formatted text, without allocating a `String`
+    /// (see [`Ui::with_fmt`]).
+    #[doc(alias = "SeparatorText")]
+    pub fn separator_with_text_fmt(&self, args: std::fmt::Arguments<'_>) {
+        self.with_fmt(args, |text| self.separator_with_text(text));
+    }
+
     /// Creates a vertical separator
     #[doc(alias = "SeparatorEx")]
     pub fn separator_vertical(&self) {
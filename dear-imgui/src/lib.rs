@@ -321,6 +321,7 @@ pub enum Condition {
 // use std::os::raw::c_char;
 
 // Core modules
+pub use self::allocator::{ImGuiAllocator, has_custom_allocator, set_allocator};
 pub use self::clipboard::{ClipboardBackend, DummyClipboardBackend};
 pub use self::context::*;
 // Note: draw types are now in render module
@@ -334,7 +335,8 @@ pub use self::style::*;
 pub use self::ui::*;
 // Re-export utility flags/types for convenience
 pub use self::utils::{
-    FocusedFlags, ItemHoveredFlags, LogAutoOpenDepth, TooltipHoveredFlags, WindowHoveredFlags,
+    FocusedFlags, FrameMetrics, FrameMetricsRecorder, ItemHoveredFlags, LogAutoOpenDepth, LogToken,
+    OverlayCorner, TooltipHoveredFlags, WindowHoveredFlags,
 };
 
 // Utility modules
@@ -361,6 +363,7 @@ pub use self::text_filter::*;
 pub use self::columns::*;
 
 // Internal modules
+mod allocator;
 mod clipboard;
 mod colors;
 mod context;
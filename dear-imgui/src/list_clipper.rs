@@ -110,6 +110,22 @@ impl<'ui> ListClipperToken<'ui> {
         ret
     }
 
+    /// Forces `index` to be part of the displayed ranges even when it is scrolled out of view,
+    /// e.g. so the selected row of a list can take keyboard focus.
+    ///
+    /// Call this before the first `step`.
+    #[doc(alias = "IncludeItemByIndex")]
+    pub fn include_item_by_index(&mut self, index: usize) {
+        let index = i32::try_from(index).unwrap_or_else(|_| {
+            panic!(
+                "ListClipperToken::include_item_by_index() index exceeded Dear ImGui's i32 range"
+            )
+        });
+        self.ui.run_with_bound_context(|| unsafe {
+            sys::ImGuiListClipper_IncludeItemByIndex(self.list_clipper, index)
+        });
+    }
+
     /// This is automatically called back the final call to
     /// `step`. You can call it sooner but typically not needed.
     pub fn end(&mut self) {
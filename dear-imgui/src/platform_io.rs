@@ -10,6 +10,7 @@
 compile_error!("The `multi-viewport` feature is not supported on wasm32 targets yet.");
 
 mod core;
+mod monitor;
 #[cfg(feature = "multi-viewport")]
 mod trampolines;
 mod viewport;
@@ -26,6 +27,7 @@ mod tests;
 mod textures;
 
 pub use core::PlatformIo;
+pub use monitor::PlatformMonitor;
 pub use viewport::Viewport;
 
 pub(crate) use core::{
@@ -1,6 +1,6 @@
 use crate::sys;
 
-use super::{PlatformIo, Viewport};
+use super::{PlatformIo, PlatformMonitor, Viewport};
 
 impl PlatformIo {
     /// Get access to the monitors vector
@@ -25,6 +25,21 @@ impl PlatformIo {
         }
     }
 
+    /// Iterate over the monitors as typed [`PlatformMonitor`]s, in the backend's order (the
+    /// primary monitor first).
+    #[cfg(feature = "multi-viewport")]
+    pub fn monitors_iter(&self) -> impl Iterator<Item = PlatformMonitor> + '_ {
+        self.monitors().iter().map(PlatformMonitor::from)
+    }
+
+    /// Returns the index and monitor containing `pos`, e.g. a window position.
+    #[cfg(feature = "multi-viewport")]
+    pub fn monitor_at(&self, pos: [f32; 2]) -> Option<(usize, PlatformMonitor)> {
+        self.monitors_iter()
+            .enumerate()
+            .find(|(_, monitor)| monitor.contains(pos))
+    }
+
     /// Replace Dear ImGui's platform monitor list.
     ///
     /// Multi-viewport backends must keep at least one monitor in `PlatformIO.Monitors` before
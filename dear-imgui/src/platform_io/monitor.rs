@@ -0,0 +1,86 @@
+use crate::sys;
+
+/// A monitor reported by the platform backend in `PlatformIO.Monitors`.
+///
+/// A copy of `ImGuiPlatformMonitor` without the platform handle, in Dear ImGui's coordinate space
+/// (the same as viewport and window positions with multi-viewport enabled). Use it to place
+/// windows on a given monitor:
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # #[cfg(feature = "multi-viewport")]
+/// let second = ctx.platform_io().monitors_iter().nth(1);
+/// # #[cfg(not(feature = "multi-viewport"))]
+/// # let second: Option<PlatformMonitor> = None;
+/// # let ui = ctx.frame();
+/// if let Some(monitor) = second {
+///     ui.window("Overlay")
+///         .position(monitor.work_pos, Condition::Appearing)
+///         .build(|| ui.text("On the second monitor"));
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PlatformMonitor {
+    /// Position of the whole monitor.
+    pub main_pos: [f32; 2],
+    /// Size of the whole monitor.
+    pub main_size: [f32; 2],
+    /// Position of the work area, i.e. without task bars and docks.
+    pub work_pos: [f32; 2],
+    /// Size of the work area.
+    pub work_size: [f32; 2],
+    /// DPI scale of the monitor (1.0 = 96 DPI).
+    pub dpi_scale: f32,
+}
+
+impl PlatformMonitor {
+    /// Center of the whole monitor.
+    pub fn center(&self) -> [f32; 2] {
+        [
+            self.main_pos[0] + self.main_size[0] * 0.5,
+            self.main_pos[1] + self.main_size[1] * 0.5,
+        ]
+    }
+
+    /// Center of the work area.
+    pub fn work_center(&self) -> [f32; 2] {
+        [
+            self.work_pos[0] + self.work_size[0] * 0.5,
+            self.work_pos[1] + self.work_size[1] * 0.5,
+        ]
+    }
+
+    /// Returns true if `pos` lies on the monitor.
+    pub fn contains(&self, pos: [f32; 2]) -> bool {
+        (0..2).all(|i| pos[i] >= self.main_pos[i] && pos[i] < self.main_pos[i] + self.main_size[i])
+    }
+}
+
+impl From<&sys::ImGuiPlatformMonitor> for PlatformMonitor {
+    fn from(raw: &sys::ImGuiPlatformMonitor) -> Self {
+        Self {
+            main_pos: [raw.MainPos.x, raw.MainPos.y],
+            main_size: [raw.MainSize.x, raw.MainSize.y],
+            work_pos: [raw.WorkPos.x, raw.WorkPos.y],
+            work_size: [raw.WorkSize.x, raw.WorkSize.y],
+            dpi_scale: raw.DpiScale,
+        }
+    }
+}
+
+impl From<PlatformMonitor> for sys::ImGuiPlatformMonitor {
+    /// Converts to the raw struct with a null platform handle, e.g. for
+    /// [`PlatformIo::set_monitors`](crate::PlatformIo::set_monitors).
+    fn from(monitor: PlatformMonitor) -> Self {
+        let vec2 = |v: [f32; 2]| sys::ImVec2_c { x: v[0], y: v[1] };
+        Self {
+            MainPos: vec2(monitor.main_pos),
+            MainSize: vec2(monitor.main_size),
+            WorkPos: vec2(monitor.work_pos),
+            WorkSize: vec2(monitor.work_size),
+            DpiScale: monitor.dpi_scale,
+            PlatformHandle: std::ptr::null_mut(),
+        }
+    }
+}
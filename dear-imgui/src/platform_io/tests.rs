@@ -677,3 +677,43 @@ fn raw_setters_clear_receiver_typed_callback_slots() {
     drop(ctx_b);
     drop(suspended_a);
 }
+
+#[cfg(feature = "multi-viewport")]
+#[test]
+fn platform_io_monitors_iter_yields_typed_monitors() {
+    let primary = PlatformMonitor {
+        main_pos: [0.0, 0.0],
+        main_size: [1920.0, 1080.0],
+        work_pos: [0.0, 0.0],
+        work_size: [1920.0, 1040.0],
+        dpi_scale: 1.0,
+    };
+    let secondary = PlatformMonitor {
+        main_pos: [1920.0, -200.0],
+        main_size: [2560.0, 1440.0],
+        work_pos: [1920.0, -200.0],
+        work_size: [2560.0, 1400.0],
+        dpi_scale: 1.5,
+    };
+
+    let mut raw: sys::ImGuiPlatformIO = new_platform_io();
+    raw.Monitors.Size = 0;
+    raw.Monitors.Capacity = 0;
+    raw.Monitors.Data = std::ptr::null_mut();
+    let mut pio = PlatformIo {
+        raw: UnsafeCell::new(raw),
+    };
+    pio.set_monitors(&[primary.into(), secondary.into()]);
+
+    assert_eq!(
+        pio.monitors_iter().collect::<Vec<_>>(),
+        [primary, secondary]
+    );
+    assert_eq!(pio.monitor_at([100.0, 100.0]), Some((0, primary)));
+    assert_eq!(pio.monitor_at([2000.0, -100.0]), Some((1, secondary)));
+    assert_eq!(pio.monitor_at([-10.0, 0.0]), None);
+    assert_eq!(secondary.work_center(), [3200.0, 500.0]);
+
+    pio.set_monitors(&[]);
+    assert_eq!(pio.monitors_iter().count(), 0);
+}
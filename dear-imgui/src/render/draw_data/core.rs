@@ -109,6 +109,20 @@ impl DrawData {
         self.framebuffer_scale
     }
 
+    /// Deep-copy this draw data into an owned [`FrameSnapshot`](crate::render::snapshot::FrameSnapshot).
+    ///
+    /// The snapshot holds no pointers into ImGui memory and is `Send + Sync`, so a render thread
+    /// can consume it while the next frame is built. Call it on the UI thread before the next
+    /// `new_frame()`; see [`SnapshotOptions`](crate::render::snapshot::SnapshotOptions) for user
+    /// callback handling and texture request capture.
+    pub fn snapshot(
+        &self,
+        options: crate::render::snapshot::SnapshotOptions,
+    ) -> Result<crate::render::snapshot::FrameSnapshot, crate::render::snapshot::SnapshotError>
+    {
+        crate::render::snapshot::FrameSnapshot::from_draw_data(self, options)
+    }
+
     /// Raw owner viewport pointer for this draw data.
     ///
     /// This is primarily useful for integrations that snapshot multiple Dear ImGui platform
@@ -47,6 +47,42 @@ impl Ui {
         });
         IdStackToken::new(self)
     }
+
+    /// Pushes an integer identifier to the ID stack.
+    #[doc(alias = "PushID")]
+    pub fn push_id_int(&self, id: i32) -> IdStackToken<'_> {
+        self.run_with_bound_context(|| unsafe { sys::igPushID_Int(id) });
+        IdStackToken::new(self)
+    }
+
+    /// Pushes the address of `value` to the ID stack.
+    ///
+    /// Useful for items backed by long-lived objects: the ID stays the same as long as the object
+    /// does not move.
+    #[doc(alias = "PushID")]
+    pub fn push_id_ptr<T: ?Sized>(&self, value: &T) -> IdStackToken<'_> {
+        let ptr = (value as *const T).cast::<std::ffi::c_void>();
+        self.run_with_bound_context(|| unsafe { sys::igPushID_Ptr(ptr) });
+        IdStackToken::new(self)
+    }
+
+    /// Pushes a string identifier to the ID stack.
+    ///
+    /// The string is hashed in place (`PushID(begin, end)`) instead of being copied into the
+    /// scratch buffer as a C string.
+    #[doc(alias = "PushID")]
+    pub fn push_id_str(&self, id: &str) -> IdStackToken<'_> {
+        let range = id.as_bytes().as_ptr_range();
+        self.run_with_bound_context(|| unsafe {
+            if id.is_empty() {
+                // `ImHashStr` treats an empty range as NUL-terminated and would read past it.
+                sys::igPushID_Str(c"".as_ptr())
+            } else {
+                sys::igPushID_StrStr(range.start.cast(), range.end.cast())
+            }
+        });
+        IdStackToken::new(self)
+    }
 }
 
 create_token!(
@@ -29,6 +29,10 @@
 //! c.pop();
 //! ```
 //!
+//! With the `serde` feature, [`Style::to_serializable`] and [`Style::from_serializable`] turn a
+//! whole style into a [`SerializableStyle`] that can be saved as TOML/JSON, and
+//! [`Style::to_serializable_diff`] keeps only the values changed from a base theme.
+//!
 #![allow(
     clippy::cast_possible_truncation,
     clippy::cast_sign_loss,
@@ -38,12 +42,15 @@ mod color;
 mod core;
 mod direction;
 mod docking;
+mod dpi;
 mod drag_drop;
 mod font;
 mod hover;
 mod layout;
 mod rendering;
 mod separators;
+#[cfg(feature = "serde")]
+mod serializable;
 mod spacing;
 mod tabs_tables;
 mod theme;
@@ -54,6 +61,9 @@ mod var;
 pub use color::StyleColor;
 pub use core::Style;
 pub use direction::Direction;
+pub use dpi::DpiManager;
+#[cfg(feature = "serde")]
+pub use serializable::SerializableStyle;
 pub use theme::{ColorOverride, StyleTweaks, TableTheme, Theme, ThemePreset, WindowTheme};
 pub use tree::TreeLineMode;
 pub use var::StyleVar;
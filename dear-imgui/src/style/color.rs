@@ -82,6 +82,73 @@ pub enum StyleColor {
 
 impl StyleColor {
     pub const COUNT: usize = sys::ImGuiCol_COUNT as usize;
+
+    /// All style colors, in `ImGuiCol_` order.
+    pub const VARIANTS: [StyleColor; StyleColor::COUNT] = [
+        StyleColor::Text,
+        StyleColor::TextDisabled,
+        StyleColor::WindowBg,
+        StyleColor::ChildBg,
+        StyleColor::PopupBg,
+        StyleColor::Border,
+        StyleColor::BorderShadow,
+        StyleColor::FrameBg,
+        StyleColor::FrameBgHovered,
+        StyleColor::FrameBgActive,
+        StyleColor::TitleBg,
+        StyleColor::TitleBgActive,
+        StyleColor::TitleBgCollapsed,
+        StyleColor::MenuBarBg,
+        StyleColor::ScrollbarBg,
+        StyleColor::ScrollbarGrab,
+        StyleColor::ScrollbarGrabHovered,
+        StyleColor::ScrollbarGrabActive,
+        StyleColor::CheckMark,
+        StyleColor::CheckboxSelectedBg,
+        StyleColor::SliderGrab,
+        StyleColor::SliderGrabActive,
+        StyleColor::Button,
+        StyleColor::ButtonHovered,
+        StyleColor::ButtonActive,
+        StyleColor::Header,
+        StyleColor::HeaderHovered,
+        StyleColor::HeaderActive,
+        StyleColor::Separator,
+        StyleColor::SeparatorHovered,
+        StyleColor::SeparatorActive,
+        StyleColor::ResizeGrip,
+        StyleColor::ResizeGripHovered,
+        StyleColor::ResizeGripActive,
+        StyleColor::Tab,
+        StyleColor::TabHovered,
+        StyleColor::TabSelected,
+        StyleColor::TabSelectedOverline,
+        StyleColor::TabDimmed,
+        StyleColor::TabDimmedSelected,
+        StyleColor::TabDimmedSelectedOverline,
+        StyleColor::DockingPreview,
+        StyleColor::DockingEmptyBg,
+        StyleColor::PlotLines,
+        StyleColor::PlotLinesHovered,
+        StyleColor::PlotHistogram,
+        StyleColor::PlotHistogramHovered,
+        StyleColor::TableHeaderBg,
+        StyleColor::TableBorderStrong,
+        StyleColor::TableBorderLight,
+        StyleColor::TableRowBg,
+        StyleColor::TableRowBgAlt,
+        StyleColor::TextSelectedBg,
+        StyleColor::TextLink,
+        StyleColor::TreeLines,
+        StyleColor::InputTextCursor,
+        StyleColor::DragDropTarget,
+        StyleColor::DragDropTargetBg,
+        StyleColor::UnsavedMarker,
+        StyleColor::NavCursor,
+        StyleColor::NavWindowingHighlight,
+        StyleColor::NavWindowingDimBg,
+        StyleColor::ModalWindowDimBg,
+    ];
 }
 
 impl Style {
@@ -92,7 +159,8 @@ impl Style {
     }
 
     /// Set a color by style color identifier
-    pub fn set_color(&mut self, color: StyleColor, value: [f32; 4]) {
+    pub fn set_color(&mut self, color: StyleColor, value: impl Into<[f32; 4]>) {
+        let value = value.into();
         validate_style_color("Style::set_color()", "value", value);
         self.inner_mut().Colors[color as usize] = sys::ImVec4 {
             x: value[0],
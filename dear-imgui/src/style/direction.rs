@@ -5,10 +5,13 @@
 )]
 
 use crate::sys;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A cardinal direction
 #[repr(i32)]
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Direction {
     None = sys::ImGuiDir_None as i32,
     Left = sys::ImGuiDir_Left as i32,
@@ -0,0 +1,129 @@
+use super::core::Style;
+use super::validation::assert_positive_f32;
+use crate::Context;
+
+/// Keeps the style and font scale in step with the DPI scale of the display.
+///
+/// `Style::scale_all_sizes` truncates the scaled sizes, so scaling the live style up and down by
+/// ratios drifts away from the original values after a few monitor changes. `DpiManager` keeps
+/// the unscaled base style instead and derives the live style from it for every scale:
+/// [`Style::scale_all_sizes`] for sizes and [`Style::set_font_scale_dpi`] for text.
+///
+/// Since Dear ImGui 1.92 fonts are rasterized on demand at the size they are used, so a scale
+/// change needs no atlas rebuild. Each distinct scale bakes and caches new glyphs, though; use
+/// [`DpiManager::bucket_step`] to round scales to a few buckets (e.g. `0.25`) so fractional
+/// scales share bakes and tiny changes do not restyle anything.
+///
+/// Feed it from the platform backend:
+/// - winit: call [`DpiManager::set_scale`] with `window.scale_factor()` after attaching the window
+///   and on `WindowEvent::ScaleFactorChanged` (with `HiDpiMode::Locked(1.0)`, so Dear ImGui
+///   coordinates stay physical pixels; do not combine with the platform's own
+///   `set_dpi_style_scaling`).
+/// - SDL3: the official backend reports the window's display scale as the main viewport DPI
+///   scale; call [`DpiManager::update_from_main_viewport`] after the backend's `new_frame`.
+///
+/// With `Io::set_config_dpi_scale_fonts(true)` Dear ImGui overwrites `FontScaleDpi` per
+/// viewport itself, so only the sizes follow the manager then.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// let mut ctx = Context::create();
+/// let mut dpi = DpiManager::new(&ctx).bucket_step(0.25);
+/// // Window moved to a 150% monitor:
+/// if dpi.set_scale(&mut ctx, 1.5) {
+///     println!("style rescaled to {}", dpi.scale());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DpiManager {
+    base: Style,
+    scale: f32,
+    bucket_step: Option<f32>,
+}
+
+impl DpiManager {
+    /// Creates a manager using the context's current style as the unscaled (1.0) base style.
+    pub fn new(ctx: &Context) -> Self {
+        Self::with_base_style(ctx.style().clone())
+    }
+
+    /// Creates a manager with an explicit unscaled base style.
+    ///
+    /// Nothing is applied until the first [`DpiManager::set_scale`] call.
+    pub fn with_base_style(base: Style) -> Self {
+        Self {
+            base,
+            scale: 1.0,
+            bucket_step: None,
+        }
+    }
+
+    /// Rounds requested scales to multiples of `step` (never below `step`).
+    pub fn bucket_step(mut self, step: f32) -> Self {
+        assert_positive_f32("DpiManager::bucket_step()", "step", step);
+        self.bucket_step = Some(step);
+        self
+    }
+
+    /// Returns the currently applied scale.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Returns the unscaled base style.
+    pub fn base_style(&self) -> &Style {
+        &self.base
+    }
+
+    /// Replaces the unscaled base style (e.g. after switching themes) and applies the current
+    /// scale to it.
+    pub fn set_base_style(&mut self, ctx: &mut Context, base: Style) {
+        self.base = base;
+        self.apply(ctx);
+    }
+
+    /// Returns the scale that would be applied for `scale`, after bucketing.
+    pub fn bucketed(&self, scale: f32) -> f32 {
+        match self.bucket_step {
+            Some(step) => ((scale / step).round() * step).max(step),
+            None => scale,
+        }
+    }
+
+    /// Applies `scale` (after bucketing) to the context's style.
+    ///
+    /// Returns true if the style changed, i.e. the bucketed scale differs from the current one.
+    pub fn set_scale(&mut self, ctx: &mut Context, scale: f32) -> bool {
+        assert_positive_f32("DpiManager::set_scale()", "scale", scale);
+        let scale = self.bucketed(scale);
+        if (scale - self.scale).abs() < 1e-6 {
+            return false;
+        }
+        self.scale = scale;
+        self.apply(ctx);
+        true
+    }
+
+    /// Applies the main viewport's DPI scale, as reported by the platform backend.
+    ///
+    /// Returns true if the style changed. Non-positive scales (not reported yet) are ignored.
+    pub fn update_from_main_viewport(&mut self, ctx: &mut Context) -> bool {
+        let viewport = ctx.main_viewport().as_raw();
+        let scale = unsafe { (*viewport).DpiScale };
+        if !scale.is_finite() || scale <= 0.0 {
+            return false;
+        }
+        self.set_scale(ctx, scale)
+    }
+
+    fn apply(&self, ctx: &mut Context) {
+        let mut style = self.base.clone();
+        if self.scale != 1.0 {
+            style.scale_all_sizes(self.scale);
+        }
+        style.set_font_scale_dpi(self.base.font_scale_dpi() * self.scale);
+        *ctx.style_mut() = style;
+    }
+}
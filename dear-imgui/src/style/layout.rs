@@ -1,11 +1,20 @@
 use super::validation::{
-    assert_non_negative_f32, assert_non_negative_vec2, assert_unit_f32, assert_unit_vec2,
-    assert_window_min_size, validate_window_menu_button_position,
+    assert_non_negative_f32, assert_non_negative_vec2, assert_positive_f32, assert_unit_f32,
+    assert_unit_vec2, assert_window_min_size, validate_window_menu_button_position,
 };
 use super::{Direction, Style};
 use crate::sys;
 
 impl Style {
+    /// Multiply all sizes, paddings, spacings and roundings by `scale_factor`
+    /// (`ImGuiStyle::ScaleAllSizes`).
+    ///
+    /// Scaling is cumulative: to move from scale `a` to scale `b`, pass `b / a`.
+    pub fn scale_all_sizes(&mut self, scale_factor: f32) {
+        assert_positive_f32("Style::scale_all_sizes()", "scale_factor", scale_factor);
+        unsafe { sys::ImGuiStyle_ScaleAllSizes(self.inner_mut(), scale_factor) }
+    }
+
     // Common style accessors (typed, convenient)
 
     pub fn alpha(&self) -> f32 {
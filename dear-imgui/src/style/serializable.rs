@@ -0,0 +1,179 @@
+use super::{ColorOverride, Direction, Style, StyleColor, TreeLineMode};
+use crate::TooltipHoveredFlags;
+use crate::sys;
+use serde::{Deserialize, Serialize};
+
+macro_rules! serializable_style {
+    ($($field:ident: $ty:ty => $setter:ident),* $(,)?) => {
+        /// Every color and variable of a [`Style`] in a serde-friendly form.
+        ///
+        /// Build one with [`Style::to_serializable`] (all values) or
+        /// [`Style::to_serializable_diff`] (only values that differ from a base style), save it
+        /// with any serde format (TOML, JSON, RON, ...) and load it back with
+        /// [`Style::from_serializable`] or [`Style::apply_serializable`].
+        ///
+        /// Every field is optional: `None` values are skipped when serializing and left
+        /// unchanged when applying, so a theme file only needs the values it changes.
+        /// `FontScaleDpi` is not included, it belongs to the platform backend's DPI handling.
+        #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+        #[serde(default)]
+        pub struct SerializableStyle {
+            $(
+                #[serde(skip_serializing_if = "Option::is_none")]
+                pub $field: Option<$ty>,
+            )*
+            /// Colors, one entry per [`StyleColor`] that is set.
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            pub colors: Vec<ColorOverride>,
+        }
+
+        impl Style {
+            /// Capture every color and variable of this style.
+            pub fn to_serializable(&self) -> SerializableStyle {
+                SerializableStyle {
+                    $($field: Some(self.$field()),)*
+                    colors: StyleColor::VARIANTS
+                        .iter()
+                        .map(|&id| ColorOverride {
+                            id,
+                            rgba: self.color(id),
+                        })
+                        .collect(),
+                }
+            }
+
+            /// Capture only the colors and variables that differ from `base`.
+            ///
+            /// Useful to ship a theme as a small set of overrides on top of a preset:
+            ///
+            /// ```no_run
+            /// # let mut ctx = dear_imgui_rs::Context::create();
+            /// let base = dear_imgui_rs::Style::from_serializable(&Default::default());
+            /// let theme = ctx.style().to_serializable_diff(&base);
+            /// ```
+            pub fn to_serializable_diff(&self, base: &Style) -> SerializableStyle {
+                SerializableStyle {
+                    $($field: Some(self.$field()).filter(|v| *v != base.$field()),)*
+                    colors: StyleColor::VARIANTS
+                        .iter()
+                        .filter(|&&id| self.color(id) != base.color(id))
+                        .map(|&id| ColorOverride {
+                            id,
+                            rgba: self.color(id),
+                        })
+                        .collect(),
+                }
+            }
+
+            /// Set the colors and variables present in `style`, leaving the others unchanged.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a value is rejected by the matching `Style` setter (e.g. a
+            /// non-finite size or an `alpha` outside `0.0..=1.0`).
+            pub fn apply_serializable(&mut self, style: &SerializableStyle) {
+                $(
+                    if let Some(v) = style.$field {
+                        self.$setter(v);
+                    }
+                )*
+                for c in &style.colors {
+                    self.set_color(c.id, c.rgba);
+                }
+            }
+        }
+    };
+}
+
+serializable_style! {
+    alpha: f32 => set_alpha,
+    disabled_alpha: f32 => set_disabled_alpha,
+    window_padding: [f32; 2] => set_window_padding,
+    window_rounding: f32 => set_window_rounding,
+    window_border_size: f32 => set_window_border_size,
+    window_border_hover_padding: f32 => set_window_border_hover_padding,
+    window_min_size: [f32; 2] => set_window_min_size,
+    window_title_align: [f32; 2] => set_window_title_align,
+    window_menu_button_position: Direction => set_window_menu_button_position,
+    child_rounding: f32 => set_child_rounding,
+    child_border_size: f32 => set_child_border_size,
+    popup_rounding: f32 => set_popup_rounding,
+    popup_border_size: f32 => set_popup_border_size,
+    frame_padding: [f32; 2] => set_frame_padding,
+    frame_rounding: f32 => set_frame_rounding,
+    frame_border_size: f32 => set_frame_border_size,
+    item_spacing: [f32; 2] => set_item_spacing,
+    item_inner_spacing: [f32; 2] => set_item_inner_spacing,
+    cell_padding: [f32; 2] => set_cell_padding,
+    touch_extra_padding: [f32; 2] => set_touch_extra_padding,
+    indent_spacing: f32 => set_indent_spacing,
+    columns_min_spacing: f32 => set_columns_min_spacing,
+    scrollbar_size: f32 => set_scrollbar_size,
+    scrollbar_rounding: f32 => set_scrollbar_rounding,
+    scrollbar_padding: f32 => set_scrollbar_padding,
+    grab_min_size: f32 => set_grab_min_size,
+    grab_rounding: f32 => set_grab_rounding,
+    log_slider_deadzone: f32 => set_log_slider_deadzone,
+    image_rounding: f32 => set_image_rounding,
+    image_border_size: f32 => set_image_border_size,
+    tab_rounding: f32 => set_tab_rounding,
+    tab_border_size: f32 => set_tab_border_size,
+    tab_min_width_base: f32 => set_tab_min_width_base,
+    tab_min_width_shrink: f32 => set_tab_min_width_shrink,
+    tab_close_button_min_width_selected: f32 => set_tab_close_button_min_width_selected,
+    tab_close_button_min_width_unselected: f32 => set_tab_close_button_min_width_unselected,
+    tab_bar_border_size: f32 => set_tab_bar_border_size,
+    tab_bar_overline_size: f32 => set_tab_bar_overline_size,
+    table_angled_headers_angle: f32 => set_table_angled_headers_angle,
+    table_angled_headers_text_align: [f32; 2] => set_table_angled_headers_text_align,
+    tree_lines_mode: TreeLineMode => set_tree_lines_mode,
+    tree_lines_size: f32 => set_tree_lines_size,
+    tree_lines_rounding: f32 => set_tree_lines_rounding,
+    drag_drop_target_rounding: f32 => set_drag_drop_target_rounding,
+    drag_drop_target_border_size: f32 => set_drag_drop_target_border_size,
+    drag_drop_target_padding: f32 => set_drag_drop_target_padding,
+    color_marker_size: f32 => set_color_marker_size,
+    color_button_position: Direction => set_color_button_position,
+    button_text_align: [f32; 2] => set_button_text_align,
+    selectable_text_align: [f32; 2] => set_selectable_text_align,
+    separator_size: f32 => set_separator_size,
+    separator_text_border_size: f32 => set_separator_text_border_size,
+    separator_text_align: [f32; 2] => set_separator_text_align,
+    separator_text_padding: [f32; 2] => set_separator_text_padding,
+    display_window_padding: [f32; 2] => set_display_window_padding,
+    display_safe_area_padding: [f32; 2] => set_display_safe_area_padding,
+    docking_node_has_close_button: bool => set_docking_node_has_close_button,
+    docking_separator_size: f32 => set_docking_separator_size,
+    mouse_cursor_scale: f32 => set_mouse_cursor_scale,
+    anti_aliased_lines: bool => set_anti_aliased_lines,
+    anti_aliased_lines_use_tex: bool => set_anti_aliased_lines_use_tex,
+    anti_aliased_fill: bool => set_anti_aliased_fill,
+    curve_tessellation_tol: f32 => set_curve_tessellation_tol,
+    circle_tessellation_max_error: f32 => set_circle_tessellation_max_error,
+    hover_stationary_delay: f32 => set_hover_stationary_delay,
+    hover_delay_short: f32 => set_hover_delay_short,
+    hover_delay_normal: f32 => set_hover_delay_normal,
+    hover_flags_for_tooltip_mouse: TooltipHoveredFlags => set_hover_flags_for_tooltip_mouse,
+    hover_flags_for_tooltip_nav: TooltipHoveredFlags => set_hover_flags_for_tooltip_nav,
+    font_size_base: f32 => set_font_size_base,
+    font_scale_main: f32 => set_font_scale_main,
+}
+
+impl Style {
+    /// Create a style from Dear ImGui's defaults (dark colors) with `style` applied on top.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is rejected by the matching `Style` setter, see
+    /// [`Style::apply_serializable`].
+    pub fn from_serializable(style: &SerializableStyle) -> Style {
+        let mut result = unsafe {
+            let defaults = sys::ImGuiStyle_ImGuiStyle();
+            let result = Style(std::cell::UnsafeCell::new(*defaults));
+            sys::ImGuiStyle_destroy(defaults);
+            result
+        };
+        result.apply_serializable(style);
+        result
+    }
+}
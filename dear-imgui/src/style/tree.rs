@@ -30,6 +30,17 @@ impl TreeLineMode {
         Self(bits)
     }
 
+    /// Name used when (de)serializing the mode.
+    #[cfg(feature = "serde")]
+    fn name(self) -> Option<&'static str> {
+        match self {
+            Self::NONE => Some("None"),
+            Self::FULL => Some("Full"),
+            Self::TO_NODES => Some("ToNodes"),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub(crate) fn validate(self, caller: &str) {
         assert!(
@@ -39,6 +50,37 @@ impl TreeLineMode {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for TreeLineMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.name() {
+            Some(name) => serializer.serialize_str(name),
+            None => Err(serde::ser::Error::custom(format!(
+                "unknown TreeLineMode bits 0x{:X}",
+                self.0
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TreeLineMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const NAMES: &[&str] = &["None", "Full", "ToNodes"];
+        let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+        [Self::NONE, Self::FULL, Self::TO_NODES]
+            .into_iter()
+            .find(|mode| mode.name() == Some(name.as_str()))
+            .ok_or_else(|| serde::de::Error::unknown_variant(&name, NAMES))
+    }
+}
+
 impl Style {
     pub fn tree_lines_mode(&self) -> TreeLineMode {
         TreeLineMode::from_bits_retain(self.inner().TreeLinesFlags as i32)
@@ -13,6 +13,7 @@ mod reference;
 mod status;
 #[cfg(test)]
 mod tests;
+mod update;
 mod validation;
 
 pub use data::TextureData;
@@ -22,3 +23,4 @@ pub use owned::OwnedTextureData;
 pub use rect::TextureRect;
 pub use reference::{TextureRef, create_texture_ref};
 pub use status::{TextureStatus, get_status_name};
+pub use update::{TextureUpdate, TextureUpdateResult};
@@ -154,3 +154,92 @@ fn set_data_checks_byte_count_before_allocating_or_copying() {
     );
     assert!(unsafe { (*texture.as_raw()).Pixels.is_null() });
 }
+
+#[test]
+fn texture_update_result_apply_to_sets_status_and_id() {
+    let mut tex = TextureData::new();
+
+    TextureUpdateResult::Created {
+        texture_id: TextureId::from(42u64),
+    }
+    .apply_to(&mut tex);
+    assert_eq!(tex.status(), TextureStatus::OK);
+    assert_eq!(tex.tex_id().id(), 42);
+
+    TextureUpdateResult::Updated.apply_to(&mut tex);
+    assert_eq!(tex.status(), TextureStatus::OK);
+    assert_eq!(tex.tex_id().id(), 42);
+
+    TextureUpdateResult::Destroyed.apply_to(&mut tex);
+    assert_eq!(tex.status(), TextureStatus::Destroyed);
+    unsafe {
+        assert!((*tex.as_raw()).WantDestroyNextFrame);
+    }
+
+    unsafe {
+        (*tex.as_raw_mut()).WantDestroyNextFrame = false;
+    }
+    tex.create(TextureFormat::RGBA32, 1, 1);
+    TextureUpdateResult::Failed.apply_to(&mut tex);
+    assert_eq!(tex.status(), TextureStatus::WantCreate);
+
+    TextureUpdateResult::NoAction.apply_to(&mut tex);
+    assert_eq!(tex.status(), TextureStatus::WantCreate);
+}
+
+#[test]
+fn texture_update_trait_drives_textures_by_status() {
+    #[derive(Default)]
+    struct CountingRenderer {
+        next_id: u64,
+        created: usize,
+        destroyed: usize,
+    }
+
+    impl TextureUpdate for CountingRenderer {
+        type Error = std::convert::Infallible;
+
+        fn update_texture_data(
+            &mut self,
+            texture_data: &mut TextureData,
+        ) -> Result<(), Self::Error> {
+            let result = match texture_data.status() {
+                TextureStatus::WantCreate => {
+                    self.next_id += 1;
+                    self.created += 1;
+                    TextureUpdateResult::Created {
+                        texture_id: TextureId::new(self.next_id),
+                    }
+                }
+                TextureStatus::WantUpdates => TextureUpdateResult::Updated,
+                TextureStatus::WantDestroy => {
+                    self.destroyed += 1;
+                    TextureUpdateResult::Destroyed
+                }
+                TextureStatus::OK | TextureStatus::Destroyed => TextureUpdateResult::NoAction,
+            };
+            result.apply_to(texture_data);
+            Ok(())
+        }
+    }
+
+    let mut renderer = CountingRenderer::default();
+    let mut a = TextureData::new();
+    let mut b = TextureData::new();
+    a.create(TextureFormat::RGBA32, 4, 4);
+    b.create(TextureFormat::Alpha8, 4, 4);
+
+    renderer.update_textures([&mut *a, &mut *b]).unwrap();
+    assert_eq!(renderer.created, 2);
+    assert_eq!((a.tex_id().id(), b.tex_id().id()), (1, 2));
+    assert_eq!(a.status(), TextureStatus::OK);
+
+    // Textures that are up to date are skipped.
+    renderer.update_textures([&mut *a]).unwrap();
+    assert_eq!(renderer.created, 2);
+
+    a.set_status(TextureStatus::WantDestroy);
+    renderer.update_texture_data(&mut a).unwrap();
+    assert_eq!(renderer.destroyed, 1);
+    assert_eq!(a.status(), TextureStatus::Destroyed);
+}
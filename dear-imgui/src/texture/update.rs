@@ -0,0 +1,90 @@
+use super::{TextureData, TextureId, TextureStatus};
+
+/// Result of a renderer handling one [`TextureData`] request.
+///
+/// Renderers return this instead of writing to the texture data directly, so uploads can be
+/// scheduled from a shared reference. Apply it with [`TextureUpdateResult::apply_to`].
+#[derive(Debug, Clone)]
+pub enum TextureUpdateResult {
+    /// Texture was successfully created.
+    Created { texture_id: TextureId },
+    /// Texture was successfully updated.
+    Updated,
+    /// Texture was destroyed.
+    Destroyed,
+    /// Texture update failed.
+    Failed,
+    /// No action was needed.
+    NoAction,
+}
+
+impl TextureUpdateResult {
+    /// Apply the result to the `TextureData` object, writing back its `TexID` and status.
+    pub fn apply_to(self, texture_data: &mut TextureData) {
+        match self {
+            TextureUpdateResult::Created { texture_id } => {
+                texture_data.set_tex_id(texture_id);
+                texture_data.set_status(TextureStatus::OK);
+            }
+            TextureUpdateResult::Updated => {
+                texture_data.set_status(TextureStatus::OK);
+            }
+            TextureUpdateResult::Destroyed => unsafe {
+                // SetStatus(Destroyed) turns back into WantCreate unless WantDestroyNextFrame is
+                // set, so set it first when honoring a requested destroy.
+                (*texture_data.as_raw_mut()).WantDestroyNextFrame = true;
+                texture_data.set_status(TextureStatus::Destroyed);
+            },
+            TextureUpdateResult::Failed => {
+                // Best-effort: if this was not a requested destroy, Dear ImGui translates this
+                // back to WantCreate and retries.
+                texture_data.set_status(TextureStatus::Destroyed);
+            }
+            TextureUpdateResult::NoAction => {}
+        }
+    }
+}
+
+/// Renderer-side handling of Dear ImGui managed textures.
+///
+/// Every renderer backend implements this trait, so code that owns its own `TextureData`
+/// (user images, or textures from extensions such as ImPlot) can create, update and destroy
+/// them the same way whichever renderer is in use. Renderers already process the textures listed
+/// in the draw data while rendering; call this for textures Dear ImGui does not know about,
+/// before they are drawn.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::{TextureData, TextureFormat, TextureUpdate};
+/// fn upload_image<R: TextureUpdate>(renderer: &mut R, texture: &mut TextureData)
+/// where
+///     R::Error: std::fmt::Debug,
+/// {
+///     // `TextureData::create` leaves the texture in `WantCreate`.
+///     renderer.update_texture_data(texture).unwrap();
+/// }
+/// ```
+pub trait TextureUpdate {
+    /// Error returned when the renderer cannot process a texture.
+    type Error;
+
+    /// Creates, updates or destroys the renderer texture according to
+    /// [`TextureData::status`], then writes the resulting `TexID` and status back.
+    ///
+    /// Textures whose status is `OK` or `Destroyed` are left untouched.
+    fn update_texture_data(&mut self, texture_data: &mut TextureData) -> Result<(), Self::Error>;
+
+    /// Calls [`TextureUpdate::update_texture_data`] for each texture, stopping at the first
+    /// error.
+    fn update_textures<'a, I>(&mut self, textures: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = &'a mut TextureData>,
+        Self: Sized,
+    {
+        for texture_data in textures {
+            self.update_texture_data(texture_data)?;
+        }
+        Ok(())
+    }
+}
@@ -29,7 +29,7 @@ use crate::internal::RawWrapper;
 use crate::string::UiBuffer;
 use crate::sys;
 use crate::texture::TextureRef;
-use std::cell::UnsafeCell;
+use std::cell::{RefCell, UnsafeCell};
 
 /// Represents the Dear ImGui user interface for one frame
 #[derive(Debug)]
@@ -39,4 +39,9 @@ pub struct Ui {
     pub(crate) ctx_alive: ContextAliveToken,
     /// Internal buffer for string operations
     buffer: UnsafeCell<UiBuffer>,
+    /// Reused output of `format_args!` helpers (`with_fmt`, `text_fmt`)
+    fmt_buffer: RefCell<String>,
+    /// Animation time and redraw requests, reset when a frame starts. Shared with the owning
+    /// `Context` so `Ui`s created by [`Ui::from_raw_in_frame`] feed the same clock.
+    pub(crate) animation: std::rc::Rc<crate::context::AnimationClock>,
 }
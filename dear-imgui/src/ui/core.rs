@@ -17,13 +17,44 @@ impl Ui {
     ///
     /// This should only be called by Context::create()
     pub(crate) fn new(ctx: *mut sys::ImGuiContext, ctx_alive: crate::ContextAliveToken) -> Self {
+        let animation = ctx_alive.animation_clock().unwrap_or_default();
         Ui {
             ctx,
             ctx_alive,
             buffer: UnsafeCell::new(UiBuffer::new(1024)),
+            fmt_buffer: RefCell::new(String::new()),
+            animation,
         }
     }
 
+    /// Creates a `Ui` handle for a context whose frame was opened elsewhere.
+    ///
+    /// This is intended for extension crates whose callbacks run inside a frame that some other
+    /// `Ui` owns, e.g. Dear ImGui Test Engine GUI functions invoked from `NewFrame()` hooks.
+    /// The returned `Ui` shares the context's [`AnimationClock`](crate::AnimationClock), so redraw
+    /// requests made through it reach [`Context::animation_clock`](crate::Context::animation_clock).
+    ///
+    /// # Safety
+    ///
+    /// `ctx` must be the live context tracked by `ctx_alive`, and the returned `Ui` must only be
+    /// used while that context is between `NewFrame()` and `Render()`/`EndFrame()` on the current
+    /// thread.
+    pub unsafe fn from_raw_in_frame(
+        ctx: *mut sys::ImGuiContext,
+        ctx_alive: crate::ContextAliveToken,
+    ) -> Self {
+        Ui::new(ctx, ctx_alive)
+    }
+
+    /// Returns the animation clock of this frame's context.
+    ///
+    /// Animated widgets read [`AnimationClock::time`](crate::AnimationClock::time) and call
+    /// [`AnimationClock::request_frame`](crate::AnimationClock::request_frame) (or one of its
+    /// variants) so on-demand event loops keep rendering until the animation settles.
+    pub fn animation_clock(&self) -> &crate::AnimationClock {
+        &self.animation
+    }
+
     pub(crate) fn context_raw(&self) -> *mut sys::ImGuiContext {
         self.ctx
     }
@@ -96,6 +127,32 @@ impl Ui {
         &self.buffer
     }
 
+    /// Formats `args` into a buffer reused across calls and passes the result to `f`.
+    ///
+    /// Use it for labels built from `format_args!` so a large UI does not allocate a `String`
+    /// per widget and frame:
+    ///
+    /// ```no_run
+    /// # let mut ctx = dear_imgui_rs::Context::create();
+    /// # let ui = ctx.frame();
+    /// for i in 0..1000 {
+    ///     ui.with_fmt(format_args!("Item {i}"), |label| ui.button(label));
+    /// }
+    /// ```
+    ///
+    /// Nested calls work but allocate a temporary buffer for the inner call.
+    pub fn with_fmt<R>(&self, args: std::fmt::Arguments<'_>, f: impl FnOnce(&str) -> R) -> R {
+        if let Some(s) = args.as_str() {
+            return f(s);
+        }
+        let mut buffer = self.fmt_buffer.take();
+        buffer.clear();
+        let _ = std::fmt::Write::write_fmt(&mut buffer, args);
+        let result = f(&buffer);
+        self.fmt_buffer.replace(buffer);
+        result
+    }
+
     /// Returns an ID from a string label in the current ID scope.
     ///
     /// This mirrors `ImGui::GetID(label)`. Useful for building stable IDs
@@ -63,6 +63,72 @@ impl Ui {
         });
     }
 
+    /// Renders the bytes and code points of `text`, to diagnose UTF-8 and missing glyph issues.
+    #[doc(alias = "DebugTextEncoding")]
+    pub fn debug_text_encoding(&self, text: impl AsRef<str>) {
+        let text = self.scratch_txt(text);
+        self.run_with_bound_context(|| unsafe { sys::igDebugTextEncoding(text) });
+    }
+
+    /// Starts the item picker: the next clicked item breaks into the debugger.
+    ///
+    /// Dear ImGui calls `IM_DEBUG_BREAK()` for the picked item, so this is only useful with a
+    /// debugger attached.
+    ///
+    /// **Warning:** without a debugger, picking an item raises `SIGTRAP` (or a breakpoint
+    /// exception on Windows) and kills the process. Only offer this when
+    /// [`Io::config_debug_is_debugger_present`](crate::Io::config_debug_is_debugger_present) is
+    /// set; use [`Ui::hovered_id`] with [`Ui::debug_locate_item`] to inspect items otherwise.
+    #[doc(alias = "DebugStartItemPicker")]
+    pub fn debug_start_item_picker(&self) {
+        self.run_with_bound_context(|| unsafe { sys::igDebugStartItemPicker() });
+    }
+
+    /// Highlights the item with `id` this frame, e.g. an ID reported by [`Ui::hovered_id`].
+    #[doc(alias = "DebugLocateItem")]
+    pub fn debug_locate_item(&self, id: Id) {
+        self.run_with_bound_context(|| unsafe { sys::igDebugLocateItem(id.raw()) });
+    }
+
+    /// Returns the ID of the hovered item, if any.
+    ///
+    /// Unlike [`Ui::is_item_hovered`] this reports whichever item is hovered, which helps finding
+    /// out which widget actually receives the mouse when two items share an ID.
+    #[doc(alias = "GetHoveredID")]
+    pub fn hovered_id(&self) -> Option<Id> {
+        let id = self.run_with_bound_context(|| unsafe { sys::igGetHoveredID() });
+        (id != 0).then_some(Id::from(id))
+    }
+
+    /// Returns the ID of the active (e.g. held or edited) item, if any.
+    #[doc(alias = "GetActiveID")]
+    pub fn active_id(&self) -> Option<Id> {
+        let id = self.run_with_bound_context(|| unsafe { sys::igGetActiveID() });
+        (id != 0).then_some(Id::from(id))
+    }
+
+    /// Returns the ID stack of the current window, from the window ID to the innermost ID.
+    ///
+    /// Each [`Ui::push_id`] adds one entry; widget IDs are hashed from the last entry, so two
+    /// widgets collide when they use the same label with the same stack.
+    pub fn id_stack(&self) -> Vec<Id> {
+        self.run_with_bound_context(|| unsafe {
+            let window = sys::igGetCurrentWindowRead();
+            if window.is_null() {
+                return Vec::new();
+            }
+            let stack = &(*window).IDStack;
+            if stack.Data.is_null() || stack.Size <= 0 {
+                return Vec::new();
+            }
+            std::slice::from_raw_parts(stack.Data, stack.Size as usize)
+                .iter()
+                .copied()
+                .map(Id::from)
+                .collect()
+        })
+    }
+
     /// Returns the Dear ImGui version string
     #[doc(alias = "GetVersion")]
     pub fn get_version(&self) -> &str {
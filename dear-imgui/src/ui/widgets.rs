@@ -15,6 +15,15 @@ impl Ui {
         });
     }
 
+    /// Display formatted text without allocating a `String`
+    ///
+    /// `ui.text_fmt(format_args!("{fps:.1} FPS"))` formats into a buffer reused across calls
+    /// (see [`Ui::with_fmt`]).
+    #[doc(alias = "Text")]
+    pub fn text_fmt(&self, args: std::fmt::Arguments<'_>) {
+        self.with_fmt(args, |text| self.text(text));
+    }
+
     /// Convenience: draw an image with background and tint (ImGui 1.92+)
     ///
     /// Equivalent to using `image_config(...).build_with_bg(bg, tint)` but in one call.
@@ -119,4 +128,33 @@ impl Ui {
     pub fn value_bool(&self, prefix: impl AsRef<str>, v: bool) {
         self.run_with_bound_context(|| unsafe { sys::igValue_Bool(self.scratch_txt(prefix), v) })
     }
+
+    /// Display a text label with an integer value, as `prefix: v`.
+    #[doc(alias = "Value")]
+    pub fn value_int(&self, prefix: impl AsRef<str>, v: i32) {
+        self.run_with_bound_context(|| unsafe { sys::igValue_Int(self.scratch_txt(prefix), v) })
+    }
+
+    /// Display a text label with an unsigned integer value, as `prefix: v`.
+    #[doc(alias = "Value")]
+    pub fn value_uint(&self, prefix: impl AsRef<str>, v: u32) {
+        self.run_with_bound_context(|| unsafe { sys::igValue_Uint(self.scratch_txt(prefix), v) })
+    }
+
+    /// Display a text label with a float value with 3 decimals, as `prefix: v`.
+    #[doc(alias = "Value")]
+    pub fn value_float(&self, prefix: impl AsRef<str>, v: f32) {
+        self.run_with_bound_context(|| unsafe {
+            sys::igValue_Float(self.scratch_txt(prefix), v, std::ptr::null())
+        })
+    }
+
+    /// Display a text label with a float value with `decimals` decimals, as `prefix: v`.
+    ///
+    /// Formatted on the Rust side, so no C format string is involved.
+    #[doc(alias = "Value")]
+    pub fn value_float_with_precision(&self, prefix: impl AsRef<str>, v: f32, decimals: usize) {
+        let prefix = prefix.as_ref();
+        self.text_fmt(format_args!("{prefix}: {v:.decimals$}"));
+    }
 }
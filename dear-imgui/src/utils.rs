@@ -7,6 +7,7 @@
 
 mod counts;
 mod focus;
+mod frame_metrics;
 mod general;
 mod geometry;
 mod hover_flags;
@@ -19,6 +20,7 @@ mod visibility;
 mod window;
 
 pub use focus::FocusedFlags;
+pub use frame_metrics::{FrameMetrics, FrameMetricsRecorder, OverlayCorner};
 pub use hover_flags::{ItemHoveredFlags, TooltipHoveredFlags, WindowHoveredFlags};
 pub(crate) use hover_flags::{validate_item_hovered_flags, validate_tooltip_hovered_flags};
-pub use logging::LogAutoOpenDepth;
+pub use logging::{LogAutoOpenDepth, LogToken};
@@ -0,0 +1,226 @@
+use std::collections::VecDeque;
+
+use crate::io::metric_count_from_i32;
+use crate::sys;
+use crate::ui::Ui;
+use crate::window::WindowFlags;
+
+/// Per-frame metrics reported by Dear ImGui.
+///
+/// The render counts describe the last rendered frame, see [`Ui::frame_metrics`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct FrameMetrics {
+    /// Time elapsed since the previous frame, in seconds.
+    pub delta_time: f32,
+    /// Dear ImGui's framerate estimate (averaged over the last 60 frames).
+    pub framerate: f32,
+    /// Vertices output by the last render.
+    pub render_vertices: usize,
+    /// Indices output by the last render.
+    pub render_indices: usize,
+    /// Number of visible windows.
+    pub render_windows: usize,
+    /// Number of active windows.
+    pub active_windows: usize,
+    /// Number of live allocations made through Dear ImGui's allocator.
+    pub active_allocations: usize,
+}
+
+/// Screen corner of the [`FrameMetricsRecorder::draw_overlay`] window.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OverlayCorner {
+    #[default]
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Ui {
+    /// Returns the metrics of the current frame (render counts are from the last render).
+    pub fn frame_metrics(&self) -> FrameMetrics {
+        let io = self.io();
+        FrameMetrics {
+            delta_time: io.delta_time(),
+            framerate: io.framerate(),
+            render_vertices: io.metrics_render_vertices(),
+            render_indices: io.metrics_render_indices(),
+            render_windows: io.metrics_render_windows(),
+            active_windows: io.metrics_active_windows(),
+            active_allocations: self.metrics_active_allocations(),
+        }
+    }
+
+    /// Number of live allocations made through Dear ImGui's allocator.
+    pub fn metrics_active_allocations(&self) -> usize {
+        self.run_with_bound_context(|| unsafe {
+            let info = &(*sys::igGetCurrentContext()).DebugAllocInfo;
+            metric_count_from_i32(
+                "Ui::metrics_active_allocations()",
+                info.TotalAllocCount - info.TotalFreeCount,
+            )
+        })
+    }
+}
+
+/// A lightweight frame profiler keeping the [`FrameMetrics`] of the last frames.
+///
+/// Call [`FrameMetricsRecorder::record`] once per frame and show the history with
+/// [`FrameMetricsRecorder::draw_overlay`] (a small frame time HUD) or your own widgets.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// let mut recorder = FrameMetricsRecorder::new(120);
+/// # let ui = ctx.frame();
+/// recorder.record(&ui);
+/// recorder.draw_overlay(&ui, OverlayCorner::TopRight);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FrameMetricsRecorder {
+    history: VecDeque<FrameMetrics>,
+    capacity: usize,
+}
+
+impl FrameMetricsRecorder {
+    /// Creates a recorder keeping the last `capacity` frames.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "FrameMetricsRecorder::new() capacity must be positive"
+        );
+        Self {
+            history: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Records the metrics of the current frame and returns them.
+    pub fn record(&mut self, ui: &Ui) -> FrameMetrics {
+        let metrics = ui.frame_metrics();
+        self.push(metrics);
+        metrics
+    }
+
+    /// Adds `metrics` as the newest frame, dropping the oldest one when full.
+    pub fn push(&mut self, metrics: FrameMetrics) {
+        if self.history.len() == self.capacity {
+            self.history.pop_front();
+        }
+        self.history.push_back(metrics);
+    }
+
+    /// Returns the recorded frames, oldest first.
+    pub fn history(&self) -> &VecDeque<FrameMetrics> {
+        &self.history
+    }
+
+    /// Returns the newest recorded frame.
+    pub fn latest(&self) -> Option<&FrameMetrics> {
+        self.history.back()
+    }
+
+    /// Returns the number of frames kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Forgets all recorded frames.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+
+    /// Average frame time of the recorded frames, in seconds.
+    pub fn average_frame_time(&self) -> Option<f32> {
+        if self.history.is_empty() {
+            return None;
+        }
+        let total: f32 = self.history.iter().map(|m| m.delta_time).sum();
+        Some(total / self.history.len() as f32)
+    }
+
+    /// Longest frame time of the recorded frames, in seconds.
+    pub fn max_frame_time(&self) -> Option<f32> {
+        self.history.iter().map(|m| m.delta_time).reduce(f32::max)
+    }
+
+    /// Draws a small, non-interactive overlay window in `corner` of the main viewport's work area
+    /// with the frame time graph and the latest render counts.
+    pub fn draw_overlay(&self, ui: &Ui, corner: OverlayCorner) {
+        const PAD: f32 = 10.0;
+        let viewport = ui.main_viewport();
+        let (pos, size) = (viewport.work_pos(), viewport.work_size());
+        let (right, bottom) = match corner {
+            OverlayCorner::TopLeft => (false, false),
+            OverlayCorner::TopRight => (true, false),
+            OverlayCorner::BottomLeft => (false, true),
+            OverlayCorner::BottomRight => (true, true),
+        };
+        let x = if right {
+            pos[0] + size[0] - PAD
+        } else {
+            pos[0] + PAD
+        };
+        let y = if bottom {
+            pos[1] + size[1] - PAD
+        } else {
+            pos[1] + PAD
+        };
+        let pivot_x = if right { 1.0 } else { 0.0 };
+        let pivot_y = if bottom { 1.0 } else { 0.0 };
+        ui.run_with_bound_context(|| unsafe {
+            sys::igSetNextWindowPos(
+                sys::ImVec2 { x, y },
+                crate::Condition::Always as i32,
+                sys::ImVec2 {
+                    x: pivot_x,
+                    y: pivot_y,
+                },
+            );
+        });
+
+        let flags = WindowFlags::NO_DECORATION
+            | WindowFlags::ALWAYS_AUTO_RESIZE
+            | WindowFlags::NO_SAVED_SETTINGS
+            | WindowFlags::NO_FOCUS_ON_APPEARING
+            | WindowFlags::NO_NAV
+            | WindowFlags::NO_MOVE
+            | WindowFlags::NO_INPUTS;
+        ui.window("##frame_metrics_overlay")
+            .flags(flags)
+            .bg_alpha(0.35)
+            .build(|| {
+                let Some(latest) = self.latest() else {
+                    ui.text("No frames recorded");
+                    return;
+                };
+                let average = self.average_frame_time().unwrap_or_default();
+                ui.text(format!(
+                    "{:.2} ms/frame ({:.0} FPS)",
+                    average * 1000.0,
+                    latest.framerate
+                ));
+                let overlay = format!(
+                    "max {:.2} ms",
+                    self.max_frame_time().unwrap_or_default() * 1000.0
+                );
+                ui.plot_lines_getter_config("##frame_times", self.history.len(), &mut |i| {
+                    self.history[i].delta_time * 1000.0
+                })
+                .overlay_text(overlay)
+                .scale_min(0.0)
+                .graph_size([200.0, 40.0])
+                .build();
+                ui.text(format!(
+                    "{} vertices, {} indices",
+                    latest.render_vertices, latest.render_indices
+                ));
+                ui.text(format!(
+                    "{} windows ({} active), {} allocations",
+                    latest.render_windows, latest.active_windows, latest.active_allocations
+                ));
+            });
+    }
+}
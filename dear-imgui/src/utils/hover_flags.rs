@@ -127,6 +127,27 @@ impl Default for TooltipHoveredFlags {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for TooltipHoveredFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_i32(self.bits())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TooltipHoveredFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bits = <i32 as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(TooltipHoveredFlags::from_bits_retain(bits))
+    }
+}
+
 pub(crate) fn validate_window_hovered_flags(caller: &str, flags: WindowHoveredFlags) {
     let unsupported = flags.bits() & !WindowHoveredFlags::all().bits();
     assert!(
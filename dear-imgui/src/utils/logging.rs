@@ -38,6 +38,17 @@ impl From<u32> for LogAutoOpenDepth {
     }
 }
 
+create_token!(
+    /// Tracks an active text logging scope started by one of the `Ui::begin_log_to_*` methods.
+    ///
+    /// Dropping the token calls `LogFinish`, which closes the log file or copies the captured
+    /// text to the clipboard as needed.
+    pub struct LogToken<'ui>;
+
+    /// Finishes logging manually. You can also just allow this token to drop on its own.
+    drop { unsafe { sys::igLogFinish() } }
+);
+
 impl crate::ui::Ui {
     /// Start logging to TTY.
     #[doc(alias = "LogToTTY")]
@@ -91,6 +102,204 @@ impl crate::ui::Ui {
     pub fn log_finish(&self) {
         self.run_with_bound_context(|| unsafe { sys::igLogFinish() });
     }
+
+    /// Append raw text to the active log without displaying it.
+    ///
+    /// This is a no-op when no logging scope is active.
+    #[doc(alias = "LogText")]
+    pub fn log_text(&self, text: impl AsRef<str>) {
+        let text_ptr = self.scratch_txt(text);
+        self.run_with_bound_context(|| unsafe {
+            // Always treat the value as unformatted user text.
+            const FMT: &[u8; 3] = b"%s\0";
+            sys::igLogText(FMT.as_ptr() as *const std::os::raw::c_char, text_ptr);
+        });
+    }
+
+    /// Runs `start` and returns a token finishing the log, unless logging is already active.
+    ///
+    /// Dear ImGui ignores `LogTo*` while a log is active, so a token for the nested scope would
+    /// finish the outer log when dropped.
+    fn begin_log_scope(
+        &self,
+        caller: &str,
+        start: impl FnOnce(),
+    ) -> crate::error::ImGuiResult<LogToken<'_>> {
+        let started = self.run_with_bound_context(|| unsafe {
+            if (*sys::igGetCurrentContext()).LogEnabled {
+                return false;
+            }
+            start();
+            true
+        });
+        if !started {
+            return Err(crate::error::ImGuiError::invalid_operation(format!(
+                "{caller} called while logging is already active"
+            )));
+        }
+        Ok(LogToken::new(self))
+    }
+
+    /// Start a scoped log to TTY. Logging finishes when the returned token is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if logging is already active.
+    #[doc(alias = "LogToTTY")]
+    pub fn begin_log_to_tty(
+        &self,
+        auto_open_depth: impl Into<LogAutoOpenDepth>,
+    ) -> crate::error::ImGuiResult<LogToken<'_>> {
+        let depth = auto_open_depth.into().raw();
+        self.begin_log_scope("Ui::begin_log_to_tty()", || unsafe {
+            sys::igLogToTTY(depth)
+        })
+    }
+
+    /// Start a scoped log to the clipboard.
+    ///
+    /// The captured text is copied to the clipboard when the returned token is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if logging is already active.
+    #[doc(alias = "LogToClipboard")]
+    pub fn begin_log_to_clipboard(
+        &self,
+        auto_open_depth: impl Into<LogAutoOpenDepth>,
+    ) -> crate::error::ImGuiResult<LogToken<'_>> {
+        let depth = auto_open_depth.into().raw();
+        self.begin_log_scope("Ui::begin_log_to_clipboard()", || unsafe {
+            sys::igLogToClipboard(depth)
+        })
+    }
+
+    /// Start a scoped log to a file. The file is closed when the returned token is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `filename` contains NUL bytes or logging is already active. No logging
+    /// scope is started in that case.
+    #[doc(alias = "LogToFile")]
+    pub fn begin_log_to_file(
+        &self,
+        auto_open_depth: impl Into<LogAutoOpenDepth>,
+        filename: &std::path::Path,
+    ) -> crate::error::ImGuiResult<LogToken<'_>> {
+        use crate::error::SafeStringConversion;
+        let cstr = filename.to_string_lossy().into_owned().to_cstring_safe()?;
+        let depth = auto_open_depth.into().raw();
+        self.begin_log_scope("Ui::begin_log_to_file()", || unsafe {
+            sys::igLogToFile(depth, cstr.as_ptr())
+        })
+    }
+
+    /// Log all text emitted by `f` to TTY.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if logging is already active. `f` is not called in that case.
+    #[doc(alias = "LogToTTY")]
+    pub fn with_log_to_tty<R>(
+        &self,
+        auto_open_depth: impl Into<LogAutoOpenDepth>,
+        f: impl FnOnce() -> R,
+    ) -> crate::error::ImGuiResult<R> {
+        let _log = self.begin_log_to_tty(auto_open_depth)?;
+        Ok(f())
+    }
+
+    /// Log all text emitted by `f` and copy it to the clipboard afterwards.
+    ///
+    /// Useful for extracting a textual dump of a UI region, e.g. for accessibility or diffing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if logging is already active. `f` is not called in that case.
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # fn demo(ui: &Ui) -> ImGuiResult<()> {
+    /// if ui.button("Copy stats") {
+    ///     ui.with_log_to_clipboard(LogAutoOpenDepth::DEFAULT, || {
+    ///         ui.text("Entities: 42");
+    ///         ui.text("Frame: 16.6 ms");
+    ///     })?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "LogToClipboard")]
+    pub fn with_log_to_clipboard<R>(
+        &self,
+        auto_open_depth: impl Into<LogAutoOpenDepth>,
+        f: impl FnOnce() -> R,
+    ) -> crate::error::ImGuiResult<R> {
+        let _log = self.begin_log_to_clipboard(auto_open_depth)?;
+        Ok(f())
+    }
+
+    /// Log all text emitted by `f` and return it as a string, alongside the result of `f`.
+    ///
+    /// Handy for bug reports and snapshot tests of menus or windows, without going through the
+    /// clipboard or a file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if logging is already active (a nested call, or inside a `log_to_*`
+    /// scope), which Dear ImGui does not support. `f` is not called in that case.
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # fn demo(ui: &Ui) -> ImGuiResult<()> {
+    /// let ((), text) = ui.with_log_to_string(LogAutoOpenDepth::DEFAULT, || {
+    ///     ui.text("Entities: 42");
+    /// })?;
+    /// assert_eq!(text.trim(), "Entities: 42");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "LogToBuffer")]
+    pub fn with_log_to_string<R>(
+        &self,
+        auto_open_depth: impl Into<LogAutoOpenDepth>,
+        f: impl FnOnce() -> R,
+    ) -> crate::error::ImGuiResult<(R, String)> {
+        let depth = auto_open_depth.into().raw();
+        let log = self.begin_log_scope("Ui::with_log_to_string()", || unsafe {
+            sys::igLogToBuffer(depth)
+        })?;
+        let result = f();
+        // LogFinish clears the buffer, so read it while the log is still active.
+        let text = self.run_with_bound_context(|| unsafe {
+            let buf = &(*sys::igGetCurrentContext()).LogBuffer.Buf;
+            if buf.Data.is_null() || buf.Size <= 1 {
+                return String::new();
+            }
+            // `Size` counts the NUL terminator.
+            let bytes = std::slice::from_raw_parts(buf.Data as *const u8, buf.Size as usize - 1);
+            String::from_utf8_lossy(bytes).into_owned()
+        });
+        drop(log);
+        Ok((result, text))
+    }
+
+    /// Log all text emitted by `f` to a file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `filename` contains NUL bytes or logging is already active. `f` is not
+    /// called in that case.
+    #[doc(alias = "LogToFile")]
+    pub fn with_log_to_file<R>(
+        &self,
+        auto_open_depth: impl Into<LogAutoOpenDepth>,
+        filename: &std::path::Path,
+        f: impl FnOnce() -> R,
+    ) -> crate::error::ImGuiResult<R> {
+        let _log = self.begin_log_to_file(auto_open_depth, filename)?;
+        Ok(f())
+    }
 }
 
 #[cfg(test)]
@@ -36,7 +36,8 @@ impl crate::ui::Ui {
     ///
     /// Note: Dear ImGui applies the global style alpha when converting colors for rendering.
     #[doc(alias = "GetColorU32")]
-    pub fn get_color_u32_from_rgba(&self, rgba: [f32; 4]) -> u32 {
+    pub fn get_color_u32_from_rgba(&self, rgba: impl Into<[f32; 4]>) -> u32 {
+        let rgba = rgba.into();
         assert_finite_vec4("Ui::get_color_u32_from_rgba()", "rgba", rgba);
         self.run_with_bound_context(|| unsafe {
             sys::igGetColorU32_Vec4(sys::ImVec4_c {
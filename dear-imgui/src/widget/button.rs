@@ -1,8 +1,10 @@
 //! Buttons
 //!
-//! Push-button widgets with optional sizing and configuration helpers.
+//! Push-button widgets with optional sizing and configuration helpers, plus checkbox-like
+//! helpers built on top of them (toggle switch, three-state checkbox, radio group).
 //!
 use crate::Ui;
+use crate::style::StyleColor;
 use crate::sys;
 use std::borrow::Cow;
 
@@ -91,6 +93,124 @@ impl Ui {
         }
         pressed
     }
+
+    /// Creates a three-state checkbox.
+    ///
+    /// `None` is shown as Dear ImGui's "mixed" state, e.g. for a parent of partially selected
+    /// children. Clicking a mixed checkbox checks it; afterwards it toggles between checked and
+    /// unchecked. Returns true if the checkbox was clicked.
+    #[doc(alias = "Checkbox", alias = "ImGuiItemFlags_MixedValue")]
+    pub fn checkbox_tristate(&self, label: impl AsRef<str>, value: &mut Option<bool>) -> bool {
+        let label_ptr = self.scratch_txt(label);
+        let mixed = value.is_none();
+        let mut checked = value.unwrap_or(false);
+        let pressed = self.run_with_bound_context(|| unsafe {
+            if mixed {
+                sys::igPushItemFlag(sys::ImGuiItemFlags_MixedValue as i32, true);
+            }
+            let pressed = sys::igCheckbox(label_ptr, &mut checked);
+            if mixed {
+                sys::igPopItemFlag();
+            }
+            pressed
+        });
+        if pressed {
+            *value = Some(if mixed { true } else { checked });
+        }
+        pressed
+    }
+
+    /// Creates a toggle switch bound to `value`.
+    ///
+    /// Behaves like [`Ui::checkbox`] but is drawn as a pill-shaped track with a sliding knob,
+    /// one frame height tall. Returns true if the switch was clicked.
+    pub fn toggle_switch(&self, label: impl AsRef<str>, value: &mut bool) -> bool {
+        let label = label.as_ref();
+        let height = self.frame_height();
+        let size = [height * 1.8, height];
+        let pos = self.cursor_screen_pos();
+        let pressed = self.invisible_button(label, size);
+        if pressed {
+            *value = !*value;
+        }
+
+        let hovered = self.is_item_hovered();
+        let track = match (*value, hovered) {
+            (true, false) => StyleColor::CheckMark,
+            (true, true) => StyleColor::SliderGrabActive,
+            (false, false) => StyleColor::FrameBg,
+            (false, true) => StyleColor::FrameBgHovered,
+        };
+        let radius = height * 0.5;
+        let knob_x = if *value {
+            pos[0] + size[0] - radius
+        } else {
+            pos[0] + radius
+        };
+        let draw_list = self.get_window_draw_list();
+        draw_list
+            .add_rect(
+                pos,
+                [pos[0] + size[0], pos[1] + size[1]],
+                self.get_color_u32(track),
+            )
+            .rounding(radius)
+            .filled(true)
+            .build();
+        draw_list
+            .add_circle(
+                [knob_x, pos[1] + radius],
+                (radius - 2.0).max(1.0),
+                self.get_color_u32(StyleColor::Text),
+            )
+            .filled(true)
+            .build();
+
+        let visible = label.split("##").next().unwrap_or_default();
+        if !visible.is_empty() {
+            let spacing = unsafe { self.style() }.item_inner_spacing()[0];
+            self.same_line_with_spacing(0.0, spacing);
+            self.align_text_to_frame_padding();
+            self.text(visible);
+        }
+        pressed
+    }
+
+    /// Creates a row of radio buttons choosing one of `labels`.
+    ///
+    /// `selected` is the index of the active entry. The buttons are laid out on one line inside an
+    /// ID scope named after `label`, so entry labels only need to be unique within the group.
+    /// The visible part of `label` is shown after the buttons. Returns true if the selection
+    /// changed.
+    #[doc(alias = "RadioButton")]
+    pub fn radio_group<S: AsRef<str>>(
+        &self,
+        label: impl AsRef<str>,
+        selected: &mut usize,
+        labels: &[S],
+    ) -> bool {
+        let label = label.as_ref();
+        let mut changed = false;
+        self.group(|| {
+            let _id = self.push_id_str(label);
+            for (index, entry) in labels.iter().enumerate() {
+                if index > 0 {
+                    self.same_line();
+                }
+                if self.radio_button(entry, *selected == index) && *selected != index {
+                    *selected = index;
+                    changed = true;
+                }
+            }
+            let visible = label.split("##").next().unwrap_or_default();
+            if !visible.is_empty() {
+                let spacing = unsafe { self.style() }.item_inner_spacing()[0];
+                self.same_line_with_spacing(0.0, spacing);
+                self.text(visible);
+            }
+        });
+        changed
+    }
 }
 
 /// Builder for button widget
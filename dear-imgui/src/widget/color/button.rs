@@ -17,11 +17,11 @@ pub struct ColorButton<'ui> {
 
 impl<'ui> ColorButton<'ui> {
     /// Creates a new color button builder
-    pub fn new(ui: &'ui Ui, desc_id: impl Into<Cow<'ui, str>>, color: [f32; 4]) -> Self {
+    pub fn new(ui: &'ui Ui, desc_id: impl Into<Cow<'ui, str>>, color: impl Into<[f32; 4]>) -> Self {
         Self {
             ui,
             desc_id: desc_id.into(),
-            color,
+            color: color.into(),
             flags: ColorButtonOptions::new(),
             size: [0.0, 0.0],
         }
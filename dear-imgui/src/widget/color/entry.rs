@@ -46,7 +46,7 @@ impl Ui {
 
     /// Creates a color button widget
     #[doc(alias = "ColorButton")]
-    pub fn color_button(&self, desc_id: impl AsRef<str>, color: [f32; 4]) -> bool {
+    pub fn color_button(&self, desc_id: impl AsRef<str>, color: impl Into<[f32; 4]>) -> bool {
         self.color_button_config(desc_id.as_ref(), color).build()
     }
 
@@ -90,7 +90,7 @@ impl Ui {
     pub fn color_button_config<'ui>(
         &'ui self,
         desc_id: impl Into<Cow<'ui, str>>,
-        color: [f32; 4],
+        color: impl Into<[f32; 4]>,
     ) -> ColorButton<'ui> {
         ColorButton::new(self, desc_id, color)
     }
@@ -122,8 +122,8 @@ impl<'ui, 'p> ColorPicker4<'ui, 'p> {
     }
 
     /// Sets the reference color for comparison
-    pub fn reference_color(mut self, ref_color: [f32; 4]) -> Self {
-        self.ref_color = Some(ref_color);
+    pub fn reference_color(mut self, ref_color: impl Into<[f32; 4]>) -> Self {
+        self.ref_color = Some(ref_color.into());
         self
     }
 
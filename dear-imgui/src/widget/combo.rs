@@ -11,3 +11,4 @@ mod ui;
 pub use builder::ComboBox;
 pub use options::{ComboBoxFlags, ComboBoxHeight, ComboBoxOptions, ComboBoxPreviewMode};
 pub use token::ComboBoxToken;
+pub(crate) use ui::clipped_selectables;
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::list_clipper::ListClipper;
 use crate::sys;
 use crate::ui::Ui;
 
@@ -181,26 +182,91 @@ impl Ui {
         result
     }
 
-    /// Builds a simple combo box for choosing from a slice of strings
+    /// Builds a simple combo box for choosing from strings.
+    ///
+    /// Accepts slices, `Vec` references, or any cloneable iterator (e.g. `names.iter().map(..)`), so
+    /// labels are read in place instead of being collected first.
     #[doc(alias = "Combo")]
-    pub fn combo_simple_string(
+    pub fn combo_simple_string<I>(
         &self,
         label: impl AsRef<str>,
         current_item: &mut usize,
-        items: &[impl AsRef<str>],
-    ) -> bool {
-        self.combo(label, current_item, items, |s| Cow::Borrowed(s.as_ref()))
+        items: I,
+    ) -> bool
+    where
+        I: IntoIterator<Item: AsRef<str>, IntoIter: Clone>,
+    {
+        let items = items.into_iter();
+        let preview_value = items.clone().nth(*current_item);
+        let mut result = false;
+
+        if let Some(combo_token) = self.begin_combo(
+            label,
+            preview_value.as_ref().map(|s| s.as_ref()).unwrap_or(""),
+        ) {
+            for (idx, item) in items.enumerate() {
+                if idx == *current_item {
+                    self.set_item_default_focus();
+                }
+                if self.selectable(item.as_ref()) {
+                    *current_item = idx;
+                    result = true;
+                }
+            }
+            combo_token.end();
+        }
+
+        result
     }
 
-    /// Builds a simple combo box for choosing from a slice of strings using an `i32` index.
+    /// Builds a simple combo box for choosing from strings using an `i32` index.
     #[doc(alias = "Combo")]
-    pub fn combo_simple_string_i32(
+    pub fn combo_simple_string_i32<I>(
         &self,
         label: impl AsRef<str>,
         current_item: &mut i32,
-        items: &[impl AsRef<str>],
-    ) -> bool {
-        self.combo_i32(label, current_item, items, |s| Cow::Borrowed(s.as_ref()))
+        items: I,
+    ) -> bool
+    where
+        I: IntoIterator<Item: AsRef<str>, IntoIter: Clone>,
+    {
+        let items = items.into_iter().take(i32::MAX as usize);
+        let mut current = usize::try_from(*current_item).unwrap_or(usize::MAX);
+        let changed = self.combo_simple_string(label, &mut current, items);
+        if changed {
+            *current_item = current as i32;
+        }
+        changed
+    }
+
+    /// Builds a combo box whose labels are produced on demand by index.
+    ///
+    /// Only the preview and the rows visible in the open popup are requested, so the per-frame
+    /// cost does not grow with `items_count`. Prefer this over the slice-based helpers for lists
+    /// with thousands of entries.
+    #[doc(alias = "Combo")]
+    pub fn combo_with_getter<'a, F>(
+        &self,
+        label: impl AsRef<str>,
+        current_item: &mut usize,
+        items_count: usize,
+        item_getter: F,
+    ) -> bool
+    where
+        F: Fn(usize) -> Cow<'a, str>,
+    {
+        let preview_value = (*current_item < items_count).then(|| item_getter(*current_item));
+        let mut result = false;
+
+        if let Some(combo_token) = self.begin_combo(
+            label,
+            preview_value.as_ref().map(|s| s.as_ref()).unwrap_or(""),
+        ) {
+            result = clipped_selectables(self, current_item, items_count, &item_getter, true);
+            combo_token.end();
+        }
+
+        result
     }
 
     /// Sets the default focus for the next item
@@ -210,3 +276,37 @@ impl Ui {
         }
     }
 }
+
+/// Submits `items_count` selectables through a list clipper, always including `current_item` so it
+/// can receive default focus. Shared by the getter-based combo and list box helpers.
+pub(crate) fn clipped_selectables<'a, F>(
+    ui: &Ui,
+    current_item: &mut usize,
+    items_count: usize,
+    item_getter: &F,
+    default_focus: bool,
+) -> bool
+where
+    F: Fn(usize) -> Cow<'a, str>,
+{
+    let mut result = false;
+    let mut clipper = ListClipper::new(items_count).begin(ui);
+    if *current_item < items_count {
+        clipper.include_item_by_index(*current_item);
+    }
+    for idx in clipper.iter() {
+        let selected = idx == *current_item;
+        if ui
+            .selectable_config(item_getter(idx).as_ref())
+            .selected(selected)
+            .build()
+        {
+            *current_item = idx;
+            result = true;
+        }
+        if selected && default_focus {
+            ui.set_item_default_focus();
+        }
+    }
+    result
+}
@@ -0,0 +1,298 @@
+//! Curve editors
+//!
+//! An editor for cubic Bezier curves over the unit square, drawn with the window draw list, and
+//! [`curve_eval`] to sample the edited curve, e.g. for animation easing or VFX parameter ramps.
+//!
+use crate::input::MouseButton;
+use crate::style::StyleColor;
+use crate::ui::Ui;
+use std::borrow::Cow;
+
+// Screen distance in pixels within which a point can be grabbed.
+const GRAB_RADIUS: f32 = 6.0;
+const DEFAULT_GRID: u32 = 4;
+const EVAL_ITERATIONS: usize = 24;
+
+fn assert_curve_points(caller: &str, points: &[[f32; 2]]) {
+    assert!(
+        points.len() >= 4 && (points.len() - 1).is_multiple_of(3),
+        "{caller} points must hold 3n + 1 points (anchors with two handles between each pair)"
+    );
+}
+
+fn bezier(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
+    let u = 1.0 - t;
+    u * u * u * p0 + 3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t * p3
+}
+
+/// Samples a curve edited with [`Ui::curve_editor`] at `x`.
+///
+/// `points` holds a cubic Bezier spline: anchors at indices `0, 3, 6, ...` with the two control
+/// handles of each segment in between, anchors sorted by x. `x` is clamped to the first and last
+/// anchor.
+///
+/// # Panics
+///
+/// Panics if `points` does not hold `3n + 1` points with `n >= 1`.
+pub fn curve_eval(points: &[[f32; 2]], x: f32) -> f32 {
+    assert_curve_points("curve_eval()", points);
+    let last = points.len() - 1;
+    let x = x.clamp(points[0][0], points[last][0]);
+    let segment = (0..last / 3)
+        .find(|&k| x <= points[3 * k + 3][0])
+        .unwrap_or(last / 3 - 1);
+    let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| points[3 * segment + i]);
+    if p3[0] - p0[0] <= f32::EPSILON {
+        return p0[1];
+    }
+
+    // Handles are kept inside their segment, so x(t) is monotonic and bisection converges.
+    let (mut lo, mut hi) = (0.0f32, 1.0f32);
+    for _ in 0..EVAL_ITERATIONS {
+        let mid = (lo + hi) * 0.5;
+        if bezier(p0[0], p1[0], p2[0], p3[0], mid) < x {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    bezier(p0[1], p1[1], p2[1], p3[1], (lo + hi) * 0.5)
+}
+
+/// # Curve Widgets
+impl Ui {
+    /// Creates a curve editor for a cubic Bezier spline in the unit square. Returns true if the
+    /// curve changed.
+    ///
+    /// See [`curve_eval`] for the layout of `points`.
+    #[doc(alias = "CurveEditor")]
+    pub fn curve_editor(&self, label: impl AsRef<str>, points: &mut Vec<[f32; 2]>) -> bool {
+        self.curve_editor_config(label.as_ref()).build(points)
+    }
+
+    /// Creates a curve editor builder.
+    pub fn curve_editor_config<'ui>(
+        &'ui self,
+        label: impl Into<Cow<'ui, str>>,
+    ) -> CurveEditor<'ui> {
+        CurveEditor::new(self, label)
+    }
+}
+
+/// Builder for a curve editor widget.
+///
+/// Drag anchors to move them (their handles follow) and handles to shape the segments.
+/// Double-click empty space to insert an anchor and right-click an inner anchor to remove it.
+/// The first and last anchors stay on the left and right edges, and every handle stays within its
+/// segment, so the curve is a function of x. Values are clamped to `0.0..=1.0`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// let mut ease = vec![[0.0, 0.0], [0.4, 0.0], [0.6, 1.0], [1.0, 1.0]];
+/// ui.curve_editor_config("Ease").size([200.0, 120.0]).build(&mut ease);
+/// let halfway = curve_eval(&ease, 0.5);
+/// # let _ = halfway;
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct CurveEditor<'ui> {
+    ui: &'ui Ui,
+    label: Cow<'ui, str>,
+    size: Option<[f32; 2]>,
+    grid: u32,
+}
+
+impl<'ui> CurveEditor<'ui> {
+    /// Creates a curve editor builder.
+    pub fn new(ui: &'ui Ui, label: impl Into<Cow<'ui, str>>) -> Self {
+        Self {
+            ui,
+            label: label.into(),
+            size: None,
+            grid: DEFAULT_GRID,
+        }
+    }
+
+    /// Sets the editor size in pixels (defaults to the item width and half of it in height).
+    pub fn size(mut self, size: impl Into<[f32; 2]>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the number of grid cells along each axis, or 0 for no grid.
+    pub fn grid(mut self, divisions: u32) -> Self {
+        self.grid = divisions;
+        self
+    }
+
+    /// Builds the editor bound to `points`. Returns true if the curve changed.
+    pub fn build(self, points: &mut Vec<[f32; 2]>) -> bool {
+        let ui = self.ui;
+        assert_curve_points("CurveEditor::build()", points);
+        let size = self.size.unwrap_or_else(|| {
+            let width = ui.calc_item_width();
+            [width, width * 0.5]
+        });
+        assert!(
+            size.iter().all(|v| v.is_finite() && *v > 0.0),
+            "CurveEditor::build() size must be positive"
+        );
+
+        let _id = ui.push_id(self.label.as_ref());
+        let pos = ui.cursor_screen_pos();
+        let to_screen = |p: [f32; 2]| [pos[0] + p[0] * size[0], pos[1] + (1.0 - p[1]) * size[1]];
+        let from_screen = |s: [f32; 2]| {
+            [
+                ((s[0] - pos[0]) / size[0]).clamp(0.0, 1.0),
+                (1.0 - (s[1] - pos[1]) / size[1]).clamp(0.0, 1.0),
+            ]
+        };
+
+        ui.invisible_button("##curve", size);
+        let hovered = ui.is_item_hovered();
+        let active = ui.is_item_active();
+        let mouse = ui.io().mouse_pos();
+        let under_mouse = points
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| {
+                let s = to_screen(p);
+                (i, (s[0] - mouse[0]).hypot(s[1] - mouse[1]))
+            })
+            .filter(|&(_, distance)| distance <= GRAB_RADIUS)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i);
+
+        let dragging_key = ui.get_id("##dragging");
+        let mut storage = ui.state_storage();
+        if ui.is_item_activated() {
+            let index = under_mouse.map_or(-1, |i| i as i32);
+            storage.set_int(dragging_key, index);
+        }
+        let dragging = if active {
+            usize::try_from(storage.get_int(dragging_key, -1)).ok()
+        } else {
+            None
+        };
+
+        let mut changed = false;
+        if let Some(index) = dragging.filter(|&i| i < points.len()) {
+            changed = move_point(points, index, from_screen(mouse));
+        } else if hovered && ui.is_mouse_double_clicked(MouseButton::Left) && under_mouse.is_none()
+        {
+            insert_anchor(points, from_screen(mouse));
+            changed = true;
+        } else if let Some(index) = under_mouse
+            .filter(|&i| i % 3 == 0 && i > 0 && i + 1 < points.len())
+            .filter(|_| hovered && ui.is_mouse_clicked(MouseButton::Right))
+        {
+            points.drain(index - 1..=index + 1);
+            changed = true;
+        }
+
+        let draw_list = ui.get_window_draw_list();
+        let max = [pos[0] + size[0], pos[1] + size[1]];
+        let rounding = unsafe { ui.style() }.frame_rounding();
+        draw_list
+            .add_rect(pos, max, ui.get_color_u32(StyleColor::FrameBg))
+            .filled(true)
+            .rounding(rounding)
+            .build();
+        let grid_color = ui.get_color_u32(StyleColor::Border);
+        for i in 1..self.grid {
+            let t = i as f32 / self.grid as f32;
+            draw_list.add_line_v(pos[0] + t * size[0], pos[1], max[1], grid_color, 1.0);
+            draw_list.add_line_h(pos[0], max[0], pos[1] + t * size[1], grid_color, 1.0);
+        }
+
+        let curve_color = ui.get_color_u32(StyleColor::PlotLines);
+        let handle_color = ui.get_color_u32(StyleColor::TextDisabled);
+        for k in 0..points.len() / 3 {
+            let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| to_screen(points[3 * k + i]));
+            draw_list.add_line(p0, p1, handle_color).build();
+            draw_list.add_line(p3, p2, handle_color).build();
+            draw_list
+                .add_bezier_curve(p0, p1, p2, p3, curve_color)
+                .thickness(2.0)
+                .build();
+        }
+        for (i, &p) in points.iter().enumerate() {
+            let highlighted = dragging == Some(i) || (dragging.is_none() && under_mouse == Some(i));
+            let color = if highlighted {
+                StyleColor::SliderGrabActive
+            } else {
+                StyleColor::SliderGrab
+            };
+            let radius = if i % 3 == 0 { 4.0 } else { 3.0 };
+            draw_list
+                .add_circle(to_screen(p), radius, ui.get_color_u32(color))
+                .filled(true)
+                .build();
+        }
+
+        let visible = self.label.split("##").next().unwrap_or_default();
+        if !visible.is_empty() {
+            let spacing = unsafe { ui.style() }.item_inner_spacing()[0];
+            ui.same_line_with_spacing(0.0, spacing);
+            ui.text(visible);
+        }
+        changed
+    }
+}
+
+/// Moves the point at `index` to `target`, keeping the spline a function of x.
+fn move_point(points: &mut [[f32; 2]], index: usize, mut target: [f32; 2]) -> bool {
+    let last = points.len() - 1;
+    let before = points.to_vec();
+    if index.is_multiple_of(3) {
+        target[0] = match index {
+            0 => 0.0,
+            i if i == last => 1.0,
+            i => target[0].clamp(points[i - 3][0], points[i + 3][0]),
+        };
+        let delta = [target[0] - points[index][0], target[1] - points[index][1]];
+        points[index] = target;
+        for handle in [index.wrapping_sub(1), index + 1] {
+            if let Some(p) = points.get_mut(handle) {
+                *p = [p[0] + delta[0], (p[1] + delta[1]).clamp(0.0, 1.0)];
+            }
+        }
+    } else {
+        points[index] = target;
+    }
+    // Keep the handles next to the moved point inside their segments.
+    let first_segment = index.saturating_sub(1) / 3;
+    let last_segment = ((index + 1) / 3).min(last / 3 - 1);
+    for k in first_segment..=last_segment {
+        let (min, max) = (points[3 * k][0], points[3 * k + 3][0]);
+        for handle in [3 * k + 1, 3 * k + 2] {
+            points[handle][0] = points[handle][0].clamp(min, max);
+        }
+    }
+    points != before.as_slice()
+}
+
+/// Inserts an anchor at `at` into the segment containing its x, with flat handles.
+fn insert_anchor(points: &mut Vec<[f32; 2]>, at: [f32; 2]) {
+    let last = points.len() - 1;
+    let segment = (0..last / 3)
+        .find(|&k| at[0] <= points[3 * k + 3][0])
+        .unwrap_or(last / 3 - 1);
+    let (min, max) = (points[3 * segment][0], points[3 * segment + 3][0]);
+    let x = at[0].clamp(min, max);
+    let reach = (max - min) / 6.0;
+    let index = 3 * segment + 2;
+    points.splice(
+        index..index,
+        [[x - reach, at[1]], [x, at[1]], [x + reach, at[1]]],
+    );
+    for handle in [index - 1, index, index + 2, index + 3] {
+        let k = handle / 3;
+        let (min, max) = (points[3 * k][0], points[3 * k + 3][0]);
+        points[handle][0] = points[handle][0].clamp(min, max);
+    }
+}
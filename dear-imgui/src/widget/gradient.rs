@@ -0,0 +1,283 @@
+//! Gradient editors
+//!
+//! An editor for color gradients made of [`GradientStop`]s, drawn with the window draw list, and
+//! [`gradient_eval`] to sample the edited gradient, e.g. for particle colors over lifetime.
+//!
+use crate::input::MouseButton;
+use crate::style::StyleColor;
+use crate::ui::Ui;
+use std::borrow::Cow;
+
+const CHECKER_SIZE: f32 = 6.0;
+
+/// One color of a gradient, at `pos` in `0.0..=1.0`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GradientStop {
+    /// Position along the gradient.
+    pub pos: f32,
+    /// RGBA color.
+    pub color: [f32; 4],
+}
+
+impl GradientStop {
+    /// Creates a stop of `color` at `pos`.
+    pub fn new(pos: f32, color: [f32; 4]) -> Self {
+        Self { pos, color }
+    }
+}
+
+/// Samples a gradient edited with [`Ui::gradient_editor`] at `t`, interpolating linearly between
+/// stops.
+///
+/// `stops` must be sorted by position. Before the first and after the last stop the gradient keeps
+/// their color; an empty gradient is transparent black.
+pub fn gradient_eval(stops: &[GradientStop], t: f32) -> [f32; 4] {
+    let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+        return [0.0; 4];
+    };
+    if t <= first.pos {
+        return first.color;
+    }
+    if t >= last.pos {
+        return last.color;
+    }
+    let next = stops.partition_point(|stop| stop.pos <= t);
+    let (a, b) = (stops[next - 1], stops[next]);
+    let span = b.pos - a.pos;
+    let f = if span > 0.0 { (t - a.pos) / span } else { 0.0 };
+    std::array::from_fn(|i| a.color[i] + (b.color[i] - a.color[i]) * f)
+}
+
+/// # Gradient Widgets
+impl Ui {
+    /// Creates a gradient editor. Returns true if the gradient changed.
+    #[doc(alias = "GradientEditor")]
+    pub fn gradient_editor(&self, label: impl AsRef<str>, stops: &mut Vec<GradientStop>) -> bool {
+        self.gradient_editor_config(label.as_ref()).build(stops)
+    }
+
+    /// Creates a gradient editor builder.
+    pub fn gradient_editor_config<'ui>(
+        &'ui self,
+        label: impl Into<Cow<'ui, str>>,
+    ) -> GradientEditor<'ui> {
+        GradientEditor::new(self, label)
+    }
+}
+
+/// Builder for a gradient editor widget.
+///
+/// The editor shows the gradient over a checkerboard with a marker per stop below it. Click a
+/// marker to select it and drag it to move the stop; click the gradient to add a stop there, and
+/// right-click a marker to remove its stop (the last stop stays). The selected stop's color is
+/// edited with a color editor below the markers. Stops are kept sorted by position.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// let mut fire = vec![
+///     GradientStop::new(0.0, [1.0, 1.0, 0.4, 1.0]),
+///     GradientStop::new(0.5, [1.0, 0.3, 0.0, 0.8]),
+///     GradientStop::new(1.0, [0.2, 0.2, 0.2, 0.0]),
+/// ];
+/// ui.gradient_editor("Fire", &mut fire);
+/// let color = gradient_eval(&fire, 0.25);
+/// # let _ = color;
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct GradientEditor<'ui> {
+    ui: &'ui Ui,
+    label: Cow<'ui, str>,
+    size: Option<[f32; 2]>,
+}
+
+impl<'ui> GradientEditor<'ui> {
+    /// Creates a gradient editor builder.
+    pub fn new(ui: &'ui Ui, label: impl Into<Cow<'ui, str>>) -> Self {
+        Self {
+            ui,
+            label: label.into(),
+            size: None,
+        }
+    }
+
+    /// Sets the size of the gradient bar in pixels (defaults to the item width and frame height).
+    pub fn size(mut self, size: impl Into<[f32; 2]>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Builds the editor bound to `stops`. Returns true if the gradient changed.
+    pub fn build(self, stops: &mut Vec<GradientStop>) -> bool {
+        let ui = self.ui;
+        assert!(
+            !stops.is_empty(),
+            "GradientEditor::build() stops must not be empty"
+        );
+        let size = self
+            .size
+            .unwrap_or_else(|| [ui.calc_item_width(), ui.frame_height()]);
+        assert!(
+            size.iter().all(|v| v.is_finite() && *v > 0.0),
+            "GradientEditor::build() size must be positive"
+        );
+        let mut changed = false;
+        if !stops.is_sorted_by(|a, b| a.pos <= b.pos) {
+            stops.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+            changed = true;
+        }
+
+        let _id = ui.push_id(self.label.as_ref());
+        let pos = ui.cursor_screen_pos();
+        let marker = (size[1] * 0.5).max(6.0);
+        let x_at = |t: f32| pos[0] + t * size[0];
+        let bar_max = [pos[0] + size[0], pos[1] + size[1]];
+
+        ui.invisible_button("##gradient", [size[0], size[1] + marker]);
+        let hovered = ui.is_item_hovered();
+        let active = ui.is_item_active();
+        let mouse = ui.io().mouse_pos();
+        let mouse_t = ((mouse[0] - pos[0]) / size[0]).clamp(0.0, 1.0);
+        let on_markers = mouse[1] >= bar_max[1];
+        let under_mouse = stops
+            .iter()
+            .enumerate()
+            .map(|(i, stop)| (i, (x_at(stop.pos) - mouse[0]).abs()))
+            .filter(|&(_, distance)| on_markers && distance <= marker * 0.5)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i);
+
+        let selected_key = ui.get_id("##selected");
+        let dragging_key = ui.get_id("##dragging");
+        let mut storage = ui.state_storage();
+        let mut selected = usize::try_from(storage.get_int(selected_key, 0))
+            .unwrap_or(0)
+            .min(stops.len() - 1);
+        if ui.is_item_activated() {
+            selected = match under_mouse {
+                Some(index) => index,
+                None if !on_markers => {
+                    let color = gradient_eval(stops, mouse_t);
+                    let index = stops.partition_point(|stop| stop.pos <= mouse_t);
+                    stops.insert(index, GradientStop::new(mouse_t, color));
+                    changed = true;
+                    index
+                }
+                None => selected,
+            };
+            storage.set_bool(dragging_key, on_markers && under_mouse.is_some());
+        }
+        if active && storage.get_bool(dragging_key, false) && stops[selected].pos != mouse_t {
+            stops[selected].pos = mouse_t;
+            // Move the stop to its sorted place, past neighbours it was dragged across.
+            while selected > 0 && stops[selected - 1].pos > mouse_t {
+                stops.swap(selected - 1, selected);
+                selected -= 1;
+            }
+            while selected + 1 < stops.len() && stops[selected + 1].pos < mouse_t {
+                stops.swap(selected, selected + 1);
+                selected += 1;
+            }
+            changed = true;
+        }
+        if let Some(index) = under_mouse
+            .filter(|_| stops.len() > 1 && hovered && ui.is_mouse_clicked(MouseButton::Right))
+        {
+            stops.remove(index);
+            selected = selected.min(stops.len() - 1);
+            changed = true;
+        }
+
+        let draw_list = ui.get_window_draw_list();
+        let light = ui.get_color_u32(StyleColor::FrameBg);
+        let dark = ui.get_color_u32(StyleColor::Border);
+        let mut row = 0;
+        let mut y = pos[1];
+        while y < bar_max[1] {
+            let y1 = (y + CHECKER_SIZE).min(bar_max[1]);
+            let mut column = row;
+            let mut x = pos[0];
+            while x < bar_max[0] {
+                let x1 = (x + CHECKER_SIZE).min(bar_max[0]);
+                let color = if column % 2 == 0 { light } else { dark };
+                draw_list
+                    .add_rect([x, y], [x1, y1], color)
+                    .filled(true)
+                    .build();
+                column += 1;
+                x = x1;
+            }
+            row += 1;
+            y = y1;
+        }
+
+        let first = stops[0];
+        let last = stops[stops.len() - 1];
+        draw_list
+            .add_rect(pos, [x_at(first.pos), bar_max[1]], first.color)
+            .filled(true)
+            .build();
+        for pair in stops.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            draw_list.add_rect_filled_multicolor(
+                [x_at(a.pos), pos[1]],
+                [x_at(b.pos), bar_max[1]],
+                a.color,
+                b.color,
+                b.color,
+                a.color,
+            );
+        }
+        draw_list
+            .add_rect([x_at(last.pos), pos[1]], bar_max, last.color)
+            .filled(true)
+            .build();
+        draw_list
+            .add_rect(pos, bar_max, ui.get_color_u32(StyleColor::Border))
+            .build();
+
+        let outline = ui.get_color_u32(StyleColor::Border);
+        let highlight = ui.get_color_u32(StyleColor::Text);
+        for (i, stop) in stops.iter().enumerate() {
+            let x = x_at(stop.pos);
+            let top = bar_max[1];
+            let half = marker * 0.5;
+            let border = if i == selected { highlight } else { outline };
+            draw_list
+                .add_triangle(
+                    [x, top],
+                    [x + half, top + half],
+                    [x - half, top + half],
+                    border,
+                )
+                .filled(true)
+                .build();
+            let swatch = ([x - half, top + half], [x + half, top + marker]);
+            draw_list
+                .add_rect(swatch.0, swatch.1, stop.color)
+                .filled(true)
+                .build();
+            draw_list.add_rect(swatch.0, swatch.1, border).build();
+        }
+
+        let visible = self.label.split("##").next().unwrap_or_default();
+        if !visible.is_empty() {
+            let spacing = unsafe { ui.style() }.item_inner_spacing()[0];
+            ui.same_line_with_spacing(0.0, spacing);
+            ui.text(visible);
+        }
+
+        let mut color = stops[selected].color;
+        ui.set_next_item_width(size[0]);
+        if ui.color_edit4("##color", &mut color) {
+            stops[selected].color = color;
+            changed = true;
+        }
+        storage.set_int(selected_key, selected as i32);
+        changed
+    }
+}
@@ -0,0 +1,80 @@
+//! Help markers
+//!
+//! Inline documentation helpers: a `(?)` marker or a label followed by one, and tooltips for the
+//! last item. Their text is rendered with [`RichText`](crate::RichText), so it supports the same
+//! markdown-lite syntax as [`Ui::text_rich`].
+//!
+use crate::ItemHoveredFlags;
+use crate::Ui;
+
+/// Tooltip wrap width in multiples of the font size (as in the Dear ImGui demo's `HelpMarker`).
+const TOOLTIP_WRAP_EMS: f32 = 35.0;
+
+/// # Help Markers
+impl Ui {
+    /// Draws `marker` (usually `"(?)"`) as disabled text and shows `text` in a tooltip while it
+    /// is hovered.
+    ///
+    /// The tooltip honors the standard tooltip delays (`style.HoverFlagsForTooltipMouse`). Returns
+    /// true while the tooltip is shown.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// ui.checkbox("VSync", &mut true);
+    /// ui.same_line();
+    /// ui.help_marker("(?)", "Waits for the display refresh.\n\n- Removes tearing\n- Adds latency");
+    /// ```
+    pub fn help_marker(&self, marker: impl AsRef<str>, text: impl AsRef<str>) -> bool {
+        self.text_disabled(marker);
+        self.item_tooltip_rich(text)
+    }
+
+    /// Draws `label` followed by a `(?)` help marker showing `text`.
+    ///
+    /// Returns true while the tooltip is shown.
+    pub fn label_with_help(&self, label: impl AsRef<str>, text: impl AsRef<str>) -> bool {
+        self.text(label);
+        self.same_line();
+        self.help_marker("(?)", text)
+    }
+
+    /// Shows rich `text` in a tooltip while the last item is hovered, using the standard tooltip
+    /// delays. Chain it after any widget to document it.
+    ///
+    /// Returns true while the tooltip is shown.
+    pub fn item_tooltip_rich(&self, text: impl AsRef<str>) -> bool {
+        if !self.is_item_hovered_with_flags(ItemHoveredFlags::FOR_TOOLTIP) {
+            return false;
+        }
+        let Some(_tooltip) = self.begin_tooltip() else {
+            return false;
+        };
+        let _ = self
+            .text_rich_config(text.as_ref())
+            .wrap_width(self.current_font_size() * TOOLTIP_WRAP_EMS)
+            .build();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn help_helpers_render_without_hover() {
+        let mut ctx = crate::Context::create();
+        let _ = ctx.font_atlas_mut().build();
+        ctx.io_mut().set_display_size([256.0, 256.0]);
+        ctx.io_mut().set_delta_time(1.0 / 60.0);
+        let ui = ctx.frame();
+        ui.window("help").build(|| {
+            assert!(!ui.label_with_help("Speed", "**Units** per second"));
+            ui.button("Go");
+            assert!(!ui.item_tooltip_rich("# Notes\n- a\n- b"));
+        });
+        let _ = ctx.render();
+    }
+}
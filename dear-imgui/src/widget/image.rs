@@ -166,8 +166,8 @@ impl<'ui, 'tex> Image<'ui, 'tex> {
     /// Dear ImGui 1.91.9 moved image tinting from `Image()` to `ImageWithBg()`.
     /// If this is set, [`build`](Self::build) will call the tinted path while
     /// keeping a transparent background.
-    pub fn tint_color(mut self, tint_color: [f32; 4]) -> Self {
-        self.tint_color = tint_color;
+    pub fn tint_color(mut self, tint_color: impl Into<[f32; 4]>) -> Self {
+        self.tint_color = tint_color.into();
         self
     }
 
@@ -176,8 +176,8 @@ impl<'ui, 'tex> Image<'ui, 'tex> {
     /// Dear ImGui 1.91.9 moved image border thickness to `Style::ImageBorderSize`
     /// and border color to `StyleColor::Border`; this builder applies matching
     /// temporary style overrides around [`build`](Self::build).
-    pub fn border_color(mut self, border_color: [f32; 4]) -> Self {
-        self.border_color = border_color;
+    pub fn border_color(mut self, border_color: impl Into<[f32; 4]>) -> Self {
+        self.border_color = border_color.into();
         self
     }
 
@@ -224,7 +224,8 @@ impl<'ui, 'tex> Image<'ui, 'tex> {
     }
 
     /// Builds the image widget with background color and tint (v1.92+)
-    pub fn build_with_bg(self, bg_color: [f32; 4], tint_color: [f32; 4]) {
+    pub fn build_with_bg(self, bg_color: impl Into<[f32; 4]>, tint_color: impl Into<[f32; 4]>) {
+        let (bg_color, tint_color) = (bg_color.into(), tint_color.into());
         assert_non_negative_finite_vec2("Image::build_with_bg()", "size", self.size);
         assert_finite_vec2("Image::build_with_bg()", "uv0", self.uv0);
         assert_finite_vec2("Image::build_with_bg()", "uv1", self.uv1);
@@ -308,14 +309,14 @@ impl<'ui, 'tex> ImageButton<'ui, 'tex> {
     }
 
     /// Sets the background color (default: transparent)
-    pub fn bg_color(mut self, bg_color: [f32; 4]) -> Self {
-        self.bg_color = bg_color;
+    pub fn bg_color(mut self, bg_color: impl Into<[f32; 4]>) -> Self {
+        self.bg_color = bg_color.into();
         self
     }
 
     /// Sets the tint color (default: white, no tint)
-    pub fn tint_color(mut self, tint_color: [f32; 4]) -> Self {
-        self.tint_color = tint_color;
+    pub fn tint_color(mut self, tint_color: impl Into<[f32; 4]>) -> Self {
+        self.tint_color = tint_color.into();
         self
     }
 
@@ -0,0 +1,258 @@
+//! Knobs
+//!
+//! Rotary knobs for audio-style UIs, drawn with the window draw list. Drag vertically to change
+//! the value; hold Shift for fine adjustments.
+//!
+use crate::draw::{DrawSegmentCount, PolylineFlags};
+use crate::style::StyleColor;
+use crate::ui::Ui;
+use std::borrow::Cow;
+use std::f32::consts::PI;
+
+// The value sweeps 270 degrees clockwise, from bottom-left to bottom-right (y points down).
+const ANGLE_MIN: f32 = PI * 0.75;
+const ANGLE_MAX: f32 = PI * 2.25;
+// Vertical drag distance in pixels covering the whole range.
+const DRAG_PIXELS: f32 = 200.0;
+const FINE_DRAG_FACTOR: f32 = 0.1;
+
+fn assert_finite_f32(caller: &str, name: &str, value: f32) {
+    assert!(value.is_finite(), "{caller} {name} must be finite");
+}
+
+/// How a [`Knob`] shows its value.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum KnobVariant {
+    /// A line from the center towards the rim.
+    #[default]
+    Tick,
+    /// A small dot near the rim.
+    Dot,
+    /// An arc along the rim filled from the minimum up to the value.
+    Wiper,
+}
+
+/// # Knob Widgets
+impl Ui {
+    /// Creates a knob for a value in `0.0..=1.0`. Returns true if the value changed.
+    pub fn knob(&self, label: impl AsRef<str>, value: &mut f32) -> bool {
+        self.knob_config(label.as_ref(), 0.0, 1.0).build(value)
+    }
+
+    /// Creates a knob builder for a value in `min..=max`.
+    pub fn knob_config<'ui>(
+        &'ui self,
+        label: impl Into<Cow<'ui, str>>,
+        min: f32,
+        max: f32,
+    ) -> Knob<'ui> {
+        Knob::new(self, label, min, max)
+    }
+}
+
+/// Builder for a knob widget.
+///
+/// The knob occupies a square of [`Knob::size`] pixels (three frame heights by default) and shows
+/// the visible part of its label to the right. While dragged, a tooltip shows the value.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// let mut cutoff = 1000.0f32;
+/// ui.knob_config("Cutoff", 20.0, 20000.0)
+///     .variant(KnobVariant::Wiper)
+///     .logarithmic(true)
+///     .unit("Hz")
+///     .build(&mut cutoff);
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Knob<'ui> {
+    ui: &'ui Ui,
+    label: Cow<'ui, str>,
+    min: f32,
+    max: f32,
+    size: Option<f32>,
+    variant: KnobVariant,
+    logarithmic: bool,
+    unit: Option<Cow<'ui, str>>,
+}
+
+impl<'ui> Knob<'ui> {
+    /// Creates a knob builder for a value in `min..=max`.
+    pub fn new(ui: &'ui Ui, label: impl Into<Cow<'ui, str>>, min: f32, max: f32) -> Self {
+        Self {
+            ui,
+            label: label.into(),
+            min,
+            max,
+            size: None,
+            variant: KnobVariant::default(),
+            logarithmic: false,
+            unit: None,
+        }
+    }
+
+    /// Sets the knob diameter in pixels.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets how the value is drawn.
+    pub fn variant(mut self, variant: KnobVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Maps the knob rotation logarithmically to the range, e.g. for frequencies.
+    ///
+    /// Requires `0 < min < max`.
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.logarithmic = logarithmic;
+        self
+    }
+
+    /// Sets a unit shown after the value in the drag tooltip.
+    pub fn unit(mut self, unit: impl Into<Cow<'ui, str>>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Builds the knob bound to `value`. Returns true if the value changed.
+    pub fn build(self, value: &mut f32) -> bool {
+        let ui = self.ui;
+        assert_finite_f32("Knob::build()", "min", self.min);
+        assert_finite_f32("Knob::build()", "max", self.max);
+        assert!(self.min < self.max, "Knob::build() requires min < max");
+        if self.logarithmic {
+            assert!(
+                self.min > 0.0,
+                "Knob::build() logarithmic knobs require a positive min"
+            );
+        }
+        let size = self.size.unwrap_or_else(|| ui.frame_height() * 3.0);
+        assert!(
+            size.is_finite() && size > 0.0,
+            "Knob::build() size must be positive"
+        );
+
+        let pos = ui.cursor_screen_pos();
+        ui.invisible_button(self.label.as_ref(), [size, size]);
+        let active = ui.is_item_active();
+        let hovered = ui.is_item_hovered();
+
+        let mut t = self.normalized(*value);
+        let mut changed = false;
+        if active {
+            let mut delta = -ui.io().mouse_delta()[1] / DRAG_PIXELS;
+            if ui.io().key_shift() {
+                delta *= FINE_DRAG_FACTOR;
+            }
+            if delta != 0.0 {
+                t = (t + delta).clamp(0.0, 1.0);
+                let new_value = self.denormalized(t);
+                changed = new_value != *value;
+                *value = new_value;
+            }
+        }
+
+        let radius = size * 0.5;
+        let center = [pos[0] + radius, pos[1] + radius];
+        let angle = ANGLE_MIN + t * (ANGLE_MAX - ANGLE_MIN);
+        let at = |r: f32| [center[0] + angle.cos() * r, center[1] + angle.sin() * r];
+        let body = match (active, hovered) {
+            (true, _) => StyleColor::FrameBgActive,
+            (false, true) => StyleColor::FrameBgHovered,
+            (false, false) => StyleColor::FrameBg,
+        };
+        let grab = if active {
+            StyleColor::SliderGrabActive
+        } else {
+            StyleColor::SliderGrab
+        };
+        let grab = ui.get_color_u32(grab);
+        let thickness = (size * 0.06).max(1.0);
+
+        let draw_list = ui.get_window_draw_list();
+        match self.variant {
+            KnobVariant::Tick | KnobVariant::Dot => {
+                draw_list
+                    .add_circle(center, radius, ui.get_color_u32(body))
+                    .filled(true)
+                    .build();
+                if self.variant == KnobVariant::Tick {
+                    draw_list
+                        .add_line(at(radius * 0.3), at(radius * 0.85), grab)
+                        .thickness(thickness)
+                        .build();
+                } else {
+                    draw_list
+                        .add_circle(at(radius * 0.65), radius * 0.12, grab)
+                        .filled(true)
+                        .build();
+                }
+            }
+            KnobVariant::Wiper => {
+                let track_radius = radius - thickness;
+                draw_list
+                    .add_circle(center, radius * 0.6, ui.get_color_u32(body))
+                    .filled(true)
+                    .build();
+                draw_list.path_arc_to(
+                    center,
+                    track_radius,
+                    ANGLE_MIN,
+                    ANGLE_MAX,
+                    DrawSegmentCount::AUTO,
+                );
+                draw_list.path_stroke(ui.get_color_u32(body), PolylineFlags::NONE, thickness * 2.0);
+                if t > 0.0 {
+                    draw_list.path_arc_to(
+                        center,
+                        track_radius,
+                        ANGLE_MIN,
+                        angle,
+                        DrawSegmentCount::AUTO,
+                    );
+                    draw_list.path_stroke(grab, PolylineFlags::NONE, thickness * 2.0);
+                }
+            }
+        }
+
+        if active {
+            let unit = self.unit.as_deref().unwrap_or_default();
+            let separator = if unit.is_empty() { "" } else { " " };
+            ui.tooltip_text(format!("{value:.3}{separator}{unit}"));
+        }
+
+        let visible = self.label.split("##").next().unwrap_or_default();
+        if !visible.is_empty() {
+            let spacing = unsafe { ui.style() }.item_inner_spacing()[0];
+            ui.same_line_with_spacing(0.0, spacing);
+            ui.text(visible);
+        }
+        changed
+    }
+
+    fn normalized(&self, value: f32) -> f32 {
+        let value = value.clamp(self.min, self.max);
+        if self.logarithmic {
+            (value / self.min).ln() / (self.max / self.min).ln()
+        } else {
+            (value - self.min) / (self.max - self.min)
+        }
+    }
+
+    fn denormalized(&self, t: f32) -> f32 {
+        let value = if self.logarithmic {
+            self.min * (self.max / self.min).powf(t)
+        } else {
+            self.min + t * (self.max - self.min)
+        };
+        value.clamp(self.min, self.max)
+    }
+}
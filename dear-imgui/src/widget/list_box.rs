@@ -6,6 +6,7 @@ use std::borrow::Cow;
 
 use crate::Ui;
 use crate::sys;
+use crate::widget::combo::clipped_selectables;
 
 fn assert_finite_vec2(caller: &str, name: &str, value: [f32; 2]) {
     assert!(
@@ -167,4 +168,51 @@ impl<T: AsRef<str>> ListBox<T> {
         }
         result
     }
+
+    /// Builds a simple list box for choosing from strings.
+    ///
+    /// Accepts slices, `Vec` references, or any iterator of string-like values, so labels are read
+    /// in place instead of being collected first. Every item is submitted each frame; use
+    /// [`Self::build_with_getter`] for long lists.
+    pub fn build_simple_string<I>(self, ui: &Ui, current_item: &mut usize, items: I) -> bool
+    where
+        I: IntoIterator<Item: AsRef<str>>,
+    {
+        let mut result = false;
+        if let Some(_lb) = self.begin(ui) {
+            for (idx, item) in items.into_iter().enumerate() {
+                let selected = idx == *current_item;
+                if ui
+                    .selectable_config(item.as_ref())
+                    .selected(selected)
+                    .build()
+                {
+                    *current_item = idx;
+                    result = true;
+                }
+            }
+        }
+        result
+    }
+
+    /// Builds a list box whose labels are produced on demand by index.
+    ///
+    /// Rows are submitted through a [`ListClipper`](crate::ListClipper), so only the visible ones
+    /// (plus the current item) call `item_getter` and the per-frame cost does not grow with
+    /// `items_count`.
+    pub fn build_with_getter<'a, F>(
+        self,
+        ui: &Ui,
+        current_item: &mut usize,
+        items_count: usize,
+        item_getter: F,
+    ) -> bool
+    where
+        F: Fn(usize) -> Cow<'a, str>,
+    {
+        match self.begin(ui) {
+            Some(_lb) => clipped_selectables(ui, current_item, items_count, &item_getter, false),
+            None => false,
+        }
+    }
 }
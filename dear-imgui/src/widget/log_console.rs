@@ -0,0 +1,324 @@
+//! Log console
+//!
+//! A scrolling log view modeled on the demo's `ExampleAppLog`: severity colors, a level and text
+//! filter, auto-scroll, copy to clipboard and a ring buffer of the most recent lines. Other
+//! threads append lines through a [`LogSender`].
+//!
+use crate::list_clipper::ListClipper;
+use crate::style::{StyleColor, StyleVar};
+use crate::sys;
+use crate::text_filter::TextFilter;
+use crate::ui::Ui;
+use crate::window::WindowFlags;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::mpsc;
+
+/// Severity of a [`LogLine`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// All levels, from least to most severe.
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Trace,
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warn,
+        LogLevel::Error,
+    ];
+
+    /// Returns the display name of the level.
+    pub fn name(self) -> &'static str {
+        match self {
+            LogLevel::Trace => "Trace",
+            LogLevel::Debug => "Debug",
+            LogLevel::Info => "Info",
+            LogLevel::Warn => "Warn",
+            LogLevel::Error => "Error",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// One line of a [`LogConsole`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogLine {
+    pub level: LogLevel,
+    pub text: String,
+}
+
+/// A cloneable, thread-safe handle appending lines to a [`LogConsole`].
+///
+/// Sending never blocks or locks: lines are queued and moved into the console on its next
+/// [`LogConsole::draw`] (or [`LogConsole::drain`]).
+#[derive(Clone, Debug)]
+pub struct LogSender(mpsc::Sender<LogLine>);
+
+impl LogSender {
+    /// Queues a line. Returns false if the console was dropped.
+    pub fn log(&self, level: LogLevel, text: impl Into<String>) -> bool {
+        self.0
+            .send(LogLine {
+                level,
+                text: text.into(),
+            })
+            .is_ok()
+    }
+
+    /// Queues an [`LogLevel::Info`] line.
+    pub fn info(&self, text: impl Into<String>) -> bool {
+        self.log(LogLevel::Info, text)
+    }
+
+    /// Queues a [`LogLevel::Warn`] line.
+    pub fn warn(&self, text: impl Into<String>) -> bool {
+        self.log(LogLevel::Warn, text)
+    }
+
+    /// Queues a [`LogLevel::Error`] line.
+    pub fn error(&self, text: impl Into<String>) -> bool {
+        self.log(LogLevel::Error, text)
+    }
+}
+
+/// A reusable log console widget keeping the last `capacity` lines.
+///
+/// The toolbar has Clear and Copy buttons, an auto-scroll toggle, a minimum level and a text
+/// filter using [`TextFilter`] syntax (`word`, `-word`, `a,b`). For other matching (e.g. regular
+/// expressions) add a [`LogConsole::set_filter_predicate`]. Only visible lines are submitted, so
+/// large buffers stay cheap.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// let mut console = LogConsole::new(10_000);
+/// let sender = console.sender();
+/// std::thread::spawn(move || {
+///     sender.info("loading assets");
+///     sender.warn("texture missing, using fallback");
+/// });
+///
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// ui.window("Log").build(|| console.draw(ui));
+/// ```
+pub struct LogConsole {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+    sender: mpsc::Sender<LogLine>,
+    receiver: mpsc::Receiver<LogLine>,
+    filter: TextFilter,
+    min_level: LogLevel,
+    auto_scroll: bool,
+    colors: [Option<[f32; 4]>; 5],
+    predicate: Option<Box<dyn Fn(&LogLine) -> bool>>,
+}
+
+impl fmt::Debug for LogConsole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogConsole")
+            .field("lines", &self.lines.len())
+            .field("capacity", &self.capacity)
+            .field("min_level", &self.min_level)
+            .field("auto_scroll", &self.auto_scroll)
+            .field("colors", &self.colors)
+            .field("predicate", &self.predicate.is_some())
+            .finish()
+    }
+}
+
+impl LogConsole {
+    /// Creates a console keeping the last `capacity` lines.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "LogConsole::new() capacity must be positive");
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            lines: VecDeque::new(),
+            capacity,
+            sender,
+            receiver,
+            filter: TextFilter::new("Filter"),
+            min_level: LogLevel::Trace,
+            auto_scroll: true,
+            colors: [
+                Some([0.5, 0.5, 0.5, 1.0]),
+                Some([0.6, 0.7, 0.8, 1.0]),
+                None,
+                Some([1.0, 0.8, 0.3, 1.0]),
+                Some([1.0, 0.4, 0.4, 1.0]),
+            ],
+            predicate: None,
+        }
+    }
+
+    /// Returns a handle for appending lines from any thread.
+    pub fn sender(&self) -> LogSender {
+        LogSender(self.sender.clone())
+    }
+
+    /// Appends a line, dropping the oldest one when full.
+    pub fn push(&mut self, level: LogLevel, text: impl Into<String>) {
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(LogLine {
+            level,
+            text: text.into(),
+        });
+    }
+
+    /// Moves lines queued by [`LogSender`]s into the console. Returns how many were moved.
+    pub fn drain(&mut self) -> usize {
+        let mut count = 0;
+        while let Ok(line) = self.receiver.try_recv() {
+            self.push(line.level, line.text);
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns the lines, oldest first.
+    pub fn lines(&self) -> &VecDeque<LogLine> {
+        &self.lines
+    }
+
+    /// Returns the number of lines kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes all lines.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Returns the minimum level shown.
+    pub fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    /// Hides lines below `level`.
+    pub fn set_min_level(&mut self, level: LogLevel) {
+        self.min_level = level;
+    }
+
+    /// Returns whether the view follows new lines while scrolled to the bottom.
+    pub fn auto_scroll(&self) -> bool {
+        self.auto_scroll
+    }
+
+    /// Sets whether the view follows new lines while scrolled to the bottom.
+    pub fn set_auto_scroll(&mut self, auto_scroll: bool) {
+        self.auto_scroll = auto_scroll;
+    }
+
+    /// Sets the text color of `level`; `None` uses the style's text color.
+    pub fn set_level_color(&mut self, level: LogLevel, color: Option<[f32; 4]>) {
+        self.colors[level.index()] = color;
+    }
+
+    /// Replaces the text filter pattern, see [`TextFilter`] for the syntax.
+    pub fn set_filter(&mut self, pattern: impl AsRef<str>) {
+        self.filter = TextFilter::new_with_filter("Filter", pattern);
+    }
+
+    /// Adds a custom filter applied after the level and text filters.
+    pub fn set_filter_predicate(&mut self, predicate: impl Fn(&LogLine) -> bool + 'static) {
+        self.predicate = Some(Box::new(predicate));
+    }
+
+    /// Removes the custom filter.
+    pub fn clear_filter_predicate(&mut self) {
+        self.predicate = None;
+    }
+
+    /// Returns whether `line` passes the level, text and custom filters.
+    pub fn is_shown(&self, line: &LogLine) -> bool {
+        line.level >= self.min_level
+            && self.filter.pass_filter(&line.text)
+            && self
+                .predicate
+                .as_ref()
+                .is_none_or(|predicate| predicate(line))
+    }
+
+    /// Returns the lines passing the filters, oldest first.
+    pub fn visible_lines(&self) -> impl Iterator<Item = &LogLine> {
+        self.lines.iter().filter(|line| self.is_shown(line))
+    }
+
+    fn is_filtering(&self) -> bool {
+        self.min_level > LogLevel::Trace || self.filter.is_active() || self.predicate.is_some()
+    }
+
+    /// Draws the toolbar and the scrolling lines into the current window.
+    pub fn draw(&mut self, ui: &Ui) {
+        self.drain();
+
+        if ui.button("Clear") {
+            self.clear();
+        }
+        ui.same_line();
+        let copy = ui.button("Copy");
+        ui.same_line();
+        ui.checkbox("Auto-scroll", &mut self.auto_scroll);
+        ui.same_line();
+        let mut level = self.min_level.index();
+        ui.set_next_item_width(ui.calc_item_width().min(ui.current_font_size() * 6.0));
+        if ui.combo_simple_string("Level", &mut level, LogLevel::ALL.iter().map(|l| l.name())) {
+            self.min_level = LogLevel::ALL[level];
+        }
+        ui.same_line();
+        self.filter.draw_with_size(ui, -f32::MIN_POSITIVE);
+        ui.separator();
+
+        let visible: Option<Vec<usize>> = self.is_filtering().then(|| {
+            (0..self.lines.len())
+                .filter(|&i| self.is_shown(&self.lines[i]))
+                .collect()
+        });
+        let line_at = |i: usize| match &visible {
+            Some(visible) => &self.lines[visible[i]],
+            None => &self.lines[i],
+        };
+        let count = visible.as_ref().map_or(self.lines.len(), Vec::len);
+
+        if copy {
+            let mut text = String::new();
+            for i in 0..count {
+                text.push_str(&line_at(i).text);
+                text.push('\n');
+            }
+            let text = ui.scratch_txt(text);
+            ui.run_with_bound_context(|| unsafe { sys::igSetClipboardText(text) });
+        }
+
+        ui.child_window("##log_console_lines")
+            .flags(WindowFlags::HORIZONTAL_SCROLLBAR)
+            .stick_to_bottom(self.auto_scroll)
+            .build(ui, || {
+                let _spacing = ui.push_style_var(StyleVar::ItemSpacing([0.0, 0.0]));
+                for i in ListClipper::new(count).begin(ui).iter() {
+                    let line = line_at(i);
+                    match self.colors[line.level.index()] {
+                        Some(color) => {
+                            let _color = ui.push_style_color(StyleColor::Text, color);
+                            ui.text(&line.text);
+                        }
+                        None => ui.text(&line.text),
+                    }
+                }
+            });
+    }
+}
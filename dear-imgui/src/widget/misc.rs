@@ -12,6 +12,7 @@
 mod basic;
 mod button_repeat;
 mod disabled;
+mod interact;
 mod invisible_button;
 mod item_key;
 mod validation;
@@ -21,6 +22,7 @@ mod tests;
 
 pub use button_repeat::ButtonRepeatToken;
 pub use disabled::DisabledToken;
+pub use interact::Interaction;
 pub use invisible_button::{
     ArrowDirection, ButtonFlags, InvisibleButtonMouseButtons, InvisibleButtonOptions,
 };
@@ -20,6 +20,13 @@ impl Ui {
             sys::igBulletText(FMT.as_ptr() as *const std::os::raw::c_char, text_ptr);
         });
     }
+
+    /// Creates a bullet point with formatted text, without allocating a `String`
+    /// (see [`Ui::with_fmt`]).
+    #[doc(alias = "BulletText")]
+    pub fn bullet_text_fmt(&self, args: std::fmt::Arguments<'_>) {
+        self.with_fmt(args, |text| self.bullet_text(text));
+    }
 }
 
 impl Ui {
@@ -49,4 +49,44 @@ impl Ui {
         self.run_with_bound_context(|| unsafe { sys::igBeginDisabled(disabled) });
         DisabledToken::new(self)
     }
+
+    /// Begin a disabled scope for subsequent items when `disabled` is true.
+    ///
+    /// Same as [`Self::begin_disabled_with_cond`]. Disabled scopes nest: items stay disabled
+    /// while any enclosing scope is disabled, so a `false` scope cannot re-enable items.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// # let busy = true;
+    /// let _disabled = ui.begin_disabled_when(busy);
+    /// ui.button("Apply");
+    /// ui.button("Reset");
+    /// ```
+    #[doc(alias = "BeginDisabled")]
+    pub fn begin_disabled_when(&self, disabled: bool) -> DisabledToken<'_> {
+        self.begin_disabled_with_cond(disabled)
+    }
+
+    /// Returns true if items submitted now are disabled by an enclosing disabled scope.
+    ///
+    /// Useful in custom widgets to skip interaction or pick dimmed colors.
+    #[doc(alias = "ImGuiItemFlags_Disabled")]
+    pub fn is_any_parent_disabled(&self) -> bool {
+        self.run_with_bound_context(|| unsafe {
+            let ctx = sys::igGetCurrentContext();
+            (*ctx).CurrentItemFlags & (sys::ImGuiItemFlags_Disabled as sys::ImGuiItemFlags) != 0
+        })
+    }
+
+    /// Returns the number of open disabled scopes, including scopes begun with `false`.
+    pub fn disabled_depth(&self) -> usize {
+        self.run_with_bound_context(|| unsafe {
+            let ctx = sys::igGetCurrentContext();
+            (*ctx).DisabledStackSize.max(0) as usize
+        })
+    }
 }
@@ -0,0 +1,107 @@
+use crate::Ui;
+use crate::input::MouseButton;
+
+/// What happened to a region created with [`Ui::interact_region`] this frame.
+///
+/// Custom widgets draw into `min..max` with the draw list and read the interaction state from
+/// here instead of issuing the individual item queries.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Interaction {
+    /// Upper-left corner of the region (screen space).
+    pub min: [f32; 2],
+    /// Lower-right corner of the region (screen space).
+    pub max: [f32; 2],
+    /// The region was clicked (pressed and released over it).
+    pub clicked: bool,
+    /// The region was double-clicked with the left mouse button.
+    pub double_clicked: bool,
+    /// The mouse is over the region and it is not blocked by a popup or another active item.
+    pub hovered: bool,
+    /// The region is held, e.g. while being dragged.
+    pub active: bool,
+    /// The region became active this frame.
+    pub activated: bool,
+    /// The region stopped being active this frame.
+    pub deactivated: bool,
+    /// Mouse position relative to [`Interaction::min`].
+    pub mouse_pos: [f32; 2],
+    /// Mouse movement this frame while [`Interaction::active`], zero otherwise.
+    pub drag_delta: [f32; 2],
+}
+
+impl Interaction {
+    /// Size of the region.
+    pub fn size(&self) -> [f32; 2] {
+        [self.max[0] - self.min[0], self.max[1] - self.min[1]]
+    }
+
+    /// Center of the region (screen space).
+    pub fn center(&self) -> [f32; 2] {
+        [
+            (self.min[0] + self.max[0]) * 0.5,
+            (self.min[1] + self.max[1]) * 0.5,
+        ]
+    }
+}
+
+impl Ui {
+    /// Creates an invisible button of `size` at the cursor and returns its interaction state.
+    ///
+    /// This is the usual base of widgets drawn with the draw list: it reserves the layout space,
+    /// gives the region an ID for hover and active tracking and reports clicks and drags. Call
+    /// [`Ui::set_next_item_allow_overlap`] before it to let later items over the region take the
+    /// mouse.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let region = ui.interact_region("##canvas", [200.0, 100.0]);
+    /// let color = if region.active { [1.0, 0.5, 0.0, 1.0] } else { [0.3, 0.3, 0.3, 1.0] };
+    /// ui.get_window_draw_list()
+    ///     .add_rect(region.min, region.max, color)
+    ///     .filled(true)
+    ///     .build();
+    /// ```
+    #[doc(alias = "InvisibleButton")]
+    pub fn interact_region(
+        &self,
+        str_id: impl AsRef<str>,
+        size: impl Into<[f32; 2]>,
+    ) -> Interaction {
+        self.interact_region_options(str_id, size, crate::widget::InvisibleButtonOptions::new())
+    }
+
+    /// Like [`Ui::interact_region`], with the invisible button's flags and mouse buttons.
+    #[doc(alias = "InvisibleButton")]
+    pub fn interact_region_options(
+        &self,
+        str_id: impl AsRef<str>,
+        size: impl Into<[f32; 2]>,
+        options: impl Into<crate::widget::InvisibleButtonOptions>,
+    ) -> Interaction {
+        let clicked = self.invisible_button_options(str_id, size, options);
+        let (min, max) = (self.item_rect_min(), self.item_rect_max());
+        let hovered = self.is_item_hovered();
+        let active = self.is_item_active();
+        let mouse = self.io().mouse_pos();
+        Interaction {
+            min,
+            max,
+            clicked,
+            double_clicked: hovered && self.is_mouse_double_clicked(MouseButton::Left),
+            hovered,
+            active,
+            activated: self.is_item_activated(),
+            deactivated: self.is_item_deactivated(),
+            mouse_pos: [mouse[0] - min[0], mouse[1] - min[1]],
+            drag_delta: if active {
+                self.io().mouse_delta()
+            } else {
+                [0.0; 2]
+            },
+        }
+    }
+}
@@ -21,26 +21,34 @@
 //! ui.input_text("Name", &mut text).build();
 //! ```
 //!
-//! Submodules group related widgets: `button`, `color`, `combo`, `drag`,
-//! `image`, `input`, `list_box`, `menu`, `misc`, `plot`, `popup`, `progress`,
-//! `selectable`, `slider`, `tab`, `table`, `text`, `tooltip`, `tree`.
+//! Submodules group related widgets: `button`, `color`, `combo`, `curve`, `drag`,
+//! `gradient`, `help`, `image`, `input`, `knob`, `list_box`, `log_console`, `menu`, `misc`, `notifications`, `plot`, `popup`, `progress`,
+//! `rich_text`, `selectable`, `slider`, `splitter`, `tab`, `table`, `text`, `tooltip`, `tree`.
 //!
 
 pub mod button;
 pub mod color;
 pub mod combo;
+pub mod curve;
 pub mod drag;
+pub mod gradient;
+pub mod help;
 pub mod image;
 pub mod input;
+pub mod knob;
 pub mod list_box;
+pub mod log_console;
 pub mod menu;
 pub mod misc;
 pub mod multi_select;
+pub mod notifications;
 pub mod plot;
 pub mod popup;
 pub mod progress;
+pub mod rich_text;
 pub mod selectable;
 pub mod slider;
+pub mod splitter;
 pub mod tab;
 pub mod table;
 pub mod text;
@@ -55,18 +63,25 @@ pub use table::{TableBgTarget, TableBuilder, TableColumnSetup};
 pub use self::button::*;
 pub use self::color::*;
 pub use self::combo::*;
+pub use self::curve::*;
 pub use self::drag::*;
+pub use self::gradient::*;
 pub use self::image::*;
 pub use self::input::*;
+pub use self::knob::*;
 pub use self::list_box::*;
+pub use self::log_console::*;
 pub use self::menu::*;
 pub use self::misc::*;
 pub use self::multi_select::*;
+pub use self::notifications::*;
 pub use self::plot::*;
 pub use self::popup::*;
 pub use self::progress::*;
+pub use self::rich_text::*;
 pub use self::selectable::*;
 pub use self::slider::*;
+pub use self::splitter::*;
 pub use self::tab::*;
 pub use self::table::*;
 pub use self::tooltip::*;
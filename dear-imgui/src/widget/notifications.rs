@@ -0,0 +1,494 @@
+//! Notifications
+//!
+//! Toast messages stacked in a corner of the main viewport. Each toast is a small undecorated
+//! window kept in front of the others, so toasts can be drawn from anywhere in the frame, inside
+//! or outside of windows, and clicking one never reaches the widgets below. See
+//! [`Notifications`].
+//!
+use crate::Condition;
+use crate::style::{StyleColor, StyleVar};
+use crate::sys;
+use crate::ui::Ui;
+use crate::utils::OverlayCorner;
+use crate::window::WindowFlags;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_WIDTH: f32 = 300.0;
+const DEFAULT_MAX_VISIBLE: usize = 5;
+// Distance to the viewport edges and between toasts.
+const MARGIN: f32 = 10.0;
+const ACCENT_WIDTH: f32 = 4.0;
+const SLIDE_SECONDS: f64 = 0.2;
+const FADE_SECONDS: f64 = 0.4;
+
+/// Severity of a [`Toast`], shown as its accent color.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ToastLevel {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    /// All levels, from least to most severe.
+    pub const ALL: [ToastLevel; 4] = [
+        ToastLevel::Info,
+        ToastLevel::Success,
+        ToastLevel::Warning,
+        ToastLevel::Error,
+    ];
+
+    /// Returns the display name of the level.
+    pub fn name(self) -> &'static str {
+        match self {
+            ToastLevel::Info => "Info",
+            ToastLevel::Success => "Success",
+            ToastLevel::Warning => "Warning",
+            ToastLevel::Error => "Error",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// A toast message for [`Notifications::push`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # use std::time::Duration;
+/// let toast = Toast::new(ToastLevel::Warning, "Disk almost full")
+///     .title("Storage")
+///     .timeout(Duration::from_secs(10));
+/// # let _ = toast;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[must_use]
+pub struct Toast {
+    level: ToastLevel,
+    text: String,
+    title: Option<String>,
+    icon: Option<String>,
+    timeout: Option<Duration>,
+    sticky: bool,
+}
+
+impl Toast {
+    /// Creates a toast with the manager's default timeout.
+    pub fn new(level: ToastLevel, text: impl Into<String>) -> Self {
+        Self {
+            level,
+            text: text.into(),
+            title: None,
+            icon: None,
+            timeout: None,
+            sticky: false,
+        }
+    }
+
+    /// Sets a title shown above the text.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets an icon shown before the text in the level color, e.g. a glyph of an icon font.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets how long the toast stays after appearing.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Keeps the toast until it is clicked or dismissed with [`Notifications::dismiss`].
+    pub fn sticky(mut self) -> Self {
+        self.sticky = true;
+        self
+    }
+
+    /// Returns the level.
+    pub fn level(&self) -> ToastLevel {
+        self.level
+    }
+
+    /// Returns the text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Identifies a toast pushed to [`Notifications`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+#[derive(Debug)]
+struct Entry {
+    id: ToastId,
+    toast: Toast,
+    shown_at: Option<f64>,
+    closing_at: Option<f64>,
+    dismissed: bool,
+}
+
+/// A queue of toast messages drawn as an overlay.
+///
+/// Push toasts at any time and call [`Notifications::draw`] once per frame. At most
+/// [`Notifications::set_max_visible`] toasts are shown, the oldest closest to the corner; the
+/// others wait and their timeout starts once they appear. Toasts slide in from the screen edge,
+/// fade out when they time out and can be dismissed by clicking them. While a toast animates or
+/// waits for its timeout, frames are requested through [`Ui::animation_clock`].
+///
+/// Toasts are submitted as windows named `##toast0`, `##toast1`, ... and are brought to the
+/// front every frame; use a single `Notifications` per context.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// let mut notifications = Notifications::new();
+/// notifications.set_corner(OverlayCorner::TopRight);
+/// notifications.success("Saved scene.ron");
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// notifications.draw(ui);
+/// ```
+#[derive(Debug)]
+pub struct Notifications {
+    entries: VecDeque<Entry>,
+    next_id: u64,
+    corner: OverlayCorner,
+    default_timeout: Duration,
+    max_visible: usize,
+    width: f32,
+    level_colors: [[f32; 4]; 4],
+}
+
+impl Default for Notifications {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Notifications {
+    /// Creates an empty queue drawn in the bottom-right corner.
+    pub fn new() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            next_id: 0,
+            corner: OverlayCorner::BottomRight,
+            default_timeout: DEFAULT_TIMEOUT,
+            max_visible: DEFAULT_MAX_VISIBLE,
+            width: DEFAULT_WIDTH,
+            level_colors: [
+                [0.35, 0.6, 1.0, 1.0],
+                [0.3, 0.85, 0.4, 1.0],
+                [1.0, 0.75, 0.2, 1.0],
+                [1.0, 0.35, 0.35, 1.0],
+            ],
+        }
+    }
+
+    /// Queues a toast.
+    pub fn push(&mut self, toast: Toast) -> ToastId {
+        let id = ToastId(self.next_id);
+        self.next_id += 1;
+        self.entries.push_back(Entry {
+            id,
+            toast,
+            shown_at: None,
+            closing_at: None,
+            dismissed: false,
+        });
+        id
+    }
+
+    /// Queues a [`ToastLevel::Info`] toast.
+    pub fn info(&mut self, text: impl Into<String>) -> ToastId {
+        self.push(Toast::new(ToastLevel::Info, text))
+    }
+
+    /// Queues a [`ToastLevel::Success`] toast.
+    pub fn success(&mut self, text: impl Into<String>) -> ToastId {
+        self.push(Toast::new(ToastLevel::Success, text))
+    }
+
+    /// Queues a [`ToastLevel::Warning`] toast.
+    pub fn warning(&mut self, text: impl Into<String>) -> ToastId {
+        self.push(Toast::new(ToastLevel::Warning, text))
+    }
+
+    /// Queues a [`ToastLevel::Error`] toast.
+    pub fn error(&mut self, text: impl Into<String>) -> ToastId {
+        self.push(Toast::new(ToastLevel::Error, text))
+    }
+
+    /// Removes a toast; a visible one fades out first. Returns false if it is already gone.
+    pub fn dismiss(&mut self, id: ToastId) -> bool {
+        let Some(index) = self.entries.iter().position(|entry| entry.id == id) else {
+            return false;
+        };
+        if self.entries[index].shown_at.is_none() {
+            self.entries.remove(index);
+        } else {
+            self.entries[index].dismissed = true;
+        }
+        true
+    }
+
+    /// Removes all toasts immediately.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of queued and visible toasts.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no toasts.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the queued and visible toasts, oldest first.
+    pub fn toasts(&self) -> impl Iterator<Item = (ToastId, &Toast)> {
+        self.entries.iter().map(|entry| (entry.id, &entry.toast))
+    }
+
+    /// Returns the corner toasts stack from.
+    pub fn corner(&self) -> OverlayCorner {
+        self.corner
+    }
+
+    /// Sets the corner of the main viewport's work area toasts stack from.
+    pub fn set_corner(&mut self, corner: OverlayCorner) {
+        self.corner = corner;
+    }
+
+    /// Sets the timeout of toasts without their own (5 seconds by default).
+    pub fn set_default_timeout(&mut self, timeout: Duration) {
+        self.default_timeout = timeout;
+    }
+
+    /// Sets how many toasts are shown at once.
+    pub fn set_max_visible(&mut self, max_visible: usize) {
+        assert!(
+            max_visible > 0,
+            "Notifications::set_max_visible() max_visible must be positive"
+        );
+        self.max_visible = max_visible;
+    }
+
+    /// Sets the toast width in pixels.
+    pub fn set_width(&mut self, width: f32) {
+        assert!(
+            width.is_finite() && width > 0.0,
+            "Notifications::set_width() width must be positive"
+        );
+        self.width = width;
+    }
+
+    /// Sets the accent color of `level`.
+    pub fn set_level_color(&mut self, level: ToastLevel, color: impl Into<[f32; 4]>) {
+        self.level_colors[level.index()] = color.into();
+    }
+
+    /// Draws the visible toasts and retires expired ones.
+    pub fn draw(&mut self, ui: &Ui) {
+        let clock = ui.animation_clock();
+        let now = clock.time();
+        let default_timeout = self.default_timeout;
+        self.entries.retain(|entry| {
+            closing_at(entry, default_timeout).is_none_or(|at| now < at + FADE_SECONDS)
+        });
+        if self.entries.is_empty() {
+            return;
+        }
+
+        let style = unsafe { ui.style() };
+        let padding = style.window_padding();
+        let rounding = style.window_rounding();
+        let spacing = style.item_spacing()[1];
+        let icon_spacing = style.item_inner_spacing()[0];
+        let font = ui.current_font();
+        let font_size = ui.current_font_size();
+        let background = ui.style_color(StyleColor::PopupBg);
+        let border = ui.style_color(StyleColor::Border);
+        let text_color = ui.style_color(StyleColor::Text);
+
+        let viewport = ui.main_viewport();
+        let (work_pos, work_size) = (viewport.work_pos(), viewport.work_size());
+        let (right, bottom) = match self.corner {
+            OverlayCorner::TopLeft => (false, false),
+            OverlayCorner::TopRight => (true, false),
+            OverlayCorner::BottomLeft => (false, true),
+            OverlayCorner::BottomRight => (true, true),
+        };
+        let width = self.width;
+        let mut y = if bottom {
+            work_pos[1] + work_size[1] - MARGIN
+        } else {
+            work_pos[1] + MARGIN
+        };
+
+        let flags = WindowFlags::NO_DECORATION
+            | WindowFlags::NO_BACKGROUND
+            | WindowFlags::NO_SAVED_SETTINGS
+            | WindowFlags::NO_FOCUS_ON_APPEARING
+            | WindowFlags::NO_NAV
+            | WindowFlags::NO_MOVE
+            | WindowFlags::NO_DOCKING
+            | WindowFlags::NO_SCROLL_WITH_MOUSE;
+        let _padding = ui.push_style_var(StyleVar::WindowPadding([0.0, 0.0]));
+        let _border = ui.push_style_var(StyleVar::WindowBorderSize(0.0));
+        let _min_size = ui.push_style_var(StyleVar::WindowMinSize([1.0, 1.0]));
+        let mut next_deadline: Option<f64> = None;
+        let mut animating = false;
+        let mut clicked = None;
+        for (slot, entry) in self.entries.iter_mut().take(self.max_visible).enumerate() {
+            let shown_at = *entry.shown_at.get_or_insert(now);
+            if entry.dismissed && entry.closing_at.is_none() {
+                let timeout = closing_at(entry, default_timeout);
+                entry.closing_at = Some(timeout.map_or(now, |at| at.min(now)));
+            }
+            let closing = closing_at(entry, default_timeout);
+            let slide = ((now - shown_at) / SLIDE_SECONDS).min(1.0);
+            let fade = closing.map_or(1.0, |at| (1.0 - (now - at) / FADE_SECONDS).clamp(0.0, 1.0));
+            match closing {
+                Some(at) if at > now => {
+                    next_deadline = Some(next_deadline.map_or(at, |d: f64| d.min(at)));
+                }
+                Some(_) => animating = true,
+                None => {}
+            }
+            animating |= slide < 1.0;
+
+            let toast = &entry.toast;
+            let icon_width = toast.icon.as_deref().map_or(0.0, |icon| {
+                font.calc_text_size(font_size, f32::MAX, 0.0, icon)[0] + icon_spacing
+            });
+            let text_x = ACCENT_WIDTH + padding[0] + icon_width;
+            let wrap = (width - text_x - padding[0]).max(1.0);
+            let title_height = toast.title.as_deref().map_or(0.0, |title| {
+                font.calc_text_size(font_size, f32::MAX, wrap, title)[1] + spacing
+            });
+            let text_height = font.calc_text_size(font_size, f32::MAX, wrap, &toast.text)[1];
+            let height = padding[1] * 2.0 + title_height + text_height;
+
+            // Slide in from the screen edge with an ease-out curve.
+            let eased = 1.0 - (1.0 - slide).powi(3);
+            let offset = (1.0 - eased) as f32 * (width + MARGIN);
+            let x = if right {
+                work_pos[0] + work_size[0] - MARGIN - width + offset
+            } else {
+                work_pos[0] + MARGIN - offset
+            };
+            let top = if bottom { y - height } else { y };
+            let min = [x, top];
+            let max = [x + width, top + height];
+            y = if bottom {
+                top - spacing
+            } else {
+                max[1] + spacing
+            };
+
+            // Fading toasts let clicks through to whatever is below.
+            let open = closing.is_none_or(|at| at > now);
+            let window_flags = if open {
+                flags
+            } else {
+                flags | WindowFlags::NO_INPUTS
+            };
+            let dismissed = ui
+                .window(format!("##toast{slot}"))
+                .position(min, Condition::Always)
+                .size([width, height], Condition::Always)
+                .flags(window_flags)
+                .build(|| {
+                    ui.run_with_bound_context(|| unsafe {
+                        sys::igBringWindowToDisplayFront(sys::igGetCurrentWindow());
+                    });
+                    let draw_list = ui.get_window_draw_list();
+                    let alpha = fade as f32;
+                    let with_alpha = |c: [f32; 4]| [c[0], c[1], c[2], c[3] * alpha];
+                    let accent = with_alpha(self.level_colors[toast.level.index()]);
+                    draw_list
+                        .add_rect(min, max, with_alpha(background))
+                        .filled(true)
+                        .rounding(rounding)
+                        .build();
+                    draw_list
+                        .add_rect(min, [min[0] + ACCENT_WIDTH, max[1]], accent)
+                        .filled(true)
+                        .build();
+                    draw_list
+                        .add_rect(min, max, with_alpha(border))
+                        .rounding(rounding)
+                        .build();
+
+                    let mut text_pos = [min[0] + text_x, min[1] + padding[1]];
+                    if let Some(icon) = toast.icon.as_deref() {
+                        draw_list.add_text(
+                            [min[0] + ACCENT_WIDTH + padding[0], text_pos[1]],
+                            accent,
+                            icon,
+                        );
+                    }
+                    let text_color = with_alpha(text_color);
+                    if let Some(title) = toast.title.as_deref() {
+                        draw_list.add_text_with_font(
+                            font, font_size, text_pos, text_color, title, wrap, None,
+                        );
+                        text_pos[1] += title_height;
+                    }
+                    draw_list.add_text_with_font(
+                        font,
+                        font_size,
+                        text_pos,
+                        text_color,
+                        &toast.text,
+                        wrap,
+                        None,
+                    );
+                    ui.invisible_button("##dismiss", [width, height])
+                })
+                .unwrap_or(false);
+            if open && dismissed {
+                clicked = Some(entry.id);
+            }
+        }
+
+        if let Some(id) = clicked {
+            self.dismiss(id);
+            animating = true;
+        }
+        if animating {
+            clock.request_frame();
+        } else if let Some(at) = next_deadline {
+            clock.request_wake_after(Duration::from_secs_f64(at - now));
+        }
+    }
+}
+
+/// Returns when `entry` starts fading out, if it is shown and either dismissed or not sticky.
+fn closing_at(entry: &Entry, default_timeout: Duration) -> Option<f64> {
+    let shown_at = entry.shown_at?;
+    if entry.closing_at.is_some() {
+        return entry.closing_at;
+    }
+    if entry.toast.sticky {
+        return None;
+    }
+    Some(shown_at + entry.toast.timeout.unwrap_or(default_timeout).as_secs_f64())
+}
@@ -11,7 +11,10 @@
 use crate::internal::plot_value_count_i32;
 use crate::sys;
 use crate::ui::Ui;
+use std::any::Any;
 use std::borrow::Cow;
+use std::ffi::{c_int, c_void};
+use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
 
 /// # Plot Widgets
 impl Ui {
@@ -44,6 +47,192 @@ impl Ui {
     ) -> PlotHistogram<'ui, 'p> {
         PlotHistogram::new(self, label, values)
     }
+
+    /// Creates a plot lines widget reading `count` values from `getter`.
+    ///
+    /// See [`Ui::plot_lines_getter_config`].
+    #[doc(alias = "PlotLines")]
+    pub fn plot_lines_getter(
+        &self,
+        label: impl AsRef<str>,
+        count: usize,
+        mut getter: impl FnMut(usize) -> f32,
+    ) {
+        self.plot_lines_getter_config(label.as_ref(), count, &mut getter)
+            .build()
+    }
+
+    /// Creates a plot histogram widget reading `count` values from `getter`.
+    ///
+    /// See [`Ui::plot_lines_getter_config`].
+    #[doc(alias = "PlotHistogram")]
+    pub fn plot_histogram_getter(
+        &self,
+        label: impl AsRef<str>,
+        count: usize,
+        mut getter: impl FnMut(usize) -> f32,
+    ) {
+        self.plot_histogram_getter_config(label.as_ref(), count, &mut getter)
+            .build()
+    }
+
+    /// Creates a plot lines builder reading `count` values from `getter`.
+    ///
+    /// The getter receives indices in `0..count`, so data does not need to be copied into a
+    /// contiguous slice. With [`PlotLines::values_offset`] Dear ImGui starts at that index and
+    /// wraps around, which plots a ring buffer oldest value first:
+    ///
+    /// ```no_run
+    /// # let mut ctx = dear_imgui_rs::Context::create();
+    /// # let ui = ctx.frame();
+    /// let frame_times = std::collections::VecDeque::from([16.6f32, 16.8, 33.1]);
+    /// ui.plot_lines_getter_config("Frame time", frame_times.len(), &mut |i| frame_times[i])
+    ///     .scale_min(0.0)
+    ///     .graph_size([0.0, 40.0])
+    ///     .build();
+    /// ```
+    ///
+    /// A panic in `getter` is resumed once Dear ImGui returns.
+    pub fn plot_lines_getter_config<'ui, 'p>(
+        &'ui self,
+        label: impl Into<Cow<'ui, str>>,
+        count: usize,
+        getter: &'p mut dyn FnMut(usize) -> f32,
+    ) -> PlotLines<'ui, 'p> {
+        PlotLines::with_values(self, label, PlotValues::Getter { count, getter })
+    }
+
+    /// Creates a plot histogram builder reading `count` values from `getter`.
+    ///
+    /// See [`Ui::plot_lines_getter_config`].
+    pub fn plot_histogram_getter_config<'ui, 'p>(
+        &'ui self,
+        label: impl Into<Cow<'ui, str>>,
+        count: usize,
+        getter: &'p mut dyn FnMut(usize) -> f32,
+    ) -> PlotHistogram<'ui, 'p> {
+        PlotHistogram::with_values(self, label, PlotValues::Getter { count, getter })
+    }
+}
+
+/// Values of a plot: a slice or a getter closure.
+enum PlotValues<'p> {
+    Slice(&'p [f32]),
+    Getter {
+        count: usize,
+        getter: &'p mut dyn FnMut(usize) -> f32,
+    },
+}
+
+impl std::fmt::Debug for PlotValues<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Slice(values) => f.debug_tuple("Slice").field(values).finish(),
+            Self::Getter { count, .. } => f
+                .debug_struct("Getter")
+                .field("count", count)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl PlotValues<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Self::Slice(values) => values.len(),
+            Self::Getter { count, .. } => *count,
+        }
+    }
+}
+
+struct GetterState<'a> {
+    getter: &'a mut dyn FnMut(usize) -> f32,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+unsafe extern "C" fn plot_values_getter(data: *mut c_void, idx: c_int) -> f32 {
+    let state = unsafe { &mut *(data as *mut GetterState<'_>) };
+    if state.panic.is_some() {
+        return 0.0;
+    }
+    let Ok(idx) = usize::try_from(idx) else {
+        return 0.0;
+    };
+    match catch_unwind(AssertUnwindSafe(|| (state.getter)(idx))) {
+        Ok(value) => value,
+        Err(payload) => {
+            state.panic = Some(payload);
+            0.0
+        }
+    }
+}
+
+/// Settings shared by the plot lines and histogram builders.
+struct PlotParams<'a> {
+    caller: &'static str,
+    histogram: bool,
+    values_offset: PlotValueOffset,
+    overlay_text: Option<&'a str>,
+    scale_min: f32,
+    scale_max: f32,
+    graph_size: [f32; 2],
+}
+
+fn build_plot(ui: &Ui, label: &str, values: PlotValues<'_>, params: PlotParams<'_>) {
+    let count = plot_value_count_i32(params.caller, values.len());
+    let values_offset = params.values_offset.into_i32(params.caller, count);
+    let (label_ptr, overlay_ptr) = ui.scratch_txt_with_opt(label, params.overlay_text);
+    let graph_size_vec: sys::ImVec2 = params.graph_size.into();
+    let (scale_min, scale_max) = (params.scale_min, params.scale_max);
+
+    match values {
+        PlotValues::Slice(values) => ui.run_with_bound_context(|| unsafe {
+            let plot = if params.histogram {
+                sys::igPlotHistogram_FloatPtr
+            } else {
+                sys::igPlotLines_FloatPtr
+            };
+            plot(
+                label_ptr,
+                values.as_ptr(),
+                count,
+                values_offset,
+                overlay_ptr,
+                scale_min,
+                scale_max,
+                graph_size_vec,
+                std::mem::size_of::<f32>() as i32,
+            );
+        }),
+        PlotValues::Getter { getter, .. } => {
+            let mut state = GetterState {
+                getter,
+                panic: None,
+            };
+            let data = &mut state as *mut GetterState<'_> as *mut c_void;
+            ui.run_with_bound_context(|| unsafe {
+                let plot = if params.histogram {
+                    sys::igPlotHistogram_FnFloatPtr
+                } else {
+                    sys::igPlotLines_FnFloatPtr
+                };
+                plot(
+                    label_ptr,
+                    Some(plot_values_getter),
+                    data,
+                    count,
+                    values_offset,
+                    overlay_ptr,
+                    scale_min,
+                    scale_max,
+                    graph_size_vec,
+                );
+            });
+            if let Some(payload) = state.panic {
+                resume_unwind(payload);
+            }
+        }
+    }
 }
 
 /// Builder for a plot lines widget
@@ -85,7 +274,7 @@ impl From<usize> for PlotValueOffset {
 pub struct PlotLines<'ui, 'p> {
     ui: &'ui Ui,
     label: Cow<'ui, str>,
-    values: &'p [f32],
+    values: PlotValues<'p>,
     values_offset: PlotValueOffset,
     overlay_text: Option<Cow<'ui, str>>,
     scale_min: f32,
@@ -96,6 +285,10 @@ pub struct PlotLines<'ui, 'p> {
 impl<'ui, 'p> PlotLines<'ui, 'p> {
     /// Creates a new plot lines builder
     pub fn new(ui: &'ui Ui, label: impl Into<Cow<'ui, str>>, values: &'p [f32]) -> Self {
+        Self::with_values(ui, label, PlotValues::Slice(values))
+    }
+
+    fn with_values(ui: &'ui Ui, label: impl Into<Cow<'ui, str>>, values: PlotValues<'p>) -> Self {
         Self {
             ui,
             label: label.into(),
@@ -140,26 +333,20 @@ impl<'ui, 'p> PlotLines<'ui, 'p> {
 
     /// Builds the plot lines widget
     pub fn build(self) {
-        let count = plot_value_count_i32("PlotLines::build()", self.values.len());
-        let values_offset = self.values_offset.into_i32("PlotLines::build()", count);
-        let (label_ptr, overlay_ptr) = self
-            .ui
-            .scratch_txt_with_opt(self.label.as_ref(), self.overlay_text.as_deref());
-        let graph_size_vec: sys::ImVec2 = self.graph_size.into();
-
-        self.ui.run_with_bound_context(|| unsafe {
-            sys::igPlotLines_FloatPtr(
-                label_ptr,
-                self.values.as_ptr(),
-                count,
-                values_offset,
-                overlay_ptr,
-                self.scale_min,
-                self.scale_max,
-                graph_size_vec,
-                std::mem::size_of::<f32>() as i32,
-            );
-        });
+        build_plot(
+            self.ui,
+            self.label.as_ref(),
+            self.values,
+            PlotParams {
+                caller: "PlotLines::build()",
+                histogram: false,
+                values_offset: self.values_offset,
+                overlay_text: self.overlay_text.as_deref(),
+                scale_min: self.scale_min,
+                scale_max: self.scale_max,
+                graph_size: self.graph_size,
+            },
+        );
     }
 }
 
@@ -169,7 +356,7 @@ impl<'ui, 'p> PlotLines<'ui, 'p> {
 pub struct PlotHistogram<'ui, 'p> {
     ui: &'ui Ui,
     label: Cow<'ui, str>,
-    values: &'p [f32],
+    values: PlotValues<'p>,
     values_offset: PlotValueOffset,
     overlay_text: Option<Cow<'ui, str>>,
     scale_min: f32,
@@ -180,6 +367,10 @@ pub struct PlotHistogram<'ui, 'p> {
 impl<'ui, 'p> PlotHistogram<'ui, 'p> {
     /// Creates a new plot histogram builder
     pub fn new(ui: &'ui Ui, label: impl Into<Cow<'ui, str>>, values: &'p [f32]) -> Self {
+        Self::with_values(ui, label, PlotValues::Slice(values))
+    }
+
+    fn with_values(ui: &'ui Ui, label: impl Into<Cow<'ui, str>>, values: PlotValues<'p>) -> Self {
         Self {
             ui,
             label: label.into(),
@@ -224,25 +415,19 @@ impl<'ui, 'p> PlotHistogram<'ui, 'p> {
 
     /// Builds the plot histogram widget
     pub fn build(self) {
-        let count = plot_value_count_i32("PlotHistogram::build()", self.values.len());
-        let values_offset = self.values_offset.into_i32("PlotHistogram::build()", count);
-        let (label_ptr, overlay_ptr) = self
-            .ui
-            .scratch_txt_with_opt(self.label.as_ref(), self.overlay_text.as_deref());
-        let graph_size_vec: sys::ImVec2 = self.graph_size.into();
-
-        self.ui.run_with_bound_context(|| unsafe {
-            sys::igPlotHistogram_FloatPtr(
-                label_ptr,
-                self.values.as_ptr(),
-                count,
-                values_offset,
-                overlay_ptr,
-                self.scale_min,
-                self.scale_max,
-                graph_size_vec,
-                std::mem::size_of::<f32>() as i32,
-            );
-        });
+        build_plot(
+            self.ui,
+            self.label.as_ref(),
+            self.values,
+            PlotParams {
+                caller: "PlotHistogram::build()",
+                histogram: true,
+                values_offset: self.values_offset,
+                overlay_text: self.overlay_text.as_deref(),
+                scale_min: self.scale_min,
+                scale_max: self.scale_max,
+                graph_size: self.graph_size,
+            },
+        );
     }
 }
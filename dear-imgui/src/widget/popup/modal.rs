@@ -5,12 +5,38 @@ use crate::window::{WindowFlags, validate_window_flags};
 use super::ModalPopupToken;
 
 /// Builder for a modal popup
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// let delete_clicked = ui.button("Delete");
+/// let confirmed = ui
+///     .modal("Delete file?")
+///     .open_when(delete_clicked)
+///     .always_centered()
+///     .flags(WindowFlags::ALWAYS_AUTO_RESIZE)
+///     .build(|modal| {
+///         ui.text("This cannot be undone.");
+///         let confirmed = ui.button("Delete");
+///         ui.same_line();
+///         if confirmed || ui.button("Cancel") {
+///             modal.close_current_popup();
+///         }
+///         confirmed
+///     })
+///     .unwrap_or(false);
+/// ```
 #[derive(Debug)]
 #[must_use]
 pub struct ModalPopup<'ui> {
     pub(super) name: &'ui str,
     pub(super) opened: Option<&'ui mut bool>,
     pub(super) flags: WindowFlags,
+    pub(super) open_when: bool,
+    pub(super) always_centered: bool,
     pub(super) ui: &'ui Ui,
 }
 
@@ -27,9 +53,32 @@ impl<'ui> ModalPopup<'ui> {
         self
     }
 
+    /// Opens the modal this frame when `open` is true, e.g. with the result of a button.
+    ///
+    /// This calls [`Ui::open_popup`] from the current ID stack right before beginning the modal,
+    /// so the open and begin calls cannot end up with different IDs.
+    #[doc(alias = "OpenPopup")]
+    pub fn open_when(mut self, open: bool) -> Self {
+        self.open_when |= open;
+        self
+    }
+
+    /// Centers the modal in the main viewport every frame it is shown.
+    pub fn always_centered(mut self) -> Self {
+        self.always_centered = true;
+        self
+    }
+
     /// Begins the modal popup
+    #[doc(alias = "BeginPopupModal")]
     pub fn begin(self) -> Option<ModalPopupToken<'ui>> {
         validate_window_flags("ModalPopup::begin()", self.flags);
+        if self.open_when {
+            self.ui.open_popup(self.name);
+        }
+        let center = self
+            .always_centered
+            .then(|| self.ui.main_viewport().center());
         let name_ptr = self.ui.scratch_txt(self.name);
         let opened_ptr = self
             .opened
@@ -37,6 +86,16 @@ impl<'ui> ModalPopup<'ui> {
             .unwrap_or(std::ptr::null_mut());
 
         let render = self.ui.run_with_bound_context(|| unsafe {
+            if let Some(center) = center {
+                sys::igSetNextWindowPos(
+                    sys::ImVec2 {
+                        x: center[0],
+                        y: center[1],
+                    },
+                    crate::Condition::Always as i32,
+                    sys::ImVec2 { x: 0.5, y: 0.5 },
+                );
+            }
             sys::igBeginPopupModal(name_ptr, opened_ptr, self.flags.bits())
         });
 
@@ -46,4 +105,14 @@ impl<'ui> ModalPopup<'ui> {
             None
         }
     }
+
+    /// Begins the modal popup and runs `f` with its token if the modal is open.
+    ///
+    /// Returns the closure's result, or `None` if the modal is not shown this frame.
+    pub fn build<F, R>(self, f: F) -> Option<R>
+    where
+        F: FnOnce(&ModalPopupToken<'ui>) -> R,
+    {
+        self.begin().map(|token| f(&token))
+    }
 }
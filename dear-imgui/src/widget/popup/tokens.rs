@@ -13,6 +13,14 @@ impl<'ui> PopupToken<'ui> {
         PopupToken { _ui: ui }
     }
 
+    /// Closes this popup, see [`Ui::close_current_popup`].
+    ///
+    /// The popup stays visible until the end of its contents for this frame.
+    #[doc(alias = "CloseCurrentPopup")]
+    pub fn close_current_popup(&self) {
+        self._ui.close_current_popup();
+    }
+
     /// Ends the popup
     pub fn end(self) {
         // The drop implementation will handle the actual ending
@@ -38,6 +46,14 @@ impl<'ui> ModalPopupToken<'ui> {
         ModalPopupToken { _ui: ui }
     }
 
+    /// Closes this modal popup, see [`Ui::close_current_popup`].
+    ///
+    /// The modal popup stays visible until the end of its contents for this frame.
+    #[doc(alias = "CloseCurrentPopup")]
+    pub fn close_current_popup(&self) {
+        self._ui.close_current_popup();
+    }
+
     /// Ends the modal popup
     pub fn end(self) {
         // The drop implementation will handle the actual ending
@@ -151,10 +151,20 @@ impl Ui {
             name,
             opened: None,
             flags: WindowFlags::empty(),
+            open_when: false,
+            always_centered: false,
             ui: self,
         }
     }
 
+    /// Creates a modal popup builder, see [`ModalPopup`].
+    ///
+    /// Unlike [`begin_modal_popup`](Self::begin_modal_popup), the builder can also open the
+    /// modal ([`ModalPopup::open_when`]) and center it ([`ModalPopup::always_centered`]).
+    pub fn modal<'a>(&'a self, name: &'a str) -> ModalPopup<'a> {
+        self.begin_modal_popup_config(name)
+    }
+
     /// Creates a modal popup and runs a closure to construct the contents.
     ///
     /// Returns the result of the closure if the popup is open.
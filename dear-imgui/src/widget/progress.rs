@@ -1,7 +1,10 @@
-//! Progress bars
+//! Progress bars and spinners
 //!
-//! Simple progress indicators with size and overlay text customization.
+//! Progress indicators with size and overlay text customization, an indeterminate mode for work
+//! of unknown length, and a small arc spinner drawn through the window draw list.
 //!
+use crate::draw::{DrawSegmentCount, PolylineFlags};
+use crate::style::StyleColor;
 use crate::sys;
 use crate::ui::Ui;
 use std::borrow::Cow;
@@ -10,6 +13,11 @@ fn assert_finite_f32(caller: &str, name: &str, value: f32) {
     assert!(value.is_finite(), "{caller} {name} must be finite");
 }
 
+fn assert_positive_f32(caller: &str, name: &str, value: f32) {
+    assert_finite_f32(caller, name, value);
+    assert!(value > 0.0, "{caller} {name} must be positive");
+}
+
 fn assert_finite_vec2(caller: &str, name: &str, value: [f32; 2]) {
     assert!(
         value[0].is_finite() && value[1].is_finite(),
@@ -36,6 +44,13 @@ impl Ui {
     ) -> ProgressBar<'ui> {
         ProgressBar::new(self, fraction).overlay_text(overlay)
     }
+
+    /// Creates a spinner ("loading" indicator) of the given radius.
+    ///
+    /// See [`Spinner`] for the available options.
+    pub fn spinner(&self, radius: f32) -> Spinner<'_> {
+        Spinner::new(self, radius)
+    }
 }
 
 /// Builder for a progress bar widget.
@@ -55,6 +70,7 @@ impl Ui {
 #[must_use]
 pub struct ProgressBar<'ui> {
     fraction: f32,
+    indeterminate: bool,
     size: [f32; 2],
     overlay_text: Option<Cow<'ui, str>>,
     ui: &'ui Ui,
@@ -71,6 +87,7 @@ impl<'ui> ProgressBar<'ui> {
     pub fn new(ui: &'ui Ui, fraction: f32) -> Self {
         ProgressBar {
             fraction,
+            indeterminate: false,
             size: [-1.0, 0.0], // -1.0 means auto-size to fill width
             overlay_text: None,
             ui,
@@ -99,11 +116,28 @@ impl<'ui> ProgressBar<'ui> {
         self
     }
 
+    /// Shows an animated bar for work of unknown length instead of the fraction.
+    ///
+    /// While the bar is visible it requests frames through [`Ui::animation_clock`], so
+    /// on-demand event loops keep animating it.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
     /// Builds the progress bar
     pub fn build(self) {
         assert_finite_f32("ProgressBar::build()", "fraction", self.fraction);
         assert_finite_vec2("ProgressBar::build()", "size", self.size);
 
+        // Dear ImGui animates the bar for negative fractions, using the value as the time base.
+        let clock = self.ui.animation_clock();
+        let fraction = if self.indeterminate {
+            -(clock.time() as f32)
+        } else {
+            self.fraction
+        };
+
         let size_vec: sys::ImVec2 = self.size.into();
         let overlay_ptr = self
             .overlay_text
@@ -111,7 +145,102 @@ impl<'ui> ProgressBar<'ui> {
             .map_or(std::ptr::null(), |txt| self.ui.scratch_txt(txt));
 
         self.ui.run_with_bound_context(|| unsafe {
-            sys::igProgressBar(self.fraction, size_vec, overlay_ptr);
+            sys::igProgressBar(fraction, size_vec, overlay_ptr);
         });
+        if self.indeterminate && self.ui.is_item_visible() {
+            clock.request_frame();
+        }
+    }
+}
+
+/// Builder for a spinner widget: a rotating arc showing that work is in progress.
+///
+/// The spinner occupies a `2 * radius` square at the cursor position. Its default color is the
+/// progress bar fill color (`StyleColor::PlotHistogram`). While visible it requests frames
+/// through [`Ui::animation_clock`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// ui.spinner(8.0).thickness(2.0).build();
+/// ui.same_line();
+/// ui.text("Loading...");
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Spinner<'ui> {
+    radius: f32,
+    thickness: Option<f32>,
+    color: Option<[f32; 4]>,
+    period: f32,
+    ui: &'ui Ui,
+}
+
+impl<'ui> Spinner<'ui> {
+    /// Creates a spinner of the given radius.
+    pub fn new(ui: &'ui Ui, radius: f32) -> Self {
+        Spinner {
+            radius,
+            thickness: None,
+            color: None,
+            period: 1.0,
+            ui,
+        }
+    }
+
+    /// Sets the arc thickness. Defaults to a quarter of the radius (at least 1 pixel).
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    /// Sets the arc color.
+    pub fn color(mut self, color: impl Into<[f32; 4]>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the time in seconds for one full turn. Defaults to `1.0`.
+    pub fn period(mut self, period: f32) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Builds the spinner
+    pub fn build(self) {
+        assert_positive_f32("Spinner::build()", "radius", self.radius);
+        assert_positive_f32("Spinner::build()", "period", self.period);
+        let thickness = self.thickness.unwrap_or((self.radius * 0.25).max(1.0));
+        assert_positive_f32("Spinner::build()", "thickness", thickness);
+
+        let pos = self.ui.cursor_screen_pos();
+        let diameter = self.radius * 2.0;
+        self.ui.dummy([diameter, diameter]);
+        if !self.ui.is_item_visible() {
+            return;
+        }
+
+        let clock = self.ui.animation_clock();
+        clock.request_frame();
+        let start = clock.phase(self.period) * std::f32::consts::TAU;
+        // Packed through the style so the spinner fades inside disabled scopes.
+        let color = match self.color {
+            Some(color) => self.ui.get_color_u32_from_rgba(color),
+            None => self.ui.get_color_u32(StyleColor::PlotHistogram),
+        };
+        let center = [pos[0] + self.radius, pos[1] + self.radius];
+        let draw_list = self.ui.get_window_draw_list();
+        draw_list.path_clear();
+        draw_list.path_arc_to(
+            center,
+            (self.radius - thickness * 0.5).max(0.0),
+            start,
+            start + std::f32::consts::PI * 1.5,
+            DrawSegmentCount::AUTO,
+        );
+        draw_list.path_stroke(color, PolylineFlags::NONE, thickness);
     }
 }
@@ -0,0 +1,293 @@
+//! Rich text
+//!
+//! A small markdown-lite renderer for help panels, tooltips and changelogs: headings, bold
+//! text, inline color spans, links and nested bullet lists, word-wrapped to the window.
+//!
+use crate::fonts::FontId;
+use crate::input::MouseCursor;
+use crate::style::StyleColor;
+use crate::sys;
+use crate::ui::Ui;
+use std::borrow::Cow;
+use std::fmt;
+
+mod parse;
+#[cfg(test)]
+mod tests;
+
+use parse::{Block, Span, hex_color, parse_blocks};
+
+// Font size multipliers of `#`, `##` and `###` headings.
+const HEADING_SCALES: [f32; 3] = [1.6, 1.3, 1.1];
+
+/// # Rich Text Widgets
+impl Ui {
+    /// Renders markdown-lite `text`, see [`RichText`] for the syntax.
+    ///
+    /// Returns the URL of the link clicked this frame, which is also opened with the shell.
+    pub fn text_rich(&self, text: impl AsRef<str>) -> Option<String> {
+        self.text_rich_config(text.as_ref()).build()
+    }
+
+    /// Creates a rich text builder for markdown-lite `text`.
+    pub fn text_rich_config<'ui>(&'ui self, text: impl Into<Cow<'ui, str>>) -> RichText<'ui> {
+        RichText::new(self, text)
+    }
+}
+
+/// Builder for word-wrapped, markdown-lite rich text.
+///
+/// Supported syntax, in the spirit of `imgui_markdown`:
+/// - `# Heading`, `## Heading`, `### Heading` (drawn with the bold font at a larger size)
+/// - `**bold**`, drawn with [`RichText::bold_font`] (or a faux bold without one)
+/// - `{color:text}` color spans, where `color` is `#RRGGBB`, `#RRGGBBAA` or a name registered
+///   with [`RichText::color`]
+/// - `[label](url)` links
+/// - `* item` or `- item` bullets, nested by two leading spaces per level
+/// - `---` separators; blank lines separate paragraphs, other lines are joined
+/// - `\` escapes the next character
+///
+/// Clicked links are passed to [`RichText::on_link`], or opened with the shell handler (see
+/// [`Context::set_open_in_shell_handler`](crate::Context::set_open_in_shell_handler)) without one.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// ui.text_rich_config(
+///     "## What's new\n\
+///      * **Faster** startup\n\
+///      * {warn:Breaking:} the config format changed, see [the guide](https://example.com)\n",
+/// )
+/// .color("warn", [1.0, 0.6, 0.0, 1.0])
+/// .on_link(|url| println!("open {url}"))
+/// .build();
+/// ```
+#[must_use]
+pub struct RichText<'ui> {
+    ui: &'ui Ui,
+    text: Cow<'ui, str>,
+    bold_font: Option<FontId>,
+    colors: Vec<(Cow<'ui, str>, [f32; 4])>,
+    wrap_width: Option<f32>,
+    on_link: Option<Box<dyn FnMut(&str) + 'ui>>,
+}
+
+impl fmt::Debug for RichText<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RichText")
+            .field("text", &self.text)
+            .field("bold_font", &self.bold_font)
+            .field("colors", &self.colors)
+            .field("wrap_width", &self.wrap_width)
+            .field("on_link", &self.on_link.is_some())
+            .finish()
+    }
+}
+
+impl<'ui> RichText<'ui> {
+    /// Creates a rich text builder for markdown-lite `text`.
+    pub fn new(ui: &'ui Ui, text: impl Into<Cow<'ui, str>>) -> Self {
+        Self {
+            ui,
+            text: text.into(),
+            bold_font: None,
+            colors: Vec::new(),
+            wrap_width: None,
+            on_link: None,
+        }
+    }
+
+    /// Sets the font used for bold text and headings.
+    pub fn bold_font(mut self, font: FontId) -> Self {
+        self.bold_font = Some(font);
+        self
+    }
+
+    /// Registers a named color for `{name:text}` spans.
+    pub fn color(mut self, name: impl Into<Cow<'ui, str>>, color: impl Into<[f32; 4]>) -> Self {
+        self.colors.push((name.into(), color.into()));
+        self
+    }
+
+    /// Wraps lines at `width` pixels from the start position instead of the content region edge.
+    pub fn wrap_width(mut self, width: f32) -> Self {
+        self.wrap_width = Some(width);
+        self
+    }
+
+    /// Calls `on_link` with the URL of a clicked link instead of opening it with the shell.
+    pub fn on_link(mut self, on_link: impl FnMut(&str) + 'ui) -> Self {
+        self.on_link = Some(Box::new(on_link));
+        self
+    }
+
+    /// Renders the text. Returns the URL of the link clicked this frame.
+    pub fn build(mut self) -> Option<String> {
+        let ui = self.ui;
+        if let Some(width) = self.wrap_width {
+            assert!(
+                width.is_finite() && width > 0.0,
+                "RichText::build() wrap_width must be positive"
+            );
+        }
+        let start = ui.cursor_screen_pos();
+        let right = match self.wrap_width {
+            Some(width) => start[0] + width,
+            None => start[0] + ui.content_region_avail()[0],
+        };
+
+        let text = std::mem::take(&mut self.text);
+        let mut clicked = None;
+        for block in parse_blocks(&text) {
+            match block {
+                Block::Paragraph(spans) => self.render_spans(&spans, right, &mut clicked),
+                Block::Heading { level, spans } => {
+                    let size = ui.current_font_size() * HEADING_SCALES[usize::from(level) - 1];
+                    self.with_font(true, size, || {
+                        self.render_spans(&spans, right, &mut clicked)
+                    });
+                    if level == 1 {
+                        ui.separator();
+                    }
+                }
+                Block::Bullet { depth, spans } => {
+                    let indent = ui.tree_node_to_label_spacing() * (depth + 1) as f32;
+                    ui.indent_by(indent);
+                    let pos = ui.cursor_screen_pos();
+                    let font_size = ui.current_font_size();
+                    let bullet_x = pos[0] - ui.tree_node_to_label_spacing() * 0.5;
+                    ui.get_window_draw_list()
+                        .add_circle(
+                            [bullet_x, pos[1] + font_size * 0.5],
+                            font_size * 0.2,
+                            ui.get_color_u32(StyleColor::Text),
+                        )
+                        .filled(true)
+                        .build();
+                    self.render_spans(&spans, right, &mut clicked);
+                    ui.unindent_by(indent);
+                }
+                Block::Separator => ui.separator(),
+                Block::Blank => ui.spacing(),
+            }
+        }
+
+        if let Some(url) = &clicked {
+            match self.on_link.as_mut() {
+                Some(on_link) => on_link(url),
+                None => open_in_shell(ui, url),
+            }
+        }
+        clicked
+    }
+
+    /// Lays out `spans` word by word, wrapping before words that would cross `right`.
+    fn render_spans(&self, spans: &[Span<'_>], right: f32, clicked: &mut Option<String>) {
+        let ui = self.ui;
+        let mut line_end: Option<f32> = None;
+        for span in spans {
+            let color = match (span.style.link, span.style.color) {
+                (Some(_), _) => Some(ui.style_color(StyleColor::TextLink)),
+                (None, Some(color)) => self.resolve_color(color),
+                (None, None) => None,
+            };
+            let _color = color.map(|color| ui.push_style_color(StyleColor::Text, color));
+            let faux_bold = span.style.bold && self.bold_font.is_none();
+            self.with_font(span.style.bold, 0.0, || {
+                for word in span.text.split_inclusive(' ') {
+                    let visible = word.trim_end();
+                    let width = text_width(ui, visible);
+                    match line_end {
+                        Some(x) if x + width <= right => ui.same_line_with_spacing(0.0, 0.0),
+                        // Wrapped lines do not start with the spaces between words.
+                        Some(_) if visible.is_empty() => continue,
+                        _ => {}
+                    }
+                    let pos = ui.cursor_screen_pos();
+                    text_unformatted(ui, word);
+                    line_end = Some(ui.item_rect_max()[0]);
+
+                    if faux_bold && !visible.is_empty() {
+                        ui.get_window_draw_list().add_text(
+                            [pos[0] + 1.0, pos[1]],
+                            ui.get_color_u32(StyleColor::Text),
+                            visible,
+                        );
+                    }
+                    if let Some(url) = span.style.link {
+                        let hovered = ui.is_item_hovered();
+                        if hovered {
+                            ui.set_mouse_cursor(Some(MouseCursor::Hand));
+                            let y = ui.item_rect_max()[1] - 1.0;
+                            ui.get_window_draw_list()
+                                .add_line(
+                                    [pos[0], y],
+                                    [pos[0] + width, y],
+                                    ui.get_color_u32(StyleColor::Text),
+                                )
+                                .build();
+                        }
+                        if hovered && ui.is_item_clicked() {
+                            *clicked = Some(url.to_owned());
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    fn resolve_color(&self, color: &str) -> Option<[f32; 4]> {
+        hex_color(color).or_else(|| {
+            self.colors
+                .iter()
+                .rev()
+                .find(|(name, _)| name.as_ref() == color)
+                .map(|&(_, color)| color)
+        })
+    }
+
+    /// Runs `f` with the bold font (when `bold` and one is set) at `size` (0 keeps the size).
+    fn with_font<R>(&self, bold: bool, size: f32, f: impl FnOnce() -> R) -> R {
+        let font = self.bold_font.filter(|_| bold);
+        if font.is_none() && size == 0.0 {
+            return f();
+        }
+        self.ui.run_with_bound_context(|| unsafe {
+            let font = font.map_or(std::ptr::null_mut(), |id| {
+                crate::fonts::validate_font_id_for_current_context(id, "RichText::build()")
+            });
+            sys::igPushFont(font, size);
+        });
+        let result = f();
+        self.ui
+            .run_with_bound_context(|| unsafe { sys::igPopFont() });
+        result
+    }
+}
+
+fn text_width(ui: &Ui, text: &str) -> f32 {
+    ui.run_with_bound_context(|| unsafe {
+        let begin = text.as_ptr() as *const std::os::raw::c_char;
+        sys::igCalcTextSize(begin, begin.add(text.len()), false, -1.0).x
+    })
+}
+
+fn text_unformatted(ui: &Ui, text: &str) {
+    ui.run_with_bound_context(|| unsafe {
+        let begin = text.as_ptr() as *const std::os::raw::c_char;
+        sys::igTextEx(begin, begin.add(text.len()), 0);
+    })
+}
+
+fn open_in_shell(ui: &Ui, url: &str) {
+    let url = ui.scratch_txt(url);
+    ui.run_with_bound_context(|| unsafe {
+        let platform_io = sys::igGetPlatformIO_Nil();
+        if let Some(open) = (*platform_io).Platform_OpenInShellFn {
+            open(sys::igGetCurrentContext(), url);
+        }
+    });
+}
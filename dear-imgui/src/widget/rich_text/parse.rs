@@ -0,0 +1,180 @@
+//! Markdown-lite parser producing blocks of styled spans.
+
+/// Inline style of a [`Span`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct SpanStyle<'a> {
+    pub(super) bold: bool,
+    /// Palette name or `#RRGGBB[AA]` hex color.
+    pub(super) color: Option<&'a str>,
+    /// URL of the link the span belongs to.
+    pub(super) link: Option<&'a str>,
+}
+
+/// A run of text with one style.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) struct Span<'a> {
+    pub(super) text: &'a str,
+    pub(super) style: SpanStyle<'a>,
+}
+
+/// One line-level element.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum Block<'a> {
+    /// Consecutive text lines joined into one word-wrapped paragraph.
+    Paragraph(Vec<Span<'a>>),
+    /// `#`, `##` or `###` heading.
+    Heading { level: u8, spans: Vec<Span<'a>> },
+    /// `*` or `-` bullet, nested by two leading spaces per level.
+    Bullet { depth: usize, spans: Vec<Span<'a>> },
+    /// `---` horizontal rule.
+    Separator,
+    /// Empty line between paragraphs.
+    Blank,
+}
+
+pub(super) fn parse_blocks(text: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        let block = if trimmed.is_empty() {
+            Block::Blank
+        } else if trimmed.len() >= 3 && trimmed.trim_end().bytes().all(|b| b == b'-') {
+            Block::Separator
+        } else if let Some((level, rest)) = heading(trimmed) {
+            Block::Heading {
+                level,
+                spans: parse_inline(rest),
+            }
+        } else if let Some(rest) = trimmed
+            .strip_prefix("* ")
+            .or_else(|| trimmed.strip_prefix("- "))
+        {
+            Block::Bullet {
+                depth: indent / 2,
+                spans: parse_inline(rest),
+            }
+        } else {
+            let spans = parse_inline(trimmed);
+            if let Some(Block::Paragraph(previous)) = blocks.last_mut() {
+                previous.push(Span {
+                    text: " ",
+                    style: SpanStyle::default(),
+                });
+                previous.extend(spans);
+                continue;
+            }
+            Block::Paragraph(spans)
+        };
+        blocks.push(block);
+    }
+    blocks
+}
+
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.bytes().take_while(|&b| b == b'#').count();
+    let rest = line[level..].strip_prefix(' ')?;
+    (1..=3).contains(&level).then_some((level as u8, rest))
+}
+
+pub(super) fn parse_inline(text: &str) -> Vec<Span<'_>> {
+    let mut spans = Vec::new();
+    parse_into(text, SpanStyle::default(), &mut spans);
+    spans
+}
+
+fn parse_into<'a>(text: &'a str, mut style: SpanStyle<'a>, out: &mut Vec<Span<'a>>) {
+    let flush = |from: usize, to: usize, style: SpanStyle<'a>, out: &mut Vec<Span<'a>>| {
+        if from < to {
+            out.push(Span {
+                text: &text[from..to],
+                style,
+            });
+        }
+    };
+
+    let bytes = text.as_bytes();
+    let (mut start, mut i) = (0, 0);
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if i + 1 < bytes.len() => {
+                flush(start, i, style, out);
+                let len = text[i + 1..].chars().next().map_or(1, char::len_utf8);
+                flush(i + 1, i + 1 + len, style, out);
+                i += 1 + len;
+                start = i;
+            }
+            b'*' if bytes.get(i + 1) == Some(&b'*') => {
+                flush(start, i, style, out);
+                style.bold = !style.bold;
+                i += 2;
+                start = i;
+            }
+            b'[' if style.link.is_none() => match link(&text[i..]) {
+                Some((label, url, len)) => {
+                    flush(start, i, style, out);
+                    parse_into(
+                        label,
+                        SpanStyle {
+                            link: Some(url),
+                            ..style
+                        },
+                        out,
+                    );
+                    i += len;
+                    start = i;
+                }
+                None => i += 1,
+            },
+            b'{' => match color_span(&text[i..]) {
+                Some((color, content, len)) => {
+                    flush(start, i, style, out);
+                    parse_into(
+                        content,
+                        SpanStyle {
+                            color: Some(color),
+                            ..style
+                        },
+                        out,
+                    );
+                    i += len;
+                    start = i;
+                }
+                None => i += 1,
+            },
+            _ => i += 1,
+        }
+    }
+    flush(start, bytes.len(), style, out);
+}
+
+/// Parses `[label](url)` at the start of `text`, returning the label, URL and length.
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = text.find("](")?;
+    let url_end = label_end + 2 + text[label_end + 2..].find(')')?;
+    let label = &text[1..label_end];
+    let url = &text[label_end + 2..url_end];
+    (!label.is_empty() && !url.is_empty()).then_some((label, url, url_end + 1))
+}
+
+/// Parses `{color:content}` at the start of `text`, returning the color, content and length.
+fn color_span(text: &str) -> Option<(&str, &str, usize)> {
+    let end = text.find('}')?;
+    let (color, content) = text[1..end].split_once(':')?;
+    let valid = !color.is_empty()
+        && color
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'#' || b == b'_' || b == b'-');
+    valid.then_some((color, content, end + 1))
+}
+
+/// Parses `#RRGGBB` or `#RRGGBBAA`.
+pub(super) fn hex_color(color: &str) -> Option<[f32; 4]> {
+    let hex = color.strip_prefix('#')?;
+    if !matches!(hex.len(), 6 | 8) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    let alpha = if hex.len() == 8 { channel(6)? } else { 255 };
+    Some([channel(0)?, channel(2)?, channel(4)?, alpha].map(|c| f32::from(c) / 255.0))
+}
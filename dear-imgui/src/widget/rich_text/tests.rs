@@ -0,0 +1,93 @@
+use super::parse::{Block, Span, SpanStyle, hex_color, parse_blocks, parse_inline};
+
+fn plain(text: &str) -> Span<'_> {
+    Span {
+        text,
+        style: SpanStyle::default(),
+    }
+}
+
+#[test]
+fn inline_styles_nest() {
+    let spans = parse_inline("a **b {red:c [d](u)}** e");
+    let bold = SpanStyle {
+        bold: true,
+        ..SpanStyle::default()
+    };
+    let red = SpanStyle {
+        color: Some("red"),
+        ..bold
+    };
+    assert_eq!(
+        spans,
+        [
+            plain("a "),
+            Span {
+                text: "b ",
+                style: bold
+            },
+            Span {
+                text: "c ",
+                style: red
+            },
+            Span {
+                text: "d",
+                style: SpanStyle {
+                    link: Some("u"),
+                    ..red
+                }
+            },
+            plain(" e"),
+        ]
+    );
+}
+
+#[test]
+fn malformed_markup_stays_literal() {
+    let text = |spans: Vec<Span<'_>>| spans.iter().map(|s| s.text).collect::<String>();
+    assert_eq!(
+        text(parse_inline("[no link] {no color} a{b c:d}")),
+        "[no link] {no color} a{b c:d}"
+    );
+    assert!(
+        parse_inline(r"\*\*not bold\*\*")
+            .iter()
+            .all(|span| !span.style.bold)
+    );
+    assert_eq!(text(parse_inline(r"\*\*x\*\* \\")), r"**x** \");
+}
+
+#[test]
+fn blocks_split_by_line_kind() {
+    let blocks = parse_blocks("# Title\nfirst\nsecond\n\n* one\n  - two\n---\n####not a heading");
+    assert_eq!(
+        blocks,
+        [
+            Block::Heading {
+                level: 1,
+                spans: vec![plain("Title")]
+            },
+            Block::Paragraph(vec![plain("first"), plain(" "), plain("second")]),
+            Block::Blank,
+            Block::Bullet {
+                depth: 0,
+                spans: vec![plain("one")]
+            },
+            Block::Bullet {
+                depth: 1,
+                spans: vec![plain("two")]
+            },
+            Block::Separator,
+            Block::Paragraph(vec![plain("####not a heading")]),
+        ]
+    );
+}
+
+#[test]
+fn hex_colors_parse_with_optional_alpha() {
+    assert_eq!(hex_color("#ff0000"), Some([1.0, 0.0, 0.0, 1.0]));
+    assert_eq!(hex_color("#00ff0000"), Some([0.0, 1.0, 0.0, 0.0]));
+    assert_eq!(hex_color("ff0000"), None);
+    assert_eq!(hex_color("#ff00"), None);
+    assert_eq!(hex_color("#gg0000"), None);
+}
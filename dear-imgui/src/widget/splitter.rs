@@ -0,0 +1,200 @@
+//! Splitters
+//!
+//! A draggable bar between two panes that resizes both, for layouts built from child windows
+//! without docking. Built on an invisible button and the window draw list.
+//!
+use crate::input::MouseCursor;
+use crate::style::StyleColor;
+use crate::ui::Ui;
+use std::borrow::Cow;
+
+const DEFAULT_THICKNESS: f32 = 4.0;
+
+fn assert_size(caller: &str, name: &str, value: f32) {
+    assert!(
+        value.is_finite() && value >= 0.0,
+        "{caller} {name} must be finite and non-negative"
+    );
+}
+
+/// Direction in which a [`Splitter`] resizes its panes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SplitterAxis {
+    /// Panes side by side, separated by a vertical bar dragged left and right.
+    X,
+    /// Panes stacked, separated by a horizontal bar dragged up and down.
+    Y,
+}
+
+impl SplitterAxis {
+    fn index(self) -> usize {
+        match self {
+            SplitterAxis::X => 0,
+            SplitterAxis::Y => 1,
+        }
+    }
+}
+
+/// # Splitter Widgets
+impl Ui {
+    /// Creates a splitter between two panes of `size_a` and `size_b` pixels along `axis`.
+    ///
+    /// Dragging the bar moves space from one pane to the other, never shrinking them below
+    /// `min_a` and `min_b`. Returns true if the sizes changed. See [`Splitter`].
+    pub fn splitter(
+        &self,
+        id: impl AsRef<str>,
+        axis: SplitterAxis,
+        size_a: &mut f32,
+        size_b: &mut f32,
+        min_a: f32,
+        min_b: f32,
+    ) -> bool {
+        self.splitter_config(id.as_ref(), axis)
+            .min_sizes(min_a, min_b)
+            .build(size_a, size_b)
+    }
+
+    /// Creates a splitter builder.
+    pub fn splitter_config<'ui>(
+        &'ui self,
+        id: impl Into<Cow<'ui, str>>,
+        axis: SplitterAxis,
+    ) -> Splitter<'ui> {
+        Splitter::new(self, id, axis)
+    }
+}
+
+/// Builder for a splitter bar between two panes.
+///
+/// The bar is placed at the cursor, offset by the first pane's size, and does not move the
+/// cursor: submit the splitter first, then the two panes with the updated sizes. Hovering or
+/// dragging the bar shows a resize mouse cursor.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// let (mut left, mut right) = (200.0f32, 300.0f32);
+/// let height = 400.0;
+/// ui.splitter_config("##split", SplitterAxis::X)
+///     .thickness(6.0)
+///     .length(height)
+///     .min_sizes(50.0, 50.0)
+///     .build(&mut left, &mut right);
+/// ui.child_window("left").size([left, height]).build(ui, || ui.text("Left"));
+/// ui.same_line_with_spacing(0.0, 6.0);
+/// ui.child_window("right").size([right, height]).build(ui, || ui.text("Right"));
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Splitter<'ui> {
+    ui: &'ui Ui,
+    id: Cow<'ui, str>,
+    axis: SplitterAxis,
+    thickness: f32,
+    length: Option<f32>,
+    min_a: f32,
+    min_b: f32,
+}
+
+impl<'ui> Splitter<'ui> {
+    /// Creates a splitter builder resizing along `axis`.
+    pub fn new(ui: &'ui Ui, id: impl Into<Cow<'ui, str>>, axis: SplitterAxis) -> Self {
+        Self {
+            ui,
+            id: id.into(),
+            axis,
+            thickness: DEFAULT_THICKNESS,
+            length: None,
+            min_a: 0.0,
+            min_b: 0.0,
+        }
+    }
+
+    /// Sets the bar thickness in pixels (default 4).
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the bar length in pixels; defaults to the available content region.
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets the minimum sizes of the two panes.
+    pub fn min_sizes(mut self, min_a: f32, min_b: f32) -> Self {
+        self.min_a = min_a;
+        self.min_b = min_b;
+        self
+    }
+
+    /// Builds the splitter resizing `size_a` and `size_b`. Returns true if they changed.
+    pub fn build(self, size_a: &mut f32, size_b: &mut f32) -> bool {
+        let ui = self.ui;
+        assert!(
+            self.thickness.is_finite() && self.thickness > 0.0,
+            "Splitter::build() thickness must be positive"
+        );
+        assert_size("Splitter::build()", "min_a", self.min_a);
+        assert_size("Splitter::build()", "min_b", self.min_b);
+        assert_size("Splitter::build()", "size_a", *size_a);
+        assert_size("Splitter::build()", "size_b", *size_b);
+
+        let axis = self.axis.index();
+        let cross = 1 - axis;
+        let length = self
+            .length
+            .unwrap_or_else(|| ui.content_region_avail()[cross])
+            .max(1.0);
+
+        let start = ui.cursor_screen_pos();
+        let mut min = start;
+        min[axis] += *size_a;
+        let mut size = [0.0; 2];
+        size[axis] = self.thickness;
+        size[cross] = length;
+
+        ui.set_cursor_screen_pos(min);
+        ui.invisible_button(self.id.as_ref(), size);
+        ui.set_cursor_screen_pos(start);
+        let active = ui.is_item_active();
+        let hovered = ui.is_item_hovered();
+
+        let mut changed = false;
+        if active {
+            // Clamp so both panes keep their minimum; a pane already below it does not shrink.
+            let delta = ui.io().mouse_delta()[axis]
+                .max((self.min_a - *size_a).min(0.0))
+                .min((*size_b - self.min_b).max(0.0));
+            if delta != 0.0 {
+                *size_a += delta;
+                *size_b -= delta;
+                min[axis] += delta;
+                changed = true;
+            }
+        }
+        if active || hovered {
+            ui.set_mouse_cursor(Some(match self.axis {
+                SplitterAxis::X => MouseCursor::ResizeEW,
+                SplitterAxis::Y => MouseCursor::ResizeNS,
+            }));
+        }
+
+        let color = match (active, hovered) {
+            (true, _) => StyleColor::SeparatorActive,
+            (false, true) => StyleColor::SeparatorHovered,
+            (false, false) => StyleColor::Separator,
+        };
+        let max = [min[0] + size[0], min[1] + size[1]];
+        ui.get_window_draw_list()
+            .add_rect(min, max, ui.get_color_u32(color))
+            .filled(true)
+            .build();
+        changed
+    }
+}
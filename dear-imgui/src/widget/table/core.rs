@@ -437,8 +437,12 @@ impl Ui {
     }
 
     /// Set a table cell background color using RGBA color (0..=1 floats).
-    pub fn table_set_cell_bg_color(&self, rgba: [f32; 4], column: impl Into<TableColumnRef>) {
-        let col = crate::colors::Color::from_array(rgba).to_imgui_u32();
+    pub fn table_set_cell_bg_color(
+        &self,
+        rgba: impl Into<[f32; 4]>,
+        column: impl Into<TableColumnRef>,
+    ) {
+        let col = crate::colors::Color::from_array(rgba.into()).to_imgui_u32();
         self.table_set_cell_bg_color_u32(col, column);
     }
 
@@ -452,8 +456,8 @@ impl Ui {
     }
 
     /// Set the first row background color using RGBA color (0..=1 floats).
-    pub fn table_set_row_bg0_color(&self, rgba: [f32; 4]) {
-        let col = crate::colors::Color::from_array(rgba).to_imgui_u32();
+    pub fn table_set_row_bg0_color(&self, rgba: impl Into<[f32; 4]>) {
+        let col = crate::colors::Color::from_array(rgba.into()).to_imgui_u32();
         self.table_set_row_bg0_color_u32(col);
     }
 
@@ -467,8 +471,8 @@ impl Ui {
     }
 
     /// Set the second row background color using RGBA color (0..=1 floats).
-    pub fn table_set_row_bg1_color(&self, rgba: [f32; 4]) {
-        let col = crate::colors::Color::from_array(rgba).to_imgui_u32();
+    pub fn table_set_row_bg1_color(&self, rgba: impl Into<[f32; 4]>) {
+        let col = crate::colors::Color::from_array(rgba.into()).to_imgui_u32();
         self.table_set_row_bg1_color_u32(col);
     }
 
@@ -523,4 +527,43 @@ impl Ui {
             }
         })
     }
+
+    /// Sorts `rows` by the current table's sort specs when they changed.
+    ///
+    /// Call it after setting up the columns and before drawing the rows. Returns true if `rows`
+    /// were sorted; see [`TableSortSpecs::sort_rows`]. Tables without
+    /// [`TableFlags::SORTABLE`](crate::TableFlags::SORTABLE) never sort.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let mut files = vec![("b.txt", 20u64), ("a.txt", 10u64)];
+    /// if let Some(_table) = ui.begin_table_with_flags("files", 2, TableFlags::SORTABLE) {
+    ///     ui.table_setup_column("Name", TableColumnFlags::DEFAULT_SORT, None, None);
+    ///     ui.table_setup_column("Size", TableColumnFlags::NONE, None, None);
+    ///     ui.table_headers_row();
+    ///     ui.table_sort_rows(&mut files, |column, a, b| match column.get() {
+    ///         0 => a.0.cmp(&b.0),
+    ///         _ => a.1.cmp(&b.1),
+    ///     });
+    ///     for (name, size) in &files {
+    ///         ui.table_next_row();
+    ///         ui.table_next_column();
+    ///         ui.text(name);
+    ///         ui.table_next_column();
+    ///         ui.text(size.to_string());
+    ///     }
+    /// }
+    /// ```
+    pub fn table_sort_rows<T>(
+        &self,
+        rows: &mut [T],
+        compare: impl FnMut(TableColumnIndex, &T, &T) -> std::cmp::Ordering,
+    ) -> bool {
+        self.table_get_sort_specs()
+            .is_some_and(|mut specs| specs.sort_rows(rows, compare))
+    }
 }
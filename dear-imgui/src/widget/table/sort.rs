@@ -3,6 +3,7 @@ use crate::widget::table::{TableColumnIndex, optional_user_id_from_raw};
 use crate::{Id, sys};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
 /// Sorting direction for table columns.
 #[repr(u8)]
@@ -76,6 +77,56 @@ impl<'a> TableSortSpecs<'a> {
             index: 0,
         }
     }
+
+    /// Compares two rows using every column spec in sort order.
+    ///
+    /// `compare` returns the ascending order of `a` and `b` for one column; it is reversed for
+    /// descending columns, and later columns break ties of earlier ones.
+    pub fn compare_rows<T>(
+        &self,
+        a: &T,
+        b: &T,
+        mut compare: impl FnMut(TableColumnIndex, &T, &T) -> Ordering,
+    ) -> Ordering {
+        for spec in self.iter() {
+            let ordering = match spec.sort_direction {
+                SortDirection::None => continue,
+                SortDirection::Ascending => compare(spec.column_index, a, b),
+                SortDirection::Descending => compare(spec.column_index, a, b).reverse(),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+
+    /// Sorts `rows` if the specs are dirty, then clears the dirty flag.
+    ///
+    /// Returns true if `rows` were sorted. The sort is stable and uses [`Self::compare_rows`],
+    /// so multi-column sorts (`TableFlags::SORT_MULTI`) are honored. Rows added or changed
+    /// since the last sort are not detected; call [`Self::sort_rows_now`] for those.
+    pub fn sort_rows<T>(
+        &mut self,
+        rows: &mut [T],
+        compare: impl FnMut(TableColumnIndex, &T, &T) -> Ordering,
+    ) -> bool {
+        if !self.is_dirty() {
+            return false;
+        }
+        self.sort_rows_now(rows, compare);
+        true
+    }
+
+    /// Sorts `rows` with the current specs regardless of the dirty flag, then clears it.
+    pub fn sort_rows_now<T>(
+        &mut self,
+        rows: &mut [T],
+        mut compare: impl FnMut(TableColumnIndex, &T, &T) -> Ordering,
+    ) {
+        rows.sort_by(|a, b| self.compare_rows(a, b, &mut compare));
+        self.clear_dirty();
+    }
 }
 
 /// Iterator over [`TableColumnSortSpec`].
@@ -325,3 +325,50 @@ fn table_angled_headers_validate_indices_before_ffi() {
         );
     });
 }
+
+#[test]
+fn table_sort_rows_sorts_only_when_specs_change() {
+    let mut ctx = setup_context();
+    let mut rows = vec![("b", 1), ("a", 1), ("b", 2), ("a", 3)];
+    let compare = |column: TableColumnIndex, a: &(&str, i32), b: &(&str, i32)| match column.get() {
+        0 => a.0.cmp(b.0),
+        _ => a.1.cmp(&b.1),
+    };
+
+    let frame = |ctx: &mut crate::Context, f: &mut dyn FnMut(&crate::Ui, &TableToken<'_>)| {
+        {
+            let ui = ctx.frame();
+            let _ = ui.window("table_sort_rows").build(|| {
+                let table = ui
+                    .begin_table_with_flags(
+                        "table",
+                        2,
+                        TableFlags::SORTABLE | TableFlags::SORT_MULTI,
+                    )
+                    .unwrap();
+                ui.table_setup_column("name", TableColumnFlags::DEFAULT_SORT, None, None);
+                ui.table_setup_column("size", TableColumnFlags::NONE, None, None);
+                ui.table_headers_row();
+                f(ui, &table);
+            });
+        }
+        ctx.render();
+    };
+
+    frame(&mut ctx, &mut |ui, table| {
+        assert!(table.sort_rows(&mut rows, compare));
+        assert!(!table.sort_rows(&mut rows, compare));
+        // Shift-click equivalent: append a descending sort on the second column.
+        ui.table_set_column_sort_direction(1, SortDirection::Descending, true);
+    });
+    assert_eq!(rows, [("a", 1), ("a", 3), ("b", 1), ("b", 2)]);
+
+    frame(&mut ctx, &mut |ui, _| {
+        assert!(ui.table_sort_rows(&mut rows, compare));
+    });
+    assert_eq!(rows, [("a", 3), ("a", 1), ("b", 2), ("b", 1)]);
+
+    frame(&mut ctx, &mut |ui, _| {
+        assert!(!ui.table_sort_rows(&mut rows, compare));
+    });
+}
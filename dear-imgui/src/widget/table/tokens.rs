@@ -13,6 +13,15 @@ impl<'ui> TableToken<'ui> {
         TableToken { _ui: ui }
     }
 
+    /// Sorts `rows` by this table's sort specs when they changed, see [`Ui::table_sort_rows`].
+    pub fn sort_rows<T>(
+        &self,
+        rows: &mut [T],
+        compare: impl FnMut(crate::TableColumnIndex, &T, &T) -> std::cmp::Ordering,
+    ) -> bool {
+        self._ui.table_sort_rows(rows, compare)
+    }
+
     /// Ends the table
     pub fn end(self) {
         // The drop implementation will handle the actual ending
@@ -33,7 +33,7 @@ impl Ui {
     /// ui.text_colored([0.0, 1.0, 0.0, 1.0], "Green text");
     /// ```
     #[doc(alias = "TextColored")]
-    pub fn text_colored(&self, color: [f32; 4], text: impl AsRef<str>) {
+    pub fn text_colored(&self, color: impl Into<[f32; 4]>, text: impl AsRef<str>) {
         let s = text.as_ref();
 
         // Temporarily set the text color
@@ -107,6 +107,13 @@ impl Ui {
         })
     }
 
+    /// Display a label and formatted text on the same line, without allocating a `String`
+    /// (see [`Ui::with_fmt`]).
+    #[doc(alias = "LabelText")]
+    pub fn label_text_fmt(&self, label: impl AsRef<str>, args: std::fmt::Arguments<'_>) {
+        self.with_fmt(args, |text| self.label_text(label, text));
+    }
+
     /// Render a hyperlink-style text button. Returns true when clicked.
     #[doc(alias = "TextLink")]
     pub fn text_link(&self, label: impl AsRef<str>) -> bool {
@@ -115,6 +122,10 @@ impl Ui {
 
     /// Render a hyperlink-style text button, and open the given URL when clicked.
     /// Returns true when clicked.
+    ///
+    /// The URL is opened with the handler set by
+    /// [`Context::set_open_in_shell_handler`](crate::Context::set_open_in_shell_handler), or
+    /// Dear ImGui's built-in shell function when none is set.
     #[doc(alias = "TextLinkOpenURL")]
     pub fn text_link_open_url(&self, label: impl AsRef<str>, url: impl AsRef<str>) -> bool {
         let (label_ptr, url_ptr) = self.scratch_txt_two(label, url);
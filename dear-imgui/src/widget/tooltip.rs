@@ -107,6 +107,63 @@ impl Ui {
             }
         });
     }
+
+    /// Shows a text tooltip while the last item is hovered, after the standard tooltip delay.
+    ///
+    /// Same as [`Self::set_item_tooltip`]. The delay and hover behavior come from
+    /// [`Style::hover_flags_for_tooltip_mouse`](crate::Style::hover_flags_for_tooltip_mouse)
+    /// and [`Style::hover_delay_normal`](crate::Style::hover_delay_normal).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// ui.button("Save");
+    /// ui.item_tooltip("Writes the project to disk");
+    /// ```
+    #[doc(alias = "SetItemTooltip")]
+    pub fn item_tooltip(&self, text: impl AsRef<str>) {
+        self.set_item_tooltip(text);
+    }
+
+    /// Builds a tooltip with any content while the last item is hovered, after the standard
+    /// tooltip delay. Returns true while the tooltip is shown.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// ui.button("Delete");
+    /// ui.item_tooltip_build(|| {
+    ///     ui.text("Removes the selected files.");
+    ///     ui.text_disabled("This cannot be undone.");
+    /// });
+    /// ```
+    #[doc(alias = "BeginItemTooltip", alias = "EndTooltip")]
+    pub fn item_tooltip_build<F: FnOnce()>(&self, f: F) -> bool {
+        if let Some(_token) = self.begin_item_tooltip() {
+            f();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Begins a tooltip for the last item if it has been hovered for the standard tooltip delay.
+    ///
+    /// Returns a `TooltipToken` that must be ended by calling `.end()` or by dropping.
+    #[doc(alias = "BeginItemTooltip")]
+    pub fn begin_item_tooltip(&self) -> Option<TooltipToken<'_>> {
+        if self.run_with_bound_context(|| unsafe { sys::igBeginItemTooltip() }) {
+            Some(TooltipToken::new(self))
+        } else {
+            None
+        }
+    }
 }
 
 /// # Item/Widget Utilities and Query Functions
@@ -13,8 +13,10 @@ mod entry;
 mod flags;
 mod id;
 mod token;
+mod view;
 
 pub use builder::TreeNode;
 pub use flags::TreeNodeFlags;
 pub use id::TreeNodeId;
 pub use token::TreeNodeToken;
+pub use view::{TreeView, TreeViewRow, TreeViewSource};
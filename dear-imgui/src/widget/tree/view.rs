@@ -0,0 +1,350 @@
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use super::flags::TreeNodeFlags;
+use crate::list_clipper::ListClipper;
+use crate::widget::multi_select::{KeySetSelection, MultiSelectIndexStorage, MultiSelectOptions};
+use crate::{Condition, Ui, sys};
+
+/// Hierarchical data shown by a [`TreeView`].
+///
+/// Children are requested lazily: [`TreeViewSource::children`] is called the first time a node
+/// is expanded and cached by the view until [`TreeView::invalidate_node`] or
+/// [`TreeView::invalidate`].
+pub trait TreeViewSource {
+    /// Stable identifier of a node.
+    type Key: Copy + Eq + Hash;
+
+    /// Appends the top-level nodes to `out`.
+    fn roots(&mut self, out: &mut Vec<Self::Key>);
+
+    /// Appends the children of `parent` to `out`.
+    fn children(&mut self, parent: Self::Key, out: &mut Vec<Self::Key>);
+
+    /// Returns whether `key` has (or may have) children; nodes without get no expand arrow.
+    fn has_children(&self, key: Self::Key) -> bool;
+
+    /// Returns the label of `key`.
+    fn label(&self, key: Self::Key) -> Cow<'_, str>;
+}
+
+/// A row submitted by [`TreeView::draw`], passed to its row callback.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TreeViewRow<K> {
+    /// Node of the row.
+    pub key: K,
+    /// Nesting depth, 0 for roots.
+    pub depth: usize,
+    /// Index of the row among the visible (expanded) rows.
+    pub index: usize,
+    /// Whether the node is expanded.
+    pub open: bool,
+    /// Whether the node is selected.
+    pub selected: bool,
+}
+
+/// A virtualized tree for hierarchies with many nodes, such as scene graphs and file trees.
+///
+/// The view flattens the expanded part of the tree into rows and submits only the visible ones
+/// through a [`ListClipper`], so the cost per frame depends on the window height rather than the
+/// tree size. Expanded state and selection are kept in the view, keyed by
+/// [`TreeViewSource::Key`]. Selection uses Dear ImGui's multi-select (ctrl/shift click, box
+/// select with the right [`MultiSelectOptions`]) unless disabled with
+/// [`TreeView::set_multi_select`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # use std::borrow::Cow;
+/// struct Numbers;
+///
+/// impl TreeViewSource for Numbers {
+///     type Key = u32;
+///     fn roots(&mut self, out: &mut Vec<u32>) {
+///         out.extend(0..100_000);
+///     }
+///     fn children(&mut self, parent: u32, out: &mut Vec<u32>) {
+///         out.extend((0..10).map(|i| parent * 10 + i + 100_000));
+///     }
+///     fn has_children(&self, key: u32) -> bool {
+///         key < 100_000
+///     }
+///     fn label(&self, key: u32) -> Cow<'_, str> {
+///         format!("Node {key}").into()
+///     }
+/// }
+///
+/// let mut tree = TreeView::new();
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// tree.draw(ui, &mut Numbers, |ui, row| {
+///     if row.selected && ui.is_item_hovered() {
+///         ui.set_tooltip(format!("{} selected", row.key));
+///     }
+/// });
+/// ```
+#[derive(Debug)]
+pub struct TreeView<K> {
+    open: HashSet<K>,
+    selected: HashSet<K>,
+    roots: Option<Vec<K>>,
+    children: HashMap<K, Vec<K>>,
+    keys: Vec<K>,
+    depths: Vec<usize>,
+    row_of: HashMap<sys::ImGuiSelectionUserData, usize>,
+    dirty: bool,
+    multi_select: Option<MultiSelectOptions>,
+}
+
+impl<K: Copy + Eq + Hash> Default for TreeView<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Copy + Eq + Hash> TreeView<K> {
+    /// Creates a fully collapsed view with multi-select enabled.
+    pub fn new() -> Self {
+        Self {
+            open: HashSet::new(),
+            selected: HashSet::new(),
+            roots: None,
+            children: HashMap::new(),
+            keys: Vec::new(),
+            depths: Vec::new(),
+            row_of: HashMap::new(),
+            dirty: true,
+            multi_select: Some(MultiSelectOptions::new()),
+        }
+    }
+
+    /// Sets the multi-select options, or `None` for single selection by click.
+    pub fn set_multi_select(&mut self, options: Option<MultiSelectOptions>) {
+        self.multi_select = options;
+    }
+
+    /// Returns whether `key` is expanded.
+    pub fn is_open(&self, key: K) -> bool {
+        self.open.contains(&key)
+    }
+
+    /// Expands or collapses `key`.
+    pub fn set_open(&mut self, key: K, open: bool) {
+        let changed = if open {
+            self.open.insert(key)
+        } else {
+            self.open.remove(&key)
+        };
+        self.dirty |= changed;
+    }
+
+    /// Collapses all nodes.
+    pub fn collapse_all(&mut self) {
+        self.open.clear();
+        self.dirty = true;
+    }
+
+    /// Returns the selected nodes.
+    pub fn selection(&self) -> &HashSet<K> {
+        &self.selected
+    }
+
+    /// Returns the selected nodes for editing.
+    pub fn selection_mut(&mut self) -> &mut HashSet<K> {
+        &mut self.selected
+    }
+
+    /// Forgets the cached children of `key`, e.g. after the source changed them.
+    pub fn invalidate_node(&mut self, key: K) {
+        self.children.remove(&key);
+        self.dirty = true;
+    }
+
+    /// Forgets all cached roots and children; they are requested again on the next draw.
+    pub fn invalidate(&mut self) {
+        self.roots = None;
+        self.children.clear();
+        self.dirty = true;
+    }
+
+    /// Returns the expanded rows of the last draw, in display order.
+    pub fn rows(&self) -> &[K] {
+        &self.keys
+    }
+
+    fn rebuild<S: TreeViewSource<Key = K>>(&mut self, source: &mut S) {
+        let roots = self.roots.get_or_insert_with(|| {
+            let mut roots = Vec::new();
+            source.roots(&mut roots);
+            roots
+        });
+        self.keys.clear();
+        self.depths.clear();
+        self.row_of.clear();
+        let mut stack: Vec<(K, usize)> = roots.iter().rev().map(|&key| (key, 0)).collect();
+        while let Some((key, depth)) = stack.pop() {
+            self.row_of.insert(selection_data(&key), self.keys.len());
+            self.keys.push(key);
+            self.depths.push(depth);
+            if !self.open.contains(&key) || !source.has_children(key) {
+                continue;
+            }
+            let children = self.children.entry(key).or_insert_with(|| {
+                let mut children = Vec::new();
+                source.children(key, &mut children);
+                children
+            });
+            stack.extend(children.iter().rev().map(|&child| (child, depth + 1)));
+        }
+        self.dirty = false;
+    }
+
+    /// Draws the visible rows. `on_row` is called after each row's tree node item, e.g. for
+    /// context menus, drag and drop or extra columns.
+    pub fn draw<S, F>(&mut self, ui: &Ui, source: &mut S, mut on_row: F)
+    where
+        S: TreeViewSource<Key = K>,
+        F: FnMut(&Ui, &TreeViewRow<K>),
+    {
+        if self.dirty || self.roots.is_none() {
+            self.rebuild(source);
+        }
+
+        let indent = unsafe { ui.style() }.indent_spacing();
+        let count = self.keys.len();
+        let mut storage = KeySetSelection::new(&self.keys, &mut self.selected);
+        let scope = self.multi_select.map(|options| {
+            let selected = i32::try_from(storage.selected_count_hint().unwrap_or(0)).ok();
+            ui.begin_multi_select_raw(options, selected, count)
+        });
+        let mut clipper = ListClipper::new(count).begin(ui);
+        if let Some(scope) = scope.as_ref() {
+            apply_requests(scope.begin_io(), &self.row_of, &mut storage);
+            // Keep the range selection anchor submitted while scrolled out of view. Items carry
+            // their key's hash rather than their row, so the anchor follows its node when rows
+            // above it are expanded or collapsed.
+            if let Some(&anchor) = self.row_of.get(&scope.begin_io().RangeSrcItem) {
+                clipper.include_item_by_index(anchor);
+            }
+        }
+
+        let mut toggled = Vec::new();
+        let mut clicked = None;
+        while clipper.step() {
+            for index in clipper.display_range() {
+                let key = self.keys[index];
+                let depth = self.depths[index];
+                let open = self.open.contains(&key);
+                let selected = storage.is_selected(index);
+                let mut flags = TreeNodeFlags::OPEN_ON_ARROW
+                    | TreeNodeFlags::OPEN_ON_DOUBLE_CLICK
+                    | TreeNodeFlags::SPAN_AVAIL_WIDTH
+                    | TreeNodeFlags::NO_TREE_PUSH_ON_OPEN;
+                if !source.has_children(key) {
+                    flags |= TreeNodeFlags::LEAF;
+                }
+                if selected {
+                    flags |= TreeNodeFlags::SELECTED;
+                }
+
+                let width = indent * depth as f32;
+                if depth > 0 {
+                    ui.indent_by(width);
+                }
+                let label = source.label(key);
+                let label_ptr = ui.scratch_txt(label.as_ref());
+                let node_open = ui.run_with_bound_context(|| unsafe {
+                    if scope.is_some() {
+                        sys::igSetNextItemSelectionUserData(selection_data(&key));
+                    }
+                    sys::igSetNextItemOpen(open, Condition::Always as i32);
+                    sys::igPushID_Ptr(key_ptr(&key));
+                    let node_open = sys::igTreeNodeEx_Str(label_ptr, flags.bits());
+                    sys::igPopID();
+                    node_open
+                });
+                if depth > 0 {
+                    ui.unindent_by(width);
+                }
+
+                if node_open != open {
+                    toggled.push((key, node_open));
+                } else if scope.is_none() && ui.is_item_clicked() {
+                    clicked = Some(key);
+                }
+                let row = TreeViewRow {
+                    key,
+                    depth,
+                    index,
+                    open: node_open,
+                    selected,
+                };
+                on_row(ui, &row);
+            }
+        }
+        drop(clipper);
+
+        if let Some(scope) = scope {
+            apply_requests(scope.end().io(), &self.row_of, &mut storage);
+        }
+        if let Some(key) = clicked {
+            self.selected.clear();
+            self.selected.insert(key);
+        }
+        for (key, open) in toggled {
+            self.set_open(key, open);
+        }
+    }
+}
+
+fn key_hash<K: Hash>(key: &K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Derives a stable ID seed from a key; only used for hashing into the ID stack.
+fn key_ptr<K: Hash>(key: &K) -> *const std::ffi::c_void {
+    key_hash(key) as usize as *const std::ffi::c_void
+}
+
+/// Selection user data of a key: its hash, kept non-negative so it never equals
+/// `ImGuiSelectionUserData_Invalid` (-1).
+fn selection_data<K: Hash>(key: &K) -> sys::ImGuiSelectionUserData {
+    (key_hash(key) >> 1) as sys::ImGuiSelectionUserData
+}
+
+/// Applies multi-select requests whose items carry [`selection_data`] instead of row indices,
+/// resolving them to the current rows through `row_of`.
+fn apply_requests<S: MultiSelectIndexStorage>(
+    io: &sys::ImGuiMultiSelectIO,
+    row_of: &HashMap<sys::ImGuiSelectionUserData, usize>,
+    storage: &mut S,
+) {
+    let requests = &io.Requests;
+    let len = usize::try_from(requests.Size).unwrap_or(0);
+    if requests.Data.is_null() || len == 0 {
+        return;
+    }
+    let requests = unsafe { std::slice::from_raw_parts(requests.Data, len) };
+    for req in requests {
+        if req.Type == sys::ImGuiSelectionRequestType_SetAll {
+            for index in 0..usize::try_from(io.ItemsCount).unwrap_or(0) {
+                storage.set_selected(index, req.Selected);
+            }
+        } else if req.Type == sys::ImGuiSelectionRequestType_SetRange {
+            let (Some(&first), Some(&last)) = (
+                row_of.get(&req.RangeFirstItem),
+                row_of.get(&req.RangeLastItem),
+            ) else {
+                continue;
+            };
+            for index in first.min(last)..=first.max(last) {
+                storage.set_selected(index, req.Selected);
+            }
+        }
+    }
+}
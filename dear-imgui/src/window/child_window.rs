@@ -88,6 +88,8 @@ pub struct ChildWindow<'ui> {
     size: [f32; 2],
     child_flags: ChildFlags,
     flags: WindowFlags,
+    scroll: Option<[f32; 2]>,
+    stick_to_bottom: bool,
     _phantom: std::marker::PhantomData<&'ui Ui>,
 }
 
@@ -99,6 +101,8 @@ impl<'ui> ChildWindow<'ui> {
             size: [0.0, 0.0],
             child_flags: ChildFlags::NONE,
             flags: WindowFlags::empty(),
+            scroll: None,
+            stick_to_bottom: false,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -127,13 +131,35 @@ impl<'ui> ChildWindow<'ui> {
         self
     }
 
+    /// Sets the scroll position of the child window for this frame.
+    #[doc(alias = "SetNextWindowScroll")]
+    pub fn scroll(mut self, scroll: [f32; 2]) -> Self {
+        self.scroll = Some(scroll);
+        self
+    }
+
+    /// Keeps the child window scrolled to the bottom while content is appended, e.g. for logs.
+    ///
+    /// The child only follows new content while it was already scrolled to the bottom, so users
+    /// can scroll up to read older lines; scrolling back down re-enables following.
+    pub fn stick_to_bottom(mut self, stick_to_bottom: bool) -> Self {
+        self.stick_to_bottom = stick_to_bottom;
+        self
+    }
+
     /// Builds the child window and calls the provided closure
     pub fn build<F, R>(self, ui: &'ui Ui, f: F) -> Option<R>
     where
         F: FnOnce() -> R,
     {
+        let stick_to_bottom = self.stick_to_bottom;
         let token = self.begin(ui)?;
+        // Scroll values still describe the previous frame at this point.
+        let follow = stick_to_bottom && ui.is_scrolled_to_bottom();
         let result = f();
+        if follow {
+            ui.set_scroll_here_y(1.0);
+        }
         drop(token); // Explicitly drop the token to call EndChild
         Some(result)
     }
@@ -147,8 +173,21 @@ impl<'ui> ChildWindow<'ui> {
             "ChildWindow::begin() size must contain finite values"
         );
 
+        if let Some(scroll) = self.scroll {
+            assert!(
+                scroll[0].is_finite() && scroll[1].is_finite(),
+                "ChildWindow::begin() scroll must contain finite values"
+            );
+        }
+
         ui.run_with_bound_context(|| {
             let result = unsafe {
+                if let Some(scroll) = self.scroll {
+                    sys::igSetNextWindowScroll(sys::ImVec2_c {
+                        x: scroll[0],
+                        y: scroll[1],
+                    });
+                }
                 let size_vec = sys::ImVec2 {
                     x: self.size[0],
                     y: self.size[1],
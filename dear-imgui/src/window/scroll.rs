@@ -43,6 +43,15 @@ impl Ui {
         self.run_with_bound_context(|| unsafe { sys::igGetScrollMaxY() })
     }
 
+    /// Returns true if the current window is scrolled to the bottom, or cannot scroll vertically.
+    ///
+    /// Call it before submitting new content and follow up with [`Ui::set_scroll_here_y`]`(1.0)`
+    /// after it to implement stick-to-bottom behavior, see also
+    /// [`ChildWindow::stick_to_bottom`](crate::ChildWindow::stick_to_bottom).
+    pub fn is_scrolled_to_bottom(&self) -> bool {
+        self.scroll_y() >= self.scroll_max_y()
+    }
+
     /// Sets the horizontal scroll position
     #[doc(alias = "SetScrollX")]
     pub fn set_scroll_x(&self, scroll_x: f32) {
@@ -0,0 +1,73 @@
+//! The allocator is process-global, so this binary holds a single test that installs it before
+//! Dear ImGui allocates anything.
+
+use dear_imgui_rs as imgui;
+use std::alloc::{Layout, alloc, dealloc};
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const HEADER: usize = 16;
+
+struct Counting {
+    live_blocks: AtomicUsize,
+    total_allocs: AtomicUsize,
+}
+
+unsafe impl imgui::ImGuiAllocator for Counting {
+    fn alloc(&self, size: usize) -> *mut c_void {
+        let layout = Layout::from_size_align(size + HEADER, HEADER).unwrap();
+        unsafe {
+            let base = alloc(layout);
+            if base.is_null() {
+                return std::ptr::null_mut();
+            }
+            base.cast::<usize>().write(size);
+            self.live_blocks.fetch_add(1, Ordering::SeqCst);
+            self.total_allocs.fetch_add(1, Ordering::SeqCst);
+            base.add(HEADER).cast()
+        }
+    }
+
+    unsafe fn free(&self, ptr: *mut c_void) {
+        unsafe {
+            let base = ptr.cast::<u8>().sub(HEADER);
+            let size = base.cast::<usize>().read();
+            self.live_blocks.fetch_sub(1, Ordering::SeqCst);
+            dealloc(
+                base,
+                Layout::from_size_align_unchecked(size + HEADER, HEADER),
+            );
+        }
+    }
+}
+
+static COUNTING: Counting = Counting {
+    live_blocks: AtomicUsize::new(0),
+    total_allocs: AtomicUsize::new(0),
+};
+
+#[test]
+fn allocations_go_through_the_installed_allocator() {
+    assert!(!imgui::has_custom_allocator());
+    unsafe { imgui::set_allocator(&COUNTING) }.unwrap();
+    assert!(imgui::has_custom_allocator());
+    assert!(unsafe { imgui::set_allocator(&COUNTING) }.is_err());
+
+    {
+        let mut ctx = imgui::Context::create();
+        assert!(unsafe { imgui::set_allocator(&COUNTING) }.is_err());
+
+        ctx.io_mut().set_display_size([800.0, 600.0]);
+        let _ = ctx.font_atlas_mut().build();
+        let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+        let ui = ctx.frame();
+        ui.window("allocator").build(|| ui.text("tracked"));
+        ctx.render();
+
+        assert!(COUNTING.total_allocs.load(Ordering::SeqCst) > 0);
+        assert!(COUNTING.live_blocks.load(Ordering::SeqCst) > 0);
+    }
+
+    // Destroying the context released everything it allocated.
+    assert_eq!(COUNTING.live_blocks.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,39 @@
+//! `set_allocator` must see contexts owned by other threads. Separate binary: the allocator is
+//! process-global.
+
+use dear_imgui_rs as imgui;
+use std::ffi::c_void;
+use std::sync::mpsc;
+
+struct Unused;
+
+unsafe impl imgui::ImGuiAllocator for Unused {
+    fn alloc(&self, _size: usize) -> *mut c_void {
+        unreachable!("the allocator must not be installed")
+    }
+
+    unsafe fn free(&self, _ptr: *mut c_void) {
+        unreachable!("the allocator must not be installed")
+    }
+}
+
+static UNUSED: Unused = Unused;
+
+#[test]
+fn set_allocator_rejects_contexts_alive_on_other_threads() {
+    let (created_tx, created_rx) = mpsc::channel();
+    let (done_tx, done_rx) = mpsc::channel::<()>();
+    let owner = std::thread::spawn(move || {
+        // A suspended context is alive but not current on any thread.
+        let ctx = imgui::SuspendedContext::create();
+        created_tx.send(()).unwrap();
+        done_rx.recv().unwrap();
+        drop(ctx);
+    });
+
+    created_rx.recv().unwrap();
+    assert!(unsafe { imgui::set_allocator(&UNUSED) }.is_err());
+    assert!(!imgui::has_custom_allocator());
+    done_tx.send(()).unwrap();
+    owner.join().unwrap();
+}
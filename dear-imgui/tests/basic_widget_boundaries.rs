@@ -165,3 +165,32 @@ fn progress_list_box_and_selectable_validate_numeric_inputs_before_ffi() {
         });
     });
 }
+
+#[test]
+fn indeterminate_progress_and_spinner_keep_frames_coming() {
+    let _guard = test_guard();
+
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    {
+        let ui = ctx.frame();
+        let _ = ui.window("loading indicators").build(|| {
+            ui.progress_bar(0.25).overlay_text("25%").build();
+            assert!(!ui.animation_clock().is_animating());
+
+            ui.progress_bar(0.0).indeterminate(true).build();
+            ui.spinner(8.0).thickness(2.0).period(0.5).build();
+            assert!(ui.animation_clock().is_animating());
+
+            assert_panics!({
+                ui.spinner(0.0).build();
+            });
+            assert_panics!({
+                ui.spinner(8.0).period(f32::NAN).build();
+            });
+        });
+    }
+    ctx.render();
+    assert!(ctx.animation_clock().is_animating());
+}
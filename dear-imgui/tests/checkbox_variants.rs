@@ -0,0 +1,112 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+/// Runs one frame of `f` in a fixed, undecorated window and returns what it returned.
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("checkbox variants")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+/// Clicks at `pos` over a few frames, returning how many frames reported the widget as pressed.
+fn click(
+    ctx: &mut imgui::Context,
+    pos: [f32; 2],
+    mut widget: impl FnMut(&imgui::Ui) -> bool,
+) -> usize {
+    let mut pressed = 0;
+    ctx.io_mut().add_mouse_pos_event(pos);
+    pressed += usize::from(frame(ctx, &mut widget));
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, true);
+    pressed += usize::from(frame(ctx, &mut widget));
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, false);
+    pressed += usize::from(frame(ctx, &mut widget));
+    pressed
+}
+
+fn item_center(ctx: &mut imgui::Context, widget: impl FnOnce(&imgui::Ui)) -> [f32; 2] {
+    frame(ctx, |ui| {
+        widget(ui);
+        let (min, max) = (ui.item_rect_min(), ui.item_rect_max());
+        [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5]
+    })
+}
+
+#[test]
+fn toggle_switch_flips_its_value_on_click() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let mut value = false;
+    // The label is drawn after the switch, so measure the switch with a hidden label.
+    let center = item_center(&mut ctx, |ui| {
+        ui.toggle_switch("##switch", &mut value);
+    });
+    let presses = click(&mut ctx, center, |ui| {
+        ui.toggle_switch("##switch", &mut value)
+    });
+    assert_eq!(presses, 1);
+    assert!(value);
+}
+
+#[test]
+fn tristate_checkbox_leaves_mixed_state_when_clicked() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let mut value = None;
+    let center = item_center(&mut ctx, |ui| {
+        ui.checkbox_tristate("##all", &mut value);
+    });
+    assert_eq!(value, None);
+
+    click(&mut ctx, center, |ui| {
+        ui.checkbox_tristate("##all", &mut value)
+    });
+    assert_eq!(value, Some(true));
+    click(&mut ctx, center, |ui| {
+        ui.checkbox_tristate("##all", &mut value)
+    });
+    assert_eq!(value, Some(false));
+}
+
+#[test]
+fn radio_group_reports_only_selection_changes() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let labels = ["Low", "Medium", "High"];
+    let mut selected = 0;
+    // Measure the last radio button of the group.
+    let center = item_center(&mut ctx, |ui| {
+        let _id = ui.push_id_str("##quality");
+        ui.radio_button("Low", true);
+        ui.same_line();
+        ui.radio_button("Medium", false);
+        ui.same_line();
+        ui.radio_button("High", false);
+    });
+
+    let mut group = |ui: &imgui::Ui| ui.radio_group("##quality", &mut selected, &labels);
+    assert_eq!(click(&mut ctx, center, &mut group), 1);
+    assert_eq!(click(&mut ctx, center, &mut group), 0);
+    assert_eq!(selected, 2);
+}
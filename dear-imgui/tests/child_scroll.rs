@@ -0,0 +1,83 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+/// Runs one frame of `f` in a fixed, undecorated window and returns what it returned.
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("scrolling")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+/// Draws a 100 pixel high log child with `lines` lines and returns its scroll y and max.
+fn log(ui: &imgui::Ui, lines: usize, stick_to_bottom: bool, scroll: Option<[f32; 2]>) -> [f32; 2] {
+    let mut child = ui
+        .child_window("log")
+        .size([200.0, 100.0])
+        .stick_to_bottom(stick_to_bottom);
+    if let Some(scroll) = scroll {
+        child = child.scroll(scroll);
+    }
+    child
+        .build(ui, || {
+            for line in 0..lines {
+                ui.text(format!("line {line}"));
+            }
+            [ui.scroll_y(), ui.scroll_max_y()]
+        })
+        .unwrap()
+}
+
+#[test]
+fn stick_to_bottom_follows_appended_lines() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    for lines in 1..=40 {
+        frame(&mut ctx, |ui| log(ui, lines, true, None));
+    }
+    let [scroll, max] = frame(&mut ctx, |ui| log(ui, 40, true, None));
+    assert!(max > 0.0);
+    assert_eq!(scroll, max);
+}
+
+#[test]
+fn stick_to_bottom_stops_following_when_scrolled_up() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    for _ in 0..3 {
+        frame(&mut ctx, |ui| log(ui, 40, true, None));
+    }
+    frame(&mut ctx, |ui| log(ui, 40, true, Some([0.0, 0.0])));
+    frame(&mut ctx, |ui| log(ui, 41, true, None));
+    let [scroll, max] = frame(&mut ctx, |ui| log(ui, 42, true, None));
+    assert!(max > 0.0);
+    assert_eq!(scroll, 0.0);
+}
+
+#[test]
+fn is_scrolled_to_bottom_holds_for_windows_that_cannot_scroll() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    assert!(frame(&mut ctx, |ui| {
+        ui.text("short");
+        ui.is_scrolled_to_bottom()
+    }));
+}
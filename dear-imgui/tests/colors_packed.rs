@@ -33,3 +33,46 @@ fn color_packing_helpers_no_panic() {
     let roundtrip = imgui::Color::from_imgui_u32(packed);
     let _ = roundtrip.to_imgui_u32();
 }
+
+#[test]
+fn color_space_conversions_and_palette() {
+    let _guard = test_guard();
+
+    let mid = imgui::Color::new(0.5, 0.25, 0.0, 0.5);
+    let linear = mid.to_linear();
+    assert!((linear.r - 0.214).abs() < 1e-3, "{linear:?}");
+    assert_eq!(linear.a, 0.5);
+    let back = linear.to_srgb();
+    for (a, b) in mid.to_array().into_iter().zip(back.to_array()) {
+        assert!((a - b).abs() < 1e-5, "{mid:?} != {back:?}");
+    }
+
+    let first = imgui::Color::palette(0);
+    assert_eq!(first, imgui::Color::palette(0));
+    assert_ne!(first, imgui::Color::palette(1));
+    assert_eq!(first.a, 1.0);
+}
+
+#[test]
+fn color_apis_accept_color_values() {
+    let _guard = test_guard();
+
+    let mut ctx = imgui::Context::create();
+    ctx.style_mut()
+        .set_color(imgui::StyleColor::Text, imgui::Color::palette(3));
+    assert_eq!(
+        ctx.style().color(imgui::StyleColor::Text),
+        imgui::Color::palette(3).to_array()
+    );
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+    ctx.io_mut().set_display_size([800.0, 600.0]);
+    let ui = ctx.frame();
+    ui.text_colored(imgui::Color::RED, "red");
+    ui.color_button("swatch", imgui::Color::BLUE);
+    assert_eq!(
+        ui.get_color_u32_from_rgba(imgui::Color::WHITE),
+        ui.get_color_u32_from_rgba([1.0, 1.0, 1.0, 1.0])
+    );
+}
@@ -0,0 +1,184 @@
+use dear_imgui_rs as imgui;
+use imgui::{GradientStop, MouseButton, curve_eval, gradient_eval};
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("editors")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+macro_rules! assert_panics {
+    ($body:block) => {
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $body)).is_err());
+    };
+}
+
+fn assert_near(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "{actual} is not close to {expected}"
+    );
+}
+
+#[test]
+fn curve_eval_follows_the_spline() {
+    let line = [
+        [0.0, 0.0],
+        [1.0 / 3.0, 1.0 / 3.0],
+        [2.0 / 3.0, 2.0 / 3.0],
+        [1.0, 1.0],
+    ];
+    for x in [0.0, 0.25, 0.5, 0.9, 1.0] {
+        assert_near(curve_eval(&line, x), x);
+    }
+    assert_near(curve_eval(&line, -1.0), 0.0);
+    assert_near(curve_eval(&line, 2.0), 1.0);
+
+    let steps = [
+        [0.0, 0.0],
+        [0.2, 0.0],
+        [0.3, 0.0],
+        [0.5, 0.0],
+        [0.6, 1.0],
+        [0.9, 1.0],
+        [1.0, 1.0],
+    ];
+    assert_near(curve_eval(&steps, 0.25), 0.0);
+    assert_near(curve_eval(&steps, 0.75), 0.875);
+
+    assert_panics!({
+        curve_eval(&[[0.0, 0.0], [1.0, 1.0]], 0.5);
+    });
+}
+
+#[test]
+fn curve_editor_drags_anchors_with_their_handles() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let mut points = vec![
+        [0.0, 0.0],
+        [0.1, 0.0],
+        [0.4, 0.5],
+        [0.5, 0.5],
+        [0.6, 0.5],
+        [0.9, 1.0],
+        [1.0, 1.0],
+    ];
+    let mut edit = |ui: &imgui::Ui| {
+        let origin = ui.cursor_screen_pos();
+        let changed = ui
+            .curve_editor_config("##curve")
+            .size([200.0, 100.0])
+            .build(&mut points);
+        (origin, changed)
+    };
+    let (origin, changed) = frame(&mut ctx, &mut edit);
+    assert!(!changed);
+
+    let anchor = [origin[0] + 100.0, origin[1] + 50.0];
+    ctx.io_mut().add_mouse_pos_event(anchor);
+    frame(&mut ctx, &mut edit);
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    frame(&mut ctx, &mut edit);
+    ctx.io_mut()
+        .add_mouse_pos_event([anchor[0] + 20.0, anchor[1] + 10.0]);
+    let (_, changed) = frame(&mut ctx, &mut edit);
+    assert!(changed);
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Left, false);
+    frame(&mut ctx, &mut edit);
+
+    let expected = [[0.5, 0.4], [0.6, 0.4], [0.7, 0.4]];
+    for (point, expected) in points[2..5].iter().zip(expected) {
+        assert_near(point[0], expected[0]);
+        assert_near(point[1], expected[1]);
+    }
+
+    assert_panics!({
+        frame(&mut ctx, |ui| {
+            ui.curve_editor("##bad", &mut vec![[0.0, 0.0]]);
+        });
+    });
+}
+
+#[test]
+fn gradient_eval_interpolates_between_stops() {
+    let stops = [
+        GradientStop::new(0.25, [0.0, 0.0, 0.0, 1.0]),
+        GradientStop::new(0.75, [1.0, 0.5, 0.0, 0.0]),
+    ];
+    assert_eq!(gradient_eval(&stops, 0.0), [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(gradient_eval(&stops, 0.5), [0.5, 0.25, 0.0, 0.5]);
+    assert_eq!(gradient_eval(&stops, 1.0), [1.0, 0.5, 0.0, 0.0]);
+    assert_eq!(gradient_eval(&[], 0.5), [0.0; 4]);
+}
+
+#[test]
+fn gradient_editor_adds_and_removes_stops() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let mut stops = vec![
+        GradientStop::new(0.0, [0.0, 0.0, 0.0, 1.0]),
+        GradientStop::new(1.0, [1.0, 1.0, 1.0, 1.0]),
+    ];
+    let edit = |ui: &imgui::Ui, stops: &mut Vec<GradientStop>| {
+        let origin = ui.cursor_screen_pos();
+        let changed = ui
+            .gradient_editor_config("##gradient")
+            .size([200.0, 20.0])
+            .build(stops);
+        (origin, changed)
+    };
+    let (origin, _) = frame(&mut ctx, |ui| edit(ui, &mut stops));
+
+    // Clicking the bar adds a stop with the color already there.
+    ctx.io_mut()
+        .add_mouse_pos_event([origin[0] + 100.0, origin[1] + 10.0]);
+    frame(&mut ctx, |ui| edit(ui, &mut stops));
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    let (_, changed) = frame(&mut ctx, |ui| edit(ui, &mut stops));
+    assert!(changed);
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Left, false);
+    frame(&mut ctx, |ui| edit(ui, &mut stops));
+    assert_eq!(stops.len(), 3);
+    assert_near(stops[1].pos, 0.5);
+    assert_near(stops[1].color[0], 0.5);
+
+    // Right-clicking its marker removes it again.
+    ctx.io_mut()
+        .add_mouse_pos_event([origin[0] + 100.0, origin[1] + 25.0]);
+    frame(&mut ctx, |ui| edit(ui, &mut stops));
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Right, true);
+    frame(&mut ctx, |ui| edit(ui, &mut stops));
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Right, false);
+    frame(&mut ctx, |ui| edit(ui, &mut stops));
+    assert_eq!(stops.len(), 2);
+
+    assert_panics!({
+        frame(&mut ctx, |ui| {
+            ui.gradient_editor("##empty", &mut Vec::new());
+        });
+    });
+}
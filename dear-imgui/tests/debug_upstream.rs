@@ -0,0 +1,38 @@
+#![cfg(feature = "debug-upstream")]
+
+use dear_imgui_rs as imgui;
+use std::process::Command;
+
+const CHILD_ENV: &str = "DEAR_IMGUI_DEBUG_UPSTREAM_CHILD";
+
+#[test]
+fn upstream_assertion_aborts_with_location() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        let mut ctx = imgui::Context::create();
+        ctx.prepare_frame(imgui::FramePrepareOptions::new([800.0, 600.0], 1.0 / 60.0));
+        let _ = ctx.font_atlas_mut().build();
+        let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+        let _ui = ctx.frame();
+        // Only the implicit "Debug" window is open: "Calling End() too many times!".
+        unsafe { imgui::sys::igEnd() };
+        unreachable!("IM_ASSERT must abort the process");
+    }
+
+    // The handler aborts, so trigger the assertion in a child process running this test.
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args([
+            "--exact",
+            "upstream_assertion_aborts_with_location",
+            "--nocapture",
+        ])
+        .env(CHILD_ENV, "1")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Dear ImGui assertion failed:") && stderr.contains("imgui"),
+        "unexpected stderr: {stderr}"
+    );
+}
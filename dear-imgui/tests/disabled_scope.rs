@@ -0,0 +1,47 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+#[test]
+fn disabled_scopes_nest_and_report_their_state() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    ctx.style_mut().set_disabled_alpha(0.5);
+
+    let ui = ctx.frame();
+    let _ = ui.window("disabled").build(|| {
+        let alpha = unsafe { ui.style() }.alpha();
+        assert!(!ui.is_any_parent_disabled());
+        assert_eq!(ui.disabled_depth(), 0);
+
+        {
+            let _enabled = ui.begin_disabled_when(false);
+            assert!(!ui.is_any_parent_disabled());
+            assert_eq!(ui.disabled_depth(), 1);
+        }
+
+        {
+            let _outer = ui.begin_disabled_when(true);
+            assert!(ui.is_any_parent_disabled());
+            assert_eq!(unsafe { ui.style() }.alpha(), alpha * 0.5);
+            {
+                // An enabled inner scope cannot re-enable items.
+                let _inner = ui.begin_disabled_when(false);
+                assert!(ui.is_any_parent_disabled());
+                assert_eq!(ui.disabled_depth(), 2);
+            }
+            assert_eq!(ui.disabled_depth(), 1);
+        }
+
+        assert!(!ui.is_any_parent_disabled());
+        assert_eq!(ui.disabled_depth(), 0);
+        assert_eq!(unsafe { ui.style() }.alpha(), alpha);
+    });
+}
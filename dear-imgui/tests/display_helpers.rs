@@ -0,0 +1,80 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("display helpers")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+fn text_width(ui: &imgui::Ui, text: &str) -> f32 {
+    ui.current_font()
+        .calc_text_size(ui.current_font_size(), f32::MAX, 0.0, text)[0]
+}
+
+#[test]
+fn values_render_prefix_and_value() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    frame(&mut ctx, |ui| {
+        let cases: [(&dyn Fn(), &str); 5] = [
+            (&|| ui.value_bool("flag", true), "flag: true"),
+            (&|| ui.value_int("count", -42), "count: -42"),
+            (&|| ui.value_uint("size", 7), "size: 7"),
+            (&|| ui.value_float("ratio", 1.5), "ratio: 1.500"),
+            (
+                &|| ui.value_float_with_precision("ratio", 1.5, 1),
+                "ratio: 1.5",
+            ),
+        ];
+        for (draw, expected) in cases {
+            draw();
+            assert_eq!(
+                ui.item_rect_size()[0],
+                text_width(ui, expected),
+                "{expected}"
+            );
+        }
+    });
+}
+
+#[test]
+fn fmt_helpers_match_their_string_versions() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    frame(&mut ctx, |ui| {
+        let n = 12;
+        ui.bullet_text(format!("{n} items"));
+        let expected = ui.item_rect_size();
+        ui.bullet_text_fmt(format_args!("{n} items"));
+        assert_eq!(ui.item_rect_size(), expected);
+
+        ui.label_text("Items", format!("{n} items"));
+        let expected = ui.item_rect_size();
+        ui.label_text_fmt("Items", format_args!("{n} items"));
+        assert_eq!(ui.item_rect_size(), expected);
+
+        ui.separator_with_text(format!("Section {n}"));
+        let expected = ui.item_rect_size();
+        ui.separator_with_text_fmt(format_args!("Section {n}"));
+        assert_eq!(ui.item_rect_size(), expected);
+    });
+}
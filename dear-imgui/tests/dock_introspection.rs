@@ -0,0 +1,66 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+    io.set_config_flags(io.config_flags() | imgui::ConfigFlags::DOCKING_ENABLE);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+#[test]
+fn dock_nodes_report_hosted_windows_and_hierarchy() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let mut layout = None;
+    for frame in 0..3 {
+        let ui = ctx.frame();
+        let (root, left, right) = *layout.get_or_insert_with(|| {
+            let root = imgui::DockBuilder::add_node(ui, 0.into(), imgui::DockNodeFlags::NONE);
+            imgui::DockBuilder::set_node_pos(ui, root, [50.0, 50.0]);
+            imgui::DockBuilder::set_node_size(ui, root, [400.0, 300.0]);
+            let (left, right) =
+                imgui::DockBuilder::split_node(ui, root, imgui::SplitDirection::Left, 0.5);
+            imgui::DockBuilder::dock_window(ui, "Left panel", left);
+            imgui::DockBuilder::dock_window(ui, "Right panel", right);
+            imgui::DockBuilder::finish(ui, root);
+            (root, left, right)
+        });
+
+        let mut left_node = None;
+        ui.window("Left panel").build(|| {
+            left_node = ui.window_dock_node().map(|node| {
+                (
+                    node.id(),
+                    node.window_count(),
+                    node.is_tab_bar_visible(),
+                    node.parent().map(|parent| parent.id()),
+                )
+            });
+        });
+        ui.window("Right panel").build(|| {});
+        let mut floating = true;
+        ui.window("Floating").build(|| {
+            floating = ui.window_dock_node().is_none();
+        });
+
+        if frame == 2 {
+            assert_eq!(left_node, Some((left, 1, true, Some(root))));
+            assert!(floating);
+
+            let right_node = imgui::DockBuilder::window_node(ui, "Right panel").unwrap();
+            assert_eq!(right_node.id(), right);
+            assert_eq!(right_node.root().unwrap().id(), root);
+            assert!(imgui::DockBuilder::window_node(ui, "Floating").is_none());
+            assert!(imgui::DockBuilder::window_node(ui, "Missing").is_none());
+
+            let root_node = imgui::DockBuilder::node(ui, root).unwrap();
+            assert!(root_node.parent().is_none());
+            assert!(root_node.is_split());
+            assert!(!root_node.is_tab_bar_visible());
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use dear_imgui_rs as imgui;
+
+#[test]
+fn dpi_manager_scales_from_the_base_style_without_drift() {
+    let mut ctx = imgui::Context::create();
+    ctx.style_mut().set_window_padding([7.0, 5.0]);
+    let base = ctx.style().clone();
+    let mut dpi = imgui::DpiManager::new(&ctx);
+
+    assert!(dpi.set_scale(&mut ctx, 1.5));
+    assert_eq!(ctx.style().window_padding(), [10.0, 7.0]);
+    assert_eq!(ctx.style().font_scale_dpi(), 1.5);
+
+    for scale in [1.25, 1.75, 2.0, 1.1] {
+        dpi.set_scale(&mut ctx, scale);
+    }
+    assert!(dpi.set_scale(&mut ctx, 1.0));
+    assert!(*ctx.style() == base);
+    assert!(!dpi.set_scale(&mut ctx, 1.0));
+}
+
+#[test]
+fn dpi_manager_buckets_scales() {
+    let mut ctx = imgui::Context::create();
+    let mut dpi = imgui::DpiManager::new(&ctx).bucket_step(0.25);
+
+    assert!(!dpi.set_scale(&mut ctx, 1.1));
+    assert_eq!(dpi.scale(), 1.0);
+    assert!(dpi.set_scale(&mut ctx, 1.4));
+    assert_eq!(dpi.scale(), 1.5);
+    assert_eq!(dpi.bucketed(0.01), 0.25);
+}
+
+#[test]
+fn dpi_manager_reapplies_the_scale_to_a_new_base_style() {
+    let mut ctx = imgui::Context::create();
+    let mut dpi = imgui::DpiManager::new(&ctx);
+    dpi.set_scale(&mut ctx, 2.0);
+
+    let mut base = dpi.base_style().clone();
+    base.set_frame_rounding(3.0);
+    dpi.set_base_style(&mut ctx, base);
+    assert_eq!(ctx.style().frame_rounding(), 6.0);
+    assert_eq!(dpi.base_style().frame_rounding(), 3.0);
+}
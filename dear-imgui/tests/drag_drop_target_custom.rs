@@ -0,0 +1,156 @@
+use dear_imgui_rs as imgui;
+use imgui::{DragDropTargetFlags, InputEvent, MouseButton, MouseSource};
+
+const DROP_MIN: [f32; 2] = [50.0, 100.0];
+const DROP_MAX: [f32; 2] = [250.0, 250.0];
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Target {
+    Rect,
+    Window,
+    /// Source and target begun through raw calls, as extensions do.
+    Extension,
+}
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+/// Runs one frame with a drag source button and the chosen target, returning the accepted
+/// payload as `(data, delivery)`.
+fn frame(ctx: &mut imgui::Context, target: Target) -> Option<(u32, bool)> {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("drop target")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| {
+                ui.button("source");
+                let source = ui.drag_drop_source_config("ASSET");
+                let tooltip = if target == Target::Extension {
+                    unsafe {
+                        source.begin_payload_with(7u32, |flags| {
+                            imgui::sys::igBeginDragDropSource(flags.bits() as i32)
+                        })
+                    }
+                } else {
+                    source.begin_payload(7u32)
+                };
+                if let Some(tooltip) = tooltip {
+                    tooltip.end();
+                }
+                let target = match target {
+                    Target::Rect => {
+                        ui.drag_drop_target_rect(DROP_MIN, DROP_MAX, ui.get_id("drop_zone"))
+                    }
+                    Target::Window => ui.drag_drop_target_window(),
+                    Target::Extension => {
+                        let rect = imgui::sys::ImRect_c {
+                            Min: imgui::sys::ImVec2_c {
+                                x: DROP_MIN[0],
+                                y: DROP_MIN[1],
+                            },
+                            Max: imgui::sys::ImVec2_c {
+                                x: DROP_MAX[0],
+                                y: DROP_MAX[1],
+                            },
+                        };
+                        let id = ui.get_id("drop_zone").raw();
+                        unsafe { imgui::sys::igBeginDragDropTargetCustom(rect, id) }
+                            .then(|| unsafe { imgui::DragDropTarget::from_begun(ui) })
+                    }
+                }?;
+                let payload = target
+                    .accept_payload::<u32, _>("ASSET", DragDropTargetFlags::BEFORE_DELIVERY)?
+                    .ok()?;
+                Some((payload.data, payload.delivery))
+            })
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+fn push(ctx: &mut imgui::Context, event: InputEvent) {
+    ctx.io_mut().push_input_event(&event);
+}
+
+fn mouse_pos(pos: [f32; 2]) -> InputEvent {
+    InputEvent::MousePos {
+        pos,
+        source: MouseSource::Mouse,
+    }
+}
+
+fn left_button(down: bool) -> InputEvent {
+    InputEvent::MouseButton {
+        button: MouseButton::Left,
+        down,
+        source: MouseSource::Mouse,
+    }
+}
+
+/// Presses the mouse on the source button and drags it to `to`.
+fn start_drag(ctx: &mut imgui::Context, target: Target, to: [f32; 2]) {
+    push(ctx, mouse_pos([20.0, 16.0]));
+    assert_eq!(frame(ctx, target), None);
+    push(ctx, left_button(true));
+    assert_eq!(frame(ctx, target), None);
+    push(ctx, mouse_pos([20.0, 60.0]));
+    frame(ctx, target);
+    push(ctx, mouse_pos(to));
+}
+
+#[test]
+fn rect_target_accepts_drops_inside_the_rect_only() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    start_drag(&mut ctx, Target::Rect, [300.0, 150.0]);
+    assert_eq!(frame(&mut ctx, Target::Rect), None);
+
+    push(&mut ctx, mouse_pos([100.0, 150.0]));
+    assert_eq!(frame(&mut ctx, Target::Rect), Some((7, false)));
+
+    push(&mut ctx, left_button(false));
+    assert_eq!(frame(&mut ctx, Target::Rect), Some((7, true)));
+}
+
+#[test]
+fn window_target_accepts_drops_anywhere_in_the_window() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    start_drag(&mut ctx, Target::Window, [350.0, 280.0]);
+    assert_eq!(frame(&mut ctx, Target::Window), Some((7, false)));
+
+    push(&mut ctx, left_button(false));
+    assert_eq!(frame(&mut ctx, Target::Window), Some((7, true)));
+}
+
+#[test]
+fn externally_begun_sources_and_targets_exchange_payloads() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    start_drag(&mut ctx, Target::Extension, [100.0, 150.0]);
+    assert_eq!(frame(&mut ctx, Target::Extension), Some((7, false)));
+
+    push(&mut ctx, left_button(false));
+    assert_eq!(frame(&mut ctx, Target::Extension), Some((7, true)));
+}
+
+#[test]
+#[should_panic(expected = "id must not be zero")]
+fn rect_target_rejects_zero_id() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let ui = ctx.frame();
+    let _ = ui.drag_drop_target_rect([0.0, 0.0], [10.0, 10.0], imgui::Id::default());
+}
@@ -0,0 +1,58 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn metrics(delta_time: f32) -> imgui::FrameMetrics {
+    imgui::FrameMetrics {
+        delta_time,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn recorder_keeps_the_last_frames() {
+    let mut recorder = imgui::FrameMetricsRecorder::new(3);
+    assert_eq!(recorder.average_frame_time(), None);
+
+    for delta_time in [0.01, 0.02, 0.03, 0.04] {
+        recorder.push(metrics(delta_time));
+    }
+    let kept: Vec<_> = recorder.history().iter().map(|m| m.delta_time).collect();
+    assert_eq!(kept, [0.02, 0.03, 0.04]);
+    assert_eq!(recorder.latest().map(|m| m.delta_time), Some(0.04));
+    assert_eq!(recorder.max_frame_time(), Some(0.04));
+    assert!((recorder.average_frame_time().unwrap() - 0.03).abs() < 1e-6);
+
+    recorder.clear();
+    assert!(recorder.history().is_empty());
+    assert!(std::panic::catch_unwind(|| imgui::FrameMetricsRecorder::new(0)).is_err());
+}
+
+#[test]
+fn recorder_reads_render_metrics_and_draws_the_overlay() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let mut recorder = imgui::FrameMetricsRecorder::new(8);
+
+    for _ in 0..3 {
+        let ui = ctx.frame();
+        ui.window("content").build(|| ui.text("some text"));
+        recorder.record(ui);
+        recorder.draw_overlay(ui, imgui::OverlayCorner::BottomRight);
+        ctx.render();
+    }
+
+    let latest = recorder.latest().unwrap();
+    assert_eq!(recorder.history().len(), 3);
+    assert_eq!(latest.delta_time, 1.0 / 60.0);
+    assert!(latest.render_vertices > 0);
+    assert!(latest.render_windows >= 2);
+    assert!(latest.active_allocations > 0);
+}
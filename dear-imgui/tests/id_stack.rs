@@ -0,0 +1,112 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_imgui(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+    io.set_backend_flags(io.backend_flags() | imgui::BackendFlags::RENDERER_HAS_TEXTURES);
+}
+
+#[test]
+fn typed_push_id_helpers_match_push_id() {
+    let mut ctx = imgui::Context::create();
+    prepare_imgui(&mut ctx);
+
+    let ui = ctx.frame();
+    let value = 5u64;
+    ui.window("id stack host").build(|| {
+        let id_in = |token: imgui::IdStackToken<'_>| {
+            let id = ui.get_id("child");
+            token.pop();
+            id
+        };
+
+        assert_eq!(id_in(ui.push_id_int(7)), id_in(ui.push_id(7)));
+        assert_eq!(id_in(ui.push_id_str("row")), id_in(ui.push_id("row")));
+        assert_eq!(id_in(ui.push_id_str("")), id_in(ui.push_id("")));
+        // An empty `String` has a dangling data pointer.
+        let empty = String::new();
+        assert_eq!(id_in(ui.push_id_str(&empty)), id_in(ui.push_id("")));
+        assert_eq!(
+            id_in(ui.push_id_ptr(&value)),
+            id_in(ui.push_id(&value as *const u64))
+        );
+        assert_ne!(id_in(ui.push_id_str("row")), id_in(ui.push_id_str("col")));
+    });
+}
+
+#[test]
+fn with_fmt_formats_and_reuses_the_buffer() {
+    let mut ctx = imgui::Context::create();
+    prepare_imgui(&mut ctx);
+
+    let ui = ctx.frame();
+    let value = 42;
+    assert_eq!(
+        ui.with_fmt(format_args!("Item {value}"), str::to_owned),
+        "Item 42"
+    );
+    assert_eq!(ui.with_fmt(format_args!("static"), str::len), 6);
+    let nested = ui.with_fmt(format_args!("outer {}", 1), |outer| {
+        let inner = ui.with_fmt(format_args!("inner {}", 2), str::to_owned);
+        format!("{outer}/{inner}")
+    });
+    assert_eq!(nested, "outer 1/inner 2");
+
+    ui.window("fmt host")
+        .build(|| ui.text_fmt(format_args!("{value} FPS")));
+}
+
+#[test]
+fn id_stack_reports_pushed_ids() {
+    let mut ctx = imgui::Context::create();
+    prepare_imgui(&mut ctx);
+
+    let ui = ctx.frame();
+    ui.window("id stack query").build(|| {
+        let base = ui.id_stack();
+        assert_eq!(base.len(), 1);
+
+        let _row = ui.push_id_int(3);
+        let stack = ui.id_stack();
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack[0], base[0]);
+        assert_ne!(stack[1], base[0]);
+    });
+}
+
+#[test]
+fn hovered_and_active_ids_follow_the_mouse() {
+    let mut ctx = imgui::Context::create();
+    prepare_imgui(&mut ctx);
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+
+    let frame = |ctx: &mut imgui::Context| {
+        let ui = ctx.frame();
+        let ids = ui
+            .window("hover ids")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| {
+                ui.button("target");
+                let button = ui.item_id();
+                ui.debug_text_encoding("ok");
+                (button, ui.hovered_id(), ui.active_id())
+            })
+            .unwrap();
+        ctx.render();
+        ids
+    };
+
+    let (_, hovered, active) = frame(&mut ctx);
+    assert_eq!((hovered, active), (None, None));
+
+    ctx.io_mut().add_mouse_pos_event([10.0, 10.0]);
+    frame(&mut ctx);
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, true);
+    let (button, hovered, active) = frame(&mut ctx);
+    assert_eq!(hovered, Some(button));
+    assert_eq!(active, Some(button));
+}
@@ -0,0 +1,86 @@
+use dear_imgui_rs as imgui;
+use imgui::MouseButton;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("interact")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+#[test]
+fn interact_region_reports_hover_drag_and_click() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let region = |ui: &imgui::Ui| ui.interact_region("##canvas", [200.0, 100.0]);
+    let idle = frame(&mut ctx, region);
+    assert_eq!(idle.size(), [200.0, 100.0]);
+    assert!(!idle.hovered && !idle.active && !idle.clicked);
+
+    let center = idle.center();
+    ctx.io_mut().add_mouse_pos_event(center);
+    let hovered = frame(&mut ctx, region);
+    assert!(hovered.hovered);
+    assert_eq!(hovered.mouse_pos, [100.0, 50.0]);
+
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    let pressed = frame(&mut ctx, region);
+    assert!(pressed.activated && pressed.active);
+
+    ctx.io_mut()
+        .add_mouse_pos_event([center[0] + 5.0, center[1] - 3.0]);
+    let dragged = frame(&mut ctx, region);
+    assert_eq!(dragged.drag_delta, [5.0, -3.0]);
+
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Left, false);
+    let released = frame(&mut ctx, region);
+    assert!(released.clicked && released.deactivated && !released.active);
+    assert_eq!(released.drag_delta, [0.0, 0.0]);
+}
+
+#[test]
+fn overlapping_items_take_the_mouse_from_the_region() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let layout = |ui: &imgui::Ui| {
+        let start = ui.cursor_pos();
+        ui.set_next_item_allow_overlap();
+        let region = ui.interact_region("##canvas", [200.0, 100.0]);
+        ui.set_cursor_pos(start);
+        let button = ui.button("Over");
+        (region, button, ui.item_rect_min(), ui.item_rect_max())
+    };
+    let (_, _, min, max) = frame(&mut ctx, layout);
+    ctx.io_mut()
+        .add_mouse_pos_event([(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5]);
+    frame(&mut ctx, layout);
+    let (region, _, _, _) = frame(&mut ctx, layout);
+    assert!(!region.hovered);
+
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    frame(&mut ctx, layout);
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Left, false);
+    let (region, button, _, _) = frame(&mut ctx, layout);
+    assert!(button);
+    assert!(!region.clicked);
+}
@@ -0,0 +1,63 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("tooltips")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+fn hovered_button(ui: &imgui::Ui) -> bool {
+    ui.button("Save");
+    ui.item_tooltip_build(|| ui.text("Writes the project to disk"))
+}
+
+#[test]
+fn item_tooltip_waits_for_the_hover_delay() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let center = frame(&mut ctx, |ui| {
+        ui.button("Save");
+        let (min, max) = (ui.item_rect_min(), ui.item_rect_max());
+        [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5]
+    });
+    ctx.io_mut().add_mouse_pos_event(center);
+    // The default tooltip flags require a stationary mouse and a short delay.
+    assert!(!frame(&mut ctx, hovered_button));
+
+    let style = ctx.style_mut();
+    style.set_hover_flags_for_tooltip_mouse(imgui::TooltipHoveredFlags::NONE);
+    assert!(frame(&mut ctx, hovered_button));
+
+    ctx.io_mut().add_mouse_pos_event([390.0, 290.0]);
+    assert!(!frame(&mut ctx, hovered_button));
+}
+
+#[test]
+fn item_tooltip_text_builds_without_hover() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    frame(&mut ctx, |ui| {
+        ui.button("Open");
+        ui.item_tooltip("Opens a project");
+        assert!(ui.begin_item_tooltip().is_none());
+    });
+}
@@ -0,0 +1,105 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("knobs")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+macro_rules! assert_panics {
+    ($body:block) => {
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $body)).is_err());
+    };
+}
+
+#[test]
+fn knob_follows_vertical_drags() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let mut value = 0.0f32;
+    let center = frame(&mut ctx, |ui| {
+        ui.knob_config("##gain", 0.0, 1.0)
+            .size(60.0)
+            .build(&mut value);
+        let (min, max) = (ui.item_rect_min(), ui.item_rect_max());
+        [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5]
+    });
+    let mut knob = |ui: &imgui::Ui| {
+        ui.knob_config("##gain", 0.0, 1.0)
+            .size(60.0)
+            .build(&mut value)
+    };
+
+    ctx.io_mut().add_mouse_pos_event(center);
+    frame(&mut ctx, &mut knob);
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, true);
+    frame(&mut ctx, &mut knob);
+    // Half of the 200 pixel drag range, upwards.
+    ctx.io_mut()
+        .add_mouse_pos_event([center[0], center[1] - 100.0]);
+    let changed = frame(&mut ctx, &mut knob);
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, false);
+    frame(&mut ctx, &mut knob);
+
+    assert!(changed);
+    assert!((value - 0.5).abs() < 1e-4, "value = {value}");
+}
+
+#[test]
+fn knob_variants_build_and_validate_their_range() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    frame(&mut ctx, |ui| {
+        let mut value = 440.0f32;
+        for variant in [
+            imgui::KnobVariant::Tick,
+            imgui::KnobVariant::Dot,
+            imgui::KnobVariant::Wiper,
+        ] {
+            let _id = ui.push_id(variant as i32);
+            let changed = ui
+                .knob_config("Frequency", 20.0, 20000.0)
+                .variant(variant)
+                .logarithmic(true)
+                .unit("Hz")
+                .build(&mut value);
+            assert!(!changed);
+        }
+        assert_eq!(value, 440.0);
+
+        assert_panics!({
+            ui.knob_config("inverted", 1.0, 0.0).build(&mut value);
+        });
+        assert_panics!({
+            ui.knob_config("log through zero", 0.0, 1.0)
+                .logarithmic(true)
+                .build(&mut value);
+        });
+        assert_panics!({
+            ui.knob_config("no size", 0.0, 1.0)
+                .size(0.0)
+                .build(&mut value);
+        });
+    });
+}
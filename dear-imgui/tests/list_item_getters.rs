@@ -0,0 +1,87 @@
+use dear_imgui_rs as imgui;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::sync::{Mutex, OnceLock};
+
+fn test_guard() -> std::sync::MutexGuard<'static, ()> {
+    static GUARD: OnceLock<Mutex<()>> = OnceLock::new();
+    GUARD.get_or_init(|| Mutex::new(())).lock().unwrap()
+}
+
+fn setup_context() -> imgui::Context {
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+    ctx
+}
+
+#[test]
+fn list_box_getter_only_requests_visible_rows_of_large_lists() {
+    let _guard = test_guard();
+    let mut ctx = setup_context();
+    const COUNT: usize = 10_000;
+    let calls = Cell::new(0usize);
+    let mut current = 5_000;
+
+    for _ in 0..2 {
+        calls.set(0);
+        let ui = ctx.frame();
+        ui.window("getters")
+            .size([400.0, 400.0], imgui::Condition::Always)
+            .build(|| {
+                let changed = ui
+                    .list_box_config("big")
+                    .size([0.0, 200.0])
+                    .build_with_getter(ui, &mut current, COUNT, |idx| {
+                        calls.set(calls.get() + 1);
+                        Cow::Owned(format!("item {idx}"))
+                    });
+                assert!(!changed);
+            });
+        let _ = ctx.render();
+    }
+
+    // A 200px list shows a few dozen rows; the forced current item adds one more.
+    assert!(calls.get() > 0);
+    assert!(calls.get() < 100, "getter called {} times", calls.get());
+    assert_eq!(current, 5_000);
+}
+
+#[test]
+fn combo_helpers_accept_iterators_and_getters() {
+    let _guard = test_guard();
+    let mut ctx = setup_context();
+    let names = vec![String::from("alpha"), String::from("beta")];
+    let calls = Cell::new(0usize);
+
+    let ui = ctx.frame();
+    ui.window("combos").build(|| {
+        let mut current = 1;
+        assert!(!ui.combo_simple_string("slice", &mut current, &names));
+        assert!(!ui.combo_simple_string(
+            "iter",
+            &mut current,
+            names.iter().map(|name| name.as_str())
+        ));
+        let mut current_i32 = -1;
+        assert!(!ui.combo_simple_string_i32("none", &mut current_i32, ["a", "b"]));
+        assert_eq!(current_i32, -1);
+
+        // A closed combo only needs the preview label.
+        let mut current = 7_000;
+        assert!(
+            !ui.combo_with_getter("getter", &mut current, 10_000, |idx| {
+                calls.set(calls.get() + 1);
+                Cow::Owned(idx.to_string())
+            })
+        );
+    });
+    let _ = ctx.render();
+
+    assert_eq!(calls.get(), 1);
+}
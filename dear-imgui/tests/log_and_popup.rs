@@ -33,9 +33,125 @@ fn log_and_popup_helpers_no_panic() {
     ui.log_finish();
     let _ = std::fs::remove_file(log_path);
 
+    {
+        let _log = ui.begin_log_to_clipboard(LogAutoOpenDepth::new(2)).unwrap();
+        ui.text("Captured");
+        ui.log_text("Raw log line");
+    }
+    let value = ui.with_log_to_tty(LogAutoOpenDepth::DEFAULT, || {
+        ui.text("Captured to TTY");
+        7
+    });
+    assert_eq!(value.unwrap(), 7);
+
+    let scoped_path = std::env::temp_dir().join(format!(
+        "dear-imgui-rs-test-scoped-log-{}.txt",
+        std::process::id()
+    ));
+    let logged = ui.with_log_to_file(LogAutoOpenDepth::DEFAULT, &scoped_path, || {
+        ui.text("Captured to file");
+        true
+    });
+    assert!(logged.unwrap());
+    let _ = std::fs::remove_file(scoped_path);
+
     let _ = ui.window("Popup").build(|| {
         let _ = ui.button("Item");
         ui.open_popup_on_item_click(None);
         let _ = ui.begin_popup_context_item();
     });
 }
+
+#[test]
+fn with_log_to_string_returns_the_captured_text() {
+    let _guard = test_guard();
+
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+
+    let ui = ctx.frame();
+    let _ = ui.window("Snapshot").build(|| {
+        let (value, text) = ui
+            .with_log_to_string(LogAutoOpenDepth::DEFAULT, || {
+                ui.text("File");
+                ui.text("Edit");
+                3
+            })
+            .unwrap();
+        assert_eq!(value, 3);
+        let lines: Vec<_> = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        assert_eq!(lines, ["File", "Edit"]);
+
+        // The log is finished afterwards, so later text is not captured.
+        let ((), text) = ui
+            .with_log_to_string(LogAutoOpenDepth::DEFAULT, || {})
+            .unwrap();
+        assert!(text.is_empty());
+
+        // Nested logging is rejected instead of tripping Dear ImGui's assertion.
+        let nested = ui
+            .with_log_to_string(LogAutoOpenDepth::DEFAULT, || {
+                ui.with_log_to_string(LogAutoOpenDepth::DEFAULT, || unreachable!())
+            })
+            .unwrap()
+            .0;
+        assert!(nested.is_err());
+    });
+}
+
+#[test]
+fn nested_log_scopes_leave_the_outer_log_running() {
+    let _guard = test_guard();
+
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+
+    let path = std::env::temp_dir().join(format!(
+        "dear-imgui-rs-test-nested-log-{}.txt",
+        std::process::id()
+    ));
+    let ui = ctx.frame();
+    let _ = ui.window("Nested log").build(|| {
+        let outer = ui
+            .begin_log_to_file(LogAutoOpenDepth::DEFAULT, &path)
+            .unwrap();
+        ui.text("before");
+        assert!(ui.begin_log_to_tty(LogAutoOpenDepth::DEFAULT).is_err());
+        assert!(
+            ui.with_log_to_clipboard(LogAutoOpenDepth::DEFAULT, || unreachable!())
+                .is_err()
+        );
+        assert!(
+            ui.with_log_to_file(LogAutoOpenDepth::DEFAULT, &path, || unreachable!())
+                .is_err()
+        );
+        // The rejected scopes did not finish the outer log.
+        ui.text("after");
+        drop(outer);
+    });
+
+    let logged = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    let lines: Vec<_> = logged
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    assert_eq!(lines, ["before", "after"]);
+}
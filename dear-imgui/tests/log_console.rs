@@ -0,0 +1,111 @@
+use dear_imgui_rs as imgui;
+use imgui::{LogConsole, LogLevel};
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn texts<'a>(lines: impl Iterator<Item = &'a imgui::LogLine>) -> Vec<&'a str> {
+    lines.map(|line| line.text.as_str()).collect()
+}
+
+#[test]
+fn console_keeps_the_last_lines() {
+    let mut console = LogConsole::new(3);
+    for i in 0..5 {
+        console.push(LogLevel::Info, format!("line {i}"));
+    }
+    assert_eq!(
+        texts(console.lines().iter()),
+        ["line 2", "line 3", "line 4"]
+    );
+    console.clear();
+    assert!(console.lines().is_empty());
+}
+
+#[test]
+fn senders_append_from_other_threads() {
+    let mut console = LogConsole::new(100);
+    let sender = console.sender();
+    std::thread::spawn(move || {
+        for i in 0..10 {
+            assert!(sender.warn(format!("worker {i}")));
+        }
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(console.drain(), 10);
+    assert_eq!(console.lines().len(), 10);
+    assert!(
+        console
+            .lines()
+            .iter()
+            .all(|line| line.level == LogLevel::Warn)
+    );
+
+    let sender = console.sender();
+    drop(console);
+    assert!(!sender.info("nobody listens"));
+}
+
+#[test]
+fn filters_combine_level_text_and_predicate() {
+    let mut console = LogConsole::new(100);
+    console.push(LogLevel::Debug, "loading shaders");
+    console.push(LogLevel::Info, "loading textures");
+    console.push(LogLevel::Error, "texture missing");
+    console.push(LogLevel::Warn, "slow frame");
+
+    console.set_min_level(LogLevel::Info);
+    assert_eq!(
+        texts(console.visible_lines()),
+        ["loading textures", "texture missing", "slow frame"]
+    );
+
+    console.set_filter("texture");
+    assert_eq!(
+        texts(console.visible_lines()),
+        ["loading textures", "texture missing"]
+    );
+
+    console.set_filter_predicate(|line| line.level >= LogLevel::Error);
+    assert_eq!(texts(console.visible_lines()), ["texture missing"]);
+
+    console.clear_filter_predicate();
+    console.set_filter("");
+    console.set_min_level(LogLevel::Trace);
+    assert_eq!(console.visible_lines().count(), 4);
+}
+
+#[test]
+fn console_draws_inside_a_window() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let mut console = LogConsole::new(1000);
+    let sender = console.sender();
+    for i in 0..500 {
+        let level = LogLevel::ALL[i % LogLevel::ALL.len()];
+        sender.log(level, format!("message {i}"));
+    }
+    console.set_level_color(LogLevel::Info, Some([0.0, 1.0, 0.0, 1.0]));
+
+    for filtered in [false, true] {
+        if filtered {
+            console.set_filter("-message 1");
+        }
+        let ui = ctx.frame();
+        ui.window("log")
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .build(|| console.draw(ui));
+        ctx.render();
+    }
+
+    assert_eq!(console.lines().len(), 500);
+}
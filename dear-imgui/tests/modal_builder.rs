@@ -0,0 +1,90 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+/// Runs one frame of `f` in a fixed, undecorated window and returns what it returned.
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("modals")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+#[test]
+fn modal_opens_when_requested_and_returns_the_closure_value() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let shown = frame(&mut ctx, |ui| ui.modal("confirm").build(|_| 1));
+    assert_eq!(shown, None);
+
+    let shown = frame(&mut ctx, |ui| {
+        ui.modal("confirm").open_when(true).build(|_| 2)
+    });
+    assert_eq!(shown, Some(2));
+
+    // The modal stays open without being requested again.
+    let shown = frame(&mut ctx, |ui| {
+        ui.modal("confirm").open_when(false).build(|_| 3)
+    });
+    assert_eq!(shown, Some(3));
+}
+
+#[test]
+fn modal_token_closes_the_modal() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    frame(&mut ctx, |ui| {
+        ui.modal("closing").open_when(true).build(|_| ())
+    });
+    let shown = frame(&mut ctx, |ui| {
+        ui.modal("closing")
+            .build(|modal| modal.close_current_popup())
+    });
+    assert_eq!(shown, Some(()));
+    let shown = frame(&mut ctx, |ui| {
+        let shown = ui.modal("closing").build(|_| ());
+        assert!(!ui.is_popup_open("closing"));
+        shown
+    });
+    assert_eq!(shown, None);
+}
+
+#[test]
+fn always_centered_modal_is_centered_in_the_main_viewport() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let build = |ui: &imgui::Ui, open: bool| {
+        ui.modal("centered")
+            .open_when(open)
+            .always_centered()
+            .flags(imgui::WindowFlags::ALWAYS_AUTO_RESIZE)
+            .build(|_| {
+                ui.text("centered contents");
+                let pos = ui.window_pos();
+                let size = ui.window_size();
+                [pos[0] + size[0] * 0.5, pos[1] + size[1] * 0.5]
+            })
+    };
+    frame(&mut ctx, |ui| build(ui, true));
+    frame(&mut ctx, |ui| build(ui, false));
+    let center = frame(&mut ctx, |ui| build(ui, false)).unwrap();
+    assert!((center[0] - 400.0).abs() <= 1.0, "center = {center:?}");
+    assert!((center[1] - 300.0).abs() <= 1.0, "center = {center:?}");
+}
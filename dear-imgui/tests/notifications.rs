@@ -0,0 +1,131 @@
+use dear_imgui_rs as imgui;
+use imgui::{MouseButton, Notifications, OverlayCorner, Toast, ToastLevel};
+use std::time::Duration;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+/// Runs one frame lasting `seconds` that draws `notifications` outside of any window.
+fn frame(ctx: &mut imgui::Context, notifications: &mut Notifications, seconds: f32) {
+    ctx.io_mut().set_delta_time(seconds);
+    let ui = ctx.frame();
+    notifications.draw(ui);
+    ctx.render();
+}
+
+#[test]
+fn toasts_time_out_after_fading() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let mut notifications = Notifications::new();
+    notifications.set_default_timeout(Duration::from_secs(2));
+    notifications.info("first");
+    notifications.push(Toast::new(ToastLevel::Error, "second").timeout(Duration::from_secs(4)));
+    notifications.push(Toast::new(ToastLevel::Warning, "sticky").sticky());
+    assert_eq!(notifications.len(), 3);
+
+    frame(&mut ctx, &mut notifications, 0.1);
+    // Sliding in keeps frames coming.
+    assert_eq!(ctx.animation_clock().next_redraw_in(), Some(Duration::ZERO));
+    frame(&mut ctx, &mut notifications, 0.5);
+    // Afterwards only the next timeout needs a frame.
+    let wake = ctx.animation_clock().next_redraw_in().unwrap();
+    assert!(wake > Duration::ZERO && wake <= Duration::from_secs(2));
+
+    frame(&mut ctx, &mut notifications, 2.0);
+    frame(&mut ctx, &mut notifications, 0.5);
+    let texts: Vec<_> = notifications.toasts().map(|(_, t)| t.text()).collect();
+    assert_eq!(texts, ["second", "sticky"]);
+
+    frame(&mut ctx, &mut notifications, 3.0);
+    frame(&mut ctx, &mut notifications, 0.5);
+    let texts: Vec<_> = notifications.toasts().map(|(_, t)| t.text()).collect();
+    assert_eq!(texts, ["sticky"]);
+}
+
+#[test]
+fn toasts_wait_for_a_visible_slot() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let mut notifications = Notifications::new();
+    notifications.set_default_timeout(Duration::from_secs(1));
+    notifications.set_max_visible(1);
+    notifications.info("first");
+    let second = notifications.info("second");
+    let third = notifications.info("third");
+
+    frame(&mut ctx, &mut notifications, 0.1);
+    // Queued toasts are removed right away.
+    assert!(notifications.dismiss(third));
+    assert!(!notifications.dismiss(third));
+    assert_eq!(notifications.len(), 2);
+
+    // The second toast's timeout only starts once the first one is gone.
+    frame(&mut ctx, &mut notifications, 1.0);
+    frame(&mut ctx, &mut notifications, 0.5);
+    frame(&mut ctx, &mut notifications, 0.9);
+    let ids: Vec<_> = notifications.toasts().map(|(id, _)| id).collect();
+    assert_eq!(ids, [second]);
+}
+
+#[test]
+fn clicking_a_toast_dismisses_it() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let mut notifications = Notifications::new();
+    notifications.set_corner(OverlayCorner::BottomRight);
+    notifications.push(Toast::new(ToastLevel::Success, "saved").sticky());
+
+    frame(&mut ctx, &mut notifications, 0.5);
+    // The toast is 300 pixels wide, 10 pixels from the bottom-right corner.
+    ctx.io_mut().add_mouse_pos_event([640.0, 580.0]);
+    frame(&mut ctx, &mut notifications, 0.1);
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    frame(&mut ctx, &mut notifications, 0.1);
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Left, false);
+    frame(&mut ctx, &mut notifications, 0.1);
+    assert_eq!(notifications.len(), 1);
+
+    frame(&mut ctx, &mut notifications, 0.5);
+    assert!(notifications.is_empty());
+}
+
+#[test]
+fn clicking_a_toast_does_not_reach_the_window_below() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let mut notifications = Notifications::new();
+    notifications.push(Toast::new(ToastLevel::Info, "hello").sticky());
+
+    let run = |ctx: &mut imgui::Context, notifications: &mut Notifications| {
+        ctx.io_mut().set_delta_time(0.5);
+        let ui = ctx.frame();
+        let pressed = ui
+            .window("below")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([800.0, 600.0], imgui::Condition::Always)
+            .build(|| ui.invisible_button("covers everything", [800.0, 600.0]))
+            .unwrap();
+        notifications.draw(ui);
+        ctx.render();
+        pressed
+    };
+
+    run(&mut ctx, &mut notifications);
+    ctx.io_mut().add_mouse_pos_event([640.0, 580.0]);
+    assert!(!run(&mut ctx, &mut notifications));
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    assert!(!run(&mut ctx, &mut notifications));
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Left, false);
+    assert!(!run(&mut ctx, &mut notifications));
+    run(&mut ctx, &mut notifications);
+    assert!(notifications.is_empty());
+}
@@ -58,3 +58,47 @@ fn plot_widgets_validate_value_offsets() {
         );
     });
 }
+
+#[test]
+fn plot_getters_read_ring_buffers_and_propagate_panics() {
+    let _guard = test_guard();
+
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+
+    let ui = ctx.frame();
+    let ring = [3.0f32, 4.0, 1.0, 2.0];
+
+    let _ = ui.window("Plot getters").build(|| {
+        let mut read = Vec::new();
+        ui.plot_lines_getter_config("ring_lines", ring.len(), &mut |i| {
+            read.push(i);
+            ring[i]
+        })
+        .values_offset(2usize)
+        .scale_min(0.0)
+        .scale_max(4.0)
+        .build();
+        // Dear ImGui starts at the offset and wraps around.
+        assert_eq!(read.first(), Some(&2));
+        assert!(read.iter().all(|&i| i < ring.len()));
+
+        let mut calls = 0;
+        ui.plot_histogram_getter("ring_histogram", ring.len(), |i| {
+            calls += 1;
+            ring[i]
+        });
+        assert!(calls >= ring.len());
+
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            ui.plot_lines_getter("panicking_lines", 3, |_| panic!("getter failed"));
+        }));
+        assert!(panicked.is_err());
+    });
+}
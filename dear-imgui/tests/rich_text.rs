@@ -0,0 +1,92 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("rich text")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+#[test]
+fn rich_text_wraps_words_at_the_wrap_width() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let text = "some **bold** words and {#ff8000:colored} words that need several lines";
+    let (one_line, wrapped) = frame(&mut ctx, |ui| {
+        let start = ui.cursor_screen_pos();
+        ui.text_rich_config(text).wrap_width(1000.0).build();
+        let one_line = ui.cursor_screen_pos()[1] - start[1];
+
+        let start = ui.cursor_screen_pos();
+        ui.text_rich_config(text).wrap_width(80.0).build();
+        (one_line, ui.cursor_screen_pos()[1] - start[1])
+    });
+
+    assert!(wrapped >= one_line * 3.0, "{wrapped} vs {one_line}");
+}
+
+#[test]
+fn rich_text_reports_clicked_links() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let mut opened = Vec::new();
+    let mut clicked = None;
+    let link_pos = frame(&mut ctx, |ui| {
+        let pos = ui.cursor_screen_pos();
+        ui.text_rich("[docs](app://docs) and more");
+        pos
+    });
+
+    let events: [fn(&mut imgui::Io, [f32; 2]); 3] = [
+        |io, pos| io.add_mouse_pos_event(pos),
+        |io, _| io.add_mouse_button_event(imgui::MouseButton::Left, true),
+        |io, _| io.add_mouse_button_event(imgui::MouseButton::Left, false),
+    ];
+    for event in events {
+        event(ctx.io_mut(), [link_pos[0] + 4.0, link_pos[1] + 4.0]);
+        let result = frame(&mut ctx, |ui| {
+            ui.text_rich_config("[docs](app://docs) and more")
+                .on_link(|url| opened.push(url.to_owned()))
+                .build()
+        });
+        clicked = clicked.or(result);
+    }
+
+    assert_eq!(clicked.as_deref(), Some("app://docs"));
+    assert_eq!(opened, ["app://docs"]);
+}
+
+#[test]
+fn rich_text_renders_every_block_kind() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    frame(&mut ctx, |ui| {
+        let clicked = ui
+            .text_rich_config(
+                "# Title\n## Section\n### Detail\n\nParagraph with \\*literal\\* stars.\n\
+                 * {warn:warning}\n  - nested [link](https://example.com)\n---\n",
+            )
+            .color("warn", [1.0, 0.6, 0.0, 1.0])
+            .build();
+        assert_eq!(clicked, None);
+    });
+}
@@ -36,4 +36,5 @@ fn serde_keeps_unknown_bits_for_public_flag_wrappers() {
     assert_deserializes_without_truncating_unknown_bits!(imgui::TableColumnFlags);
     assert_deserializes_without_truncating_unknown_bits!(imgui::TableColumnStateFlags);
     assert_deserializes_without_truncating_unknown_bits!(imgui::TableRowFlags);
+    assert_deserializes_without_truncating_unknown_bits!(imgui::TooltipHoveredFlags);
 }
@@ -0,0 +1,99 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Action {
+    Save,
+    SaveAs,
+}
+
+fn ctrl(key: imgui::Key) -> imgui::KeyChord {
+    imgui::KeyChord::new(key).with_mods(imgui::KeyMods::CTRL)
+}
+
+fn registry() -> imgui::Shortcuts<Action> {
+    let global = imgui::ShortcutRoute::Global(imgui::ShortcutGlobalRouteFlags::NONE);
+    let mut shortcuts = imgui::Shortcuts::new();
+    shortcuts
+        .register_with(Action::Save, ctrl(imgui::Key::S), global, None)
+        .register_with(
+            Action::SaveAs,
+            ctrl(imgui::Key::S).with_mods(imgui::KeyMods::SHIFT),
+            global,
+            None,
+        );
+    shortcuts
+}
+
+fn poll(ctx: &mut imgui::Context, shortcuts: &mut imgui::Shortcuts<Action>) -> Vec<Action> {
+    let triggered = {
+        let ui = ctx.frame();
+        shortcuts.poll(ui).to_vec()
+    };
+    ctx.render();
+    triggered
+}
+
+#[test]
+fn shortcuts_report_triggered_actions() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let mut shortcuts = registry();
+
+    assert!(poll(&mut ctx, &mut shortcuts).is_empty());
+
+    ctx.io_mut().add_key_event(imgui::Key::ModCtrl, true);
+    ctx.io_mut().add_key_event(imgui::Key::S, true);
+    assert_eq!(poll(&mut ctx, &mut shortcuts), [Action::Save]);
+    assert!(shortcuts.triggered(&Action::Save));
+
+    // Held keys do not repeat without `ShortcutFlags::REPEAT`.
+    assert!(poll(&mut ctx, &mut shortcuts).is_empty());
+    assert!(!shortcuts.triggered(&Action::Save));
+
+    shortcuts.unregister(&Action::Save);
+    assert_eq!(shortcuts.bindings().len(), 1);
+    assert_eq!(shortcuts.chord(&Action::Save), None);
+}
+
+#[test]
+fn key_chord_names_follow_the_platform_convention() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let shortcuts = registry();
+
+    ctx.io_mut().set_config_macosx_behaviors(false);
+    {
+        let ui = ctx.frame();
+        assert_eq!(
+            shortcuts.display(ui, &Action::SaveAs).as_deref(),
+            Some("Ctrl+Shift+S")
+        );
+        assert_eq!(
+            ui.key_chord_name(imgui::KeyChord::new(imgui::Key::ModAlt)),
+            "Alt"
+        );
+    }
+    ctx.render();
+
+    ctx.io_mut().set_config_macosx_behaviors(true);
+    let ui = ctx.frame();
+    assert_eq!(
+        shortcuts.display(ui, &Action::Save).as_deref(),
+        Some("Cmd+S")
+    );
+    assert_eq!(
+        shortcuts.display(ui, &Action::SaveAs).as_deref(),
+        Some("Cmd+Shift+S")
+    );
+    let save_as = shortcuts.chord(&Action::SaveAs).unwrap();
+    assert_eq!(ui.key_chord_symbols(save_as), "⇧⌘S");
+}
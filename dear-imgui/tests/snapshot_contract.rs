@@ -260,3 +260,42 @@ fn snapshot_captures_texture_update_rects_as_tight_uploads() {
         other => panic!("expected update request, got {other:?}"),
     }
 }
+
+#[test]
+fn draw_data_snapshot_can_move_to_a_render_thread() {
+    let _guard = test_guard();
+
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    {
+        let ui = ctx.frame();
+        ui.get_foreground_draw_list()
+            .add_rect([0.0, 0.0], [16.0, 16.0], imgui::Color::WHITE)
+            .filled(true)
+            .build();
+    }
+    let draw_data = ctx.render();
+    let snapshot = draw_data
+        .snapshot(imgui::render::snapshot::SnapshotOptions::default())
+        .expect("snapshot should capture draw commands");
+    let expected_vtx = draw_data.total_vtx_count();
+
+    // Start the next frame while the "render thread" still holds the previous one.
+    {
+        let _ui = ctx.frame();
+    }
+    let _ = ctx.render();
+    let vtx_count = std::thread::spawn(move || {
+        snapshot
+            .draw
+            .draw_lists
+            .iter()
+            .map(|list| list.vtx.len())
+            .sum::<usize>()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(vtx_count, expected_vtx);
+    assert!(expected_vtx > 0);
+}
@@ -0,0 +1,113 @@
+use dear_imgui_rs as imgui;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("splitters")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+macro_rules! assert_panics {
+    ($body:block) => {
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $body)).is_err());
+    };
+}
+
+#[test]
+fn splitter_moves_space_between_panes_and_keeps_minimums() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let (mut a, mut b) = (100.0f32, 200.0f32);
+    let (center, cursor_before, cursor_after) = frame(&mut ctx, |ui| {
+        let before = ui.cursor_screen_pos();
+        ui.splitter(
+            "##split",
+            imgui::SplitterAxis::X,
+            &mut a,
+            &mut b,
+            20.0,
+            60.0,
+        );
+        let (min, max) = (ui.item_rect_min(), ui.item_rect_max());
+        (
+            [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5],
+            before,
+            ui.cursor_screen_pos(),
+        )
+    });
+    // The bar sits after the first pane and leaves the cursor where it was.
+    assert_eq!(center[0], cursor_before[0] + 100.0 + 2.0);
+    assert_eq!(cursor_before, cursor_after);
+
+    let split = |ui: &imgui::Ui, a: &mut f32, b: &mut f32| {
+        ui.splitter("##split", imgui::SplitterAxis::X, a, b, 20.0, 60.0)
+    };
+    ctx.io_mut().add_mouse_pos_event(center);
+    frame(&mut ctx, |ui| split(ui, &mut a, &mut b));
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, true);
+    frame(&mut ctx, |ui| split(ui, &mut a, &mut b));
+    ctx.io_mut()
+        .add_mouse_pos_event([center[0] + 50.0, center[1]]);
+    assert!(frame(&mut ctx, |ui| split(ui, &mut a, &mut b)));
+    assert_eq!((a, b), (150.0, 150.0));
+
+    // Dragging past the second pane's minimum stops at it.
+    ctx.io_mut()
+        .add_mouse_pos_event([center[0] + 200.0, center[1]]);
+    assert!(frame(&mut ctx, |ui| split(ui, &mut a, &mut b)));
+    assert_eq!((a, b), (240.0, 60.0));
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, false);
+    assert!(!frame(&mut ctx, |ui| split(ui, &mut a, &mut b)));
+}
+
+#[test]
+fn splitter_validates_its_sizes() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    frame(&mut ctx, |ui| {
+        let (mut a, mut b) = (100.0f32, 100.0f32);
+        assert!(
+            !ui.splitter_config("##y", imgui::SplitterAxis::Y)
+                .thickness(8.0)
+                .length(200.0)
+                .build(&mut a, &mut b)
+        );
+        assert_eq!(ui.item_rect_size(), [200.0, 8.0]);
+
+        assert_panics!({
+            ui.splitter_config("##thin", imgui::SplitterAxis::X)
+                .thickness(0.0)
+                .build(&mut a, &mut b);
+        });
+        assert_panics!({
+            ui.splitter(
+                "##negative",
+                imgui::SplitterAxis::X,
+                &mut a,
+                &mut b,
+                -1.0,
+                0.0,
+            );
+        });
+    });
+}
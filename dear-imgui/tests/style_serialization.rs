@@ -0,0 +1,61 @@
+#![cfg(feature = "serde")]
+
+use dear_imgui_rs as imgui;
+use serde::Deserialize;
+use serde::de::value::{Error as ValueError, StrDeserializer};
+
+#[test]
+fn serializable_style_round_trips_every_value() {
+    let mut ctx = imgui::Context::create();
+    let style = ctx.style_mut();
+    style.set_window_rounding(7.0);
+    style.set_item_spacing([3.0, 5.0]);
+    style.set_tree_lines_mode(imgui::TreeLineMode::TO_NODES);
+    style.set_color(imgui::StyleColor::Button, [0.1, 0.2, 0.3, 1.0]);
+
+    let saved = ctx.style().to_serializable();
+    assert_eq!(saved.colors.len(), imgui::StyleColor::COUNT);
+    assert_eq!(saved.window_rounding, Some(7.0));
+
+    let loaded = imgui::Style::from_serializable(&saved);
+    assert_eq!(loaded.to_serializable(), saved);
+    assert_eq!(
+        loaded.color(imgui::StyleColor::Button),
+        [0.1, 0.2, 0.3, 1.0]
+    );
+}
+
+#[test]
+fn serializable_style_diff_keeps_only_changed_values() {
+    let base = imgui::Style::from_serializable(&Default::default());
+    let mut themed = base.clone();
+    themed.set_frame_rounding(4.0);
+    themed.set_color(imgui::StyleColor::WindowBg, [0.0, 0.0, 0.0, 1.0]);
+
+    let diff = themed.to_serializable_diff(&base);
+    assert_eq!(
+        diff,
+        imgui::SerializableStyle {
+            frame_rounding: Some(4.0),
+            colors: vec![imgui::ColorOverride {
+                id: imgui::StyleColor::WindowBg,
+                rgba: [0.0, 0.0, 0.0, 1.0],
+            }],
+            ..Default::default()
+        }
+    );
+    assert_eq!(base.to_serializable_diff(&base), Default::default());
+
+    let mut applied = base.clone();
+    applied.apply_serializable(&diff);
+    assert!(applied == themed);
+}
+
+#[test]
+fn tree_line_mode_deserializes_from_its_name() {
+    let mode = imgui::TreeLineMode::deserialize(StrDeserializer::<ValueError>::new("ToNodes"));
+    assert_eq!(mode.unwrap(), imgui::TreeLineMode::TO_NODES);
+    assert!(
+        imgui::TreeLineMode::deserialize(StrDeserializer::<ValueError>::new("Dotted")).is_err()
+    );
+}
@@ -0,0 +1,110 @@
+use dear_imgui_rs as imgui;
+use imgui::{InputEvent, MouseButton, MouseSource};
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+/// Runs one frame of `f` in a fixed, undecorated window and returns what it returned.
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("synthetic input")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+#[test]
+fn pushed_mouse_events_keep_their_source() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    ctx.io_mut().push_input_events(&[
+        InputEvent::MousePos {
+            pos: [30.0, 40.0],
+            source: MouseSource::Pen,
+        },
+        InputEvent::MouseButton {
+            button: MouseButton::Left,
+            down: true,
+            source: MouseSource::Pen,
+        },
+    ]);
+    frame(&mut ctx, |_| ());
+
+    let io = ctx.io();
+    assert_eq!(io.mouse_pos(), [30.0, 40.0]);
+    assert!(io.mouse_down(MouseButton::Left));
+    assert_eq!(io.mouse_source(), MouseSource::Pen);
+}
+
+#[test]
+fn pushed_clicks_and_text_drive_widgets() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let mut text = String::new();
+    let center = frame(&mut ctx, |ui| {
+        ui.input_text("##field", &mut text).build();
+        let (min, max) = (ui.item_rect_min(), ui.item_rect_max());
+        [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5]
+    });
+
+    let source = MouseSource::Mouse;
+    let events = [
+        InputEvent::MousePos {
+            pos: center,
+            source,
+        },
+        InputEvent::MouseButton {
+            button: MouseButton::Left,
+            down: true,
+            source,
+        },
+        InputEvent::MouseButton {
+            button: MouseButton::Left,
+            down: false,
+            source,
+        },
+        InputEvent::Text("hi".to_owned()),
+    ];
+    ctx.io_mut().push_input_events(&events);
+    // Dear ImGui trickles the press, release, and text over separate frames.
+    for _ in 0..4 {
+        frame(&mut ctx, |ui| {
+            ui.input_text("##field", &mut text).build();
+        });
+    }
+    assert_eq!(text, "hi");
+}
+
+#[test]
+fn cleared_event_queue_drops_pending_events() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    ctx.io_mut().push_input_event(&InputEvent::MouseButton {
+        button: MouseButton::Left,
+        down: true,
+        source: MouseSource::Mouse,
+    });
+    ctx.io_mut().clear_events_queue();
+    frame(&mut ctx, |_| ());
+    assert!(!ctx.io().mouse_down(MouseButton::Left));
+
+    ctx.io_mut().set_app_accepting_events(false);
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    frame(&mut ctx, |_| ());
+    assert!(!ctx.io().mouse_down(MouseButton::Left));
+}
@@ -0,0 +1,63 @@
+use dear_imgui_rs as imgui;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("links")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+fn docs_link(ui: &imgui::Ui) -> bool {
+    ui.text_link_open_url("Documentation", "https://docs.rs/dear-imgui-rs")
+}
+
+#[test]
+fn text_link_open_url_goes_through_the_handler() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+
+    let opened = Rc::new(RefCell::new(Vec::new()));
+    let log = Rc::clone(&opened);
+    ctx.set_open_in_shell_handler(move |url| {
+        log.borrow_mut().push(url.to_owned());
+        true
+    });
+
+    let center = frame(&mut ctx, |ui| {
+        docs_link(ui);
+        let (min, max) = (ui.item_rect_min(), ui.item_rect_max());
+        [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5]
+    });
+    ctx.io_mut().add_mouse_pos_event(center);
+    assert!(!frame(&mut ctx, docs_link));
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, true);
+    assert!(!frame(&mut ctx, docs_link));
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, false);
+    assert!(frame(&mut ctx, docs_link));
+
+    assert_eq!(*opened.borrow(), ["https://docs.rs/dear-imgui-rs"]);
+
+    ctx.clear_open_in_shell_handler();
+    // The handler and its captured log are released.
+    assert_eq!(Rc::strong_count(&opened), 1);
+}
@@ -0,0 +1,203 @@
+use dear_imgui_rs as imgui;
+use imgui::{TreeView, TreeViewRow, TreeViewSource};
+use std::borrow::Cow;
+
+fn prepare_context(ctx: &mut imgui::Context) {
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+}
+
+fn frame<R>(ctx: &mut imgui::Context, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("tree view")
+            .position([0.0, 0.0], imgui::Condition::Always)
+            .size([400.0, 300.0], imgui::Condition::Always)
+            .flags(imgui::WindowFlags::NO_DECORATION)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+/// Roots `0..roots`; node `n < 1000` has `fanout` children `(n + 1) * 1000 + i`.
+struct Numbers {
+    roots: u32,
+    fanout: u32,
+    children_calls: usize,
+}
+
+impl Numbers {
+    fn new(roots: u32, fanout: u32) -> Self {
+        Self {
+            roots,
+            fanout,
+            children_calls: 0,
+        }
+    }
+}
+
+impl TreeViewSource for Numbers {
+    type Key = u32;
+
+    fn roots(&mut self, out: &mut Vec<u32>) {
+        out.extend(0..self.roots);
+    }
+
+    fn children(&mut self, parent: u32, out: &mut Vec<u32>) {
+        self.children_calls += 1;
+        out.extend((0..self.fanout).map(|i| (parent + 1) * 1000 + i));
+    }
+
+    fn has_children(&self, key: u32) -> bool {
+        key < 1000
+    }
+
+    fn label(&self, key: u32) -> Cow<'_, str> {
+        format!("Node {key}").into()
+    }
+}
+
+fn draw(
+    ctx: &mut imgui::Context,
+    tree: &mut TreeView<u32>,
+    source: &mut Numbers,
+) -> Vec<TreeViewRow<u32>> {
+    frame(ctx, |ui| {
+        let mut rows = Vec::new();
+        tree.draw(ui, source, |_, row| rows.push(*row));
+        rows
+    })
+}
+
+#[test]
+fn tree_view_expands_lazily() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let mut tree = TreeView::new();
+    let mut source = Numbers::new(3, 2);
+
+    let rows = draw(&mut ctx, &mut tree, &mut source);
+    assert_eq!(rows.iter().map(|r| r.key).collect::<Vec<_>>(), [0, 1, 2]);
+    assert!(rows.iter().all(|r| r.depth == 0 && !r.open && !r.selected));
+    assert_eq!(source.children_calls, 0);
+
+    tree.set_open(1, true);
+    let rows = draw(&mut ctx, &mut tree, &mut source);
+    assert_eq!(tree.rows(), [0, 1, 2000, 2001, 2]);
+    assert_eq!(rows[1].key, 1);
+    assert!(rows[1].open);
+    assert_eq!((rows[2].depth, rows[2].index), (1, 2));
+    assert_eq!(source.children_calls, 1);
+
+    // Children are cached across collapse and expand until invalidated.
+    tree.set_open(1, false);
+    draw(&mut ctx, &mut tree, &mut source);
+    assert_eq!(tree.rows(), [0, 1, 2]);
+    tree.set_open(1, true);
+    draw(&mut ctx, &mut tree, &mut source);
+    assert_eq!(source.children_calls, 1);
+    tree.invalidate_node(1);
+    draw(&mut ctx, &mut tree, &mut source);
+    assert_eq!(source.children_calls, 2);
+}
+
+#[test]
+fn tree_view_selects_clicked_rows() {
+    for multi_select in [true, false] {
+        let mut ctx = imgui::Context::create();
+        prepare_context(&mut ctx);
+        let mut tree = TreeView::new();
+        if !multi_select {
+            tree.set_multi_select(None);
+        }
+        let mut source = Numbers::new(3, 2);
+
+        let mut center = [0.0; 2];
+        frame(&mut ctx, |ui| {
+            tree.draw(ui, &mut source, |ui, row| {
+                if row.key == 1 {
+                    let (min, max) = (ui.item_rect_min(), ui.item_rect_max());
+                    center = [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5];
+                }
+            });
+        });
+
+        ctx.io_mut().add_mouse_pos_event(center);
+        draw(&mut ctx, &mut tree, &mut source);
+        ctx.io_mut()
+            .add_mouse_button_event(imgui::MouseButton::Left, true);
+        draw(&mut ctx, &mut tree, &mut source);
+        ctx.io_mut()
+            .add_mouse_button_event(imgui::MouseButton::Left, false);
+        draw(&mut ctx, &mut tree, &mut source);
+
+        assert_eq!(tree.selection().iter().copied().collect::<Vec<_>>(), [1]);
+        // A click on the label selects without expanding.
+        assert!(!tree.is_open(1));
+        let rows = draw(&mut ctx, &mut tree, &mut source);
+        assert!(rows[1].selected);
+    }
+}
+
+fn click_row(ctx: &mut imgui::Context, tree: &mut TreeView<u32>, source: &mut Numbers, key: u32) {
+    let mut center = [0.0; 2];
+    frame(ctx, |ui| {
+        tree.draw(ui, source, |ui, row| {
+            if row.key == key {
+                let (min, max) = (ui.item_rect_min(), ui.item_rect_max());
+                center = [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5];
+            }
+        });
+    });
+    ctx.io_mut().add_mouse_pos_event(center);
+    draw(ctx, tree, source);
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, true);
+    draw(ctx, tree, source);
+    ctx.io_mut()
+        .add_mouse_button_event(imgui::MouseButton::Left, false);
+    draw(ctx, tree, source);
+}
+
+#[test]
+fn tree_view_range_anchor_follows_its_node() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let mut tree = TreeView::new();
+    let mut source = Numbers::new(5, 2);
+
+    click_row(&mut ctx, &mut tree, &mut source, 3);
+    // Expanding a node above the anchor shifts its row from 3 to 5.
+    tree.set_open(0, true);
+    draw(&mut ctx, &mut tree, &mut source);
+    assert_eq!(tree.rows(), [0, 1000, 1001, 1, 2, 3, 4]);
+
+    ctx.io_mut().add_key_event(imgui::Key::ModShift, true);
+    click_row(&mut ctx, &mut tree, &mut source, 4);
+    ctx.io_mut().add_key_event(imgui::Key::ModShift, false);
+
+    let mut selection: Vec<_> = tree.selection().iter().copied().collect();
+    selection.sort();
+    assert_eq!(selection, [3, 4]);
+}
+
+#[test]
+fn tree_view_submits_only_visible_rows() {
+    let mut ctx = imgui::Context::create();
+    prepare_context(&mut ctx);
+    let mut tree = TreeView::new();
+    let mut source = Numbers::new(1, 100_000);
+
+    tree.set_open(0, true);
+    let rows = draw(&mut ctx, &mut tree, &mut source);
+    assert_eq!(tree.rows().len(), 100_001);
+    assert!(rows.len() < 100, "{} rows submitted", rows.len());
+    assert_eq!(rows[0].key, 0);
+    assert_eq!(rows[1].depth, 1);
+}
@@ -144,6 +144,7 @@ fn main() {
         redraw: RedrawMode::Poll,
         io_config_flags: None,
         theme: Some(Theme::Dark),
+        ..Default::default()
     };
 
     let addons_cfg = AddOnsConfig::default();
@@ -0,0 +1,181 @@
+//! Dear ImGui on the CPU: the tiny-skia renderer presenting through softbuffer
+//!
+//! No GPU API is used, so this runs on machines without usable graphics drivers (VMs, remote
+//! desktops). Each frame is rasterized into a `tiny_skia::Pixmap` and copied into the window's
+//! softbuffer surface.
+
+use std::{num::NonZeroU32, sync::Arc, time::Instant};
+
+use dear_imgui_rs::*;
+use dear_imgui_tiny_skia::{TinySkiaRenderer, copy_to_softbuffer, tiny_skia};
+use dear_imgui_winit::WinitPlatform;
+use winit::{
+    application::ApplicationHandler,
+    dpi::LogicalSize,
+    event::WindowEvent,
+    event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
+    keyboard::{Key, NamedKey},
+    window::{Window, WindowId},
+};
+
+struct AppWindow {
+    window: Arc<Window>,
+    surface: softbuffer::Surface<Arc<Window>, Arc<Window>>,
+    context: Context,
+    platform: WinitPlatform,
+    renderer: TinySkiaRenderer,
+    pixmap: Option<tiny_skia::Pixmap>,
+    clear_color: [f32; 4],
+    demo_open: bool,
+    last_frame: Instant,
+}
+
+#[derive(Default)]
+struct App {
+    window: Option<AppWindow>,
+}
+
+impl AppWindow {
+    fn new(event_loop: &ActiveEventLoop) -> Result<Self, Box<dyn std::error::Error>> {
+        let window_attributes = Window::default_attributes()
+            .with_title("Dear ImGui tiny-skia + softbuffer")
+            .with_inner_size(LogicalSize::new(1280.0, 720.0));
+        let window = Arc::new(event_loop.create_window(window_attributes)?);
+
+        let softbuffer_context = softbuffer::Context::new(window.clone())?;
+        let surface = softbuffer::Surface::new(&softbuffer_context, window.clone())?;
+
+        let mut context = Context::create();
+        context.set_ini_filename(None::<String>).unwrap();
+        let mut platform = WinitPlatform::new(&mut context);
+        platform.attach_window(&window, dear_imgui_winit::HiDpiMode::Default, &mut context);
+        let renderer = TinySkiaRenderer::new(&mut context);
+
+        Ok(Self {
+            window,
+            surface,
+            context,
+            platform,
+            renderer,
+            pixmap: None,
+            clear_color: [0.1, 0.2, 0.3, 1.0],
+            demo_open: true,
+            last_frame: Instant::now(),
+        })
+    }
+
+    fn render(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let size = self.window.inner_size();
+        let (Some(width), Some(height)) =
+            (NonZeroU32::new(size.width), NonZeroU32::new(size.height))
+        else {
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        self.context
+            .io_mut()
+            .set_delta_time((now - self.last_frame).as_secs_f32());
+        self.last_frame = now;
+
+        self.platform.prepare_frame(&self.window, &mut self.context);
+        let ui = self.context.frame();
+        ui.window("Hello, tiny-skia!")
+            .size([400.0, 200.0], Condition::FirstUseEver)
+            .build(|| {
+                ui.text("Rendered on the CPU, presented with softbuffer.");
+                ui.text(format!(
+                    "{:.3} ms/frame ({:.1} FPS)",
+                    1000.0 / ui.io().framerate(),
+                    ui.io().framerate()
+                ));
+                ui.color_edit4("Clear color", &mut self.clear_color);
+                if ui.button("Show Demo Window") {
+                    self.demo_open = true;
+                }
+            });
+        if self.demo_open {
+            ui.show_demo_window(&mut self.demo_open);
+        }
+        self.platform.prepare_render_with_ui(ui, &self.window);
+        let draw_data = self.context.render();
+
+        // Reuse the pixmap while the window size stays the same.
+        if self
+            .pixmap
+            .as_ref()
+            .is_none_or(|pixmap| pixmap.width() != width.get() || pixmap.height() != height.get())
+        {
+            self.pixmap = Some(
+                tiny_skia::Pixmap::new(width.get(), height.get()).ok_or("invalid pixmap size")?,
+            );
+        }
+        let pixmap = self.pixmap.as_mut().expect("pixmap created above");
+        let [r, g, b, a] = self.clear_color;
+        pixmap.fill(tiny_skia::Color::from_rgba(r, g, b, a).unwrap_or(tiny_skia::Color::BLACK));
+        self.renderer.render(draw_data, &mut pixmap.as_mut())?;
+
+        self.surface.resize(width, height)?;
+        let mut buffer = self.surface.buffer_mut()?;
+        copy_to_softbuffer(&pixmap.as_ref(), &mut buffer);
+        buffer.present()?;
+        Ok(())
+    }
+}
+
+impl ApplicationHandler for App {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.window.is_none() {
+            match AppWindow::new(event_loop) {
+                Ok(window) => {
+                    window.window.request_redraw();
+                    self.window = Some(window);
+                }
+                Err(e) => {
+                    eprintln!("Failed to create window: {e}");
+                    event_loop.exit();
+                }
+            }
+        }
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        _window_id: WindowId,
+        event: WindowEvent,
+    ) {
+        let Some(window) = self.window.as_mut() else {
+            return;
+        };
+        window
+            .platform
+            .handle_window_event(&mut window.context, &window.window, &event);
+
+        match event {
+            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::KeyboardInput { event, .. }
+                if event.logical_key == Key::Named(NamedKey::Escape) =>
+            {
+                event_loop.exit();
+            }
+            WindowEvent::RedrawRequested => {
+                if let Err(e) = window.render() {
+                    eprintln!("Render error: {e}");
+                }
+                window.window.request_redraw();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn main() {
+    env_logger::init();
+
+    let event_loop = EventLoop::new().unwrap();
+    event_loop.set_control_flow(ControlFlow::Poll);
+
+    let mut app = App::default();
+    event_loop.run_app(&mut app).unwrap();
+}
@@ -100,6 +100,7 @@ fn main() {
         redraw: RedrawMode::Poll,
         io_config_flags: None,
         theme: Some(dear_app::Theme::Dark),
+        ..Default::default()
     };
 
     // Enable add-ons compiled into dear-app via features
@@ -37,6 +37,16 @@ pub enum CoreEvent {
         /// Modifier keys used by selection semantics.
         modifiers: Modifiers,
     },
+    /// Move focus to the first entry (Home).
+    FocusFirst {
+        /// Modifier keys used by selection semantics.
+        modifiers: Modifiers,
+    },
+    /// Move focus to the last entry (End).
+    FocusLast {
+        /// Modifier keys used by selection semantics.
+        modifiers: Modifiers,
+    },
     /// Click an entry row/cell.
     ClickEntry {
         /// Entry identity in current view.
@@ -671,6 +681,11 @@ pub struct FileDialogCore {
     pub click_action: ClickAction,
     /// Search query to filter entries by substring (case-insensitive).
     pub search: String,
+    /// Glob pattern typed into the filename field (e.g. `*.rs`, `render_*`).
+    ///
+    /// Only applied when it contains `*` or `?`; matches file names case-insensitively on top of
+    /// the active filter. Directories stay visible so the user can keep navigating.
+    pub name_filter: String,
     /// Current sort column.
     pub sort_by: SortBy,
     /// Sort order flag (true = ascending).
@@ -726,6 +741,9 @@ enum FilterSelectionMode {
     Manual,
 }
 
+/// Maximum number of recently visited directories kept per dialog.
+const NAV_RECENT_MAX: usize = 24;
+
 impl FileDialogCore {
     /// Creates a new dialog core for a mode.
     pub fn new(mode: DialogMode) -> Self {
@@ -741,6 +759,7 @@ impl FileDialogCore {
             filter_selection_mode: FilterSelectionMode::AutoFirst,
             click_action: ClickAction::Select,
             search: String::new(),
+            name_filter: String::new(),
             sort_by: SortBy::Name,
             sort_ascending: true,
             sort_mode: SortMode::default(),
@@ -792,6 +811,22 @@ impl FileDialogCore {
         self.nav_recent.iter()
     }
 
+    /// Replaces recently visited directories (most recent first), e.g. to restore a list
+    /// persisted from [`FileDialogCore::recent_paths`] across sessions.
+    ///
+    /// Duplicates are dropped and only the first 24 paths are kept.
+    pub fn set_recent_paths(&mut self, paths: impl IntoIterator<Item = PathBuf>) {
+        self.nav_recent.clear();
+        for path in paths {
+            if self.nav_recent.len() >= NAV_RECENT_MAX {
+                break;
+            }
+            if !self.nav_recent.contains(&path) {
+                self.nav_recent.push_back(path);
+            }
+        }
+    }
+
     fn push_nav_back(&mut self, cwd: PathBuf) {
         const NAV_HISTORY_MAX: usize = 64;
 
@@ -857,8 +892,6 @@ impl FileDialogCore {
     }
 
     fn record_recent_cwd(&mut self) {
-        const NAV_RECENT_MAX: usize = 24;
-
         let cwd = self.cwd.clone();
         if self.nav_recent.front() == Some(&cwd) {
             return;
@@ -958,6 +991,14 @@ impl FileDialogCore {
                 self.move_focus(delta, modifiers);
                 CoreEventOutcome::None
             }
+            CoreEvent::FocusFirst { modifiers } => {
+                self.move_focus_to_index(0, modifiers);
+                CoreEventOutcome::None
+            }
+            CoreEvent::FocusLast { modifiers } => {
+                self.move_focus_to_index(usize::MAX, modifiers);
+                CoreEventOutcome::None
+            }
             CoreEvent::ClickEntry { id, modifiers } => {
                 self.click_entry(id, modifiers);
                 CoreEventOutcome::None
@@ -1198,6 +1239,7 @@ impl FileDialogCore {
             &self.filters,
             self.active_filter,
             &self.search,
+            &self.name_filter,
         );
         let type_dots_to_extract = igfd_type_dots_to_extract(self.active_filter());
         sort_entries_in_place(
@@ -1480,8 +1522,16 @@ impl FileDialogCore {
                 }
             }
         };
+        self.move_focus_to_index(next_idx, modifiers);
+    }
+
+    /// Moves keyboard focus to `index` in the current view, clamped to the last entry.
+    pub(crate) fn move_focus_to_index(&mut self, index: usize, modifiers: Modifiers) {
+        if self.view_ids.is_empty() {
+            return;
+        }
 
-        let target_id = self.view_ids[next_idx];
+        let target_id = self.view_ids[index.min(self.view_ids.len() - 1)];
         if modifiers.shift {
             let anchor_id = self
                 .selection_anchor_id
@@ -1640,6 +1690,11 @@ impl FileDialogCore {
             && selected_entries.is_empty()
         {
             if let Some(typed) = typed_footer_name.map(str::trim) {
+                if is_name_glob(typed) {
+                    return Err(FileDialogError::InvalidPath(format!(
+                        "'{typed}' is a filter pattern, not a file name"
+                    )));
+                }
                 if !typed.is_empty() {
                     let raw = PathBuf::from(typed);
                     let raw = if raw.is_absolute() {
@@ -1671,6 +1726,13 @@ impl FileDialogCore {
             }
         }
 
+        if matches!(self.mode, DialogMode::SaveFile) && is_name_glob(&self.save_name) {
+            return Err(FileDialogError::InvalidPath(format!(
+                "'{}' is a filter pattern, not a file name",
+                self.save_name.trim()
+            )));
+        }
+
         let sel = finalize_selection(
             self.mode,
             &self.cwd,
@@ -1808,6 +1870,7 @@ struct ViewKey {
     mode: DialogMode,
     show_hidden: bool,
     search: String,
+    name_filter: String,
     sort_by: SortBy,
     sort_ascending: bool,
     sort_mode: SortMode,
@@ -1822,6 +1885,7 @@ impl ViewKey {
             mode: core.mode,
             show_hidden: core.show_hidden,
             search: core.search.clone(),
+            name_filter: core.name_filter.clone(),
             sort_by: core.sort_by,
             sort_ascending: core.sort_ascending,
             sort_mode: core.sort_mode,
@@ -1959,6 +2023,11 @@ fn full_extension_lower(name_lower: &str) -> &str {
     name_lower.find('.').map(|i| &name_lower[i..]).unwrap_or("")
 }
 
+/// Returns true if filename-field text is a glob pattern rather than a file name.
+pub(crate) fn is_name_glob(text: &str) -> bool {
+    text.contains(['*', '?'])
+}
+
 fn wildcard_match(pattern: &str, text: &str) -> bool {
     // Basic glob matcher supporting `*` and `?`.
     //
@@ -2130,6 +2199,7 @@ fn filter_entries_in_place(
     filters: &[FileFilter],
     active_filter: Option<usize>,
     search: &str,
+    name_filter: &str,
 ) {
     let display_filters = effective_filters(filters, active_filter);
     let matchers = compile_filter_matchers(&display_filters);
@@ -2138,6 +2208,11 @@ fn filter_entries_in_place(
     } else {
         Some(search.to_lowercase())
     };
+    let name_glob_lower = if is_name_glob(name_filter) {
+        Some(name_filter.trim().to_lowercase())
+    } else {
+        None
+    };
     entries.retain(|e| {
         if !show_hidden && e.name.starts_with('.') {
             return false;
@@ -2151,7 +2226,11 @@ fn filter_entries_in_place(
             None => true,
             Some(q) => e.name.to_lowercase().contains(q),
         };
-        pass_kind && pass_search
+        let pass_name_glob = match &name_glob_lower {
+            None => true,
+            Some(g) => e.is_dir || wildcard_match(g, &e.name.to_lowercase()),
+        };
+        pass_kind && pass_search && pass_name_glob
     });
 }
 
@@ -2561,6 +2640,27 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn set_recent_paths_restores_deduplicated_capped_history() {
+        let mut core = FileDialogCore::new(DialogMode::OpenFile);
+        let a = PathBuf::from("/tmp").join("a");
+        let b = PathBuf::from("/tmp").join("b");
+        core.set_recent_paths([a.clone(), b.clone(), a.clone()]);
+        assert_eq!(
+            core.recent_paths().cloned().collect::<Vec<_>>(),
+            vec![a.clone(), b.clone()]
+        );
+
+        let _ = core.handle_event(CoreEvent::NavigateTo(b.clone()));
+        assert_eq!(
+            core.recent_paths().cloned().collect::<Vec<_>>(),
+            vec![b.clone(), a.clone()]
+        );
+
+        core.set_recent_paths((0..40).map(|i| PathBuf::from("/tmp").join(i.to_string())));
+        assert_eq!(core.recent_paths().count(), NAV_RECENT_MAX);
+    }
+
     #[test]
     fn navigation_history_back_forward_tracks_and_clears_forward() {
         let mut core = FileDialogCore::new(DialogMode::OpenFile);
@@ -2852,6 +2952,26 @@ mod tests {
         assert_eq!(core.focused_entry_id(), Some(entry_id(&core, "d")));
     }
 
+    #[test]
+    fn handle_event_focus_first_and_last_jump_to_view_edges() {
+        let mut core = FileDialogCore::new(DialogMode::OpenFiles);
+        core.allow_multi = true;
+        set_view_files(&mut core, &["a", "b", "c", "d"]);
+
+        let outcome = core.handle_event(CoreEvent::FocusFirst {
+            modifiers: mods(false, false),
+        });
+        assert_eq!(outcome, CoreEventOutcome::None);
+        assert_eq!(core.focused_entry_id(), Some(entry_id(&core, "a")));
+
+        core.click_entry(entry_id(&core, "b"), mods(false, false));
+        let _ = core.handle_event(CoreEvent::FocusLast {
+            modifiers: mods(false, true),
+        });
+        assert_eq!(selected_entry_names(&core), vec!["b", "c", "d"]);
+        assert_eq!(core.focused_entry_id(), Some(entry_id(&core, "d")));
+    }
+
     #[test]
     fn handle_event_activate_focused_requests_confirm() {
         let mut core = FileDialogCore::new(DialogMode::OpenFile);
@@ -2939,6 +3059,62 @@ mod tests {
         assert_eq!(sel.paths, vec![PathBuf::from("/tmp/a.txt")]);
     }
 
+    #[test]
+    fn open_footer_typed_glob_is_rejected_as_file_name() {
+        let mut core = FileDialogCore::new(DialogMode::OpenFile);
+        core.cwd = PathBuf::from("/tmp");
+
+        let gate = ConfirmGate::default();
+        let err = core
+            .confirm(&TestFs::default(), &gate, Some("*.rs"))
+            .unwrap_err();
+        assert!(matches!(err, FileDialogError::InvalidPath(_)));
+        assert!(core.take_result().is_none());
+    }
+
+    #[test]
+    fn name_filter_glob_filters_files_and_keeps_directories() {
+        let entry = |name: &str, is_dir: bool| crate::fs::FsEntry {
+            name: name.into(),
+            path: PathBuf::from("/tmp").join(name),
+            is_dir,
+            is_symlink: false,
+            size: None,
+            modified: None,
+        };
+        let fs = TestFs {
+            entries: vec![
+                entry("main.rs", false),
+                entry("Render_Pass.RS", false),
+                entry("render_notes.txt", false),
+                entry("src", true),
+            ],
+            ..Default::default()
+        };
+
+        let mut core = FileDialogCore::new(DialogMode::OpenFile);
+        core.cwd = PathBuf::from("/tmp");
+        let names = |core: &FileDialogCore| {
+            let mut names: Vec<_> = core.entries().iter().map(|e| e.name.clone()).collect();
+            names.sort();
+            names
+        };
+
+        core.name_filter = "*.rs".into();
+        core.rescan_if_needed(&fs);
+        assert_eq!(names(&core), ["Render_Pass.RS", "main.rs", "src"]);
+
+        core.name_filter = "render_*".into();
+        core.rescan_if_needed(&fs);
+        assert_eq!(names(&core), ["Render_Pass.RS", "render_notes.txt", "src"]);
+
+        // Plain names are file names, not patterns.
+        core.name_filter = "main.rs".into();
+        core.rescan_if_needed(&fs);
+        assert_eq!(core.entries().len(), 4);
+        assert_eq!(fs.read_dir_calls.get(), 1);
+    }
+
     #[test]
     fn open_footer_typed_directory_navigates_instead_of_confirming() {
         let mut core = FileDialogCore::new(DialogMode::OpenFile);
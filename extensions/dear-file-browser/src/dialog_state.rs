@@ -696,6 +696,8 @@ pub(crate) struct FileDialogUiRuntime {
     pub(crate) type_select_buffer: String,
     /// Last keypress timestamp used to expire the type-to-select prefix.
     pub(crate) type_select_last_input: Option<std::time::Instant>,
+    /// Scroll the focused entry into view on next draw if it is off-screen (keyboard navigation).
+    pub(crate) focus_reveal_next: bool,
     /// Breadcrumb runtime state.
     pub(crate) breadcrumb: BreadcrumbUiRuntime,
     /// Footer runtime state.
@@ -1139,6 +1141,7 @@ mod tests {
         assert!(state.config.type_select_enabled);
         assert!(state.runtime.type_select_buffer.is_empty());
         assert!(state.runtime.type_select_last_input.is_none());
+        assert!(!state.runtime.focus_reveal_next);
         assert!(!state.operations.new_folder.inline_active);
         assert!(!state.operations.new_folder.open_next);
         assert!(state.operations.new_folder.name.is_empty());
@@ -479,18 +479,14 @@ fn draw_file_table_view(
                     state.ui.operations.paste.job = None;
                 }
             }
-            if ui.is_key_pressed_with_repeat(Key::UpArrow, true) {
-                let _ = state.core.handle_event(CoreEvent::MoveFocus {
-                    delta: -1,
-                    modifiers,
-                });
-            }
-            if ui.is_key_pressed_with_repeat(Key::DownArrow, true) {
-                let _ = state.core.handle_event(CoreEvent::MoveFocus {
-                    delta: 1,
-                    modifiers,
-                });
-            }
+            let row_h = if show_preview {
+                state.ui.config.thumbnail_size[1].max(ui.text_line_height_with_spacing())
+            } else {
+                ui.text_line_height_with_spacing()
+            };
+            // One row of the visible height is taken by the header.
+            let page = ((size[1] / row_h).floor() as i32 - 1).max(1);
+            handle_focus_keys(ui, state, modifiers, 1, page);
             if state.ui.config.type_select_enabled && !modifiers.ctrl && !modifiers.shift {
                 handle_type_select(ui, state);
             }
@@ -634,7 +630,14 @@ fn draw_file_table_view(
                 ui.set_scroll_here_y(0.5);
                 state.ui.operations.reveal_id_next = None;
             }
+            if state.ui.runtime.focus_reveal_next && state.core.focused_entry_id() == Some(e.id) {
+                if !ui.is_item_visible() {
+                    ui.set_scroll_here_y(0.5);
+                }
+                state.ui.runtime.focus_reveal_next = false;
+            }
         }
+        state.ui.runtime.focus_reveal_next = false;
 
         if let Some(_popup) = ui.begin_popup_context_window() {
             draw_file_list_window_context_menu(ui, state, fs, has_thumbnail_backend);
@@ -750,25 +753,17 @@ fn draw_file_grid_view(
                         delta: -1,
                         modifiers,
                     });
+                    state.ui.runtime.focus_reveal_next = true;
                 }
                 if ui.is_key_pressed_with_repeat(Key::RightArrow, true) {
                     let _ = state.core.handle_event(CoreEvent::MoveFocus {
                         delta: 1,
                         modifiers,
                     });
+                    state.ui.runtime.focus_reveal_next = true;
                 }
-                if ui.is_key_pressed_with_repeat(Key::UpArrow, true) {
-                    let _ = state.core.handle_event(CoreEvent::MoveFocus {
-                        delta: -(cols as i32),
-                        modifiers,
-                    });
-                }
-                if ui.is_key_pressed_with_repeat(Key::DownArrow, true) {
-                    let _ = state.core.handle_event(CoreEvent::MoveFocus {
-                        delta: cols as i32,
-                        modifiers,
-                    });
-                }
+                let page_rows = ((size[1] / cell_h).floor() as i32).max(1);
+                handle_focus_keys(ui, state, modifiers, cols as i32, page_rows * cols as i32);
                 if state.ui.config.type_select_enabled && !modifiers.ctrl && !modifiers.shift {
                     handle_type_select(ui, state);
                 }
@@ -809,6 +804,12 @@ fn draw_file_grid_view(
                         ui.set_scroll_here_y(0.5);
                         state.ui.operations.reveal_id_next = None;
                     }
+                    if state.ui.runtime.focus_reveal_next && focused {
+                        if !ui.is_item_visible() {
+                            ui.set_scroll_here_y(0.5);
+                        }
+                        state.ui.runtime.focus_reveal_next = false;
+                    }
 
                     if state.ui.config.thumbnails_enabled && !e.is_dir {
                         let max_size_u32 = [thumb[0].max(1.0) as u32, thumb[1].max(1.0) as u32];
@@ -925,6 +926,8 @@ fn draw_file_grid_view(
                     }
                 }
             }
+            state.ui.runtime.focus_reveal_next = false;
+
             if let Some(_popup) = ui.begin_popup_context_window() {
                 draw_file_list_window_context_menu(ui, state, fs, has_thumbnail_backend);
             }
@@ -967,6 +970,39 @@ fn draw_thumbnail_cell(ui: &Ui, state: &mut FileDialogState, e: &DirEntry) {
     }
 }
 
+/// Moves keyboard focus for Up/Down by `row` entries, PageUp/PageDown by `page` entries and
+/// Home/End to the first/last entry, then scrolls the focused entry into view.
+fn handle_focus_keys(
+    ui: &Ui,
+    state: &mut FileDialogState,
+    modifiers: Modifiers,
+    row: i32,
+    page: i32,
+) {
+    let steps = [
+        (Key::UpArrow, -row),
+        (Key::DownArrow, row),
+        (Key::PageUp, -page),
+        (Key::PageDown, page),
+    ];
+    for (key, delta) in steps {
+        if ui.is_key_pressed_with_repeat(key, true) {
+            let _ = state
+                .core
+                .handle_event(CoreEvent::MoveFocus { delta, modifiers });
+            state.ui.runtime.focus_reveal_next = true;
+        }
+    }
+    if ui.is_key_pressed(Key::Home) {
+        let _ = state.core.handle_event(CoreEvent::FocusFirst { modifiers });
+        state.ui.runtime.focus_reveal_next = true;
+    }
+    if ui.is_key_pressed(Key::End) {
+        let _ = state.core.handle_event(CoreEvent::FocusLast { modifiers });
+        state.ui.runtime.focus_reveal_next = true;
+    }
+}
+
 fn handle_type_select(ui: &Ui, state: &mut FileDialogState) {
     if !state.ui.config.type_select_enabled {
         return;
@@ -994,6 +1030,7 @@ fn handle_type_select(ui: &Ui, state: &mut FileDialogState) {
     let _ = state.core.handle_event(CoreEvent::SelectByPrefix(
         state.ui.runtime.type_select_buffer.clone(),
     ));
+    state.ui.runtime.focus_reveal_next = true;
 }
 
 fn collect_type_select_char(ui: &Ui) -> Option<char> {
@@ -1,5 +1,5 @@
 use crate::core::DialogMode;
-use crate::dialog_core::{ConfirmGate, CoreEvent, CoreEventOutcome, ScanStatus};
+use crate::dialog_core::{ConfirmGate, CoreEvent, CoreEventOutcome, ScanStatus, is_name_glob};
 use crate::dialog_state::{
     FileDialogState, PathBarStyle, ValidationButtonsAlign, ValidationButtonsOrder,
 };
@@ -91,7 +91,21 @@ pub(super) fn draw_footer(
             .build(),
     };
 
-    if file_entered {
+    // Typing `*.rs` or `render_*` live-filters the listing instead of naming a file.
+    let typed_name = match state.core.mode {
+        DialogMode::SaveFile => state.core.save_name.as_str(),
+        DialogMode::OpenFile | DialogMode::OpenFiles => {
+            state.ui.runtime.footer.file_name_buffer.as_str()
+        }
+        DialogMode::PickFolder => "",
+    };
+    let typed_glob = is_name_glob(typed_name);
+    let name_filter = if typed_glob { typed_name.trim() } else { "" };
+    if state.core.name_filter != name_filter {
+        state.core.name_filter = name_filter.to_string();
+    }
+
+    if file_entered && !typed_glob {
         *request_confirm = match state.core.mode {
             DialogMode::SaveFile => !state.core.save_name.trim().is_empty(),
             DialogMode::OpenFile | DialogMode::OpenFiles => {
@@ -410,10 +424,13 @@ fn draw_validation_buttons_row(
 
 fn core_can_confirm(state: &FileDialogState) -> bool {
     match state.core.mode {
-        DialogMode::SaveFile => !state.core.save_name.trim().is_empty(),
+        DialogMode::SaveFile => {
+            let name = state.core.save_name.trim();
+            !name.is_empty() && !is_name_glob(name)
+        }
         DialogMode::OpenFile | DialogMode::OpenFiles => {
-            state.core.has_selection()
-                || !state.ui.runtime.footer.file_name_buffer.trim().is_empty()
+            let typed = state.ui.runtime.footer.file_name_buffer.trim();
+            state.core.has_selection() || (!typed.is_empty() && !is_name_glob(typed))
         }
         DialogMode::PickFolder => true,
     }
@@ -426,6 +443,10 @@ fn confirm_disabled_reason(state: &FileDialogState, gate: &ConfirmGate) -> Strin
         }
         return "Blocked".to_string();
     }
+    if !state.core.name_filter.is_empty() {
+        return "The file name field holds a filter pattern; select a file or type a name."
+            .to_string();
+    }
     match state.core.mode {
         DialogMode::SaveFile => "Type a file name to save.".to_string(),
         DialogMode::OpenFile | DialogMode::OpenFiles => {
@@ -468,6 +489,9 @@ fn footer_status_text(state: &FileDialogState, gate: &ConfirmGate) -> String {
     if !state.core.search.trim().is_empty() {
         parts.push("Search: on".to_string());
     }
+    if !state.core.name_filter.is_empty() {
+        parts.push(format!("Pattern: {}", state.core.name_filter));
+    }
 
     if !gate.can_confirm {
         if let Some(msg) = gate.message.as_deref() {
@@ -5,6 +5,8 @@ use syn::{Expr, Field, Ident, LitStr, Result};
 pub struct FieldAttrs {
     pub skip: bool,
     pub label_override: Option<LitStr>,
+    /// Settings category (`#[imgui(group = "...")]`).
+    pub group: Option<LitStr>,
     // Numeric configuration
     pub slider: bool,
     pub slider_default_range: bool,
@@ -69,6 +71,12 @@ pub fn parse_field_attrs(_field_ident: &Ident, field: &Field) -> Result<FieldAtt
                 return Ok(());
             }
 
+            if meta.path.is_ident("group") {
+                let lit: LitStr = meta.value()?.parse()?;
+                attrs.group = Some(lit);
+                return Ok(());
+            }
+
             if meta.path.is_ident("slider") {
                 attrs.slider = true;
                 return Ok(());
@@ -28,6 +28,8 @@ mod tests;
 ///
 /// - `#[imgui(skip)]` — do not generate any UI for this field.
 /// - `#[imgui(name = "Custom Label")]` — override the label used for this field.
+/// - `#[imgui(group = "Category")]` — place the field in a settings category
+///   (see `ImGuiReflect::imgui_reflect_groups`).
 /// - `#[imgui(slider, min = ..., max = ..., format = "...")]` — use a slider
 ///   with the given range/format for numeric fields.
 /// - `#[imgui(multiline, hint = "...", read_only)]` — use multiline text
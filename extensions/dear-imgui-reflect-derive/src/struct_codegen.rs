@@ -22,6 +22,11 @@ pub(crate) fn derive_for_struct(
     }
 
     let mut field_stmts = Vec::new();
+    // Group of each entry in `field_stmts` ("" for fields without `#[imgui(group)]`).
+    let mut field_groups: Vec<String> = Vec::new();
+    // Whether each entry in `field_stmts` formats text from the enclosing `label`
+    // (bool fields that may render as a button).
+    let mut field_uses_label: Vec<bool> = Vec::new();
     let mut bound_types: Vec<Type> = Vec::new();
     let mut default_range_types: Vec<Type> = Vec::new();
 
@@ -69,6 +74,7 @@ pub(crate) fn derive_for_struct(
         let FieldAttrs {
             skip,
             label_override,
+            group,
             slider,
             slider_default_range,
             as_input,
@@ -324,6 +330,10 @@ pub(crate) fn derive_for_struct(
             default_range_types.push(ty.clone());
         }
 
+        let uses_label = kind == FieldTypeKind::Bool
+            && ((bool_style.is_none() && true_text.is_none() && false_text.is_none())
+                || bool_style.as_deref() == Some("button"));
+
         // Decide how to render this field based on attributes and type.
         let inner_stmt = match kind {
             FieldTypeKind::Bool => {
@@ -1914,7 +1924,30 @@ pub(crate) fn derive_for_struct(
         };
 
         field_stmts.push(stmt);
+        field_groups.push(group.map(|lit| lit.value()).unwrap_or_default());
+        field_uses_label.push(uses_label);
+    }
+
+    let mut group_names: Vec<String> = Vec::new();
+    for group in &field_groups {
+        if !group_names.contains(group) {
+            group_names.push(group.clone());
+        }
+    }
+    if group_names.is_empty() {
+        group_names.push(String::new());
     }
+    let group_arms = group_names.iter().map(|name| {
+        let in_group = |index: &usize| field_groups[*index] == *name;
+        let stmts = (0..field_stmts.len())
+            .filter(in_group)
+            .map(|index| &field_stmts[index]);
+        let label_binding = (0..field_stmts.len())
+            .filter(in_group)
+            .any(|index| field_uses_label[index])
+            .then(|| quote! { let label = __label; });
+        quote! { #name => { #label_binding #(#stmts)* } }
+    });
 
     {
         let where_clause = generics.make_where_clause();
@@ -1947,6 +1980,28 @@ pub(crate) fn derive_for_struct(
                 }
                 __changed
             }
+
+            fn imgui_reflect_groups() -> &'static [&'static str]
+            where
+                Self: Sized,
+            {
+                &[#(#group_names),*]
+            }
+
+            fn imgui_reflect_group(
+                &mut self,
+                ui: &::dear_imgui_reflect::imgui::Ui,
+                __label: &str,
+                group: &str,
+            ) -> bool {
+                let #reflect_settings_ident = ::dear_imgui_reflect::current_settings();
+                let mut __changed = false;
+                match group {
+                    #(#group_arms)*
+                    _ => {}
+                }
+                __changed
+            }
         }
     };
 
@@ -13,6 +13,21 @@ fn parses_basic_imgui_field_attrs() {
     assert!(attrs.min_expr.is_some());
     assert!(attrs.max_expr.is_some());
     assert_eq!(attrs.format_str.unwrap().value(), "%.2f");
+    assert!(attrs.group.is_none());
+}
+
+#[test]
+fn parses_group_attr() {
+    let field: syn::Field = syn::parse_quote! {
+        #[imgui(group = "Audio", slider, min = 0.0, max = 1.0)]
+        volume: f32
+    };
+    let ident = syn::Ident::new("volume", proc_macro2::Span::call_site());
+
+    let attrs = crate::attrs::parse_field_attrs(&ident, &field).expect("attrs parse");
+
+    assert_eq!(attrs.group.unwrap().value(), "Audio");
+    assert!(attrs.slider);
 }
 
 #[test]
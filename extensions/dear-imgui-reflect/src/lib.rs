@@ -74,7 +74,9 @@
 //! - text helpers like `#[imgui(multiline, lines = 4, hint = "Search...")]`,
 //!   `#[imgui(read_only)]`, `#[imgui(display_only)]`;
 //! - tuple layout helpers such as
-//!   `#[imgui(tuple_render = "grid", tuple_columns = 3)]`.
+//!   `#[imgui(tuple_render = "grid", tuple_columns = 3)]`;
+//! - `#[imgui(group = "Audio")]` to sort fields into categories, which
+//!   [`SettingsWindow`] turns into a sidebar-driven preferences dialog.
 //!
 //! See the documentation on the re-exported [`ImGuiReflect` derive macro]
 //! for the full list of supported attributes and validation rules.
@@ -262,6 +264,7 @@ pub use dear_imgui_rs as imgui;
 mod containers;
 mod response;
 mod settings;
+mod settings_window;
 mod values;
 
 pub use containers::{
@@ -274,6 +277,7 @@ pub use settings::{
     NumericRange, NumericTypeSettings, NumericWidgetKind, ReflectSettings, TupleRenderMode,
     TupleSettings, VecSettings, current_settings, with_settings, with_settings_scope,
 };
+pub use settings_window::SettingsWindow;
 pub use values::imgui_tuple_body;
 
 /// Trait for values that can render themselves as a single ImGui input widget.
@@ -297,6 +301,28 @@ pub trait ImGuiReflect {
     ///
     /// Returns `true` if any field was modified.
     fn imgui_reflect(&mut self, ui: &imgui::Ui, label: &str) -> bool;
+
+    /// Field groups declared with `#[imgui(group = "...")]`, in order of first appearance.
+    ///
+    /// Fields without a group belong to the unnamed group `""`, which is listed like any other
+    /// group when such fields exist. Types without field groups return `&[""]`.
+    fn imgui_reflect_groups() -> &'static [&'static str]
+    where
+        Self: Sized,
+    {
+        &[""]
+    }
+
+    /// Draw the fields of one group without the enclosing tree node.
+    ///
+    /// `label` is only used by types that have no field list (enums, manual implementations):
+    /// the default implementation draws the whole editor under `label` for the group `""` and
+    /// nothing for other groups.
+    ///
+    /// Returns `true` if any field was modified.
+    fn imgui_reflect_group(&mut self, ui: &imgui::Ui, label: &str, group: &str) -> bool {
+        group.is_empty() && self.imgui_reflect(ui, label)
+    }
 }
 
 /// Blanket implementation: any type that implements [`ImGuiReflect`] can also
@@ -316,6 +342,14 @@ impl<T: ImGuiReflect> ImGuiReflect for Box<T> {
     fn imgui_reflect(&mut self, ui: &imgui::Ui, label: &str) -> bool {
         self.as_mut().imgui_reflect(ui, label)
     }
+
+    fn imgui_reflect_groups() -> &'static [&'static str] {
+        T::imgui_reflect_groups()
+    }
+
+    fn imgui_reflect_group(&mut self, ui: &imgui::Ui, label: &str, group: &str) -> bool {
+        self.as_mut().imgui_reflect_group(ui, label, group)
+    }
 }
 
 /// Transparent reflection for reference-counted values (`Rc<T>`).
@@ -0,0 +1,220 @@
+//! Preferences dialog generated from a reflected config struct.
+
+use crate::{ImGuiReflect, imgui};
+
+/// Preferences window that edits a working copy of a reflected config struct.
+///
+/// Fields are grouped into categories with `#[imgui(group = "...")]`; when a struct declares more
+/// than one group, the window shows a category sidebar and the fields of the selected category.
+/// Edits only touch the working copy until Apply is pressed. Revert discards them and Defaults
+/// loads `T::default()` into the working copy (it still has to be applied).
+///
+/// ```no_run
+/// use dear_imgui_reflect as reflect;
+///
+/// #[derive(reflect::ImGuiReflect, Clone, Default, PartialEq)]
+/// struct Preferences {
+///     #[imgui(group = "Video")]
+///     vsync: bool,
+///     #[imgui(group = "Audio", slider, min = 0.0, max = 1.0)]
+///     volume: f32,
+/// }
+///
+/// fn draw(ui: &reflect::imgui::Ui, window: &mut reflect::SettingsWindow<Preferences>) {
+///     if window.show(ui) {
+///         // Persist `window.value()`.
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SettingsWindow<T> {
+    title: String,
+    applied: T,
+    working: T,
+    selected_group: usize,
+    sidebar_width: f32,
+    general_label: String,
+}
+
+impl<T> SettingsWindow<T>
+where
+    T: ImGuiReflect + Clone + PartialEq + Default,
+{
+    /// Creates a window titled `title` editing a copy of `value`.
+    pub fn new(title: impl Into<String>, value: T) -> Self {
+        Self {
+            title: title.into(),
+            working: value.clone(),
+            applied: value,
+            selected_group: 0,
+            sidebar_width: 160.0,
+            general_label: "General".to_string(),
+        }
+    }
+
+    /// Sets the category sidebar width in pixels (default: 160).
+    pub fn sidebar_width(mut self, width: f32) -> Self {
+        self.sidebar_width = width;
+        self
+    }
+
+    /// Sets the sidebar label of fields without a group (default: "General").
+    pub fn general_label(mut self, label: impl Into<String>) -> Self {
+        self.general_label = label.into();
+        self
+    }
+
+    /// The last applied value.
+    pub fn value(&self) -> &T {
+        &self.applied
+    }
+
+    /// The value being edited, including unapplied changes.
+    pub fn working(&self) -> &T {
+        &self.working
+    }
+
+    /// Mutable access to the working copy, e.g. for changes made outside the window.
+    pub fn working_mut(&mut self) -> &mut T {
+        &mut self.working
+    }
+
+    /// Replaces both the applied value and the working copy, e.g. after reloading from disk.
+    pub fn set_value(&mut self, value: T) {
+        self.working = value.clone();
+        self.applied = value;
+    }
+
+    /// Returns true if the working copy differs from the applied value.
+    pub fn is_dirty(&self) -> bool {
+        self.working != self.applied
+    }
+
+    /// Applies the working copy. Returns true if anything changed.
+    pub fn apply(&mut self) -> bool {
+        if !self.is_dirty() {
+            return false;
+        }
+        self.applied = self.working.clone();
+        true
+    }
+
+    /// Discards unapplied changes.
+    pub fn revert(&mut self) {
+        self.working = self.applied.clone();
+    }
+
+    /// Loads `T::default()` into the working copy without applying it.
+    pub fn reset_to_defaults(&mut self) {
+        self.working = T::default();
+    }
+
+    /// Name of the selected category (`""` for fields without a group).
+    pub fn selected_group(&self) -> &'static str {
+        let groups = T::imgui_reflect_groups();
+        groups
+            .get(self.selected_group)
+            .or_else(|| groups.first())
+            .copied()
+            .unwrap_or("")
+    }
+
+    /// Selects the category shown next to the sidebar. Returns false for unknown groups.
+    pub fn select_group(&mut self, group: &str) -> bool {
+        match T::imgui_reflect_groups().iter().position(|g| *g == group) {
+            Some(index) => {
+                self.selected_group = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Draws the settings in a window of their own.
+    ///
+    /// Returns true on the frame Apply was pressed; read the new settings from [`Self::value`].
+    pub fn show(&mut self, ui: &imgui::Ui) -> bool {
+        self.show_with_opened(ui, None)
+    }
+
+    /// Like [`Self::show`], with a title-bar close button bound to `opened`.
+    pub fn show_with_opened(&mut self, ui: &imgui::Ui, opened: Option<&mut bool>) -> bool {
+        let title = self.title.clone();
+        let mut window = ui
+            .window(title)
+            .size([560.0, 400.0], imgui::Condition::FirstUseEver);
+        if let Some(opened) = opened {
+            window = window.opened(opened);
+        }
+        window.build(|| self.draw_contents(ui)).unwrap_or(false)
+    }
+
+    /// Draws the sidebar, fields and buttons into the current window.
+    ///
+    /// Returns true on the frame Apply was pressed.
+    pub fn draw_contents(&mut self, ui: &imgui::Ui) -> bool {
+        let groups = T::imgui_reflect_groups();
+        if self.selected_group >= groups.len() {
+            self.selected_group = 0;
+        }
+        let footer_h = ui.frame_height_with_spacing() + ui.clone_style().item_spacing()[1];
+        let body_h = (ui.content_region_avail_height() - footer_h).max(0.0);
+
+        if groups.len() > 1 {
+            ui.child_window("##settings_categories")
+                .size([self.sidebar_width, body_h])
+                .border(true)
+                .build(ui, || {
+                    for (index, group) in groups.iter().enumerate() {
+                        let label = if group.is_empty() {
+                            self.general_label.as_str()
+                        } else {
+                            group
+                        };
+                        let _id = ui.push_id(index);
+                        if ui
+                            .selectable_config(label)
+                            .selected(index == self.selected_group)
+                            .build()
+                        {
+                            self.selected_group = index;
+                        }
+                    }
+                });
+            ui.same_line();
+        }
+
+        let group = groups.get(self.selected_group).copied().unwrap_or("");
+        let title = self.title.as_str();
+        let working = &mut self.working;
+        ui.child_window("##settings_fields")
+            .size([0.0, body_h])
+            .build(ui, || {
+                let _id = ui.push_id(group);
+                working.imgui_reflect_group(ui, title, group);
+            });
+
+        ui.separator();
+        let dirty = self.is_dirty();
+        let mut applied = false;
+        {
+            let _disabled = ui.begin_disabled_with_cond(!dirty);
+            if ui.button("Apply") {
+                applied = self.apply();
+            }
+            ui.same_line();
+            if ui.button("Revert") {
+                self.revert();
+            }
+        }
+        ui.same_line();
+        if ui.button("Defaults") {
+            self.reset_to_defaults();
+        }
+        if dirty {
+            ui.same_line();
+            ui.text_disabled("Unsaved changes");
+        }
+        applied
+    }
+}
@@ -0,0 +1,85 @@
+use dear_imgui_reflect as reflect;
+use dear_imgui_reflect::imgui::Context;
+use reflect::{ImGuiReflect, SettingsWindow};
+
+mod common;
+
+use common::test_guard;
+
+#[derive(ImGuiReflect, Clone, Default, PartialEq, Debug)]
+struct Preferences {
+    name: String,
+    #[imgui(group = "Video")]
+    vsync: bool,
+    #[imgui(group = "Audio", slider, min = 0.0, max = 1.0)]
+    volume: f32,
+    #[imgui(group = "Video")]
+    fov: f32,
+}
+
+#[derive(ImGuiReflect, Clone, Default, PartialEq)]
+struct Ungrouped {
+    value: i32,
+}
+
+fn new_test_ctx() -> Context {
+    let mut ctx = Context::create();
+    ctx.io_mut().set_display_size([800.0, 600.0]);
+    ctx.io_mut().set_delta_time(1.0 / 60.0);
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+    ctx
+}
+
+#[test]
+fn derive_lists_groups_in_declaration_order() {
+    assert_eq!(Preferences::imgui_reflect_groups(), ["", "Video", "Audio"]);
+    assert_eq!(Ungrouped::imgui_reflect_groups(), [""]);
+}
+
+#[test]
+fn settings_window_tracks_dirty_state_and_apply_revert_defaults() {
+    let initial = Preferences {
+        name: "player".into(),
+        volume: 0.5,
+        ..Default::default()
+    };
+    let mut window = SettingsWindow::new("Preferences", initial.clone());
+    assert!(!window.is_dirty());
+    assert!(!window.apply());
+
+    window.working_mut().volume = 0.8;
+    assert!(window.is_dirty());
+    window.revert();
+    assert_eq!(window.working(), &initial);
+
+    window.working_mut().vsync = true;
+    assert!(window.apply());
+    assert!(window.value().vsync);
+    assert!(!window.is_dirty());
+
+    window.reset_to_defaults();
+    assert_eq!(window.working(), &Preferences::default());
+    assert!(window.value().vsync);
+
+    assert!(window.select_group("Audio"));
+    assert_eq!(window.selected_group(), "Audio");
+    assert!(!window.select_group("Missing"));
+}
+
+#[test]
+fn settings_window_renders_each_group() {
+    let _guard = test_guard();
+    let mut ctx = new_test_ctx();
+    let mut window = SettingsWindow::new("Preferences", Preferences::default());
+    let mut plain = SettingsWindow::new("Plain", Ungrouped::default());
+
+    for group in ["", "Video", "Audio"] {
+        assert!(window.select_group(group));
+        let ui = ctx.frame();
+        assert!(!window.show(ui));
+        assert!(!plain.show(ui));
+        let _ = ctx.render();
+    }
+    assert!(!window.is_dirty());
+}
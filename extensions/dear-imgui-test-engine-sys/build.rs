@@ -109,6 +109,7 @@ fn generate_bindings(cfg: &BuildConfig) {
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         .allowlist_function("imgui_test_engine_.*")
         .allowlist_type("ImGuiTestEngine.*")
+        .allowlist_type("ImGuiTestContext")
         .allowlist_var("ImGuiTestEngine.*")
         .blocklist_type("ImGuiContext")
         .derive_default(true)
@@ -193,12 +194,14 @@ fn build_with_cc(cfg: &BuildConfig, test_engine_root: &Path, imgui_src: &Path, c
     build.file(test_engine_root.join("imgui_te_perftool.cpp"));
     build.file(test_engine_root.join("imgui_te_ui.cpp"));
     build.file(test_engine_root.join("imgui_te_utils.cpp"));
+    build.file(cfg.manifest_dir.join("shim/capture.cpp"));
     build.file(cfg.manifest_dir.join("shim/cimgui_test_engine.cpp"));
     build.file(cfg.manifest_dir.join("shim/default_tests.cpp"));
     build.file(
         cfg.manifest_dir
             .join("shim/imgui_test_engine_hooks_register.cpp"),
     );
+    build.file(cfg.manifest_dir.join("shim/native_tests.cpp"));
     build.file(cfg.manifest_dir.join("shim/script_tests.cpp"));
 
     if cfg.is_msvc() && cfg.is_windows() {
@@ -295,9 +298,11 @@ fn main() {
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=src/bindings_pregenerated.rs");
     println!("cargo:rerun-if-changed=shim/cimgui_test_engine.h");
+    println!("cargo:rerun-if-changed=shim/capture.cpp");
     println!("cargo:rerun-if-changed=shim/cimgui_test_engine.cpp");
     println!("cargo:rerun-if-changed=shim/default_tests.cpp");
     println!("cargo:rerun-if-changed=shim/imgui_test_engine_hooks_register.cpp");
+    println!("cargo:rerun-if-changed=shim/native_tests.cpp");
     println!("cargo:rerun-if-changed=shim/script_tests.cpp");
     println!(
         "cargo:rerun-if-changed=third-party/imgui_test_engine/imgui_test_engine/imgui_capture_tool.cpp"
@@ -0,0 +1,164 @@
+// Screenshot/video capture helpers for Rust consumers.
+// This file is part of dear-imgui-rs and is licensed under MIT OR Apache-2.0.
+
+#include <string.h>
+
+#define IMGUI_DEFINE_MATH_OPERATORS
+#include "imgui.h"
+#include "imgui_internal.h"
+
+#include "imgui_capture_tool.h"
+#include "imgui_te_context.h"
+#include "imgui_te_engine.h"
+#include "imgui_te_utils.h" // ImFileExist()
+
+#include "cimgui_test_engine.h"
+
+static_assert((int)ImGuiTestEngineCaptureFlags_StitchAll == (int)ImGuiCaptureFlags_StitchAll, "");
+static_assert(
+    (int)ImGuiTestEngineCaptureFlags_IncludeOtherWindows == (int)ImGuiCaptureFlags_IncludeOtherWindows,
+    ""
+);
+static_assert(
+    (int)ImGuiTestEngineCaptureFlags_IncludeTooltipsAndPopups == (int)ImGuiCaptureFlags_IncludeTooltipsAndPopups,
+    ""
+);
+static_assert((int)ImGuiTestEngineCaptureFlags_HideMouseCursor == (int)ImGuiCaptureFlags_HideMouseCursor, "");
+static_assert((int)ImGuiTestEngineCaptureFlags_Instant == (int)ImGuiCaptureFlags_Instant, "");
+static_assert((int)ImGuiTestEngineCaptureFlags_NoSave == (int)ImGuiCaptureFlags_NoSave, "");
+
+extern "C" {
+
+void imgui_test_engine_set_screen_capture_func(
+    ImGuiTestEngine* engine,
+    ImGuiTestEngineScreenCaptureFunc func,
+    void* user_data
+) {
+    if (engine == nullptr) {
+        return;
+    }
+    ImGuiTestEngineIO& io = ImGuiTestEngine_GetIO(engine);
+    io.ScreenCaptureFunc = func;
+    io.ScreenCaptureUserData = func ? user_data : nullptr;
+}
+
+void imgui_test_engine_set_capture_on_error(ImGuiTestEngine* engine, bool enabled) {
+    if (engine == nullptr) {
+        return;
+    }
+    ImGuiTestEngine_GetIO(engine).ConfigCaptureOnError = enabled;
+}
+
+bool imgui_test_engine_set_video_capture_encoder(
+    ImGuiTestEngine* engine,
+    const char* encoder_path,
+    const char* encoder_params
+) {
+    if (engine == nullptr) {
+        return false;
+    }
+    ImGuiTestEngineIO& io = ImGuiTestEngine_GetIO(engine);
+    const char* path = encoder_path ? encoder_path : "";
+    if (strlen(path) >= IM_ARRAYSIZE(io.VideoCaptureEncoderPath)) {
+        return false;
+    }
+    if (encoder_params != nullptr && strlen(encoder_params) >= IM_ARRAYSIZE(io.VideoCaptureEncoderParams)) {
+        return false;
+    }
+    ImStrncpy(io.VideoCaptureEncoderPath, path, IM_ARRAYSIZE(io.VideoCaptureEncoderPath));
+    if (encoder_params != nullptr) {
+        ImStrncpy(io.VideoCaptureEncoderParams, encoder_params, IM_ARRAYSIZE(io.VideoCaptureEncoderParams));
+    }
+    return true;
+}
+
+bool imgui_test_engine_is_video_capture_available(ImGuiTestEngine* engine) {
+#if IMGUI_TEST_ENGINE_ENABLE_CAPTURE
+    if (engine == nullptr) {
+        return false;
+    }
+    ImGuiTestEngineIO& io = ImGuiTestEngine_GetIO(engine);
+    return io.VideoCaptureEncoderPath[0] != 0 && ImFileExist(io.VideoCaptureEncoderPath);
+#else
+    IM_UNUSED(engine);
+    return false;
+#endif
+}
+
+void imgui_test_engine_ctx_capture_reset(ImGuiTestContext* ctx) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->CaptureReset();
+}
+
+bool imgui_test_engine_ctx_capture_add_window(ImGuiTestContext* ctx, const char* ref) {
+    if (ctx == nullptr) {
+        return false;
+    }
+    return ctx->CaptureAddWindow(ref ? ref : "");
+}
+
+bool imgui_test_engine_ctx_capture_set_output_file(ImGuiTestContext* ctx, const char* path) {
+    if (ctx == nullptr || ctx->CaptureArgs == nullptr) {
+        return false;
+    }
+    const char* p = path ? path : "";
+    if (strlen(p) >= IM_ARRAYSIZE(ctx->CaptureArgs->InOutputFile)) {
+        return false;
+    }
+    ImStrncpy(ctx->CaptureArgs->InOutputFile, p, IM_ARRAYSIZE(ctx->CaptureArgs->InOutputFile));
+    return true;
+}
+
+void imgui_test_engine_ctx_capture_set_padding(ImGuiTestContext* ctx, float padding) {
+    if (ctx == nullptr || ctx->CaptureArgs == nullptr) {
+        return;
+    }
+    ctx->CaptureArgs->InPadding = padding;
+}
+
+bool imgui_test_engine_ctx_capture_screenshot(ImGuiTestContext* ctx, int capture_flags) {
+#if IMGUI_TEST_ENGINE_ENABLE_CAPTURE
+    if (ctx == nullptr) {
+        return false;
+    }
+    return ctx->CaptureScreenshot(capture_flags);
+#else
+    IM_UNUSED(capture_flags);
+    if (ctx != nullptr) {
+        ctx->LogWarning("CaptureScreenshot: built without IMGUI_TEST_ENGINE_ENABLE_CAPTURE");
+    }
+    return false;
+#endif
+}
+
+bool imgui_test_engine_ctx_capture_begin_video(ImGuiTestContext* ctx, int capture_flags) {
+#if IMGUI_TEST_ENGINE_ENABLE_CAPTURE
+    if (ctx == nullptr || ctx->CaptureArgs == nullptr) {
+        return false;
+    }
+    ctx->CaptureArgs->InFlags = capture_flags;
+    return ctx->CaptureBeginVideo();
+#else
+    IM_UNUSED(capture_flags);
+    if (ctx != nullptr) {
+        ctx->LogWarning("CaptureBeginVideo: built without IMGUI_TEST_ENGINE_ENABLE_CAPTURE");
+    }
+    return false;
+#endif
+}
+
+bool imgui_test_engine_ctx_capture_end_video(ImGuiTestContext* ctx) {
+#if IMGUI_TEST_ENGINE_ENABLE_CAPTURE
+    if (ctx == nullptr) {
+        return false;
+    }
+    return ctx->CaptureEndVideo();
+#else
+    IM_UNUSED(ctx);
+    return false;
+#endif
+}
+
+} // extern "C"
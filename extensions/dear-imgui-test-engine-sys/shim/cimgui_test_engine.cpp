@@ -1,11 +1,17 @@
 #include "cimgui_test_engine.h"
 
+#include <string.h>
+
 #include "imgui_te_engine.h"
+#include "imgui_te_exporters.h"
 #include "imgui_te_internal.h"
+#include "imgui_te_perftool.h"
 #include "imgui_te_ui.h"
 
 // Implemented in script_tests.cpp (internal cleanup hook).
 void imgui_test_engine__script_cleanup(ImGuiTestEngine* engine);
+// Implemented in native_tests.cpp (internal cleanup hook).
+void imgui_test_engine__native_cleanup(ImGuiTestEngine* engine);
 
 extern "C" {
 
@@ -57,6 +63,10 @@ void imgui_test_engine_destroy_context(ImGuiTestEngine* engine) {
 
     ImGuiTestEngine_DestroyContext(engine);
 
+    // Native test user data may own caller closures that are still referenced by a running test
+    // coroutine until the engine is stopped, so release it only after the engine is gone.
+    imgui_test_engine__native_cleanup(engine);
+
     imgui_test_engine__restore_current_if_needed(prev, target);
 }
 
@@ -146,6 +156,23 @@ void imgui_test_engine_show_windows(ImGuiTestEngine* engine, bool* p_open) {
     imgui_test_engine__restore_current_if_needed(prev, target);
 }
 
+void imgui_test_engine_show_perf_tool(ImGuiTestEngine* engine, bool* p_open) {
+    if (engine == nullptr) {
+        return;
+    }
+    if (engine->UiContextTarget == nullptr) {
+        return;
+    }
+    ImGuiPerfTool* perf_tool = ImGuiTestEngine_GetPerfTool(engine);
+    if (perf_tool == nullptr) {
+        return;
+    }
+    ImGuiContext* target = engine->UiContextTarget;
+    ImGuiContext* prev = imgui_test_engine__set_current_if_needed(target);
+    perf_tool->ShowPerfToolWindow(engine, p_open);
+    imgui_test_engine__restore_current_if_needed(prev, target);
+}
+
 void imgui_test_engine_queue_tests(
     ImGuiTestEngine* engine,
     ImGuiTestEngineGroup group,
@@ -163,6 +190,18 @@ void imgui_test_engine_queue_tests(
     );
 }
 
+bool imgui_test_engine_queue_test(ImGuiTestEngine* engine, int index, int run_flags) {
+    if (engine == nullptr || index < 0 || index >= engine->TestsAll.Size) {
+        return false;
+    }
+    ImGuiTestEngine_QueueTest(
+        engine,
+        engine->TestsAll[index],
+        static_cast<ImGuiTestRunFlags>(run_flags)
+    );
+    return true;
+}
+
 bool imgui_test_engine_is_test_queue_empty(ImGuiTestEngine* engine) {
     if (engine == nullptr) {
         return true;
@@ -218,6 +257,63 @@ void imgui_test_engine_get_result_summary(
     out_summary->CountInQueue = count_remaining;
 }
 
+static_assert((int)ImGuiTestEngineTestStatus_Success == (int)ImGuiTestStatus_Success, "");
+static_assert((int)ImGuiTestEngineTestStatus_Queued == (int)ImGuiTestStatus_Queued, "");
+static_assert((int)ImGuiTestEngineTestStatus_Running == (int)ImGuiTestStatus_Running, "");
+static_assert((int)ImGuiTestEngineTestStatus_Error == (int)ImGuiTestStatus_Error, "");
+static_assert((int)ImGuiTestEngineTestStatus_Suspended == (int)ImGuiTestStatus_Suspended, "");
+
+int imgui_test_engine_get_test_count(ImGuiTestEngine* engine) {
+    if (engine == nullptr) {
+        return 0;
+    }
+    return engine->TestsAll.Size;
+}
+
+bool imgui_test_engine_get_test_info(ImGuiTestEngine* engine, int index, ImGuiTestEngineTestInfo_c* out_info) {
+    if (engine == nullptr || out_info == nullptr || index < 0 || index >= engine->TestsAll.Size) {
+        return false;
+    }
+    ImGuiTest* test = engine->TestsAll[index];
+    out_info->Category = test->Category ? test->Category : "";
+    out_info->Name = test->Name ? test->Name : "";
+    out_info->Group = static_cast<ImGuiTestEngineGroup>(test->Group);
+    out_info->Status = static_cast<ImGuiTestEngineTestStatus>(test->Output.Status);
+    out_info->SourceFile = test->SourceFile ? test->SourceFile : "";
+    out_info->SourceLine = test->SourceLine;
+    return true;
+}
+
+int imgui_test_engine_get_test_last_error(ImGuiTestEngine* engine, int index, char* out_buf, int buf_size) {
+    if (out_buf != nullptr && buf_size > 0) {
+        out_buf[0] = 0;
+    }
+    if (engine == nullptr || index < 0 || index >= engine->TestsAll.Size) {
+        return 0;
+    }
+    ImGuiTest* test = engine->TestsAll[index];
+    ImGuiTextBuffer errors;
+    test->Output.Log.ExtractLinesForVerboseLevels(ImGuiTestVerboseLevel_Error, ImGuiTestVerboseLevel_Error, &errors);
+
+    // Keep the last non-empty line.
+    const char* begin = errors.begin();
+    const char* end = errors.end();
+    while (end > begin && (end[-1] == '\n' || end[-1] == '\r')) {
+        end--;
+    }
+    const char* line = end;
+    while (line > begin && line[-1] != '\n') {
+        line--;
+    }
+    const int len = static_cast<int>(end - line);
+    if (out_buf != nullptr && buf_size > 0) {
+        const int copy = len < buf_size - 1 ? len : buf_size - 1;
+        memcpy(out_buf, line, static_cast<size_t>(copy));
+        out_buf[copy] = 0;
+    }
+    return len;
+}
+
 void imgui_test_engine_set_run_speed(ImGuiTestEngine* engine, ImGuiTestEngineRunSpeed speed) {
     if (engine == nullptr) {
         return;
@@ -242,6 +338,41 @@ void imgui_test_engine_set_capture_enabled(ImGuiTestEngine* engine, bool enabled
     ImGuiTestEngine_GetIO(engine).ConfigCaptureEnabled = enabled;
 }
 
+void imgui_test_engine_set_stop_on_error(ImGuiTestEngine* engine, bool enabled) {
+    if (engine == nullptr) {
+        return;
+    }
+    ImGuiTestEngine_GetIO(engine).ConfigStopOnError = enabled;
+}
+
+void imgui_test_engine_set_break_on_error(ImGuiTestEngine* engine, bool enabled) {
+    if (engine == nullptr) {
+        return;
+    }
+    ImGuiTestEngine_GetIO(engine).ConfigBreakOnError = enabled;
+}
+
+void imgui_test_engine_set_no_throttle(ImGuiTestEngine* engine, bool enabled) {
+    if (engine == nullptr) {
+        return;
+    }
+    ImGuiTestEngine_GetIO(engine).ConfigNoThrottle = enabled;
+}
+
+void imgui_test_engine_get_io_config(ImGuiTestEngine* engine, ImGuiTestEngineIOConfig_c* out_config) {
+    if (engine == nullptr || out_config == nullptr) {
+        return;
+    }
+    const ImGuiTestEngineIO& io = ImGuiTestEngine_GetIO(engine);
+    out_config->RunSpeed = static_cast<ImGuiTestEngineRunSpeed>(io.ConfigRunSpeed);
+    out_config->VerboseLevel = static_cast<ImGuiTestEngineVerboseLevel>(io.ConfigVerboseLevel);
+    out_config->CaptureEnabled = io.ConfigCaptureEnabled;
+    out_config->CaptureOnError = io.ConfigCaptureOnError;
+    out_config->StopOnError = io.ConfigStopOnError;
+    out_config->BreakOnError = io.ConfigBreakOnError;
+    out_config->NoThrottle = io.ConfigNoThrottle;
+}
+
 bool imgui_test_engine_is_running_tests(ImGuiTestEngine* engine) {
     if (engine == nullptr) {
         return false;
@@ -260,4 +391,21 @@ void imgui_test_engine_install_default_crash_handler(void) {
     ImGuiTestEngine_InstallDefaultCrashHandler();
 }
 
+static_assert((int)ImGuiTestEngineResultsFormat_JUnitXml == (int)ImGuiTestEngineExportFormat_JUnitXml, "");
+
+bool imgui_test_engine_export_results(
+    ImGuiTestEngine* engine,
+    ImGuiTestEngineResultsFormat format,
+    const char* filename
+) {
+    if (engine == nullptr || filename == nullptr || filename[0] == 0) {
+        return false;
+    }
+    if (format == ImGuiTestEngineResultsFormat_None) {
+        return false;
+    }
+    ImGuiTestEngine_ExportEx(engine, static_cast<ImGuiTestEngineExportFormat>(format), filename);
+    return true;
+}
+
 } // extern "C"
@@ -9,6 +9,7 @@ extern "C" {
 typedef struct ImGuiContext ImGuiContext;
 typedef struct ImGuiTestEngine ImGuiTestEngine;
 typedef struct ImGuiTestEngineScript ImGuiTestEngineScript;
+typedef struct ImGuiTestContext ImGuiTestContext;
 
 typedef enum ImGuiTestEngineRunSpeed {
     ImGuiTestEngineRunSpeed_Fast = 0,
@@ -44,6 +45,54 @@ typedef enum ImGuiTestEngineRunFlags {
     ImGuiTestEngineRunFlags_ShareTestContext = 1 << 12,
 } ImGuiTestEngineRunFlags;
 
+// Mirrors `ImGuiCaptureFlags_` from imgui_capture_tool.h.
+typedef enum ImGuiTestEngineCaptureFlags {
+    ImGuiTestEngineCaptureFlags_None = 0,
+    ImGuiTestEngineCaptureFlags_StitchAll = 1 << 0,
+    ImGuiTestEngineCaptureFlags_IncludeOtherWindows = 1 << 1,
+    ImGuiTestEngineCaptureFlags_IncludeTooltipsAndPopups = 1 << 2,
+    ImGuiTestEngineCaptureFlags_HideMouseCursor = 1 << 3,
+    ImGuiTestEngineCaptureFlags_Instant = 1 << 4,
+    ImGuiTestEngineCaptureFlags_NoSave = 1 << 5,
+} ImGuiTestEngineCaptureFlags;
+
+// Mirrors upstream `ImGuiTestEngineExportFormat` (renamed to avoid clashing with it).
+typedef enum ImGuiTestEngineResultsFormat {
+    ImGuiTestEngineResultsFormat_None = 0,
+    ImGuiTestEngineResultsFormat_JUnitXml = 1,
+} ImGuiTestEngineResultsFormat;
+
+// Mirrors upstream `ImGuiTestStatus`.
+typedef enum ImGuiTestEngineTestStatus {
+    ImGuiTestEngineTestStatus_Unknown = 0,
+    ImGuiTestEngineTestStatus_Success = 1,
+    ImGuiTestEngineTestStatus_Queued = 2,
+    ImGuiTestEngineTestStatus_Running = 3,
+    ImGuiTestEngineTestStatus_Error = 4,
+    ImGuiTestEngineTestStatus_Suspended = 5,
+} ImGuiTestEngineTestStatus;
+
+// Strings are owned by the engine and stay valid until the engine context is destroyed.
+typedef struct ImGuiTestEngineTestInfo_c {
+    const char* Category;
+    const char* Name;
+    ImGuiTestEngineGroup Group;
+    ImGuiTestEngineTestStatus Status;
+    // Where the test was registered (may be empty for tests registered without a location).
+    const char* SourceFile;
+    int SourceLine;
+} ImGuiTestEngineTestInfo_c;
+
+typedef struct ImGuiTestEngineIOConfig_c {
+    ImGuiTestEngineRunSpeed RunSpeed;
+    ImGuiTestEngineVerboseLevel VerboseLevel;
+    bool CaptureEnabled;
+    bool CaptureOnError;
+    bool StopOnError;
+    bool BreakOnError;
+    bool NoThrottle;
+} ImGuiTestEngineIOConfig_c;
+
 typedef struct ImGuiTestEngineResultSummary_c {
     int CountTested;
     int CountSuccess;
@@ -67,6 +116,8 @@ void imgui_test_engine_stop(ImGuiTestEngine* engine);
 void imgui_test_engine_post_swap(ImGuiTestEngine* engine);
 
 void imgui_test_engine_show_windows(ImGuiTestEngine* engine, bool* p_open);
+// Shows the built-in perf tool window (perf results and comparison against previous runs).
+void imgui_test_engine_show_perf_tool(ImGuiTestEngine* engine, bool* p_open);
 
 void imgui_test_engine_queue_tests(
     ImGuiTestEngine* engine,
@@ -74,6 +125,9 @@ void imgui_test_engine_queue_tests(
     const char* filter,
     int run_flags
 );
+// Queues the registered test at `index` (see `imgui_test_engine_get_test_info`).
+// Returns false if `index` is out of range.
+bool imgui_test_engine_queue_test(ImGuiTestEngine* engine, int index, int run_flags);
 
 bool imgui_test_engine_is_test_queue_empty(ImGuiTestEngine* engine);
 bool imgui_test_engine_try_abort_engine(ImGuiTestEngine* engine);
@@ -84,18 +138,43 @@ void imgui_test_engine_get_result_summary(
     ImGuiTestEngineResultSummary_c* out_summary
 );
 
+// Registered tests, in registration order.
+int imgui_test_engine_get_test_count(ImGuiTestEngine* engine);
+// Returns false if `index` is out of range.
+bool imgui_test_engine_get_test_info(ImGuiTestEngine* engine, int index, ImGuiTestEngineTestInfo_c* out_info);
+// Copies the last error line logged by the test's most recent run into `out_buf` (NUL-terminated,
+// truncated to `buf_size`) and returns its full length in bytes, or 0 if there is none.
+// `out_buf` may be null to query the length.
+int imgui_test_engine_get_test_last_error(ImGuiTestEngine* engine, int index, char* out_buf, int buf_size);
+
 void imgui_test_engine_set_run_speed(ImGuiTestEngine* engine, ImGuiTestEngineRunSpeed speed);
 void imgui_test_engine_set_verbose_level(
     ImGuiTestEngine* engine,
     ImGuiTestEngineVerboseLevel level
 );
 void imgui_test_engine_set_capture_enabled(ImGuiTestEngine* engine, bool enabled);
+// Stop running the queue after the first failed test.
+void imgui_test_engine_set_stop_on_error(ImGuiTestEngine* engine, bool enabled);
+// Break into the debugger when a check fails.
+void imgui_test_engine_set_break_on_error(ImGuiTestEngine* engine, bool enabled);
+// Tell the application not to throttle (vsync/sleep) while tests run.
+void imgui_test_engine_set_no_throttle(ImGuiTestEngine* engine, bool enabled);
+// Reads the current `ImGuiTestEngineIO` configuration.
+void imgui_test_engine_get_io_config(ImGuiTestEngine* engine, ImGuiTestEngineIOConfig_c* out_config);
 
 bool imgui_test_engine_is_running_tests(ImGuiTestEngine* engine);
 bool imgui_test_engine_is_requesting_max_app_speed(ImGuiTestEngine* engine);
 
 void imgui_test_engine_install_default_crash_handler(void);
 
+// Writes the results of every test that ran to `filename` (e.g. JUnit XML for CI).
+// Returns false on invalid arguments; I/O failures are reported by the engine on stderr.
+bool imgui_test_engine_export_results(
+    ImGuiTestEngine* engine,
+    ImGuiTestEngineResultsFormat format,
+    const char* filename
+);
+
 // Register a small set of built-in demo tests (useful to validate integration).
 // This does not start the engine; it only registers tests into the engine instance.
 void imgui_test_engine_register_default_tests(ImGuiTestEngine* engine);
@@ -219,6 +298,8 @@ void imgui_test_engine_script_assert_item_opened(ImGuiTestEngineScript* script,
 void imgui_test_engine_script_wait_for_item_checked(ImGuiTestEngineScript* script, const char* ref, int max_frames);
 void imgui_test_engine_script_wait_for_item_opened(ImGuiTestEngineScript* script, const char* ref, int max_frames);
 void imgui_test_engine_script_yield(ImGuiTestEngineScript* script, int frames);
+// Yields `frames` frames with `io.DeltaTime` forced to `delta_time` (seconds, must be > 0).
+void imgui_test_engine_script_yield_with_delta_time(ImGuiTestEngineScript* script, int frames, float delta_time);
 void imgui_test_engine_register_script_test(
     ImGuiTestEngine* engine,
     const char* category,
@@ -226,6 +307,109 @@ void imgui_test_engine_register_script_test(
     ImGuiTestEngineScript* script
 );
 
+// Native tests: GUI/test functions implemented by the caller (e.g. Rust closures).
+//
+// `source_file`/`source_line` are reported as the test's location (copied; may be null).
+// `gui_func` runs on the application thread from inside `NewFrame()`, `test_func` runs on the
+// test engine coroutine. Either may be null. `user_data_drop` (optional) is called exactly once
+// with `user_data` when the engine context is destroyed (or immediately if registration fails).
+typedef void (*ImGuiTestEngineNativeFunc)(ImGuiTestContext* ctx, void* user_data);
+typedef void (*ImGuiTestEngineUserDataDrop)(void* user_data);
+
+void imgui_test_engine_register_native_test(
+    ImGuiTestEngine* engine,
+    const char* category,
+    const char* name,
+    const char* source_file,
+    int source_line,
+    ImGuiTestEngineNativeFunc gui_func,
+    ImGuiTestEngineNativeFunc test_func,
+    void* user_data,
+    ImGuiTestEngineUserDataDrop user_data_drop
+);
+
+// Like `imgui_test_engine_register_native_test`, but registers into the Perfs group. A null
+// `test_func` measures `gui_func` with `PerfCapture()` using the context's iteration count.
+void imgui_test_engine_register_native_perf_test(
+    ImGuiTestEngine* engine,
+    const char* category,
+    const char* name,
+    const char* source_file,
+    int source_line,
+    ImGuiTestEngineNativeFunc gui_func,
+    ImGuiTestEngineNativeFunc test_func,
+    void* user_data,
+    ImGuiTestEngineUserDataDrop user_data_drop
+);
+
+// Test context helpers: only valid while the owning native test is running.
+ImGuiContext* imgui_test_engine_ctx_get_ui_context(ImGuiTestContext* ctx);
+bool imgui_test_engine_ctx_is_error(ImGuiTestContext* ctx);
+// Records a check result (like `IM_CHECK`) and returns `result`.
+bool imgui_test_engine_ctx_check(ImGuiTestContext* ctx, bool result, const char* expr, const char* file, int line);
+void imgui_test_engine_ctx_log_info(ImGuiTestContext* ctx, const char* message);
+void imgui_test_engine_ctx_log_error(ImGuiTestContext* ctx, const char* message);
+void imgui_test_engine_ctx_set_ref(ImGuiTestContext* ctx, const char* ref);
+void imgui_test_engine_ctx_item_click(ImGuiTestContext* ctx, const char* ref, int button);
+void imgui_test_engine_ctx_item_double_click(ImGuiTestContext* ctx, const char* ref);
+void imgui_test_engine_ctx_item_open(ImGuiTestContext* ctx, const char* ref);
+void imgui_test_engine_ctx_item_close(ImGuiTestContext* ctx, const char* ref);
+void imgui_test_engine_ctx_item_check(ImGuiTestContext* ctx, const char* ref);
+void imgui_test_engine_ctx_item_uncheck(ImGuiTestContext* ctx, const char* ref);
+void imgui_test_engine_ctx_item_input_int(ImGuiTestContext* ctx, const char* ref, int v);
+void imgui_test_engine_ctx_item_input_str(ImGuiTestContext* ctx, const char* ref, const char* v);
+bool imgui_test_engine_ctx_item_exists(ImGuiTestContext* ctx, const char* ref);
+void imgui_test_engine_ctx_mouse_move(ImGuiTestContext* ctx, const char* ref);
+// Key chord is `ImGuiKeyChord` (an int) from Dear ImGui.
+void imgui_test_engine_ctx_key_press(ImGuiTestContext* ctx, int key_chord, int count);
+void imgui_test_engine_ctx_key_chars(ImGuiTestContext* ctx, const char* chars);
+// Measures the average frame time over the context's perf iteration count and records it.
+void imgui_test_engine_ctx_perf_capture(ImGuiTestContext* ctx);
+// Sets the number of frames measured by `imgui_test_engine_ctx_perf_capture` (ignored if <= 0).
+void imgui_test_engine_ctx_set_perf_iterations(ImGuiTestContext* ctx, int iterations);
+void imgui_test_engine_ctx_sleep(ImGuiTestContext* ctx, float time_in_seconds);
+void imgui_test_engine_ctx_yield(ImGuiTestContext* ctx, int frames);
+// Yields `frames` frames with `io.DeltaTime` forced to `delta_time` (seconds, must be > 0).
+void imgui_test_engine_ctx_yield_with_delta_time(ImGuiTestContext* ctx, int frames, float delta_time);
+
+// Capture: screenshots and videos of windows, written by the capture tool.
+//
+// The application provides the pixels through `ScreenCaptureFunc` (called on the application
+// thread from `PostSwap()`). It must fill `pixels` with `w * h` RGBA8 values read back from the
+// framebuffer of `viewport_id` and return true on success.
+typedef bool (*ImGuiTestEngineScreenCaptureFunc)(
+    unsigned int viewport_id,
+    int x,
+    int y,
+    int w,
+    int h,
+    unsigned int* pixels,
+    void* user_data
+);
+void imgui_test_engine_set_screen_capture_func(
+    ImGuiTestEngine* engine,
+    ImGuiTestEngineScreenCaptureFunc func,
+    void* user_data
+);
+void imgui_test_engine_set_capture_on_error(ImGuiTestEngine* engine, bool enabled);
+// Returns false if a string does not fit the engine's fixed-size buffers. Null params keep the default.
+bool imgui_test_engine_set_video_capture_encoder(
+    ImGuiTestEngine* engine,
+    const char* encoder_path,
+    const char* encoder_params
+);
+// True when capture support is compiled in and the configured encoder (e.g. ffmpeg) exists.
+bool imgui_test_engine_is_video_capture_available(ImGuiTestEngine* engine);
+
+void imgui_test_engine_ctx_capture_reset(ImGuiTestContext* ctx);
+bool imgui_test_engine_ctx_capture_add_window(ImGuiTestContext* ctx, const char* ref);
+// Returns false if `path` does not fit the capture tool's output buffer.
+bool imgui_test_engine_ctx_capture_set_output_file(ImGuiTestContext* ctx, const char* path);
+void imgui_test_engine_ctx_capture_set_padding(ImGuiTestContext* ctx, float padding);
+bool imgui_test_engine_ctx_capture_screenshot(ImGuiTestContext* ctx, int capture_flags);
+bool imgui_test_engine_ctx_capture_begin_video(ImGuiTestContext* ctx, int capture_flags);
+bool imgui_test_engine_ctx_capture_end_video(ImGuiTestContext* ctx);
+
 #ifdef __cplusplus
 }
 #endif
@@ -0,0 +1,356 @@
+// Native (Rust callback) tests for Rust consumers.
+// This file is part of dear-imgui-rs and is licensed under MIT OR Apache-2.0.
+
+#include <string>
+#include <unordered_map>
+#include <vector>
+
+#define IMGUI_DEFINE_MATH_OPERATORS
+#include "imgui.h"
+#include "imgui_internal.h"
+
+#include "imgui_te_context.h"
+#include "imgui_te_engine.h" // ImGuiTestEngine_RegisterTest(), ImGuiTestEngine_Check()
+
+#include "cimgui_test_engine.h"
+
+namespace {
+
+struct NativeTest {
+    std::string Category{};
+    std::string SourceFile{};
+    ImGuiTestEngineNativeFunc GuiFunc = nullptr;
+    ImGuiTestEngineNativeFunc TestFunc = nullptr;
+    void* UserData = nullptr;
+    ImGuiTestEngineUserDataDrop UserDataDrop = nullptr;
+};
+
+static std::unordered_map<ImGuiTestEngine*, std::vector<NativeTest*>> g_native_tests_by_engine;
+
+static void native_free_for_engine(ImGuiTestEngine* engine) {
+    auto it = g_native_tests_by_engine.find(engine);
+    if (it == g_native_tests_by_engine.end()) {
+        return;
+    }
+    for (NativeTest* test : it->second) {
+        if (test->UserDataDrop != nullptr) {
+            test->UserDataDrop(test->UserData);
+        }
+        delete test;
+    }
+    g_native_tests_by_engine.erase(it);
+}
+
+static NativeTest* native_test_from_ctx(ImGuiTestContext* ctx) {
+    if (ctx == nullptr || ctx->Test == nullptr) {
+        return nullptr;
+    }
+    return static_cast<NativeTest*>(ctx->Test->UserData);
+}
+
+static void native_gui_func(ImGuiTestContext* ctx) {
+    NativeTest* test = native_test_from_ctx(ctx);
+    if (test == nullptr || test->GuiFunc == nullptr) {
+        return;
+    }
+    test->GuiFunc(ctx, test->UserData);
+}
+
+static void native_test_func(ImGuiTestContext* ctx) {
+    NativeTest* test = native_test_from_ctx(ctx);
+    if (test == nullptr || test->TestFunc == nullptr) {
+        return;
+    }
+    test->TestFunc(ctx, test->UserData);
+}
+
+// Default test function of perf tests registered without one: measure the GUI function.
+static void native_perf_capture_func(ImGuiTestContext* ctx) { ctx->PerfCapture(); }
+
+static void native_register(
+    ImGuiTestEngine* engine,
+    ImGuiTestGroup group,
+    const char* category,
+    const char* name,
+    const char* source_file,
+    int source_line,
+    ImGuiTestEngineNativeFunc gui_func,
+    ImGuiTestEngineNativeFunc test_func,
+    void* user_data,
+    ImGuiTestEngineUserDataDrop user_data_drop
+) {
+    if (engine == nullptr || category == nullptr || name == nullptr) {
+        if (user_data_drop != nullptr) {
+            user_data_drop(user_data);
+        }
+        return;
+    }
+
+    NativeTest* test = new NativeTest();
+    test->Category = category;
+    test->SourceFile = source_file ? source_file : "";
+    test->GuiFunc = gui_func;
+    test->TestFunc = test_func;
+    test->UserData = user_data;
+    test->UserDataDrop = user_data_drop;
+
+    // Register and make sure the test name is owned (category and source file are kept alive by
+    // the native test).
+    ImGuiTest* t = ImGuiTestEngine_RegisterTest(
+        engine,
+        test->Category.c_str(),
+        name,
+        test->SourceFile.c_str(),
+        source_line
+    );
+    t->SetOwnedName(name);
+    t->Group = group;
+    t->UserData = test;
+    t->GuiFunc = gui_func ? native_gui_func : nullptr;
+    if (test_func != nullptr) {
+        t->TestFunc = native_test_func;
+    } else {
+        t->TestFunc = group == ImGuiTestGroup_Perfs ? native_perf_capture_func : nullptr;
+    }
+
+    g_native_tests_by_engine[engine].push_back(test);
+}
+
+} // namespace
+
+// Called from imgui_test_engine_destroy_context().
+void imgui_test_engine__native_cleanup(ImGuiTestEngine* engine) { native_free_for_engine(engine); }
+
+extern "C" {
+
+void imgui_test_engine_register_native_test(
+    ImGuiTestEngine* engine,
+    const char* category,
+    const char* name,
+    const char* source_file,
+    int source_line,
+    ImGuiTestEngineNativeFunc gui_func,
+    ImGuiTestEngineNativeFunc test_func,
+    void* user_data,
+    ImGuiTestEngineUserDataDrop user_data_drop
+) {
+    native_register(
+        engine,
+        ImGuiTestGroup_Tests,
+        category,
+        name,
+        source_file,
+        source_line,
+        gui_func,
+        test_func,
+        user_data,
+        user_data_drop
+    );
+}
+
+void imgui_test_engine_register_native_perf_test(
+    ImGuiTestEngine* engine,
+    const char* category,
+    const char* name,
+    const char* source_file,
+    int source_line,
+    ImGuiTestEngineNativeFunc gui_func,
+    ImGuiTestEngineNativeFunc test_func,
+    void* user_data,
+    ImGuiTestEngineUserDataDrop user_data_drop
+) {
+    native_register(
+        engine,
+        ImGuiTestGroup_Perfs,
+        category,
+        name,
+        source_file,
+        source_line,
+        gui_func,
+        test_func,
+        user_data,
+        user_data_drop
+    );
+}
+
+ImGuiContext* imgui_test_engine_ctx_get_ui_context(ImGuiTestContext* ctx) {
+    if (ctx == nullptr) {
+        return nullptr;
+    }
+    return ctx->UiContext;
+}
+
+bool imgui_test_engine_ctx_is_error(ImGuiTestContext* ctx) {
+    if (ctx == nullptr) {
+        return true;
+    }
+    return ctx->IsError();
+}
+
+bool imgui_test_engine_ctx_check(
+    ImGuiTestContext* ctx,
+    bool result,
+    const char* expr,
+    const char* file,
+    int line
+) {
+    if (ctx == nullptr) {
+        return result;
+    }
+    ImGuiTestEngine_Check(
+        file ? file : "",
+        "",
+        line,
+        ImGuiTestCheckFlags_None,
+        result,
+        expr ? expr : ""
+    );
+    return result;
+}
+
+void imgui_test_engine_ctx_log_info(ImGuiTestContext* ctx, const char* message) {
+    if (ctx == nullptr || message == nullptr) {
+        return;
+    }
+    ctx->LogInfo("%s", message);
+}
+
+void imgui_test_engine_ctx_log_error(ImGuiTestContext* ctx, const char* message) {
+    if (ctx == nullptr || message == nullptr) {
+        return;
+    }
+    ctx->LogError("%s", message);
+}
+
+void imgui_test_engine_ctx_set_ref(ImGuiTestContext* ctx, const char* ref) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->SetRef(ref ? ref : "");
+}
+
+void imgui_test_engine_ctx_item_click(ImGuiTestContext* ctx, const char* ref, int button) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->ItemClick(ref ? ref : "", static_cast<ImGuiMouseButton>(button));
+}
+
+void imgui_test_engine_ctx_item_double_click(ImGuiTestContext* ctx, const char* ref) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->ItemDoubleClick(ref ? ref : "");
+}
+
+void imgui_test_engine_ctx_item_open(ImGuiTestContext* ctx, const char* ref) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->ItemOpen(ref ? ref : "");
+}
+
+void imgui_test_engine_ctx_item_close(ImGuiTestContext* ctx, const char* ref) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->ItemClose(ref ? ref : "");
+}
+
+void imgui_test_engine_ctx_item_check(ImGuiTestContext* ctx, const char* ref) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->ItemCheck(ref ? ref : "");
+}
+
+void imgui_test_engine_ctx_item_uncheck(ImGuiTestContext* ctx, const char* ref) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->ItemUncheck(ref ? ref : "");
+}
+
+void imgui_test_engine_ctx_item_input_int(ImGuiTestContext* ctx, const char* ref, int v) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->ItemInputValue(ref ? ref : "", v);
+}
+
+void imgui_test_engine_ctx_item_input_str(ImGuiTestContext* ctx, const char* ref, const char* v) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->ItemInputValue(ref ? ref : "", v ? v : "");
+}
+
+bool imgui_test_engine_ctx_item_exists(ImGuiTestContext* ctx, const char* ref) {
+    if (ctx == nullptr) {
+        return false;
+    }
+    return ctx->ItemExists(ref ? ref : "");
+}
+
+void imgui_test_engine_ctx_mouse_move(ImGuiTestContext* ctx, const char* ref) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->MouseMove(ref ? ref : "");
+}
+
+void imgui_test_engine_ctx_key_press(ImGuiTestContext* ctx, int key_chord, int count) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->KeyPress(static_cast<ImGuiKeyChord>(key_chord), count);
+}
+
+void imgui_test_engine_ctx_key_chars(ImGuiTestContext* ctx, const char* chars) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->KeyChars(chars ? chars : "");
+}
+
+void imgui_test_engine_ctx_perf_capture(ImGuiTestContext* ctx) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->PerfCapture();
+}
+
+void imgui_test_engine_ctx_set_perf_iterations(ImGuiTestContext* ctx, int iterations) {
+    if (ctx == nullptr || iterations <= 0) {
+        return;
+    }
+    ctx->PerfIterations = iterations;
+}
+
+void imgui_test_engine_ctx_sleep(ImGuiTestContext* ctx, float time_in_seconds) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->Sleep(time_in_seconds);
+}
+
+void imgui_test_engine_ctx_yield(ImGuiTestContext* ctx, int frames) {
+    if (ctx == nullptr) {
+        return;
+    }
+    ctx->Yield(frames);
+}
+
+void imgui_test_engine_ctx_yield_with_delta_time(ImGuiTestContext* ctx, int frames, float delta_time) {
+    if (ctx == nullptr || ctx->UiContext == nullptr || delta_time <= 0.0f) {
+        return;
+    }
+    // The test coroutine runs from the NewFrame() pre-hook, after the backend wrote io.DeltaTime
+    // and before Dear ImGui consumes it, so overriding it here controls the frame being started.
+    for (int n = 0; n < frames; n++) {
+        ctx->UiContext->IO.DeltaTime = delta_time;
+        ctx->Yield(1);
+    }
+}
+
+} // extern "C"
@@ -101,6 +101,7 @@ struct ImGuiTestEngineScript {
         WaitForItemChecked,
         WaitForItemOpened,
         Yield,
+        YieldWithDeltaTime,
     };
 
     struct Cmd {
@@ -688,6 +689,9 @@ static void script_test_func(ImGuiTestContext* ctx) {
             case ImGuiTestEngineScript::CmdKind::Yield:
                 ctx->Yield(cmd.I);
                 break;
+            case ImGuiTestEngineScript::CmdKind::YieldWithDeltaTime:
+                imgui_test_engine_ctx_yield_with_delta_time(ctx, cmd.I, cmd.F);
+                break;
         }
     }
 }
@@ -1639,6 +1643,17 @@ void imgui_test_engine_script_yield(ImGuiTestEngineScript* script, int frames) {
     script->Cmds.push_back(std::move(cmd));
 }
 
+void imgui_test_engine_script_yield_with_delta_time(ImGuiTestEngineScript* script, int frames, float delta_time) {
+    if (script == nullptr) {
+        return;
+    }
+    ImGuiTestEngineScript::Cmd cmd;
+    cmd.Kind = ImGuiTestEngineScript::CmdKind::YieldWithDeltaTime;
+    cmd.I = frames;
+    cmd.F = delta_time;
+    script->Cmds.push_back(std::move(cmd));
+}
+
 void imgui_test_engine_register_script_test(
     ImGuiTestEngine* engine,
     const char* category,
@@ -13,6 +13,11 @@ pub struct ImGuiTestEngine {
 pub struct ImGuiTestEngineScript {
     _unused: [u8; 0],
 }
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ImGuiTestContext {
+    _unused: [u8; 0],
+}
 pub const ImGuiTestEngineRunSpeed_Fast: ImGuiTestEngineRunSpeed = 0;
 pub const ImGuiTestEngineRunSpeed_Normal: ImGuiTestEngineRunSpeed = 1;
 pub const ImGuiTestEngineRunSpeed_Cinematic: ImGuiTestEngineRunSpeed = 2;
@@ -39,6 +44,54 @@ pub const ImGuiTestEngineRunFlags_NoError: ImGuiTestEngineRunFlags = 1024;
 pub const ImGuiTestEngineRunFlags_ShareVars: ImGuiTestEngineRunFlags = 2048;
 pub const ImGuiTestEngineRunFlags_ShareTestContext: ImGuiTestEngineRunFlags = 4096;
 pub type ImGuiTestEngineRunFlags = ::std::os::raw::c_int;
+pub const ImGuiTestEngineCaptureFlags_None: ImGuiTestEngineCaptureFlags = 0;
+pub const ImGuiTestEngineCaptureFlags_StitchAll: ImGuiTestEngineCaptureFlags = 1;
+pub const ImGuiTestEngineCaptureFlags_IncludeOtherWindows: ImGuiTestEngineCaptureFlags = 2;
+pub const ImGuiTestEngineCaptureFlags_IncludeTooltipsAndPopups: ImGuiTestEngineCaptureFlags = 4;
+pub const ImGuiTestEngineCaptureFlags_HideMouseCursor: ImGuiTestEngineCaptureFlags = 8;
+pub const ImGuiTestEngineCaptureFlags_Instant: ImGuiTestEngineCaptureFlags = 16;
+pub const ImGuiTestEngineCaptureFlags_NoSave: ImGuiTestEngineCaptureFlags = 32;
+pub type ImGuiTestEngineCaptureFlags = ::std::os::raw::c_int;
+pub const ImGuiTestEngineResultsFormat_None: ImGuiTestEngineResultsFormat = 0;
+pub const ImGuiTestEngineResultsFormat_JUnitXml: ImGuiTestEngineResultsFormat = 1;
+pub type ImGuiTestEngineResultsFormat = ::std::os::raw::c_int;
+pub const ImGuiTestEngineTestStatus_Unknown: ImGuiTestEngineTestStatus = 0;
+pub const ImGuiTestEngineTestStatus_Success: ImGuiTestEngineTestStatus = 1;
+pub const ImGuiTestEngineTestStatus_Queued: ImGuiTestEngineTestStatus = 2;
+pub const ImGuiTestEngineTestStatus_Running: ImGuiTestEngineTestStatus = 3;
+pub const ImGuiTestEngineTestStatus_Error: ImGuiTestEngineTestStatus = 4;
+pub const ImGuiTestEngineTestStatus_Suspended: ImGuiTestEngineTestStatus = 5;
+pub type ImGuiTestEngineTestStatus = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct ImGuiTestEngineTestInfo_c {
+    pub Category: *const ::std::os::raw::c_char,
+    pub Name: *const ::std::os::raw::c_char,
+    pub Group: ImGuiTestEngineGroup,
+    pub Status: ImGuiTestEngineTestStatus,
+    pub SourceFile: *const ::std::os::raw::c_char,
+    pub SourceLine: ::std::os::raw::c_int,
+}
+impl Default for ImGuiTestEngineTestInfo_c {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct ImGuiTestEngineIOConfig_c {
+    pub RunSpeed: ImGuiTestEngineRunSpeed,
+    pub VerboseLevel: ImGuiTestEngineVerboseLevel,
+    pub CaptureEnabled: bool,
+    pub CaptureOnError: bool,
+    pub StopOnError: bool,
+    pub BreakOnError: bool,
+    pub NoThrottle: bool,
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub struct ImGuiTestEngineResultSummary_c {
@@ -78,6 +131,9 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn imgui_test_engine_show_windows(engine: *mut ImGuiTestEngine, p_open: *mut bool);
 }
+unsafe extern "C" {
+    pub fn imgui_test_engine_show_perf_tool(engine: *mut ImGuiTestEngine, p_open: *mut bool);
+}
 unsafe extern "C" {
     pub fn imgui_test_engine_queue_tests(
         engine: *mut ImGuiTestEngine,
@@ -86,6 +142,13 @@ unsafe extern "C" {
         run_flags: ::std::os::raw::c_int,
     );
 }
+unsafe extern "C" {
+    pub fn imgui_test_engine_queue_test(
+        engine: *mut ImGuiTestEngine,
+        index: ::std::os::raw::c_int,
+        run_flags: ::std::os::raw::c_int,
+    ) -> bool;
+}
 unsafe extern "C" {
     pub fn imgui_test_engine_is_test_queue_empty(engine: *mut ImGuiTestEngine) -> bool;
 }
@@ -101,6 +164,24 @@ unsafe extern "C" {
         out_summary: *mut ImGuiTestEngineResultSummary_c,
     );
 }
+unsafe extern "C" {
+    pub fn imgui_test_engine_get_test_count(engine: *mut ImGuiTestEngine) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_get_test_info(
+        engine: *mut ImGuiTestEngine,
+        index: ::std::os::raw::c_int,
+        out_info: *mut ImGuiTestEngineTestInfo_c,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_get_test_last_error(
+        engine: *mut ImGuiTestEngine,
+        index: ::std::os::raw::c_int,
+        out_buf: *mut ::std::os::raw::c_char,
+        buf_size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
 unsafe extern "C" {
     pub fn imgui_test_engine_set_run_speed(
         engine: *mut ImGuiTestEngine,
@@ -116,6 +197,21 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn imgui_test_engine_set_capture_enabled(engine: *mut ImGuiTestEngine, enabled: bool);
 }
+unsafe extern "C" {
+    pub fn imgui_test_engine_set_stop_on_error(engine: *mut ImGuiTestEngine, enabled: bool);
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_set_break_on_error(engine: *mut ImGuiTestEngine, enabled: bool);
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_set_no_throttle(engine: *mut ImGuiTestEngine, enabled: bool);
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_get_io_config(
+        engine: *mut ImGuiTestEngine,
+        out_config: *mut ImGuiTestEngineIOConfig_c,
+    );
+}
 unsafe extern "C" {
     pub fn imgui_test_engine_is_running_tests(engine: *mut ImGuiTestEngine) -> bool;
 }
@@ -125,6 +221,13 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn imgui_test_engine_install_default_crash_handler();
 }
+unsafe extern "C" {
+    pub fn imgui_test_engine_export_results(
+        engine: *mut ImGuiTestEngine,
+        format: ImGuiTestEngineResultsFormat,
+        filename: *const ::std::os::raw::c_char,
+    ) -> bool;
+}
 unsafe extern "C" {
     pub fn imgui_test_engine_register_default_tests(engine: *mut ImGuiTestEngine);
 }
@@ -669,6 +772,13 @@ unsafe extern "C" {
         frames: ::std::os::raw::c_int,
     );
 }
+unsafe extern "C" {
+    pub fn imgui_test_engine_script_yield_with_delta_time(
+        script: *mut ImGuiTestEngineScript,
+        frames: ::std::os::raw::c_int,
+        delta_time: f32,
+    );
+}
 unsafe extern "C" {
     pub fn imgui_test_engine_register_script_test(
         engine: *mut ImGuiTestEngine,
@@ -677,3 +787,229 @@ unsafe extern "C" {
         script: *mut ImGuiTestEngineScript,
     );
 }
+pub type ImGuiTestEngineNativeFunc = ::std::option::Option<
+    unsafe extern "C" fn(ctx: *mut ImGuiTestContext, user_data: *mut ::std::os::raw::c_void),
+>;
+pub type ImGuiTestEngineUserDataDrop =
+    ::std::option::Option<unsafe extern "C" fn(user_data: *mut ::std::os::raw::c_void)>;
+unsafe extern "C" {
+    pub fn imgui_test_engine_register_native_test(
+        engine: *mut ImGuiTestEngine,
+        category: *const ::std::os::raw::c_char,
+        name: *const ::std::os::raw::c_char,
+        source_file: *const ::std::os::raw::c_char,
+        source_line: ::std::os::raw::c_int,
+        gui_func: ImGuiTestEngineNativeFunc,
+        test_func: ImGuiTestEngineNativeFunc,
+        user_data: *mut ::std::os::raw::c_void,
+        user_data_drop: ImGuiTestEngineUserDataDrop,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_register_native_perf_test(
+        engine: *mut ImGuiTestEngine,
+        category: *const ::std::os::raw::c_char,
+        name: *const ::std::os::raw::c_char,
+        source_file: *const ::std::os::raw::c_char,
+        source_line: ::std::os::raw::c_int,
+        gui_func: ImGuiTestEngineNativeFunc,
+        test_func: ImGuiTestEngineNativeFunc,
+        user_data: *mut ::std::os::raw::c_void,
+        user_data_drop: ImGuiTestEngineUserDataDrop,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_get_ui_context(ctx: *mut ImGuiTestContext) -> *mut ImGuiContext;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_is_error(ctx: *mut ImGuiTestContext) -> bool;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_check(
+        ctx: *mut ImGuiTestContext,
+        result: bool,
+        expr: *const ::std::os::raw::c_char,
+        file: *const ::std::os::raw::c_char,
+        line: ::std::os::raw::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_log_info(
+        ctx: *mut ImGuiTestContext,
+        message: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_log_error(
+        ctx: *mut ImGuiTestContext,
+        message: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_set_ref(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_item_click(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+        button: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_item_double_click(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_item_open(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_item_close(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_item_check(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_item_uncheck(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_item_input_int(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+        v: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_item_input_str(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+        v: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_item_exists(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_mouse_move(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_key_press(
+        ctx: *mut ImGuiTestContext,
+        key_chord: ::std::os::raw::c_int,
+        count: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_key_chars(
+        ctx: *mut ImGuiTestContext,
+        chars: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_perf_capture(ctx: *mut ImGuiTestContext);
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_set_perf_iterations(
+        ctx: *mut ImGuiTestContext,
+        iterations: ::std::os::raw::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_sleep(ctx: *mut ImGuiTestContext, time_in_seconds: f32);
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_yield(ctx: *mut ImGuiTestContext, frames: ::std::os::raw::c_int);
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_yield_with_delta_time(
+        ctx: *mut ImGuiTestContext,
+        frames: ::std::os::raw::c_int,
+        delta_time: f32,
+    );
+}
+pub type ImGuiTestEngineScreenCaptureFunc = ::std::option::Option<
+    unsafe extern "C" fn(
+        viewport_id: ::std::os::raw::c_uint,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        pixels: *mut ::std::os::raw::c_uint,
+        user_data: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+unsafe extern "C" {
+    pub fn imgui_test_engine_set_screen_capture_func(
+        engine: *mut ImGuiTestEngine,
+        func: ImGuiTestEngineScreenCaptureFunc,
+        user_data: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_set_capture_on_error(engine: *mut ImGuiTestEngine, enabled: bool);
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_set_video_capture_encoder(
+        engine: *mut ImGuiTestEngine,
+        encoder_path: *const ::std::os::raw::c_char,
+        encoder_params: *const ::std::os::raw::c_char,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_is_video_capture_available(engine: *mut ImGuiTestEngine) -> bool;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_capture_reset(ctx: *mut ImGuiTestContext);
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_capture_add_window(
+        ctx: *mut ImGuiTestContext,
+        ref_: *const ::std::os::raw::c_char,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_capture_set_output_file(
+        ctx: *mut ImGuiTestContext,
+        path: *const ::std::os::raw::c_char,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_capture_set_padding(ctx: *mut ImGuiTestContext, padding: f32);
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_capture_screenshot(
+        ctx: *mut ImGuiTestContext,
+        capture_flags: ::std::os::raw::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_capture_begin_video(
+        ctx: *mut ImGuiTestContext,
+        capture_flags: ::std::os::raw::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn imgui_test_engine_ctx_capture_end_video(ctx: *mut ImGuiTestContext) -> bool;
+}
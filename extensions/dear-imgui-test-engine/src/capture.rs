@@ -0,0 +1,169 @@
+use bitflags::bitflags;
+use dear_imgui_rs::{ImGuiError, ImGuiResult};
+use dear_imgui_test_engine_sys as sys;
+use std::{
+    ffi::c_void,
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+};
+
+/// Size of the capture tool's fixed output path buffer, including the terminating NUL.
+pub(crate) const CAPTURE_PATH_CAPACITY: usize = 256;
+
+bitflags! {
+    /// Options for screenshots and videos taken with [`TestContext`](crate::TestContext).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct CaptureFlags: i32 {
+        const NONE = sys::ImGuiTestEngineCaptureFlags_None;
+        /// Capture the entire window area by scrolling and stitching several captures.
+        const STITCH_ALL = sys::ImGuiTestEngineCaptureFlags_StitchAll;
+        /// Keep other windows visible instead of hiding them during the capture.
+        const INCLUDE_OTHER_WINDOWS = sys::ImGuiTestEngineCaptureFlags_IncludeOtherWindows;
+        /// Also capture tooltips and popups above the captured windows.
+        const INCLUDE_TOOLTIPS_AND_POPUPS = sys::ImGuiTestEngineCaptureFlags_IncludeTooltipsAndPopups;
+        const HIDE_MOUSE_CURSOR = sys::ImGuiTestEngineCaptureFlags_HideMouseCursor;
+        /// Capture in the current frame without hiding or repositioning windows.
+        const INSTANT = sys::ImGuiTestEngineCaptureFlags_Instant;
+        /// Do not write a file; only the capture tool's internal buffer is filled.
+        const NO_SAVE = sys::ImGuiTestEngineCaptureFlags_NoSave;
+    }
+}
+
+/// What to capture and where to write it.
+///
+/// Without any window the capture covers the whole viewport. Without an output file the capture
+/// tool picks a name under the engine's output folder.
+#[derive(Debug, Clone, Default)]
+pub struct CaptureArgs {
+    windows: Vec<String>,
+    output_file: Option<PathBuf>,
+    padding: Option<f32>,
+    flags: CaptureFlags,
+}
+
+impl CaptureArgs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a window (by test reference, e.g. `"//My Window"`) to the capture.
+    pub fn window(mut self, window_ref: impl Into<String>) -> Self {
+        self.windows.push(window_ref.into());
+        self
+    }
+
+    /// Sets the output file. The extension selects the format (`.png`, or `.mp4`/`.gif` for videos).
+    pub fn output_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.output_file = Some(path.into());
+        self
+    }
+
+    /// Sets the padding in pixels around the captured windows (capture tool default: 16).
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    pub fn flags(mut self, flags: CaptureFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub(crate) fn windows(&self) -> &[String] {
+        &self.windows
+    }
+
+    pub(crate) fn padding_value(&self) -> Option<f32> {
+        self.padding
+    }
+
+    pub(crate) fn flags_value(&self) -> CaptureFlags {
+        self.flags
+    }
+
+    /// Validates every argument so nothing reaches the capture tool unless all of it is usable.
+    pub(crate) fn validate(&self) -> ImGuiResult<Option<&str>> {
+        for window in &self.windows {
+            if window.contains('\0') {
+                return Err(ImGuiError::invalid_operation(
+                    "capture window reference contained interior NUL",
+                ));
+            }
+        }
+        if let Some(padding) = self.padding
+            && (!padding.is_finite() || padding < 0.0)
+        {
+            return Err(ImGuiError::invalid_operation(
+                "capture padding must be finite and non-negative",
+            ));
+        }
+        self.output_file
+            .as_deref()
+            .map(|path| capture_path_str(path, "capture output file"))
+            .transpose()
+    }
+}
+
+/// Converts `path` for one of the engine's fixed-size path buffers.
+pub(crate) fn capture_path_str<'a>(path: &'a Path, what: &str) -> ImGuiResult<&'a str> {
+    let Some(path) = path.to_str() else {
+        return Err(ImGuiError::invalid_operation(format!(
+            "{what} is not valid UTF-8"
+        )));
+    };
+    if path.contains('\0') {
+        return Err(ImGuiError::invalid_operation(format!(
+            "{what} contained interior NUL"
+        )));
+    }
+    if path.len() >= CAPTURE_PATH_CAPACITY {
+        return Err(ImGuiError::invalid_operation(format!(
+            "{what} must be shorter than {CAPTURE_PATH_CAPACITY} bytes"
+        )));
+    }
+    Ok(path)
+}
+
+/// Framebuffer region requested by the capture tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaptureRect {
+    pub viewport_id: u32,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+pub(crate) type ScreenCaptureFn = Box<dyn FnMut(CaptureRect, &mut [u32]) -> bool>;
+
+pub(crate) unsafe extern "C" fn screen_capture_trampoline(
+    viewport_id: u32,
+    x: i32,
+    y: i32,
+    w: i32,
+    h: i32,
+    pixels: *mut u32,
+    user_data: *mut c_void,
+) -> bool {
+    if user_data.is_null() || pixels.is_null() {
+        return false;
+    }
+    let (Ok(width), Ok(height)) = (u32::try_from(w), u32::try_from(h)) else {
+        return false;
+    };
+    let Some(len) = (width as usize).checked_mul(height as usize) else {
+        return false;
+    };
+    let rect = CaptureRect {
+        viewport_id,
+        x,
+        y,
+        width,
+        height,
+    };
+    // Safety: the capture tool hands us a `w * h` RGBA8 buffer, and `user_data` is the boxed
+    // callback installed by `TestEngine::set_screen_capture_func()`.
+    let pixels = unsafe { std::slice::from_raw_parts_mut(pixels, len) };
+    let callback = unsafe { &mut *(user_data as *mut ScreenCaptureFn) };
+    std::panic::catch_unwind(AssertUnwindSafe(|| callback(rect, pixels))).unwrap_or(false)
+}
@@ -20,6 +20,56 @@ pub enum VerboseLevel {
     Trace = sys::ImGuiTestEngineVerboseLevel_Trace as i32,
 }
 
+impl RunSpeed {
+    pub(crate) fn from_raw(raw: sys::ImGuiTestEngineRunSpeed) -> Self {
+        match raw {
+            sys::ImGuiTestEngineRunSpeed_Normal => Self::Normal,
+            sys::ImGuiTestEngineRunSpeed_Cinematic => Self::Cinematic,
+            _ => Self::Fast,
+        }
+    }
+}
+
+impl VerboseLevel {
+    pub(crate) fn from_raw(raw: sys::ImGuiTestEngineVerboseLevel) -> Self {
+        match raw {
+            sys::ImGuiTestEngineVerboseLevel_Silent => Self::Silent,
+            sys::ImGuiTestEngineVerboseLevel_Error => Self::Error,
+            sys::ImGuiTestEngineVerboseLevel_Warning => Self::Warning,
+            sys::ImGuiTestEngineVerboseLevel_Debug => Self::Debug,
+            sys::ImGuiTestEngineVerboseLevel_Trace => Self::Trace,
+            _ => Self::Info,
+        }
+    }
+}
+
+/// Snapshot of the engine's `ImGuiTestEngineIO` settings, returned by
+/// [`TestEngine::io_config`](crate::TestEngine::io_config).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EngineIoConfig {
+    pub run_speed: RunSpeed,
+    pub verbose_level: VerboseLevel,
+    pub capture_enabled: bool,
+    pub capture_on_error: bool,
+    pub stop_on_error: bool,
+    pub break_on_error: bool,
+    pub no_throttle: bool,
+}
+
+impl EngineIoConfig {
+    pub(crate) fn from_raw(raw: &sys::ImGuiTestEngineIOConfig_c) -> Self {
+        Self {
+            run_speed: RunSpeed::from_raw(raw.RunSpeed),
+            verbose_level: VerboseLevel::from_raw(raw.VerboseLevel),
+            capture_enabled: raw.CaptureEnabled,
+            capture_on_error: raw.CaptureOnError,
+            stop_on_error: raw.StopOnError,
+            break_on_error: raw.BreakOnError,
+            no_throttle: raw.NoThrottle,
+        }
+    }
+}
+
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InputMode {
@@ -36,6 +86,14 @@ pub enum TestGroup {
     Perfs = sys::ImGuiTestEngineGroup_Perfs,
 }
 
+/// File format for [`TestEngine::export_results`](crate::TestEngine::export_results).
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// JUnit XML, understood by most CI systems (GitHub Actions reporters, GitLab, Jenkins).
+    JUnitXml = sys::ImGuiTestEngineResultsFormat_JUnitXml,
+}
+
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct RunFlags: u32 {
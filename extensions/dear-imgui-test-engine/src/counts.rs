@@ -1,3 +1,4 @@
+use dear_imgui_rs::{ImGuiError, ImGuiResult};
 use std::num::NonZeroU32;
 
 /// Positive script count or frame count for test-engine actions.
@@ -76,3 +77,13 @@ impl From<u32> for ScriptLimit {
         Self::new(limit)
     }
 }
+
+/// Validates a simulated frame duration for the `yield_*_delta_time` helpers.
+pub(crate) fn check_delta_time(caller: &str, delta_time: f32) -> ImGuiResult<()> {
+    if !delta_time.is_finite() || delta_time <= 0.0 {
+        return Err(ImGuiError::invalid_operation(format!(
+            "{caller} requires finite positive delta times"
+        )));
+    }
+    Ok(())
+}
@@ -1,10 +1,32 @@
 use dear_imgui_rs::{
-    Context, ContextAliveToken, ImGuiError, ImGuiResult, Ui, with_scratch_txt, with_scratch_txt_two,
+    Context, ContextAliveToken, ImGuiError, ImGuiResult, Ui, with_scratch_txt,
+    with_scratch_txt_three, with_scratch_txt_two,
 };
 use dear_imgui_test_engine_sys as sys;
-use std::{marker::PhantomData, rc::Rc};
+use std::{cell::RefCell, marker::PhantomData, path::Path, rc::Rc};
 
-use crate::{ResultSummary, RunFlags, RunSpeed, Script, ScriptTest, TestGroup, VerboseLevel};
+use crate::capture::{CaptureRect, ScreenCaptureFn, capture_path_str, screen_capture_trampoline};
+use crate::native::{
+    GuiBinding, GuiFn, NativeTest, TestFn, drop_native_test, native_gui_trampoline,
+    native_test_trampoline,
+};
+use crate::{
+    EngineIoConfig, ExportFormat, ResultSummary, RunFlags, RunSpeed, Script, ScriptTest,
+    TestContext, TestGroup, TestResult, VerboseLevel,
+};
+
+type NativeTrampoline = unsafe extern "C" fn(*mut sys::ImGuiTestContext, *mut std::ffi::c_void);
+type RegisterNativeFn = unsafe extern "C" fn(
+    *mut sys::ImGuiTestEngine,
+    *const std::os::raw::c_char,
+    *const std::os::raw::c_char,
+    *const std::os::raw::c_char,
+    std::os::raw::c_int,
+    sys::ImGuiTestEngineNativeFunc,
+    sys::ImGuiTestEngineNativeFunc,
+    *mut std::ffi::c_void,
+    sys::ImGuiTestEngineUserDataDrop,
+);
 
 /// Dear ImGui Test Engine context.
 ///
@@ -13,11 +35,14 @@ pub struct TestEngine {
     pub(super) raw: *mut sys::ImGuiTestEngine,
     pub(super) bound_imgui_ctx_raw: Option<*mut dear_imgui_rs::sys::ImGuiContext>,
     pub(super) bound_imgui_alive: Option<ContextAliveToken>,
+    pub(super) gui_binding: GuiBinding,
+    /// Kept alive for the capture tool; dropped after the engine context in `Drop`.
+    pub(super) screen_capture: Option<Box<ScreenCaptureFn>>,
     pub(super) _not_send_sync: PhantomData<Rc<()>>,
 }
 
 impl TestEngine {
-    fn assert_bound_imgui_alive(&self, caller: &str) {
+    pub(crate) fn assert_bound_imgui_alive(&self, caller: &str) {
         if let Some(alive) = &self.bound_imgui_alive {
             assert!(
                 alive.is_alive(),
@@ -38,6 +63,8 @@ impl TestEngine {
             raw,
             bound_imgui_ctx_raw: None,
             bound_imgui_alive: None,
+            gui_binding: Rc::new(RefCell::new(None)),
+            screen_capture: None,
             _not_send_sync: PhantomData,
         })
     }
@@ -102,6 +129,7 @@ impl TestEngine {
         unsafe { sys::imgui_test_engine_start(self.raw, ctx) };
         self.bound_imgui_ctx_raw = Some(ctx);
         self.bound_imgui_alive = Some(imgui_ctx.alive_token());
+        *self.gui_binding.borrow_mut() = Some(imgui_ctx.alive_token());
         Ok(())
     }
 
@@ -131,6 +159,7 @@ impl TestEngine {
         unsafe { sys::imgui_test_engine_unbind(self.raw) };
         self.bound_imgui_ctx_raw = None;
         self.bound_imgui_alive = None;
+        *self.gui_binding.borrow_mut() = None;
     }
 
     pub fn post_swap(&mut self) {
@@ -144,6 +173,14 @@ impl TestEngine {
         unsafe { sys::imgui_test_engine_show_windows(self.raw, ptr) };
     }
 
+    /// Shows the built-in perf tool window, which lists perf test results and compares them
+    /// against previous runs.
+    pub fn show_perf_tool(&mut self, _ui: &Ui, opened: Option<&mut bool>) {
+        self.assert_bound_imgui_alive("TestEngine::show_perf_tool()");
+        let ptr = opened.map_or(std::ptr::null_mut(), |b| b as *mut bool);
+        unsafe { sys::imgui_test_engine_show_perf_tool(self.raw, ptr) };
+    }
+
     /// Registers a small set of built-in demo tests (useful to validate integration).
     pub fn register_default_tests(&mut self) {
         self.assert_bound_imgui_alive("TestEngine::register_default_tests()");
@@ -182,6 +219,134 @@ impl TestEngine {
         Ok(())
     }
 
+    /// Registers a test implemented with Rust closures, like `IM_REGISTER_TEST` in C++.
+    ///
+    /// `gui` runs once per frame while the test is active, on the application thread from inside
+    /// `NewFrame()`, and should submit the UI under test. `test` runs on the test engine coroutine
+    /// thread and drives that UI through [`TestContext`]. State shared between the two must be
+    /// `Send + Sync` (e.g. `Arc<Mutex<_>>`).
+    ///
+    /// Panics in either closure are caught and reported as test failures. Both closures are
+    /// dropped when the engine is dropped.
+    #[track_caller]
+    pub fn add_test<G, T>(&mut self, category: &str, name: &str, gui: G, test: T) -> ImGuiResult<()>
+    where
+        G: FnMut(&Ui) + 'static,
+        T: FnMut(&mut TestContext<'_>) + Send + 'static,
+    {
+        self.assert_bound_imgui_alive("TestEngine::add_test()");
+        self.register_native(
+            "add_test",
+            sys::imgui_test_engine_register_native_test,
+            category,
+            name,
+            Box::new(gui),
+            Some(Box::new(test)),
+        )
+    }
+
+    /// Registers a performance test in the [`TestGroup::Perfs`] group.
+    ///
+    /// The engine renders `gui` for a fixed number of frames and records the average frame time,
+    /// which shows up in the perf tool window ([`Self::show_perf_tool`]) and in exported results.
+    /// Queue perf tests with `queue_tests(TestGroup::Perfs, ..)`.
+    #[track_caller]
+    pub fn add_perf_test<G>(&mut self, category: &str, name: &str, gui: G) -> ImGuiResult<()>
+    where
+        G: FnMut(&Ui) + 'static,
+    {
+        self.assert_bound_imgui_alive("TestEngine::add_perf_test()");
+        self.register_native(
+            "add_perf_test",
+            sys::imgui_test_engine_register_native_perf_test,
+            category,
+            name,
+            Box::new(gui),
+            None,
+        )
+    }
+
+    /// Registers a performance test whose `test` closure prepares the UI (opening windows,
+    /// filling inputs, ...) and then calls [`TestContext::perf_capture`] to measure `gui`.
+    #[track_caller]
+    pub fn add_perf_test_with<G, T>(
+        &mut self,
+        category: &str,
+        name: &str,
+        gui: G,
+        test: T,
+    ) -> ImGuiResult<()>
+    where
+        G: FnMut(&Ui) + 'static,
+        T: FnMut(&mut TestContext<'_>) + Send + 'static,
+    {
+        self.assert_bound_imgui_alive("TestEngine::add_perf_test_with()");
+        self.register_native(
+            "add_perf_test_with",
+            sys::imgui_test_engine_register_native_perf_test,
+            category,
+            name,
+            Box::new(gui),
+            Some(Box::new(test)),
+        )
+    }
+
+    #[track_caller]
+    fn register_native(
+        &mut self,
+        caller: &str,
+        register: RegisterNativeFn,
+        category: &str,
+        name: &str,
+        gui: GuiFn,
+        test: Option<TestFn>,
+    ) -> ImGuiResult<()> {
+        if category.contains('\0') {
+            return Err(ImGuiError::invalid_operation(format!(
+                "{caller} category contained interior NUL"
+            )));
+        }
+        if name.contains('\0') {
+            return Err(ImGuiError::invalid_operation(format!(
+                "{caller} name contained interior NUL"
+            )));
+        }
+
+        let location = std::panic::Location::caller();
+        let source_file = location.file().replace('\0', "");
+        let source_line = i32::try_from(location.line()).unwrap_or(i32::MAX);
+        let test_func = test
+            .is_some()
+            .then_some(native_test_trampoline as NativeTrampoline);
+        let native = Box::new(NativeTest {
+            binding: Rc::clone(&self.gui_binding),
+            gui,
+            test: test.unwrap_or_else(|| Box::new(|_: &mut TestContext<'_>| {})),
+        });
+        let user_data = Box::into_raw(native).cast::<std::ffi::c_void>();
+
+        with_scratch_txt_three(
+            category,
+            name,
+            &source_file,
+            |cat_ptr, name_ptr, file_ptr| unsafe {
+                register(
+                    self.raw,
+                    cat_ptr,
+                    name_ptr,
+                    file_ptr,
+                    source_line,
+                    Some(native_gui_trampoline),
+                    test_func,
+                    user_data,
+                    Some(drop_native_test),
+                )
+            },
+        );
+
+        Ok(())
+    }
+
     pub fn queue_tests(
         &mut self,
         group: TestGroup,
@@ -217,6 +382,17 @@ impl TestEngine {
         Ok(())
     }
 
+    /// Queues the registered test at `index` (see [`TestResult::index`]).
+    ///
+    /// Returns false if there is no test at `index`.
+    pub fn queue_test(&mut self, index: usize, run_flags: RunFlags) -> bool {
+        self.assert_bound_imgui_alive("TestEngine::queue_test()");
+        let Ok(index) = i32::try_from(index) else {
+            return false;
+        };
+        unsafe { sys::imgui_test_engine_queue_test(self.raw, index, run_flags.bits() as i32) }
+    }
+
     pub fn queue_all_tests(&mut self) {
         let _ = self.queue_tests(TestGroup::Tests, None, RunFlags::NONE);
     }
@@ -236,6 +412,47 @@ impl TestEngine {
         ResultSummary::from_raw(raw.CountTested, raw.CountSuccess, raw.CountInQueue)
     }
 
+    /// Iterates over every registered test with its current status, in registration order.
+    ///
+    /// Each item is a snapshot taken when it is yielded, so a custom runner UI can call this
+    /// every frame to show a live list.
+    pub fn tests(&self) -> impl Iterator<Item = TestResult> + '_ {
+        self.assert_bound_imgui_alive("TestEngine::tests()");
+        let count = unsafe { sys::imgui_test_engine_get_test_count(self.raw) };
+        (0..count.max(0)).filter_map(move |index| {
+            let mut raw = sys::ImGuiTestEngineTestInfo_c::default();
+            if !unsafe { sys::imgui_test_engine_get_test_info(self.raw, index, &mut raw) } {
+                return None;
+            }
+            let last_error = self.test_last_error(index);
+            // Safety: the shim points at strings owned by the registered test.
+            Some(unsafe { TestResult::from_raw(index as usize, &raw, last_error) })
+        })
+    }
+
+    /// Collects [`Self::tests`].
+    pub fn test_results(&self) -> Vec<TestResult> {
+        self.tests().collect()
+    }
+
+    fn test_last_error(&self, index: i32) -> Option<String> {
+        let len = unsafe {
+            sys::imgui_test_engine_get_test_last_error(self.raw, index, std::ptr::null_mut(), 0)
+        };
+        let len = usize::try_from(len).ok().filter(|&len| len > 0)?;
+        let mut buf = vec![0u8; len + 1];
+        let written = unsafe {
+            sys::imgui_test_engine_get_test_last_error(
+                self.raw,
+                index,
+                buf.as_mut_ptr().cast(),
+                i32::try_from(buf.len()).ok()?,
+            )
+        };
+        buf.truncate(usize::try_from(written).ok()?.min(len));
+        Some(String::from_utf8_lossy(&buf).into_owned())
+    }
+
     pub fn is_test_queue_empty(&self) -> bool {
         self.assert_bound_imgui_alive("TestEngine::is_test_queue_empty()");
         unsafe { sys::imgui_test_engine_is_test_queue_empty(self.raw) }
@@ -283,6 +500,154 @@ impl TestEngine {
         unsafe { sys::imgui_test_engine_set_capture_enabled(self.raw, enabled) };
     }
 
+    /// Stops running the remaining queued tests after the first failure.
+    pub fn set_stop_on_error(&mut self, enabled: bool) {
+        self.assert_bound_imgui_alive("TestEngine::set_stop_on_error()");
+        unsafe { sys::imgui_test_engine_set_stop_on_error(self.raw, enabled) };
+    }
+
+    /// Breaks into the attached debugger when a check fails.
+    ///
+    /// Without a debugger the break usually terminates the process, so only enable this while
+    /// debugging interactively.
+    pub fn set_break_on_error(&mut self, enabled: bool) {
+        self.assert_bound_imgui_alive("TestEngine::set_break_on_error()");
+        unsafe { sys::imgui_test_engine_set_break_on_error(self.raw, enabled) };
+    }
+
+    /// Asks the application not to throttle frames (vsync, idle sleeps) while tests run.
+    ///
+    /// The engine only records the request; frame loops read it through
+    /// [`Self::io_config`] or [`Self::is_requesting_max_app_speed`] and disable their own
+    /// throttling.
+    pub fn set_no_throttle(&mut self, enabled: bool) {
+        self.assert_bound_imgui_alive("TestEngine::set_no_throttle()");
+        unsafe { sys::imgui_test_engine_set_no_throttle(self.raw, enabled) };
+    }
+
+    /// Returns the current engine settings.
+    pub fn io_config(&self) -> EngineIoConfig {
+        self.assert_bound_imgui_alive("TestEngine::io_config()");
+        let mut raw = sys::ImGuiTestEngineIOConfig_c::default();
+        unsafe { sys::imgui_test_engine_get_io_config(self.raw, &mut raw) };
+        EngineIoConfig::from_raw(&raw)
+    }
+
+    /// Captures the screen when a test fails (requires capture to be enabled).
+    pub fn set_capture_on_error(&mut self, enabled: bool) {
+        self.assert_bound_imgui_alive("TestEngine::set_capture_on_error()");
+        unsafe { sys::imgui_test_engine_set_capture_on_error(self.raw, enabled) };
+    }
+
+    /// Installs the callback the capture tool uses to read back framebuffer pixels.
+    ///
+    /// The callback runs on the application thread from [`Self::post_swap`], so call `post_swap()`
+    /// after rendering and before presenting. It must fill the slice with `width * height` RGBA8
+    /// pixels of the requested region (top row first) and return true on success.
+    pub fn set_screen_capture_func<F>(&mut self, func: F)
+    where
+        F: FnMut(CaptureRect, &mut [u32]) -> bool + 'static,
+    {
+        self.assert_bound_imgui_alive("TestEngine::set_screen_capture_func()");
+        let mut callback: Box<ScreenCaptureFn> = Box::new(Box::new(func));
+        let user_data = (&mut *callback as *mut ScreenCaptureFn).cast();
+        unsafe {
+            sys::imgui_test_engine_set_screen_capture_func(
+                self.raw,
+                Some(screen_capture_trampoline),
+                user_data,
+            )
+        };
+        self.screen_capture = Some(callback);
+    }
+
+    pub fn clear_screen_capture_func(&mut self) {
+        self.assert_bound_imgui_alive("TestEngine::clear_screen_capture_func()");
+        unsafe {
+            sys::imgui_test_engine_set_screen_capture_func(self.raw, None, std::ptr::null_mut())
+        };
+        self.screen_capture = None;
+    }
+
+    /// Configures the external encoder used for video captures, typically an `ffmpeg` binary.
+    ///
+    /// `params` replaces the encoder command line template when given.
+    pub fn set_video_capture_encoder(
+        &mut self,
+        path: impl AsRef<Path>,
+        params: Option<&str>,
+    ) -> ImGuiResult<()> {
+        self.assert_bound_imgui_alive("TestEngine::set_video_capture_encoder()");
+        let path = capture_path_str(path.as_ref(), "video capture encoder path")?;
+        let params = params.unwrap_or_default();
+        if params.contains('\0') {
+            return Err(ImGuiError::invalid_operation(
+                "video capture encoder params contained interior NUL",
+            ));
+        }
+        let ok = if params.is_empty() {
+            with_scratch_txt(path, |path_ptr| unsafe {
+                sys::imgui_test_engine_set_video_capture_encoder(
+                    self.raw,
+                    path_ptr,
+                    std::ptr::null(),
+                )
+            })
+        } else {
+            with_scratch_txt_two(path, params, |path_ptr, params_ptr| unsafe {
+                sys::imgui_test_engine_set_video_capture_encoder(self.raw, path_ptr, params_ptr)
+            })
+        };
+        if !ok {
+            return Err(ImGuiError::invalid_operation(
+                "video capture encoder params are too long",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns true when capture support is compiled in and the configured video encoder exists.
+    pub fn is_video_capture_available(&self) -> bool {
+        self.assert_bound_imgui_alive("TestEngine::is_video_capture_available()");
+        unsafe { sys::imgui_test_engine_is_video_capture_available(self.raw) }
+    }
+
+    /// Writes per-test results of every test that ran to `path`.
+    ///
+    /// Unlike [`Self::result_summary`], the report lists each test with its status and failure
+    /// messages, so CI systems can show them individually.
+    pub fn export_results(&self, path: impl AsRef<Path>, format: ExportFormat) -> ImGuiResult<()> {
+        self.assert_bound_imgui_alive("TestEngine::export_results()");
+        let path = path.as_ref();
+        let Some(path_str) = path.to_str() else {
+            return Err(ImGuiError::invalid_operation(
+                "TestEngine::export_results() path is not valid UTF-8",
+            ));
+        };
+        if path_str.is_empty() || path_str.contains('\0') {
+            return Err(ImGuiError::invalid_operation(
+                "TestEngine::export_results() path must be non-empty and contain no NUL",
+            ));
+        }
+        // The exporter only reports I/O failures on stderr, so remove any stale report first and
+        // check that a fresh one was written.
+        let _ = std::fs::remove_file(path);
+        let exported = with_scratch_txt(path_str, |ptr| unsafe {
+            sys::imgui_test_engine_export_results(
+                self.raw,
+                format as sys::ImGuiTestEngineResultsFormat,
+                ptr,
+            )
+        });
+        if !exported || !path.is_file() {
+            return Err(ImGuiError::io_operation(format!(
+                "TestEngine::export_results() failed to write {}",
+                path.display()
+            )));
+        }
+        Ok(())
+    }
+
     pub fn install_default_crash_handler() {
         unsafe { sys::imgui_test_engine_install_default_crash_handler() };
     }
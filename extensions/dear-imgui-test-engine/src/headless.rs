@@ -0,0 +1,148 @@
+use dear_imgui_rs::{Context, ImGuiError, ImGuiResult, Ui};
+
+use crate::{ResultSummary, TestEngine, TestResult, TestStatus};
+
+/// Frame loop settings for [`TestEngine::run_tests_blocking`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeadlessOptions {
+    display_size: [f32; 2],
+    delta_time: f32,
+    max_frames: u32,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        Self {
+            display_size: [1280.0, 720.0],
+            delta_time: 1.0 / 60.0,
+            max_frames: 60 * 60,
+        }
+    }
+}
+
+impl HeadlessOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `io.DisplaySize` for every frame (default: 1280x720).
+    pub fn display_size(mut self, size: [f32; 2]) -> Self {
+        self.display_size = size;
+        self
+    }
+
+    /// Sets `io.DeltaTime` for every frame (default: 1/60 s).
+    pub fn delta_time(mut self, delta_time: f32) -> Self {
+        self.delta_time = delta_time;
+        self
+    }
+
+    /// Gives up after this many frames (default: 3600, one minute of simulated time at 60 FPS).
+    pub fn max_frames(mut self, max_frames: u32) -> Self {
+        self.max_frames = max_frames;
+        self
+    }
+
+    fn validate(&self) -> ImGuiResult<()> {
+        let [w, h] = self.display_size;
+        if !(w.is_finite() && h.is_finite() && w > 0.0 && h > 0.0) {
+            return Err(ImGuiError::invalid_operation(
+                "HeadlessOptions::display_size must be finite and positive",
+            ));
+        }
+        if !(self.delta_time.is_finite() && self.delta_time > 0.0) {
+            return Err(ImGuiError::invalid_operation(
+                "HeadlessOptions::delta_time must be finite and positive",
+            ));
+        }
+        if self.max_frames == 0 {
+            return Err(ImGuiError::invalid_operation(
+                "HeadlessOptions::max_frames must be non-zero",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of [`TestEngine::run_tests_blocking`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestRunReport {
+    pub summary: ResultSummary,
+    /// Every registered test with its status after the run.
+    pub tests: Vec<TestResult>,
+    /// Number of frames rendered until the queue drained.
+    pub frames: u32,
+}
+
+impl TestRunReport {
+    /// Returns true if no test that ran during this session failed.
+    pub fn all_passed(&self) -> bool {
+        self.summary.count_tested == self.summary.count_success
+    }
+
+    /// Tests that finished with [`TestStatus::Error`].
+    pub fn failures(&self) -> impl Iterator<Item = &TestResult> {
+        self.tests
+            .iter()
+            .filter(|test| test.status == TestStatus::Error)
+    }
+}
+
+impl TestEngine {
+    /// Renders frames without a platform or renderer backend until all queued tests finished.
+    ///
+    /// The engine is started on `ctx` if needed, and the font atlas is built when no renderer has
+    /// done it yet. `gui` runs inside every frame to draw the application UI that script tests
+    /// drive; native tests registered with [`Self::add_test`] draw their own UI. Draw data is
+    /// produced but never rendered, so no window or GPU is required.
+    ///
+    /// Returns an error without touching the engine if `options` is invalid, and aborts the
+    /// remaining tests and returns an error if the queue has not drained after
+    /// [`HeadlessOptions::max_frames`].
+    pub fn run_tests_blocking<F>(
+        &mut self,
+        ctx: &mut Context,
+        options: &HeadlessOptions,
+        mut gui: F,
+    ) -> ImGuiResult<TestRunReport>
+    where
+        F: FnMut(&Ui),
+    {
+        self.assert_bound_imgui_alive("TestEngine::run_tests_blocking()");
+        options.validate()?;
+        self.try_start(ctx)?;
+
+        if !ctx.font_atlas().is_built() {
+            let _ = ctx.font_atlas_mut().build();
+        }
+
+        let mut frames = 0;
+        loop {
+            if frames == options.max_frames {
+                self.try_abort_engine();
+                return Err(ImGuiError::invalid_operation(format!(
+                    "TestEngine::run_tests_blocking() gave up after {frames} frames with tests still queued"
+                )));
+            }
+
+            let io = ctx.io_mut();
+            io.set_display_size(options.display_size);
+            io.set_delta_time(options.delta_time);
+            let ui = ctx.frame();
+            gui(ui);
+            let _ = ctx.render();
+            self.post_swap();
+            frames += 1;
+
+            if self.is_test_queue_empty() && !self.is_running_tests() {
+                break;
+            }
+        }
+
+        Ok(TestRunReport {
+            summary: self.result_summary(),
+            tests: self.test_results(),
+            frames,
+        })
+    }
+}
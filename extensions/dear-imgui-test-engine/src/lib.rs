@@ -1,22 +1,37 @@
 //! Dear ImGui Test Engine bindings for `dear-imgui-rs`.
 //!
 //! This crate wraps `dear-imgui-test-engine-sys` with a small safe API for
-//! engine lifetime management and per-frame UI integration.
+//! engine lifetime management and per-frame UI integration. Tests can be
+//! registered as scripts ([`TestEngine::add_script_test`]) or as native Rust
+//! GUI/test closures ([`TestEngine::add_test`]). Besides the upstream windows
+//! ([`TestEngine::show_windows`]), [`TestRunner`] draws an embeddable test list
+//! with filters and queue controls.
 
+mod capture;
 mod config;
 mod counts;
 mod engine;
+mod headless;
+mod native;
 mod results;
+mod runner;
 mod script;
+mod test_context;
 
 #[cfg(test)]
 mod tests;
 
-pub use config::{InputMode, RunFlags, RunSpeed, TestGroup, VerboseLevel};
+pub use capture::{CaptureArgs, CaptureFlags, CaptureRect};
+pub use config::{
+    EngineIoConfig, ExportFormat, InputMode, RunFlags, RunSpeed, TestGroup, VerboseLevel,
+};
 pub use counts::{ScriptCount, ScriptLimit};
 pub use engine::TestEngine;
-pub use results::ResultSummary;
+pub use headless::{HeadlessOptions, TestRunReport};
+pub use results::{ResultSummary, TestResult, TestStatus};
+pub use runner::TestRunner;
 pub use script::ScriptTest;
+pub use test_context::TestContext;
 
 pub use dear_imgui_test_engine_sys as raw;
 
@@ -0,0 +1,95 @@
+use dear_imgui_rs::{ContextAliveToken, Ui, with_scratch_txt};
+use dear_imgui_test_engine_sys as sys;
+use std::{
+    any::Any, cell::RefCell, ffi::c_void, marker::PhantomData, panic::AssertUnwindSafe, rc::Rc,
+};
+
+use crate::TestContext;
+
+/// The ImGui context a started engine is bound to, shared with every native test so GUI
+/// functions can build a `Ui` for it.
+pub(crate) type GuiBinding = Rc<RefCell<Option<ContextAliveToken>>>;
+
+pub(crate) type GuiFn = Box<dyn FnMut(&Ui)>;
+pub(crate) type TestFn = Box<dyn FnMut(&mut TestContext<'_>) + Send>;
+
+/// User data owned by the C++ side for one native test and released through [`drop_native_test`].
+///
+/// `gui` is only touched from the application thread and `test` only from the coroutine thread,
+/// so the trampolines borrow the two fields separately.
+pub(crate) struct NativeTest {
+    pub(crate) binding: GuiBinding,
+    pub(crate) gui: GuiFn,
+    pub(crate) test: TestFn,
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.as_str()
+    } else {
+        "<non-string panic payload>"
+    }
+}
+
+fn report_panic(ctx: *mut sys::ImGuiTestContext, what: &str, payload: &(dyn Any + Send)) {
+    let message = format!("{what} panicked: {}", panic_message(payload)).replace('\0', "");
+    with_scratch_txt(&message, |ptr| unsafe {
+        sys::imgui_test_engine_ctx_log_error(ctx, ptr);
+        sys::imgui_test_engine_ctx_check(ctx, false, ptr, c"".as_ptr(), 0);
+    });
+}
+
+pub(crate) unsafe extern "C" fn native_gui_trampoline(
+    ctx: *mut sys::ImGuiTestContext,
+    user_data: *mut c_void,
+) {
+    if ctx.is_null() || user_data.is_null() {
+        return;
+    }
+    let native = user_data as *mut NativeTest;
+    let binding = unsafe { &(*native).binding };
+    let Some(alive) = binding.borrow().clone() else {
+        return;
+    };
+    if !alive.is_alive() {
+        return;
+    }
+    let ui_ctx = unsafe { sys::imgui_test_engine_ctx_get_ui_context(ctx) };
+    if ui_ctx.is_null() {
+        return;
+    }
+
+    // Safety: GUI functions are called from `NewFrame()` of the bound context, on the thread that
+    // owns it.
+    let ui = unsafe { Ui::from_raw_in_frame(ui_ctx, alive) };
+    let gui = unsafe { &mut *std::ptr::addr_of_mut!((*native).gui) };
+    if let Err(payload) = std::panic::catch_unwind(AssertUnwindSafe(|| gui(&ui))) {
+        report_panic(ctx, "GUI function", &*payload);
+    }
+}
+
+pub(crate) unsafe extern "C" fn native_test_trampoline(
+    ctx: *mut sys::ImGuiTestContext,
+    user_data: *mut c_void,
+) {
+    if ctx.is_null() || user_data.is_null() {
+        return;
+    }
+    let native = user_data as *mut NativeTest;
+    let test = unsafe { &mut *std::ptr::addr_of_mut!((*native).test) };
+    let mut test_ctx = TestContext {
+        raw: ctx,
+        _marker: PhantomData,
+    };
+    if let Err(payload) = std::panic::catch_unwind(AssertUnwindSafe(|| test(&mut test_ctx))) {
+        report_panic(ctx, "Test function", &*payload);
+    }
+}
+
+pub(crate) unsafe extern "C" fn drop_native_test(user_data: *mut c_void) {
+    if !user_data.is_null() {
+        drop(unsafe { Box::from_raw(user_data as *mut NativeTest) });
+    }
+}
@@ -1,3 +1,8 @@
+use dear_imgui_test_engine_sys as sys;
+use std::ffi::CStr;
+
+use crate::TestGroup;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ResultSummary {
     pub count_tested: usize,
@@ -18,3 +23,85 @@ impl ResultSummary {
         }
     }
 }
+
+/// Status of one registered test, as reported by the engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TestStatus {
+    /// Not run since registration.
+    Unknown,
+    Success,
+    Queued,
+    Running,
+    Error,
+    Suspended,
+}
+
+impl TestStatus {
+    pub(super) fn from_raw(raw: sys::ImGuiTestEngineTestStatus) -> Self {
+        match raw {
+            sys::ImGuiTestEngineTestStatus_Success => Self::Success,
+            sys::ImGuiTestEngineTestStatus_Queued => Self::Queued,
+            sys::ImGuiTestEngineTestStatus_Running => Self::Running,
+            sys::ImGuiTestEngineTestStatus_Error => Self::Error,
+            sys::ImGuiTestEngineTestStatus_Suspended => Self::Suspended,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Returns true for tests that ran to completion (successfully or not).
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Success | Self::Error)
+    }
+}
+
+/// Per-test entry returned by [`TestEngine::tests`](crate::TestEngine::tests).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestResult {
+    /// Position in registration order, accepted by
+    /// [`TestEngine::queue_test`](crate::TestEngine::queue_test).
+    pub index: usize,
+    pub category: String,
+    pub name: String,
+    pub group: TestGroup,
+    pub status: TestStatus,
+    /// File that registered the test (the Rust caller of `add_test` for native tests), or empty.
+    pub source_file: String,
+    pub source_line: u32,
+    /// Last error logged by the most recent run, e.g. the failed check.
+    pub last_error: Option<String>,
+}
+
+impl TestResult {
+    /// # Safety
+    /// The string pointers in `raw` must be null or valid NUL-terminated strings.
+    pub(super) unsafe fn from_raw(
+        index: usize,
+        raw: &sys::ImGuiTestEngineTestInfo_c,
+        last_error: Option<String>,
+    ) -> Self {
+        let text = |ptr: *const std::os::raw::c_char| {
+            if ptr.is_null() {
+                String::new()
+            } else {
+                unsafe { CStr::from_ptr(ptr) }
+                    .to_string_lossy()
+                    .into_owned()
+            }
+        };
+        let group = match raw.Group {
+            sys::ImGuiTestEngineGroup_Tests => TestGroup::Tests,
+            sys::ImGuiTestEngineGroup_Perfs => TestGroup::Perfs,
+            _ => TestGroup::Unknown,
+        };
+        Self {
+            index,
+            category: text(raw.Category),
+            name: text(raw.Name),
+            group,
+            status: TestStatus::from_raw(raw.Status),
+            source_file: text(raw.SourceFile),
+            source_line: u32::try_from(raw.SourceLine).unwrap_or(0),
+            last_error,
+        }
+    }
+}
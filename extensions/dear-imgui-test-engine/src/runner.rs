@@ -0,0 +1,215 @@
+use dear_imgui_rs::{TableFlags, Ui};
+
+use crate::{RunFlags, TestEngine, TestResult, TestStatus};
+
+const STATUS_FILTERS: [Option<TestStatus>; 7] = [
+    None,
+    Some(TestStatus::Unknown),
+    Some(TestStatus::Success),
+    Some(TestStatus::Queued),
+    Some(TestStatus::Running),
+    Some(TestStatus::Error),
+    Some(TestStatus::Suspended),
+];
+
+fn status_label(status: Option<TestStatus>) -> &'static str {
+    match status {
+        None => "All",
+        Some(TestStatus::Unknown) => "Not run",
+        Some(TestStatus::Success) => "Success",
+        Some(TestStatus::Queued) => "Queued",
+        Some(TestStatus::Running) => "Running",
+        Some(TestStatus::Error) => "Error",
+        Some(TestStatus::Suspended) => "Suspended",
+    }
+}
+
+fn status_color(status: TestStatus) -> [f32; 4] {
+    match status {
+        TestStatus::Success => [0.4, 0.9, 0.4, 1.0],
+        TestStatus::Error => [1.0, 0.4, 0.4, 1.0],
+        TestStatus::Queued | TestStatus::Running => [1.0, 0.85, 0.3, 1.0],
+        TestStatus::Unknown | TestStatus::Suspended => [0.6, 0.6, 0.6, 1.0],
+    }
+}
+
+/// Filter and queue state of an embeddable test runner panel.
+///
+/// [`TestRunner::draw`] draws the panel into the current window: a name filter, category and
+/// status combos, "Run filtered" / "Abort" buttons, a progress bar and the list of matching tests
+/// with a "Run" button each. Apps building their own UI can use [`TestRunner::matches`] and
+/// [`TestRunner::queue_matching`] with [`TestEngine::tests`] instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # use dear_imgui_test_engine::*;
+/// # let mut ctx = Context::create();
+/// # let mut engine = TestEngine::create();
+/// # engine.start(&ctx);
+/// let mut runner = TestRunner::new();
+/// let ui = ctx.frame();
+/// ui.window("Tests").build(|| runner.draw(ui, &mut engine));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestRunner {
+    /// Case-insensitive substring matched against `category/name`; empty matches every test.
+    pub filter: String,
+    /// Only match tests of this category.
+    pub category: Option<String>,
+    /// Only match tests with this status.
+    pub status: Option<TestStatus>,
+    /// Flags used when queueing tests from the runner.
+    pub run_flags: RunFlags,
+}
+
+impl Default for TestRunner {
+    fn default() -> Self {
+        Self {
+            filter: String::new(),
+            category: None,
+            status: None,
+            run_flags: RunFlags::RUN_FROM_GUI,
+        }
+    }
+}
+
+impl TestRunner {
+    /// Creates a runner matching every test.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if `test` passes the category, status and name filters.
+    pub fn matches(&self, test: &TestResult) -> bool {
+        if self.category.as_ref().is_some_and(|c| *c != test.category) {
+            return false;
+        }
+        if self.status.is_some_and(|s| s != test.status) {
+            return false;
+        }
+        if self.filter.is_empty() {
+            return true;
+        }
+        format!("{}/{}", test.category, test.name)
+            .to_lowercase()
+            .contains(&self.filter.to_lowercase())
+    }
+
+    /// Queues every registered test that [`Self::matches`]. Returns the number of tests queued.
+    pub fn queue_matching(&self, engine: &mut TestEngine) -> usize {
+        let indices: Vec<usize> = engine
+            .tests()
+            .filter(|test| self.matches(test))
+            .map(|test| test.index)
+            .collect();
+        indices
+            .into_iter()
+            .filter(|&index| engine.queue_test(index, self.run_flags))
+            .count()
+    }
+
+    /// Draws the runner panel into the current window.
+    pub fn draw(&mut self, ui: &Ui, engine: &mut TestEngine) {
+        let tests = engine.test_results();
+
+        ui.input_text("Filter", &mut self.filter)
+            .hint("category/name")
+            .build();
+
+        let mut categories: Vec<&str> = tests.iter().map(|test| test.category.as_str()).collect();
+        categories.sort_unstable();
+        categories.dedup();
+        if let Some(_combo) = ui.begin_combo("Category", self.category.as_deref().unwrap_or("All"))
+        {
+            if ui
+                .selectable_config("All")
+                .selected(self.category.is_none())
+                .build()
+            {
+                self.category = None;
+            }
+            for category in categories {
+                let selected = self.category.as_deref() == Some(category);
+                if ui.selectable_config(category).selected(selected).build() {
+                    self.category = Some(category.to_owned());
+                }
+            }
+        }
+
+        let mut status = STATUS_FILTERS
+            .iter()
+            .position(|&s| s == self.status)
+            .unwrap_or(0);
+        if ui.combo_simple_string(
+            "Status",
+            &mut status,
+            STATUS_FILTERS.iter().map(|&s| status_label(s)),
+        ) {
+            self.status = STATUS_FILTERS[status];
+        }
+
+        if ui.button("Run filtered") {
+            self.queue_matching(engine);
+        }
+        ui.same_line();
+        {
+            let _disabled = ui.begin_disabled_with_cond(!engine.is_running_tests());
+            if ui.button("Abort") {
+                engine.abort_current_test();
+            }
+        }
+
+        let summary = engine.result_summary();
+        let total = summary.count_tested + summary.count_in_queue;
+        let fraction = if total == 0 {
+            0.0
+        } else {
+            summary.count_tested as f32 / total as f32
+        };
+        ui.progress_bar(fraction)
+            .overlay_text(format!(
+                "{}/{} tested, {} passed",
+                summary.count_tested, total, summary.count_success
+            ))
+            .build();
+
+        ui.table("##tests")
+            .flags(TableFlags::RESIZABLE | TableFlags::ROW_BG | TableFlags::BORDERS_OUTER)
+            .column("Status")
+            .weight(0.15)
+            .done()
+            .column("Category")
+            .weight(0.3)
+            .done()
+            .column("Name")
+            .weight(0.45)
+            .done()
+            .column("##run")
+            .weight(0.1)
+            .done()
+            .headers(true)
+            .build(|ui| {
+                for test in tests.iter().filter(|test| self.matches(test)) {
+                    let _id = ui.push_id(test.index as i32);
+                    ui.table_next_row();
+                    ui.table_next_column();
+                    ui.text_colored(status_color(test.status), status_label(Some(test.status)));
+                    ui.table_next_column();
+                    ui.text(&test.category);
+                    ui.table_next_column();
+                    ui.text(&test.name);
+                    if let Some(error) = test.last_error.as_deref()
+                        && ui.is_item_hovered()
+                    {
+                        ui.tooltip_text(error);
+                    }
+                    ui.table_next_column();
+                    if ui.small_button("Run") {
+                        engine.queue_test(test.index, self.run_flags);
+                    }
+                }
+            });
+    }
+}
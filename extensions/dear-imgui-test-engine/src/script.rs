@@ -4,6 +4,7 @@ use dear_imgui_rs::{
 };
 use dear_imgui_test_engine_sys as sys;
 
+use crate::counts::check_delta_time;
 use crate::{InputMode, ScriptCount, ScriptLimit};
 
 pub(crate) struct Script {
@@ -1163,4 +1164,38 @@ impl ScriptTest<'_> {
         let frames = frames.into().raw();
         unsafe { sys::imgui_test_engine_script_yield(self.script.raw, frames) };
     }
+
+    /// Lets the application run `frames` frames with `io.DeltaTime` forced to `delta_time`
+    /// seconds, regardless of wall-clock time or the engine's run speed.
+    ///
+    /// Use this to test time-dependent logic deterministically: a long frame to exceed the
+    /// double-click time, or many short ones while a key repeats or a tween completes.
+    pub fn yield_frames_with_delta_time(
+        &mut self,
+        frames: impl Into<ScriptCount>,
+        delta_time: f32,
+    ) -> ImGuiResult<()> {
+        check_delta_time("yield_frames_with_delta_time", delta_time)?;
+        let frames = frames.into().raw();
+        unsafe {
+            sys::imgui_test_engine_script_yield_with_delta_time(self.script.raw, frames, delta_time)
+        };
+        Ok(())
+    }
+
+    /// Runs one frame per entry of `delta_times`, forcing `io.DeltaTime` to that many seconds,
+    /// e.g. `&[1.0 / 60.0, 0.25, 1.0 / 60.0]` for a single hitch in an otherwise smooth run.
+    ///
+    /// Returns an error without adding any step if any entry is not finite and positive.
+    pub fn yield_frame_pattern(&mut self, delta_times: &[f32]) -> ImGuiResult<()> {
+        for &delta_time in delta_times {
+            check_delta_time("yield_frame_pattern", delta_time)?;
+        }
+        for &delta_time in delta_times {
+            unsafe {
+                sys::imgui_test_engine_script_yield_with_delta_time(self.script.raw, 1, delta_time)
+            };
+        }
+        Ok(())
+    }
 }
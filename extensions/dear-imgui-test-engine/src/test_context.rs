@@ -0,0 +1,319 @@
+use dear_imgui_rs::{
+    ImGuiError, ImGuiResult, KeyChord, MouseButton, with_scratch_txt, with_scratch_txt_two,
+};
+use dear_imgui_test_engine_sys as sys;
+use std::{marker::PhantomData, path::Path};
+
+use crate::counts::check_delta_time;
+use crate::{CaptureArgs, ScriptCount};
+
+fn reject_nul(value: &str, what: &str) -> ImGuiResult<()> {
+    if value.contains('\0') {
+        return Err(ImGuiError::invalid_operation(format!(
+            "{what} contained interior NUL"
+        )));
+    }
+    Ok(())
+}
+
+fn until_nul(value: &str) -> &str {
+    value.split('\0').next().unwrap_or_default()
+}
+
+/// Handle to the running test, passed to test functions registered with
+/// [`TestEngine::add_test`](crate::TestEngine::add_test).
+///
+/// Test functions run on the test engine coroutine thread. Every action blocks until the engine
+/// has simulated the frames it needs, so a test reads top to bottom like its C++ counterpart.
+pub struct TestContext<'a> {
+    pub(crate) raw: *mut sys::ImGuiTestContext,
+    pub(crate) _marker: PhantomData<&'a mut sys::ImGuiTestContext>,
+}
+
+impl TestContext<'_> {
+    pub fn as_raw(&self) -> *mut sys::ImGuiTestContext {
+        self.raw
+    }
+
+    /// Returns true once a check failed or the test was aborted.
+    ///
+    /// Long-running tests should return early when this becomes true.
+    pub fn is_error(&self) -> bool {
+        unsafe { sys::imgui_test_engine_ctx_is_error(self.raw) }
+    }
+
+    /// Records a check, like `IM_CHECK(result)` in C++, and returns `result`.
+    ///
+    /// A failed check marks the test as failed but does not stop the Rust function; return early
+    /// when it yields `false`. The caller location is reported alongside `expr`, which is
+    /// truncated at the first interior NUL.
+    #[track_caller]
+    pub fn check(&mut self, result: bool, expr: &str) -> bool {
+        let location = std::panic::Location::caller();
+        let line = i32::try_from(location.line()).unwrap_or(i32::MAX);
+        with_scratch_txt_two(
+            until_nul(expr),
+            until_nul(location.file()),
+            |expr_ptr, file_ptr| unsafe {
+                sys::imgui_test_engine_ctx_check(self.raw, result, expr_ptr, file_ptr, line)
+            },
+        )
+    }
+
+    /// Records an equality check, like `IM_CHECK_EQ(lhs, rhs)` in C++, and returns whether the
+    /// values are equal.
+    #[track_caller]
+    pub fn check_eq<T: PartialEq + std::fmt::Debug>(&mut self, lhs: T, rhs: T) -> bool {
+        let result = lhs == rhs;
+        let expr = format!("{lhs:?} == {rhs:?}");
+        self.check(result, &expr)
+    }
+
+    pub fn log_info(&mut self, message: &str) -> ImGuiResult<()> {
+        reject_nul(message, "log_info")?;
+        with_scratch_txt(message, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_log_info(self.raw, ptr)
+        });
+        Ok(())
+    }
+
+    pub fn log_error(&mut self, message: &str) -> ImGuiResult<()> {
+        reject_nul(message, "log_error")?;
+        with_scratch_txt(message, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_log_error(self.raw, ptr)
+        });
+        Ok(())
+    }
+
+    pub fn set_ref(&mut self, r#ref: &str) -> ImGuiResult<()> {
+        reject_nul(r#ref, "set_ref")?;
+        with_scratch_txt(r#ref, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_set_ref(self.raw, ptr)
+        });
+        Ok(())
+    }
+
+    pub fn item_click(&mut self, r#ref: &str) -> ImGuiResult<()> {
+        self.item_click_with_button(r#ref, MouseButton::Left)
+    }
+
+    pub fn item_click_with_button(&mut self, r#ref: &str, button: MouseButton) -> ImGuiResult<()> {
+        reject_nul(r#ref, "item_click")?;
+        let button = button as i32;
+        with_scratch_txt(r#ref, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_item_click(self.raw, ptr, button)
+        });
+        Ok(())
+    }
+
+    pub fn item_double_click(&mut self, r#ref: &str) -> ImGuiResult<()> {
+        reject_nul(r#ref, "item_double_click")?;
+        with_scratch_txt(r#ref, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_item_double_click(self.raw, ptr)
+        });
+        Ok(())
+    }
+
+    pub fn item_open(&mut self, r#ref: &str) -> ImGuiResult<()> {
+        reject_nul(r#ref, "item_open")?;
+        with_scratch_txt(r#ref, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_item_open(self.raw, ptr)
+        });
+        Ok(())
+    }
+
+    pub fn item_close(&mut self, r#ref: &str) -> ImGuiResult<()> {
+        reject_nul(r#ref, "item_close")?;
+        with_scratch_txt(r#ref, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_item_close(self.raw, ptr)
+        });
+        Ok(())
+    }
+
+    pub fn item_check(&mut self, r#ref: &str) -> ImGuiResult<()> {
+        reject_nul(r#ref, "item_check")?;
+        with_scratch_txt(r#ref, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_item_check(self.raw, ptr)
+        });
+        Ok(())
+    }
+
+    pub fn item_uncheck(&mut self, r#ref: &str) -> ImGuiResult<()> {
+        reject_nul(r#ref, "item_uncheck")?;
+        with_scratch_txt(r#ref, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_item_uncheck(self.raw, ptr)
+        });
+        Ok(())
+    }
+
+    pub fn item_input_int(&mut self, r#ref: &str, v: i32) -> ImGuiResult<()> {
+        reject_nul(r#ref, "item_input_int")?;
+        with_scratch_txt(r#ref, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_item_input_int(self.raw, ptr, v)
+        });
+        Ok(())
+    }
+
+    pub fn item_input_str(&mut self, r#ref: &str, v: &str) -> ImGuiResult<()> {
+        reject_nul(r#ref, "item_input_str ref")?;
+        reject_nul(v, "item_input_str value")?;
+        with_scratch_txt_two(r#ref, v, |ref_ptr, v_ptr| unsafe {
+            sys::imgui_test_engine_ctx_item_input_str(self.raw, ref_ptr, v_ptr)
+        });
+        Ok(())
+    }
+
+    pub fn item_exists(&mut self, r#ref: &str) -> ImGuiResult<bool> {
+        reject_nul(r#ref, "item_exists")?;
+        Ok(with_scratch_txt(r#ref, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_item_exists(self.raw, ptr)
+        }))
+    }
+
+    pub fn mouse_move(&mut self, r#ref: &str) -> ImGuiResult<()> {
+        reject_nul(r#ref, "mouse_move")?;
+        with_scratch_txt(r#ref, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_mouse_move(self.raw, ptr)
+        });
+        Ok(())
+    }
+
+    pub fn key_press(&mut self, key_chord: KeyChord, count: impl Into<ScriptCount>) {
+        let count = count.into().raw();
+        unsafe { sys::imgui_test_engine_ctx_key_press(self.raw, key_chord.raw(), count) };
+    }
+
+    pub fn key_chars(&mut self, chars: &str) -> ImGuiResult<()> {
+        reject_nul(chars, "key_chars")?;
+        with_scratch_txt(chars, |ptr| unsafe {
+            sys::imgui_test_engine_ctx_key_chars(self.raw, ptr)
+        });
+        Ok(())
+    }
+
+    pub fn sleep_seconds(&mut self, seconds: f32) -> ImGuiResult<()> {
+        if !seconds.is_finite() || seconds < 0.0 {
+            return Err(ImGuiError::invalid_operation(
+                "sleep_seconds requires a finite non-negative value",
+            ));
+        }
+        unsafe { sys::imgui_test_engine_ctx_sleep(self.raw, seconds) };
+        Ok(())
+    }
+
+    /// Lets the application run `frames` frames before the test continues.
+    pub fn yield_frames(&mut self, frames: impl Into<ScriptCount>) {
+        let frames = frames.into().raw();
+        unsafe { sys::imgui_test_engine_ctx_yield(self.raw, frames) };
+    }
+
+    /// Lets the application run `frames` frames with `io.DeltaTime` forced to `delta_time`
+    /// seconds, regardless of wall-clock time or the engine's run speed.
+    ///
+    /// Use this to test time-dependent logic deterministically: a long frame to exceed the
+    /// double-click time, or many short ones while a key repeats or a tween completes.
+    pub fn yield_frames_with_delta_time(
+        &mut self,
+        frames: impl Into<ScriptCount>,
+        delta_time: f32,
+    ) -> ImGuiResult<()> {
+        check_delta_time("yield_frames_with_delta_time", delta_time)?;
+        let frames = frames.into().raw();
+        unsafe { sys::imgui_test_engine_ctx_yield_with_delta_time(self.raw, frames, delta_time) };
+        Ok(())
+    }
+
+    /// Runs one frame per entry of `delta_times`, forcing `io.DeltaTime` to that many seconds,
+    /// e.g. `&[1.0 / 60.0, 0.25, 1.0 / 60.0]` for a single hitch in an otherwise smooth run.
+    ///
+    /// Returns an error before yielding if any entry is not finite and positive.
+    pub fn yield_frame_pattern(&mut self, delta_times: &[f32]) -> ImGuiResult<()> {
+        for &delta_time in delta_times {
+            check_delta_time("yield_frame_pattern", delta_time)?;
+        }
+        for &delta_time in delta_times {
+            unsafe { sys::imgui_test_engine_ctx_yield_with_delta_time(self.raw, 1, delta_time) };
+        }
+        Ok(())
+    }
+
+    /// Measures the average frame time of the test's GUI function and records it as this perf
+    /// test's result, like `ctx->PerfCapture()` in C++.
+    ///
+    /// Only meaningful in tests registered with
+    /// [`TestEngine::add_perf_test_with`](crate::TestEngine::add_perf_test_with).
+    pub fn perf_capture(&mut self) {
+        unsafe { sys::imgui_test_engine_ctx_perf_capture(self.raw) };
+    }
+
+    /// Sets how many frames [`Self::perf_capture`] measures (upstream default: 400).
+    pub fn set_perf_iterations(&mut self, frames: impl Into<ScriptCount>) {
+        let frames = frames.into().raw();
+        unsafe { sys::imgui_test_engine_ctx_set_perf_iterations(self.raw, frames) };
+    }
+
+    /// Captures `window_ref` into the image file at `path`.
+    ///
+    /// Requires capture to be enabled on the engine and a screen capture callback
+    /// ([`TestEngine::set_screen_capture_func`](crate::TestEngine::set_screen_capture_func)).
+    /// Returns `Ok(false)` if the window was not found or the capture failed.
+    pub fn capture_screenshot(
+        &mut self,
+        window_ref: &str,
+        path: impl AsRef<Path>,
+    ) -> ImGuiResult<bool> {
+        self.capture_screenshot_with(
+            &CaptureArgs::new()
+                .window(window_ref)
+                .output_file(path.as_ref()),
+        )
+    }
+
+    /// Captures a screenshot described by `args`.
+    pub fn capture_screenshot_with(&mut self, args: &CaptureArgs) -> ImGuiResult<bool> {
+        if !self.prepare_capture(args)? {
+            return Ok(false);
+        }
+        let flags = args.flags_value().bits();
+        Ok(unsafe { sys::imgui_test_engine_ctx_capture_screenshot(self.raw, flags) })
+    }
+
+    /// Starts recording a video described by `args`; stop it with [`Self::capture_end_video`].
+    ///
+    /// Requires a video encoder (see
+    /// [`TestEngine::set_video_capture_encoder`](crate::TestEngine::set_video_capture_encoder)).
+    pub fn capture_begin_video(&mut self, args: &CaptureArgs) -> ImGuiResult<bool> {
+        if !self.prepare_capture(args)? {
+            return Ok(false);
+        }
+        let flags = args.flags_value().bits();
+        Ok(unsafe { sys::imgui_test_engine_ctx_capture_begin_video(self.raw, flags) })
+    }
+
+    pub fn capture_end_video(&mut self) -> bool {
+        unsafe { sys::imgui_test_engine_ctx_capture_end_video(self.raw) }
+    }
+
+    fn prepare_capture(&mut self, args: &CaptureArgs) -> ImGuiResult<bool> {
+        let output_file = args.validate()?;
+        unsafe { sys::imgui_test_engine_ctx_capture_reset(self.raw) };
+        if let Some(path) = output_file {
+            let fits = with_scratch_txt(path, |ptr| unsafe {
+                sys::imgui_test_engine_ctx_capture_set_output_file(self.raw, ptr)
+            });
+            debug_assert!(fits, "capture path length is validated before FFI");
+        }
+        if let Some(padding) = args.padding_value() {
+            unsafe { sys::imgui_test_engine_ctx_capture_set_padding(self.raw, padding) };
+        }
+        for window in args.windows() {
+            let added = with_scratch_txt(window, |ptr| unsafe {
+                sys::imgui_test_engine_ctx_capture_add_window(self.raw, ptr)
+            });
+            if !added {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
@@ -87,3 +87,428 @@ fn test_engine_try_start_rejects_rebinding_after_bound_context_drop() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn add_test_rejects_interior_nul_and_drops_closures_with_engine() {
+    use std::sync::Arc;
+
+    let mut engine = TestEngine::create();
+    assert!(engine.add_test("bad\0cat", "name", |_| {}, |_| {}).is_err());
+    assert!(engine.add_test("cat", "bad\0name", |_| {}, |_| {}).is_err());
+
+    let gui_state = Arc::new(());
+    let test_state = Arc::new(());
+    {
+        let gui_state = Arc::clone(&gui_state);
+        let test_state = Arc::clone(&test_state);
+        engine
+            .add_test(
+                "native",
+                "drop",
+                move |_| {
+                    let _ = &gui_state;
+                },
+                move |_| {
+                    let _ = &test_state;
+                },
+            )
+            .unwrap();
+    }
+    assert_eq!(Arc::strong_count(&gui_state), 2);
+    assert_eq!(Arc::strong_count(&test_state), 2);
+
+    drop(engine);
+    assert_eq!(Arc::strong_count(&gui_state), 1);
+    assert_eq!(Arc::strong_count(&test_state), 1);
+}
+
+#[test]
+fn capture_args_are_validated_before_ffi() {
+    assert!(CaptureArgs::new().window("//Demo").validate().is_ok());
+    assert!(CaptureArgs::new().window("bad\0ref").validate().is_err());
+    assert!(CaptureArgs::new().padding(f32::NAN).validate().is_err());
+    assert!(CaptureArgs::new().padding(-1.0).validate().is_err());
+    assert!(
+        CaptureArgs::new()
+            .output_file("a".repeat(capture::CAPTURE_PATH_CAPACITY))
+            .validate()
+            .is_err()
+    );
+    assert_eq!(
+        CaptureArgs::new()
+            .output_file("out/demo.png")
+            .validate()
+            .unwrap(),
+        Some("out/demo.png")
+    );
+}
+
+#[test]
+fn screen_capture_trampoline_forwards_region_and_catches_panics() {
+    let seen = std::rc::Rc::new(std::cell::Cell::new(None));
+    let seen_in_callback = std::rc::Rc::clone(&seen);
+    let mut callback: capture::ScreenCaptureFn = Box::new(move |rect, pixels| {
+        seen_in_callback.set(Some(rect));
+        pixels.fill(0xFF00_00FF);
+        true
+    });
+    let mut pixels = [0u32; 6];
+    let ok = unsafe {
+        capture::screen_capture_trampoline(
+            7,
+            1,
+            2,
+            3,
+            2,
+            pixels.as_mut_ptr(),
+            (&mut callback as *mut capture::ScreenCaptureFn).cast(),
+        )
+    };
+    assert!(ok);
+    assert_eq!(pixels, [0xFF00_00FF; 6]);
+    assert_eq!(
+        seen.get(),
+        Some(CaptureRect {
+            viewport_id: 7,
+            x: 1,
+            y: 2,
+            width: 3,
+            height: 2,
+        })
+    );
+
+    let mut panicking: capture::ScreenCaptureFn = Box::new(|_, _| panic!("readback failed"));
+    let ok = unsafe {
+        capture::screen_capture_trampoline(
+            0,
+            0,
+            0,
+            1,
+            1,
+            pixels.as_mut_ptr(),
+            (&mut panicking as *mut capture::ScreenCaptureFn).cast(),
+        )
+    };
+    assert!(!ok);
+    assert!(!unsafe {
+        capture::screen_capture_trampoline(
+            0,
+            0,
+            0,
+            -1,
+            1,
+            pixels.as_mut_ptr(),
+            (&mut callback as *mut capture::ScreenCaptureFn).cast(),
+        )
+    });
+}
+
+#[test]
+fn export_results_writes_junit_xml_and_rejects_bad_paths() {
+    let mut ctx = Context::create();
+    let _ = ctx.font_atlas_mut().build();
+
+    let mut engine = TestEngine::create();
+    engine.start(&ctx);
+
+    assert!(engine.export_results("", ExportFormat::JUnitXml).is_err());
+    assert!(
+        engine
+            .export_results("bad\0name.xml", ExportFormat::JUnitXml)
+            .is_err()
+    );
+
+    let path = std::env::temp_dir().join(format!(
+        "dear-imgui-test-engine-junit-{}.xml",
+        std::process::id()
+    ));
+    engine
+        .export_results(&path, ExportFormat::JUnitXml)
+        .unwrap();
+    let xml = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(xml.contains("<testsuites"), "unexpected report: {xml}");
+
+    engine.shutdown();
+}
+
+#[test]
+fn run_tests_blocking_reports_per_test_status() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    };
+
+    let mut ctx = Context::create();
+    let mut engine = TestEngine::create();
+    engine.set_run_speed(RunSpeed::Fast);
+
+    let clicks = Arc::new(AtomicU32::new(0));
+    let gui_clicks = Arc::clone(&clicks);
+    engine
+        .add_test(
+            "headless",
+            "click",
+            move |ui| {
+                ui.window("Headless").build(|| {
+                    if ui.button("Click") {
+                        gui_clicks.fetch_add(1, Ordering::Relaxed);
+                    }
+                });
+            },
+            move |t| {
+                t.set_ref("Headless").unwrap();
+                t.item_click("Click").unwrap();
+                t.yield_frames(ScriptCount::new(1));
+                t.check_eq(clicks.load(Ordering::Relaxed), 1);
+            },
+        )
+        .unwrap();
+    engine
+        .add_test(
+            "headless",
+            "fail",
+            |_| {},
+            |t| {
+                t.check_eq(1, 2);
+            },
+        )
+        .unwrap();
+
+    let invalid = HeadlessOptions::new().delta_time(0.0);
+    assert!(
+        engine
+            .run_tests_blocking(&mut ctx, &invalid, |_| {})
+            .is_err()
+    );
+    assert!(!engine.is_bound());
+
+    engine
+        .queue_tests(
+            TestGroup::Tests,
+            Some("headless"),
+            RunFlags::RUN_FROM_COMMAND_LINE,
+        )
+        .unwrap();
+    let report = engine
+        .run_tests_blocking(&mut ctx, &HeadlessOptions::new(), |_| {})
+        .unwrap();
+
+    assert!(report.frames > 0);
+    assert!(!report.all_passed());
+    let status = |name: &str| {
+        report
+            .tests
+            .iter()
+            .find(|test| test.category == "headless" && test.name == name)
+            .map(|test| test.status)
+    };
+    assert_eq!(status("click"), Some(TestStatus::Success));
+    assert_eq!(status("fail"), Some(TestStatus::Error));
+    assert_eq!(report.failures().count(), 1);
+
+    let click = report
+        .tests
+        .iter()
+        .find(|test| test.name == "click")
+        .unwrap();
+    assert!(
+        click.source_file.ends_with("tests.rs"),
+        "{}",
+        click.source_file
+    );
+    assert!(click.source_line > 0);
+    assert_eq!(click.last_error, None);
+    let fail = report.failures().next().unwrap();
+    assert!(fail.last_error.is_some());
+    assert_eq!(engine.tests().count(), report.tests.len());
+
+    engine.shutdown();
+}
+
+#[test]
+fn perf_tests_register_into_perfs_group_and_run() {
+    let mut ctx = Context::create();
+    let mut engine = TestEngine::create();
+    engine.set_run_speed(RunSpeed::Fast);
+
+    assert!(engine.add_perf_test("perf\0", "name", |_| {}).is_err());
+    engine
+        .add_perf_test("perf_widgets", "buttons", |ui| {
+            ui.window("Perf").build(|| {
+                for i in 0..100 {
+                    ui.button(format!("Button {i}"));
+                }
+            });
+        })
+        .unwrap();
+    engine
+        .add_perf_test_with(
+            "perf_widgets",
+            "short",
+            |ui| ui.text("measured"),
+            |t| {
+                t.set_perf_iterations(ScriptCount::new(10));
+                t.perf_capture();
+            },
+        )
+        .unwrap();
+
+    engine
+        .queue_tests(
+            TestGroup::Perfs,
+            Some("perf_widgets"),
+            RunFlags::RUN_FROM_COMMAND_LINE,
+        )
+        .unwrap();
+    let report = engine
+        .run_tests_blocking(&mut ctx, &HeadlessOptions::new(), |_| {})
+        .unwrap();
+
+    let perfs: Vec<_> = report
+        .tests
+        .iter()
+        .filter(|test| test.category == "perf_widgets")
+        .collect();
+    assert_eq!(perfs.len(), 2);
+    for test in perfs {
+        assert_eq!(test.group, TestGroup::Perfs);
+        assert_eq!(test.status, TestStatus::Success, "{}", test.name);
+    }
+
+    let ui = ctx.frame();
+    let mut open = true;
+    engine.show_perf_tool(ui, Some(&mut open));
+    let _ = ctx.render();
+
+    engine.shutdown();
+}
+
+#[test]
+fn yield_frame_pattern_forces_io_delta_time() {
+    use std::sync::{Arc, Mutex};
+
+    let mut ctx = Context::create();
+    let mut engine = TestEngine::create();
+    engine.set_run_speed(RunSpeed::Fast);
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let gui_seen = Arc::clone(&seen);
+    let checked = Arc::new(Mutex::new(None));
+    let test_checked = Arc::clone(&checked);
+    engine
+        .add_test(
+            "timing",
+            "pattern",
+            move |ui| gui_seen.lock().unwrap().push(ui.io().delta_time()),
+            move |t| {
+                assert!(t.yield_frame_pattern(&[0.5, f32::NAN]).is_err());
+                assert!(
+                    t.yield_frames_with_delta_time(ScriptCount::new(1), 0.0)
+                        .is_err()
+                );
+                t.yield_frame_pattern(&[0.5, 0.25]).unwrap();
+                t.yield_frames_with_delta_time(ScriptCount::new(2), 0.125)
+                    .unwrap();
+                *test_checked.lock().unwrap() = Some(());
+            },
+        )
+        .unwrap();
+
+    engine
+        .queue_tests(
+            TestGroup::Tests,
+            Some("timing"),
+            RunFlags::RUN_FROM_COMMAND_LINE,
+        )
+        .unwrap();
+    let report = engine
+        .run_tests_blocking(&mut ctx, &HeadlessOptions::new(), |_| {})
+        .unwrap();
+    assert!(report.all_passed());
+    assert!(checked.lock().unwrap().is_some());
+
+    let seen = seen.lock().unwrap();
+    let expected = [0.5, 0.25, 0.125, 0.125];
+    assert!(
+        seen.windows(expected.len()).any(|w| w == expected),
+        "delta times seen by the GUI: {seen:?}"
+    );
+
+    engine.shutdown();
+}
+
+#[test]
+fn io_config_setters_round_trip() {
+    let mut engine = TestEngine::create();
+    engine.set_run_speed(RunSpeed::Normal);
+    engine.set_verbose_level(VerboseLevel::Warning);
+    engine.set_stop_on_error(true);
+    engine.set_break_on_error(false);
+    engine.set_no_throttle(true);
+    engine.set_capture_on_error(true);
+
+    let config = engine.io_config();
+    assert_eq!(config.run_speed, RunSpeed::Normal);
+    assert_eq!(config.verbose_level, VerboseLevel::Warning);
+    assert!(config.stop_on_error);
+    assert!(!config.break_on_error);
+    assert!(config.no_throttle);
+    assert!(config.capture_on_error);
+
+    engine.set_stop_on_error(false);
+    engine.set_no_throttle(false);
+    let config = engine.io_config();
+    assert!(!config.stop_on_error);
+    assert!(!config.no_throttle);
+}
+
+#[test]
+fn test_runner_filters_and_queues_individual_tests() {
+    let mut ctx = Context::create();
+    let mut engine = TestEngine::create();
+    engine.set_run_speed(RunSpeed::Fast);
+    for (category, name) in [
+        ("runner", "first"),
+        ("runner", "second"),
+        ("other", "third"),
+    ] {
+        engine.add_test(category, name, |_| {}, |_| {}).unwrap();
+    }
+
+    let mut runner = TestRunner::new();
+    runner.filter = "RUNNER/".to_owned();
+    assert_eq!(runner.queue_matching(&mut engine), 2);
+    assert!(!engine.queue_test(usize::MAX, RunFlags::NONE));
+    let status = |engine: &TestEngine, name: &str| {
+        engine
+            .tests()
+            .find(|test| test.name == name)
+            .map(|test| test.status)
+    };
+    assert_eq!(status(&engine, "first"), Some(TestStatus::Queued));
+    assert_eq!(status(&engine, "third"), Some(TestStatus::Unknown));
+
+    let report = engine
+        .run_tests_blocking(&mut ctx, &HeadlessOptions::new(), |_| {})
+        .unwrap();
+    assert!(report.all_passed());
+    assert_eq!(report.summary.count_tested, 2);
+
+    runner.filter.clear();
+    runner.status = Some(TestStatus::Unknown);
+    let unrun: Vec<TestResult> = engine.tests().filter(|t| runner.matches(t)).collect();
+    assert_eq!(unrun.len(), 1);
+    assert_eq!(unrun[0].name, "third");
+    assert_eq!(unrun[0].index, 2);
+
+    let io = ctx.io_mut();
+    io.set_display_size([800.0, 600.0]);
+    io.set_delta_time(1.0 / 60.0);
+    let _ = ctx.font_atlas_mut().build();
+    let ui = ctx.frame();
+    ui.window("Runner").build(|| runner.draw(ui, &mut engine));
+    let _ = ctx.render();
+
+    engine.shutdown();
+}
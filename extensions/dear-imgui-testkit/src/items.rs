@@ -0,0 +1,105 @@
+use dear_imgui_rs::{Id, Ui};
+
+/// State of one item, captured right after it was submitted.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ItemState {
+    /// Item ID (`Ui::item_id`).
+    pub id: Id,
+    /// Upper-left corner of the item rectangle, in screen coordinates.
+    pub rect_min: [f32; 2],
+    /// Lower-right corner of the item rectangle, in screen coordinates.
+    pub rect_max: [f32; 2],
+    pub visible: bool,
+    pub hovered: bool,
+    pub active: bool,
+    pub focused: bool,
+    /// Clicked with the left mouse button this frame (`Ui::is_item_clicked`).
+    pub clicked: bool,
+    /// The item's value changed this frame.
+    pub edited: bool,
+    pub activated: bool,
+    pub deactivated: bool,
+    pub deactivated_after_edit: bool,
+}
+
+impl ItemState {
+    /// Captures the state of the last submitted item.
+    pub fn capture(ui: &Ui) -> Self {
+        Self {
+            id: ui.item_id(),
+            rect_min: ui.item_rect_min(),
+            rect_max: ui.item_rect_max(),
+            visible: ui.is_item_visible(),
+            hovered: ui.is_item_hovered(),
+            active: ui.is_item_active(),
+            focused: ui.is_item_focused(),
+            clicked: ui.is_item_clicked(),
+            edited: ui.is_item_edited(),
+            activated: ui.is_item_activated(),
+            deactivated: ui.is_item_deactivated(),
+            deactivated_after_edit: ui.is_item_deactivated_after_edit(),
+        }
+    }
+
+    /// Center of the item rectangle, e.g. as a target for [`TestContext::click`].
+    ///
+    /// [`TestContext::click`]: crate::TestContext::click
+    pub fn center(&self) -> [f32; 2] {
+        [
+            (self.rect_min[0] + self.rect_max[0]) * 0.5,
+            (self.rect_min[1] + self.rect_max[1]) * 0.5,
+        ]
+    }
+
+    /// Width and height of the item rectangle.
+    pub fn size(&self) -> [f32; 2] {
+        [
+            self.rect_max[0] - self.rect_min[0],
+            self.rect_max[1] - self.rect_min[1],
+        ]
+    }
+}
+
+/// Item states recorded during one frame, in recording order.
+#[derive(Debug, Clone, Default)]
+pub struct ItemLog {
+    entries: Vec<(String, ItemState)>,
+}
+
+impl ItemLog {
+    /// Records the state of the last submitted item under `label`.
+    ///
+    /// Recording a label again replaces the earlier entry.
+    pub fn record(&mut self, ui: &Ui, label: impl Into<String>) {
+        let label = label.into();
+        let state = ItemState::capture(ui);
+        match self.entries.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, existing)) => *existing = state,
+            None => self.entries.push((label, state)),
+        }
+    }
+
+    /// State recorded under `label`, if any.
+    pub fn get(&self, label: &str) -> Option<&ItemState> {
+        self.entries
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, state)| state)
+    }
+
+    /// Recorded labels, in recording order.
+    pub fn labels(&self) -> impl Iterator<Item = &str> + '_ {
+        self.entries.iter().map(|(label, _)| label.as_str())
+    }
+
+    /// Number of recorded items.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
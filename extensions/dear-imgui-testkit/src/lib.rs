@@ -0,0 +1,279 @@
+//! Headless test utilities for `dear-imgui-rs`.
+//!
+//! [`TestContext`] owns a Dear ImGui context with no platform or renderer backend: it sets a
+//! display size and frame delta itself, builds the font atlas on the CPU, and turns each frame's
+//! draw data into a [`DrawDataSnapshot`] instead of submitting it to a GPU. That is enough to unit
+//! test custom widgets without a window or the full test engine:
+//!
+//! ```
+//! use dear_imgui_testkit::TestContext;
+//!
+//! let mut t = TestContext::new();
+//! let frame = t.frame(|ui, items| {
+//!     ui.window("Test").build(|| {
+//!         ui.button("Save");
+//!         items.record(ui, "save");
+//!     });
+//! });
+//!
+//! let save = frame.item("save");
+//! assert!(save.visible);
+//! assert!(frame.has_geometry_in(save.rect_min, save.rect_max));
+//! ```
+//!
+//! Input is queued on the context (see [`TestContext::mouse_move`] etc.) and takes effect in the
+//! next frame, as with a real platform backend; [`TestContext::click`] runs the frames needed for
+//! a complete press and release.
+//!
+//! Dear ImGui keeps the current context in a global, so a `TestContext` holds a process-wide lock
+//! for its whole lifetime. Tests creating one run one after another even under the parallel test
+//! runner; avoid creating other `Context`s in the same test binary outside of a `TestContext`.
+
+use std::sync::{Mutex, MutexGuard};
+
+use dear_imgui_rs::render::{
+    DrawCmdSnapshot, DrawDataSnapshot, FrameSnapshot, SnapshotOptions, TextureBinding,
+    UserCallbackPolicy,
+};
+use dear_imgui_rs::{Context, Key, MouseButton, Ui};
+
+mod items;
+pub use items::{ItemLog, ItemState};
+
+static CONTEXT_LOCK: Mutex<()> = Mutex::new(());
+
+/// Default display size of a [`TestContext`].
+pub const DEFAULT_DISPLAY_SIZE: [f32; 2] = [800.0, 600.0];
+
+/// Headless Dear ImGui context for unit tests.
+pub struct TestContext {
+    // Dropped before the lock guard so the context is destroyed while still holding the lock.
+    ctx: Context,
+    frame_count: u64,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl TestContext {
+    /// Creates a context with an 800x600 display, a 60 FPS frame delta, no ini file and a built
+    /// font atlas.
+    ///
+    /// Blocks while another `TestContext` is alive (see the crate docs).
+    pub fn new() -> Self {
+        let lock = CONTEXT_LOCK
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+        let mut ctx = Context::create();
+        let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+        {
+            let io = ctx.io_mut();
+            io.set_display_size(DEFAULT_DISPLAY_SIZE);
+            io.set_delta_time(1.0 / 60.0);
+        }
+        let _ = ctx.font_atlas_mut().build();
+        Self {
+            ctx,
+            frame_count: 0,
+            _lock: lock,
+        }
+    }
+
+    /// Sets the display size used by the following frames.
+    pub fn display_size(mut self, size: [f32; 2]) -> Self {
+        self.ctx.io_mut().set_display_size(size);
+        self
+    }
+
+    /// The underlying context, e.g. to change style, IO flags or fonts.
+    pub fn context_mut(&mut self) -> &mut Context {
+        &mut self.ctx
+    }
+
+    /// Number of frames run so far.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Runs one frame: calls `f` between `new_frame` and `render` and captures the draw data.
+    ///
+    /// Draw callbacks are dropped from the captured draw data.
+    pub fn frame<R>(&mut self, f: impl FnOnce(&Ui, &mut ItemLog) -> R) -> Frame<R> {
+        let mut items = ItemLog::default();
+        let value = {
+            let ui = self.ctx.frame();
+            f(ui, &mut items)
+        };
+        let draw_data = self.ctx.render();
+        let options = SnapshotOptions {
+            user_callback_policy: UserCallbackPolicy::Drop,
+            capture_texture_requests: false,
+        };
+        let snapshot = FrameSnapshot::from_draw_data(draw_data, options)
+            .expect("snapshotting draw data without callbacks or texture requests cannot fail");
+        self.frame_count += 1;
+        Frame {
+            value,
+            draw: snapshot.draw,
+            items,
+        }
+    }
+
+    /// Runs `count` frames with the same closure and returns the last one.
+    ///
+    /// Useful to let layout settle (auto-sized windows take a frame or two) or to let queued
+    /// input play out.
+    ///
+    /// # Panics
+    /// Panics if `count` is zero.
+    pub fn frames<R>(
+        &mut self,
+        count: usize,
+        mut f: impl FnMut(&Ui, &mut ItemLog) -> R,
+    ) -> Frame<R> {
+        assert!(count > 0, "TestContext::frames() needs at least one frame");
+        for _ in 1..count {
+            self.frame(&mut f);
+        }
+        self.frame(f)
+    }
+
+    /// Queues a mouse move to `pos` (in display coordinates).
+    pub fn mouse_move(&mut self, pos: [f32; 2]) {
+        self.ctx.io_mut().add_mouse_pos_event(pos);
+    }
+
+    /// Queues a mouse button press or release.
+    pub fn mouse_button(&mut self, button: MouseButton, down: bool) {
+        self.ctx.io_mut().add_mouse_button_event(button, down);
+    }
+
+    /// Queues a mouse wheel scroll.
+    pub fn mouse_wheel(&mut self, delta: [f32; 2]) {
+        self.ctx.io_mut().add_mouse_wheel_event(delta);
+    }
+
+    /// Queues a key press or release.
+    pub fn key(&mut self, key: Key, down: bool) {
+        self.ctx.io_mut().add_key_event(key, down);
+    }
+
+    /// Queues typed text, one input character per `char`.
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.ctx.io_mut().add_input_character(c);
+        }
+    }
+
+    /// Left-clicks at `pos`, running `f` for the hover, press and release frames, and returns the
+    /// release frame (where `Ui::button` returns true).
+    pub fn click<R>(
+        &mut self,
+        pos: [f32; 2],
+        mut f: impl FnMut(&Ui, &mut ItemLog) -> R,
+    ) -> Frame<R> {
+        self.mouse_move(pos);
+        self.frame(&mut f);
+        self.mouse_button(MouseButton::Left, true);
+        self.frame(&mut f);
+        self.mouse_button(MouseButton::Left, false);
+        self.frame(f)
+    }
+}
+
+impl Default for TestContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result of one [`TestContext::frame`].
+#[derive(Debug)]
+pub struct Frame<R = ()> {
+    /// Value returned by the frame closure.
+    pub value: R,
+    /// Captured draw data of the frame.
+    pub draw: DrawDataSnapshot,
+    /// Item states recorded with [`ItemLog::record`].
+    pub items: ItemLog,
+}
+
+/// One `Elements` draw command of a captured frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawElements {
+    /// Number of indices drawn.
+    pub count: usize,
+    /// Clip rectangle `[min_x, min_y, max_x, max_y]`.
+    pub clip_rect: [f32; 4],
+    /// Texture used by the command.
+    pub texture: TextureBinding,
+}
+
+impl<R> Frame<R> {
+    /// State of the item recorded as `label`.
+    ///
+    /// # Panics
+    /// Panics if no item was recorded under `label`.
+    #[track_caller]
+    pub fn item(&self, label: &str) -> &ItemState {
+        self.items.get(label).unwrap_or_else(|| {
+            panic!(
+                "no item recorded as {label:?} (recorded: {:?})",
+                self.items.labels().collect::<Vec<_>>()
+            )
+        })
+    }
+
+    /// Total number of vertices over all draw lists.
+    pub fn vertex_count(&self) -> usize {
+        self.draw.draw_lists.iter().map(|list| list.vtx.len()).sum()
+    }
+
+    /// Total number of indices over all draw lists.
+    pub fn index_count(&self) -> usize {
+        self.draw.draw_lists.iter().map(|list| list.idx.len()).sum()
+    }
+
+    /// All `Elements` draw commands, in submission order.
+    pub fn elements(&self) -> impl Iterator<Item = DrawElements> + '_ {
+        self.draw
+            .draw_lists
+            .iter()
+            .flat_map(|list| list.commands.iter())
+            .filter_map(|cmd| match *cmd {
+                DrawCmdSnapshot::Elements {
+                    count,
+                    clip_rect,
+                    texture,
+                    ..
+                } => Some(DrawElements {
+                    count,
+                    clip_rect,
+                    texture,
+                }),
+                _ => None,
+            })
+    }
+
+    /// Returns true if any vertex lies inside the rectangle from `min` to `max` (inclusive).
+    pub fn has_geometry_in(&self, min: [f32; 2], max: [f32; 2]) -> bool {
+        self.draw
+            .draw_lists
+            .iter()
+            .flat_map(|list| list.vtx.iter())
+            .any(|vtx| {
+                let [x, y] = vtx.pos;
+                x >= min[0] && x <= max[0] && y >= min[1] && y <= max[1]
+            })
+    }
+
+    /// Returns true if any vertex inside the rectangle from `min` to `max` has color `rgba`.
+    pub fn has_color_in(&self, min: [f32; 2], max: [f32; 2], rgba: [u8; 4]) -> bool {
+        self.draw
+            .draw_lists
+            .iter()
+            .flat_map(|list| list.vtx.iter())
+            .any(|vtx| {
+                let [x, y] = vtx.pos;
+                x >= min[0] && x <= max[0] && y >= min[1] && y <= max[1] && vtx.rgba() == rgba
+            })
+    }
+}
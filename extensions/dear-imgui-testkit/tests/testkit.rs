@@ -0,0 +1,95 @@
+use dear_imgui_rs::Condition;
+use dear_imgui_testkit::TestContext;
+
+fn fixed_window(ui: &dear_imgui_rs::Ui, f: impl FnOnce()) {
+    let _ = ui
+        .window("Test")
+        .position([10.0, 10.0], Condition::Always)
+        .size([300.0, 200.0], Condition::Always)
+        .build(f);
+}
+
+#[test]
+fn frame_captures_draw_data_and_item_rects() {
+    let mut t = TestContext::new();
+    let frame = t.frame(|ui, items| {
+        fixed_window(ui, || {
+            ui.button("Save");
+            items.record(ui, "save");
+        });
+        42
+    });
+
+    assert_eq!(frame.value, 42);
+    assert_eq!(t.frame_count(), 1);
+    assert!(frame.vertex_count() > 0);
+    assert!(frame.index_count() > 0);
+    assert!(frame.elements().all(|cmd| cmd.count > 0));
+
+    let save = frame.item("save");
+    assert!(save.visible);
+    assert!(!save.hovered);
+    assert!(save.size()[0] > 0.0 && save.size()[1] > 0.0);
+    assert!(frame.has_geometry_in(save.rect_min, save.rect_max));
+    assert!(!frame.has_geometry_in([700.0, 500.0], [800.0, 600.0]));
+}
+
+#[test]
+fn click_reports_button_press_on_release_frame() {
+    let mut t = TestContext::new();
+    let layout = t.frames(2, |ui, items| {
+        fixed_window(ui, || {
+            ui.button("Go");
+            items.record(ui, "go");
+        });
+    });
+    let target = layout.item("go").center();
+
+    let frame = t.click(target, |ui, items| {
+        let mut pressed = false;
+        fixed_window(ui, || {
+            pressed = ui.button("Go");
+            items.record(ui, "go");
+        });
+        pressed
+    });
+    assert!(frame.value);
+    assert!(frame.item("go").hovered);
+}
+
+#[test]
+fn typed_text_reaches_focused_input() {
+    let mut t = TestContext::new();
+    let mut text = String::new();
+    let layout = t.frames(2, |ui, items| {
+        fixed_window(ui, || {
+            ui.input_text("Name", &mut text).build();
+            items.record(ui, "name");
+        });
+    });
+    let target = layout.item("name").center();
+
+    t.click(target, |ui, _| {
+        fixed_window(ui, || {
+            ui.input_text("Name", &mut text).build();
+        });
+    });
+    t.type_text("hi");
+    let frame = t.frame(|ui, items| {
+        fixed_window(ui, || {
+            ui.input_text("Name", &mut text).build();
+            items.record(ui, "name");
+        });
+    });
+    assert!(frame.item("name").active);
+    assert!(frame.item("name").edited);
+    assert_eq!(text, "hi");
+}
+
+#[test]
+#[should_panic(expected = "no item recorded as \"missing\"")]
+fn missing_item_panics_with_recorded_labels() {
+    let mut t = TestContext::new();
+    let frame = t.frame(|_, _| {});
+    frame.item("missing");
+}
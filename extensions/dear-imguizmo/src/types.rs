@@ -88,6 +88,31 @@ impl MoveType {
     pub const fn is_none(self) -> bool {
         self.0 == sys::MT_NONE
     }
+
+    /// The operation bits driven by this handle, e.g. `TRANSLATE_Y | TRANSLATE_Z` for the YZ plane.
+    ///
+    /// Scale handles report both the per-axis and the uniform scale bits, since ImGuizmo uses the
+    /// same handles for both. `NONE` and unknown values map to an empty operation.
+    pub fn operation(self) -> Operation {
+        match self {
+            Self::MOVE_X => Operation::TRANSLATE_X,
+            Self::MOVE_Y => Operation::TRANSLATE_Y,
+            Self::MOVE_Z => Operation::TRANSLATE_Z,
+            Self::MOVE_YZ => Operation::TRANSLATE_Y | Operation::TRANSLATE_Z,
+            Self::MOVE_ZX => Operation::TRANSLATE_Z | Operation::TRANSLATE_X,
+            Self::MOVE_XY => Operation::TRANSLATE_X | Operation::TRANSLATE_Y,
+            Self::MOVE_SCREEN => Operation::TRANSLATE,
+            Self::ROTATE_X => Operation::ROTATE_X,
+            Self::ROTATE_Y => Operation::ROTATE_Y,
+            Self::ROTATE_Z => Operation::ROTATE_Z,
+            Self::ROTATE_SCREEN => Operation::ROTATE_SCREEN,
+            Self::SCALE_X => Operation::SCALE_X | Operation::SCALE_UNIFORM_X,
+            Self::SCALE_Y => Operation::SCALE_Y | Operation::SCALE_UNIFORM_Y,
+            Self::SCALE_Z => Operation::SCALE_Z | Operation::SCALE_UNIFORM_Z,
+            Self::SCALE_XYZ => Operation::SCALE | Operation::SCALE_UNIFORM,
+            _ => Operation::empty(),
+        }
+    }
 }
 
 /// Color slots used by ImGuizmo style
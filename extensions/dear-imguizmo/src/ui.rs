@@ -417,26 +417,40 @@ impl<'ui> GizmoUi<'ui> {
         let _guard = self.bind();
         unsafe { sys::ImGuizmo_Enable(enable) }
     }
+    /// Whether the mouse is over any handle of the last manipulated gizmo.
     pub fn is_over(&self) -> bool {
         let _guard = self.bind();
         unsafe { sys::ImGuizmo_IsOver_Nil() }
     }
+    /// Whether the gizmo with the current ID is being dragged.
     pub fn is_using(&self) -> bool {
         let _guard = self.bind();
         unsafe { sys::ImGuizmo_IsUsing() }
     }
+    /// Whether any gizmo is being dragged, regardless of its ID.
     pub fn is_using_any(&self) -> bool {
         let _guard = self.bind();
         unsafe { sys::ImGuizmo_IsUsingAny() }
     }
+    /// Whether any gizmo is being dragged through a handle of `operation`.
+    ///
+    /// `operation` may combine bits, e.g. `Operation::TRANSLATE_X | Operation::ROTATE_Z`, so
+    /// editors can keep camera controls enabled except while specific axes are dragged. Bounds
+    /// dragging has no handle type and is not reported here.
+    pub fn is_using_operation(&self, operation: Operation) -> bool {
+        self.is_using_any() && self.active_move_type().operation().intersects(operation)
+    }
+    /// Whether the view manipulator cube is being dragged.
     pub fn is_using_view_manipulate(&self) -> bool {
         let _guard = self.bind();
         unsafe { sys::ImGuizmo_IsUsingViewManipulate() }
     }
+    /// Whether the mouse is over the view manipulator cube.
     pub fn is_view_manipulate_hovered(&self) -> bool {
         let _guard = self.bind();
         unsafe { sys::ImGuizmo_IsViewManipulateHovered() }
     }
+    /// Whether the mouse is over a handle of `operation`, which may combine bits.
     pub fn is_over_operation(&self, operation: Operation) -> bool {
         let _guard = self.bind();
         unsafe { sys::ImGuizmo_IsOver_OPERATION(operation.into()) }
@@ -612,7 +626,7 @@ impl GuizmoExt for Ui {
 
 #[cfg(test)]
 mod tests {
-    use super::{GuizmoExt, sys};
+    use super::{GuizmoExt, MoveType, Operation, sys};
     use dear_imgui_rs::{BackendFlags, Context};
     use dear_imgui_sys as imgui_sys;
     use std::sync::{Mutex, OnceLock};
@@ -704,4 +718,25 @@ mod tests {
         }
         let _ = imgui.render();
     }
+
+    #[test]
+    fn operation_state_queries_report_an_idle_gizmo() {
+        let _guard = test_guard();
+        let mut imgui = Context::create();
+        prepare_imgui(&mut imgui);
+
+        assert_eq!(
+            MoveType::MOVE_YZ.operation(),
+            Operation::TRANSLATE_Y | Operation::TRANSLATE_Z
+        );
+        assert!(MoveType::NONE.operation().is_empty());
+        {
+            let ui = imgui.frame();
+            let giz = ui.guizmo();
+            let axes = Operation::TRANSLATE_X | Operation::ROTATE_Z;
+            assert!(!giz.is_using_any());
+            assert!(!giz.is_using_operation(axes));
+        }
+        let _ = imgui.render();
+    }
 }
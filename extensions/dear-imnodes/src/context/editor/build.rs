@@ -1,4 +1,5 @@
 use super::super::{AttrKind, AttributeToken, NodeEditor, NodeToken};
+use crate::ColorElement;
 use crate::sys;
 
 impl<'ui> NodeEditor<'ui> {
@@ -50,4 +51,26 @@ impl<'ui> NodeEditor<'ui> {
         let _guard = self.bind();
         unsafe { sys::imnodes_Link(id.raw(), start_attr.raw(), end_attr.raw()) }
     }
+
+    /// Draw a link between two attributes with its own colors
+    ///
+    /// ImNodes captures the link colors when the link is submitted, so this pushes the colors
+    /// of `style` around [`link`](Self::link).
+    pub fn link_styled(
+        &self,
+        id: crate::LinkId,
+        start_attr: crate::PinId,
+        end_attr: crate::PinId,
+        style: crate::LinkStyle,
+    ) {
+        let _colors: Vec<_> = [
+            (ColorElement::Link, style.color),
+            (ColorElement::LinkHovered, style.hovered_color),
+            (ColorElement::LinkSelected, style.selected_color),
+        ]
+        .into_iter()
+        .filter_map(|(elem, color)| color.map(|color| self.push_color(elem, color)))
+        .collect();
+        self.link(id, start_attr, end_attr);
+    }
 }
@@ -2,4 +2,7 @@ mod build;
 mod drop_impl;
 mod lifecycle;
 mod minimap;
+mod navigation;
 mod style_io;
+
+pub(super) use navigation::{panning_to_frame, set_panning};
@@ -0,0 +1,80 @@
+use super::super::NodeEditor;
+use crate::sys;
+
+/// Panning that centers the grid-space bounding box of `nodes` in a canvas of `canvas_size`.
+///
+/// Must be called with the editor scope bound. Returns `None` if `nodes` is empty.
+pub(in crate::context) fn panning_to_frame(
+    nodes: &[crate::NodeId],
+    canvas_size: [f32; 2],
+) -> Option<[f32; 2]> {
+    if nodes.is_empty() {
+        return None;
+    }
+    let mut min = [f32::INFINITY; 2];
+    let mut max = [f32::NEG_INFINITY; 2];
+    for &node_id in nodes {
+        let pos = unsafe { sys::imnodes_GetNodeGridSpacePos(node_id.raw()) };
+        let size = unsafe { crate::compat_ffi::imnodes_GetNodeDimensions(node_id.raw()) };
+        min = [min[0].min(pos.x), min[1].min(pos.y)];
+        max = [max[0].max(pos.x + size.x), max[1].max(pos.y + size.y)];
+    }
+    // Editor space is grid space offset by the panning, with the canvas origin at (0, 0).
+    Some(std::array::from_fn(|i| {
+        canvas_size[i] * 0.5 - (min[i] + max[i]) * 0.5
+    }))
+}
+
+pub(in crate::context) fn set_panning(pos: [f32; 2]) {
+    unsafe {
+        sys::imnodes_EditorContextResetPanning(sys::ImVec2_c {
+            x: pos[0],
+            y: pos[1],
+        })
+    };
+}
+
+impl<'ui> NodeEditor<'ui> {
+    /// Current panning of the editor: the editor-space offset of the grid origin.
+    #[doc(alias = "EditorContextGetPanning")]
+    pub fn get_panning(&self) -> [f32; 2] {
+        let _guard = self.bind();
+        let out = unsafe { crate::compat_ffi::imnodes_EditorContextGetPanning() };
+        [out.x, out.y]
+    }
+
+    /// Set the editor panning. Nodes already submitted this frame move on the next frame.
+    #[doc(alias = "EditorContextResetPanning")]
+    pub fn set_panning(&self, pos: [f32; 2]) {
+        let _guard = self.bind();
+        set_panning(pos);
+    }
+
+    /// Pan the editor so that the node's top-left corner sits at the canvas origin.
+    #[doc(alias = "EditorContextMoveToNode")]
+    pub fn pan_to_node(&self, node_id: crate::NodeId) {
+        let _guard = self.bind();
+        unsafe { sys::imnodes_EditorContextMoveToNode(node_id.raw()) };
+    }
+
+    /// Size of the editor canvas in pixels.
+    pub fn canvas_size(&self) -> [f32; 2] {
+        let _guard = self.bind();
+        self._ui.window_size()
+    }
+
+    /// Pan the editor so that the bounding box of `nodes` is centered in the canvas.
+    ///
+    /// Uses each node's dimensions from the last frame it was drawn. Returns false and leaves the
+    /// panning unchanged if `nodes` is empty.
+    pub fn frame_nodes(&self, nodes: &[crate::NodeId]) -> bool {
+        let _guard = self.bind();
+        match panning_to_frame(nodes, self._ui.window_size()) {
+            Some(panning) => {
+                set_panning(panning);
+                true
+            }
+            None => false,
+        }
+    }
+}
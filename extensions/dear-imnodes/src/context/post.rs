@@ -12,6 +12,7 @@ pub struct PostEditor<'ui> {
     #[allow(dead_code)]
     pub(super) _ctx: &'ui Context,
     pub(super) scope: ImNodesScope,
+    pub(super) canvas_size: [f32; 2],
     pub(super) editor_hovered: bool,
     pub(super) hovered_node: Option<crate::NodeId>,
     pub(super) hovered_link: Option<crate::LinkId>,
@@ -30,6 +31,8 @@ impl<'ui> NodeEditor<'ui> {
     /// Explicitly end the node editor and return post-editor query handle
     pub fn end(mut self) -> PostEditor<'ui> {
         let _guard = self.bind();
+        // The editor canvas is the current ImGui window until EndNodeEditor closes it.
+        let canvas_size = self._ui.window_size();
         if !self.ended {
             unsafe { sys::imnodes_EndNodeEditor() };
             self.ended = true;
@@ -145,6 +148,7 @@ impl<'ui> NodeEditor<'ui> {
             _ui: self._ui,
             _ctx: self._ctx,
             scope: self.scope.clone(),
+            canvas_size,
             editor_hovered,
             hovered_node,
             hovered_link,
@@ -294,6 +298,49 @@ impl<'ui> PostEditor<'ui> {
         }
     }
 
+    /// Current panning of the editor: the editor-space offset of the grid origin.
+    #[doc(alias = "EditorContextGetPanning")]
+    pub fn get_panning(&self) -> [f32; 2] {
+        let _guard = self.bind();
+        let out = unsafe { crate::compat_ffi::imnodes_EditorContextGetPanning() };
+        [out.x, out.y]
+    }
+
+    /// Set the editor panning, applied from the next frame.
+    #[doc(alias = "EditorContextResetPanning")]
+    pub fn set_panning(&self, pos: [f32; 2]) {
+        let _guard = self.bind();
+        super::editor::set_panning(pos);
+    }
+
+    /// Pan the editor so that the node's top-left corner sits at the canvas origin.
+    #[doc(alias = "EditorContextMoveToNode")]
+    pub fn pan_to_node(&self, node_id: crate::NodeId) {
+        let _guard = self.bind();
+        unsafe { sys::imnodes_EditorContextMoveToNode(node_id.raw()) };
+    }
+
+    /// Size of the editor canvas in pixels, captured when the editor ended.
+    pub fn canvas_size(&self) -> [f32; 2] {
+        self.canvas_size
+    }
+
+    /// Pan the editor so that the selected nodes are centered in the canvas ("frame selected").
+    ///
+    /// ImNodes has no zoom, so a selection larger than the canvas stays clipped at its edges.
+    /// Returns false and leaves the panning unchanged if no node is selected.
+    pub fn zoom_to_selection(&self) -> bool {
+        let selected = self.selected_nodes();
+        let _guard = self.bind();
+        match super::editor::panning_to_frame(&selected, self.canvas_size) {
+            Some(panning) => {
+                super::editor::set_panning(panning);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn is_link_created(&self) -> Option<crate::LinkCreated> {
         self.link_created
     }
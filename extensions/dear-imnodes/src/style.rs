@@ -90,6 +90,59 @@ impl Drop for AttributeFlagToken<'_> {
     }
 }
 
+/// Colors of a single link, for [`NodeEditor::link_styled`](crate::NodeEditor::link_styled)
+///
+/// Colors left as `None` use the editor style. Link thickness and line segments are read from
+/// the style when the editor is drawn, so they apply to all links of an editor; see
+/// [`NodeEditor::set_link_thickness`](crate::NodeEditor::set_link_thickness).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LinkStyle {
+    pub color: Option<[f32; 4]>,
+    pub hovered_color: Option<[f32; 4]>,
+    pub selected_color: Option<[f32; 4]>,
+}
+
+impl LinkStyle {
+    /// A style that keeps every editor style color.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A style colored `color`, with brighter hovered and selected variants, e.g. for wires
+    /// colored by data type.
+    pub fn colored(color: [f32; 4]) -> Self {
+        let brighten = |amount: f32| {
+            let [r, g, b, a] = color;
+            [
+                r + (1.0 - r) * amount,
+                g + (1.0 - g) * amount,
+                b + (1.0 - b) * amount,
+                a,
+            ]
+        };
+        Self {
+            color: Some(color),
+            hovered_color: Some(brighten(0.25)),
+            selected_color: Some(brighten(0.5)),
+        }
+    }
+
+    pub fn with_color(mut self, color: [f32; 4]) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_hovered_color(mut self, color: [f32; 4]) -> Self {
+        self.hovered_color = Some(color);
+        self
+    }
+
+    pub fn with_selected_color(mut self, color: [f32; 4]) -> Self {
+        self.selected_color = Some(color);
+        self
+    }
+}
+
 /// Style helpers available from NodeEditor
 impl<'ui> crate::NodeEditor<'ui> {
     pub fn push_attribute_flag(&self, flag: crate::AttributeFlags) -> AttributeFlagToken<'_> {
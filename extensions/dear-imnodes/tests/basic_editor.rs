@@ -51,3 +51,106 @@ fn basic_editor_smoke_test() {
     let _ = post.is_link_created_with_nodes();
     let _ = post.is_link_destroyed();
 }
+
+#[test]
+fn styled_links_restore_the_editor_link_colors() {
+    let mut imgui = Context::create();
+    let imnodes_ctx = imnodes::Context::create(&imgui);
+    let editor = imnodes_ctx.create_editor_context();
+
+    {
+        let io = imgui.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+
+    let _ = imgui.font_atlas_mut().build();
+    let _ = imgui.set_ini_filename::<std::path::PathBuf>(None);
+
+    let ui = imgui.frame();
+    let editor_ui = ui.imnodes(&imnodes_ctx).editor(Some(&editor));
+    let link_color = editor_ui.get_color(imnodes::ColorElement::Link);
+    let selected_color = editor_ui.get_color(imnodes::ColorElement::LinkSelected);
+
+    for (id, input, output) in [(1, 10, 11), (2, 20, 21)] {
+        let node = editor_ui.node(imnodes::NodeId::new(id));
+        editor_ui
+            .output_attr(imnodes::PinId::new(input), imnodes::PinShape::Circle)
+            .end();
+        editor_ui
+            .input_attr(imnodes::PinId::new(output), imnodes::PinShape::Circle)
+            .end();
+        node.end();
+    }
+    let float_wire = imnodes::LinkStyle::colored([0.0, 0.5, 1.0, 1.0]);
+    assert_eq!(float_wire.hovered_color, Some([0.25, 0.625, 1.0, 1.0]));
+    editor_ui.link_styled(
+        imnodes::LinkId::new(1),
+        imnodes::PinId::new(10),
+        imnodes::PinId::new(21),
+        float_wire,
+    );
+    editor_ui.link_styled(
+        imnodes::LinkId::new(2),
+        imnodes::PinId::new(20),
+        imnodes::PinId::new(11),
+        imnodes::LinkStyle::new().with_selected_color([1.0, 0.0, 0.0, 1.0]),
+    );
+
+    assert_eq!(editor_ui.get_color(imnodes::ColorElement::Link), link_color);
+    assert_eq!(
+        editor_ui.get_color(imnodes::ColorElement::LinkSelected),
+        selected_color
+    );
+    let _ = editor_ui.end();
+}
+
+#[test]
+fn pane_navigation_frames_the_selection() {
+    let mut imgui = Context::create();
+    let imnodes_ctx = imnodes::Context::create(&imgui);
+    let editor = imnodes_ctx.create_editor_context();
+
+    {
+        let io = imgui.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+
+    let _ = imgui.font_atlas_mut().build();
+    let _ = imgui.set_ini_filename::<std::path::PathBuf>(None);
+
+    let ui = imgui.frame();
+    let editor_ui = ui.imnodes(&imnodes_ctx).editor(Some(&editor));
+    editor_ui.set_panning([0.0, 0.0]);
+    for (id, pos) in [(1, [0.0, 0.0]), (2, [300.0, 200.0])] {
+        let node_id = imnodes::NodeId::new(id);
+        editor_ui.set_node_pos_grid(node_id, pos);
+        let node = editor_ui.node(node_id);
+        ui.text("node");
+        node.end();
+    }
+    let size = editor_ui.get_node_dimensions(imnodes::NodeId::new(2));
+    let canvas = editor_ui.canvas_size();
+    assert!(canvas[0] > 0.0 && canvas[1] > 0.0);
+    let post = editor_ui.end();
+    assert_eq!(post.canvas_size(), canvas);
+
+    post.pan_to_node(imnodes::NodeId::new(2));
+    assert_eq!(post.get_panning(), [-300.0, -200.0]);
+
+    post.clear_selection();
+    assert!(!post.zoom_to_selection());
+    assert_eq!(post.get_panning(), [-300.0, -200.0]);
+
+    post.select_node(imnodes::NodeId::new(1));
+    post.select_node(imnodes::NodeId::new(2));
+    assert!(post.zoom_to_selection());
+    let panning = post.get_panning();
+    let expected = [
+        canvas[0] * 0.5 - (300.0 + size[0]) * 0.5,
+        canvas[1] * 0.5 - (200.0 + size[1]) * 0.5,
+    ];
+    assert!((panning[0] - expected[0]).abs() < 1e-3);
+    assert!((panning[1] - expected[1]).abs() < 1e-3);
+}
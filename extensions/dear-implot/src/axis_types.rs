@@ -44,6 +44,28 @@ impl YAxis {
     }
 }
 
+/// Axis scale matching ImPlot's ImPlotScale values
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(i32)]
+pub enum AxisScale {
+    /// Default linear scale
+    #[default]
+    Linear = sys::ImPlotScale_Linear,
+    /// Date/time scale; values are UTC Unix timestamps in seconds and ticks are formatted as
+    /// dates and times according to [`PlotTimeFormat`](crate::PlotTimeFormat)
+    Time = sys::ImPlotScale_Time,
+    /// Base 10 logarithmic scale
+    Log10 = sys::ImPlotScale_Log10,
+    /// Symmetric logarithmic scale
+    SymLog = sys::ImPlotScale_SymLog,
+}
+
+impl From<AxisScale> for sys::ImPlotScale {
+    fn from(scale: AxisScale) -> Self {
+        scale as sys::ImPlotScale
+    }
+}
+
 /// Any ImPlot axis selector matching ImPlot's ImAxis values.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(i32)]
@@ -1,6 +1,7 @@
 mod axis;
 mod callbacks;
 mod core;
+mod drag_drop;
 mod token;
 mod ui;
 mod validation;
@@ -3,7 +3,7 @@ use super::validation::{
     assert_axis_constraint_range, assert_axis_limit_range, assert_axis_zoom_range,
     assert_finite_f64_slice, axis_tick_count_to_i32,
 };
-use crate::{Axis, AxisFlags, PlotCond, XAxis, YAxis, sys};
+use crate::{Axis, AxisFlags, AxisScale, PlotCond, XAxis, YAxis, sys};
 use dear_imgui_rs::{with_scratch_txt, with_scratch_txt_slice, with_scratch_txt_two};
 use std::os::raw::c_char;
 
@@ -481,16 +481,25 @@ impl<'ui> PlotUi<'ui> {
         })
     }
 
-    /// Setup scale for a specific X axis (pass sys::ImPlotScale variant)
-    pub fn setup_x_axis_scale(&self, axis: XAxis, scale: sys::ImPlotScale) {
+    /// Setup scale for a specific X axis ([`AxisScale`] or a raw sys::ImPlotScale value)
+    pub fn setup_x_axis_scale(&self, axis: XAxis, scale: impl Into<sys::ImPlotScale>) {
         let _guard = self.bind();
-        unsafe { sys::ImPlot_SetupAxisScale_PlotScale(axis as sys::ImAxis, scale) }
+        unsafe { sys::ImPlot_SetupAxisScale_PlotScale(axis as sys::ImAxis, scale.into()) }
     }
 
-    /// Setup scale for a specific Y axis (pass sys::ImPlotScale variant)
-    pub fn setup_y_axis_scale(&self, axis: YAxis, scale: sys::ImPlotScale) {
+    /// Setup a specific X axis as a time axis showing UTC Unix timestamps (in seconds) as dates
+    ///
+    /// Shorthand for [`setup_x_axis_scale`](Self::setup_x_axis_scale) with [`AxisScale::Time`].
+    /// Use [`PlotContext::set_time_format`](crate::PlotContext::set_time_format) to pick local
+    /// time, ISO 8601 dates or a 24-hour clock for the tick labels.
+    pub fn setup_x_axis_time(&self, axis: XAxis) {
+        self.setup_x_axis_scale(axis, AxisScale::Time);
+    }
+
+    /// Setup scale for a specific Y axis ([`AxisScale`] or a raw sys::ImPlotScale value)
+    pub fn setup_y_axis_scale(&self, axis: YAxis, scale: impl Into<sys::ImPlotScale>) {
         let _guard = self.bind();
-        unsafe { sys::ImPlot_SetupAxisScale_PlotScale(axis as sys::ImAxis, scale) }
+        unsafe { sys::ImPlot_SetupAxisScale_PlotScale(axis as sys::ImAxis, scale.into()) }
     }
 
     /// Setup axis limits constraints
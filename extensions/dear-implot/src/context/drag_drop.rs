@@ -0,0 +1,127 @@
+use super::ui::PlotUi;
+use crate::{Axis, sys};
+use dear_imgui_rs::{DragDropSourceFlags, DragDropSourceTooltip, DragDropTarget, with_scratch_txt};
+
+/// Drag and drop between plots and other widgets.
+///
+/// These must be called between [`PlotUi::begin_plot`] and the end of the plot. Targets and
+/// sources are the regular dear-imgui [`DragDropTarget`] / [`DragDropSourceTooltip`] tokens, so
+/// payloads are accepted and submitted exactly as with other widgets.
+///
+/// # Example
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # use dear_implot::*;
+/// # let mut ctx = Context::create();
+/// # let plot_ctx = PlotContext::create(&ctx);
+/// # let ui = ctx.frame();
+/// # let plot_ui = plot_ctx.get_plot_ui(&ui);
+/// let mut shown: Vec<u32> = Vec::new();
+/// // A signal list elsewhere starts drags with
+/// // `ui.drag_drop_source_config("SIGNAL").begin_payload(signal_index)`.
+/// if let Some(token) = plot_ui.begin_plot("Signals") {
+///     if let Some(target) = plot_ui.drag_drop_target_plot() {
+///         if let Some(Ok(payload)) =
+///             target.accept_payload::<u32, _>("SIGNAL", DragDropTargetFlags::NONE)
+///         {
+///             shown.push(payload.data);
+///         }
+///     }
+///     token.end();
+/// }
+/// ```
+impl<'ui> PlotUi<'ui> {
+    /// Turns the current plot's area into a drag drop target
+    #[doc(alias = "BeginDragDropTargetPlot")]
+    pub fn drag_drop_target_plot(&self) -> Option<DragDropTarget<'ui>> {
+        let _guard = self.bind();
+        let began = unsafe { sys::ImPlot_BeginDragDropTargetPlot() };
+        // SAFETY: ImPlot began the target on the Dear ImGui context of this plot UI.
+        began.then(|| unsafe { DragDropTarget::from_begun(self.ui) })
+    }
+
+    /// Turns an axis of the current plot into a drag drop target
+    #[doc(alias = "BeginDragDropTargetAxis")]
+    pub fn drag_drop_target_axis(&self, axis: Axis) -> Option<DragDropTarget<'ui>> {
+        let _guard = self.bind();
+        let began = unsafe { sys::ImPlot_BeginDragDropTargetAxis(axis.to_sys()) };
+        began.then(|| unsafe { DragDropTarget::from_begun(self.ui) })
+    }
+
+    /// Turns the legend of the current plot into a drag drop target
+    #[doc(alias = "BeginDragDropTargetLegend")]
+    pub fn drag_drop_target_legend(&self) -> Option<DragDropTarget<'ui>> {
+        let _guard = self.bind();
+        let began = unsafe { sys::ImPlot_BeginDragDropTargetLegend() };
+        began.then(|| unsafe { DragDropTarget::from_begun(self.ui) })
+    }
+
+    /// Makes the current plot's area a drag source carrying `payload` under `name`
+    ///
+    /// By default ImPlot requires the modifier configured in the input map (Ctrl) to be held
+    /// while dragging, so panning still works.
+    #[doc(alias = "BeginDragDropSourcePlot")]
+    pub fn drag_drop_source_plot<P: Copy + 'static>(
+        &self,
+        name: &str,
+        payload: P,
+        flags: DragDropSourceFlags,
+    ) -> Option<DragDropSourceTooltip<'ui>> {
+        let source = self.ui.drag_drop_source_config(name).flags(flags);
+        let _guard = self.bind();
+        unsafe {
+            source.begin_payload_with(payload, |flags| {
+                sys::ImPlot_BeginDragDropSourcePlot(flags.bits() as sys::ImGuiDragDropFlags)
+            })
+        }
+    }
+
+    /// Makes an axis of the current plot a drag source carrying `payload` under `name`
+    #[doc(alias = "BeginDragDropSourceAxis")]
+    pub fn drag_drop_source_axis<P: Copy + 'static>(
+        &self,
+        axis: Axis,
+        name: &str,
+        payload: P,
+        flags: DragDropSourceFlags,
+    ) -> Option<DragDropSourceTooltip<'ui>> {
+        let source = self.ui.drag_drop_source_config(name).flags(flags);
+        let _guard = self.bind();
+        unsafe {
+            source.begin_payload_with(payload, |flags| {
+                sys::ImPlot_BeginDragDropSourceAxis(
+                    axis.to_sys(),
+                    flags.bits() as sys::ImGuiDragDropFlags,
+                )
+            })
+        }
+    }
+
+    /// Makes the legend entry of the item `label_id` a drag source carrying `payload` under
+    /// `name`, e.g. to drag a series from one plot onto another
+    ///
+    /// Returns `None` if `label_id` contains a NUL byte or no drag started.
+    #[doc(alias = "BeginDragDropSourceItem")]
+    pub fn drag_drop_source_item<P: Copy + 'static>(
+        &self,
+        label_id: &str,
+        name: &str,
+        payload: P,
+        flags: DragDropSourceFlags,
+    ) -> Option<DragDropSourceTooltip<'ui>> {
+        if label_id.contains('\0') {
+            return None;
+        }
+        let source = self.ui.drag_drop_source_config(name).flags(flags);
+        let _guard = self.bind();
+        with_scratch_txt(label_id, |label_ptr| unsafe {
+            source.begin_payload_with(payload, |flags| {
+                sys::ImPlot_BeginDragDropSourceItem(
+                    label_ptr,
+                    flags.bits() as sys::ImGuiDragDropFlags,
+                )
+            })
+        })
+    }
+}
@@ -1,7 +1,9 @@
 use super::{PlotContext, validation::axis_tick_count_to_i32};
 use crate::sys;
-use crate::{Axis, PlotCond, XAxis, YAxis};
-use dear_imgui_rs::{BackendFlags, Context};
+use crate::{
+    Axis, AxisScale, PlotCond, PlotPoint, PlotRange, PlotRect, PlotTimeFormat, XAxis, YAxis,
+};
+use dear_imgui_rs::{BackendFlags, Context, DragDropSourceFlags};
 use std::sync::{Mutex, OnceLock};
 
 fn test_guard() -> std::sync::MutexGuard<'static, ()> {
@@ -213,3 +215,136 @@ fn typed_axis_apis_accept_valid_axes() {
     let _ = imgui.render();
     drop(plot);
 }
+
+#[test]
+fn time_axes_use_time_scale_and_style_time_format() {
+    let _guard = test_guard();
+    let mut imgui = Context::create();
+    prepare_imgui(&mut imgui);
+    let plot = PlotContext::create(&imgui);
+
+    assert_eq!(i32::from(AxisScale::Time), sys::ImPlotScale_Time);
+    assert_eq!(plot.time_format(), PlotTimeFormat::default());
+    let format = PlotTimeFormat {
+        use_local_time: false,
+        use_iso8601: true,
+        use_24_hour_clock: true,
+    };
+    plot.set_time_format(format);
+    assert_eq!(plot.time_format(), format);
+
+    {
+        let ui = imgui.frame();
+        let plot_ui = plot.get_plot_ui(&ui);
+        let token = plot_ui
+            .begin_plot("time-axis")
+            .expect("failed to begin plot");
+        plot_ui.setup_x_axis_time(XAxis::X1);
+        plot_ui.setup_y_axis_scale(YAxis::Y1, AxisScale::Log10);
+        plot_ui.setup_x_axis_limits(
+            XAxis::X1,
+            1_700_000_000.0,
+            1_700_086_400.0,
+            PlotCond::Always,
+        );
+        token.end();
+    }
+
+    let _ = imgui.render();
+    drop(plot);
+}
+
+#[test]
+fn drag_drop_helpers_begin_nothing_without_a_drag() {
+    let _guard = test_guard();
+    let mut imgui = Context::create();
+    prepare_imgui(&mut imgui);
+    let plot = PlotContext::create(&imgui);
+
+    {
+        let ui = imgui.frame();
+        let plot_ui = plot.get_plot_ui(&ui);
+        let token = plot_ui
+            .begin_plot("drag-drop")
+            .expect("failed to begin plot");
+        plot_ui.setup_x_axis(XAxis::X1, None, crate::AxisFlags::NONE);
+        assert!(
+            plot_ui
+                .drag_drop_source_item("series", "SERIES", 1u32, DragDropSourceFlags::NONE)
+                .is_none()
+        );
+        assert!(
+            plot_ui
+                .drag_drop_source_axis(Axis::Y1, "AXIS", (), DragDropSourceFlags::NONE)
+                .is_none()
+        );
+        assert!(plot_ui.drag_drop_target_plot().is_none());
+        assert!(plot_ui.drag_drop_target_axis(Axis::X1).is_none());
+        assert!(plot_ui.drag_drop_target_legend().is_none());
+        token.end();
+    }
+
+    let _ = imgui.render();
+    drop(plot);
+}
+
+#[test]
+fn typed_plot_queries_report_limits_in_plot_space() {
+    let _guard = test_guard();
+    let mut imgui = Context::create();
+    prepare_imgui(&mut imgui);
+    let plot = PlotContext::create(&imgui);
+
+    let expected = PlotRect::new(PlotRange::new(0.0, 10.0), PlotRange::new(-1.0, 1.0));
+    assert_eq!(PlotRect::from(sys::ImPlotRect::from(expected)), expected);
+    assert!(expected.contains(PlotPoint::new(5.0, 0.0)));
+    assert!(!expected.contains(PlotPoint::new(5.0, 2.0)));
+    assert_eq!(expected.size(), [10.0, 2.0]);
+
+    {
+        let ui = imgui.frame();
+        let plot_ui = plot.get_plot_ui(ui);
+        let token = plot_ui.begin_plot("queries").expect("failed to begin plot");
+        plot_ui.setup_x_axis_limits(XAxis::X1, 0.0, 10.0, PlotCond::Always);
+        plot_ui.setup_y_axis_limits(YAxis::Y1, -1.0, 1.0, PlotCond::Always);
+        assert_eq!(plot_ui.plot_limits_axes(XAxis::X1, YAxis::Y1), expected);
+        // No mouse position was submitted, so the mouse lies outside the plot.
+        let mouse = plot_ui.plot_mouse_position_axes(XAxis::X1, YAxis::Y1);
+        assert!(!expected.contains(mouse));
+        assert!(!plot_ui.is_plot_hovered());
+        assert!(!plot_ui.is_axis_hovered(Axis::X1));
+        assert_eq!(plot_ui.plot_selection_axes(XAxis::X1, YAxis::Y1), None);
+        token.end();
+    }
+
+    let _ = imgui.render();
+    drop(plot);
+}
+
+#[test]
+fn all_nan_heatmaps_still_submit_their_item() {
+    use crate::plots::{HeatmapPlot, Plot};
+
+    let _guard = test_guard();
+    let mut imgui = Context::create();
+    prepare_imgui(&mut imgui);
+    let plot = PlotContext::create(&imgui);
+
+    {
+        let ui = imgui.frame();
+        let plot_ui = plot.get_plot_ui(ui);
+        let token = plot_ui.begin_plot("heatmap").expect("failed to begin plot");
+        let values = [f64::NAN; 4];
+        HeatmapPlot::new("missing", &values, 2, 2).plot(&plot_ui);
+        let item = {
+            let _bound = plot_ui.bind();
+            unsafe { sys::ImPlot_GetItem(c"missing".as_ptr()) }
+        };
+        assert!(!item.is_null());
+        assert!(unsafe { (*item).SeenThisFrame });
+        token.end();
+    }
+
+    let _ = imgui.render();
+    drop(plot);
+}
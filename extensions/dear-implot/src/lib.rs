@@ -111,7 +111,7 @@ mod utils;
 // New modular plot types
 pub mod plots;
 
-pub use axis_types::{Axis, XAxis, YAxis, YAxisChoice};
+pub use axis_types::{Axis, AxisScale, XAxis, YAxis, YAxisChoice};
 pub(crate) use axis_types::{IMPLOT_AUTO, y_axis_choice_option_to_i32};
 pub use colormap::Colormap;
 pub use colors::PlotColorElement;
@@ -119,7 +119,7 @@ pub use context::*;
 pub use flags::*;
 pub use histogram_bins::{BinMethod, HistogramBins};
 pub use markers::Marker;
-pub use plot_types::{PlotCond, PlotLocation, PlotOrientation};
+pub use plot_types::{PlotCond, PlotLocation, PlotOrientation, PlotPoint, PlotRange, PlotRect};
 pub use style::*;
 pub use ui_ext::ImPlotExt;
 pub use utils::*;
@@ -31,3 +31,132 @@ pub enum PlotCond {
     Always = 1,
     Once = 2,
 }
+
+/// Point in plot coordinates
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PlotPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl PlotPoint {
+    /// Creates a point at `(x, y)`.
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<sys::ImPlotPoint> for PlotPoint {
+    fn from(point: sys::ImPlotPoint) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+impl From<PlotPoint> for sys::ImPlotPoint {
+    fn from(point: PlotPoint) -> Self {
+        sys::ImPlotPoint {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl From<[f64; 2]> for PlotPoint {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<PlotPoint> for [f64; 2] {
+    fn from(point: PlotPoint) -> Self {
+        [point.x, point.y]
+    }
+}
+
+/// Range of plot coordinates along one axis
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PlotRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl PlotRange {
+    /// Creates the range `min..=max`.
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns `max - min`.
+    pub fn size(&self) -> f64 {
+        self.max - self.min
+    }
+
+    /// Returns true if `value` lies within the range, bounds included.
+    pub fn contains(&self, value: f64) -> bool {
+        self.min <= value && value <= self.max
+    }
+}
+
+impl From<sys::ImPlotRange> for PlotRange {
+    fn from(range: sys::ImPlotRange) -> Self {
+        Self::new(range.Min, range.Max)
+    }
+}
+
+impl From<PlotRange> for sys::ImPlotRange {
+    fn from(range: PlotRange) -> Self {
+        sys::ImPlotRange {
+            Min: range.min,
+            Max: range.max,
+        }
+    }
+}
+
+/// Rectangle in plot coordinates, such as the visible limits of a pair of axes
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PlotRect {
+    pub x: PlotRange,
+    pub y: PlotRange,
+}
+
+impl PlotRect {
+    /// Creates the rectangle spanning `x` and `y`.
+    pub const fn new(x: PlotRange, y: PlotRange) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the corner with the smallest coordinates.
+    pub fn min(&self) -> PlotPoint {
+        PlotPoint::new(self.x.min, self.y.min)
+    }
+
+    /// Returns the corner with the largest coordinates.
+    pub fn max(&self) -> PlotPoint {
+        PlotPoint::new(self.x.max, self.y.max)
+    }
+
+    /// Returns the width and height.
+    pub fn size(&self) -> [f64; 2] {
+        [self.x.size(), self.y.size()]
+    }
+
+    /// Returns true if `point` lies within the rectangle, edges included.
+    pub fn contains(&self, point: PlotPoint) -> bool {
+        self.x.contains(point.x) && self.y.contains(point.y)
+    }
+}
+
+impl From<sys::ImPlotRect> for PlotRect {
+    fn from(rect: sys::ImPlotRect) -> Self {
+        Self::new(rect.X.into(), rect.Y.into())
+    }
+}
+
+impl From<PlotRect> for sys::ImPlotRect {
+    fn from(rect: PlotRect) -> Self {
+        sys::ImPlotRect {
+            X: rect.x.into(),
+            Y: rect.y.into(),
+        }
+    }
+}
@@ -1,10 +1,9 @@
 //! Heatmap plot implementation
 
-use super::{
-    Plot, PlotDataLayout, PlotError, PlotItemStyle, plot_spec_with_style, with_plot_str_or_empty,
-};
+use super::{Plot, PlotDataLayout, PlotError, PlotItemStyle, plot_spec_with_style};
 use crate::{HeatmapFlags, ItemFlags, sys};
 use dear_imgui_rs::with_scratch_txt_two;
+use std::os::raw::c_char;
 
 fn validate_grid_counts(
     caller: &str,
@@ -39,7 +38,273 @@ fn heatmap_count_to_i32(caller: &str, name: &str, value: usize) -> Result<i32, P
         .map_err(|_| PlotError::InvalidData(format!("{caller} {name} exceeded ImPlot's i32 range")))
 }
 
+/// Heatmaps whose NaN cells split them into more runs than this are drawn in a single call, with
+/// NaN cells shown in the lowest color of the scale instead of transparent.
+pub const MAX_NAN_RUNS: usize = 256;
+
+/// Memory layout of heatmap values
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum HeatmapLayout {
+    /// `values[row * cols + col]`, rows drawn top to bottom
+    #[default]
+    RowMajor,
+    /// `values[col * rows + row]`, as produced by column-major (e.g. Fortran-ordered) matrices
+    ColumnMajor,
+}
+
+trait HeatmapValue: Copy {
+    fn is_nan(self) -> bool;
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+    unsafe fn plot_heatmap(
+        label_id: *const c_char,
+        values: *const Self,
+        shape: (i32, i32),
+        scale: (f64, f64),
+        label_fmt: *const c_char,
+        bounds: (sys::ImPlotPoint, sys::ImPlotPoint),
+        spec: sys::ImPlotSpec_c,
+    );
+}
+
+impl HeatmapValue for f64 {
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    unsafe fn plot_heatmap(
+        label_id: *const c_char,
+        values: *const Self,
+        shape: (i32, i32),
+        scale: (f64, f64),
+        label_fmt: *const c_char,
+        bounds: (sys::ImPlotPoint, sys::ImPlotPoint),
+        spec: sys::ImPlotSpec_c,
+    ) {
+        unsafe {
+            sys::ImPlot_PlotHeatmap_doublePtr(
+                label_id, values, shape.0, shape.1, scale.0, scale.1, label_fmt, bounds.0,
+                bounds.1, spec,
+            )
+        }
+    }
+}
+
+impl HeatmapValue for f32 {
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    unsafe fn plot_heatmap(
+        label_id: *const c_char,
+        values: *const Self,
+        shape: (i32, i32),
+        scale: (f64, f64),
+        label_fmt: *const c_char,
+        bounds: (sys::ImPlotPoint, sys::ImPlotPoint),
+        spec: sys::ImPlotSpec_c,
+    ) {
+        unsafe {
+            sys::ImPlot_PlotHeatmap_FloatPtr(
+                label_id, values, shape.0, shape.1, scale.0, scale.1, label_fmt, bounds.0,
+                bounds.1, spec,
+            )
+        }
+    }
+}
+
+fn visible_label_fmt(
+    label_fmt: Option<&str>,
+    max_labeled_cells: Option<usize>,
+    cells: usize,
+) -> Option<&str> {
+    label_fmt
+        .filter(|s| !s.contains('\0'))
+        .filter(|_| max_labeled_cells.is_none_or(|max| cells <= max))
+}
+
+/// A run of consecutive non-NaN values along one row (row-major) or column (column-major).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct HeatmapRun {
+    /// Row (row-major) or column (column-major) index.
+    line: usize,
+    /// Index of the first cell within the line.
+    first: usize,
+    len: usize,
+}
+
+fn heatmap_runs<T: HeatmapValue>(values: &[T], line_len: usize) -> Vec<HeatmapRun> {
+    let mut runs = Vec::new();
+    for (line, cells) in values.chunks_exact(line_len).enumerate() {
+        let mut first = 0;
+        while first < cells.len() {
+            if cells[first].is_nan() {
+                first += 1;
+                continue;
+            }
+            let len = cells[first..]
+                .iter()
+                .position(|v| v.is_nan())
+                .unwrap_or(cells.len() - first);
+            runs.push(HeatmapRun { line, first, len });
+            first += len;
+        }
+    }
+    runs
+}
+
+/// The color scale ImPlot would pick for `values`, ignoring NaN cells.
+fn finite_scale<T: HeatmapValue>(values: &[T], scale: (f64, f64)) -> Option<(f64, f64)> {
+    if scale != (0.0, 0.0) {
+        return Some(scale);
+    }
+    let (min, max) = values
+        .iter()
+        .filter(|v| !v.is_nan())
+        .map(|v| v.to_f64())
+        .fold(None, |acc: Option<(f64, f64)>, v| match acc {
+            Some((min, max)) => Some((min.min(v), max.max(v))),
+            None => Some((v, v)),
+        })?;
+    Some(if min == max {
+        (min - 0.5, max + 0.5)
+    } else {
+        (min, max)
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_heatmap<T: HeatmapValue>(
+    label: &str,
+    values: &[T],
+    rows: usize,
+    cols: usize,
+    scale: (f64, f64),
+    label_fmt: Option<&str>,
+    bounds: (sys::ImPlotPoint, sys::ImPlotPoint),
+    style: PlotItemStyle,
+    flags: u32,
+) {
+    let label = if label.contains('\0') { "" } else { label };
+    let spec = plot_spec_with_style(style, flags, PlotDataLayout::DEFAULT);
+    with_scratch_txt_two(label, label_fmt.unwrap_or(""), |label_ptr, fmt_ptr| {
+        let fmt_ptr = if label_fmt.is_some() {
+            fmt_ptr
+        } else {
+            std::ptr::null()
+        };
+        if !values.iter().any(|v| v.is_nan()) {
+            // Counts were validated against i32 before drawing.
+            unsafe {
+                T::plot_heatmap(
+                    label_ptr,
+                    values.as_ptr(),
+                    (rows as i32, cols as i32),
+                    scale,
+                    fmt_ptr,
+                    bounds,
+                    spec,
+                )
+            };
+            return;
+        }
+
+        // ImPlot has no notion of missing cells, so draw each run of non-NaN cells as its own
+        // strip with a shared scale, leaving NaN cells transparent.
+        let Some(scale) = finite_scale(values, scale) else {
+            // Nothing to draw, but still submit the item (ImPlot returns early for an empty
+            // shape) so it keeps its legend entry and fits its bounds.
+            unsafe {
+                T::plot_heatmap(
+                    label_ptr,
+                    values.as_ptr(),
+                    (0, 0),
+                    (0.0, 1.0),
+                    fmt_ptr,
+                    bounds,
+                    spec,
+                )
+            };
+            return;
+        };
+        let column_major = flags & HeatmapFlags::COL_MAJOR.bits() != 0;
+        let line_len = if column_major { rows } else { cols };
+        let runs = heatmap_runs(values, line_len);
+        if runs.len() > MAX_NAN_RUNS {
+            // One call per run would get expensive for scattered NaN cells.
+            let filled: Vec<T> = values
+                .iter()
+                .map(|&v| if v.is_nan() { T::from_f64(scale.0) } else { v })
+                .collect();
+            unsafe {
+                T::plot_heatmap(
+                    label_ptr,
+                    filled.as_ptr(),
+                    (rows as i32, cols as i32),
+                    scale,
+                    fmt_ptr,
+                    bounds,
+                    spec,
+                )
+            };
+            return;
+        }
+        let (min, max) = bounds;
+        let cell_w = (max.x - min.x) / cols as f64;
+        let cell_h = (max.y - min.y) / rows as f64;
+        for run in runs {
+            let (row, col, run_rows, run_cols) = if column_major {
+                (run.first, run.line, run.len, 1)
+            } else {
+                (run.line, run.first, 1, run.len)
+            };
+            // Row 0 is drawn at the top of the bounds.
+            let run_min = sys::ImPlotPoint {
+                x: min.x + col as f64 * cell_w,
+                y: max.y - (row + run_rows) as f64 * cell_h,
+            };
+            let run_max = sys::ImPlotPoint {
+                x: min.x + (col + run_cols) as f64 * cell_w,
+                y: max.y - row as f64 * cell_h,
+            };
+            let start = run.line * line_len + run.first;
+            unsafe {
+                T::plot_heatmap(
+                    label_ptr,
+                    values[start..].as_ptr(),
+                    (run_rows as i32, run_cols as i32),
+                    scale,
+                    fmt_ptr,
+                    (run_min, run_max),
+                    spec,
+                )
+            };
+        }
+    });
+}
+
 /// Builder for heatmap plots with extensive customization options
+///
+/// NaN values mark missing cells: they are drawn transparent, get no value label and are
+/// ignored when auto-scaling the colormap. Each run of non-NaN cells along a row (or column in
+/// [`HeatmapLayout::ColumnMajor`]) is drawn as its own ImPlot heatmap; beyond [`MAX_NAN_RUNS`]
+/// runs the heatmap is drawn at once and NaN cells take the lowest color of the scale.
 pub struct HeatmapPlot<'a> {
     label: &'a str,
     values: &'a [f64],
@@ -49,6 +314,7 @@ pub struct HeatmapPlot<'a> {
     scale_min: f64,
     scale_max: f64,
     label_fmt: Option<&'a str>,
+    max_labeled_cells: Option<usize>,
     bounds_min: sys::ImPlotPoint,
     bounds_max: sys::ImPlotPoint,
     flags: HeatmapFlags,
@@ -66,7 +332,8 @@ impl<'a> HeatmapPlot<'a> {
     ///
     /// # Arguments
     /// * `label` - The label for the heatmap
-    /// * `values` - The data values in row-major order (unless ColMajor flag is set)
+    /// * `values` - The data values in row-major order (see [`HeatmapPlot::with_layout`]),
+    ///   NaN for missing cells
     /// * `rows` - Number of rows in the data
     /// * `cols` - Number of columns in the data
     pub fn new(label: &'a str, values: &'a [f64], rows: usize, cols: usize) -> Self {
@@ -79,6 +346,7 @@ impl<'a> HeatmapPlot<'a> {
             scale_min: 0.0,
             scale_max: 0.0, // Auto-scale when both are 0
             label_fmt: Some("%.1f"),
+            max_labeled_cells: None,
             bounds_min: sys::ImPlotPoint { x: 0.0, y: 0.0 },
             bounds_max: sys::ImPlotPoint { x: 1.0, y: 1.0 },
             flags: HeatmapFlags::NONE,
@@ -127,9 +395,40 @@ impl<'a> HeatmapPlot<'a> {
         self
     }
 
+    /// Set how `values` are laid out in memory
+    pub fn with_layout(mut self, layout: HeatmapLayout) -> Self {
+        self.flags.set(
+            HeatmapFlags::COL_MAJOR,
+            layout == HeatmapLayout::ColumnMajor,
+        );
+        self
+    }
+
     /// Use column-major data ordering instead of row-major
-    pub fn column_major(mut self) -> Self {
-        self.flags |= HeatmapFlags::COL_MAJOR;
+    pub fn column_major(self) -> Self {
+        self.with_layout(HeatmapLayout::ColumnMajor)
+    }
+
+    /// Set the drawing area bounds as `(min, max)` corners, or `None` for the unit square
+    pub fn with_bounds_rect(mut self, bounds: Option<([f64; 2], [f64; 2])>) -> Self {
+        let (min, max) = bounds.unwrap_or(([0.0, 0.0], [1.0, 1.0]));
+        self.bounds_min = sys::ImPlotPoint {
+            x: min[0],
+            y: min[1],
+        };
+        self.bounds_max = sys::ImPlotPoint {
+            x: max[0],
+            y: max[1],
+        };
+        self
+    }
+
+    /// Only draw value labels when the matrix has at most `max_cells` cells
+    ///
+    /// Labels of large matrices overlap into unreadable text; a cap around `Some(1024)` keeps
+    /// them for small matrices only. `None` (the default) always draws them.
+    pub fn with_max_labeled_cells(mut self, max_cells: Option<usize>) -> Self {
+        self.max_labeled_cells = max_cells;
         self
     }
 
@@ -153,61 +452,18 @@ impl<'a> Plot for HeatmapPlot<'a> {
         if self.validate().is_err() {
             return; // Skip plotting if data is invalid
         }
-        let Ok(rows) = heatmap_count_to_i32("HeatmapPlot::plot()", "rows", self.rows) else {
-            return;
-        };
-        let Ok(cols) = heatmap_count_to_i32("HeatmapPlot::plot()", "cols", self.cols) else {
-            return;
-        };
-        let label_fmt = self.label_fmt.filter(|s| !s.contains('\0'));
         let _guard = plot_ui.bind();
-        match label_fmt {
-            Some(label_fmt) => {
-                let label = if self.label.contains('\0') {
-                    ""
-                } else {
-                    self.label
-                };
-                with_scratch_txt_two(label, label_fmt, |label_ptr, label_fmt_ptr| unsafe {
-                    let spec = plot_spec_with_style(
-                        self.style,
-                        self.flags.bits() | self.item_flags.bits(),
-                        PlotDataLayout::DEFAULT,
-                    );
-                    sys::ImPlot_PlotHeatmap_doublePtr(
-                        label_ptr,
-                        self.values.as_ptr(),
-                        rows,
-                        cols,
-                        self.scale_min,
-                        self.scale_max,
-                        label_fmt_ptr,
-                        self.bounds_min,
-                        self.bounds_max,
-                        spec,
-                    );
-                })
-            }
-            None => with_plot_str_or_empty(self.label, |label_ptr| unsafe {
-                let spec = plot_spec_with_style(
-                    self.style,
-                    self.flags.bits() | self.item_flags.bits(),
-                    PlotDataLayout::DEFAULT,
-                );
-                sys::ImPlot_PlotHeatmap_doublePtr(
-                    label_ptr,
-                    self.values.as_ptr(),
-                    rows,
-                    cols,
-                    self.scale_min,
-                    self.scale_max,
-                    std::ptr::null(),
-                    self.bounds_min,
-                    self.bounds_max,
-                    spec,
-                );
-            }),
-        }
+        draw_heatmap(
+            self.label,
+            self.values,
+            self.rows,
+            self.cols,
+            (self.scale_min, self.scale_max),
+            visible_label_fmt(self.label_fmt, self.max_labeled_cells, self.values.len()),
+            (self.bounds_min, self.bounds_max),
+            self.style,
+            self.flags.bits() | self.item_flags.bits(),
+        );
     }
 
     fn label(&self) -> &str {
@@ -216,6 +472,8 @@ impl<'a> Plot for HeatmapPlot<'a> {
 }
 
 /// Float version of heatmap for better performance with f32 data
+///
+/// NaN values are handled like in [`HeatmapPlot`].
 pub struct HeatmapPlotF32<'a> {
     label: &'a str,
     values: &'a [f32],
@@ -225,6 +483,7 @@ pub struct HeatmapPlotF32<'a> {
     scale_min: f64,
     scale_max: f64,
     label_fmt: Option<&'a str>,
+    max_labeled_cells: Option<usize>,
     bounds_min: sys::ImPlotPoint,
     bounds_max: sys::ImPlotPoint,
     flags: HeatmapFlags,
@@ -249,6 +508,7 @@ impl<'a> HeatmapPlotF32<'a> {
             scale_min: 0.0,
             scale_max: 0.0,
             label_fmt: Some("%.1f"),
+            max_labeled_cells: None,
             bounds_min: sys::ImPlotPoint { x: 0.0, y: 0.0 },
             bounds_max: sys::ImPlotPoint { x: 1.0, y: 1.0 },
             flags: HeatmapFlags::NONE,
@@ -288,9 +548,40 @@ impl<'a> HeatmapPlotF32<'a> {
         self
     }
 
+    /// Set how `values` are laid out in memory
+    pub fn with_layout(mut self, layout: HeatmapLayout) -> Self {
+        self.flags.set(
+            HeatmapFlags::COL_MAJOR,
+            layout == HeatmapLayout::ColumnMajor,
+        );
+        self
+    }
+
     /// Use column-major data ordering
-    pub fn column_major(mut self) -> Self {
-        self.flags |= HeatmapFlags::COL_MAJOR;
+    pub fn column_major(self) -> Self {
+        self.with_layout(HeatmapLayout::ColumnMajor)
+    }
+
+    /// Set the drawing area bounds as `(min, max)` corners, or `None` for the unit square
+    pub fn with_bounds_rect(mut self, bounds: Option<([f64; 2], [f64; 2])>) -> Self {
+        let (min, max) = bounds.unwrap_or(([0.0, 0.0], [1.0, 1.0]));
+        self.bounds_min = sys::ImPlotPoint {
+            x: min[0],
+            y: min[1],
+        };
+        self.bounds_max = sys::ImPlotPoint {
+            x: max[0],
+            y: max[1],
+        };
+        self
+    }
+
+    /// Only draw value labels when the matrix has at most `max_cells` cells
+    ///
+    /// Labels of large matrices overlap into unreadable text; a cap around `Some(1024)` keeps
+    /// them for small matrices only. `None` (the default) always draws them.
+    pub fn with_max_labeled_cells(mut self, max_cells: Option<usize>) -> Self {
+        self.max_labeled_cells = max_cells;
         self
     }
 
@@ -314,61 +605,18 @@ impl<'a> Plot for HeatmapPlotF32<'a> {
         if self.validate().is_err() {
             return;
         }
-        let Ok(rows) = heatmap_count_to_i32("HeatmapPlotF32::plot()", "rows", self.rows) else {
-            return;
-        };
-        let Ok(cols) = heatmap_count_to_i32("HeatmapPlotF32::plot()", "cols", self.cols) else {
-            return;
-        };
-        let label_fmt = self.label_fmt.filter(|s| !s.contains('\0'));
         let _guard = plot_ui.bind();
-        match label_fmt {
-            Some(label_fmt) => {
-                let label = if self.label.contains('\0') {
-                    ""
-                } else {
-                    self.label
-                };
-                with_scratch_txt_two(label, label_fmt, |label_ptr, label_fmt_ptr| unsafe {
-                    let spec = plot_spec_with_style(
-                        self.style,
-                        self.flags.bits() | self.item_flags.bits(),
-                        PlotDataLayout::DEFAULT,
-                    );
-                    sys::ImPlot_PlotHeatmap_FloatPtr(
-                        label_ptr,
-                        self.values.as_ptr(),
-                        rows,
-                        cols,
-                        self.scale_min,
-                        self.scale_max,
-                        label_fmt_ptr,
-                        self.bounds_min,
-                        self.bounds_max,
-                        spec,
-                    );
-                })
-            }
-            None => with_plot_str_or_empty(self.label, |label_ptr| unsafe {
-                let spec = plot_spec_with_style(
-                    self.style,
-                    self.flags.bits() | self.item_flags.bits(),
-                    PlotDataLayout::DEFAULT,
-                );
-                sys::ImPlot_PlotHeatmap_FloatPtr(
-                    label_ptr,
-                    self.values.as_ptr(),
-                    rows,
-                    cols,
-                    self.scale_min,
-                    self.scale_max,
-                    std::ptr::null(),
-                    self.bounds_min,
-                    self.bounds_max,
-                    spec,
-                );
-            }),
-        }
+        draw_heatmap(
+            self.label,
+            self.values,
+            self.rows,
+            self.cols,
+            (self.scale_min, self.scale_max),
+            visible_label_fmt(self.label_fmt, self.max_labeled_cells, self.values.len()),
+            (self.bounds_min, self.bounds_max),
+            self.style,
+            self.flags.bits() | self.item_flags.bits(),
+        );
     }
 
     fn label(&self) -> &str {
@@ -429,8 +677,11 @@ impl<'ui> crate::PlotUi<'ui> {
 
 #[cfg(test)]
 mod tests {
-    use super::{HeatmapPlot, HeatmapPlotF32};
-    use crate::PlotError;
+    use super::{
+        HeatmapLayout, HeatmapPlot, HeatmapPlotF32, HeatmapRun, finite_scale, heatmap_runs,
+        visible_label_fmt,
+    };
+    use crate::{HeatmapFlags, PlotError};
 
     fn invalid_data_message(err: PlotError) -> String {
         match err {
@@ -474,4 +725,67 @@ mod tests {
             .expect_err("oversized column count must be rejected");
         assert!(invalid_data_message(err).contains("cols exceeded ImPlot's i32 range"));
     }
+
+    #[test]
+    fn heatmap_layout_toggles_col_major_flag() {
+        let values = [1.0; 4];
+        let plot = HeatmapPlot::new("heat", &values, 2, 2).with_layout(HeatmapLayout::ColumnMajor);
+        assert!(plot.flags.contains(HeatmapFlags::COL_MAJOR));
+        let plot = plot.with_layout(HeatmapLayout::RowMajor);
+        assert!(!plot.flags.contains(HeatmapFlags::COL_MAJOR));
+        assert!(
+            HeatmapPlotF32::new("heat", &[1.0f32; 4], 2, 2)
+                .column_major()
+                .flags
+                .contains(HeatmapFlags::COL_MAJOR)
+        );
+    }
+
+    #[test]
+    fn heatmap_labels_are_skipped_for_large_matrices() {
+        assert_eq!(visible_label_fmt(Some("%.1f"), Some(4), 4), Some("%.1f"));
+        assert_eq!(visible_label_fmt(Some("%.1f"), Some(4), 5), None);
+        assert_eq!(visible_label_fmt(Some("%.1f"), None, 5), Some("%.1f"));
+        assert_eq!(visible_label_fmt(Some("%.1\0f"), None, 1), None);
+        assert_eq!(visible_label_fmt(None, None, 1), None);
+    }
+
+    #[test]
+    fn heatmap_runs_skip_nan_cells() {
+        let nan = f64::NAN;
+        let values = [1.0, nan, 2.0, 3.0, nan, nan, nan, 4.0];
+        assert_eq!(
+            heatmap_runs(&values, 4),
+            vec![
+                HeatmapRun {
+                    line: 0,
+                    first: 0,
+                    len: 1
+                },
+                HeatmapRun {
+                    line: 0,
+                    first: 2,
+                    len: 2
+                },
+                HeatmapRun {
+                    line: 1,
+                    first: 3,
+                    len: 1
+                },
+            ]
+        );
+        assert!(heatmap_runs(&[f32::NAN; 6], 3).is_empty());
+    }
+
+    #[test]
+    fn heatmap_auto_scale_ignores_nan_cells() {
+        let values = [f64::NAN, 2.0, -1.0, f64::NAN];
+        assert_eq!(finite_scale(&values, (0.0, 0.0)), Some((-1.0, 2.0)));
+        assert_eq!(finite_scale(&values, (0.0, 10.0)), Some((0.0, 10.0)));
+        assert_eq!(
+            finite_scale(&[3.0f32, f32::NAN], (0.0, 0.0)),
+            Some((2.5, 3.5))
+        );
+        assert_eq!(finite_scale(&[f64::NAN], (0.0, 0.0)), None);
+    }
 }
@@ -118,12 +118,25 @@ impl<'a> PieChartPlot<'a> {
             });
         }
 
-        if self.radius <= 0.0 {
+        if !(self.radius.is_finite() && self.radius > 0.0) {
             return Err(PlotError::InvalidData(
                 "Radius must be positive".to_string(),
             ));
         }
 
+        if !(self.center_x.is_finite() && self.center_y.is_finite() && self.angle0.is_finite()) {
+            return Err(PlotError::InvalidData(
+                "Pie chart center and start angle must be finite".to_string(),
+            ));
+        }
+
+        // NaN or infinite slices would poison the normalized sum and every slice angle.
+        if self.values.iter().any(|v| !v.is_finite()) {
+            return Err(PlotError::InvalidData(
+                "Pie chart values must be finite".to_string(),
+            ));
+        }
+
         // Check for negative values
         if self.values.iter().any(|&v| v < 0.0) {
             return Err(PlotError::InvalidData(
@@ -277,12 +290,25 @@ impl<'a> PieChartPlotF32<'a> {
             });
         }
 
-        if self.radius <= 0.0 {
+        if !(self.radius.is_finite() && self.radius > 0.0) {
             return Err(PlotError::InvalidData(
                 "Radius must be positive".to_string(),
             ));
         }
 
+        if !(self.center_x.is_finite() && self.center_y.is_finite() && self.angle0.is_finite()) {
+            return Err(PlotError::InvalidData(
+                "Pie chart center and start angle must be finite".to_string(),
+            ));
+        }
+
+        // NaN or infinite slices would poison the normalized sum and every slice angle.
+        if self.values.iter().any(|v| !v.is_finite()) {
+            return Err(PlotError::InvalidData(
+                "Pie chart values must be finite".to_string(),
+            ));
+        }
+
         if self.values.iter().any(|&v| v < 0.0) {
             return Err(PlotError::InvalidData(
                 "Pie chart values cannot be negative".to_string(),
@@ -375,3 +401,55 @@ impl<'ui> crate::PlotUi<'ui> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{PieChartPlot, PieChartPlotF32};
+    use crate::PlotError;
+
+    fn invalid_data_message(err: PlotError) -> String {
+        match err {
+            PlotError::InvalidData(message) => message,
+            other => panic!("expected invalid data error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn pie_chart_rejects_non_finite_values_before_ffi() {
+        let values = [1.0, f64::INFINITY];
+        let err = PieChartPlot::new(vec!["a", "b"], &values, 0.5, 0.5, 0.4)
+            .validate()
+            .expect_err("infinite slice must be rejected");
+        assert!(invalid_data_message(err).contains("values must be finite"));
+
+        let values = [f32::NAN, 1.0];
+        let err = PieChartPlotF32::new(vec!["a", "b"], &values, 0.5, 0.5, 0.4)
+            .validate()
+            .expect_err("NaN slice must be rejected");
+        assert!(invalid_data_message(err).contains("values must be finite"));
+    }
+
+    #[test]
+    fn pie_chart_rejects_non_finite_geometry_before_ffi() {
+        let values = [1.0, 2.0];
+        let err = PieChartPlot::new(vec!["a", "b"], &values, 0.5, 0.5, f64::NAN)
+            .validate()
+            .expect_err("NaN radius must be rejected");
+        assert!(invalid_data_message(err).contains("Radius must be positive"));
+
+        let err = PieChartPlot::new(vec!["a", "b"], &values, 0.5, 0.5, 0.4)
+            .with_start_angle(f64::INFINITY)
+            .validate()
+            .expect_err("infinite start angle must be rejected");
+        assert!(invalid_data_message(err).contains("start angle must be finite"));
+
+        assert!(
+            PieChartPlot::new(vec!["a", "b"], &values, 0.5, 0.5, 0.4)
+                .normalize()
+                .ignore_hidden()
+                .exploding()
+                .validate()
+                .is_ok()
+        );
+    }
+}
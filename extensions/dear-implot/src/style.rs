@@ -500,6 +500,19 @@ fn assert_colormap_sample_t(t: f32) {
     );
 }
 
+/// How time axes format their tick labels, stored in the ImPlot style.
+///
+/// Applies to axes using [`AxisScale::Time`](crate::AxisScale::Time).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PlotTimeFormat {
+    /// Show timestamps in the local time zone instead of UTC.
+    pub use_local_time: bool,
+    /// Format dates as ISO 8601 (`2024-03-15`) instead of `3/15/24`.
+    pub use_iso8601: bool,
+    /// Use a 24-hour clock (`13:30`) instead of `1:30pm`.
+    pub use_24_hour_clock: bool,
+}
+
 impl PlotContext {
     #[inline]
     fn with_bound_style<R>(&self, caller: &str, f: impl FnOnce() -> R) -> R {
@@ -633,6 +646,33 @@ impl PlotContext {
         )
     }
 
+    /// Return how this context's time axes format dates and times.
+    pub fn time_format(&self) -> PlotTimeFormat {
+        self.with_bound_style("dear-implot: PlotContext::time_format()", || unsafe {
+            let style = sys::ImPlot_GetStyle();
+            if style.is_null() {
+                return PlotTimeFormat::default();
+            }
+            PlotTimeFormat {
+                use_local_time: (*style).UseLocalTime,
+                use_iso8601: (*style).UseISO8601,
+                use_24_hour_clock: (*style).Use24HourClock,
+            }
+        })
+    }
+
+    /// Permanently set how this context's time axes format dates and times.
+    pub fn set_time_format(&self, format: PlotTimeFormat) {
+        self.with_bound_style("dear-implot: PlotContext::set_time_format()", || unsafe {
+            let style = sys::ImPlot_GetStyle();
+            if !style.is_null() {
+                (*style).UseLocalTime = format.use_local_time;
+                (*style).UseISO8601 = format.use_iso8601;
+                (*style).Use24HourClock = format.use_24_hour_clock;
+            }
+        })
+    }
+
     /// Permanently set the default colormap by name. Invalid names are ignored.
     pub fn set_style_colormap_by_name(&self, name: &str) {
         if let Some(idx) = self.colormap_index_by_name(name) {
@@ -1,6 +1,6 @@
 // Utility functions for ImPlot
 
-use crate::{Axis, PlotUi, XAxis, YAxis, compat_ffi, sys};
+use crate::{Axis, PlotPoint, PlotRect, PlotUi, XAxis, YAxis, compat_ffi, sys};
 use dear_imgui_rs::with_scratch_txt;
 use std::fmt;
 
@@ -62,9 +62,10 @@ impl PlotUi<'_> {
     }
 
     /// Get the mouse position in plot coordinates for specific axes.
-    pub fn plot_mouse_position_axes(&self, x_axis: XAxis, y_axis: YAxis) -> sys::ImPlotPoint {
+    #[doc(alias = "GetPlotMousePos")]
+    pub fn plot_mouse_position_axes(&self, x_axis: XAxis, y_axis: YAxis) -> PlotPoint {
         let _guard = self.bind();
-        unsafe { sys::ImPlot_GetPlotMousePos(x_axis as sys::ImAxis, y_axis as sys::ImAxis) }
+        unsafe { sys::ImPlot_GetPlotMousePos(x_axis as sys::ImAxis, y_axis as sys::ImAxis) }.into()
     }
 
     /// Convert pixels to plot coordinates.
@@ -198,13 +199,14 @@ impl PlotUi<'_> {
     }
 
     /// Get the current plot selection rectangle for specific axes.
-    pub fn plot_selection_axes(&self, x_axis: XAxis, y_axis: YAxis) -> Option<sys::ImPlotRect> {
+    #[doc(alias = "GetPlotSelection")]
+    pub fn plot_selection_axes(&self, x_axis: XAxis, y_axis: YAxis) -> Option<PlotRect> {
         if !self.is_plot_selected() {
             return None;
         }
         let _guard = self.bind();
         let rect = unsafe { sys::ImPlot_GetPlotSelection(x_axis as i32, y_axis as i32) };
-        Some(rect)
+        Some(rect.into())
     }
 
     /// Draw a simple round annotation marker at (x,y).
@@ -332,9 +334,10 @@ impl PlotUi<'_> {
     }
 
     /// Get the current plot limits for specific axes.
-    pub fn plot_limits_axes(&self, x_axis: XAxis, y_axis: YAxis) -> sys::ImPlotRect {
+    #[doc(alias = "GetPlotLimits")]
+    pub fn plot_limits_axes(&self, x_axis: XAxis, y_axis: YAxis) -> PlotRect {
         let _guard = self.bind();
-        unsafe { sys::ImPlot_GetPlotLimits(x_axis as i32, y_axis as i32) }
+        unsafe { sys::ImPlot_GetPlotLimits(x_axis as i32, y_axis as i32) }.into()
     }
 
     /// Check if an axis is hovered.
@@ -53,6 +53,7 @@ mod item_style;
 mod layout;
 mod mesh_builder;
 pub mod meshes;
+mod orbit;
 pub mod plots;
 mod style;
 mod surface_builder;
@@ -67,6 +68,7 @@ pub use image_builder::{Image3DByAxesBuilder, Image3DByCornersBuilder};
 pub use item_style::*;
 pub use layout::{Plot3DDataLayout, Plot3DDataOffset, Plot3DDataStride};
 pub use mesh_builder::Mesh3DBuilder;
+pub use orbit::OrbitAnimation;
 pub use plots::*;
 pub use style::*;
 pub use surface_builder::Surface3DBuilder;
@@ -0,0 +1,167 @@
+use std::time::Duration;
+
+use crate::{Plot3DCond, Plot3DUi};
+
+/// Turntable rotation state for [`Plot3DUi::orbit_animation`].
+///
+/// The azimuth advances by a fixed step per frame (`duration * frame_rate` frames in total)
+/// instead of following wall-clock time, so every run produces the same sequence of views. This
+/// is what frame-by-frame recordings need: capture one image per frame until the animation
+/// reports completion.
+///
+/// Keep the value alive across frames (one per plot) and call [`Self::reset`] to record again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrbitAnimation {
+    duration: Duration,
+    frame_count: u32,
+    revolutions: f32,
+    elevation: f32,
+    start_azimuth: f32,
+    frame: u32,
+}
+
+impl OrbitAnimation {
+    /// Default frame rate used to turn `duration` into a frame count.
+    pub const DEFAULT_FRAME_RATE: f32 = 60.0;
+
+    /// Creates an orbit doing `revolutions` full turns over `duration` at 60 frames per second.
+    ///
+    /// # Panics
+    /// Panics if `revolutions` is not finite.
+    pub fn new(duration: Duration, revolutions: f32) -> Self {
+        assert!(
+            revolutions.is_finite(),
+            "OrbitAnimation::new() revolutions must be finite"
+        );
+        Self {
+            duration,
+            frame_count: frames_for(duration, Self::DEFAULT_FRAME_RATE),
+            revolutions,
+            elevation: 25.0,
+            start_azimuth: -135.0,
+            frame: 0,
+        }
+    }
+
+    /// Recomputes the frame count for a recording frame rate other than 60 FPS.
+    ///
+    /// # Panics
+    /// Panics if `fps` is not finite and positive.
+    pub fn frame_rate(mut self, fps: f32) -> Self {
+        assert!(
+            fps.is_finite() && fps > 0.0,
+            "OrbitAnimation::frame_rate() fps must be finite and positive"
+        );
+        self.frame_count = frames_for(self.duration, fps);
+        self
+    }
+
+    /// Sets the camera elevation in degrees (default: 25).
+    pub fn elevation(mut self, degrees: f32) -> Self {
+        self.elevation = degrees;
+        self
+    }
+
+    /// Sets the azimuth of the first frame in degrees (default: -135).
+    pub fn start_azimuth(mut self, degrees: f32) -> Self {
+        self.start_azimuth = degrees;
+        self
+    }
+
+    /// Total number of frames in the animation.
+    pub fn frame_count(&self) -> u32 {
+        self.frame_count
+    }
+
+    /// Index of the next frame to be set up.
+    pub fn frame_index(&self) -> u32 {
+        self.frame
+    }
+
+    /// Returns true once the last frame has been set up.
+    pub fn is_finished(&self) -> bool {
+        self.frame >= self.frame_count
+    }
+
+    /// Fraction of the animation already set up, in `0.0..=1.0`.
+    pub fn progress(&self) -> f32 {
+        self.frame as f32 / self.frame_count as f32
+    }
+
+    /// Restarts the animation from the first frame.
+    pub fn reset(&mut self) {
+        self.frame = 0;
+    }
+
+    /// `(elevation, azimuth)` in degrees for `frame`. The last frame lands exactly on the final
+    /// angle so a full revolution ends where it started.
+    pub fn angles_at(&self, frame: u32) -> (f32, f32) {
+        let last = self.frame_count.saturating_sub(1).max(1);
+        let t = frame.min(last) as f32 / last as f32;
+        let azimuth = self.start_azimuth + 360.0 * self.revolutions * t;
+        (self.elevation, azimuth)
+    }
+
+    fn advance(&mut self) -> (f32, f32) {
+        let angles = self.angles_at(self.frame);
+        if !self.is_finished() {
+            self.frame += 1;
+        }
+        angles
+    }
+}
+
+fn frames_for(duration: Duration, fps: f32) -> u32 {
+    let frames = (duration.as_secs_f64() * f64::from(fps)).round();
+    (frames as u32).max(1)
+}
+
+impl<'ui> Plot3DUi<'ui> {
+    /// Rotates the current plot's box to the next frame of `orbit` and returns true once the
+    /// animation has completed.
+    ///
+    /// Call it once per frame in the setup phase, right after `begin_plot().build()` and before
+    /// plotting items. After completion the box stays at the final angle; mouse rotation is
+    /// overridden while the animation runs.
+    pub fn orbit_animation(&self, orbit: &mut OrbitAnimation) -> bool {
+        let (elevation, azimuth) = orbit.advance();
+        self.setup_box_rotation(elevation, azimuth, false, Plot3DCond::Always);
+        orbit.is_finished()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrbitAnimation;
+    use std::time::Duration;
+
+    #[test]
+    fn orbit_steps_deterministically_and_ends_on_final_angle() {
+        let mut orbit = OrbitAnimation::new(Duration::from_secs(2), 1.0).start_azimuth(0.0);
+        assert_eq!(orbit.frame_count(), 120);
+
+        let mut last = None;
+        while !orbit.is_finished() {
+            last = Some(orbit.advance());
+        }
+        assert_eq!(orbit.angles_at(0), (25.0, 0.0));
+        assert_eq!(last, Some((25.0, 360.0)));
+        assert_eq!(orbit.progress(), 1.0);
+
+        // Further frames hold the final view.
+        assert_eq!(orbit.advance(), (25.0, 360.0));
+        orbit.reset();
+        assert_eq!(orbit.frame_index(), 0);
+    }
+
+    #[test]
+    fn orbit_frame_rate_controls_frame_count() {
+        let orbit = OrbitAnimation::new(Duration::from_secs(3), 0.5).frame_rate(30.0);
+        assert_eq!(orbit.frame_count(), 90);
+        assert_eq!(OrbitAnimation::new(Duration::ZERO, 1.0).frame_count(), 1);
+        assert!(
+            std::panic::catch_unwind(|| OrbitAnimation::new(Duration::from_secs(1), f32::NAN))
+                .is_err()
+        );
+    }
+}
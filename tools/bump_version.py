@@ -38,6 +38,8 @@ WORKSPACE_CRATES = [
     "backends/dear-imgui-glow",
     "backends/dear-imgui-sdl3",
     "backends/dear-imgui-ash",
+    "backends/dear-imgui-metal",
+    "backends/dear-imgui-tiny-skia",
     "backends/dear-imgui-bevy",
     "dear-app",
     "extensions/dear-implot-sys",
@@ -57,6 +59,7 @@ WORKSPACE_CRATES = [
     "extensions/dear-file-browser",
     "extensions/dear-imgui-reflect-derive",
     "extensions/dear-imgui-reflect",
+    "extensions/dear-imgui-testkit",
     "examples",
     "examples-wasm",
 ]
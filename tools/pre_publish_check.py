@@ -57,6 +57,8 @@ ALL_CRATES = [
     ("dear-imgui-wgpu", "backends/dear-imgui-wgpu"),
     ("dear-imgui-glow", "backends/dear-imgui-glow"),
     ("dear-imgui-ash", "backends/dear-imgui-ash"),
+    ("dear-imgui-metal", "backends/dear-imgui-metal"),
+    ("dear-imgui-tiny-skia", "backends/dear-imgui-tiny-skia"),
     ("dear-imgui-sdl3", "backends/dear-imgui-sdl3"),
     ("dear-imgui-bevy", "backends/dear-imgui-bevy"),
     ("dear-app", "dear-app"),
@@ -77,6 +79,7 @@ ALL_CRATES = [
     ("dear-file-browser", "extensions/dear-file-browser"),
     ("dear-imgui-reflect-derive", "extensions/dear-imgui-reflect-derive"),
     ("dear-imgui-reflect", "extensions/dear-imgui-reflect"),
+    ("dear-imgui-testkit", "extensions/dear-imgui-testkit"),
 ]
 
 
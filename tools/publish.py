@@ -8,14 +8,16 @@ dependencies are published before their dependents.
 Publishing Order:
 1. Tooling: dear-imgui-build-support
 2. Core: dear-imgui-sys -> dear-imgui-rs
-3. Backends: dear-imgui-winit, dear-imgui-wgpu, dear-imgui-glow, dear-imgui-ash, dear-imgui-sdl3
+3. Backends: dear-imgui-winit, dear-imgui-wgpu, dear-imgui-glow, dear-imgui-ash, dear-imgui-metal,
+             dear-imgui-tiny-skia, dear-imgui-sdl3
 4. Extensions (sys): dear-implot-sys, dear-imnodes-sys, dear-node-editor-sys,
                      dear-imguizmo-sys, dear-implot3d-sys, dear-imguizmo-quat-sys,
                      dear-imgui-test-engine-sys
 5. Extensions (high-level): dear-implot, dear-imnodes, dear-node-editor,
                             dear-imguizmo, dear-implot3d, dear-imguizmo-quat,
                             dear-imgui-test-engine, dear-file-browser,
-                            dear-imgui-reflect-derive, dear-imgui-reflect
+                            dear-imgui-reflect-derive, dear-imgui-reflect,
+                            dear-imgui-testkit
 6. Bevy backend: dear-imgui-bevy
 7. Application: dear-app
 
@@ -68,6 +70,8 @@ PUBLISH_ORDER = [
     ("dear-imgui-wgpu", "backends/dear-imgui-wgpu"),
     ("dear-imgui-glow", "backends/dear-imgui-glow"),
     ("dear-imgui-ash", "backends/dear-imgui-ash"),
+    ("dear-imgui-metal", "backends/dear-imgui-metal"),
+    ("dear-imgui-tiny-skia", "backends/dear-imgui-tiny-skia"),
     ("dear-imgui-sdl3", "backends/dear-imgui-sdl3"),
     
     # Extension sys crates (depend on dear-imgui-sys)
@@ -90,6 +94,7 @@ PUBLISH_ORDER = [
     ("dear-file-browser", "extensions/dear-file-browser"),
     ("dear-imgui-reflect-derive", "extensions/dear-imgui-reflect-derive"),
     ("dear-imgui-reflect", "extensions/dear-imgui-reflect"),
+    ("dear-imgui-testkit", "extensions/dear-imgui-testkit"),
     
     # Bevy backend has optional ecosystem extension dependencies.
     ("dear-imgui-bevy", "backends/dear-imgui-bevy"),
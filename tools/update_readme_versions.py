@@ -34,6 +34,8 @@ README_FILES = [
     "backends/dear-imgui-winit/README.md",
     "backends/dear-imgui-sdl3/README.md",
     "backends/dear-imgui-ash/README.md",
+    "backends/dear-imgui-metal/README.md",
+    "backends/dear-imgui-tiny-skia/README.md",
     "backends/dear-imgui-bevy/README.md",
     "dear-app/README.md",
     "dear-imgui-sys/README.md",